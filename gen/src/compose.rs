@@ -0,0 +1,164 @@
+/*!
+Tables for canonical composition and for canonical and compatibility
+decomposition.
+*/
+
+use super::data::Data;
+use super::tables::Trie;
+use super::ucd::CODEPOINTS;
+
+const HANGUL_BASE: usize = 0xAC00;
+const HANGUL_COUNT: usize = 11172;
+
+/// Characters that appear in one position of a composition pair, stored as
+/// ranges of consecutive code points. Each range holds the first code point,
+/// the length minus one and the index of the first code point among all of
+/// the characters. The ranges are terminated by an entry of zeros.
+pub struct Starters {
+    pub count: usize,
+    pub ranges: Vec<(usize, usize, usize)>,
+}
+
+impl Starters {
+    fn new(mut chars: Vec<usize>) -> Self {
+        chars.sort_unstable();
+        chars.dedup();
+        let mut ranges: Vec<(usize, usize, usize)> = Vec::new();
+        for (index, &cp) in chars.iter().enumerate() {
+            match ranges.last_mut() {
+                Some(range) if range.0 + range.1 + 1 == cp => range.1 += 1,
+                _ => ranges.push((cp, 0, index)),
+            }
+        }
+        ranges.push((0, 0, 0));
+        Self {
+            count: chars.len(),
+            ranges,
+        }
+    }
+
+    fn index(&self, cp: usize) -> usize {
+        self.ranges
+            .iter()
+            .find(|range| (range.0..=range.0 + range.1).contains(&cp))
+            .map(|range| range.2 + cp - range.0)
+            .unwrap_or(0)
+    }
+}
+
+/// Tables for composing pairs of characters. The composite for a pair is
+/// found by the index of the first character multiplied by the number of
+/// second characters plus the index of the second character.
+pub struct Composition {
+    pub first: Starters,
+    pub second: Starters,
+    pub trie: Trie,
+}
+
+pub fn composition(data: &Data) -> Composition {
+    let pairs = data
+        .mappings
+        .iter()
+        .filter(|(&cp, mapping)| {
+            !mapping.compat && mapping.chars.len() == 2 && !data.exclusions[cp]
+        })
+        .map(|(&cp, mapping)| (mapping.chars[0], mapping.chars[1], cp))
+        .collect::<Vec<_>>();
+    let first = Starters::new(pairs.iter().map(|pair| pair.0).collect());
+    let second = Starters::new(pairs.iter().map(|pair| pair.1).collect());
+    let mut values = vec![0; first.count * second.count];
+    for (a, b, composite) in pairs {
+        values[first.index(a) * second.count + second.index(b)] = composite as u32;
+    }
+    Composition {
+        first,
+        second,
+        trie: Trie::new(&values, 2, 1),
+    }
+}
+
+/// Tables for decomposition. Each code point maps to an offset into a list
+/// of sequences where each sequence is preceded by its length. Offset zero
+/// indicates that the character does not decompose.
+pub struct Decomposition {
+    pub trie: Trie,
+    pub list: Vec<u32>,
+}
+
+/// Returns the tables for full canonical decomposition and for full
+/// compatibility decomposition. Compatibility decompositions that match the
+/// canonical decomposition are stored with an offset of one.
+pub fn decomposition(data: &Data) -> (Decomposition, Decomposition) {
+    let mut canonical_index = vec![0; CODEPOINTS];
+    let mut compat_index = vec![0; CODEPOINTS];
+    let mut canonical_list = vec![0, 255];
+    let mut compat_list = vec![0, 255];
+    for (&cp, mapping) in &data.mappings {
+        let compat = decompose(data, cp, true);
+        if !mapping.compat {
+            let canonical = decompose(data, cp, false);
+            canonical_index[cp] = canonical_list.len() as u32;
+            push(&mut canonical_list, &canonical);
+            if compat == canonical {
+                compat_index[cp] = 1;
+                continue;
+            }
+        }
+        compat_index[cp] = compat_list.len() as u32;
+        push(&mut compat_list, &compat);
+    }
+    (
+        Decomposition {
+            trie: Trie::new(&canonical_index, 6, 4),
+            list: canonical_list,
+        },
+        Decomposition {
+            trie: Trie::new(&compat_index, 6, 4),
+            list: compat_list,
+        },
+    )
+}
+
+fn push(list: &mut Vec<u32>, chars: &[usize]) {
+    list.push(chars.len() as u32);
+    list.extend(chars.iter().map(|&cp| cp as u32));
+}
+
+/// Returns the full decomposition of a character in canonical order.
+fn decompose(data: &Data, cp: usize, compat: bool) -> Vec<usize> {
+    let mut chars = Vec::new();
+    decompose_into(data, cp, compat, &mut chars);
+    // Sort each run of non-starters by combining class.
+    let ccc = |cp: usize| data.chars[cp].combining_class;
+    let mut start = 0;
+    while start < chars.len() {
+        let len = chars[start..]
+            .iter()
+            .take_while(|&&cp| ccc(cp) != 0)
+            .count();
+        chars[start..start + len].sort_by_key(|&cp| ccc(cp));
+        start += len.max(1);
+    }
+    chars
+}
+
+fn decompose_into(data: &Data, cp: usize, compat: bool, chars: &mut Vec<usize>) {
+    if (HANGUL_BASE..HANGUL_BASE + HANGUL_COUNT).contains(&cp) {
+        let index = cp - HANGUL_BASE;
+        chars.push(0x1100 + index / 588);
+        chars.push(0x1161 + index % 588 / 28);
+        let trailing = index % 28;
+        if trailing != 0 {
+            chars.push(0x11A7 + trailing);
+        }
+        return;
+    }
+    match data.mappings.get(&cp) {
+        Some(mapping) if compat || !mapping.compat => {
+            for &c in &mapping.chars {
+                decompose_into(data, c, compat, chars);
+            }
+        }
+        _ => chars.push(cp),
+    }
+}
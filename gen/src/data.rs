@@ -0,0 +1,625 @@
+/*!
+Character properties assembled from the Unicode Character Database.
+*/
+
+use super::emoji::{self, Sequence};
+use super::props::{
+    Property, BIDI_CLASS, CATEGORY, CLUSTER_BREAK, DECOMPOSITION_TYPE, JOINING_TYPE, LINE_BREAK,
+    WORD_BREAK,
+};
+use super::shaping;
+use super::ucd::{self, Ucd, CODEPOINTS};
+use super::unihan::{self, Unihan};
+use super::Result;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+const SCRIPT_METADATA: &str = include_str!("../data/ScriptMetadata.txt");
+
+/// Name of the block variant for code points outside of any block.
+pub const NO_BLOCK: &str = "NoBlock";
+
+/// Short name of the script for unassigned code points.
+const UNKNOWN_SCRIPT: &str = "Zzzz";
+
+/// Properties of a single code point. Code points with identical properties
+/// share a record in the generated tables.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Char {
+    pub flags: u16,
+    pub category: u8,
+    pub block: u16,
+    pub script: u8,
+    pub combining_class: u8,
+    pub bidi_class: u8,
+    pub joining_type: u8,
+    pub cluster_break: u8,
+    pub word_break: u8,
+    pub line_break: u8,
+    pub use_class: u8,
+    pub myanmar_class: u8,
+    pub decomposition_type: u8,
+    pub mirror: i16,
+}
+
+pub struct Block {
+    pub name: String,
+    pub range: Option<RangeInclusive<usize>>,
+}
+
+pub struct Script {
+    pub name: String,
+    pub long_name: String,
+    pub short_name: String,
+    pub number: u16,
+    /// OpenType tags, newest first.
+    pub tags: Vec<u32>,
+    pub complex: bool,
+    pub rtl: bool,
+}
+
+/// Decomposition mapping of a character as listed in `UnicodeData.txt`.
+pub struct Mapping {
+    pub compat: bool,
+    pub chars: Vec<usize>,
+}
+
+pub struct Data {
+    pub version: (u8, u8, u8),
+    pub blocks: Vec<Block>,
+    pub scripts: Vec<Script>,
+    pub combining_classes: Vec<(String, u8)>,
+    pub chars: Vec<Char>,
+    pub mappings: BTreeMap<usize, Mapping>,
+    pub exclusions: Vec<bool>,
+    /// Number of characters with derived shaping classes.
+    pub derived_shaping: usize,
+    /// Emoji sequences with their qualification status.
+    pub emoji_sequences: Vec<Sequence>,
+    /// Subset of the Unihan database.
+    pub unihan: Unihan,
+    /// Files from which each property was loaded.
+    pub sources: Vec<Source>,
+}
+
+/// File from which a property was loaded along with the version of the
+/// file.
+pub struct Source {
+    pub property: &'static str,
+    pub file: String,
+    pub version: (u8, u8, u8),
+}
+
+/// Loads the database. Variants of the block and script enums that appear
+/// in the previously generated tables keep their discriminants.
+pub fn load(ucd: &Ucd, previous: Option<&str>) -> Result<Data> {
+    let version = ucd.version()?;
+    let mut chars = vec![Char::default(); CODEPOINTS];
+    let bidi_l = BIDI_CLASS.value("L")?;
+    for c in chars.iter_mut() {
+        c.bidi_class = bidi_l;
+    }
+    let mut mappings = BTreeMap::new();
+    load_unicode_data(ucd, &mut chars, &mut mappings)?;
+    let blocks = load_blocks(ucd, &mut chars, previous)?;
+    let scripts = load_scripts(ucd, &mut chars, previous)?;
+    let combining_classes = load_combining_classes(ucd)?;
+    load_joining_types(ucd, &mut chars)?;
+    let text = ucd.read("GraphemeBreakProperty.txt")?;
+    assign(&text, &CLUSTER_BREAK, "XX", &mut chars, |c, v| {
+        c.cluster_break = v
+    })?;
+    let text = ucd.read("WordBreakProperty.txt")?;
+    assign(&text, &WORD_BREAK, "XX", &mut chars, |c, v| {
+        c.word_break = v
+    })?;
+    let text = ucd.read("LineBreak.txt")?;
+    assign(&text, &LINE_BREAK, "XX", &mut chars, |c, v| {
+        c.line_break = v
+    })?;
+    load_mirroring(ucd, &mut chars)?;
+    load_flags(ucd, &mut chars)?;
+    let classes = shaping::load(
+        ucd,
+        |cp| {
+            CATEGORY.variants[chars[cp].category as usize]
+                .1
+                .first()
+                .copied()
+                .unwrap_or("Cn")
+        },
+        |cp| &blocks[chars[cp].block as usize].name,
+    )?;
+    for (cp, c) in chars.iter_mut().enumerate() {
+        c.use_class = classes.use_classes[cp];
+        c.myanmar_class = classes.myanmar_classes[cp];
+    }
+    // Vowel signs of the Universal Shaping Engine with a canonical
+    // decomposition must be decomposed before shaping.
+    let vowels = ["VAbv", "VBlw", "VPst", "VPre"]
+        .iter()
+        .map(|name| super::props::USE_CLASS.value(name))
+        .collect::<Result<Vec<_>>>()?;
+    let canonical = DECOMPOSITION_TYPE.value("Canonical")?;
+    for c in chars.iter_mut() {
+        if c.decomposition_type == canonical && vowels.contains(&c.use_class) {
+            c.flags |= 128;
+        }
+    }
+    let text = ucd.read("DerivedNormalizationProps.txt")?;
+    let exclusions = ucd::binary(&text, "Full_Composition_Exclusion")?;
+    let emoji_sequences = emoji::load(ucd)?;
+    let unihan = unihan::load(ucd)?;
+    let sources = sources(ucd, version, &classes);
+    Ok(Data {
+        version,
+        blocks,
+        scripts,
+        combining_classes,
+        chars,
+        mappings,
+        exclusions,
+        derived_shaping: classes.derived,
+        emoji_sequences,
+        unihan,
+        sources,
+    })
+}
+
+/// Returns the files from which each property was loaded. Files without a
+/// version in their header are assumed to match the database.
+fn sources(ucd: &Ucd, version: (u8, u8, u8), classes: &shaping::Classes) -> Vec<Source> {
+    let from_ucd = |property, file: &str| Source {
+        property,
+        file: file.to_string(),
+        version: ucd.file_version(file).unwrap_or(version),
+    };
+    let mut sources = vec![
+        from_ucd("General_Category", "UnicodeData.txt"),
+        from_ucd("Canonical_Combining_Class", "UnicodeData.txt"),
+        from_ucd("Bidi_Class", "UnicodeData.txt"),
+        from_ucd("Decomposition_Mapping", "UnicodeData.txt"),
+        from_ucd("Block", "Blocks.txt"),
+        from_ucd("Script", "Scripts.txt"),
+        from_ucd("Property_Value_Aliases", "PropertyValueAliases.txt"),
+        from_ucd("Joining_Type", "ArabicShaping.txt"),
+        from_ucd("Grapheme_Cluster_Break", "GraphemeBreakProperty.txt"),
+        from_ucd("Word_Break", "WordBreakProperty.txt"),
+        from_ucd("Line_Break", "LineBreak.txt"),
+        from_ucd("Bidi_Mirroring_Glyph", "BidiMirroring.txt"),
+        from_ucd("Bidi_Paired_Bracket_Type", "BidiBrackets.txt"),
+        from_ucd("Emoji", "emoji-data.txt"),
+        from_ucd("Extended_Pictographic", "emoji-data.txt"),
+        from_ucd("Emoji_Qualification", "emoji-test.txt"),
+        from_ucd("Default_Ignorable_Code_Point", "DerivedCoreProperties.txt"),
+        from_ucd("Math", "DerivedCoreProperties.txt"),
+        from_ucd("Alphabetic", "DerivedCoreProperties.txt"),
+        from_ucd("Case_Ignorable", "DerivedCoreProperties.txt"),
+        from_ucd("Cased", "DerivedCoreProperties.txt"),
+        from_ucd("Noncharacter_Code_Point", "PropList.txt"),
+        from_ucd("Deprecated", "PropList.txt"),
+        from_ucd("White_Space", "PropList.txt"),
+        from_ucd(
+            "Full_Composition_Exclusion",
+            "DerivedNormalizationProps.txt",
+        ),
+        from_ucd("kTotalStrokes", "Unihan_IRGSources.txt"),
+        from_ucd("kMandarin", "Unihan_Readings.txt"),
+        from_ucd("kSimplifiedVariant", "Unihan_Variants.txt"),
+        from_ucd("kTraditionalVariant", "Unihan_Variants.txt"),
+        Source {
+            property: "USE_Class",
+            file: "UseClass.txt".to_string(),
+            version: classes.use_version,
+        },
+        Source {
+            property: "Myanmar_Class",
+            file: "MyanmarClass.txt".to_string(),
+            version: classes.myanmar_version,
+        },
+    ];
+    if classes.derived != 0 {
+        sources.push(from_ucd("Age", "DerivedAge.txt"));
+        sources.push(from_ucd(
+            "Indic_Syllabic_Category",
+            "IndicSyllabicCategory.txt",
+        ));
+        sources.push(from_ucd(
+            "Indic_Positional_Category",
+            "IndicPositionalCategory.txt",
+        ));
+    }
+    sources
+}
+
+fn load_unicode_data(
+    ucd: &Ucd,
+    chars: &mut [Char],
+    mappings: &mut BTreeMap<usize, Mapping>,
+) -> Result<()> {
+    let text = ucd.read("UnicodeData.txt")?;
+    let mut first = None;
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let fields = line.split(';').collect::<Vec<_>>();
+        if fields.len() < 6 {
+            return Err(format!("invalid line in UnicodeData.txt: `{}`", line));
+        }
+        let cp = ucd::parse_codepoint(fields[0])?;
+        let name = fields[1];
+        let range = if name.ends_with(", First>") {
+            first = Some(cp);
+            continue;
+        } else if name.ends_with(", Last>") {
+            first
+                .take()
+                .ok_or_else(|| format!("unmatched range end at {:04X}", cp))?..=cp
+        } else {
+            cp..=cp
+        };
+        let category = CATEGORY.value(fields[2])?;
+        let combining_class = fields[3]
+            .parse::<u8>()
+            .map_err(|_| format!("invalid combining class for {:04X}", cp))?;
+        let bidi_class = BIDI_CLASS.value(fields[4])?;
+        let decomposition = fields[5].trim();
+        let decomposition_type = if decomposition.is_empty() {
+            DECOMPOSITION_TYPE.value("None")?
+        } else if decomposition.starts_with('<') {
+            let end = decomposition.find('>').unwrap_or(0);
+            DECOMPOSITION_TYPE.value(&decomposition[1..end])?
+        } else {
+            DECOMPOSITION_TYPE.value("Canonical")?
+        };
+        if !decomposition.is_empty() {
+            let compat = decomposition.starts_with('<');
+            let list = match decomposition.find('>') {
+                Some(pos) => &decomposition[pos + 1..],
+                None => decomposition,
+            };
+            let chars = list
+                .split_whitespace()
+                .map(ucd::parse_codepoint)
+                .collect::<Result<Vec<_>>>()?;
+            mappings.insert(cp, Mapping { compat, chars });
+        }
+        for cp in range {
+            let c = &mut chars[cp];
+            c.category = category;
+            c.combining_class = combining_class;
+            c.bidi_class = bidi_class;
+            c.decomposition_type = decomposition_type;
+        }
+    }
+    // Hangul syllables decompose algorithmically and are not listed.
+    let canonical = DECOMPOSITION_TYPE.value("Canonical")?;
+    for c in &mut chars[0xAC00..=0xD7A3] {
+        c.decomposition_type = canonical;
+    }
+    Ok(())
+}
+
+/// Returns the variant names of an enum in a previously generated file.
+fn previous_variants(previous: Option<&str>, name: &str) -> Vec<String> {
+    let text = match previous {
+        Some(text) => text,
+        None => return Vec::new(),
+    };
+    let start = match text.find(&format!("pub enum {} {{\n", name)) {
+        Some(pos) => pos,
+        None => return Vec::new(),
+    };
+    text[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| *line != "}")
+        .filter_map(|line| line.trim().split(" =").next())
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Orders names to preserve the discriminants of previously generated
+/// variants. New names follow in the order given.
+fn stable_order(previous: Vec<String>, mut names: Vec<String>) -> Vec<String> {
+    let mut order = previous;
+    order.retain(|name| names.contains(name));
+    names.retain(|name| !order.contains(name));
+    order.extend(names);
+    order
+}
+
+fn variant_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .collect()
+}
+
+fn load_blocks(ucd: &Ucd, chars: &mut [Char], previous: Option<&str>) -> Result<Vec<Block>> {
+    let text = ucd.read("Blocks.txt")?;
+    let entries = ucd::entries(&text)?;
+    let mut names = entries
+        .iter()
+        .map(|entry| variant_name(entry.fields.first().copied().unwrap_or("")))
+        .collect::<Vec<_>>();
+    names.push(NO_BLOCK.to_string());
+    names.sort();
+    let order = stable_order(previous_variants(previous, "Block"), names);
+    let mut blocks = order
+        .into_iter()
+        .map(|name| Block { name, range: None })
+        .collect::<Vec<_>>();
+    let no_block = blocks
+        .iter()
+        .position(|block| block.name == NO_BLOCK)
+        .unwrap_or(0);
+    for c in chars.iter_mut() {
+        c.block = no_block as u16;
+    }
+    for entry in entries {
+        let name = variant_name(entry.fields.first().copied().unwrap_or(""));
+        let index = blocks
+            .iter()
+            .position(|block| block.name == name)
+            .unwrap_or(0);
+        for c in &mut chars[entry.range.clone()] {
+            c.block = index as u16;
+        }
+        blocks[index].range = Some(entry.range);
+    }
+    Ok(blocks)
+}
+
+fn load_scripts(ucd: &Ucd, chars: &mut [Char], previous: Option<&str>) -> Result<Vec<Script>> {
+    // Long and short names from the property value aliases.
+    let text = ucd.read("PropertyValueAliases.txt")?;
+    let mut aliases = Vec::new();
+    for line in text.lines() {
+        let fields = line.split('#').next().unwrap_or("").split(';');
+        let fields = fields.map(|x| x.trim()).collect::<Vec<_>>();
+        if fields.len() >= 3 && fields[0] == "sc" {
+            aliases.push((fields[1].to_string(), fields[2].to_string()));
+        }
+    }
+    let short_name = |long: &str| {
+        aliases
+            .iter()
+            .find(|(_, name)| name == long)
+            .map(|(short, _)| short.clone())
+            .ok_or_else(|| format!("no alias for script {}", long))
+    };
+    let text = ucd.read("Scripts.txt")?;
+    let entries = ucd::entries(&text)?;
+    let mut used = vec![UNKNOWN_SCRIPT.to_string()];
+    for entry in &entries {
+        let short = short_name(entry.fields.first().copied().unwrap_or(""))?;
+        if !used.contains(&short) {
+            used.push(short);
+        }
+    }
+    used.sort();
+    let long_name = |short: &str| {
+        aliases
+            .iter()
+            .find(|(name, _)| name == short)
+            .map(|(_, long)| long.clone())
+            .ok_or_else(|| format!("no alias for script {}", short))
+    };
+    let names = used
+        .into_iter()
+        .map(|short| Ok((variant_name(&long_name(&short)?), short)))
+        .collect::<Result<Vec<_>>>()?;
+    let order = stable_order(
+        previous_variants(previous, "Script"),
+        names.iter().map(|(name, _)| name.clone()).collect(),
+    );
+    let mut scripts = Vec::new();
+    for name in order {
+        let short = names
+            .iter()
+            .find(|(variant, _)| *variant == name)
+            .map(|(_, short)| short.clone())
+            .unwrap_or_default();
+        let long = long_name(&short)?;
+        let metadata = SCRIPT_METADATA
+            .lines()
+            .map(|line| line.split(';').map(|x| x.trim()).collect::<Vec<_>>())
+            .find(|fields| fields[0] == short)
+            .ok_or_else(|| format!("missing metadata for script {}", short))?;
+        if metadata.len() != 4 {
+            return Err(format!("invalid metadata for script {}", short));
+        }
+        let number = metadata[1]
+            .parse()
+            .map_err(|_| format!("invalid ISO 15924 number for script {}", short))?;
+        let tags = metadata[2]
+            .split_whitespace()
+            .map(tag)
+            .collect::<Result<Vec<_>>>()?;
+        scripts.push(Script {
+            name,
+            long_name: long.replace('_', " "),
+            short_name: short,
+            number,
+            tags,
+            complex: metadata[3] == "Y",
+            rtl: false,
+        });
+    }
+    let unknown = scripts
+        .iter()
+        .position(|script| script.short_name == UNKNOWN_SCRIPT)
+        .unwrap_or(0);
+    for c in chars.iter_mut() {
+        c.script = unknown as u8;
+    }
+    for entry in entries {
+        let short = short_name(entry.fields.first().copied().unwrap_or(""))?;
+        let index = scripts
+            .iter()
+            .position(|script| script.short_name == short)
+            .unwrap_or(0);
+        for c in &mut chars[entry.range] {
+            c.script = index as u8;
+        }
+    }
+    // A script is right-to-left when most of its characters are.
+    let (l, r, al) = (
+        BIDI_CLASS.value("L")?,
+        BIDI_CLASS.value("R")?,
+        BIDI_CLASS.value("AL")?,
+    );
+    let mut counts = vec![(0usize, 0usize); scripts.len()];
+    for c in chars.iter().filter(|c| c.category != 0) {
+        let count = &mut counts[c.script as usize];
+        if c.bidi_class == r || c.bidi_class == al {
+            count.1 += 1;
+        } else if c.bidi_class == l {
+            count.0 += 1;
+        }
+    }
+    for (script, (ltr, rtl)) in scripts.iter_mut().zip(counts) {
+        script.rtl = rtl > ltr;
+    }
+    Ok(scripts)
+}
+
+/// Converts an OpenType tag to an integer, padding it with spaces.
+pub fn tag(s: &str) -> Result<u32> {
+    if s.is_empty() || s.len() > 4 || !s.is_ascii() {
+        return Err(format!("invalid OpenType tag `{}`", s));
+    }
+    let mut bytes = *b"    ";
+    bytes[..s.len()].copy_from_slice(s.as_bytes());
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn load_combining_classes(ucd: &Ucd) -> Result<Vec<(String, u8)>> {
+    let text = ucd.read("PropertyValueAliases.txt")?;
+    let mut classes = Vec::new();
+    for line in text.lines() {
+        let fields = line.split('#').next().unwrap_or("").split(';');
+        let fields = fields.map(|x| x.trim()).collect::<Vec<_>>();
+        if fields.len() >= 4 && fields[0] == "ccc" {
+            let value = fields[1]
+                .parse::<u8>()
+                .map_err(|_| format!("invalid combining class `{}`", fields[1]))?;
+            let mut name = variant_name(fields[3]);
+            // Names of fixed position classes are all capitals, such as
+            // CCC10.
+            if !name.chars().any(|c| c.is_ascii_lowercase()) {
+                name = name[..1].to_string() + &name[1..].to_ascii_lowercase();
+            }
+            classes.push((name, value));
+        }
+    }
+    classes.sort_by_key(|(_, value)| *value);
+    Ok(classes)
+}
+
+fn load_joining_types(ucd: &Ucd, chars: &mut [Char]) -> Result<()> {
+    let transparent = [
+        CATEGORY.value("Mn")?,
+        CATEGORY.value("Me")?,
+        CATEGORY.value("Cf")?,
+    ];
+    let (t, u) = (JOINING_TYPE.value("T")?, JOINING_TYPE.value("U")?);
+    for c in chars.iter_mut() {
+        c.joining_type = if transparent.contains(&c.category) {
+            t
+        } else {
+            u
+        };
+    }
+    let text = ucd.read("ArabicShaping.txt")?;
+    for entry in ucd::entries(&text)? {
+        let value = match entry.fields.get(2).copied() {
+            Some("ALAPH") => "Alaph",
+            Some("DALATH RISH") => "DalathRish",
+            _ => entry.fields.get(1).copied().unwrap_or(""),
+        };
+        let value = JOINING_TYPE.value(value)?;
+        for c in &mut chars[entry.range] {
+            c.joining_type = value;
+        }
+    }
+    Ok(())
+}
+
+fn assign(
+    text: &str,
+    property: &Property,
+    default: &str,
+    chars: &mut [Char],
+    f: impl Fn(&mut Char, u8),
+) -> Result<()> {
+    let values = ucd::property(text, property.value(default)?, |value| {
+        property.value(value)
+    })?;
+    for (c, value) in chars.iter_mut().zip(values) {
+        f(c, value);
+    }
+    Ok(())
+}
+
+fn load_mirroring(ucd: &Ucd, chars: &mut [Char]) -> Result<()> {
+    let text = ucd.read("BidiMirroring.txt")?;
+    for entry in ucd::entries(&text)? {
+        let cp = *entry.range.start();
+        let mirror = ucd::parse_codepoint(entry.fields.first().copied().unwrap_or(""))?;
+        chars[cp].mirror = (mirror as i64 - cp as i64) as i16;
+    }
+    Ok(())
+}
+
+fn load_flags(ucd: &Ucd, chars: &mut [Char]) -> Result<()> {
+    let emoji = ucd.read("emoji-data.txt")?;
+    let core = ucd.read("DerivedCoreProperties.txt")?;
+    let list = ucd.read("PropList.txt")?;
+    let binary = [
+        (1, &emoji, "Emoji"),
+        (2, &emoji, "Extended_Pictographic"),
+        (256, &core, "Default_Ignorable_Code_Point"),
+        (512, &list, "Noncharacter_Code_Point"),
+        (1024, &list, "Deprecated"),
+        (2048, &core, "Math"),
+        (4096, &core, "Alphabetic"),
+        (8192, &core, "Case_Ignorable"),
+        (16384, &core, "Cased"),
+        (32768, &list, "White_Space"),
+    ];
+    for (flag, text, name) in binary.iter() {
+        for (c, value) in chars.iter_mut().zip(ucd::binary(text, name)?) {
+            if value {
+                c.flags |= flag;
+            }
+        }
+    }
+    let text = ucd.read("BidiBrackets.txt")?;
+    for entry in ucd::entries(&text)? {
+        let flag = match entry.fields.get(1).copied() {
+            Some("o") => 4,
+            Some("c") => 8,
+            _ => {
+                return Err(format!(
+                    "invalid bracket type at {:04X}",
+                    entry.range.start()
+                ))
+            }
+        };
+        for c in &mut chars[entry.range] {
+            c.flags |= flag;
+        }
+    }
+    for cp in (0xFE00..=0xFE0F).chain(0xE0100..=0xE01EF) {
+        chars[cp].flags |= 16;
+    }
+    let ignorable = [CATEGORY.value("Cc")?, CATEGORY.value("Cf")?];
+    for (cp, c) in chars.iter_mut().enumerate() {
+        if ignorable.contains(&c.category) {
+            c.flags |= 32;
+        }
+        if !ignorable.contains(&c.category) || cp == 0x200C || cp == 0x200D {
+            c.flags |= 64;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,348 @@
+/*!
+Emission of the generated source file.
+*/
+
+use super::compose::{self, Starters};
+use super::data::{self, Char, Data};
+use super::props::{
+    Property, BIDI_CLASS, CATEGORY, CLUSTER_BREAK, DECOMPOSITION_TYPE, JOINING_TYPE, LINE_BREAK,
+    MYANMAR_CLASS, USE_CLASS, WORD_BREAK,
+};
+use super::tables::{array, Trie, WIDTH};
+use super::unihan;
+use std::collections::HashMap;
+
+const RECORDS_TEMPLATE: &str = include_str!("../templates/records.rs.in");
+const FLAGS_TEMPLATE: &str = include_str!("../templates/flags.rs.in");
+
+/// Maximum line width for the array of records, which are too long to
+/// usefully wrap at the usual width.
+const RECORDS_WIDTH: usize = 239;
+
+pub fn emit(data: &Data) -> String {
+    let mut out = String::new();
+    let (major, minor, micro) = data.version;
+    out.push_str(&format!(
+        "// Automatically generated from the Unicode version {}.{}.{} character database.\n\n",
+        major, minor, micro
+    ));
+    out.push_str("#![allow(dead_code)]\n\n");
+    out.push_str(
+        "/// The version of the Unicode Character Database used to generate character properties.\n",
+    );
+    out.push_str(&format!(
+        "pub const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});\n\n",
+        major, minor, micro
+    ));
+    sources(&mut out, data);
+    property(&mut out, &CATEGORY);
+    let blocks = data.blocks.iter().map(|block| block.name.as_str());
+    enumeration(
+        &mut out,
+        "Block",
+        "Unicode block property.",
+        "u16",
+        blocks.zip(0..),
+    );
+    let scripts = data.scripts.iter().map(|script| script.name.as_str());
+    enumeration(
+        &mut out,
+        "Script",
+        "Unicode script property.",
+        "u8",
+        scripts.zip(0..),
+    );
+    let classes = data
+        .combining_classes
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value as usize));
+    enumeration(
+        &mut out,
+        "CombiningClass",
+        "Canonical combining class.",
+        "u8",
+        classes,
+    );
+    for property in &[
+        BIDI_CLASS,
+        CLUSTER_BREAK,
+        LINE_BREAK,
+        WORD_BREAK,
+        JOINING_TYPE,
+        DECOMPOSITION_TYPE,
+        USE_CLASS,
+        MYANMAR_CLASS,
+    ] {
+        self::property(&mut out, property);
+    }
+    scripts_tables(&mut out, data);
+    let ranges = data.blocks.iter().map(|block| match &block.range {
+        Some(range) => format!("({}, {})", range.start(), range.end()),
+        None => "(1, 0)".to_string(),
+    });
+    array(&mut out, true, "BLOCK_RANGES", "(u32, u32)", ranges, WIDTH);
+    records(&mut out, data);
+    composition(&mut out, data);
+    emoji_sequences(&mut out, data);
+    while out.ends_with("\n\n") {
+        out.pop();
+    }
+    out
+}
+
+fn property(out: &mut String, property: &Property) {
+    let variants = property.variants.iter().map(|(name, _)| *name);
+    enumeration(out, property.name, property.doc, "u8", variants.zip(0..));
+}
+
+fn enumeration<'a>(
+    out: &mut String,
+    name: &str,
+    doc: &str,
+    repr: &str,
+    variants: impl Iterator<Item = (&'a str, usize)>,
+) {
+    out.push_str(&format!("/// {}\n", doc));
+    out.push_str("#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]\n");
+    out.push_str(&format!("#[repr({})]\npub enum {} {{\n", repr, name));
+    for (variant, value) in variants {
+        out.push_str(&format!("    {} = {},\n", variant, value));
+    }
+    out.push_str("}\n\n");
+}
+
+/// Formats a tag as an expression that shows the individual bytes.
+fn tag_expr(tag: u32) -> String {
+    let b = tag.to_be_bytes();
+    format!("({}<<24|{}<<16|{}<<8|{})", b[0], b[1], b[2], b[3])
+}
+
+fn scripts_tables(out: &mut String, data: &Data) {
+    let scripts = &data.scripts;
+    let tags = scripts.iter().map(|script| {
+        let mut tags = script.tags.iter().map(|&t| tag_expr(t));
+        let first = tags.next().unwrap_or_else(|| "0".into());
+        let second = tags.next().unwrap_or_else(|| "0".into());
+        format!("[{}, {}]", first, second)
+    });
+    array(out, true, "SCRIPT_TAGS", "[u32; 2]", tags, WIDTH);
+    // A tag shared by several scripts, such as `kana`, maps to the script
+    // with the matching ISO 15924 code.
+    let mut by_tag = scripts
+        .iter()
+        .flat_map(|script| {
+            let code = data::tag(&script.short_name.to_ascii_lowercase()).unwrap_or(0);
+            script
+                .tags
+                .iter()
+                .map(move |&t| (t, t != code, &script.name))
+        })
+        .collect::<Vec<_>>();
+    by_tag.sort();
+    by_tag.dedup_by_key(|(t, _, _)| *t);
+    let by_tag = by_tag
+        .into_iter()
+        .map(|(t, _, name)| format!("({}, Script::{})", tag_expr(t), name));
+    array(out, true, "SCRIPTS_BY_TAG", "(u32, Script)", by_tag, WIDTH);
+    let names = scripts.iter().map(|s| format!("\"{}\"", s.long_name));
+    array(out, true, "SCRIPT_NAMES", "&str", names, WIDTH);
+    let names = scripts.iter().map(|s| format!("\"{}\"", s.short_name));
+    array(out, true, "SCRIPT_SHORT_NAMES", "&str", names, WIDTH);
+    let numbers = scripts.iter().map(|s| s.number);
+    array(out, true, "SCRIPT_ISO15924_NUMBERS", "u16", numbers, WIDTH);
+    let complex = scripts.iter().map(|s| s.complex);
+    array(out, true, "SCRIPT_COMPLEXITY", "bool", complex, WIDTH);
+    let rtl = scripts.iter().map(|s| s.rtl);
+    array(out, true, "SCRIPT_RTL", "bool", rtl, WIDTH);
+}
+
+fn records(out: &mut String, data: &Data) {
+    // Records are numbered in order of first appearance.
+    let mut numbers = HashMap::new();
+    let mut records = Vec::new();
+    let mut firsts = Vec::new();
+    let indices = data
+        .chars
+        .iter()
+        .enumerate()
+        .map(|(cp, c)| {
+            *numbers.entry(*c).or_insert_with(|| {
+                records.push(*c);
+                firsts.push(cp);
+                records.len() as u32 - 1
+            })
+        })
+        .collect::<Vec<_>>();
+    let trie = Trie::overlapping(&indices, 5, 3);
+    trie.emit(out, "RECORD_INDEX", "");
+    // The bit set of uniform blocks covers at least the range of a byte.
+    let blocks = (trie.index1.len() >> trie.shift1).max(256);
+    // usize::div_ceil requires Rust 1.73.
+    #[allow(clippy::manual_div_ceil)]
+    let words = (blocks + 31) / 32;
+    out.push_str(&RECORDS_TEMPLATE.replace("{uniform_words}", &words.to_string()));
+    out.push('\n');
+    let mut mirrors = records.iter().map(|c| c.mirror).collect::<Vec<_>>();
+    mirrors.sort_by_key(|&m| (m.abs(), m < 0));
+    mirrors.dedup();
+    out.push_str("/// Distinct mirroring offsets, referenced by index from the records.\n");
+    out.push_str("#[cfg(feature = \"bidi\")]\n");
+    array(out, false, "MIRROR_OFFSETS", "i16", mirrors.iter(), WIDTH);
+    out.push_str(FLAGS_TEMPLATE);
+    out.push('\n');
+    let records = records.iter().map(|c| record(data, c));
+    array(out, true, "RECORDS", "Record", records, RECORDS_WIDTH);
+    out.push_str("/// First code point with each record.\n");
+    out.push_str("#[cfg(feature = \"serde\")]\n");
+    array(out, true, "RECORD_CODEPOINTS", "u32", firsts.iter(), WIDTH);
+}
+
+fn record(data: &Data, c: &Char) -> String {
+    format!(
+        "r({},C::{},B::{},S::{},{},Bc::{},Jt::{},Cb::{},Wb::{},Lb::{},Uc::{},Mc::{},Dt::{},{})",
+        c.flags,
+        CATEGORY.name(c.category),
+        data.blocks[c.block as usize].name,
+        data.scripts[c.script as usize].name,
+        c.combining_class,
+        BIDI_CLASS.name(c.bidi_class),
+        JOINING_TYPE.name(c.joining_type),
+        CLUSTER_BREAK.name(c.cluster_break),
+        WORD_BREAK.name(c.word_break),
+        LINE_BREAK.name(c.line_break),
+        USE_CLASS.name(c.use_class),
+        MYANMAR_CLASS.name(c.myanmar_class),
+        DECOMPOSITION_TYPE.name(c.decomposition_type),
+        c.mirror
+    )
+}
+
+fn composition(out: &mut String, data: &Data) {
+    let composition = compose::composition(data);
+    out.push_str(&format!(
+        "pub const COMPOSE0_COUNT: usize = {};\n",
+        composition.first.count
+    ));
+    out.push_str(&format!(
+        "pub const COMPOSE1_COUNT: usize = {};\n\n",
+        composition.second.count
+    ));
+    starters(out, "COMPOSE0", &composition.first);
+    starters(out, "COMPOSE1", &composition.second);
+    composition.trie.emit(out, "COMPOSE_INDEX", "compose_index");
+    let (canonical, compat) = compose::decomposition(data);
+    canonical
+        .trie
+        .emit(out, "DECOMPOSE_INDEX", "decompose_index");
+    compat
+        .trie
+        .emit(out, "DECOMPOSE_COMPAT_INDEX", "decompose_compat_index");
+    array(out, true, "DECOMPOSE", "u32", canonical.list.iter(), WIDTH);
+    array(
+        out,
+        true,
+        "DECOMPOSE_COMPAT",
+        "u32",
+        compat.list.iter(),
+        WIDTH,
+    );
+}
+
+fn starters(out: &mut String, name: &str, starters: &Starters) {
+    let ranges = starters
+        .ranges
+        .iter()
+        .map(|(start, len, index)| format!("({},{},{})", start, len, index));
+    array(out, true, name, "(u32, u16, u16)", ranges, WIDTH);
+}
+
+/// Returns the source of the module for the Unihan subset.
+pub fn emit_unihan(data: &Data) -> String {
+    let mut out = String::new();
+    let (major, minor, micro) = data.version;
+    out.push_str(&format!(
+        "// Automatically generated from the Unicode version {}.{}.{} Unihan database.\n\n",
+        major, minor, micro
+    ));
+    out.push_str(&format!(
+        "/// Flag in the value of a character that indicates a Mandarin reading.\n\
+         pub const MANDARIN: u8 = {};\n\n",
+        unihan::MANDARIN
+    ));
+    let unihan = &data.unihan;
+    // Code points following the last ideograph are outside of the table.
+    let len = unihan
+        .values
+        .iter()
+        .rposition(|&v| v != 0)
+        .map_or(1, |i| i + 1);
+    let values = unihan.values[..len]
+        .iter()
+        .map(|&v| v as u32)
+        .collect::<Vec<_>>();
+    Trie::new(&values, 5, 4).emit(&mut out, "UNIHAN", "unihan_value");
+    out.push('\n');
+    for (name, pairs) in &[
+        ("SIMPLIFIED_VARIANTS", &unihan.simplified),
+        ("TRADITIONAL_VARIANTS", &unihan.traditional),
+    ] {
+        let pairs = pairs.iter().map(|(cp, v)| format!("({},{})", cp, v));
+        array(&mut out, true, name, "(u32, u32)", pairs, WIDTH);
+    }
+    while out.ends_with("\n\n") {
+        out.pop();
+    }
+    out
+}
+
+/// Emits the emoji sequences as a list of code points along with the
+/// offset, length and qualification status of each sequence.
+fn emoji_sequences(out: &mut String, data: &Data) {
+    let mut chars = Vec::new();
+    let mut sequences = Vec::new();
+    for sequence in &data.emoji_sequences {
+        sequences.push(format!(
+            "({},{},{})",
+            chars.len(),
+            sequence.chars.len(),
+            sequence.status
+        ));
+        chars.extend_from_slice(&sequence.chars);
+    }
+    out.push_str("#[cfg(feature = \"emoji-sequences\")]\n");
+    array(
+        out,
+        true,
+        "EMOJI_SEQUENCE_CHARS",
+        "u32",
+        chars.iter(),
+        WIDTH,
+    );
+    out.push_str("#[cfg(feature = \"emoji-sequences\")]\n");
+    array(
+        out,
+        true,
+        "EMOJI_SEQUENCES",
+        "(u16, u8, u8)",
+        sequences.into_iter(),
+        WIDTH,
+    );
+}
+
+/// Emits the property, file and file version for each source of the data.
+fn sources(out: &mut String, data: &Data) {
+    out.push_str("/// Source files of the character properties along with their versions.\n");
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str(&format!(
+        "pub const DATA_SOURCES: [(&str, &str, (u8, u8, u8)); {}] = [\n",
+        data.sources.len()
+    ));
+    for source in &data.sources {
+        let (major, minor, micro) = source.version;
+        out.push_str(&format!(
+            "    ({:?}, {:?}, ({}, {}, {})),\n",
+            source.property, source.file, major, minor, micro
+        ));
+    }
+    out.push_str("];\n\n");
+}
@@ -0,0 +1,108 @@
+/*!
+Emoji sequences and their qualification status from `emoji-test.txt`.
+*/
+
+use super::ucd::{self, Ucd};
+use super::Result;
+
+const PRESENTATION_SELECTOR: usize = 0xFE0F;
+
+/// Qualification status values in the order of the crate enum.
+const STATUS: [&str; 4] = [
+    "component",
+    "fully-qualified",
+    "minimally-qualified",
+    "unqualified",
+];
+
+/// Emoji sequence listed in the test data.
+pub struct Sequence {
+    pub chars: Vec<usize>,
+    pub status: u8,
+}
+
+impl Sequence {
+    /// Returns the characters of the sequence without presentation
+    /// selectors, which groups the qualified and unqualified forms of an
+    /// emoji.
+    pub fn key(&self) -> impl Iterator<Item = usize> + '_ {
+        self.chars
+            .iter()
+            .copied()
+            .filter(|&cp| cp != PRESENTATION_SELECTOR)
+    }
+}
+
+/// Loads the sequences sorted by key and then by status, so that the fully
+/// qualified form precedes the other forms of the same emoji.
+pub fn load(ucd: &Ucd) -> Result<Vec<Sequence>> {
+    let text = ucd.read("emoji-test.txt")?;
+    let mut sequences = Vec::new();
+    let mut expected = [None; STATUS.len()];
+    for line in text.lines() {
+        if let Some((status, count)) = status_count(line) {
+            expected[status] = Some(count);
+            continue;
+        }
+        let line = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => line,
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut fields = line.split(';').map(|field| field.trim());
+        let chars = fields
+            .next()
+            .unwrap_or("")
+            .split_whitespace()
+            .map(ucd::parse_codepoint)
+            .collect::<Result<Vec<_>>>()?;
+        let status = fields.next().unwrap_or("");
+        let status = STATUS
+            .iter()
+            .position(|&name| name == status)
+            .ok_or_else(|| format!("unsupported emoji status `{}`", status))?;
+        if chars.is_empty() || chars.len() > u8::MAX as usize {
+            return Err(format!("invalid emoji sequence `{}`", line));
+        }
+        sequences.push(Sequence {
+            chars,
+            status: status as u8,
+        });
+    }
+    // The file ends with the number of entries for each status, which
+    // guards against generating tables from a truncated copy.
+    for (status, &expected) in STATUS.iter().zip(&expected) {
+        let count = sequences
+            .iter()
+            .filter(|s| STATUS[s.status as usize] == *status)
+            .count();
+        match expected {
+            Some(expected) if expected == count => {}
+            Some(expected) => {
+                return Err(format!(
+                    "emoji-test.txt lists {} {} sequences but states {}",
+                    count, status, expected
+                ))
+            }
+            None => return Err(format!("emoji-test.txt lacks the {} count", status)),
+        }
+    }
+    // Sequences are referenced by 16-bit offsets into the list of code
+    // points.
+    if sequences.iter().map(|s| s.chars.len()).sum::<usize>() > u16::MAX as usize {
+        return Err("too many emoji sequences".into());
+    }
+    sequences.sort_by(|a, b| a.key().cmp(b.key()).then(a.status.cmp(&b.status)));
+    Ok(sequences)
+}
+
+/// Parses a line of the status counts at the end of the file, such as
+/// `# fully-qualified : 3773`.
+fn status_count(line: &str) -> Option<(usize, usize)> {
+    let mut fields = line.strip_prefix('#')?.split(':').map(|field| field.trim());
+    let name = fields.next()?;
+    let status = STATUS.iter().position(|&s| s == name)?;
+    Some((status, fields.next()?.parse().ok()?))
+}
@@ -0,0 +1,79 @@
+/*!
+Generator for the Unicode data tables of lipi.
+
+This reads a local copy of the Unicode Character Database and writes the
+`src/unicode_data.rs` module of the crate along with the Unihan subset in
+`src/unihan_data.rs`, which is written next to the output file:
+
+```text
+cargo run -p lipi-gen -- path/to/ucd [output]
+```
+
+The directory should contain the contents of `UCD.zip` for the desired
+version along with the `emoji-data.txt` and `emoji-test.txt` files, which
+may be placed in an `emoji` subdirectory, and the contents of `Unihan.zip`,
+which may be placed in a `unihan` subdirectory. Classes for the Universal Shaping Engine and the
+Myanmar shaping model are not part of the database; see the `shaping`
+module for how they are produced.
+
+Variants of the block and script enums keep the discriminants assigned in
+the existing output file, or in the module of the crate when writing to a
+new file, so that regenerating the tables only appends new
+values.
+*/
+
+mod compose;
+mod data;
+mod emit;
+mod emoji;
+mod props;
+mod shaping;
+mod tables;
+mod ucd;
+mod unihan;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+type Result<T> = std::result::Result<T, String>;
+
+const DEFAULT_OUTPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/unicode_data.rs");
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.is_empty() || args.len() > 2 || args[0].starts_with('-') {
+        return Err("usage: lipi-gen <ucd-dir> [output]".into());
+    }
+    let output = args.get(1).map(|s| s.as_str()).unwrap_or(DEFAULT_OUTPUT);
+    let previous = fs::read_to_string(output)
+        .or_else(|_| fs::read_to_string(DEFAULT_OUTPUT))
+        .ok();
+    let ucd = ucd::Ucd::new(&args[0]);
+    let data = data::load(&ucd, previous.as_deref())?;
+    let source = emit::emit(&data);
+    fs::write(output, source).map_err(|e| format!("{}: {}", output, e))?;
+    let unihan_output = Path::new(output).with_file_name("unihan_data.rs");
+    fs::write(&unihan_output, emit::emit_unihan(&data))
+        .map_err(|e| format!("{}: {}", unihan_output.display(), e))?;
+    let (major, minor, micro) = data.version;
+    println!(
+        "generated tables for Unicode {}.{}.{} in {}",
+        major, minor, micro, output
+    );
+    if data.derived_shaping != 0 {
+        println!(
+            "derived shaping classes for {} characters newer than the baseline",
+            data.derived_shaping
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,327 @@
+/*!
+Enumerated properties with a fixed set of values.
+
+The crate depends on the discriminants of these enums, for example to index
+the pair table of the line breaking algorithm, so values are never derived
+from the data files. A value that is not listed here is reported as an
+error and requires a corresponding change to the crate.
+*/
+
+use super::Result;
+
+/// Enumerated property emitted as an enum with variants in the order
+/// listed.
+pub struct Property {
+    pub name: &'static str,
+    pub doc: &'static str,
+    /// Variant names with the aliases used for them in the database.
+    pub variants: &'static [(&'static str, &'static [&'static str])],
+}
+
+impl Property {
+    /// Returns the discriminant for a value in the database.
+    pub fn value(&self, value: &str) -> Result<u8> {
+        self.variants
+            .iter()
+            .position(|(name, aliases)| *name == value || aliases.contains(&value))
+            .map(|index| index as u8)
+            .ok_or_else(|| format!("unsupported value `{}` for {}", value, self.name))
+    }
+
+    /// Returns the variant name for a discriminant.
+    pub fn name(&self, value: u8) -> &'static str {
+        self.variants[value as usize].0
+    }
+}
+
+pub const CATEGORY: Property = Property {
+    name: "Category",
+    doc: "Unicode general category property.",
+    variants: &[
+        ("Other", &["Cn"]),
+        ("Control", &["Cc"]),
+        ("Format", &["Cf"]),
+        ("Unassigned", &[]),
+        ("PrivateUse", &["Co"]),
+        ("Surrogate", &["Cs"]),
+        ("Letter", &[]),
+        ("CasedLetter", &[]),
+        ("LowercaseLetter", &["Ll"]),
+        ("ModifierLetter", &["Lm"]),
+        ("OtherLetter", &["Lo"]),
+        ("TitlecaseLetter", &["Lt"]),
+        ("UppercaseLetter", &["Lu"]),
+        ("Mark", &[]),
+        ("SpacingMark", &["Mc"]),
+        ("EnclosingMark", &["Me"]),
+        ("NonspacingMark", &["Mn"]),
+        ("Number", &[]),
+        ("DecimalNumber", &["Nd"]),
+        ("LetterNumber", &["Nl"]),
+        ("OtherNumber", &["No"]),
+        ("Punctuation", &[]),
+        ("ConnectorPunctuation", &["Pc"]),
+        ("DashPunctuation", &["Pd"]),
+        ("ClosePunctuation", &["Pe"]),
+        ("FinalPunctuation", &["Pf"]),
+        ("InitialPunctuation", &["Pi"]),
+        ("OtherPunctuation", &["Po"]),
+        ("OpenPunctuation", &["Ps"]),
+        ("Symbol", &[]),
+        ("CurrencySymbol", &["Sc"]),
+        ("ModifierSymbol", &["Sk"]),
+        ("MathSymbol", &["Sm"]),
+        ("OtherSymbol", &["So"]),
+        ("Separator", &[]),
+        ("LineSeparator", &["Zl"]),
+        ("ParagraphSeparator", &["Zp"]),
+        ("SpaceSeparator", &["Zs"]),
+    ],
+};
+
+pub const BIDI_CLASS: Property = Property {
+    name: "BidiClass",
+    doc: "Property for the bidirectional algorithm.",
+    variants: &[
+        ("AL", &[]),
+        ("AN", &[]),
+        ("B", &[]),
+        ("BN", &[]),
+        ("CS", &[]),
+        ("EN", &[]),
+        ("ES", &[]),
+        ("ET", &[]),
+        ("FSI", &[]),
+        ("L", &[]),
+        ("LRE", &[]),
+        ("LRI", &[]),
+        ("LRO", &[]),
+        ("NSM", &[]),
+        ("ON", &[]),
+        ("PDF", &[]),
+        ("PDI", &[]),
+        ("R", &[]),
+        ("RLE", &[]),
+        ("RLI", &[]),
+        ("RLO", &[]),
+        ("S", &[]),
+        ("WS", &[]),
+    ],
+};
+
+pub const CLUSTER_BREAK: Property = Property {
+    name: "ClusterBreak",
+    doc: "Property for the cluster breaking algorithm.",
+    variants: &[
+        ("CN", &["Control"]),
+        ("CR", &[]),
+        ("EX", &["Extend"]),
+        ("L", &[]),
+        ("LF", &[]),
+        ("LV", &[]),
+        ("LVT", &[]),
+        ("PP", &["Prepend"]),
+        ("RI", &["Regional_Indicator"]),
+        ("SM", &["SpacingMark"]),
+        ("T", &[]),
+        ("V", &[]),
+        ("XX", &["Other"]),
+        ("ZWJ", &[]),
+    ],
+};
+
+pub const LINE_BREAK: Property = Property {
+    name: "LineBreak",
+    doc: "Property for the line breaking algorithm.",
+    variants: &[
+        ("AI", &[]),
+        ("AL", &[]),
+        ("B2", &[]),
+        ("BA", &[]),
+        ("BB", &[]),
+        ("BK", &[]),
+        ("CB", &[]),
+        ("CJ", &[]),
+        ("CL", &[]),
+        ("CM", &[]),
+        ("CP", &[]),
+        ("CR", &[]),
+        ("EB", &[]),
+        ("EM", &[]),
+        ("EX", &[]),
+        ("GL", &[]),
+        ("H2", &[]),
+        ("H3", &[]),
+        ("HL", &[]),
+        ("HY", &[]),
+        ("ID", &[]),
+        ("IN", &[]),
+        ("IS", &[]),
+        ("JL", &[]),
+        ("JT", &[]),
+        ("JV", &[]),
+        ("LF", &[]),
+        ("NL", &[]),
+        ("NS", &[]),
+        ("NU", &[]),
+        ("OP", &[]),
+        ("PO", &[]),
+        ("PR", &[]),
+        ("QU", &[]),
+        ("RI", &[]),
+        ("SA", &[]),
+        ("SG", &[]),
+        ("SP", &[]),
+        ("SY", &[]),
+        ("WJ", &[]),
+        ("XX", &[]),
+        ("ZW", &[]),
+        ("ZWJ", &[]),
+    ],
+};
+
+pub const WORD_BREAK: Property = Property {
+    name: "WordBreak",
+    doc: "Property for the word breaking algorithm.",
+    variants: &[
+        ("CR", &[]),
+        ("DQ", &["Double_Quote"]),
+        ("EX", &["ExtendNumLet"]),
+        ("Extend", &[]),
+        ("FO", &["Format"]),
+        ("HL", &["Hebrew_Letter"]),
+        ("KA", &["Katakana"]),
+        ("LE", &["ALetter"]),
+        ("LF", &[]),
+        ("MB", &["MidNumLet"]),
+        ("ML", &["MidLetter"]),
+        ("MN", &["MidNum"]),
+        ("NL", &["Newline"]),
+        ("NU", &["Numeric"]),
+        ("RI", &["Regional_Indicator"]),
+        ("SQ", &["Single_Quote"]),
+        ("WSegSpace", &[]),
+        ("XX", &["Other"]),
+        ("ZWJ", &[]),
+    ],
+};
+
+/// Join causing characters are treated as dual joining and the Syriac
+/// `Alaph` and `DalathRish` joining groups are distinguished from other
+/// right joining characters.
+pub const JOINING_TYPE: Property = Property {
+    name: "JoiningType",
+    doc: "Joining type property.",
+    variants: &[
+        ("U", &[]),
+        ("L", &[]),
+        ("R", &[]),
+        ("D", &["C"]),
+        ("Alaph", &[]),
+        ("DalathRish", &[]),
+        ("T", &[]),
+    ],
+};
+
+pub const DECOMPOSITION_TYPE: Property = Property {
+    name: "DecompositionType",
+    doc: "Decomposition type property.",
+    variants: &[
+        ("None", &[]),
+        ("Canonical", &[]),
+        ("Compat", &["compat"]),
+        ("Circle", &["circle"]),
+        ("Final", &["final"]),
+        ("Font", &["font"]),
+        ("Fraction", &["fraction"]),
+        ("Initial", &["initial"]),
+        ("Isolated", &["isolated"]),
+        ("Medial", &["medial"]),
+        ("Narrow", &["narrow"]),
+        ("NoBreak", &["noBreak"]),
+        ("Small", &["small"]),
+        ("Square", &["square"]),
+        ("Sub", &["sub"]),
+        ("Super", &["super"]),
+        ("Vertical", &["vertical"]),
+        ("Wide", &["wide"]),
+    ],
+};
+
+pub const USE_CLASS: Property = Property {
+    name: "UseClass",
+    doc: "Character class for the Universal Shaping Engine.",
+    variants: &[
+        ("B", &[]),
+        ("CGJ", &[]),
+        ("CMAbv", &[]),
+        ("CMBlw", &[]),
+        ("CS", &[]),
+        ("FAbv", &[]),
+        ("FBlw", &[]),
+        ("FPst", &[]),
+        ("FM", &[]),
+        ("GB", &[]),
+        ("H", &[]),
+        ("HN", &[]),
+        ("IND", &[]),
+        ("MAbv", &[]),
+        ("MBlw", &[]),
+        ("MPre", &[]),
+        ("MPst", &[]),
+        ("N", &[]),
+        ("O", &[]),
+        ("R", &[]),
+        ("Rsv", &[]),
+        ("S", &[]),
+        ("SMAbv", &[]),
+        ("SMBlw", &[]),
+        ("SUB", &[]),
+        ("VAbv", &[]),
+        ("VBlw", &[]),
+        ("VPre", &[]),
+        ("VPst", &[]),
+        ("VMAbv", &[]),
+        ("VMBlw", &[]),
+        ("VMPre", &[]),
+        ("VMPst", &[]),
+        ("VS", &[]),
+        ("WJ", &[]),
+        ("ZWJ", &[]),
+        ("ZWNJ", &[]),
+    ],
+};
+
+pub const MYANMAR_CLASS: Property = Property {
+    name: "MyanmarClass",
+    doc: "Character class for the Myanmar shaping model.",
+    variants: &[
+        ("A", &[]),
+        ("As", &[]),
+        ("C", &[]),
+        ("D", &[]),
+        ("D0", &[]),
+        ("DB", &[]),
+        ("GB", &[]),
+        ("H", &[]),
+        ("IV", &[]),
+        ("J", &[]),
+        ("K", &[]),
+        ("MH", &[]),
+        ("MR", &[]),
+        ("MW", &[]),
+        ("MY", &[]),
+        ("O", &[]),
+        ("P", &[]),
+        ("PT", &[]),
+        ("R", &[]),
+        ("S", &[]),
+        ("V", &[]),
+        ("VAbv", &[]),
+        ("VBlw", &[]),
+        ("VPre", &[]),
+        ("VPst", &[]),
+        ("VS", &[]),
+        ("WJ", &[]),
+    ],
+};
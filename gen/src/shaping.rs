@@ -0,0 +1,190 @@
+/*!
+Classes for the Universal Shaping Engine and the Myanmar shaping model.
+
+These are not properties in the Unicode Character Database. The classes of
+the characters in the baseline version come from the files in the `data`
+directory. Classes for characters assigned in later versions are derived
+from the Indic syllabic and positional categories following the rules of
+the Universal Shaping Engine specification as implemented by HarfBuzz.
+*/
+
+use super::props::{Property, MYANMAR_CLASS, USE_CLASS};
+use super::ucd::{self, Ucd, CODEPOINTS};
+use super::Result;
+
+const USE_BASELINE: &str = include_str!("../data/UseClass.txt");
+const MYANMAR_BASELINE: &str = include_str!("../data/MyanmarClass.txt");
+
+/// Shaping classes for every code point.
+pub struct Classes {
+    pub use_classes: Vec<u8>,
+    pub myanmar_classes: Vec<u8>,
+    /// Number of characters with derived rather than baseline classes.
+    pub derived: usize,
+    /// Versions declared by the baseline files for each model.
+    pub use_version: (u8, u8, u8),
+    pub myanmar_version: (u8, u8, u8),
+}
+
+/// Properties of a character that are inputs to the derivation of its
+/// shaping classes.
+struct Input<'a> {
+    cp: usize,
+    category: &'a str,
+    block: &'a str,
+    syllabic: &'a str,
+    positional: &'a str,
+}
+
+/// Loads the baseline classes and derives the classes of characters that
+/// were assigned in later versions. The category and block for each code
+/// point are given as database values and names.
+pub fn load<'a>(
+    ucd: &Ucd,
+    category: impl Fn(usize) -> &'a str,
+    block: impl Fn(usize) -> &'a str,
+) -> Result<Classes> {
+    let (use_version, mut use_classes) = baseline(USE_BASELINE, &USE_CLASS)?;
+    let (myanmar_version, mut myanmar_classes) = baseline(MYANMAR_BASELINE, &MYANMAR_CLASS)?;
+    let version = use_version.max(myanmar_version);
+    let age = ucd.read("DerivedAge.txt")?;
+    let syllabic_text = ucd.read("IndicSyllabicCategory.txt")?;
+    let syllabic = ucd::property(&syllabic_text, "Other", Ok)?;
+    let positional_text = ucd.read("IndicPositionalCategory.txt")?;
+    let positional = ucd::property(&positional_text, "Not_Applicable", Ok)?;
+    let mut derived = 0;
+    for entry in ucd::entries(&age)? {
+        let age = entry.fields.first().copied().unwrap_or("");
+        let age = ucd::parse_version(age).ok_or_else(|| format!("invalid age `{}`", age))?;
+        if age <= version {
+            continue;
+        }
+        for cp in entry.range {
+            let input = Input {
+                cp,
+                category: category(cp),
+                block: block(cp),
+                syllabic: syllabic[cp],
+                positional: positional[cp],
+            };
+            use_classes[cp] = USE_CLASS.value(derive_use(&input))?;
+            myanmar_classes[cp] = MYANMAR_CLASS.value(derive_myanmar(&input))?;
+            derived += 1;
+        }
+    }
+    Ok(Classes {
+        use_classes,
+        myanmar_classes,
+        derived,
+        use_version,
+        myanmar_version,
+    })
+}
+
+/// Parses a file of baseline classes, returning the version declared by
+/// its `@baseline` line along with the class of every code point.
+fn baseline(text: &str, property: &Property) -> Result<((u8, u8, u8), Vec<u8>)> {
+    let version = text
+        .lines()
+        .find_map(|line| line.strip_prefix("# @baseline:"))
+        .and_then(ucd::parse_version)
+        .ok_or_else(|| format!("missing baseline version for {}", property.name))?;
+    let classes = ucd::property(text, property.value("O")?, |value| property.value(value))?;
+    debug_assert_eq!(classes.len(), CODEPOINTS);
+    Ok((version, classes))
+}
+
+fn derive_use(c: &Input) -> &'static str {
+    let (cp, gc) = (c.cp, c.category);
+    let lo = gc == "Lo";
+    let base = match c.syllabic {
+        _ if cp == 0x034F => "CGJ",
+        _ if cp == 0x2060 => "WJ",
+        _ if (0xFE00..=0xFE0F).contains(&cp) => "VS",
+        "Number" | "Consonant" | "Consonant_Head_Letter" | "Tone_Letter" | "Vowel_Independent" => {
+            "B"
+        }
+        "Avagraha"
+        | "Bindu"
+        | "Consonant_Final"
+        | "Consonant_Medial"
+        | "Consonant_Subjoined"
+        | "Vowel"
+        | "Vowel_Dependent"
+            if lo =>
+        {
+            "B"
+        }
+        "Consonant_Dead" | "Modifying_Letter" => "IND",
+        "Brahmi_Joining_Number" => "N",
+        "Consonant_Placeholder" => "GB",
+        "Consonant_Final" | "Consonant_Succeeding_Repha" => "F",
+        "Syllable_Modifier" => "FM",
+        "Consonant_Medial" | "Consonant_Initial_Postfixed" => "M",
+        "Nukta" | "Gemination_Mark" | "Consonant_Killer" => "CM",
+        "Consonant_Subjoined" => "SUB",
+        "Consonant_With_Stacker" => "CS",
+        "Virama" | "Invisible_Stacker" => "H",
+        "Number_Joiner" => "HN",
+        "Non_Joiner" => "ZWNJ",
+        "Joiner" => "ZWJ",
+        "Consonant_Preceding_Repha" | "Consonant_Prefixed" => "R",
+        "Pure_Killer" | "Reordering_Killer" | "Vowel" | "Vowel_Dependent" => "V",
+        "Tone_Mark" | "Cantillation_Mark" | "Register_Shifter" | "Visarga" | "Bindu" => "VM",
+        _ => match gc {
+            "Po" => "IND",
+            "So" | "Sc" if cp != 0x25CC => "S",
+            _ if [0x2015, 0x2022, 0x25FB, 0x25FC, 0x25FD, 0x25FE].contains(&cp) => "GB",
+            _ => "O",
+        },
+    };
+    let (above, below, post, pre) = match base {
+        "F" => ("FAbv", "FBlw", "FPst", "FPst"),
+        "M" => ("MAbv", "MBlw", "MPst", "MPre"),
+        "CM" => ("CMAbv", "CMBlw", "CMAbv", "CMAbv"),
+        "SM" => ("SMAbv", "SMBlw", "SMAbv", "SMAbv"),
+        "V" => ("VAbv", "VBlw", "VPst", "VPre"),
+        "VM" => ("VMAbv", "VMBlw", "VMPst", "VMPre"),
+        _ => return base,
+    };
+    match c.positional {
+        "Top" | "Top_And_Bottom" | "Top_And_Bottom_And_Right" | "Top_And_Right" => above,
+        "Bottom" | "Bottom_And_Left" | "Bottom_And_Right" | "Overstruck" => below,
+        "Right" | "Top_And_Left" | "Top_And_Left_And_Right" | "Left_And_Right" => post,
+        "Left" | "Visual_Order_Left" => pre,
+        // Spacing marks without a position follow the base.
+        _ if gc == "Mc" => post,
+        _ => above,
+    }
+}
+
+fn derive_myanmar(c: &Input) -> &'static str {
+    if !c.block.starts_with("Myanmar") {
+        return "O";
+    }
+    match c.syllabic {
+        "Consonant" | "Consonant_Placeholder" => "C",
+        "Vowel_Independent" => "IV",
+        "Vowel_Dependent" => match c.positional {
+            "Left" | "Visual_Order_Left" => "VPre",
+            "Top" | "Top_And_Left" => "VAbv",
+            "Bottom" | "Top_And_Bottom" => "VBlw",
+            _ => "VPst",
+        },
+        "Consonant_Medial" => match c.positional {
+            "Left" => "MR",
+            "Right" => "MY",
+            _ => "MW",
+        },
+        "Number" => "D",
+        "Visarga" | "Tone_Mark" => "V",
+        "Bindu" => "A",
+        "Pure_Killer" => "As",
+        "Virama" | "Invisible_Stacker" => "H",
+        "Joiner" | "Non_Joiner" => "J",
+        _ => match c.category {
+            "Po" | "So" => "S",
+            _ => "O",
+        },
+    }
+}
@@ -0,0 +1,189 @@
+/*!
+Construction and formatting of lookup tables.
+*/
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Maximum line width for arrays in the generated source.
+pub const WIDTH: usize = 99;
+
+/// Three level lookup table mapping keys to values.
+///
+/// The first level stores block numbers of the middle level, which stores
+/// block numbers of the last level. Identical blocks are shared.
+pub struct Trie {
+    pub shift1: usize,
+    pub shift2: usize,
+    pub len: usize,
+    pub index0: Vec<u32>,
+    pub index1: Vec<u32>,
+    pub index2: Vec<u32>,
+    /// The middle level stores offsets into the last level, where blocks
+    /// may overlap, rather than block numbers.
+    pub overlapping: bool,
+}
+
+impl Trie {
+    pub fn new(values: &[u32], shift1: usize, shift2: usize) -> Self {
+        let (leaves, index2) = dedup(values.chunks(1 << shift2));
+        let (mids, index1) = dedup(leaves.chunks(1 << shift1));
+        Self {
+            shift1,
+            shift2,
+            len: values.len(),
+            index0: mids,
+            index1,
+            index2,
+            overlapping: false,
+        }
+    }
+
+    /// Creates a trie where blocks of the last level are packed by reusing
+    /// any existing occurrence of a block or by overlapping it with the end
+    /// of the previous one.
+    pub fn overlapping(values: &[u32], shift1: usize, shift2: usize) -> Self {
+        let mut index2: Vec<u32> = Vec::new();
+        let mut offsets = HashMap::new();
+        let mut leaves = Vec::new();
+        for leaf in values.chunks(1 << shift2) {
+            if let Some(&offset) = offsets.get(leaf) {
+                leaves.push(offset);
+                continue;
+            }
+            let offset = match index2.windows(leaf.len()).position(|w| w == leaf) {
+                Some(offset) => offset,
+                None => {
+                    let overlap = (1..leaf.len())
+                        .rev()
+                        .find(|&n| index2.ends_with(&leaf[..n]))
+                        .unwrap_or(0);
+                    let offset = index2.len() - overlap;
+                    index2.extend_from_slice(&leaf[overlap..]);
+                    offset
+                }
+            };
+            offsets.insert(leaf, offset as u32);
+            leaves.push(offset as u32);
+        }
+        let (mids, index1) = dedup(leaves.chunks(1 << shift1));
+        Self {
+            shift1,
+            shift2,
+            len: values.len(),
+            index0: mids,
+            index1,
+            index2,
+            overlapping: true,
+        }
+    }
+
+    /// Emits the three levels as arrays with the specified name prefix
+    /// along with a lookup function.
+    pub fn emit(&self, out: &mut String, prefix: &str, function: &str) {
+        for (level, values) in [&self.index0, &self.index1, &self.index2]
+            .iter()
+            .enumerate()
+        {
+            let name = format!("{}{}", prefix, level);
+            let ty = int_type(values.iter().copied().max().unwrap_or(0));
+            array(out, false, &name, ty, values.iter(), WIDTH);
+        }
+        if !function.is_empty() {
+            let index1 = if self.overlapping {
+                format!("{}1[index + offset] as usize", prefix)
+            } else {
+                format!("({}1[index + offset] as usize) << SHIFT2", prefix)
+            };
+            out.push_str(&format!(
+                "pub fn {f}(x: usize) -> usize {{
+    const SHIFT1: usize = {s1};
+    const SHIFT2: usize = {s2};
+    if x >= {len} {{
+        return 0;
+    }}
+    let mut index = ({p}0[x >> (SHIFT1 + SHIFT2)] as usize) << SHIFT1;
+    let mut offset = (x >> SHIFT2) & ((1 << SHIFT1) - 1);
+    index = {index1};
+    offset = x & ((1 << SHIFT2) - 1);
+    {p}2[index + offset] as usize
+}}
+",
+                f = function,
+                s1 = self.shift1,
+                s2 = self.shift2,
+                len = self.len,
+                p = prefix,
+                index1 = index1,
+            ));
+        }
+    }
+}
+
+/// Assigns numbers to distinct blocks in order of first appearance,
+/// returning the number of each block and the concatenated distinct blocks.
+fn dedup<'a>(blocks: impl Iterator<Item = &'a [u32]>) -> (Vec<u32>, Vec<u32>) {
+    let mut numbers = HashMap::new();
+    let mut indices = Vec::new();
+    let mut data = Vec::new();
+    for block in blocks {
+        let next = numbers.len() as u32;
+        let number = *numbers.entry(block).or_insert_with(|| {
+            data.extend_from_slice(block);
+            next
+        });
+        indices.push(number);
+    }
+    (indices, data)
+}
+
+/// Returns the smallest unsigned integer type that can hold a value.
+pub fn int_type(max: u32) -> &'static str {
+    if max <= u8::MAX as u32 {
+        "u8"
+    } else if max <= u16::MAX as u32 {
+        "u16"
+    } else {
+        "u32"
+    }
+}
+
+/// Emits a constant array, wrapping items to fit within a line width.
+pub fn array<T: Display>(
+    out: &mut String,
+    public: bool,
+    name: &str,
+    ty: &str,
+    items: impl ExactSizeIterator<Item = T>,
+    width: usize,
+) {
+    let visibility = if public { "pub " } else { "" };
+    out.push_str("#[rustfmt::skip]\n");
+    out.push_str(&format!(
+        "{}const {}: [{}; {}] = [\n",
+        visibility,
+        name,
+        ty,
+        items.len()
+    ));
+    let mut line = String::new();
+    for item in items {
+        let item = item.to_string();
+        if !line.is_empty() && line.len() + item.len() + 2 > width {
+            out.push_str(&line);
+            out.push('\n');
+            line.clear();
+        }
+        if line.is_empty() {
+            line.push_str("   ");
+        }
+        line.push(' ');
+        line.push_str(&item);
+        line.push(',');
+    }
+    if !line.is_empty() {
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out.push_str("];\n\n");
+}
@@ -0,0 +1,216 @@
+/*!
+Parsing of the Unicode Character Database files.
+*/
+
+use super::Result;
+use std::cell::RefCell;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+/// Number of code points in the Unicode code space.
+pub const CODEPOINTS: usize = 0x110000;
+
+/// Major, minor and micro version numbers.
+pub type Version = (u8, u8, u8);
+
+/// Directory containing the Unicode Character Database.
+pub struct Ucd {
+    dir: PathBuf,
+    /// Names of the files that have been read along with the versions
+    /// declared in their headers.
+    files: RefCell<Vec<(String, Option<Version>)>>,
+}
+
+impl Ucd {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            files: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Reads a data file. Files are located in the root of the directory
+    /// or in one of the subdirectories used by the published archive. The
+    /// Unihan database may be placed in a `unihan` subdirectory.
+    pub fn read(&self, name: &str) -> Result<String> {
+        for sub in &["", "auxiliary", "emoji", "extracted", "unihan"] {
+            let path = self.dir.join(sub).join(name);
+            if path.is_file() {
+                let text = read(&path)?;
+                let mut files = self.files.borrow_mut();
+                if !files.iter().any(|(file, _)| file == name) {
+                    files.push((name.to_string(), header_version(&text)));
+                }
+                return Ok(text);
+            }
+        }
+        Err(format!("{} not found in {}", name, self.dir.display()))
+    }
+
+    /// Returns the version declared in the header of a file that has been
+    /// read.
+    pub fn file_version(&self, name: &str) -> Option<Version> {
+        let files = self.files.borrow();
+        files.iter().find(|(file, _)| file == name)?.1
+    }
+
+    /// Returns the version of the database from the header of
+    /// `Scripts.txt`, which begins with a line such as
+    /// `# Scripts-13.0.0.txt`.
+    pub fn version(&self) -> Result<(u8, u8, u8)> {
+        let text = self.read("Scripts.txt")?;
+        let header = text.lines().next().unwrap_or("");
+        let version = header
+            .trim_start_matches("# Scripts-")
+            .trim_end_matches(".txt");
+        parse_version(version).ok_or_else(|| format!("unrecognized header `{}`", header))
+    }
+}
+
+/// Returns the version declared in the header of a data file, which begins
+/// with a line such as `# LineBreak-13.0.0.txt` or, for the emoji and
+/// Unihan data, contains a line such as `# Version: 13.0`.
+fn header_version(text: &str) -> Option<Version> {
+    let mut lines = text.lines().take_while(|line| line.starts_with('#'));
+    let first = lines.next()?;
+    let name = first
+        .trim_start_matches('#')
+        .trim()
+        .trim_end_matches(".txt");
+    if let Some(version) = name.rsplit('-').next().and_then(parse_version) {
+        return Some(version);
+    }
+    lines.find_map(|line| {
+        line.strip_prefix("# Version:")
+            .or_else(|| line.strip_prefix("# Unicode version:"))
+            .and_then(parse_version)
+    })
+}
+
+/// Reads a text file.
+pub fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parses a version number with two or three components.
+pub fn parse_version(s: &str) -> Option<(u8, u8, u8)> {
+    let mut parts = s.trim().split('.').map(|x| x.parse::<u8>());
+    let major = parts.next()?.ok()?;
+    let minor = parts.next()?.ok()?;
+    let micro = match parts.next() {
+        Some(micro) => micro.ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, micro))
+}
+
+/// Line of a data file with the range of code points in the first field.
+pub struct Entry<'a> {
+    pub range: RangeInclusive<usize>,
+    pub fields: Vec<&'a str>,
+}
+
+/// Returns the entries of a data file in the semicolon separated format
+/// used by most of the database. Comments and blank lines are skipped.
+pub fn entries(text: &str) -> Result<Vec<Entry<'_>>> {
+    let mut entries = Vec::new();
+    for line in text.lines() {
+        let line = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => line,
+        };
+        if let Some(entry) = parse_entry(line)? {
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
+
+/// Returns the default values declared by `@missing` lines, which apply
+/// to code points that are not otherwise listed in a data file.
+pub fn missing(text: &str) -> Result<Vec<Entry<'_>>> {
+    let mut entries = Vec::new();
+    for line in text.lines() {
+        if let Some(line) = line.strip_prefix("# @missing:") {
+            if let Some(entry) = parse_entry(line)? {
+                entries.push(entry);
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn parse_entry(line: &str) -> Result<Option<Entry<'_>>> {
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    let fields = line.split(';').map(|x| x.trim()).collect::<Vec<_>>();
+    let range = parse_range(fields[0])?;
+    Ok(Some(Entry {
+        range,
+        fields: fields[1..].to_vec(),
+    }))
+}
+
+/// Parses a code point or a range of code points in the form `0000..007F`.
+pub fn parse_range(s: &str) -> Result<RangeInclusive<usize>> {
+    let (start, end) = match s.find("..") {
+        Some(pos) => (&s[..pos], &s[pos + 2..]),
+        None => (s, s),
+    };
+    let start = parse_codepoint(start)?;
+    let end = parse_codepoint(end)?;
+    if start > end {
+        return Err(format!("invalid range `{}`", s));
+    }
+    Ok(start..=end)
+}
+
+/// Parses a hexadecimal code point.
+pub fn parse_codepoint(s: &str) -> Result<usize> {
+    match usize::from_str_radix(s.trim(), 16) {
+        Ok(cp) if cp < CODEPOINTS => Ok(cp),
+        _ => Err(format!("invalid code point `{}`", s)),
+    }
+}
+
+/// Builds a table with a value for every code point from the first field
+/// of each entry in a data file. Code points that are not listed have the
+/// value declared by `@missing` lines or the specified default.
+pub fn property<'a, T: Copy>(
+    text: &'a str,
+    default: T,
+    mut f: impl FnMut(&'a str) -> Result<T>,
+) -> Result<Vec<T>> {
+    let mut values = vec![default; CODEPOINTS];
+    for entry in missing(text)?.into_iter().chain(entries(text)?) {
+        let value = f(entry.fields.first().copied().unwrap_or(""))?;
+        for cp in entry.range {
+            values[cp] = value;
+        }
+    }
+    Ok(values)
+}
+
+/// Builds a table with a flag for every code point that has the specified
+/// binary property in a data file that lists several properties.
+pub fn binary(text: &str, name: &str) -> Result<Vec<bool>> {
+    let mut values = vec![false; CODEPOINTS];
+    let mut found = false;
+    for entry in entries(text)? {
+        if entry.fields.first() == Some(&name) {
+            found = true;
+            for cp in entry.range {
+                values[cp] = true;
+            }
+        }
+    }
+    if !found {
+        return Err(format!("property {} not found", name));
+    }
+    Ok(values)
+}
@@ -0,0 +1,99 @@
+/*!
+Subset of the Unihan database: total stroke counts, simplified and
+traditional variants and the presence of Mandarin readings.
+*/
+
+use super::ucd::{self, Ucd, CODEPOINTS};
+use super::Result;
+
+/// Flag in the packed value of a character that indicates a Mandarin
+/// reading. The remaining bits hold the total stroke count.
+pub const MANDARIN: u8 = 0x80;
+
+pub struct Unihan {
+    /// Stroke count and Mandarin flag for every code point.
+    pub values: Vec<u8>,
+    /// Pairs of a character and one of its simplified variants, sorted.
+    pub simplified: Vec<(usize, usize)>,
+    /// Pairs of a character and one of its traditional variants, sorted.
+    pub traditional: Vec<(usize, usize)>,
+}
+
+pub fn load(ucd: &Ucd) -> Result<Unihan> {
+    let mut values = vec![0; CODEPOINTS];
+    let text = ucd.read("Unihan_IRGSources.txt")?;
+    for (cp, value) in fields(&text, "kTotalStrokes")? {
+        // Multiple counts are listed when the preferred count differs
+        // between regions. The first is the count for mainland China.
+        let count = value.split_whitespace().next().unwrap_or("");
+        match count.parse::<u8>() {
+            Ok(count) if count < MANDARIN => values[cp] = count,
+            _ => return Err(format!("invalid stroke count `{}` at {:04X}", value, cp)),
+        }
+    }
+    // Every ideograph of the original CJK Unified Ideographs block has a
+    // stroke count, so a gap means the database is incomplete.
+    if let Some(cp) = (0x4E00..=0x9FA5).find(|&cp| values[cp] == 0) {
+        return Err(format!(
+            "no stroke count for {:04X}; is the Unihan database complete?",
+            cp
+        ));
+    }
+    let text = ucd.read("Unihan_Readings.txt")?;
+    for (cp, _) in fields(&text, "kMandarin")? {
+        values[cp] |= MANDARIN;
+    }
+    let text = ucd.read("Unihan_Variants.txt")?;
+    Ok(Unihan {
+        values,
+        simplified: variants(&text, "kSimplifiedVariant")?,
+        traditional: variants(&text, "kTraditionalVariant")?,
+    })
+}
+
+/// Returns the sorted pairs of characters and variants for a field. Values
+/// may list several variants, some of which are the character itself.
+fn variants(text: &str, field: &str) -> Result<Vec<(usize, usize)>> {
+    let mut pairs = Vec::new();
+    for (cp, value) in fields(text, field)? {
+        for variant in value.split_whitespace() {
+            let variant = parse(variant)?;
+            if variant != cp {
+                pairs.push((cp, variant));
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs.dedup();
+    Ok(pairs)
+}
+
+/// Returns the code point and value of each line of a Unihan file with the
+/// specified field. Lines have the form `U+4E00<tab>kField<tab>value`.
+fn fields<'a>(text: &'a str, field: &str) -> Result<Vec<(usize, &'a str)>> {
+    let mut entries = Vec::new();
+    for line in text.lines() {
+        if line.starts_with('#') || line.trim().is_empty() {
+            continue;
+        }
+        let mut parts = line.splitn(3, '\t');
+        let (cp, name, value) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(cp), Some(name), Some(value)) => (cp, name, value),
+            _ => return Err(format!("invalid Unihan line `{}`", line)),
+        };
+        if name == field {
+            entries.push((parse(cp)?, value.trim()));
+        }
+    }
+    Ok(entries)
+}
+
+/// Parses a code point in the form `U+4E00`, ignoring any source
+/// annotation that follows it.
+fn parse(s: &str) -> Result<usize> {
+    let s = s.split('<').next().unwrap_or("");
+    match s.strip_prefix("U+") {
+        Some(hex) => ucd::parse_codepoint(hex),
+        None => Err(format!("invalid code point `{}`", s)),
+    }
+}
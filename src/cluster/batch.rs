@@ -0,0 +1,193 @@
+//! Batched cluster output into caller provided buffers.
+
+use super::{Cluster, ClusterInfo, Parser, SourceChar, UserData};
+
+use core::ops::Range;
+
+/// Caller provided parallel buffers that receive the output of
+/// [`Parser::next_batch`].
+///
+/// The character buffers are indexed by character and the cluster buffers
+/// are indexed by cluster. The capacity of each group is the minimum length
+/// of its buffers.
+pub struct ClusterBatch<'a> {
+    /// Characters of all clusters in the batch.
+    pub chars: &'a mut [char],
+    /// Offsets of the characters in code units.
+    pub offsets: &'a mut [usize],
+    /// User data of the characters.
+    pub data: &'a mut [UserData],
+    /// Nominal glyph identifiers of the characters. These are zero unless
+    /// the parser has a glyph mapping (see [`Parser::with_glyphs`]).
+    pub glyph_ids: &'a mut [u32],
+    /// Information for each cluster.
+    pub infos: &'a mut [ClusterInfo],
+    /// Source range of each cluster in code units.
+    pub ranges: &'a mut [Range<usize>],
+    /// Index one past the last character of each cluster in the character
+    /// buffers.
+    pub ends: &'a mut [usize],
+}
+
+impl<'a> ClusterBatch<'a> {
+    fn char_capacity(&self) -> usize {
+        self.chars
+            .len()
+            .min(self.offsets.len())
+            .min(self.data.len())
+            .min(self.glyph_ids.len())
+    }
+
+    fn cluster_capacity(&self) -> usize {
+        self.infos.len().min(self.ranges.len()).min(self.ends.len())
+    }
+}
+
+/// Number of clusters and characters written by [`Parser::next_batch`].
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct BatchCount {
+    /// Number of clusters written.
+    pub clusters: usize,
+    /// Number of characters written.
+    pub chars: usize,
+}
+
+impl<I, V, M> Parser<I, V, M>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+    V: FnMut(char, char) -> Option<u32>,
+    M: Fn(char) -> u32,
+{
+    /// Parses as many clusters as will fit into the specified buffers,
+    /// returning the number of clusters and characters written. The
+    /// cluster is used for parsing and its capacity `N` limits the size of
+    /// each cluster as with [`next`](Self::next).
+    ///
+    /// Parsing stops when the cluster buffers are full or when fewer than
+    /// `N` characters remain in the character buffers, so a cluster is
+    /// never split across batches. A result with zero clusters indicates
+    /// that the input is exhausted (or that the buffers are too small to
+    /// hold a cluster).
+    ///
+    /// The characters of each cluster are written as mapped, so they are
+    /// composed or decomposed when the glyph mapping selected such a form.
+    pub fn next_batch<const N: usize>(
+        &mut self,
+        cluster: &mut Cluster<N>,
+        batch: &mut ClusterBatch,
+    ) -> BatchCount {
+        let char_capacity = batch.char_capacity();
+        let cluster_capacity = batch.cluster_capacity();
+        let mut count = BatchCount::default();
+        while count.clusters < cluster_capacity
+            && char_capacity - count.chars >= N
+            && self.next(cluster)
+        {
+            for ch in cluster.mapped_chars() {
+                batch.chars[count.chars] = ch.ch;
+                batch.offsets[count.chars] = ch.offset;
+                batch.data[count.chars] = ch.data;
+                batch.glyph_ids[count.chars] = ch.glyph_id;
+                count.chars += 1;
+            }
+            batch.infos[count.clusters] = cluster.info();
+            batch.ranges[count.clusters] = cluster.range();
+            batch.ends[count.clusters] = count.chars;
+            count.clusters += 1;
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::Script;
+
+    struct Buffers {
+        chars: [char; 8],
+        offsets: [usize; 8],
+        data: [UserData; 8],
+        glyph_ids: [u32; 8],
+        infos: [ClusterInfo; 4],
+        ranges: [Range<usize>; 4],
+        ends: [usize; 4],
+    }
+
+    impl Buffers {
+        fn new() -> Self {
+            Self {
+                chars: ['\0'; 8],
+                offsets: [0; 8],
+                data: [0; 8],
+                glyph_ids: [0; 8],
+                infos: [ClusterInfo::default(); 4],
+                ranges: [0..0, 0..0, 0..0, 0..0],
+                ends: [0; 4],
+            }
+        }
+
+        fn batch(&mut self) -> ClusterBatch<'_> {
+            ClusterBatch {
+                chars: &mut self.chars,
+                offsets: &mut self.offsets,
+                data: &mut self.data,
+                glyph_ids: &mut self.glyph_ids,
+                infos: &mut self.infos,
+                ranges: &mut self.ranges,
+                ends: &mut self.ends,
+            }
+        }
+    }
+
+    #[test]
+    fn glyph_ids() {
+        // Only the precomposed form of the second cluster is supported.
+        let mut parser = Parser::from_str(Script::Latin, "xe\u{301}").with_glyphs(|ch| {
+            if ch == '\u{301}' {
+                0
+            } else {
+                ch as u32
+            }
+        });
+        let mut buffers = Buffers::new();
+        let mut cluster = Cluster::<4>::with_capacity();
+        let count = parser.next_batch(&mut cluster, &mut buffers.batch());
+        assert_eq!(
+            count,
+            BatchCount {
+                clusters: 2,
+                chars: 2
+            }
+        );
+        assert_eq!(buffers.chars[..2], ['x', '\u{e9}']);
+        assert_eq!(buffers.glyph_ids[..2], ['x' as u32, 0xE9]);
+        assert_eq!(buffers.offsets[..2], [0, 1]);
+        assert_eq!(buffers.ranges[..2], [0..1, 1..4]);
+        assert_eq!(buffers.ends[..2], [1, 2]);
+    }
+
+    #[test]
+    fn capacity() {
+        let s = "a\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}";
+        let mut buffers = Buffers::new();
+        // The default capacity does not fit in the character buffers.
+        let mut parser = Parser::from_str(Script::Latin, s);
+        let count = parser.next_batch(&mut Cluster::new(), &mut buffers.batch());
+        assert_eq!(count, BatchCount::default());
+        let mut parser = Parser::from_str(Script::Latin, s);
+        let mut cluster = Cluster::<4>::with_capacity();
+        let count = parser.next_batch(&mut cluster, &mut buffers.batch());
+        assert_eq!(
+            count,
+            BatchCount {
+                clusters: 2,
+                chars: 7
+            }
+        );
+        assert_eq!(buffers.ends[..2], [4, 7]);
+        assert_eq!(buffers.ranges[..2], [0..7, 7..13]);
+        let count = parser.next_batch(&mut cluster, &mut buffers.batch());
+        assert_eq!(count, BatchCount::default());
+    }
+}
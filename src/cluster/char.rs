@@ -1,4 +1,4 @@
-use super::JoiningType;
+use super::{Category, JoiningType};
 use super::{CharInfo, UserData};
 
 /// Character output from the cluster parser.
@@ -18,6 +18,9 @@ pub struct Char {
     pub contributes_to_shaping: bool,
     /// Nominal glyph identifier.
     pub glyph_id: u32,
+    /// True if the glyph identifier was resolved from a variation sequence
+    /// and should be preserved when mapping the cluster.
+    pub variant: bool,
     /// Arbitrary user data.
     pub data: UserData,
 }
@@ -31,14 +34,114 @@ impl Default for Char {
             ignorable: false,
             contributes_to_shaping: true,
             glyph_id: 0,
+            variant: false,
             data: 0,
             offset: 0,
         }
     }
 }
 
+/// Character output from the cluster parser with a fixed layout that
+/// contains no padding.
+///
+/// This allows the characters of a cluster to be copied into GPU or shared
+/// memory buffers as bytes. Offsets are truncated to 32 bits.
+#[cfg(feature = "bytemuck")]
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+#[repr(C)]
+pub struct PodChar {
+    /// The character as a scalar value.
+    pub ch: u32,
+    /// Offset of the character in code units.
+    pub offset: u32,
+    /// Nominal glyph identifier.
+    pub glyph_id: u32,
+    /// Arbitrary user data.
+    pub data: UserData,
+    /// Shaping class of the character as a [`ShapeClass`] discriminant.
+    pub shape_class: u8,
+    /// Joining type of the character as a [`JoiningType`] discriminant.
+    pub joining_type: u8,
+    /// Combination of the `IGNORABLE`, `CONTRIBUTES_TO_SHAPING` and
+    /// `VARIANT` flags.
+    pub flags: u8,
+    /// Reserved for future use. This is always zero.
+    pub reserved: u8,
+}
+
+#[cfg(feature = "bytemuck")]
+impl PodChar {
+    /// Flag set when the character is ignorable.
+    pub const IGNORABLE: u8 = 1;
+    /// Flag set when the character should be considered when mapping
+    /// glyphs.
+    pub const CONTRIBUTES_TO_SHAPING: u8 = 2;
+    /// Flag set when the glyph identifier was resolved from a variation
+    /// sequence.
+    pub const VARIANT: u8 = 4;
+
+    /// Converts the character back to a [`Char`]. Returns `None` if the
+    /// character, shaping class or joining type is invalid.
+    pub fn to_char(self) -> Option<Char> {
+        if self.shape_class > ShapeClass::Other as u8 || self.joining_type > JoiningType::T as u8 {
+            return None;
+        }
+        // SAFETY: Both enums are `repr(u8)` with contiguous discriminants
+        // and the values were checked against the largest ones.
+        let (shape_class, joining_type) = unsafe {
+            (
+                core::mem::transmute::<u8, ShapeClass>(self.shape_class),
+                core::mem::transmute::<u8, JoiningType>(self.joining_type),
+            )
+        };
+        Some(Char {
+            ch: char::from_u32(self.ch)?,
+            offset: self.offset as usize,
+            shape_class,
+            joining_type,
+            ignorable: self.flags & Self::IGNORABLE != 0,
+            contributes_to_shaping: self.flags & Self::CONTRIBUTES_TO_SHAPING != 0,
+            glyph_id: self.glyph_id,
+            variant: self.flags & Self::VARIANT != 0,
+            data: self.data,
+        })
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl From<Char> for PodChar {
+    fn from(ch: Char) -> Self {
+        let mut flags = 0;
+        if ch.ignorable {
+            flags |= Self::IGNORABLE;
+        }
+        if ch.contributes_to_shaping {
+            flags |= Self::CONTRIBUTES_TO_SHAPING;
+        }
+        if ch.variant {
+            flags |= Self::VARIANT;
+        }
+        Self {
+            ch: ch.ch as u32,
+            offset: ch.offset as u32,
+            glyph_id: ch.glyph_id,
+            data: ch.data,
+            shape_class: ch.shape_class as u8,
+            joining_type: ch.joining_type as u8,
+            flags,
+            reserved: 0,
+        }
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for PodChar {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for PodChar {}
+
 /// Shaping class of a character.
-#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Default, Debug)]
 #[repr(u8)]
 pub enum ShapeClass {
     /// Reph form.
@@ -48,21 +151,32 @@ pub enum ShapeClass {
     /// Myanmar three character prefix.
     Kinzi,
     /// Base character.
+    #[default]
     Base,
     /// Mark character.
     Mark,
     /// Halant modifier.
     Halant,
+    /// Subjoined consonant.
+    Subjoined,
     /// Medial consonant Ra.
     MedialRa,
     /// Pre-base vowel modifier.
     VmPre,
     /// Pre-base dependent vowel.
     VPre,
+    /// Above base dependent vowel.
+    VAbv,
     /// Below base dependent vowel.
     VBlw,
     /// Anusvara class.
     Anusvara,
+    /// Hangul leading consonant jamo.
+    LJamo,
+    /// Hangul vowel jamo.
+    VJamo,
+    /// Hangul trailing consonant jamo.
+    TJamo,
     /// Zero width joiner.
     Zwj,
     /// Zero width non-joiner.
@@ -75,12 +189,6 @@ pub enum ShapeClass {
     Other,
 }
 
-impl Default for ShapeClass {
-    fn default() -> Self {
-        Self::Base
-    }
-}
-
 /// Character input to the cluster parser.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct SourceChar {
@@ -96,6 +204,17 @@ pub struct SourceChar {
     pub data: UserData,
 }
 
+impl SourceChar {
+    /// Returns true if the character is emitted as a cluster of its own
+    /// when [`ParserOptions::separate_ignorables`](super::ParserOptions::separate_ignorables)
+    /// is set.
+    pub(super) fn is_separable(&self) -> bool {
+        self.info.category() == Category::Format
+            && self.info.is_default_ignorable()
+            && !matches!(self.ch, '\u{E0020}'..='\u{E007F}')
+    }
+}
+
 impl Default for SourceChar {
     fn default() -> Self {
         Self {
@@ -107,3 +226,22 @@ impl Default for SourceChar {
         }
     }
 }
+
+/// Generates an arbitrary character with properties that match the
+/// character and arbitrary boundary analysis. The length is the UTF-8
+/// length of the character and the offset is limited to 32 bits so that
+/// the end of the character cannot overflow.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for SourceChar {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let ch = char::arbitrary(u)?;
+        let info = CharInfo::arbitrary(u)?.with_properties(ch.into());
+        Ok(Self {
+            ch,
+            offset: u32::arbitrary(u)? as usize,
+            len: ch.len_utf8() as u8,
+            info,
+            data: UserData::arbitrary(u)?,
+        })
+    }
+}
@@ -1,39 +1,68 @@
 use super::char::{Char, ShapeClass, SourceChar};
-use super::{ClusterInfo, UserData};
+use super::{CharInfo, ClusterBreak, ClusterInfo, Emoji, Newline, Script, UserData, Whitespace};
 use super::{Codepoint as _, JoiningType};
 
-use core::ops::Range;
+use core::ops::{Range, RangeInclusive};
 
-/// The maximum number of characters in a single cluster.
+/// The default maximum number of characters in a single cluster.
 pub const MAX_CLUSTER_SIZE: usize = 32;
 
 /// Character cluster; output from the parser and input to the shaper.
+///
+/// The capacity of the cluster is specified by `N` which defaults to
+/// [`MAX_CLUSTER_SIZE`] and must be in the range `1..=255`. Parsing
+/// terminates the current cluster when it reaches capacity and returns the
+/// remaining characters in continuation clusters (see
+/// [`is_continuation`](Self::is_continuation)), so embedders that expect
+/// long sequences of combining marks may choose a larger value.
 #[derive(Copy, Clone)]
-pub struct Cluster {
+pub struct Cluster<const N: usize = MAX_CLUSTER_SIZE> {
     info: ClusterInfo,
-    chars: [Char; MAX_CLUSTER_SIZE],
+    script: Script,
+    /// Script of the first pushed character that is not `Common` or
+    /// `Inherited`, or `Common` if there is none.
+    char_script: Script,
+    chars: [Char; N],
     len: u8,
     map_len: u8,
     start: usize,
     end: usize,
     force_normalize: bool,
-    comp: Form,
-    decomp: Form,
+    continued: bool,
+    continuation: bool,
+    comp: Form<N>,
+    decomp: Form<N>,
     form: FormKind,
     best_ratio: f32,
 }
 
 impl Cluster {
-    /// Creates a new empty cluster.
+    /// Creates a new empty cluster with the default capacity.
     pub fn new() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl<const N: usize> Cluster<N> {
+    /// Fails to compile when referenced with a capacity that is zero or
+    /// cannot be stored in the length fields.
+    const VALID_CAPACITY: () = assert!(N > 0 && N <= u8::MAX as usize, "invalid cluster capacity");
+
+    /// Creates a new empty cluster with a capacity of `N` characters.
+    pub fn with_capacity() -> Self {
+        let () = Self::VALID_CAPACITY;
         Self {
             info: ClusterInfo(0),
-            chars: [DEFAULT_CHAR; MAX_CLUSTER_SIZE],
+            script: Script::Unknown,
+            char_script: Script::Common,
+            chars: [DEFAULT_CHAR; N],
             len: 0,
             map_len: 0,
             start: 0,
             end: 0,
             force_normalize: false,
+            continued: false,
+            continuation: false,
             comp: Form::new(),
             decomp: Form::new(),
             form: FormKind::Original,
@@ -46,6 +75,16 @@ impl Cluster {
         self.info
     }
 
+    /// Returns the resolved script of the cluster.
+    ///
+    /// This is the script of the first character in the cluster that is
+    /// not `Common` or `Inherited`. Clusters that consist entirely of such
+    /// characters inherit the script of the preceding cluster, or the
+    /// script specified for the parser if there is none.
+    pub fn script(&self) -> Script {
+        self.script
+    }
+
     /// Returns the primary user data for the cluster.
     pub fn user_data(&self) -> UserData {
         self.chars[0].data
@@ -61,6 +100,22 @@ impl Cluster {
         self.len == 0
     }
 
+    /// Returns true if the cluster reached capacity and the following
+    /// cluster holds the remaining characters.
+    pub fn is_continued(&self) -> bool {
+        self.continued
+    }
+
+    /// Returns true if the cluster holds the remaining characters of the
+    /// previous cluster, which reached capacity. Such a cluster typically
+    /// begins with a combining mark but is not marked as broken. Its range
+    /// begins at the end of the previous cluster and is empty when it only
+    /// holds the remaining parts of a character that was split, such as a
+    /// decomposed character.
+    pub fn is_continuation(&self) -> bool {
+        self.continuation
+    }
+
     /// Returns the sequence of characters in the cluster.
     pub fn chars(&self) -> &[Char] {
         &self.chars[..self.len as usize]
@@ -82,7 +137,7 @@ impl Cluster {
         if len == 0 {
             return Status::Complete;
         }
-        let mut glyph_ids = [0u32; MAX_CLUSTER_SIZE];
+        let mut glyph_ids = [0u32; N];
         let prev_ratio = self.best_ratio;
         let mut ratio;
         if self.force_normalize && self.composed().is_some() {
@@ -134,14 +189,194 @@ impl Cluster {
         }
     }
 
+    /// Repairs a broken cluster by inserting a dotted circle (U+25CC) to
+    /// serve as the missing base character. The dotted circle is inserted
+    /// before the first character, or after a leading reph, and shares the
+    /// offset and user data of the character that follows it.
+    ///
+    /// Returns true if the cluster was repaired. Clusters that are not
+    /// broken, or that are already at the maximum size, are left unchanged.
+    /// This should be called before mapping the cluster.
+    pub fn repair(&mut self) -> bool {
+        let len = self.len as usize;
+        if !self.info.is_broken() || len == 0 || len == N {
+            return false;
+        }
+        let index = self.chars[..len]
+            .iter()
+            .position(|ch| ch.shape_class != ShapeClass::Reph)
+            .unwrap_or(len);
+        let next = self.chars[index.min(len - 1)];
+        self.chars.copy_within(index..len, index + 1);
+        self.chars[index] = Char {
+            ch: '\u{25cc}',
+            offset: next.offset,
+            data: next.data,
+            ..DEFAULT_CHAR
+        };
+        self.len += 1;
+        self.map_len += 1;
+        self.info.clear_broken();
+        self.comp.clear();
+        self.decomp.clear();
+        true
+    }
+
+    /// Composes the characters in the cluster into precomposed forms,
+    /// returning true if any compositions were applied.
+    ///
+    /// This follows the canonical composition algorithm, but a composition
+    /// is only applied when `supported` returns true for the resulting
+    /// character. This is intended for clusters of simple scripts (such as
+    /// Latin, Greek and Cyrillic) where the font may provide precomposed
+    /// glyphs. A composed character keeps the offset, user data and shape
+    /// class of the starter. This should be called before mapping the
+    /// cluster.
+    pub fn compose(&mut self, supported: impl Fn(char) -> bool) -> bool {
+        let len = self.len as usize;
+        let mut starter: Option<usize> = None;
+        let mut last_ccc = 0;
+        let mut out = 0;
+        for i in 0..len {
+            let ch = self.chars[i];
+            let ccc = ch.ch.combining_class();
+            if let Some(s) = starter {
+                let blocked = out != s + 1 && (last_ccc == 0 || last_ccc >= ccc);
+                if !blocked && !self.chars[s].variant {
+                    if let Some(comp) = char::compose(self.chars[s].ch, ch.ch) {
+                        if supported(comp) {
+                            let props = comp.properties();
+                            let base = &mut self.chars[s];
+                            base.ch = comp;
+                            base.joining_type = props.joining_type();
+                            base.ignorable = props.is_ignorable();
+                            base.contributes_to_shaping = props.contributes_to_shaping();
+                            continue;
+                        }
+                    }
+                }
+            }
+            if ccc == 0 {
+                starter = Some(out);
+            }
+            last_ccc = ccc;
+            self.chars[out] = ch;
+            out += 1;
+        }
+        if out == len {
+            return false;
+        }
+        self.len = out as u8;
+        self.map_len = self.chars[..out]
+            .iter()
+            .filter(|ch| ch.contributes_to_shaping)
+            .count() as u8;
+        self.comp.clear();
+        self.decomp.clear();
+        true
+    }
+
+    /// Returns the precomposed Hangul syllable that is canonically
+    /// equivalent to the cluster if it consists entirely of a composable
+    /// sequence of conjoining jamo (L V, L V T or LV T).
+    ///
+    /// This allows shapers to fall back to precomposed glyphs for fonts
+    /// that lack the jamo composition features.
+    pub fn hangul_syllable(&self) -> Option<char> {
+        const L: RangeInclusive<char> = '\u{1100}'..='\u{115F}';
+        const V: RangeInclusive<char> = '\u{1160}'..='\u{11A7}';
+        const T: RangeInclusive<char> = '\u{11A8}'..='\u{11FF}';
+        const SYLLABLE: RangeInclusive<char> = '\u{AC00}'..='\u{D7A3}';
+        let syllable = match self.chars() {
+            [l, v] if L.contains(&l.ch) && V.contains(&v.ch) => char::compose(l.ch, v.ch)?,
+            [lv, t] if SYLLABLE.contains(&lv.ch) && T.contains(&t.ch) => {
+                char::compose(lv.ch, t.ch)?
+            }
+            [l, v, t] if L.contains(&l.ch) && V.contains(&v.ch) && T.contains(&t.ch) => {
+                char::compose(char::compose(l.ch, v.ch)?, t.ch)?
+            }
+            _ => return None,
+        };
+        if SYLLABLE.contains(&syllable) {
+            Some(syllable)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the qualification status of the cluster as an emoji sequence
+    /// or `None` if it is not a recommended emoji sequence.
+    ///
+    /// Clusters that are not fully qualified lack presentation selectors
+    /// that may be required to select a color glyph. The fully qualified
+    /// form is available from [`emoji::fully_qualified`].
+    ///
+    /// [`emoji::fully_qualified`]: crate::unicode::emoji::fully_qualified
+    #[cfg(feature = "emoji-sequences")]
+    pub fn emoji_qualification(&self) -> Option<crate::unicode::emoji::Qualification> {
+        crate::unicode::emoji::lookup(self.chars().iter().map(|c| c.ch))
+    }
+
+    /// Returns an iterator that splits the cluster into sub-clusters at
+    /// grapheme cluster boundaries.
+    ///
+    /// This is intended as a fallback when a font is unable to shape the
+    /// full cluster, such as an unsupported emoji ZWJ sequence or a syllable
+    /// of a complex script. Emoji ZWJ sequences are split into the component
+    /// emoji, each retaining the following joiner. Each piece retains the
+    /// offsets, user data and shape classes of its characters along with
+    /// cluster information computed for the piece. Boundary analysis applies
+    /// to the first piece only.
+    pub fn split_graphemes(&self) -> Graphemes<'_, N> {
+        Graphemes {
+            cluster: self,
+            pos: 0,
+        }
+    }
+
+    /// Reorders combining marks in the cluster into canonical order.
+    ///
+    /// Each sequence of characters with a non-zero canonical combining class
+    /// is stably sorted by combining class as described by the canonical
+    /// ordering algorithm, so that the order of marks is independent of the
+    /// input order. This should be called before mapping the cluster.
+    pub fn reorder_marks(&mut self) {
+        let chars = &mut self.chars[..self.len as usize];
+        let mut reordered = false;
+        for i in 1..chars.len() {
+            let ccc = chars[i].ch.combining_class();
+            if ccc == 0 {
+                continue;
+            }
+            let mut j = i;
+            while j > 0 {
+                let prev = chars[j - 1].ch.combining_class();
+                if prev <= ccc {
+                    break;
+                }
+                chars.swap(j - 1, j);
+                j -= 1;
+                reordered = true;
+            }
+        }
+        if reordered {
+            self.comp.clear();
+            self.decomp.clear();
+        }
+    }
+
     /// Resets the cluster to the intial empty state.
     pub fn clear(&mut self) {
         self.info = ClusterInfo(0);
+        self.script = Script::Unknown;
+        self.char_script = Script::Common;
         self.len = 0;
         self.map_len = 0;
         self.start = 0;
         self.end = 0;
         self.force_normalize = false;
+        self.continued = false;
+        self.continuation = false;
         self.comp.clear();
         self.decomp.clear();
         self.form = FormKind::Original;
@@ -158,8 +393,16 @@ impl Cluster {
                 for ch in &self.chars[..self.len as usize] {
                     let mut end = i;
                     let mut copy = *ch;
+                    if ch.variant {
+                        if end == N {
+                            return None;
+                        }
+                        self.decomp.chars[end] = copy;
+                        i = end + 1;
+                        continue;
+                    }
                     for c in ch.ch.decompose() {
-                        if end == MAX_CLUSTER_SIZE {
+                        if end == N {
                             return None;
                         }
                         copy.ch = c;
@@ -193,7 +436,12 @@ impl Cluster {
                 let mut last = self.decomp.chars[0];
                 let mut i = 0;
                 for ch in &self.decomp.chars()[1..] {
-                    if let Some(comp) = char::compose(last.ch, ch.ch) {
+                    let comp = if last.variant || ch.variant {
+                        None
+                    } else {
+                        char::compose(last.ch, ch.ch)
+                    };
+                    if let Some(comp) = comp {
                         last.ch = comp;
                     } else {
                         self.comp.chars[i] = last;
@@ -212,14 +460,14 @@ impl Cluster {
     }
 }
 
-impl Default for Cluster {
+impl<const N: usize> Default for Cluster<N> {
     fn default() -> Self {
-        Self::new()
+        Self::with_capacity()
     }
 }
 
 /// Functions for cluster building.
-impl Cluster {
+impl<const N: usize> Cluster<N> {
     pub(super) fn info_mut(&mut self) -> &mut ClusterInfo {
         &mut self.info
     }
@@ -228,6 +476,62 @@ impl Cluster {
         self.len
     }
 
+    pub(super) fn is_full(&self) -> bool {
+        self.len as usize == N
+    }
+
+    /// Resolves the script of the cluster, using `context` for clusters
+    /// that do not contain any characters of a specific script.
+    pub(super) fn resolve_script(&mut self, context: Script) -> Script {
+        self.script = match self.char_script {
+            Script::Common => context,
+            script => script,
+        };
+        self.script
+    }
+
+    /// Records the line terminator of a newline cluster.
+    pub(super) fn resolve_newline(&mut self) {
+        if self.info.whitespace() != Whitespace::Newline {
+            return;
+        }
+        let newline = match (self.chars[0].ch, self.len > 1) {
+            ('\r', true) if self.chars[1].ch == '\n' => Newline::CrLf,
+            ('\r', _) => Newline::Cr,
+            ('\n', _) => Newline::Lf,
+            _ => Newline::None,
+        };
+        self.info.set_newline(newline);
+    }
+
+    /// Records whether the cluster ends with an unpaired regional
+    /// indicator.
+    pub(super) fn resolve_regional_indicators(&mut self) {
+        let chars = &self.chars[..self.len as usize];
+        // Regional indicators are exactly the characters of this block.
+        let is_ri = |ch: &Char| matches!(ch.ch, '\u{1F1E6}'..='\u{1F1FF}');
+        if matches!(chars.last(), Some(ch) if is_ri(ch))
+            && chars.iter().filter(|ch| is_ri(ch)).count() == 1
+        {
+            self.info.set_unpaired_regional_indicator();
+        }
+    }
+
+    pub(super) fn set_continued(&mut self) {
+        self.continued = true;
+    }
+
+    /// Marks the cluster as holding the remaining characters of a cluster
+    /// that ended at `end`. A source character that was split between the
+    /// two clusters, such as a decomposed character, remains in the range
+    /// of the previous cluster only, so the range may be empty.
+    pub(super) fn set_continuation(&mut self, end: usize) {
+        self.continuation = true;
+        self.info.clear_broken();
+        self.start = self.start.max(end);
+        self.end = self.end.max(self.start);
+    }
+
     pub(super) fn force_normalize(&mut self) {
         self.force_normalize = true;
     }
@@ -241,18 +545,50 @@ impl Cluster {
             ignorable: input.info.is_ignorable(),
             contributes_to_shaping,
             glyph_id: 0,
+            variant: false,
             offset: input.offset,
             data: input.data,
         };
         if self.len == 0 {
             self.start = input.offset;
         }
-        self.info.merge_boundary(input.info.boundary() as u16);
+        if self.char_script == Script::Common {
+            let script = input.info.script();
+            if script != Script::Inherited {
+                self.char_script = script;
+            }
+        }
+        self.info.merge_boundary(input.info.boundary());
         self.end = input.offset + input.len as usize;
         self.len += 1;
         self.map_len += contributes_to_shaping as u8;
     }
 
+    /// Resolves each variation selector that follows another character in
+    /// the cluster with the specified function. A resolved glyph is assigned
+    /// to the preceding character which is then marked as a variant. The
+    /// selector is removed from the cluster in either case, but remains
+    /// included in the cluster range.
+    pub(super) fn resolve_variations(&mut self, f: &mut impl FnMut(char, char) -> Option<u32>) {
+        let len = self.len as usize;
+        let mut out = 0;
+        for i in 0..len {
+            let ch = self.chars[i];
+            if ch.shape_class == ShapeClass::Vs && out != 0 {
+                let base = &mut self.chars[out - 1];
+                if let Some(glyph_id) = f(base.ch, ch.ch) {
+                    base.glyph_id = glyph_id;
+                    base.variant = true;
+                }
+                self.map_len -= ch.contributes_to_shaping as u8;
+                continue;
+            }
+            self.chars[out] = ch;
+            out += 1;
+        }
+        self.len = out as u8;
+    }
+
     /// This function records the attributes and range information for
     /// a character but does not add it to the cluster. It is used when
     /// characters such as emoji variation selectors are dropped from
@@ -261,11 +597,104 @@ impl Cluster {
         if self.len == 0 {
             self.start = input.offset;
         }
-        self.info.merge_boundary(input.info.boundary() as u16);
+        self.info.merge_boundary(input.info.boundary());
         self.end = input.offset + input.len as usize;
     }
 }
 
+/// Iterator over the grapheme clusters contained in a cluster. This iterator
+/// is created by the [`Cluster::split_graphemes`] method.
+#[derive(Clone)]
+pub struct Graphemes<'a, const N: usize> {
+    cluster: &'a Cluster<N>,
+    pos: usize,
+}
+
+impl<'a, const N: usize> Iterator for Graphemes<'a, N> {
+    type Item = Cluster<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use ClusterBreak::*;
+        let parent = self.cluster;
+        let chars = parent.chars();
+        let start = self.pos;
+        if start >= chars.len() {
+            return None;
+        }
+        let (first, _) = chars[start].ch.properties().cluster_class();
+        let mut prev = first;
+        let mut regional_count = (first == RI) as usize;
+        let mut end = start + 1;
+        while end < chars.len() {
+            let (kind, _) = chars[end].ch.properties().cluster_class();
+            let join = match (prev, kind) {
+                (CR, LF) => true,
+                (CN, _) | (CR, _) | (LF, _) | (_, CN) | (_, CR) | (_, LF) => false,
+                (L, L) | (L, V) | (L, LV) | (L, LVT) => true,
+                (LV, V) | (LV, T) | (V, V) | (V, T) => true,
+                (LVT, T) | (T, T) => true,
+                (_, EX) | (_, ZWJ) | (_, SM) | (PP, _) => true,
+                (RI, RI) => regional_count % 2 == 1,
+                _ => false,
+            };
+            if !join {
+                break;
+            }
+            regional_count = if kind == RI { regional_count + 1 } else { 0 };
+            prev = kind;
+            end += 1;
+        }
+        self.pos = end;
+        let mut piece = Cluster::<N>::with_capacity();
+        piece.chars[..end - start].copy_from_slice(&chars[start..end]);
+        piece.len = (end - start) as u8;
+        piece.map_len = chars[start..end]
+            .iter()
+            .filter(|ch| ch.contributes_to_shaping)
+            .count() as u8;
+        piece.force_normalize = parent.force_normalize;
+        piece.script = parent.script;
+        piece.start = if start == 0 {
+            parent.start
+        } else {
+            chars[start].offset
+        };
+        piece.end = if end == chars.len() {
+            parent.end
+        } else {
+            chars[end].offset
+        };
+        if start == 0 {
+            piece.info.merge_boundary(parent.info.boundary());
+            if parent.info.is_broken() {
+                piece.info.set_broken();
+            }
+            piece.continuation = parent.continuation;
+        }
+        piece.continued = end == chars.len() && parent.continued;
+        let base = chars[start..end]
+            .iter()
+            .map(|ch| (ch.ch, ch.ch.properties().cluster_class()))
+            .find(|(_, (kind, _))| *kind != PP);
+        if let Some((ch, (kind, emoji))) = base {
+            if emoji {
+                piece.info.set_emoji(if parent.info.is_emoji() {
+                    parent.info.emoji()
+                } else {
+                    Emoji::Default
+                });
+            } else if matches!(kind, EX | SM | ZWJ) && !piece.continuation {
+                piece.info.set_broken();
+            } else {
+                piece.info.set_space_from_char(ch, CharInfo::from(ch));
+            }
+        }
+        piece.resolve_newline();
+        piece.resolve_regional_indicators();
+        Some(piece)
+    }
+}
+
 /// Iterative status of mapping a character cluster to nominal glyph identifiers.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Status {
@@ -293,17 +722,17 @@ enum FormState {
 }
 
 #[derive(Copy, Clone)]
-struct Form {
-    pub chars: [Char; MAX_CLUSTER_SIZE],
+struct Form<const N: usize> {
+    pub chars: [Char; N],
     pub len: u8,
     pub map_len: u8,
     pub state: FormState,
 }
 
-impl Form {
+impl<const N: usize> Form<N> {
     fn new() -> Self {
         Self {
-            chars: [DEFAULT_CHAR; MAX_CLUSTER_SIZE],
+            chars: [DEFAULT_CHAR; N],
             len: 0,
             map_len: 0,
             state: FormState::None,
@@ -329,12 +758,7 @@ impl Form {
             .max(1);
     }
 
-    fn map(
-        &mut self,
-        f: &impl Fn(char) -> u32,
-        glyphs: &mut [u32; MAX_CLUSTER_SIZE],
-        best_ratio: f32,
-    ) -> f32 {
+    fn map(&mut self, f: &impl Fn(char) -> u32, glyphs: &mut [u32], best_ratio: f32) -> f32 {
         Mapper {
             chars: &mut self.chars[..self.len as usize],
             map_len: self.map_len,
@@ -349,18 +773,16 @@ struct Mapper<'a> {
 }
 
 impl<'a> Mapper<'a> {
-    fn map(
-        &mut self,
-        f: &impl Fn(char) -> u32,
-        glyphs: &mut [u32; MAX_CLUSTER_SIZE],
-        best_ratio: f32,
-    ) -> f32 {
+    fn map(&mut self, f: &impl Fn(char) -> u32, glyphs: &mut [u32], best_ratio: f32) -> f32 {
         if self.map_len == 0 {
             return 1.;
         }
         let mut mapped = 0;
         for (c, g) in self.chars.iter().zip(glyphs.iter_mut()) {
-            if !c.contributes_to_shaping {
+            if c.variant {
+                *g = c.glyph_id;
+                mapped += 1;
+            } else if !c.contributes_to_shaping {
                 *g = f(c.ch);
                 if self.map_len == 1 {
                     mapped += 1;
@@ -390,6 +812,7 @@ const DEFAULT_CHAR: Char = Char {
     ignorable: false,
     contributes_to_shaping: true,
     glyph_id: 0,
+    variant: false,
     data: 0,
     offset: 0,
 };
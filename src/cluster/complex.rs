@@ -2,8 +2,8 @@
 //! specification.
 
 use super::unicode_data::{ClusterBreak, UseClass};
-use super::{Category, Codepoint, Script};
-use super::{Cluster, Emoji, ShapeClass, SourceChar, Whitespace, MAX_CLUSTER_SIZE};
+use super::{Category, Codepoint};
+use super::{Cluster, Emoji, ShapeClass, SourceChar, Syllable, Whitespace};
 
 type Kind = UseClass;
 
@@ -13,14 +13,15 @@ pub struct ComplexState<I> {
     cur_kind: Kind,
     cur_emoji: bool,
     done: bool,
+    separate: bool,
 }
 
 impl<I> ComplexState<I>
 where
     I: Iterator<Item = SourceChar> + Clone,
 {
-    pub fn new(script: Script, chars: I) -> Self {
-        let mut chars = Tokens::new(script, chars);
+    pub fn new(chars: I) -> Self {
+        let mut chars = Tokens::new(chars);
         if let Some((first, kind, emoji)) = chars.by_ref().next() {
             Self {
                 chars,
@@ -28,6 +29,7 @@ where
                 cur_kind: kind,
                 cur_emoji: emoji,
                 done: false,
+                separate: false,
             }
         } else {
             Self {
@@ -36,11 +38,22 @@ where
                 cur_kind: UseClass::O,
                 cur_emoji: false,
                 done: true,
+                separate: false,
             }
         }
     }
 
-    pub fn next(&mut self, cluster: &mut Cluster) -> bool {
+    /// Emits separable ignorables as clusters of their own by treating them
+    /// as other characters.
+    pub fn separate_ignorables(&mut self) {
+        self.separate = true;
+        if self.cur.is_separable() {
+            self.cur_kind = Kind::O;
+            self.cur_emoji = false;
+        }
+    }
+
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
         if self.done {
             return false;
         }
@@ -49,17 +62,17 @@ where
     }
 }
 
-struct Parser<'a, I> {
+struct Parser<'a, I, const N: usize> {
     s: &'a mut ComplexState<I>,
-    cluster: &'a mut Cluster,
+    cluster: &'a mut Cluster<N>,
     vt: bool,
 }
 
-impl<'a, I> Parser<'a, I>
+impl<'a, I, const N: usize> Parser<'a, I, N>
 where
     I: Iterator<Item = SourceChar> + Clone,
 {
-    fn new(s: &'a mut ComplexState<I>, cluster: &'a mut Cluster) -> Self {
+    fn new(s: &'a mut ComplexState<I>, cluster: &'a mut Cluster<N>) -> Self {
         Self {
             s,
             cluster,
@@ -74,6 +87,7 @@ where
         }
         if self.emoji() {
             self.cluster.info_mut().set_emoji(Emoji::Default);
+            self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
             while self.emoji() {
                 self.accept_any_as(ShapeClass::Base)?;
                 if !self.parse_emoji_extension()? {
@@ -83,7 +97,16 @@ where
             return Some(());
         }
         match self.kind() {
+            _ if self.s.cur.info.cluster_break() == ClusterBreak::RI => {
+                // Regional indicators are paired as in the simple parser.
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Base)?;
+                if self.s.cur.info.cluster_break() == ClusterBreak::RI {
+                    self.accept_any_as(ShapeClass::Base)?;
+                }
+            }
             O => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
                 // This is not in the USE spec, but added to support uniform
                 // clustering of CRLF across the parsers.
                 match self.s.cur.ch {
@@ -99,7 +122,9 @@ where
                         self.accept_any_as(ShapeClass::Control)?;
                     }
                     _ => {
-                        self.cluster.info_mut().set_space_from_char(self.s.cur.ch);
+                        self.cluster
+                            .info_mut()
+                            .set_space_from_char(self.s.cur.ch, self.s.cur.info);
                         let class = match self.s.cur.info.category() {
                             Category::Format => match self.s.cur.ch as u32 {
                                 0x200C => ShapeClass::Zwnj,
@@ -114,6 +139,7 @@ where
                 }
             }
             IND | Rsv | WJ => {
+                self.cluster.info_mut().set_syllable(Syllable::Independent);
                 self.accept_any_as(ShapeClass::Base)?;
                 self.accept_as(VS, ShapeClass::Vs)?;
             }
@@ -130,11 +156,13 @@ where
                 self.parse_standard(is_potential_symbol)?;
             }
             N => {
+                self.cluster.info_mut().set_syllable(Syllable::Numeral);
                 self.accept_any_as(ShapeClass::Base)?;
                 self.accept_as(VS, ShapeClass::Vs)?;
                 while self.parse_halant_number()? {}
             }
             S => {
+                self.cluster.info_mut().set_syllable(Syllable::Symbol);
                 self.accept_any_as(ShapeClass::Base)?;
                 self.accept_as(VS, ShapeClass::Vs)?;
                 self.accept_zero_or_many(SMAbv)?;
@@ -151,11 +179,13 @@ where
         use UseClass::*;
         match self.kind() {
             B | GB => {
+                self.cluster.info_mut().set_syllable(Syllable::Standard);
                 self.accept_any_as(ShapeClass::Base)?;
                 self.parse_standard_tail(is_potential_symbol)?;
             }
             _ => {
                 self.cluster.info_mut().set_broken();
+                self.cluster.info_mut().set_syllable(Syllable::Broken);
                 self.accept_any_as(self.kind().to_shape_class())?;
             }
         }
@@ -167,6 +197,7 @@ where
         self.accept_as(VS, ShapeClass::Vs)?;
         let k = self.kind();
         if is_potential_symbol && (k == SMAbv || k == SMBlw) {
+            self.cluster.info_mut().set_syllable(Syllable::Symbol);
             self.accept_zero_or_many(SMAbv)?;
             self.accept_zero_or_many(SMBlw)?;
             return Some(());
@@ -229,14 +260,14 @@ where
             }
             H => {
                 self.vt = true;
-                if self.s.chars.script == Script::Khmer && self.s.cur.ch == '\u{17d2}' {
-                    self.accept_any_as(ShapeClass::Other)?;
-                } else {
-                    self.accept_any_as(ShapeClass::Halant)?;
-                }
+                self.cluster
+                    .info_mut()
+                    .set_syllable(Syllable::HalantTerminated);
+                self.accept_any_as(ShapeClass::Halant)?;
                 match self.kind() {
                     B => {
                         self.vt = false;
+                        self.cluster.info_mut().set_syllable(Syllable::Standard);
                         self.accept_any_as(ShapeClass::Base)?;
                         self.accept_as(VS, ShapeClass::Vs)?;
                         self.accept_zero_or_many(CMAbv)?;
@@ -274,6 +305,9 @@ where
     fn parse_emoji_extension(&mut self) -> Option<bool> {
         use ClusterBreak::*;
         loop {
+            if self.s.separate && self.s.cur.is_separable() {
+                break;
+            }
             match self.s.cur.info.cluster_break() {
                 EX => match self.s.cur.ch as u32 {
                     0x200C => self.accept_any_as(ShapeClass::Zwnj)?,
@@ -301,7 +335,7 @@ where
 
     #[inline(always)]
     fn emoji(&self) -> bool {
-        self.s.cur_emoji
+        cfg!(feature = "emoji") && self.s.cur_emoji
     }
 
     #[inline(always)]
@@ -339,25 +373,38 @@ where
     }
 
     fn accept_any(&mut self) -> Option<()> {
+        self.reserve()?;
         self.cluster.push(&self.s.cur, ShapeClass::Other);
         self.advance()?;
         Some(())
     }
 
     fn accept_any_as(&mut self, as_class: ShapeClass) -> Option<()> {
+        self.reserve()?;
         self.cluster.push(&self.s.cur, as_class);
         self.advance()?;
         Some(())
     }
 
-    fn advance(&mut self) -> Option<()> {
-        if self.cluster.len() as usize == MAX_CLUSTER_SIZE {
+    /// Terminates the cluster if it has reached capacity, leaving the
+    /// current character for a continuation cluster.
+    fn reserve(&mut self) -> Option<()> {
+        if self.cluster.is_full() {
+            self.cluster.set_continued();
             return None;
         }
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<()> {
         if let Some((input, kind, emoji)) = self.s.chars.next() {
             self.s.cur = input;
             self.s.cur_emoji = emoji;
             self.s.cur_kind = kind;
+            if self.s.separate && input.is_separable() {
+                self.s.cur_kind = Kind::O;
+                self.s.cur_emoji = false;
+            }
             if input.ch == '\u{34f}' {
                 self.accept_any_as(ShapeClass::Other)?;
             }
@@ -391,17 +438,15 @@ struct Tokens<I> {
     decomp: [(SourceChar, UseClass); 3],
     decomp_len: u8,
     decomp_offset: u8,
-    script: Script,
 }
 
 impl<I> Tokens<I> {
-    fn new(script: Script, iter: I) -> Self {
+    fn new(iter: I) -> Self {
         Self {
             iter,
             decomp: [(SourceChar::default(), UseClass::O); 3],
             decomp_len: 0,
             decomp_offset: 0,
-            script,
         }
     }
 }
@@ -419,7 +464,7 @@ where
             Some((input, class, false))
         } else {
             let input = self.iter.next()?;
-            let (class, needs_decomp, emoji) = input.info.use_class();
+            let (class, needs_decomp, emoji) = input.info.use_class_info();
             if needs_decomp {
                 self.decomp_offset = 0;
                 self.decomp_len = 0;
@@ -429,7 +474,7 @@ where
                         break;
                     }
                     let props = c.properties();
-                    let (class, ..) = props.use_class();
+                    let class = props.use_class();
                     let c2 = SourceChar {
                         ch: *c,
                         info: input.info.with_properties(props),
@@ -440,25 +485,6 @@ where
                 }
                 //self.decomp[..self.decomp_len as usize].reverse(); //.sort_unstable_by(|a, b| a.3.cmp(&b.3));
                 return self.next();
-            } else if self.script == Script::Khmer {
-                match input.ch as u32 {
-                    0x17BE | 0x17BF | 0x17C0 | 0x17C4 | 0x17C5 => {
-                        let a = '\u{17C1}';
-                        let props = a.properties();
-                        let a_class = props.use_class().0;
-                        let a = SourceChar {
-                            ch: a,
-                            info: input.info.with_properties(props),
-                            ..input
-                        };
-                        self.decomp[0] = (a, a_class);
-                        self.decomp[1] = (input, class);
-                        self.decomp_len = 2;
-                        self.decomp_offset = 0;
-                        return self.next();
-                    }
-                    _ => {}
-                }
             }
             Some((input, class, emoji))
         }
@@ -0,0 +1,104 @@
+//! Change detection between cluster streams.
+
+use super::{Cluster, ClusterInfo};
+
+use core::ops::Range;
+
+/// Compact summary of a cluster for detecting changes between two cluster
+/// streams of the same text.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ClusterSpan {
+    /// Source range of the cluster in code units.
+    pub range: Range<usize>,
+    /// Cluster information.
+    pub info: ClusterInfo,
+    /// Hash of the characters and shape classes in the cluster.
+    pub hash: u32,
+}
+
+impl ClusterSpan {
+    /// Creates a new summary for the specified cluster.
+    pub fn new<const N: usize>(cluster: &Cluster<N>) -> Self {
+        let mut hash = FNV_OFFSET;
+        for ch in cluster.chars() {
+            for byte in (ch.ch as u32).to_le_bytes().iter() {
+                hash = (hash ^ *byte as u32).wrapping_mul(FNV_PRIME);
+            }
+            hash = (hash ^ ch.shape_class as u32).wrapping_mul(FNV_PRIME);
+        }
+        Self {
+            range: cluster.range(),
+            info: cluster.info(),
+            hash,
+        }
+    }
+
+    fn is_shifted(&self, other: &Self, delta: isize) -> bool {
+        self.info == other.info
+            && self.hash == other.hash
+            && shift(self.range.start, delta) == Some(other.range.start)
+            && shift(self.range.end, delta) == Some(other.range.end)
+    }
+}
+
+impl<const N: usize> From<&Cluster<N>> for ClusterSpan {
+    fn from(cluster: &Cluster<N>) -> Self {
+        Self::new(cluster)
+    }
+}
+
+/// Ranges of clusters that differ between two cluster streams.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ClusterDiff {
+    /// Range of changed clusters in the old stream.
+    pub old: Range<usize>,
+    /// Range of replacement clusters in the new stream.
+    pub new: Range<usize>,
+}
+
+impl ClusterDiff {
+    /// Returns true if the cluster streams are identical.
+    pub fn is_empty(&self) -> bool {
+        self.old.is_empty() && self.new.is_empty()
+    }
+}
+
+/// Compares two cluster streams covering the full text before and after an
+/// edit and returns the smallest contiguous ranges of clusters that must be
+/// replaced to transform the old stream into the new one.
+///
+/// Clusters before the change must match exactly, while clusters after the
+/// change must match with source ranges shifted by the change in length of
+/// the text. Clusters outside of the returned ranges may be retained by a
+/// shaping cache (with adjusted offsets for those that follow).
+pub fn diff_clusters(old: &[ClusterSpan], new: &[ClusterSpan]) -> ClusterDiff {
+    let prefix = old
+        .iter()
+        .zip(new)
+        .take_while(|(a, b)| a.is_shifted(b, 0))
+        .count();
+    let old_end = old.last().map(|span| span.range.end).unwrap_or(0);
+    let new_end = new.last().map(|span| span.range.end).unwrap_or(0);
+    let delta = new_end as isize - old_end as isize;
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a.is_shifted(b, delta))
+        .count();
+    ClusterDiff {
+        old: prefix..old.len() - suffix,
+        new: prefix..new.len() - suffix,
+    }
+}
+
+fn shift(offset: usize, delta: isize) -> Option<usize> {
+    if delta < 0 {
+        offset.checked_sub(delta.unsigned_abs())
+    } else {
+        offset.checked_add(delta as usize)
+    }
+}
+
+const FNV_OFFSET: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
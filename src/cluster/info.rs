@@ -6,6 +6,7 @@ use core::fmt;
 /// Information about a character including unicode properties and boundary
 /// analysis.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
 pub struct CharInfo(pub(crate) Properties);
 
 impl CharInfo {
@@ -35,6 +36,20 @@ impl CharInfo {
         LineBoundary::from_raw(self.0.boundary() & 0b11)
     }
 
+    /// Sets whether the character is a word boundary. This allows merging
+    /// the results of external boundary analysis.
+    pub fn set_word_boundary(&mut self, is_word_boundary: bool) {
+        let bits = self.0.boundary() & !0b100 | (is_word_boundary as u16) << 2;
+        self.0.set_boundary(bits);
+    }
+
+    /// Sets the line boundary state for the character. This allows merging
+    /// the results of external boundary analysis.
+    pub fn set_line_boundary(&mut self, line_boundary: LineBoundary) {
+        let bits = self.0.boundary() & !0b11 | line_boundary as u16;
+        self.0.set_boundary(bits);
+    }
+
     pub(crate) fn with_properties(self, props: Properties) -> Self {
         Self(props.with_boundary(self.0.boundary()))
     }
@@ -78,16 +93,103 @@ impl From<&Properties> for CharInfo {
     }
 }
 
+/// Generates the properties of an arbitrary character with arbitrary
+/// boundary analysis.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for CharInfo {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::new(
+            Properties::from(char::arbitrary(u)?),
+            bool::arbitrary(u)?,
+            LineBoundary::arbitrary(u)?,
+        ))
+    }
+}
+
+// Like properties, character information can be cast to bytes but must be
+// validated when cast from bytes.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for CharInfo {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for CharInfo {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for CharInfo {
+    type Bits = u16;
+
+    fn is_valid_bit_pattern(bits: &u16) -> bool {
+        Properties::from_raw(*bits).is_some()
+    }
+}
+
+/// Character information is serialized as a code point with the same
+/// properties followed by the word and line boundary states. Properties are
+/// restored from the Unicode data of the crate when deserializing, so the
+/// serialized form does not depend on the layout of the property tables.
+#[cfg(feature = "serde")]
+impl serde::Serialize for CharInfo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = (
+            self.0.codepoint(),
+            self.is_word_boundary(),
+            self.line_boundary(),
+        );
+        serde::Serialize::serialize(&value, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CharInfo {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (codepoint, is_word_boundary, line_boundary) =
+            <(u32, bool, LineBoundary) as serde::Deserialize>::deserialize(deserializer)?;
+        let properties = Properties::from_codepoint(codepoint).ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Unsigned(codepoint as u64),
+                &"a code point",
+            )
+        })?;
+        Ok(Self::new(properties, is_word_boundary, line_boundary))
+    }
+}
+
 const BOUND_SHIFT: u16 = 13;
 const SPACE_SHIFT: u16 = 1;
+const SYLLABLE_SHIFT: u16 = 4;
 const EMOJI_SHIFT: u16 = 8;
+const NEWLINE_SHIFT: u16 = 10;
+const UNPAIRED_RI_BIT: u16 = 1 << 12;
 const SPACE_MASK: u16 = 0b111;
+const SYLLABLE_MASK: u16 = 0b1111;
 const EMOJI_MASK: u16 = 0b11;
+const NEWLINE_MASK: u16 = 0b11;
 
 /// Information about a cluster including content properties and boundary analysis.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
 pub struct ClusterInfo(pub u16);
 
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for ClusterInfo {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for ClusterInfo {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ClusterInfo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ClusterInfo {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u16 as serde::Deserialize>::deserialize(deserializer).map(Self)
+    }
+}
+
 impl ClusterInfo {
     /// Returns true if the cluster is missing an appropriate base
     /// character.
@@ -115,6 +217,30 @@ impl ClusterInfo {
         Whitespace::from_raw(self.0 >> SPACE_SHIFT & SPACE_MASK)
     }
 
+    /// Returns the line terminator of a cluster with
+    /// [`Whitespace::Newline`] content.
+    pub fn newline(self) -> Newline {
+        Newline::from_raw(self.0 >> NEWLINE_SHIFT & NEWLINE_MASK)
+    }
+
+    /// Returns true if the cluster ends with a regional indicator that is
+    /// not paired with another.
+    ///
+    /// Only pairs of regional indicators form a cluster, so the state of a
+    /// run depends on all preceding indicators. When text is parsed in
+    /// separate chunks, such a cluster at the end of a chunk may pair with
+    /// an indicator at the start of the next chunk, and parsing should
+    /// resume at the start of the cluster.
+    pub fn is_unpaired_regional_indicator(self) -> bool {
+        self.0 & UNPAIRED_RI_BIT != 0
+    }
+
+    /// Returns the syllable type of the cluster. This is only available for
+    /// clusters produced by the parsers for complex scripts.
+    pub fn syllable(self) -> Syllable {
+        Syllable::from_raw(self.0 >> SYLLABLE_SHIFT & SYLLABLE_MASK)
+    }
+
     /// Returns true if the cluster is a word or line boundary.
     pub fn is_boundary(self) -> bool {
         (self.0 >> BOUND_SHIFT) != 0
@@ -134,24 +260,50 @@ impl ClusterInfo {
         self.0 |= 1;
     }
 
+    pub(super) fn clear_broken(&mut self) {
+        self.0 &= !1;
+    }
+
     pub(super) fn set_emoji(&mut self, emoji: Emoji) {
         self.0 = self.0 & !(EMOJI_MASK << EMOJI_SHIFT) | (emoji as u16) << EMOJI_SHIFT;
     }
 
+    #[cfg(feature = "complex-scripts")]
+    pub(super) fn set_syllable(&mut self, syllable: Syllable) {
+        self.0 = self.0 & !(SYLLABLE_MASK << SYLLABLE_SHIFT) | (syllable as u16) << SYLLABLE_SHIFT;
+    }
+
     pub(super) fn set_space(&mut self, space: Whitespace) {
         self.0 = self.0 & !(SPACE_MASK << SPACE_SHIFT) | (space as u16) << SPACE_SHIFT;
     }
 
+    pub(super) fn set_newline(&mut self, newline: Newline) {
+        self.0 = self.0 & !(NEWLINE_MASK << NEWLINE_SHIFT) | (newline as u16) << NEWLINE_SHIFT;
+    }
+
+    pub(super) fn set_unpaired_regional_indicator(&mut self) {
+        self.0 |= UNPAIRED_RI_BIT;
+    }
+
     #[inline]
-    pub(super) fn set_space_from_char(&mut self, ch: char) {
+    pub(super) fn set_space_from_char(&mut self, ch: char, info: CharInfo) {
         match ch {
             ' ' => self.set_space(Whitespace::Space),
             '\u{a0}' => self.set_space(Whitespace::NoBreakSpace),
             '\t' => self.set_space(Whitespace::Tab),
-            _ => {}
+            '\r' | '\n' => self.set_space(Whitespace::Newline),
+            _ => {
+                if info.is_whitespace() {
+                    self.set_space(Whitespace::Other);
+                }
+            }
         }
     }
 
+    pub(super) fn boundary(self) -> u16 {
+        self.0 >> BOUND_SHIFT
+    }
+
     pub(super) fn merge_boundary(&mut self, boundary: u16) {
         let bits = ((self.0 >> BOUND_SHIFT) | boundary) << BOUND_SHIFT;
         self.0 = ((self.0 << 3) >> 3) | bits;
@@ -199,6 +351,9 @@ pub enum Emoji {
     Color = 3,
 }
 
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(Emoji, Color);
+
 impl Emoji {
     #[inline]
     fn from_raw(bits: u16) -> Self {
@@ -224,12 +379,16 @@ pub enum Whitespace {
     NoBreakSpace = 2,
     /// Horizontal tab.
     Tab = 3,
-    /// Newline (CR, LF, or CRLF).
+    /// Newline (CR, LF, or CRLF). The terminator is available from
+    /// [`ClusterInfo::newline`].
     Newline = 4,
     /// Other space.
     Other = 5,
 }
 
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(Whitespace, Other);
+
 impl Whitespace {
     /// Returns true for space or no break space.
     pub fn is_space_or_nbsp(self) -> bool {
@@ -249,3 +408,101 @@ impl Whitespace {
         }
     }
 }
+
+/// Line terminator of a newline cluster.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Newline {
+    /// Not a newline.
+    None = 0,
+    /// Carriage return (U+000D).
+    Cr = 1,
+    /// Line feed (U+000A).
+    Lf = 2,
+    /// Carriage return followed by line feed.
+    CrLf = 3,
+}
+
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(Newline, CrLf);
+
+impl Newline {
+    /// Returns the number of characters in the terminator.
+    pub fn len(self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Cr | Self::Lf => 1,
+            Self::CrLf => 2,
+        }
+    }
+
+    /// Returns true if the cluster is not a newline.
+    pub fn is_empty(self) -> bool {
+        self == Self::None
+    }
+
+    #[inline]
+    fn from_raw(bits: u16) -> Self {
+        match bits & 0b11 {
+            0 => Self::None,
+            1 => Self::Cr,
+            2 => Self::Lf,
+            3 => Self::CrLf,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Syllable type of a cluster produced by a complex script parser.
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum Syllable {
+    /// No syllable type.
+    None = 0,
+    /// Standard syllable with a consonant or vowel base.
+    Standard = 1,
+    /// Syllable that ends with a halant.
+    HalantTerminated = 2,
+    /// Independent cluster.
+    Independent = 3,
+    /// Numeral cluster.
+    Numeral = 4,
+    /// Symbol cluster.
+    Symbol = 5,
+    /// Punctuation cluster.
+    Punctuation = 6,
+    /// Syllable that is missing an appropriate base character.
+    Broken = 7,
+    /// Cluster that is outside the syllable structure of the script.
+    NonSyllable = 8,
+}
+
+impl Syllable {
+    #[inline]
+    fn from_raw(bits: u16) -> Self {
+        match bits & 0b1111 {
+            1 => Self::Standard,
+            2 => Self::HalantTerminated,
+            3 => Self::Independent,
+            4 => Self::Numeral,
+            5 => Self::Symbol,
+            6 => Self::Punctuation,
+            7 => Self::Broken,
+            8 => Self::NonSyllable,
+            _ => Self::None,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialized_codepoints() {
+        assert!(Properties::from_codepoint(0x110000).is_none());
+        for props in crate::unicode::data::records() {
+            assert!(Properties::from_codepoint(props.codepoint()) == Some(props));
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! Parser for Khmer clusters based on the OpenType Khmer shaping
+//! specification.
+
+use super::unicode_data::ClusterBreak;
+use super::{Category, Codepoint, Properties, UseClass};
+use super::{Cluster, Emoji, ShapeClass, SourceChar, Syllable, Whitespace};
+
+type Kind = KhmerClass;
+
+/// Character classes for Khmer syllables.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum KhmerClass {
+    /// Other character.
+    O,
+    /// Consonant.
+    C,
+    /// Independent vowel.
+    V,
+    /// Placeholder such as the dotted circle.
+    GB,
+    /// Coeng (subscript sign).
+    Coeng,
+    /// Robat and the register shifters.
+    Robatic,
+    /// Marks that may appear after the consonant stack or between vowels.
+    Xgroup,
+    /// Final marks.
+    Ygroup,
+    /// Pre-base dependent vowel.
+    VPre,
+    /// Above base dependent vowel.
+    VAbv,
+    /// Below base dependent vowel.
+    VBlw,
+    /// Post-base dependent vowel.
+    VPst,
+    /// Zero width joiner or non-joiner.
+    Joiner,
+    /// Variation selector.
+    VS,
+}
+
+impl KhmerClass {
+    fn from_char(ch: char, props: Properties) -> Self {
+        use KhmerClass::*;
+        match ch as u32 {
+            0x1780..=0x17A2 => C,
+            0x17A3..=0x17B3 => V,
+            0x17B4 | 0x17B5 | 0x17B7..=0x17BA => VAbv,
+            0x17B6 | 0x17BE..=0x17C0 | 0x17C4 | 0x17C5 => VPst,
+            0x17BB..=0x17BD => VBlw,
+            0x17C1..=0x17C3 => VPre,
+            0x17C6 | 0x17CB | 0x17CD..=0x17D1 => Xgroup,
+            0x17C7 | 0x17C8 | 0x17D3 | 0x17DD => Ygroup,
+            0x17C9 | 0x17CA | 0x17CC => Robatic,
+            0x17D2 => Coeng,
+            0x200C | 0x200D => Joiner,
+            0xFE00..=0xFE0F => VS,
+            0x00A0 | 0x25CC => GB,
+            _ => {
+                if props.use_class() == UseClass::GB {
+                    GB
+                } else {
+                    O
+                }
+            }
+        }
+    }
+
+    fn to_shape_class(self) -> ShapeClass {
+        match self {
+            Self::C | Self::V | Self::GB => ShapeClass::Base,
+            Self::VPre => ShapeClass::VPre,
+            _ => ShapeClass::Other,
+        }
+    }
+}
+
+pub struct KhmerState<I> {
+    chars: Tokens<I>,
+    cur: SourceChar,
+    cur_kind: Kind,
+    cur_emoji: bool,
+    done: bool,
+    separate: bool,
+}
+
+impl<I> KhmerState<I>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+{
+    pub fn new(chars: I) -> Self {
+        let mut chars = Tokens::new(chars);
+        if let Some((first, kind, emoji)) = chars.by_ref().next() {
+            Self {
+                chars,
+                cur: first,
+                cur_kind: kind,
+                cur_emoji: emoji,
+                done: false,
+                separate: false,
+            }
+        } else {
+            Self {
+                chars,
+                cur: SourceChar::default(),
+                cur_kind: KhmerClass::O,
+                cur_emoji: false,
+                done: true,
+                separate: false,
+            }
+        }
+    }
+
+    /// Emits separable ignorables as clusters of their own by treating them
+    /// as other characters.
+    pub fn separate_ignorables(&mut self) {
+        self.separate = true;
+        if self.cur.is_separable() {
+            self.cur_kind = Kind::O;
+            self.cur_emoji = false;
+        }
+    }
+
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
+        if self.done {
+            return false;
+        }
+        Parser::new(self, cluster).parse();
+        true
+    }
+}
+
+struct Parser<'a, I, const N: usize> {
+    s: &'a mut KhmerState<I>,
+    cluster: &'a mut Cluster<N>,
+}
+
+impl<'a, I, const N: usize> Parser<'a, I, N>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+{
+    fn new(s: &'a mut KhmerState<I>, cluster: &'a mut Cluster<N>) -> Self {
+        Self { s, cluster }
+    }
+
+    fn parse(&mut self) -> Option<()> {
+        use KhmerClass::*;
+        if self.s.done {
+            return Some(());
+        }
+        if self.emoji() {
+            self.cluster.info_mut().set_emoji(Emoji::Default);
+            self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+            while self.emoji() {
+                self.accept_any_as(ShapeClass::Base)?;
+                if !self.parse_emoji_extension()? {
+                    break;
+                }
+            }
+            return Some(());
+        }
+        match self.kind() {
+            _ if self.s.cur.info.cluster_break() == ClusterBreak::RI => {
+                // Regional indicators are paired as in the simple parser.
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Base)?;
+                if self.s.cur.info.cluster_break() == ClusterBreak::RI {
+                    self.accept_any_as(ShapeClass::Base)?;
+                }
+            }
+            O => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                // This is not in the Khmer spec, but added to support uniform
+                // clustering of CRLF across the parsers.
+                match self.s.cur.ch {
+                    '\r' => {
+                        self.cluster.info_mut().set_space(Whitespace::Newline);
+                        self.accept_any_as(ShapeClass::Control)?;
+                        if self.s.cur.ch == '\n' {
+                            self.accept_any_as(ShapeClass::Control)?;
+                        }
+                    }
+                    '\n' => {
+                        self.cluster.info_mut().set_space(Whitespace::Newline);
+                        self.accept_any_as(ShapeClass::Control)?;
+                    }
+                    _ => {
+                        self.cluster
+                            .info_mut()
+                            .set_space_from_char(self.s.cur.ch, self.s.cur.info);
+                        let class = match self.s.cur.info.category() {
+                            Category::Control | Category::Format => ShapeClass::Control,
+                            _ => ShapeClass::Base,
+                        };
+                        self.accept_any_as(class)?;
+                    }
+                }
+            }
+            C | V | GB => {
+                self.cluster.info_mut().set_syllable(Syllable::Standard);
+                self.cluster
+                    .info_mut()
+                    .set_space_from_char(self.s.cur.ch, self.s.cur.info);
+                self.accept_any_as(ShapeClass::Base)?;
+                self.accept_as(VS, ShapeClass::Vs)?;
+                self.parse_robatic()?;
+                self.parse_syllable_tail()?;
+            }
+            Joiner => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_joiner()?;
+            }
+            VS => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Vs)?;
+            }
+            _ => {
+                // A syllable that begins with anything other than a consonant
+                // or placeholder is broken. Consume the remainder of the
+                // syllable so that the marks remain in a single cluster.
+                self.cluster.info_mut().set_broken();
+                self.cluster.info_mut().set_syllable(Syllable::Broken);
+                let len = self.cluster.len();
+                self.parse_syllable_tail()?;
+                if self.cluster.len() == len {
+                    self.accept_any_as(self.kind().to_shape_class())?;
+                }
+            }
+        }
+        None
+    }
+
+    /// Parses an optional robat or register shifter following a consonant,
+    /// possibly preceded by a joiner.
+    fn parse_robatic(&mut self) -> Option<()> {
+        use KhmerClass::*;
+        if self.kind() == Joiner && self.peek_kind() == Some(Robatic) {
+            self.accept_joiner()?;
+        }
+        self.accept(Robatic)?;
+        Some(())
+    }
+
+    /// Parses the subscript consonant stack, dependent vowels and final
+    /// marks.
+    fn parse_syllable_tail(&mut self) -> Option<()> {
+        use KhmerClass::*;
+        while self.kind() == Coeng {
+            self.accept_any()?;
+            match self.kind() {
+                C | V => {
+                    self.accept_any_as(ShapeClass::Base)?;
+                    self.parse_robatic()?;
+                }
+                _ => return Some(()),
+            }
+        }
+        self.parse_xgroup()?;
+        self.accept_as(VPre, ShapeClass::VPre)?;
+        self.parse_xgroup()?;
+        self.accept(VBlw)?;
+        self.parse_xgroup()?;
+        if self.kind() == Joiner && self.peek_kind() == Some(VAbv) {
+            self.accept_joiner()?;
+        }
+        self.accept(VAbv)?;
+        self.parse_xgroup()?;
+        self.accept(VPst)?;
+        self.parse_xgroup()?;
+        if self.accept(Coeng)? && matches!(self.kind(), C | V) {
+            self.accept_any_as(ShapeClass::Base)?;
+        }
+        self.accept_zero_or_many(Ygroup)?;
+        Some(())
+    }
+
+    /// Parses a sequence of marks, each of which may be preceded by
+    /// joiners.
+    fn parse_xgroup(&mut self) -> Option<()> {
+        use KhmerClass::*;
+        loop {
+            match self.kind() {
+                Xgroup => self.accept_any()?,
+                Joiner if self.peek_kind() == Some(Xgroup) => self.accept_joiner()?,
+                _ => break,
+            }
+        }
+        Some(())
+    }
+
+    fn parse_emoji_extension(&mut self) -> Option<bool> {
+        use ClusterBreak::*;
+        loop {
+            if self.s.separate && self.s.cur.is_separable() {
+                break;
+            }
+            match self.s.cur.info.cluster_break() {
+                EX => match self.s.cur.ch as u32 {
+                    0x200C => self.accept_any_as(ShapeClass::Zwnj)?,
+                    0xFE0F => {
+                        self.cluster.info_mut().set_emoji(Emoji::Color);
+                        self.cluster.note_char(&self.s.cur);
+                        self.advance()?;
+                    }
+                    0xFE0E => {
+                        self.cluster.info_mut().set_emoji(Emoji::Text);
+                        self.cluster.note_char(&self.s.cur);
+                        self.advance()?;
+                    }
+                    _ => self.accept_any_as(ShapeClass::Mark)?,
+                },
+                ZWJ => {
+                    self.accept_any_as(ShapeClass::Zwj)?;
+                    return Some(true);
+                }
+                _ => break,
+            }
+        }
+        Some(false)
+    }
+
+    #[inline(always)]
+    fn emoji(&self) -> bool {
+        cfg!(feature = "emoji") && self.s.cur_emoji
+    }
+
+    #[inline(always)]
+    fn kind(&self) -> Kind {
+        self.s.cur_kind
+    }
+
+    fn peek_kind(&self) -> Option<Kind> {
+        self.s.chars.clone().next().map(|(_, kind, _)| kind)
+    }
+
+    fn accept(&mut self, kind: Kind) -> Option<bool> {
+        self.accept_as(kind, ShapeClass::Other)
+    }
+
+    fn accept_as(&mut self, kind: Kind, as_class: ShapeClass) -> Option<bool> {
+        if self.s.cur_kind == kind {
+            self.accept_any_as(as_class)?;
+            Some(true)
+        } else {
+            Some(false)
+        }
+    }
+
+    fn accept_zero_or_many(&mut self, kind: Kind) -> Option<bool> {
+        let mut some = false;
+        while self.accept(kind)? {
+            some = true;
+        }
+        Some(some)
+    }
+
+    fn accept_joiner(&mut self) -> Option<()> {
+        let class = if self.s.cur.ch == '\u{200d}' {
+            ShapeClass::Zwj
+        } else {
+            ShapeClass::Zwnj
+        };
+        self.accept_any_as(class)
+    }
+
+    fn accept_any(&mut self) -> Option<()> {
+        self.reserve()?;
+        self.cluster.push(&self.s.cur, ShapeClass::Other);
+        self.advance()?;
+        Some(())
+    }
+
+    fn accept_any_as(&mut self, as_class: ShapeClass) -> Option<()> {
+        self.reserve()?;
+        self.cluster.push(&self.s.cur, as_class);
+        self.advance()?;
+        Some(())
+    }
+
+    /// Terminates the cluster if it has reached capacity, leaving the
+    /// current character for a continuation cluster.
+    fn reserve(&mut self) -> Option<()> {
+        if self.cluster.is_full() {
+            self.cluster.set_continued();
+            return None;
+        }
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<()> {
+        if let Some((input, kind, emoji)) = self.s.chars.next() {
+            self.s.cur = input;
+            self.s.cur_emoji = emoji;
+            self.s.cur_kind = kind;
+            if self.s.separate && input.is_separable() {
+                self.s.cur_kind = Kind::O;
+                self.s.cur_emoji = false;
+            }
+            if input.ch == '\u{34f}' {
+                self.accept_any()?;
+            }
+            Some(())
+        } else {
+            self.s.done = true;
+            None
+        }
+    }
+}
+
+/// Iterator that classifies characters and splits the two part vowels into
+/// a pre-base component (U+17C1) followed by the original character.
+#[derive(Clone)]
+struct Tokens<I> {
+    iter: I,
+    split: Option<(SourceChar, Kind)>,
+}
+
+impl<I> Tokens<I> {
+    fn new(iter: I) -> Self {
+        Self { iter, split: None }
+    }
+}
+
+impl<I> Iterator for Tokens<I>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+{
+    type Item = (SourceChar, Kind, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((input, kind)) = self.split.take() {
+            return Some((input, kind, false));
+        }
+        let input = self.iter.next()?;
+        let kind = KhmerClass::from_char(input.ch, input.info.properties());
+        match input.ch as u32 {
+            0x17BE | 0x17BF | 0x17C0 | 0x17C4 | 0x17C5 => {
+                let a = '\u{17C1}';
+                let props = a.properties();
+                let a = SourceChar {
+                    ch: a,
+                    info: input.info.with_properties(props),
+                    ..input
+                };
+                self.split = Some((input, kind));
+                Some((a, KhmerClass::from_char(a.ch, props), false))
+            }
+            _ => Some((input, kind, input.info.is_extended_pictographic())),
+        }
+    }
+}
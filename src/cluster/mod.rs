@@ -7,23 +7,39 @@ equivalent to Unicode grapheme clusters. More complex scripts, however,
 may produce shaping clusters that contain multiple graphemes.
 */
 
+mod batch;
 mod char;
 #[allow(clippy::module_inception)]
 mod cluster;
+#[cfg(feature = "complex-scripts")]
 mod complex;
+mod diff;
 mod info;
+#[cfg(feature = "complex-scripts")]
+mod khmer;
+#[cfg(feature = "complex-scripts")]
 mod myanmar;
 mod parse;
 mod simple;
+#[cfg(feature = "complex-scripts")]
+mod tibetan;
 
+#[cfg(feature = "bytemuck")]
+pub use self::char::PodChar;
 pub use self::{
+    batch::{BatchCount, ClusterBatch},
     char::{Char, ShapeClass, SourceChar},
-    cluster::{Cluster, Status, MAX_CLUSTER_SIZE},
-    info::{CharInfo, ClusterInfo, Emoji, Whitespace},
-    parse::Parser,
+    cluster::{Cluster, Graphemes, Status, MAX_CLUSTER_SIZE},
+    diff::{diff_clusters, ClusterDiff, ClusterSpan},
+    info::{CharInfo, ClusterInfo, Emoji, Newline, Syllable, Whitespace},
+    parse::{
+        source_char_indices, source_chars, source_chars_utf16, CharIndex, Parser, ParserOptions,
+        ReverseParser, SourceCharIndices, SourceChars, SourceCharsUtf16,
+    },
 };
 
 use super::unicode::*;
+#[cfg(feature = "complex-scripts")]
 use super::unicode_data;
 
 /// Artibrary user data that can be associated with a character throughout
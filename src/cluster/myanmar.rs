@@ -1,7 +1,7 @@
 //! Parser for Myanmar clusters.
 
 use super::unicode_data::{Category, ClusterBreak, MyanmarClass};
-use super::{Cluster, Emoji, ShapeClass, SourceChar, Whitespace, MAX_CLUSTER_SIZE};
+use super::{Cluster, Emoji, ShapeClass, SourceChar, Syllable, Whitespace};
 
 type Kind = MyanmarClass;
 
@@ -11,6 +11,7 @@ pub struct MyanmarState<I> {
     cur_kind: Kind,
     cur_emoji: bool,
     done: bool,
+    separate: bool,
 }
 
 impl<I> MyanmarState<I>
@@ -19,13 +20,14 @@ where
 {
     pub fn new(mut chars: I) -> Self {
         if let Some(first) = chars.by_ref().next() {
-            let (kind, emoji) = first.info.myanmar_class();
+            let (kind, emoji) = first.info.myanmar_class_info();
             Self {
                 chars,
                 cur: first,
                 cur_kind: kind,
                 cur_emoji: emoji,
                 done: false,
+                separate: false,
             }
         } else {
             Self {
@@ -34,11 +36,22 @@ where
                 cur_kind: MyanmarClass::O,
                 cur_emoji: false,
                 done: true,
+                separate: false,
             }
         }
     }
 
-    pub fn next(&mut self, cluster: &mut Cluster) -> bool {
+    /// Emits separable ignorables as clusters of their own by treating them
+    /// as other characters.
+    pub fn separate_ignorables(&mut self) {
+        self.separate = true;
+        if self.cur.is_separable() {
+            self.cur_kind = Kind::O;
+            self.cur_emoji = false;
+        }
+    }
+
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
         if self.done {
             return false;
         }
@@ -47,17 +60,17 @@ where
     }
 }
 
-struct Parser<'a, I> {
+struct Parser<'a, I, const N: usize> {
     s: &'a mut MyanmarState<I>,
-    cluster: &'a mut Cluster,
+    cluster: &'a mut Cluster<N>,
     vt: bool,
 }
 
-impl<'a, I> Parser<'a, I>
+impl<'a, I, const N: usize> Parser<'a, I, N>
 where
     I: Iterator<Item = SourceChar> + Clone,
 {
-    fn new(s: &'a mut MyanmarState<I>, cluster: &'a mut Cluster) -> Self {
+    fn new(s: &'a mut MyanmarState<I>, cluster: &'a mut Cluster<N>) -> Self {
         Self {
             s,
             cluster,
@@ -72,6 +85,7 @@ where
         }
         if self.emoji() {
             self.cluster.info_mut().set_emoji(Emoji::Default);
+            self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
             while self.emoji() {
                 self.accept_any_as(ShapeClass::Base)?;
                 if !self.parse_emoji_extension()? {
@@ -81,7 +95,16 @@ where
             return Some(());
         }
         match self.kind() {
+            _ if self.s.cur.info.cluster_break() == ClusterBreak::RI => {
+                // Regional indicators are paired as in the simple parser.
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Base)?;
+                if self.s.cur.info.cluster_break() == ClusterBreak::RI {
+                    self.accept_any_as(ShapeClass::Base)?;
+                }
+            }
             O => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
                 // This is not in the Myanmar spec, but added to support uniform
                 // clustering of CRLF across the parsers.
                 match self.s.cur.ch {
@@ -97,7 +120,9 @@ where
                         self.accept_any_as(ShapeClass::Control)?;
                     }
                     _ => {
-                        self.cluster.info_mut().set_space_from_char(self.s.cur.ch);
+                        self.cluster
+                            .info_mut()
+                            .set_space_from_char(self.s.cur.ch, self.s.cur.info);
                         let class = match self.s.cur.info.category() {
                             Category::Format => match self.s.cur.ch as u32 {
                                 0x200C => ShapeClass::Zwnj,
@@ -112,11 +137,19 @@ where
                 }
             }
             P | S | R | WJ | D0 => {
+                let syllable = match self.kind() {
+                    P => Syllable::Punctuation,
+                    S => Syllable::Symbol,
+                    D0 => Syllable::Numeral,
+                    _ => Syllable::Independent,
+                };
+                self.cluster.info_mut().set_syllable(syllable);
                 self.accept_any()?;
             }
             _ => {
+                self.cluster.info_mut().set_syllable(Syllable::Standard);
                 match self.s.cur.ch as u32 {
-                    0x1004 | 0x101B | 0x105A => {
+                    0x1004 | 0x101B | 0x105A if self.cluster.len() as usize + 3 <= N => {
                         let mut iter = self.s.chars.clone();
                         if let Some(b) = iter.next() {
                             if b.ch == '\u{103A}' {
@@ -169,6 +202,7 @@ where
                     }
                     _ => {
                         self.cluster.info_mut().set_broken();
+                        self.cluster.info_mut().set_syllable(Syllable::Broken);
                         self.accept_any()?;
                         return Some(());
                     }
@@ -183,10 +217,14 @@ where
         match self.kind() {
             H => {
                 self.vt = true;
+                self.cluster
+                    .info_mut()
+                    .set_syllable(Syllable::HalantTerminated);
                 self.accept_any_as(ShapeClass::Halant)?;
                 match self.kind() {
                     C | IV => {
                         self.vt = false;
+                        self.cluster.info_mut().set_syllable(Syllable::Standard);
                         self.accept_any_as(ShapeClass::Base)?;
                         self.accept_as(VS, ShapeClass::Vs)?;
                         Some(true)
@@ -240,6 +278,9 @@ where
     fn parse_emoji_extension(&mut self) -> Option<bool> {
         use ClusterBreak::*;
         loop {
+            if self.s.separate && self.s.cur.is_separable() {
+                break;
+            }
             match self.s.cur.info.cluster_break() {
                 EX => match self.s.cur.ch as u32 {
                     0x200C => self.accept_any_as(ShapeClass::Zwnj)?,
@@ -267,7 +308,7 @@ where
 
     #[inline(always)]
     fn emoji(&self) -> bool {
-        self.s.cur_emoji
+        cfg!(feature = "emoji") && self.s.cur_emoji
     }
 
     #[inline(always)]
@@ -305,26 +346,39 @@ where
     }
 
     fn accept_any(&mut self) -> Option<()> {
+        self.reserve()?;
         self.cluster.push(&self.s.cur, ShapeClass::Other);
         self.advance()?;
         Some(())
     }
 
     fn accept_any_as(&mut self, as_class: ShapeClass) -> Option<()> {
+        self.reserve()?;
         self.cluster.push(&self.s.cur, as_class);
         self.advance()?;
         Some(())
     }
 
-    fn advance(&mut self) -> Option<()> {
-        if self.cluster.len() as usize == MAX_CLUSTER_SIZE {
+    /// Terminates the cluster if it has reached capacity, leaving the
+    /// current character for a continuation cluster.
+    fn reserve(&mut self) -> Option<()> {
+        if self.cluster.is_full() {
+            self.cluster.set_continued();
             return None;
         }
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<()> {
         if let Some(input) = self.s.chars.next() {
-            let (kind, emoji) = input.info.myanmar_class();
+            let (kind, emoji) = input.info.myanmar_class_info();
             self.s.cur = input;
             self.s.cur_emoji = emoji;
             self.s.cur_kind = kind;
+            if self.s.separate && input.is_separable() {
+                self.s.cur_kind = Kind::O;
+                self.s.cur_emoji = false;
+            }
             if input.ch == '\u{34f}' {
                 self.accept_any()?;
             }
@@ -1,10 +1,27 @@
+use super::UserData;
+#[cfg(feature = "complex-scripts")]
 use super::{
-    complex::ComplexState, myanmar::MyanmarState, simple::SimpleState, Cluster, Script, SourceChar,
+    complex::ComplexState, khmer::KhmerState, myanmar::MyanmarState, tibetan::TibetanState,
 };
+use super::{simple::SimpleState, Cluster, Script, SourceChar};
+use super::{Category, CharInfo, ClusterBreak, Codepoint, Decompose, DecompositionType};
+use crate::locale::Locale;
+use crate::paragraph::{paragraph_info, ParagraphInfo};
+
+use core::char::{decode_utf16, DecodeUtf16, DecodeUtf16Error, REPLACEMENT_CHARACTER};
+use core::iter::{Copied, Map};
+use core::slice::Iter;
+use core::str::{CharIndices, Chars};
 
 /// Parser that accepts a sequence of characters and outputs character clusters.
-pub struct Parser<I> {
-    inner: Inner<I>,
+pub struct Parser<I, V = fn(char, char) -> Option<u32>, M = fn(char) -> u32> {
+    inner: Inner<Input<I>>,
+    script: Script,
+    variations: Option<V>,
+    glyphs: Option<M>,
+    /// End of the source range of the previous cluster if it reached
+    /// capacity.
+    continued: Option<usize>,
 }
 
 // enum Inner<I> {
@@ -13,12 +30,61 @@ pub struct Parser<I> {
 //     Complex(ComplexClusters<Filter<I>>),
 // }
 
+/// Options for cluster parsing.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct ParserOptions {
+    /// Emits default ignorable format characters, such as the zero width
+    /// joiner and non-joiner and the directional formatting characters, as
+    /// clusters of their own rather than merging them with adjacent
+    /// characters.
+    ///
+    /// This is intended for displaying invisible characters. It also splits
+    /// emoji ZWJ sequences and prevents joiners from taking part in the
+    /// syllables of complex scripts. Tag characters remain part of emoji
+    /// tag sequences.
+    pub separate_ignorables: bool,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for ParserOptions {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            separate_ignorables: bool::arbitrary(u)?,
+        })
+    }
+}
+
 enum Inner<I> {
     Simple(SimpleState<I>),
+    #[cfg(feature = "complex-scripts")]
     Myanmar(MyanmarState<I>),
+    #[cfg(feature = "complex-scripts")]
+    Khmer(KhmerState<I>),
+    #[cfg(feature = "complex-scripts")]
+    Tibetan(TibetanState<I>),
+    #[cfg(feature = "complex-scripts")]
     Complex(ComplexState<I>),
 }
 
+impl<I> Inner<I>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+{
+    fn separate_ignorables(&mut self) {
+        match self {
+            Inner::Simple(c) => c.separate_ignorables(),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Myanmar(c) => c.separate_ignorables(),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Khmer(c) => c.separate_ignorables(),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Tibetan(c) => c.separate_ignorables(),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Complex(c) => c.separate_ignorables(),
+        }
+    }
+}
+
 impl<I> Parser<I>
 where
     I: Iterator<Item = SourceChar> + Clone,
@@ -26,27 +92,760 @@ where
     /// Creates a new cluster parser for the specified script and iterator
     /// over tokens.
     pub fn new(script: Script, tokens: I) -> Self {
+        Self::with_input(script, Input::new(tokens, false))
+    }
+
+    /// Creates a new cluster parser for the specified script and iterator
+    /// over tokens that applies canonical decomposition to the input.
+    ///
+    /// Each decomposed character is emitted with the source offset, length
+    /// and user data of the original character. Marks are not reordered;
+    /// use [`Cluster::reorder_marks`] for a fully normalized sequence.
+    pub fn new_decomposed(script: Script, tokens: I) -> Self {
+        Self::with_input(script, Input::new(tokens, true))
+    }
+
+    fn with_input(script: Script, tokens: Input<I>) -> Self {
+        // Complex scripts fall back to grapheme clusters when the
+        // `complex-scripts` feature is disabled.
+        #[cfg(not(feature = "complex-scripts"))]
+        let inner = Inner::Simple(SimpleState::new(tokens));
+        #[cfg(feature = "complex-scripts")]
+        let inner = if script.is_complex() {
+            match script {
+                Script::Myanmar => Inner::Myanmar(MyanmarState::new(tokens)),
+                Script::Khmer => Inner::Khmer(KhmerState::new(tokens)),
+                Script::Tibetan => Inner::Tibetan(TibetanState::new(tokens)),
+                _ => Inner::Complex(ComplexState::new(tokens)),
+            }
+        } else {
+            Inner::Simple(SimpleState::new(tokens))
+        };
         Self {
-            inner: if script.is_complex() {
-                if script == Script::Myanmar {
-                    Inner::Myanmar(MyanmarState::new(tokens))
-                } else {
-                    Inner::Complex(ComplexState::new(script, tokens))
-                }
-            } else {
-                Inner::Simple(SimpleState::new(tokens))
-            },
+            inner,
+            script,
+            variations: None,
+            glyphs: None,
+            continued: None,
+        }
+    }
+}
+
+impl<I, V, M> Parser<I, V, M>
+where
+    I: Iterator<Item = SourceChar> + Clone,
+    V: FnMut(char, char) -> Option<u32>,
+    M: Fn(char) -> u32,
+{
+    /// Sets the options for cluster formation.
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        if options.separate_ignorables {
+            self.inner.separate_ignorables();
+        }
+        self
+    }
+
+    /// Sets a function that resolves variation sequences while parsing.
+    ///
+    /// When a variation selector follows another character in a cluster,
+    /// the function is invoked with the character and the selector and
+    /// should return the glyph identifier for the sequence (typically from
+    /// a format 14 cmap subtable) or `None` if the sequence is not supported.
+    /// A resolved glyph is assigned to the character which is marked as a
+    /// variant. The selector itself is dropped from the cluster in either
+    /// case.
+    pub fn with_variations<F>(self, f: F) -> Parser<I, F, M>
+    where
+        F: FnMut(char, char) -> Option<u32>,
+    {
+        Parser {
+            inner: self.inner,
+            script: self.script,
+            variations: Some(f),
+            glyphs: self.glyphs,
+            continued: self.continued,
+        }
+    }
+
+    /// Sets a function that maps characters to nominal glyph identifiers
+    /// while parsing.
+    ///
+    /// Each parsed cluster is mapped with [`Cluster::map`] so that the
+    /// glyph identifiers of the characters returned by
+    /// [`Cluster::mapped_chars`] are populated when [`next`](Self::next)
+    /// returns. Characters that were resolved from variation sequences
+    /// retain their variant glyphs.
+    pub fn with_glyphs<F>(self, f: F) -> Parser<I, V, F>
+    where
+        F: Fn(char) -> u32,
+    {
+        Parser {
+            inner: self.inner,
+            script: self.script,
+            variations: self.variations,
+            glyphs: Some(f),
+            continued: self.continued,
         }
     }
 
     /// Parses the next cluster.
     #[inline]
-    pub fn next(&mut self, cluster: &mut Cluster) -> bool {
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
         cluster.clear();
-        match self.inner {
+        let result = match self.inner {
             Inner::Simple(ref mut c) => c.next(cluster),
+            #[cfg(feature = "complex-scripts")]
             Inner::Myanmar(ref mut c) => c.next(cluster),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Khmer(ref mut c) => c.next(cluster),
+            #[cfg(feature = "complex-scripts")]
+            Inner::Tibetan(ref mut c) => c.next(cluster),
+            #[cfg(feature = "complex-scripts")]
             Inner::Complex(ref mut c) => c.next(cluster),
+        };
+        if result {
+            if let Some(end) = self.continued {
+                cluster.set_continuation(end);
+            }
+            self.continued = if cluster.is_continued() {
+                Some(cluster.range().end)
+            } else {
+                None
+            };
+            self.script = cluster.resolve_script(self.script);
+            cluster.resolve_newline();
+            cluster.resolve_regional_indicators();
+        }
+        if let Some(f) = &mut self.variations {
+            cluster.resolve_variations(f);
+        }
+        if let Some(f) = &self.glyphs {
+            cluster.map(f);
+        }
+        result
+    }
+}
+
+impl<'a> Parser<SourceChars<'a>> {
+    /// Creates a new cluster parser for the specified script and string.
+    /// Character properties and boundary analysis are computed internally.
+    pub fn from_str(script: Script, s: &'a str) -> Self {
+        Self::new(script, source_chars(s))
+    }
+
+    /// Creates a new cluster parser for the specified locale and string.
+    ///
+    /// The script is taken from the script subtag of the locale if present.
+    /// Otherwise, it is the script of the first character in the string that
+    /// belongs to a specific script.
+    pub fn from_str_with_locale(locale: &Locale, s: &'a str) -> Self {
+        let script = locale
+            .script()
+            .and_then(|tag| {
+                let tag = tag.as_bytes();
+                Script::from_iso15924_code([tag[0], tag[1], tag[2], tag[3]])
+            })
+            .or_else(|| {
+                s.chars().map(|ch| ch.script()).find(|script| {
+                    !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+                })
+            })
+            .unwrap_or(Script::Common);
+        Self::from_str(script, s)
+    }
+}
+
+impl<'a> Parser<SourceCharsUtf16<'a>> {
+    /// Creates a new cluster parser for the specified script and UTF-16
+    /// encoded text. Character offsets are reported in UTF-16 code units.
+    pub fn from_utf16(script: Script, s: &'a [u16]) -> Self {
+        Self::new(script, source_chars_utf16(s))
+    }
+}
+
+/// Parser that outputs character clusters in reverse order, starting from
+/// the end of a sequence of characters.
+///
+/// The clusters produced are identical to those of a forward [`Parser`]
+/// over the same sequence. To begin at an arbitrary cluster boundary, create
+/// the parser with a slice ending at that boundary.
+///
+/// Parsing begins by scanning backward to a character that always begins a
+/// cluster (whitespace or a control character) and parsing forward from
+/// there. Each forward pass records evenly spaced cluster boundaries at
+/// which parsing can later resume, so text without such characters, such
+/// as Chinese or Thai, does not need to be rescanned for every cluster.
+pub struct ReverseParser<'a> {
+    script: Script,
+    chars: &'a [SourceChar],
+    end: usize,
+    options: ParserOptions,
+    /// Cluster boundaries before `end` at which parsing can resume, in
+    /// ascending order.
+    checkpoints: [Checkpoint; MAX_CHECKPOINTS],
+    len: usize,
+    /// Number of clusters that have been returned from those beginning at
+    /// the last checkpoint. This is only nonzero when the cluster at the
+    /// checkpoint reached capacity and was followed by continuations.
+    parsed: usize,
+    /// Capacity of the clusters that the checkpoints were recorded for.
+    capacity: usize,
+}
+
+/// Maximum number of checkpoints held by a reverse parser. The oldest are
+/// discarded when this is exceeded.
+const MAX_CHECKPOINTS: usize = 64;
+
+/// Number of checkpoints recorded by each forward pass.
+const CHECKPOINT_SPLIT: usize = 8;
+
+/// Cluster boundary along with the script context of the forward parser.
+#[derive(Copy, Clone)]
+struct Checkpoint {
+    index: usize,
+    script: Script,
+}
+
+impl<'a> ReverseParser<'a> {
+    /// Creates a new reverse cluster parser for the specified script and
+    /// sequence of characters.
+    pub fn new(script: Script, chars: &'a [SourceChar]) -> Self {
+        Self {
+            script,
+            chars,
+            end: chars.len(),
+            options: ParserOptions::default(),
+            checkpoints: [Checkpoint {
+                index: 0,
+                script: Script::Unknown,
+            }; MAX_CHECKPOINTS],
+            len: 0,
+            parsed: 0,
+            capacity: 0,
+        }
+    }
+
+    /// Sets the options for cluster formation.
+    pub fn with_options(mut self, options: ParserOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Returns the number of characters that remain to be parsed. The
+    /// characters of a cluster that reached capacity are included until
+    /// all of its continuation clusters have been parsed.
+    pub fn remaining(&self) -> usize {
+        self.end
+    }
+
+    /// Parses the previous cluster.
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
+        cluster.clear();
+        if self.end == 0 {
+            return false;
+        }
+        // Boundaries depend on the capacity of the cluster.
+        if self.capacity != N {
+            self.len = 0;
+            self.parsed = 0;
+            self.capacity = N;
+        }
+        loop {
+            if self.len == 0 {
+                let chars = &self.chars[..self.end];
+                let mut start = self.end - 1;
+                while start > 0 && !is_cluster_start(chars, start) {
+                    start -= 1;
+                }
+                self.push(Checkpoint {
+                    index: start,
+                    script: self.script,
+                });
+            }
+            let checkpoint = self.checkpoints[self.len - 1];
+            // Count the clusters that begin a new cluster along with the
+            // continuations that follow the last of them.
+            let mut parser = self.parser(checkpoint);
+            let (mut count, mut last_len) = (0, 0);
+            while parser.next(cluster) {
+                if cluster.is_continuation() {
+                    last_len += 1;
+                } else {
+                    count += 1;
+                    last_len = 1;
+                }
+            }
+            if count == 1 {
+                let mut parser = self.parser(checkpoint);
+                for _ in self.parsed..last_len {
+                    parser.next(cluster);
+                }
+                self.parsed += 1;
+                if self.parsed == last_len {
+                    self.len -= 1;
+                    self.parsed = 0;
+                    self.end = checkpoint.index;
+                }
+                return true;
+            }
+            // Record checkpoints at evenly spaced clusters and at the last
+            // cluster, which is parsed on the next iteration.
+            let mut parser = self.parser(checkpoint);
+            let mut i = 0;
+            loop {
+                let script = parser.script;
+                if !parser.next(cluster) {
+                    break;
+                }
+                if cluster.is_continuation() {
+                    continue;
+                }
+                if i != 0
+                    && (i == count - 1
+                        || i * CHECKPOINT_SPLIT / count != (i - 1) * CHECKPOINT_SPLIT / count)
+                {
+                    let start = cluster.range().start;
+                    let chars = &self.chars[checkpoint.index..self.end];
+                    let index = checkpoint.index + chars.partition_point(|ch| ch.offset < start);
+                    self.push(Checkpoint { index, script });
+                }
+                i += 1;
+            }
+        }
+    }
+
+    fn parser(&self, checkpoint: Checkpoint) -> Parser<Copied<Iter<'a, SourceChar>>> {
+        let chars = &self.chars[checkpoint.index..self.end];
+        Parser::new(checkpoint.script, chars.iter().copied()).with_options(self.options)
+    }
+
+    /// Adds a checkpoint, discarding the oldest when full. Discarded
+    /// boundaries are found again by scanning backward.
+    fn push(&mut self, checkpoint: Checkpoint) {
+        if self.len == MAX_CHECKPOINTS {
+            self.checkpoints.copy_within(1.., 0);
+            self.len -= 1;
+        }
+        self.checkpoints[self.len] = checkpoint;
+        self.len += 1;
+    }
+}
+
+impl<'a> Parser<Copied<Iter<'a, SourceChar>>> {
+    /// Creates a new cluster parser for the specified script that begins at
+    /// the character at `index`, which must be a cluster boundary, and
+    /// continues to the end of the sequence.
+    pub fn from_index(script: Script, chars: &'a [SourceChar], index: usize) -> Self {
+        Self::new(script, chars[index.min(chars.len())..].iter().copied())
+    }
+}
+
+/// Returns true if the character at the specified index always begins a
+/// new cluster, regardless of script and preceding context.
+fn is_cluster_start(chars: &[SourceChar], index: usize) -> bool {
+    let cur = &chars[index];
+    let prev = &chars[index - 1];
+    if prev.info.cluster_break() == ClusterBreak::PP {
+        return false;
+    }
+    match cur.ch {
+        '\n' => prev.ch != '\r',
+        '\u{200c}' | '\u{200d}' => false,
+        _ => matches!(
+            cur.info.category(),
+            Category::SpaceSeparator
+                | Category::LineSeparator
+                | Category::ParagraphSeparator
+                | Category::Control
+        ),
+    }
+}
+
+/// Returns an iterator yielding source characters with Unicode properties
+/// and boundary analysis for the specified string.
+pub fn source_chars(s: &str) -> SourceChars<'_> {
+    SourceChars {
+        chars: s.char_indices(),
+        info: paragraph_info(s.chars()),
+    }
+}
+
+/// Iterator that yields source characters for a string. This iterator is
+/// created by the [`source_chars`] function.
+#[derive(Clone)]
+pub struct SourceChars<'a> {
+    chars: CharIndices<'a>,
+    info: ParagraphInfo<Chars<'a>>,
+}
+
+impl SourceChars<'_> {
+    /// Disables word and line boundary analysis. See
+    /// [`ParagraphInfo::without_boundaries`].
+    pub fn without_boundaries(mut self) -> Self {
+        self.info = self.info.without_boundaries();
+        self
+    }
+}
+
+impl<'a> Iterator for SourceChars<'a> {
+    type Item = SourceChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (offset, ch) = self.chars.next()?;
+        let info: CharInfo = self.info.next()?;
+        Some(SourceChar {
+            ch,
+            offset,
+            len: ch.len_utf8() as u8,
+            info,
+            data: 0,
+        })
+    }
+}
+
+/// Character with an offset and optional user data that can be converted
+/// into a source character.
+///
+/// This is implemented for the `(usize, char)` pairs produced by
+/// [`str::char_indices`] and for `(usize, char, UserData)` triples.
+pub trait CharIndex: Copy {
+    /// Returns the offset of the character in code units.
+    fn offset(self) -> usize;
+
+    /// Returns the character.
+    fn ch(self) -> char;
+
+    /// Returns the user data associated with the character.
+    fn data(self) -> UserData {
+        0
+    }
+}
+
+impl CharIndex for (usize, char) {
+    fn offset(self) -> usize {
+        self.0
+    }
+
+    fn ch(self) -> char {
+        self.1
+    }
+}
+
+impl CharIndex for (usize, char, UserData) {
+    fn offset(self) -> usize {
+        self.0
+    }
+
+    fn ch(self) -> char {
+        self.1
+    }
+
+    fn data(self) -> UserData {
+        self.2
+    }
+}
+
+/// Returns an iterator yielding source characters with Unicode properties
+/// and boundary analysis for the specified sequence of indexed characters,
+/// such as the output of [`str::char_indices`].
+///
+/// Offsets and user data are taken from the input. Lengths are computed
+/// in UTF-8 code units.
+pub fn source_char_indices<I>(chars: I) -> SourceCharIndices<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: CharIndex,
+{
+    let chars = chars.into_iter();
+    SourceCharIndices {
+        info: paragraph_info(chars.clone().map(CharIndex::ch as _)),
+        chars,
+    }
+}
+
+/// Iterator that yields source characters for a sequence of indexed
+/// characters. This iterator is created by the [`source_char_indices`]
+/// function.
+#[derive(Clone)]
+pub struct SourceCharIndices<I>
+where
+    I: Iterator,
+{
+    chars: I,
+    info: ParagraphInfo<IndexedChars<I>>,
+}
+
+type IndexedChars<I> = Map<I, fn(<I as Iterator>::Item) -> char>;
+
+impl<I> SourceCharIndices<I>
+where
+    I: Iterator,
+{
+    /// Disables word and line boundary analysis. See
+    /// [`ParagraphInfo::without_boundaries`].
+    pub fn without_boundaries(mut self) -> Self {
+        self.info = self.info.without_boundaries();
+        self
+    }
+}
+
+impl<I> Iterator for SourceCharIndices<I>
+where
+    I: Iterator + Clone,
+    I::Item: CharIndex,
+{
+    type Item = SourceChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let input = self.chars.next()?;
+        let info: CharInfo = self.info.next()?;
+        let ch = input.ch();
+        Some(SourceChar {
+            ch,
+            offset: input.offset(),
+            len: ch.len_utf8() as u8,
+            info,
+            data: input.data(),
+        })
+    }
+}
+
+/// Returns an iterator yielding source characters with Unicode properties
+/// and boundary analysis for the specified UTF-16 encoded text.
+///
+/// Offsets and lengths of the resulting characters are in UTF-16 code units.
+/// Unpaired surrogates are replaced with U+FFFD and have a length of one.
+pub fn source_chars_utf16(s: &[u16]) -> SourceCharsUtf16<'_> {
+    SourceCharsUtf16 {
+        chars: decode_utf16(s.iter().copied()),
+        info: paragraph_info(decode_utf16(s.iter().copied()).map(decode_lossy as _)),
+        offset: 0,
+    }
+}
+
+type DecodeUtf16Lossy<'a> =
+    Map<DecodeUtf16<Copied<Iter<'a, u16>>>, fn(Result<char, DecodeUtf16Error>) -> char>;
+
+fn decode_lossy(result: Result<char, DecodeUtf16Error>) -> char {
+    result.unwrap_or(REPLACEMENT_CHARACTER)
+}
+
+/// Iterator that yields source characters for UTF-16 encoded text. This
+/// iterator is created by the [`source_chars_utf16`] function.
+#[derive(Clone)]
+pub struct SourceCharsUtf16<'a> {
+    chars: DecodeUtf16<Copied<Iter<'a, u16>>>,
+    info: ParagraphInfo<DecodeUtf16Lossy<'a>>,
+    offset: usize,
+}
+
+impl SourceCharsUtf16<'_> {
+    /// Disables word and line boundary analysis. See
+    /// [`ParagraphInfo::without_boundaries`].
+    pub fn without_boundaries(mut self) -> Self {
+        self.info = self.info.without_boundaries();
+        self
+    }
+}
+
+impl<'a> Iterator for SourceCharsUtf16<'a> {
+    type Item = SourceChar;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (ch, len) = match self.chars.next()? {
+            Ok(ch) => (ch, ch.len_utf16()),
+            Err(_) => (REPLACEMENT_CHARACTER, 1),
+        };
+        let info: CharInfo = self.info.next()?;
+        let offset = self.offset;
+        self.offset += len;
+        Some(SourceChar {
+            ch,
+            offset,
+            len: len as u8,
+            info,
+            data: 0,
+        })
+    }
+}
+
+/// Parser input that optionally applies canonical decomposition.
+#[derive(Clone)]
+struct Input<I> {
+    iter: I,
+    decompose: bool,
+    parts: Option<(SourceChar, Decompose)>,
+}
+
+impl<I> Input<I> {
+    fn new(iter: I, decompose: bool) -> Self {
+        Self {
+            iter,
+            decompose,
+            parts: None,
+        }
+    }
+}
+
+impl<I> Iterator for Input<I>
+where
+    I: Iterator<Item = SourceChar>,
+{
+    type Item = SourceChar;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((input, parts)) = &mut self.parts {
+            if let Some(ch) = parts.next() {
+                return Some(SourceChar {
+                    ch,
+                    info: input.info.with_properties(ch.properties()),
+                    ..*input
+                });
+            }
+            self.parts = None;
+        }
+        let input = self.iter.next()?;
+        if self.decompose && input.info.decomposition_type() == DecompositionType::Canonical {
+            self.parts = Some((input, input.ch.decompose()));
+            return self.next();
+        }
+        Some(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Range;
+
+    /// Collects the range and continuation flag of each cluster.
+    fn ranges<I>(mut parser: Parser<I>) -> ([(Range<usize>, bool); 4], usize)
+    where
+        I: Iterator<Item = SourceChar> + Clone,
+    {
+        let mut cluster = Cluster::new();
+        let mut ranges = [(0..0, false), (0..0, false), (0..0, false), (0..0, false)];
+        let mut len = 0;
+        while parser.next(&mut cluster) {
+            ranges[len] = (cluster.range(), cluster.is_continuation());
+            len += 1;
+        }
+        (ranges, len)
+    }
+
+    fn text<const N: usize>(first: char, mark: char, marks: usize, last: char) -> ([u8; N], usize) {
+        let mut buf = [0; N];
+        let mut len = first.encode_utf8(&mut buf).len();
+        for _ in 0..marks {
+            len += mark.encode_utf8(&mut buf[len..]).len();
+        }
+        len += last.encode_utf8(&mut buf[len..]).len();
+        (buf, len)
+    }
+
+    #[test]
+    #[cfg(feature = "complex-scripts")]
+    fn continuation_split_vowel() {
+        // The two part vowel U+17BE is split into U+17C1 and itself, which
+        // fall on either side of the capacity.
+        let (buf, len) = text::<128>('\u{1780}', '\u{17CB}', 30, '\u{17BE}');
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        let (ranges, len) = ranges(Parser::from_str(Script::Khmer, s));
+        assert_eq!(&ranges[..len], &[(0..96, false), (96..96, true)]);
+    }
+
+    #[test]
+    fn continuation_decomposed() {
+        // U+0344 decomposes to U+0308 U+0301, which fall on either side of
+        // the capacity.
+        let (buf, len) = text::<128>('a', '\u{301}', 30, '\u{344}');
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        let parser = Parser::new_decomposed(Script::Latin, source_chars(s));
+        let (ranges, len) = ranges(parser);
+        assert_eq!(&ranges[..len], &[(0..63, false), (63..63, true)]);
+    }
+
+    #[test]
+    fn continuation() {
+        let (buf, len) = text::<128>('a', '\u{301}', 32, '\u{300}');
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        let (ranges, len) = ranges(Parser::from_str(Script::Latin, s));
+        assert_eq!(&ranges[..len], &[(0..63, false), (63..67, true)]);
+    }
+
+    #[test]
+    fn regional_indicators() {
+        let mut parser = Parser::from_str(Script::Common, "\u{1F1FA}\u{1F1F8}\u{1F1EB}");
+        let mut cluster = Cluster::new();
+        assert!(parser.next(&mut cluster));
+        assert_eq!(cluster.chars().len(), 2);
+        assert!(!cluster.info().is_unpaired_regional_indicator());
+        assert!(parser.next(&mut cluster));
+        assert!(cluster.info().is_unpaired_regional_indicator());
+        assert!(!parser.next(&mut cluster));
+    }
+
+    fn summary<const N: usize>(cluster: &Cluster<N>) -> (usize, usize, Script, bool, usize) {
+        let range = cluster.range();
+        (
+            range.start,
+            range.end,
+            cluster.script(),
+            cluster.is_continuation(),
+            cluster.chars().len(),
+        )
+    }
+
+    /// Checks that the reverse parser yields the clusters of the forward
+    /// parser in reverse order.
+    fn check_reverse<const N: usize>(script: Script, s: &str) {
+        let filler = source_chars(" ").next().unwrap();
+        let mut chars = [filler; 256];
+        let mut len = 0;
+        for ch in source_chars(s) {
+            chars[len] = ch;
+            len += 1;
+        }
+        let chars = &chars[..len];
+        let mut forward = [(0, 0, Script::Unknown, false, 0); 256];
+        let mut count = 0;
+        let mut parser = Parser::new(script, chars.iter().copied());
+        let mut cluster = Cluster::<N>::with_capacity();
+        while parser.next(&mut cluster) {
+            forward[count] = summary(&cluster);
+            count += 1;
+        }
+        let mut reverse = ReverseParser::new(script, chars);
+        while reverse.next(&mut cluster) {
+            count -= 1;
+            assert_eq!(summary(&cluster), forward[count], "{:?}", s);
+        }
+        assert_eq!(count, 0);
+        assert_eq!(reverse.remaining(), 0);
+    }
+
+    #[test]
+    fn reverse() {
+        let cases = [
+            (Script::Latin, "The quick brown fox\r\njumps over the lazy dog."),
+            (Script::Han, "\u{4E2D}\u{6587}\u{7684}\u{6587}\u{672C}\u{6CA1}\u{6709}\u{7A7A}\u{683C}\u{3002}"),
+            (Script::Thai, "\u{0E20}\u{0E32}\u{0E29}\u{0E32}\u{0E44}\u{0E17}\u{0E22}\u{0E44}\u{0E21}\u{0E48}\u{0E21}\u{0E35}\u{0E0A}\u{0E48}\u{0E2D}\u{0E07}\u{0E27}\u{0E48}\u{0E32}\u{0E07}"),
+            (Script::Khmer, "\u{1780}\u{17CB}\u{17CB}\u{17CB}\u{17BE}\u{1781}\u{17D2}\u{1798}\u{17C2}\u{179A}"),
+            (Script::Latin, "a\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}\u{301}b c\u{301}\u{301}\u{301}\u{301}\u{301}"),
+        ];
+        for &(script, s) in &cases {
+            check_reverse::<32>(script, s);
+            check_reverse::<4>(script, s);
+        }
+        let mut buf = [0; 768];
+        let mut len = 0;
+        for i in 0..240 {
+            let ch = char::from_u32(0x4E00 + i * 7).unwrap();
+            len += ch.encode_utf8(&mut buf[len..]).len();
         }
+        let s = core::str::from_utf8(&buf[..len]).unwrap();
+        check_reverse::<32>(Script::Han, s);
     }
 }
@@ -1,7 +1,7 @@
 //! Simple cluster formation (unicode grapheme cluster algorithm).
 
 use super::ClusterBreak;
-use super::{Cluster, Emoji, ShapeClass, SourceChar, Whitespace, MAX_CLUSTER_SIZE};
+use super::{Cluster, Emoji, ShapeClass, SourceChar, Whitespace};
 
 pub struct SimpleState<I> {
     chars: I,
@@ -9,6 +9,7 @@ pub struct SimpleState<I> {
     cur_kind: ClusterBreak,
     cur_emoji: bool,
     done: bool,
+    separate: bool,
 }
 
 impl<I> SimpleState<I>
@@ -24,6 +25,7 @@ where
                 cur_kind: kind,
                 cur_emoji: emoji,
                 done: false,
+                separate: false,
             }
         } else {
             Self {
@@ -32,25 +34,39 @@ where
                 cur_kind: ClusterBreak::XX,
                 cur_emoji: false,
                 done: true,
+                separate: false,
             }
         }
     }
 
-    pub fn next(&mut self, cluster: &mut Cluster) -> bool {
+    /// Emits separable ignorables as clusters of their own by treating them
+    /// as control characters.
+    pub fn separate_ignorables(&mut self) {
+        self.separate = true;
+        if self.cur.is_separable() {
+            self.cur_kind = ClusterBreak::CN;
+            self.cur_emoji = false;
+        }
+    }
+
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
         if self.done {
             return false;
         }
-        Parser { s: self, cluster }.parse();
+        let mut parser = Parser { s: self, cluster };
+        if !parser.parse_single() {
+            parser.parse();
+        }
         true
     }
 }
 
-pub struct Parser<'a, I> {
+pub struct Parser<'a, I, const N: usize> {
     s: &'a mut SimpleState<I>,
-    cluster: &'a mut Cluster,
+    cluster: &'a mut Cluster<N>,
 }
 
-impl<'a, I> Parser<'a, I>
+impl<'a, I, const N: usize> Parser<'a, I, N>
 where
     I: Iterator<Item = SourceChar>,
 {
@@ -68,6 +84,9 @@ where
         } else {
             match self.kind() {
                 CN => {
+                    self.cluster
+                        .info_mut()
+                        .set_space_from_char(self.s.cur.ch, self.s.cur.info);
                     self.accept_any_as(ShapeClass::Control)?;
                 }
                 LF => {
@@ -79,36 +98,20 @@ where
                     self.accept_any_as(ShapeClass::Control)?;
                     self.accept_as(LF, ShapeClass::Control)?;
                 }
-                L => {
-                    self.accept_any()?;
-                    match self.kind() {
-                        L | V | LV | LVT => {
-                            self.accept_any()?;
-                        }
-                        _ => {}
-                    }
-                }
-                LV | V => {
-                    self.accept_any()?;
-                    match self.kind() {
-                        V | T => {
-                            self.accept_any()?;
-                        }
-                        _ => {}
-                    }
-                }
-                LVT | T => {
-                    self.accept_any()?;
-                    self.accept(T)?;
+                L | V | T | LV | LVT => {
+                    self.parse_hangul()?;
                 }
                 RI => {
+                    self.accept_any()?;
                     self.accept(RI)?;
                 }
                 EX | SM | ZWJ => {
                     self.cluster.info_mut().set_broken();
                 }
                 _ => {
-                    self.cluster.info_mut().set_space_from_char(self.s.cur.ch);
+                    self.cluster
+                        .info_mut()
+                        .set_space_from_char(self.s.cur.ch, self.s.cur.info);
                     self.accept_any()?;
                 }
             }
@@ -117,6 +120,53 @@ where
         Some(())
     }
 
+    /// Fast path for a character that forms a cluster on its own, which is
+    /// the common case for text in simple scripts. Returns false without
+    /// consuming any input if the cluster requires the full parser.
+    #[inline(always)]
+    fn parse_single(&mut self) -> bool {
+        use ClusterBreak::*;
+        if self.kind() != XX || self.emoji() {
+            return false;
+        }
+        self.cluster
+            .info_mut()
+            .set_space_from_char(self.s.cur.ch, self.s.cur.info);
+        if self.accept_any().is_some() {
+            while let Some(true) = self.parse_extension() {}
+        }
+        true
+    }
+
+    /// Parses a Hangul syllable consisting of a sequence of conjoining jamo
+    /// and precomposed syllables.
+    fn parse_hangul(&mut self) -> Option<()> {
+        use ClusterBreak::*;
+        let start = self.cluster.len();
+        while self.accept_as(L, ShapeClass::LJamo)? {}
+        let trailing = match self.kind() {
+            V => {
+                while self.accept_as(V, ShapeClass::VJamo)? {}
+                true
+            }
+            LV => {
+                self.accept_any()?;
+                while self.accept_as(V, ShapeClass::VJamo)? {}
+                true
+            }
+            LVT => {
+                self.accept_any()?;
+                true
+            }
+            T => self.cluster.len() == start,
+            _ => false,
+        };
+        if trailing {
+            while self.accept_as(T, ShapeClass::TJamo)? {}
+        }
+        Some(())
+    }
+
     fn parse_emoji_extension(&mut self) -> Option<bool> {
         use ClusterBreak::*;
         loop {
@@ -172,9 +222,11 @@ where
         })
     }
 
+    /// Emoji sequences are never formed when the `emoji` feature is
+    /// disabled, which removes the associated paths from the parsers.
     #[inline(always)]
     fn emoji(&self) -> bool {
-        self.s.cur_emoji
+        cfg!(feature = "emoji") && self.s.cur_emoji
     }
 
     #[inline(always)]
@@ -201,23 +253,36 @@ where
     }
 
     fn accept_any(&mut self) -> Option<()> {
+        self.reserve()?;
         self.push_cur();
         self.advance()?;
         Some(())
     }
 
     fn accept_any_as(&mut self, as_kind: ShapeClass) -> Option<()> {
+        self.reserve()?;
         self.cluster.push(&self.s.cur, as_kind);
         self.advance()?;
         Some(())
     }
 
-    fn advance(&mut self) -> Option<()> {
-        if self.cluster.len() as usize == MAX_CLUSTER_SIZE {
+    /// Terminates the cluster if it has reached capacity, leaving the
+    /// current character for a continuation cluster.
+    fn reserve(&mut self) -> Option<()> {
+        if self.cluster.is_full() {
+            self.cluster.set_continued();
             return None;
         }
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<()> {
         if let Some(input) = self.s.chars.next() {
-            let (kind, emoji) = input.info.cluster_class();
+            let (kind, emoji) = if self.s.separate && input.is_separable() {
+                (ClusterBreak::CN, false)
+            } else {
+                input.info.cluster_class()
+            };
             self.s.cur = input;
             self.s.cur_emoji = emoji;
             self.s.cur_kind = kind;
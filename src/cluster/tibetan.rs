@@ -0,0 +1,328 @@
+//! Parser for Tibetan clusters.
+
+use super::unicode_data::ClusterBreak;
+use super::Category;
+use super::{Cluster, Emoji, ShapeClass, SourceChar, Syllable, Whitespace};
+
+type Kind = TibetanClass;
+
+/// Character classes for Tibetan syllables.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum TibetanClass {
+    /// Other character.
+    O,
+    /// Head letter, digit or placeholder.
+    B,
+    /// Subjoined consonant.
+    Sub,
+    /// Tsa-phru mark which may appear in the stack or after the vowels.
+    TsaPhru,
+    /// Vowel sign.
+    V,
+    /// Other modifying mark.
+    M,
+    /// Zero width joiner or non-joiner.
+    Joiner,
+    /// Variation selector.
+    VS,
+}
+
+impl TibetanClass {
+    fn from_char(ch: char) -> Self {
+        use TibetanClass::*;
+        match ch as u32 {
+            0x0F20..=0x0F33 | 0x0F40..=0x0F6C | 0x0F88..=0x0F8C => B,
+            0x0F8D..=0x0FBC => Sub,
+            0x0F39 => TsaPhru,
+            0x0F71..=0x0F7D | 0x0F80 | 0x0F81 => V,
+            0x0F18 | 0x0F19 | 0x0F35 | 0x0F37 | 0x0F3E | 0x0F3F => M,
+            0x0F7E | 0x0F7F | 0x0F82..=0x0F84 | 0x0F86 | 0x0F87 | 0x0FC6 => M,
+            0x200C | 0x200D => Joiner,
+            0xFE00..=0xFE0F => VS,
+            0x00A0 | 0x25CC => B,
+            _ => O,
+        }
+    }
+}
+
+/// Returns the shape class for a Tibetan vowel sign.
+fn vowel_shape_class(ch: char) -> ShapeClass {
+    match ch as u32 {
+        0x0F72 | 0x0F7A..=0x0F7D | 0x0F80 => ShapeClass::VAbv,
+        0x0F71 | 0x0F74 | 0x0F75 => ShapeClass::VBlw,
+        _ => ShapeClass::Other,
+    }
+}
+
+pub struct TibetanState<I> {
+    chars: I,
+    cur: SourceChar,
+    cur_kind: Kind,
+    cur_emoji: bool,
+    done: bool,
+    separate: bool,
+}
+
+impl<I> TibetanState<I>
+where
+    I: Iterator<Item = SourceChar>,
+{
+    pub fn new(mut chars: I) -> Self {
+        if let Some(first) = chars.by_ref().next() {
+            Self {
+                chars,
+                cur: first,
+                cur_kind: TibetanClass::from_char(first.ch),
+                cur_emoji: first.info.is_extended_pictographic(),
+                done: false,
+                separate: false,
+            }
+        } else {
+            Self {
+                chars,
+                cur: SourceChar::default(),
+                cur_kind: TibetanClass::O,
+                cur_emoji: false,
+                done: true,
+                separate: false,
+            }
+        }
+    }
+
+    /// Emits separable ignorables as clusters of their own by treating them
+    /// as other characters.
+    pub fn separate_ignorables(&mut self) {
+        self.separate = true;
+        if self.cur.is_separable() {
+            self.cur_kind = Kind::O;
+            self.cur_emoji = false;
+        }
+    }
+
+    pub fn next<const N: usize>(&mut self, cluster: &mut Cluster<N>) -> bool {
+        if self.done {
+            return false;
+        }
+        Parser { s: self, cluster }.parse();
+        true
+    }
+}
+
+struct Parser<'a, I, const N: usize> {
+    s: &'a mut TibetanState<I>,
+    cluster: &'a mut Cluster<N>,
+}
+
+impl<'a, I, const N: usize> Parser<'a, I, N>
+where
+    I: Iterator<Item = SourceChar>,
+{
+    fn parse(&mut self) -> Option<()> {
+        use TibetanClass::*;
+        if self.s.done {
+            return Some(());
+        }
+        if self.emoji() {
+            self.cluster.info_mut().set_emoji(Emoji::Default);
+            self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+            while self.emoji() {
+                self.accept_any_as(ShapeClass::Base)?;
+                if !self.parse_emoji_extension()? {
+                    break;
+                }
+            }
+            return Some(());
+        }
+        match self.kind() {
+            _ if self.s.cur.info.cluster_break() == ClusterBreak::RI => {
+                // Regional indicators are paired as in the simple parser.
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Base)?;
+                if self.s.cur.info.cluster_break() == ClusterBreak::RI {
+                    self.accept_any_as(ShapeClass::Base)?;
+                }
+            }
+            O => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                // This is not part of Tibetan syllable structure, but added
+                // to support uniform clustering of CRLF across the parsers.
+                match self.s.cur.ch {
+                    '\r' => {
+                        self.cluster.info_mut().set_space(Whitespace::Newline);
+                        self.accept_any_as(ShapeClass::Control)?;
+                        if self.s.cur.ch == '\n' {
+                            self.accept_any_as(ShapeClass::Control)?;
+                        }
+                    }
+                    '\n' => {
+                        self.cluster.info_mut().set_space(Whitespace::Newline);
+                        self.accept_any_as(ShapeClass::Control)?;
+                    }
+                    _ => {
+                        self.cluster
+                            .info_mut()
+                            .set_space_from_char(self.s.cur.ch, self.s.cur.info);
+                        let class = match self.s.cur.info.category() {
+                            Category::Control | Category::Format => ShapeClass::Control,
+                            _ => ShapeClass::Base,
+                        };
+                        self.accept_any_as(class)?;
+                    }
+                }
+            }
+            B => {
+                self.cluster.info_mut().set_syllable(Syllable::Standard);
+                self.cluster
+                    .info_mut()
+                    .set_space_from_char(self.s.cur.ch, self.s.cur.info);
+                self.accept_any_as(ShapeClass::Base)?;
+                self.accept_as(VS, ShapeClass::Vs)?;
+                self.parse_stack()?;
+                self.parse_marks()?;
+            }
+            Joiner => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                let class = if self.s.cur.ch == '\u{200d}' {
+                    ShapeClass::Zwj
+                } else {
+                    ShapeClass::Zwnj
+                };
+                self.accept_any_as(class)?;
+            }
+            VS => {
+                self.cluster.info_mut().set_syllable(Syllable::NonSyllable);
+                self.accept_any_as(ShapeClass::Vs)?;
+            }
+            _ => {
+                // Marks without a preceding head letter form a broken
+                // cluster.
+                self.cluster.info_mut().set_broken();
+                self.cluster.info_mut().set_syllable(Syllable::Broken);
+                self.parse_stack()?;
+                self.parse_marks()?;
+            }
+        }
+        None
+    }
+
+    /// Parses the subjoined consonants below the head letter.
+    fn parse_stack(&mut self) -> Option<()> {
+        use TibetanClass::*;
+        loop {
+            match self.kind() {
+                Sub => self.accept_any_as(ShapeClass::Subjoined)?,
+                TsaPhru => self.accept_any()?,
+                _ => break,
+            }
+        }
+        Some(())
+    }
+
+    /// Parses the vowel signs and modifying marks that follow the stack.
+    /// These are accepted in any order since normalization may move marks
+    /// with a higher combining class after the vowels.
+    fn parse_marks(&mut self) -> Option<()> {
+        use TibetanClass::*;
+        loop {
+            match self.kind() {
+                V => self.accept_any_as(vowel_shape_class(self.s.cur.ch))?,
+                M | TsaPhru => self.accept_any()?,
+                _ => break,
+            }
+        }
+        Some(())
+    }
+
+    fn parse_emoji_extension(&mut self) -> Option<bool> {
+        use ClusterBreak::*;
+        loop {
+            if self.s.separate && self.s.cur.is_separable() {
+                break;
+            }
+            match self.s.cur.info.cluster_break() {
+                EX => match self.s.cur.ch as u32 {
+                    0x200C => self.accept_any_as(ShapeClass::Zwnj)?,
+                    0xFE0F => {
+                        self.cluster.info_mut().set_emoji(Emoji::Color);
+                        self.cluster.note_char(&self.s.cur);
+                        self.advance()?;
+                    }
+                    0xFE0E => {
+                        self.cluster.info_mut().set_emoji(Emoji::Text);
+                        self.cluster.note_char(&self.s.cur);
+                        self.advance()?;
+                    }
+                    _ => self.accept_any_as(ShapeClass::Mark)?,
+                },
+                ZWJ => {
+                    self.accept_any_as(ShapeClass::Zwj)?;
+                    return Some(true);
+                }
+                _ => break,
+            }
+        }
+        Some(false)
+    }
+
+    #[inline(always)]
+    fn emoji(&self) -> bool {
+        cfg!(feature = "emoji") && self.s.cur_emoji
+    }
+
+    #[inline(always)]
+    fn kind(&self) -> Kind {
+        self.s.cur_kind
+    }
+
+    fn accept_as(&mut self, kind: Kind, as_class: ShapeClass) -> Option<bool> {
+        if self.s.cur_kind == kind {
+            self.accept_any_as(as_class)?;
+            Some(true)
+        } else {
+            Some(false)
+        }
+    }
+
+    fn accept_any(&mut self) -> Option<()> {
+        self.reserve()?;
+        self.cluster.push(&self.s.cur, ShapeClass::Other);
+        self.advance()?;
+        Some(())
+    }
+
+    fn accept_any_as(&mut self, as_class: ShapeClass) -> Option<()> {
+        self.reserve()?;
+        self.cluster.push(&self.s.cur, as_class);
+        self.advance()?;
+        Some(())
+    }
+
+    /// Terminates the cluster if it has reached capacity, leaving the
+    /// current character for a continuation cluster.
+    fn reserve(&mut self) -> Option<()> {
+        if self.cluster.is_full() {
+            self.cluster.set_continued();
+            return None;
+        }
+        Some(())
+    }
+
+    fn advance(&mut self) -> Option<()> {
+        if let Some(input) = self.s.chars.next() {
+            self.s.cur = input;
+            self.s.cur_kind = TibetanClass::from_char(input.ch);
+            self.s.cur_emoji = input.info.is_extended_pictographic();
+            if self.s.separate && input.is_separable() {
+                self.s.cur_kind = Kind::O;
+                self.s.cur_emoji = false;
+            }
+            if input.ch == '\u{34f}' {
+                self.accept_any()?;
+            }
+            Some(())
+        } else {
+            self.s.done = true;
+            None
+        }
+    }
+}
@@ -21,6 +21,11 @@ impl Decompose {
             DecomposeInner::Array(ref chars, len) => &chars[..len as usize],
         }
     }
+
+    /// Returns the trivial decomposition of a character to itself.
+    pub(crate) fn single(c: char) -> Self {
+        DecomposeInner::Array([c, ' ', ' '], 1).into()
+    }
 }
 
 impl Iterator for Decompose {
@@ -82,7 +87,7 @@ fn pair_index(c: u32, table: &[(u32, u16, u16)]) -> Option<usize> {
             return None;
         }
         let end = start + entry.1 as usize;
-        if c as usize <= end {
+        if c <= end {
             return Some(entry.2 as usize + (c - start));
         }
     }
@@ -104,25 +109,22 @@ fn is_hangul(c: char) -> bool {
     (SBASE..(SBASE + SCOUNT)).contains(&c)
 }
 
+// The modulo check avoids u32::is_multiple_of, which needs Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
 fn compose_hangul(a: char, b: char) -> Option<char> {
     let a = a as u32;
     let b = b as u32;
-    if !(VBASE..(TBASE + TCOUNT)).contains(&b) {
-        return None;
-    }
-    if !(LBASE..(LBASE + LCOUNT)).contains(&a) && !(SBASE..(SBASE + SCOUNT)).contains(&a) {
-        return None;
-    }
-    if a >= SBASE {
-        if (a - SBASE) % TCOUNT == 0 {
-            Some(unsafe { from_u32_unchecked(a + (b - TBASE)) })
-        } else {
-            None
-        }
-    } else {
+    if (LBASE..(LBASE + LCOUNT)).contains(&a) && (VBASE..(VBASE + VCOUNT)).contains(&b) {
         let li = a - LBASE;
         let vi = b - VBASE;
         Some(unsafe { from_u32_unchecked(SBASE + li * NCOUNT + vi * TCOUNT) })
+    } else if (SBASE..(SBASE + SCOUNT)).contains(&a)
+        && (a - SBASE) % TCOUNT == 0
+        && ((TBASE + 1)..(TBASE + TCOUNT)).contains(&b)
+    {
+        Some(unsafe { from_u32_unchecked(a + (b - TBASE)) })
+    } else {
+        None
     }
 }
 
@@ -0,0 +1,589 @@
+/*!
+Conformance testing against the official Unicode test data.
+
+The test files published with the Unicode Character Database, such as
+`GraphemeBreakTest.txt` and `NormalizationTest.txt`, can be run against the
+implementations in this crate to verify the exact build that is shipped.
+The contents of a file are passed to [`run`] along with the corresponding
+[`Suite`], which produces a [`Report`] of the passing and failing cases.
+Additional cases for behavior that the official files exercise only
+lightly are generated by [`regional_indicator_cases`].
+
+Cases that cannot be represented, such as those containing surrogate code
+points, are skipped. Suites for algorithms that are not implemented by this
+crate skip all cases:
+
+- Sentence segmentation is not implemented.
+- BiDi resolution is performed by the `unicode-bidi` crate using the
+  properties of this crate and requires the `unicode-bidi` and `bidi`
+  features.
+*/
+
+use super::cluster::{Cluster, Parser};
+use super::paragraph::{paragraph_info, LineBoundary};
+use super::unicode::{Codepoint, Script};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// Test suite of the Unicode Character Database.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Suite {
+    /// Grapheme cluster boundaries (`GraphemeBreakTest.txt`).
+    GraphemeBreak,
+    /// Word boundaries (`WordBreakTest.txt`).
+    WordBreak,
+    /// Sentence boundaries (`SentenceBreakTest.txt`).
+    SentenceBreak,
+    /// Line break opportunities (`LineBreakTest.txt`).
+    LineBreak,
+    /// Resolved BiDi levels (`BidiTest.txt`).
+    Bidi,
+    /// Normalization forms (`NormalizationTest.txt`).
+    Normalization,
+}
+
+impl Suite {
+    /// Returns the name of the test file for the suite.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            Self::GraphemeBreak => "GraphemeBreakTest.txt",
+            Self::WordBreak => "WordBreakTest.txt",
+            Self::SentenceBreak => "SentenceBreakTest.txt",
+            Self::LineBreak => "LineBreakTest.txt",
+            Self::Bidi => "BidiTest.txt",
+            Self::Normalization => "NormalizationTest.txt",
+        }
+    }
+
+    /// Returns the suite for the specified test file name.
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        Self::all().find(|suite| suite.file_name() == name)
+    }
+
+    /// Returns an iterator yielding all suites.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        [
+            Self::GraphemeBreak,
+            Self::WordBreak,
+            Self::SentenceBreak,
+            Self::LineBreak,
+            Self::Bidi,
+            Self::Normalization,
+        ]
+        .iter()
+        .copied()
+    }
+
+    /// Returns true if the suite is run by this build.
+    pub fn is_supported(self) -> bool {
+        let bidi = cfg!(all(feature = "unicode-bidi", feature = "bidi"));
+        self != Self::SentenceBreak && (self != Self::Bidi || bidi)
+    }
+}
+
+/// Results of running a test suite.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Report {
+    /// The suite that was run.
+    pub suite: Suite,
+    /// Number of cases that passed.
+    pub passed: usize,
+    /// Number of cases that were skipped.
+    pub skipped: usize,
+    /// Cases that failed.
+    pub failures: Vec<Failure>,
+}
+
+impl Report {
+    fn new(suite: Suite) -> Self {
+        Self {
+            suite,
+            passed: 0,
+            skipped: 0,
+            failures: Vec::new(),
+        }
+    }
+
+    /// Returns the number of cases that failed.
+    pub fn failed(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Returns true if no cases failed and at least one case passed.
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty() && self.passed != 0
+    }
+
+    fn record(&mut self, line: usize, case: &str, outcome: Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Skip => self.skipped += 1,
+            Outcome::Fail(actual) => self.failures.push(Failure {
+                line,
+                case: case.into(),
+                actual,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} passed, {} failed, {} skipped",
+            self.suite.file_name(),
+            self.passed,
+            self.failed(),
+            self.skipped
+        )
+    }
+}
+
+/// Test case that failed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Failure {
+    /// Line number of the case in the test file, starting at one.
+    pub line: usize,
+    /// The case as written in the test file, without comments.
+    pub case: String,
+    /// The result computed by this crate in the notation of the test file.
+    pub actual: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}: expected {}, found {}",
+            self.line, self.case, self.actual
+        )
+    }
+}
+
+/// Outcome of a single test case.
+enum Outcome {
+    Pass,
+    /// Failure with the result in the notation of the test file.
+    Fail(String),
+    Skip,
+}
+
+/// Runs the test cases in the contents of a test file for the specified
+/// suite.
+pub fn run(suite: Suite, data: &str) -> Report {
+    let mut report = Report::new(suite);
+    let mut bidi_levels = Vec::new();
+    for (index, line) in data.lines().enumerate() {
+        let case = line.split('#').next().unwrap_or("").trim();
+        if case.is_empty() {
+            continue;
+        }
+        if case.starts_with('@') {
+            // Directives such as `@Levels:` apply to the following cases.
+            if suite == Suite::Bidi {
+                if let Some(value) = case.strip_prefix("@Levels:") {
+                    bidi_levels = value.split_whitespace().map(|l| l.parse().ok()).collect();
+                }
+            }
+            continue;
+        }
+        let outcome = match suite {
+            _ if !suite.is_supported() => Outcome::Skip,
+            Suite::GraphemeBreak => segmentation(case, graphemes),
+            Suite::WordBreak => segmentation(case, words),
+            Suite::LineBreak => segmentation(case, lines),
+            Suite::Normalization => normalization(case),
+            Suite::Bidi => bidi(case, &bidi_levels),
+            Suite::SentenceBreak => Outcome::Skip,
+        };
+        report.record(index + 1, case, outcome);
+    }
+    report
+}
+
+/// Returns test cases for runs of regional indicators in the notation of
+/// the segmentation test files.
+///
+/// Only pairs of regional indicators are kept together, so the boundaries
+/// depend on the length of the whole run while the official test files
+/// contain only short runs. The cases cover runs of every length up to
+/// `max_len`, alone and adjacent to other characters. The boundaries are
+/// the same for grapheme clusters, words and lines, so the result can be
+/// passed to [`run`] with any of those suites.
+pub fn regional_indicator_cases(max_len: usize) -> String {
+    let mut s = String::new();
+    for len in 1..=max_len {
+        for &(before, after) in &[(false, false), (true, false), (false, true), (true, true)] {
+            s.push('÷');
+            if before {
+                s.push_str(" 0061 ÷");
+            }
+            for i in 0..len {
+                if i != 0 {
+                    s.push_str(if i % 2 == 1 { " ×" } else { " ÷" });
+                }
+                let _ = write!(s, " {:04X}", 0x1F1E6 + (i % 26) as u32);
+            }
+            if after {
+                s.push_str(" ÷ 0061");
+            }
+            s.push_str(" ÷\n");
+        }
+    }
+    s
+}
+
+/// Parses a space separated sequence of hexadecimal code points. Returns
+/// `None` if any of them is not a valid character, such as a surrogate.
+fn parse_chars<'a>(tokens: impl Iterator<Item = &'a str>) -> Option<Vec<char>> {
+    tokens
+        .map(|cp| char::from_u32(u32::from_str_radix(cp, 16).ok()?))
+        .collect()
+}
+
+/// Runs a segmentation case in the form `÷ 0020 × 0308 ÷`. The function
+/// returns whether there is a boundary before each character other than
+/// the first.
+fn segmentation(case: &str, f: fn(&str) -> Vec<bool>) -> Outcome {
+    let tokens = case.split_whitespace();
+    let expected = tokens
+        .clone()
+        .step_by(2)
+        .map(|token| token == "÷")
+        .collect::<Vec<_>>();
+    let text = match parse_chars(tokens.skip(1).step_by(2)) {
+        Some(chars) if chars.len() + 1 == expected.len() => chars.into_iter().collect::<String>(),
+        _ => return Outcome::Skip,
+    };
+    // Boundaries at the start and end of text are implied.
+    let actual = f(&text);
+    if actual == expected[1..expected.len() - 1] {
+        return Outcome::Pass;
+    }
+    let mut s = String::from("÷");
+    for (i, ch) in text.chars().enumerate() {
+        if i != 0 {
+            s.push_str(if actual[i - 1] { " ÷" } else { " ×" });
+        }
+        let _ = write!(s, " {:04X}", ch as u32);
+    }
+    s.push_str(" ÷");
+    Outcome::Fail(s)
+}
+
+fn graphemes(text: &str) -> Vec<bool> {
+    let mut ends = Vec::new();
+    let mut parser = Parser::from_str(Script::Latin, text);
+    let mut cluster = Cluster::new();
+    while parser.next(&mut cluster) {
+        ends.push(cluster.range().end);
+    }
+    text.char_indices()
+        .skip(1)
+        .map(|(offset, _)| ends.contains(&offset))
+        .collect()
+}
+
+fn words(text: &str) -> Vec<bool> {
+    paragraph_info(text.chars())
+        .skip(1)
+        .map(|info| info.is_word_boundary())
+        .collect()
+}
+
+fn lines(text: &str) -> Vec<bool> {
+    paragraph_info(text.chars())
+        .skip(1)
+        .map(|info| info.line_boundary() != LineBoundary::None)
+        .collect()
+}
+
+/// Runs a normalization case in the form `c1;c2;c3;c4;c5;` and verifies
+/// the invariants listed in the header of the test file.
+fn normalization(case: &str) -> Outcome {
+    let columns = case
+        .split(';')
+        .take(5)
+        .map(|column| parse_chars(column.split_whitespace()))
+        .collect::<Option<Vec<_>>>();
+    let columns = match columns {
+        Some(columns) if columns.len() == 5 => columns,
+        _ => return Outcome::Skip,
+    };
+    // Expected column for each source column and each of NFC, NFD, NFKC
+    // and NFKD.
+    const EXPECTED: [[usize; 4]; 5] = [
+        [1, 2, 3, 4],
+        [1, 2, 3, 4],
+        [1, 2, 3, 4],
+        [3, 4, 3, 4],
+        [3, 4, 3, 4],
+    ];
+    const FORMS: [&str; 4] = ["NFC", "NFD", "NFKC", "NFKD"];
+    for (source, expected) in columns.iter().zip(EXPECTED.iter()) {
+        for (form, &expected) in expected.iter().enumerate() {
+            let compat = form >= 2;
+            let mut actual = decompose(source, compat);
+            if form % 2 == 0 {
+                actual = compose(&actual);
+            }
+            if actual != columns[expected] {
+                let mut s = String::new();
+                let _ = write!(s, "{}(", FORMS[form]);
+                for (i, ch) in source.iter().enumerate() {
+                    let sep = if i == 0 { "" } else { " " };
+                    let _ = write!(s, "{}{:04X}", sep, *ch as u32);
+                }
+                s.push_str(") =");
+                for ch in &actual {
+                    let _ = write!(s, " {:04X}", *ch as u32);
+                }
+                return Outcome::Fail(s);
+            }
+        }
+    }
+    Outcome::Pass
+}
+
+/// Returns the full decomposition of the characters in canonical order.
+fn decompose(chars: &[char], compat: bool) -> Vec<char> {
+    let mut result = Vec::new();
+    for &ch in chars {
+        let decomposed = if compat {
+            ch.decompose_compatible()
+        } else {
+            ch.decompose()
+        };
+        result.extend_from_slice(decomposed.chars());
+    }
+    // Stable sort of each run of non-starters by combining class.
+    let mut start = 0;
+    while start < result.len() {
+        let len = result[start..]
+            .iter()
+            .take_while(|ch| ch.combining_class() != 0)
+            .count();
+        result[start..start + len].sort_by_key(|ch| ch.combining_class());
+        start += len.max(1);
+    }
+    result
+}
+
+/// Applies the canonical composition algorithm to decomposed characters.
+fn compose(chars: &[char]) -> Vec<char> {
+    let mut result: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    // Combining class of the last character following the starter.
+    let mut last_class = None;
+    for &ch in chars {
+        let class = ch.combining_class();
+        if let Some(starter) = starter {
+            let blocked = matches!(last_class, Some(last) if last == 0 || last >= class);
+            if !blocked {
+                if let Some(composed) = char::compose(result[starter], ch) {
+                    result[starter] = composed;
+                    continue;
+                }
+            }
+        }
+        if class == 0 {
+            starter = Some(result.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        result.push(ch);
+    }
+    result
+}
+
+/// Runs a BiDi case in the form `L R AN; 7`, where the number is a bit set
+/// of paragraph levels: auto, left-to-right and right-to-left. The expected
+/// levels are set by the preceding `@Levels:` line, where characters that
+/// are removed by rule X9 are marked with `x`.
+#[cfg(all(feature = "unicode-bidi", feature = "bidi"))]
+// Option::is_none_or requires Rust 1.82.
+#[allow(clippy::unnecessary_map_or)]
+fn bidi(case: &str, levels: &[Option<u8>]) -> Outcome {
+    use crate::unicode_bidi::bidi_info;
+    use ::unicode_bidi::Level;
+    let mut parts = case.split(';');
+    let classes = parts.next().unwrap_or("");
+    let bits = parts.next().and_then(|bits| bits.trim().parse::<u8>().ok());
+    let text = classes
+        .split_whitespace()
+        .map(representative)
+        .collect::<Option<String>>();
+    let (bits, text) = match (bits, text) {
+        (Some(bits), Some(text)) => (bits, text),
+        _ => return Outcome::Skip,
+    };
+    for &(bit, level) in [(1, None), (2, Some(Level::ltr())), (4, Some(Level::rtl()))].iter() {
+        if bits & bit == 0 {
+            continue;
+        }
+        let info = bidi_info(&text, level);
+        let actual = text
+            .char_indices()
+            .map(|(offset, _)| info.levels[offset].number())
+            .collect::<Vec<_>>();
+        let matches = actual.len() == levels.len()
+            && actual
+                .iter()
+                .zip(levels.iter())
+                .all(|(a, e)| e.map_or(true, |e| e == *a));
+        if !matches {
+            let mut s = String::from("@Levels:");
+            for (level, expected) in actual.iter().zip(levels.iter()) {
+                match expected {
+                    Some(_) => {
+                        let _ = write!(s, " {}", level);
+                    }
+                    None => s.push_str(" x"),
+                }
+            }
+            let _ = write!(s, " for {}; {}", classes.trim(), bit);
+            return Outcome::Fail(s);
+        }
+    }
+    Outcome::Pass
+}
+
+#[cfg(not(all(feature = "unicode-bidi", feature = "bidi")))]
+fn bidi(_case: &str, _levels: &[Option<u8>]) -> Outcome {
+    Outcome::Skip
+}
+
+/// Returns a character with the specified BiDi class.
+#[cfg(all(feature = "unicode-bidi", feature = "bidi"))]
+fn representative(class: &str) -> Option<char> {
+    Some(match class {
+        "L" => 'a',
+        "R" => '\u{05D0}',
+        "AL" => '\u{0627}',
+        "EN" => '0',
+        "ES" => '+',
+        "ET" => '#',
+        "AN" => '\u{0660}',
+        "CS" => ',',
+        "NSM" => '\u{0300}',
+        "BN" => '\u{00AD}',
+        "B" => '\u{2029}',
+        "S" => '\t',
+        "WS" => ' ',
+        "ON" => '!',
+        "LRE" => '\u{202A}',
+        "RLE" => '\u{202B}',
+        "PDF" => '\u{202C}',
+        "LRO" => '\u{202D}',
+        "RLO" => '\u{202E}',
+        "LRI" => '\u{2066}',
+        "RLI" => '\u{2067}',
+        "FSI" => '\u{2068}',
+        "PDI" => '\u{2069}',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segmentation_cases() {
+        let graphemes = "\
+÷ 0020 ÷ 0020 ÷
+÷ 0061 × 0308 ÷ 0062 ÷
+÷ 000D × 000A ÷ 0061 ÷
+÷ 1100 × 1161 × 11A8 ÷ 1100 ÷
+÷ AC00 × 11A8 ÷ 1100 ÷
+÷ 1F476 × 1F3FF ÷ 1F476 ÷
+÷ 1F6D1 × 200D × 1F6D1 ÷
+";
+        let words = "\
+÷ 0061 × 0062 ÷ 0020 ÷ 0063 ÷
+÷ 0031 × 002E × 0032 ÷
+÷ 0063 × 0061 × 006E × 0027 × 0074 ÷
+÷ 30A2 × 30A2 ÷
+";
+        let lines = "\
+× 0061 × 0020 ÷ 0062 ÷
+× 4E00 ÷ 4E00 ÷
+× 0061 × 000A ÷ 0062 ÷
+× 0028 × 0061 × 0029 ÷
+";
+        for &(suite, data) in &[
+            (Suite::GraphemeBreak, graphemes),
+            (Suite::WordBreak, words),
+            (Suite::LineBreak, lines),
+        ] {
+            let report = run(suite, data);
+            assert!(report.is_success(), "{}: {:?}", report, report.failures);
+            assert_eq!(report.passed, data.lines().count());
+            let report = run(suite, &regional_indicator_cases(8));
+            assert!(report.is_success(), "{}: {:?}", report, report.failures);
+        }
+    }
+
+    #[test]
+    fn normalization_cases() {
+        let data = "\
+# Comment
+@Part0 # Directive
+1E0A;1E0A;0044 0307;1E0A;0044 0307; # comment
+00C5;00C5;0041 030A;00C5;0041 030A;
+AC00;AC00;1100 1161;AC00;1100 1161;
+FB01;FB01;FB01;0066 0069;0066 0069;
+0061 0315 0300 05AE 0300 0062;00E0 05AE 0300 0315 0062;0061 05AE 0300 0300 0315 0062;00E0 05AE 0300 0315 0062;0061 05AE 0300 0300 0315 0062;
+";
+        let report = run(Suite::Normalization, data);
+        assert!(report.is_success(), "{}: {:?}", report, report.failures);
+        assert_eq!((report.passed, report.skipped), (5, 0));
+    }
+
+    #[test]
+    fn failures_and_skips() {
+        let report = run(Suite::GraphemeBreak, "÷ 0061 ÷ 0308 ÷\n÷ D800 ÷ 0061 ÷\n");
+        assert_eq!((report.passed, report.skipped), (0, 1));
+        let failure = &report.failures[0];
+        assert_eq!(failure.line, 1);
+        assert_eq!(failure.actual, "÷ 0061 × 0308 ÷");
+        let report = run(Suite::SentenceBreak, "÷ 0061 ÷\n");
+        assert_eq!((report.skipped, report.is_success()), (1, false));
+        assert_eq!(
+            Suite::from_file_name("LineBreakTest.txt"),
+            Some(Suite::LineBreak)
+        );
+    }
+
+    /// Runs the test files in the directory named by `LIPI_UCD_TESTS`,
+    /// which must match the Unicode version of the crate:
+    ///
+    /// ```text
+    /// LIPI_UCD_TESTS=path/to/ucd cargo test --features conformance -- --ignored
+    /// ```
+    #[test]
+    #[ignore = "requires the UCD test files in LIPI_UCD_TESTS"]
+    fn ucd_files() {
+        extern crate std;
+        let dir = std::env::var("LIPI_UCD_TESTS").expect("LIPI_UCD_TESTS is not set");
+        let mut ran = 0;
+        for suite in Suite::all().filter(|suite| suite.is_supported()) {
+            let path = std::path::Path::new(&dir).join(suite.file_name());
+            let data = match std::fs::read_to_string(&path) {
+                Ok(data) => data,
+                Err(_) => continue,
+            };
+            let report = run(suite, &data);
+            let failures = report.failures.iter().take(10);
+            let failures = failures
+                .map(|f| alloc::format!("{}", f))
+                .collect::<Vec<_>>();
+            assert!(report.is_success(), "{}\n{}", report, failures.join("\n"));
+            ran += 1;
+        }
+        assert!(ran != 0, "no test files found in {}", dir);
+    }
+}
@@ -0,0 +1,126 @@
+/*!
+Conversions to the script and language representations used by HarfBuzz.
+
+HarfBuzz identifies scripts with `hb_script_t` values, which are the ISO
+15924 codes packed as big endian tags, and languages with BCP 47 strings
+that are normalized to lowercase by `hb_language_from_string`.
+*/
+
+use super::locale::{Locale, MAX_LOCALE_TAG_LEN};
+use super::unicode::Script;
+use core::fmt::{self, Write};
+
+impl Script {
+    /// Returns the HarfBuzz script (`hb_script_t`) value for the script.
+    pub fn to_harfbuzz(self) -> u32 {
+        u32::from_be_bytes(self.iso15924_code())
+    }
+
+    /// Returns the script for the specified HarfBuzz script value. Like
+    /// `hb_script_from_iso15924_tag`, this ignores case and accepts the
+    /// ISO 15924 aliases for Coptic, Georgian, Inherited and Syriac.
+    pub fn from_harfbuzz(script: u32) -> Option<Self> {
+        let code = (script & 0xDFDF_DFDF | 0x0020_2020).to_be_bytes();
+        let code = match &code {
+            b"Qaac" => *b"Copt",
+            b"Qaai" => *b"Zinh",
+            b"Geok" => *b"Geor",
+            b"Syre" | b"Syrj" | b"Syrn" => *b"Syrc",
+            _ => code,
+        };
+        Self::from_iso15924_code(code)
+    }
+}
+
+impl Locale {
+    /// Returns the language string for the locale in the form produced by
+    /// `hb_language_to_string`, suitable for `hb_language_from_string`.
+    pub fn to_harfbuzz_language(&self) -> HarfBuzzLanguage {
+        let mut language = HarfBuzzLanguage {
+            buf: [0; LANGUAGE_CAPACITY],
+            len: 0,
+        };
+        if write!(language, "{}", self).is_err() {
+            // Omit the variant and extension subtags rather than emitting a
+            // truncated tag.
+            language.len = 0;
+            let _ = language.write_str(self.language());
+            for subtag in self.script().iter().chain(self.region().iter()) {
+                let _ = write!(language, "-{}", subtag);
+            }
+        }
+        language
+    }
+}
+
+/// Capacity of a language string, which holds any tag that can be
+/// represented by a locale.
+const LANGUAGE_CAPACITY: usize = MAX_LOCALE_TAG_LEN;
+
+/// Lowercase BCP 47 language string for HarfBuzz. This is created by the
+/// [`Locale::to_harfbuzz_language`] method.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct HarfBuzzLanguage {
+    buf: [u8; LANGUAGE_CAPACITY],
+    len: u8,
+}
+
+impl HarfBuzzLanguage {
+    /// Returns the language string.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The buffer only contains ASCII characters written from
+        // a validated locale.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len as usize]) }
+    }
+}
+
+impl Write for HarfBuzzLanguage {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.len as usize;
+        let end = start + s.len();
+        if end > LANGUAGE_CAPACITY {
+            return Err(fmt::Error);
+        }
+        for (d, s) in self.buf[start..end].iter_mut().zip(s.bytes()) {
+            *d = s.to_ascii_lowercase();
+        }
+        self.len = end as u8;
+        Ok(())
+    }
+}
+
+impl fmt::Display for HarfBuzzLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for HarfBuzzLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_language() {
+        let tag = "yue-Hant-419-x-aaaaaaaa-bbbbbbbb-cccccccc-dddddddd-eeeeeeee-ffffffff-gggggggg";
+        let locale = Locale::parse(tag).unwrap();
+        assert_eq!(
+            locale.to_harfbuzz_language().as_str(),
+            "yue-hant-419-x-aaaaaaaa-bbbbbbbb-cccccccc-dddddddd-eeeeeeee-ffffffff-gggggggg"
+        );
+    }
+
+    #[test]
+    fn script_round_trip() {
+        assert_eq!(Script::Arabic.to_harfbuzz(), u32::from_be_bytes(*b"Arab"));
+        assert_eq!(
+            Script::from_harfbuzz(u32::from_be_bytes(*b"Qaai")),
+            Some(Script::Inherited)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+/*!
+Conversions between Unicode property values and the corresponding types
+in the `icu_properties` crate.
+
+Conversions to the ICU4X types are infallible except for general category
+groups, which have no equivalent [`GeneralCategory`](props::GeneralCategory).
+Conversions from the ICU4X types fail for values that were introduced in
+versions of Unicode newer than the data in this crate.
+*/
+
+use super::unicode::{
+    BidiClass, Category, ClusterBreak, JoiningType, LineBreak, Script, WordBreak,
+};
+use core::convert::TryFrom;
+use core::fmt;
+use icu_properties::props;
+
+/// Error returned when a property value has no equivalent in the target
+/// type.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ConversionError;
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "property value has no equivalent")
+    }
+}
+
+/// Implements conversions for property values that share the same names in
+/// both crates.
+macro_rules! impl_conversions {
+    ($ty:ident, $icu:ident, [$($value:ident),* $(,)?]) => {
+        impl From<$ty> for props::$icu {
+            fn from(value: $ty) -> Self {
+                match value {
+                    $($ty::$value => Self::$value,)*
+                }
+            }
+        }
+
+        impl TryFrom<props::$icu> for $ty {
+            type Error = ConversionError;
+
+            fn try_from(value: props::$icu) -> Result<Self, Self::Error> {
+                Ok(match value {
+                    $(props::$icu::$value => Self::$value,)*
+                    _ => return Err(ConversionError),
+                })
+            }
+        }
+    };
+}
+
+impl_conversions!(
+    Script,
+    Script,
+    [
+        Adlam,
+        CaucasianAlbanian,
+        Ahom,
+        Arabic,
+        ImperialAramaic,
+        Armenian,
+        Avestan,
+        Balinese,
+        Bamum,
+        BassaVah,
+        Batak,
+        Bengali,
+        Bhaiksuki,
+        Bopomofo,
+        Brahmi,
+        Braille,
+        Buginese,
+        Buhid,
+        Chakma,
+        CanadianAboriginal,
+        Carian,
+        Cham,
+        Cherokee,
+        Chorasmian,
+        Coptic,
+        Cypriot,
+        Cyrillic,
+        Devanagari,
+        DivesAkuru,
+        Dogra,
+        Deseret,
+        Duployan,
+        EgyptianHieroglyphs,
+        Elbasan,
+        Elymaic,
+        Ethiopic,
+        Georgian,
+        Glagolitic,
+        GunjalaGondi,
+        MasaramGondi,
+        Gothic,
+        Grantha,
+        Greek,
+        Gujarati,
+        Gurmukhi,
+        Hangul,
+        Han,
+        Hanunoo,
+        Hatran,
+        Hebrew,
+        Hiragana,
+        AnatolianHieroglyphs,
+        PahawhHmong,
+        NyiakengPuachueHmong,
+        OldHungarian,
+        OldItalic,
+        Javanese,
+        KayahLi,
+        Katakana,
+        Kharoshthi,
+        Khmer,
+        Khojki,
+        KhitanSmallScript,
+        Kannada,
+        Kaithi,
+        TaiTham,
+        Lao,
+        Latin,
+        Lepcha,
+        Limbu,
+        LinearA,
+        LinearB,
+        Lisu,
+        Lycian,
+        Lydian,
+        Mahajani,
+        Makasar,
+        Mandaic,
+        Manichaean,
+        Marchen,
+        Medefaidrin,
+        MendeKikakui,
+        MeroiticCursive,
+        MeroiticHieroglyphs,
+        Malayalam,
+        Modi,
+        Mongolian,
+        Mro,
+        MeeteiMayek,
+        Multani,
+        Myanmar,
+        Nandinagari,
+        OldNorthArabian,
+        Nabataean,
+        Newa,
+        Nko,
+        Nushu,
+        Ogham,
+        OlChiki,
+        OldTurkic,
+        Oriya,
+        Osage,
+        Osmanya,
+        Palmyrene,
+        PauCinHau,
+        OldPermic,
+        PhagsPa,
+        InscriptionalPahlavi,
+        PsalterPahlavi,
+        Phoenician,
+        Miao,
+        InscriptionalParthian,
+        Rejang,
+        HanifiRohingya,
+        Runic,
+        Samaritan,
+        OldSouthArabian,
+        Saurashtra,
+        SignWriting,
+        Shavian,
+        Sharada,
+        Siddham,
+        Khudawadi,
+        Sinhala,
+        Sogdian,
+        OldSogdian,
+        SoraSompeng,
+        Soyombo,
+        Sundanese,
+        SylotiNagri,
+        Syriac,
+        Tagbanwa,
+        Takri,
+        TaiLe,
+        NewTaiLue,
+        Tamil,
+        Tangut,
+        TaiViet,
+        Telugu,
+        Tifinagh,
+        Tagalog,
+        Thaana,
+        Thai,
+        Tibetan,
+        Tirhuta,
+        Ugaritic,
+        Vai,
+        WarangCiti,
+        Wancho,
+        OldPersian,
+        Cuneiform,
+        Yezidi,
+        Yi,
+        ZanabazarSquare,
+        Inherited,
+        Common,
+        Unknown,
+    ]
+);
+
+impl_conversions!(
+    BidiClass,
+    BidiClass,
+    [
+        AL, AN, B, BN, CS, EN, ES, ET, FSI, L, LRE, LRI, LRO, NSM, ON, PDF, PDI, R, RLE, RLI, RLO,
+        S, WS,
+    ]
+);
+
+impl_conversions!(
+    ClusterBreak,
+    GraphemeClusterBreak,
+    [CN, CR, EX, L, LF, LV, LVT, PP, RI, SM, T, V, XX, ZWJ]
+);
+
+impl_conversions!(
+    LineBreak,
+    LineBreak,
+    [
+        AI, AL, B2, BA, BB, BK, CB, CJ, CL, CM, CP, CR, EB, EM, EX, GL, H2, H3, HL, HY, ID, IN, IS,
+        JL, JT, JV, LF, NL, NS, NU, OP, PO, PR, QU, RI, SA, SG, SP, SY, WJ, XX, ZW, ZWJ,
+    ]
+);
+
+impl_conversions!(
+    WordBreak,
+    WordBreak,
+    [CR, DQ, EX, Extend, FO, HL, KA, LE, LF, MB, ML, MN, NL, NU, RI, SQ, WSegSpace, XX, ZWJ]
+);
+
+/// The Syriac `Alaph` and `DalathRish` joining types are right joining.
+impl From<JoiningType> for props::JoiningType {
+    fn from(value: JoiningType) -> Self {
+        use JoiningType::*;
+        match value {
+            U => Self::NonJoining,
+            L => Self::LeftJoining,
+            R | Alaph | DalathRish => Self::RightJoining,
+            D => Self::DualJoining,
+            T => Self::Transparent,
+        }
+    }
+}
+
+/// Join causing characters are treated as dual joining.
+impl TryFrom<props::JoiningType> for JoiningType {
+    type Error = ConversionError;
+
+    fn try_from(value: props::JoiningType) -> Result<Self, Self::Error> {
+        use props::JoiningType as Icu;
+        Ok(match value {
+            Icu::NonJoining => Self::U,
+            Icu::LeftJoining => Self::L,
+            Icu::RightJoining => Self::R,
+            Icu::DualJoining | Icu::JoinCausing => Self::D,
+            Icu::Transparent => Self::T,
+            _ => return Err(ConversionError),
+        })
+    }
+}
+
+/// Unassigned code points are converted to [`Category::Other`], which is
+/// the category reported for them by [`Properties`](crate::unicode::Properties).
+impl From<props::GeneralCategory> for Category {
+    fn from(value: props::GeneralCategory) -> Self {
+        use props::GeneralCategory as Icu;
+        match value {
+            Icu::Unassigned => Self::Other,
+            Icu::UppercaseLetter => Self::UppercaseLetter,
+            Icu::LowercaseLetter => Self::LowercaseLetter,
+            Icu::TitlecaseLetter => Self::TitlecaseLetter,
+            Icu::ModifierLetter => Self::ModifierLetter,
+            Icu::OtherLetter => Self::OtherLetter,
+            Icu::NonspacingMark => Self::NonspacingMark,
+            Icu::EnclosingMark => Self::EnclosingMark,
+            Icu::SpacingMark => Self::SpacingMark,
+            Icu::DecimalNumber => Self::DecimalNumber,
+            Icu::LetterNumber => Self::LetterNumber,
+            Icu::OtherNumber => Self::OtherNumber,
+            Icu::SpaceSeparator => Self::SpaceSeparator,
+            Icu::LineSeparator => Self::LineSeparator,
+            Icu::ParagraphSeparator => Self::ParagraphSeparator,
+            Icu::Control => Self::Control,
+            Icu::Format => Self::Format,
+            Icu::PrivateUse => Self::PrivateUse,
+            Icu::Surrogate => Self::Surrogate,
+            Icu::DashPunctuation => Self::DashPunctuation,
+            Icu::OpenPunctuation => Self::OpenPunctuation,
+            Icu::ClosePunctuation => Self::ClosePunctuation,
+            Icu::ConnectorPunctuation => Self::ConnectorPunctuation,
+            Icu::OtherPunctuation => Self::OtherPunctuation,
+            Icu::MathSymbol => Self::MathSymbol,
+            Icu::CurrencySymbol => Self::CurrencySymbol,
+            Icu::ModifierSymbol => Self::ModifierSymbol,
+            Icu::OtherSymbol => Self::OtherSymbol,
+            Icu::InitialPunctuation => Self::InitialPunctuation,
+            Icu::FinalPunctuation => Self::FinalPunctuation,
+        }
+    }
+}
+
+/// Fails for the categories that represent groups, such as
+/// [`Category::Letter`], with the exception of [`Category::Other`] which
+/// is reported for unassigned code points.
+impl TryFrom<Category> for props::GeneralCategory {
+    type Error = ConversionError;
+
+    fn try_from(value: Category) -> Result<Self, Self::Error> {
+        use Category::*;
+        Ok(match value {
+            Other | Unassigned => Self::Unassigned,
+            UppercaseLetter => Self::UppercaseLetter,
+            LowercaseLetter => Self::LowercaseLetter,
+            TitlecaseLetter => Self::TitlecaseLetter,
+            ModifierLetter => Self::ModifierLetter,
+            OtherLetter => Self::OtherLetter,
+            NonspacingMark => Self::NonspacingMark,
+            EnclosingMark => Self::EnclosingMark,
+            SpacingMark => Self::SpacingMark,
+            DecimalNumber => Self::DecimalNumber,
+            LetterNumber => Self::LetterNumber,
+            OtherNumber => Self::OtherNumber,
+            SpaceSeparator => Self::SpaceSeparator,
+            LineSeparator => Self::LineSeparator,
+            ParagraphSeparator => Self::ParagraphSeparator,
+            Control => Self::Control,
+            Format => Self::Format,
+            PrivateUse => Self::PrivateUse,
+            Surrogate => Self::Surrogate,
+            DashPunctuation => Self::DashPunctuation,
+            OpenPunctuation => Self::OpenPunctuation,
+            ClosePunctuation => Self::ClosePunctuation,
+            ConnectorPunctuation => Self::ConnectorPunctuation,
+            OtherPunctuation => Self::OtherPunctuation,
+            MathSymbol => Self::MathSymbol,
+            CurrencySymbol => Self::CurrencySymbol,
+            ModifierSymbol => Self::ModifierSymbol,
+            OtherSymbol => Self::OtherSymbol,
+            InitialPunctuation => Self::InitialPunctuation,
+            FinalPunctuation => Self::FinalPunctuation,
+            Letter | CasedLetter | Mark | Number | Punctuation | Symbol | Separator => {
+                return Err(ConversionError)
+            }
+        })
+    }
+}
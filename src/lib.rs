@@ -4,14 +4,65 @@ Font independent text analysis support for shaping and layout.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Implements serde traits for a fieldless enum with contiguous
+/// discriminants, using the discriminant as the serialized value. The
+/// second argument names the variant with the largest discriminant.
+#[cfg(feature = "serde")]
+macro_rules! impl_serde_discriminant {
+    ($ty:ident, $last:ident) => {
+        impl serde::Serialize for $ty {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_u8(*self as u8)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $ty {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <u8 as serde::Deserialize>::deserialize(deserializer)?;
+                if value > $ty::$last as u8 {
+                    return Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Unsigned(value as u64),
+                        &concat!("a ", stringify!($ty), " discriminant"),
+                    ));
+                }
+                // SAFETY: The discriminants are contiguous and the value was
+                // checked against the largest one.
+                Ok(unsafe { core::mem::transmute::<u8, Self>(value) })
+            }
+        }
+    };
+}
+
 // Avoid errors for generated Unicode data.
 
 mod compose;
 
-#[allow(clippy::upper_case_acronyms)]
+#[allow(
+    clippy::upper_case_acronyms,
+    clippy::large_const_arrays,
+    clippy::too_many_arguments
+)]
 mod unicode_data;
+#[cfg(feature = "unihan")]
+mod unihan_data;
 
 pub mod cluster;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+#[cfg(feature = "harfbuzz")]
+pub mod harfbuzz;
+#[cfg(feature = "icu_properties")]
+pub mod icu;
 pub mod locale;
 pub mod paragraph;
 pub mod unicode;
+#[cfg(feature = "unicode-bidi")]
+pub mod unicode_bidi;
+#[cfg(feature = "wasm")]
+pub mod wasm;
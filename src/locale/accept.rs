@@ -0,0 +1,164 @@
+//! Parsing support for the HTTP `Accept-Language` header.
+
+use super::{negotiate, Locale};
+
+/// The maximum number of entries of an `Accept-Language` header that are
+/// retained by [`parse_accept_language`].
+pub const MAX_ACCEPT_LANGUAGE_ENTRIES: usize = 16;
+
+/// Returns an iterator that yields the locales and quality values of an
+/// `Accept-Language` header in priority order. Entries with higher quality
+/// values come first and entries with equal quality values retain the order
+/// in which they appear in the header.
+///
+/// Entries that are malformed, have a quality value of zero or consist of
+/// the `*` wildcard are skipped. The header is parsed up front and only the
+/// first [`MAX_ACCEPT_LANGUAGE_ENTRIES`] entries in priority order are
+/// retained.
+///
+/// For example, `fr-CH, fr;q=0.9, en;q=0.8, de;q=0.7, *;q=0.5` yields
+/// `(fr-CH, 1.0)`, `(fr, 0.9)`, `(en, 0.8)` and `(de, 0.7)`.
+pub fn parse_accept_language(header: &str) -> AcceptLanguage {
+    let mut entries = AcceptLanguage {
+        entries: [None; MAX_ACCEPT_LANGUAGE_ENTRIES],
+        len: 0,
+        pos: 0,
+    };
+    for entry in header.split(',').filter_map(parse_entry) {
+        entries.insert(entry);
+    }
+    entries
+}
+
+/// Returns the available locale that best matches the locales requested by
+/// an `Accept-Language` header. The requested locales are tried in priority
+/// order and the first one with an acceptable match determines the result.
+pub fn negotiate_accept_language<'a>(header: &str, available: &'a [Locale]) -> Option<&'a Locale> {
+    parse_accept_language(header).find_map(|(locale, _)| negotiate(&locale, available))
+}
+
+/// Iterator over the locales in an `Accept-Language` header. This iterator
+/// is created by the [`parse_accept_language`] function.
+#[derive(Clone)]
+pub struct AcceptLanguage {
+    /// Entries sorted in priority order.
+    entries: [Option<(Locale, f32)>; MAX_ACCEPT_LANGUAGE_ENTRIES],
+    len: usize,
+    pos: usize,
+}
+
+impl AcceptLanguage {
+    /// Inserts an entry after those with an equal or higher quality value,
+    /// discarding the last entry when full.
+    fn insert(&mut self, entry: (Locale, f32)) {
+        let q = entry.1;
+        let pos = self.entries[..self.len]
+            .iter()
+            .position(|e| matches!(e, Some((_, e)) if *e < q))
+            .unwrap_or(self.len);
+        if pos == MAX_ACCEPT_LANGUAGE_ENTRIES {
+            return;
+        }
+        let end = self.len.min(MAX_ACCEPT_LANGUAGE_ENTRIES - 1);
+        self.entries.copy_within(pos..end, pos + 1);
+        self.entries[pos] = Some(entry);
+        self.len = end + 1;
+    }
+}
+
+impl Iterator for AcceptLanguage {
+    type Item = (Locale, f32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos == self.len {
+            return None;
+        }
+        self.pos += 1;
+        self.entries[self.pos - 1]
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for AcceptLanguage {}
+
+/// Parses a single entry of the header, returning the locale and quality
+/// value.
+fn parse_entry(entry: &str) -> Option<(Locale, f32)> {
+    let mut params = entry.split(';');
+    let tag = params.next()?.trim();
+    if tag.is_empty() || tag == "*" {
+        return None;
+    }
+    let mut q = 1.0;
+    for param in params {
+        let mut kv = param.splitn(2, '=');
+        let key = kv.next()?.trim();
+        if key.eq_ignore_ascii_case("q") {
+            q = kv.next()?.trim().parse::<f32>().ok()?;
+            if !(0.0..=1.0).contains(&q) {
+                return None;
+            }
+        }
+    }
+    if q == 0.0 {
+        return None;
+    }
+    Some((Locale::parse(tag)?, q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags<const N: usize>(header: &str) -> ([(Locale, f32); N], usize) {
+        let und = Locale::parse("und").unwrap();
+        let mut tags = [(und, 0.0); N];
+        let mut len = 0;
+        for entry in parse_accept_language(header) {
+            tags[len] = entry;
+            len += 1;
+        }
+        (tags, len)
+    }
+
+    #[test]
+    fn priority_order() {
+        let header = "de;q=0.7, fr-CH, *;q=0.5, en;q=0.8, fr;q=0.9, x;q=2, es;q=0, it;q=0.8";
+        let (tags, len) = tags::<8>(header);
+        let expected = [
+            ("fr-CH", 1.0),
+            ("fr", 0.9),
+            ("en", 0.8),
+            ("it", 0.8),
+            ("de", 0.7),
+        ];
+        assert_eq!(len, expected.len());
+        for ((locale, q), (tag, expected_q)) in tags.iter().zip(&expected) {
+            assert!(*locale == Locale::parse(tag).unwrap(), "{}", tag);
+            assert_eq!(q, expected_q);
+        }
+        assert_eq!(parse_accept_language(header).len(), 5);
+    }
+
+    #[test]
+    fn many_entries() {
+        // The last entries in priority order are discarded.
+        let header = "aa;q=0.1, ab, ac, ad, ae, af, ag, ah, ai, aj, ak, al, am, an, ao, ap, aq, ar";
+        let (tags, len) = tags::<20>(header);
+        assert_eq!(len, MAX_ACCEPT_LANGUAGE_ENTRIES);
+        assert!(tags[0].0 == Locale::parse("ab").unwrap());
+        assert!(tags[len - 1].0 == Locale::parse("aq").unwrap());
+    }
+
+    #[test]
+    fn negotiation() {
+        let available = [Locale::parse("en").unwrap(), Locale::parse("fr").unwrap()];
+        let result = negotiate_accept_language("de, fr-CH;q=0.9, en;q=0.8", &available);
+        assert!(result == Some(&available[1]));
+        assert!(negotiate_accept_language("", &available).is_none());
+    }
+}
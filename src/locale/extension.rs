@@ -0,0 +1,379 @@
+//! Parsing support for locale extensions.
+
+use super::{Locale, ParseLocaleError};
+use core::fmt;
+use core::str::FromStr;
+
+/// Key of a keyword in the Unicode locale extension (`-u-`).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum UnicodeKey {
+    /// Calendar algorithm (`ca`).
+    Calendar,
+    /// Currency format style (`cf`).
+    CurrencyFormat,
+    /// Collation type (`co`).
+    Collation,
+    /// Currency type (`cu`).
+    Currency,
+    /// Emoji presentation style (`em`).
+    Emoji,
+    /// First day of the week (`fw`).
+    FirstDay,
+    /// Hour cycle (`hc`).
+    HourCycle,
+    /// Line break style (`lb`).
+    LineBreak,
+    /// Line break word handling (`lw`).
+    LineBreakWord,
+    /// Measurement system (`ms`).
+    Measurement,
+    /// Numbering system (`nu`).
+    Numbers,
+    /// Region override (`rg`).
+    Region,
+    /// Subdivision (`sd`).
+    Subdivision,
+    /// Sentence break suppressions (`ss`).
+    SentenceBreakSuppressions,
+    /// Time zone (`tz`).
+    TimeZone,
+    /// Common variant type (`va`).
+    Variant,
+    /// Any other key, stored in lowercase.
+    Other([u8; 2]),
+}
+
+impl UnicodeKey {
+    /// Returns the key for the specified two character subtag. The
+    /// comparison is case insensitive. Returns `None` if the subtag is not
+    /// a well formed key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        use UnicodeKey::*;
+        let bytes = key.as_bytes();
+        if bytes.len() != 2 || !bytes[0].is_ascii_alphanumeric() || !bytes[1].is_ascii_alphabetic()
+        {
+            return None;
+        }
+        let key = [bytes[0].to_ascii_lowercase(), bytes[1].to_ascii_lowercase()];
+        Some(match &key {
+            b"ca" => Calendar,
+            b"cf" => CurrencyFormat,
+            b"co" => Collation,
+            b"cu" => Currency,
+            b"em" => Emoji,
+            b"fw" => FirstDay,
+            b"hc" => HourCycle,
+            b"lb" => LineBreak,
+            b"lw" => LineBreakWord,
+            b"ms" => Measurement,
+            b"nu" => Numbers,
+            b"rg" => Region,
+            b"sd" => Subdivision,
+            b"ss" => SentenceBreakSuppressions,
+            b"tz" => TimeZone,
+            b"va" => Variant,
+            _ => Other(key),
+        })
+    }
+
+    /// Returns the two character subtag for the key.
+    pub fn as_str(&self) -> &str {
+        use UnicodeKey::*;
+        match self {
+            Calendar => "ca",
+            CurrencyFormat => "cf",
+            Collation => "co",
+            Currency => "cu",
+            Emoji => "em",
+            FirstDay => "fw",
+            HourCycle => "hc",
+            LineBreak => "lb",
+            LineBreakWord => "lw",
+            Measurement => "ms",
+            Numbers => "nu",
+            Region => "rg",
+            Subdivision => "sd",
+            SentenceBreakSuppressions => "ss",
+            TimeZone => "tz",
+            Variant => "va",
+            // Keys are validated as ASCII on construction.
+            Other(key) => core::str::from_utf8(key).unwrap_or(""),
+        }
+    }
+}
+
+impl FromStr for UnicodeKey {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_key(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for UnicodeKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for UnicodeKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for UnicodeKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(super::FromStrVisitor::<Self>::new(
+            "a Unicode extension key",
+        ))
+    }
+}
+
+/// Returns an iterator over the keywords of a Unicode locale extension. The
+/// extension is expected without the leading singleton, as returned by
+/// `Locale::extension('u')`. Leading attributes are skipped and keys without
+/// a value yield `"true"`.
+///
+/// For example, `co-phonebk-nu-arab` yields `(Collation, "phonebk")`
+/// followed by `(Numbers, "arab")`.
+pub fn unicode_keywords(extension: &str) -> UnicodeKeywords<'_> {
+    UnicodeKeywords { rest: extension }
+}
+
+/// Iterator over the keywords of a Unicode locale extension. This iterator
+/// is created by the [`unicode_keywords`] function.
+#[derive(Clone)]
+pub struct UnicodeKeywords<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for UnicodeKeywords<'a> {
+    type Item = (UnicodeKey, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (part, rest) = split_first(self.rest)?;
+            self.rest = rest;
+            // Attributes and malformed subtags preceding a key are skipped.
+            if let Some(key) = UnicodeKey::from_key(part) {
+                let rest = rest.strip_prefix('-').unwrap_or(rest);
+                let mut end = 0;
+                for value in rest.split('-') {
+                    if value.len() < 3 {
+                        break;
+                    }
+                    end += value.len() + 1;
+                }
+                let end = end.saturating_sub(1).min(rest.len());
+                let value = &rest[..end];
+                self.rest = &rest[end..];
+                return Some((key, if value.is_empty() { "true" } else { value }));
+            }
+        }
+    }
+}
+
+/// Splits the first hyphen separated subtag from the string, ignoring a
+/// leading hyphen.
+fn split_first(s: &str) -> Option<(&str, &str)> {
+    let s = s.strip_prefix('-').unwrap_or(s);
+    if s.is_empty() {
+        return None;
+    }
+    Some(match s.find('-') {
+        Some(pos) => (&s[..pos], &s[pos..]),
+        None => (s, ""),
+    })
+}
+
+/// Key of a field in the transformed content extension (`-t-`).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TransformKey {
+    /// Destination content (`d0`).
+    Destination,
+    /// Hybrid locale (`h0`).
+    Hybrid,
+    /// Input method engine (`i0`).
+    Input,
+    /// Keyboard layout (`k0`).
+    Keyboard,
+    /// Transform mechanism (`m0`).
+    Mechanism,
+    /// Source content (`s0`).
+    Source,
+    /// Machine translation (`t0`).
+    Translation,
+    /// Private use (`x0`).
+    Private,
+    /// Any other key, stored in lowercase.
+    Other([u8; 2]),
+}
+
+impl TransformKey {
+    /// Returns the key for the specified two character subtag. The
+    /// comparison is case insensitive. Returns `None` if the subtag is not
+    /// a well formed key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        use TransformKey::*;
+        let bytes = key.as_bytes();
+        if bytes.len() != 2 || !bytes[0].is_ascii_alphabetic() || !bytes[1].is_ascii_digit() {
+            return None;
+        }
+        let key = [bytes[0].to_ascii_lowercase(), bytes[1]];
+        Some(match &key {
+            b"d0" => Destination,
+            b"h0" => Hybrid,
+            b"i0" => Input,
+            b"k0" => Keyboard,
+            b"m0" => Mechanism,
+            b"s0" => Source,
+            b"t0" => Translation,
+            b"x0" => Private,
+            _ => Other(key),
+        })
+    }
+
+    /// Returns the two character subtag for the key.
+    pub fn as_str(&self) -> &str {
+        use TransformKey::*;
+        match self {
+            Destination => "d0",
+            Hybrid => "h0",
+            Input => "i0",
+            Keyboard => "k0",
+            Mechanism => "m0",
+            Source => "s0",
+            Translation => "t0",
+            Private => "x0",
+            // Keys are validated as ASCII on construction.
+            Other(key) => core::str::from_utf8(key).unwrap_or(""),
+        }
+    }
+}
+
+impl FromStr for TransformKey {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_key(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for TransformKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TransformKey {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TransformKey {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(super::FromStrVisitor::<Self>::new(
+            "a transformed content extension key",
+        ))
+    }
+}
+
+/// Parsed transformed content extension.
+///
+/// The extension consists of an optional source locale, identifying the
+/// language that the content was transformed from, followed by a sequence
+/// of fields describing the transformation. For example, `ja-t-it-m0-xtra`
+/// describes Japanese content transliterated from Italian.
+#[derive(Copy, Clone, Debug)]
+pub struct Transform<'a> {
+    source: Option<&'a str>,
+    fields: &'a str,
+}
+
+impl<'a> Transform<'a> {
+    /// Parses a transformed content extension. The extension is expected
+    /// without the leading singleton, as returned by `Locale::extension('t')`.
+    pub fn parse(extension: &'a str) -> Self {
+        let extension = extension.strip_prefix('-').unwrap_or(extension);
+        let mut end = 0;
+        for part in extension.split('-') {
+            if TransformKey::from_key(part).is_some() {
+                break;
+            }
+            end += part.len() + 1;
+        }
+        let end = end.saturating_sub(1).min(extension.len());
+        let source = &extension[..end];
+        Self {
+            source: if source.is_empty() {
+                None
+            } else {
+                Some(source)
+            },
+            fields: &extension[end..],
+        }
+    }
+
+    /// Returns the source language tag, if any.
+    pub fn source_tag(&self) -> Option<&'a str> {
+        self.source
+    }
+
+    /// Returns the source locale, if any.
+    pub fn source(&self) -> Option<Locale> {
+        Locale::parse(self.source?)
+    }
+
+    /// Returns an iterator over the fields of the extension. For example,
+    /// `und-Latn-t-ru-m0-ungegn-2007` yields `(Mechanism, "ungegn-2007")`.
+    pub fn fields(&self) -> TransformFields<'a> {
+        TransformFields { rest: self.fields }
+    }
+
+    /// Returns the value of the field with the specified key.
+    pub fn field(&self, key: TransformKey) -> Option<&'a str> {
+        self.fields()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+}
+
+/// Iterator over the fields of a transformed content extension. This
+/// iterator is created by the [`Transform::fields`] method.
+#[derive(Clone)]
+pub struct TransformFields<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for TransformFields<'a> {
+    type Item = (TransformKey, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (part, rest) = split_first(self.rest)?;
+            self.rest = rest;
+            // Fields without a value are malformed and skipped.
+            if let Some(key) = TransformKey::from_key(part) {
+                let rest = rest.strip_prefix('-').unwrap_or(rest);
+                let mut end = 0;
+                for value in rest.split('-') {
+                    if value.len() < 3 {
+                        break;
+                    }
+                    end += value.len() + 1;
+                }
+                let end = end.saturating_sub(1).min(rest.len());
+                self.rest = &rest[end..];
+                if end != 0 {
+                    return Some((key, &rest[..end]));
+                }
+            }
+        }
+    }
+}
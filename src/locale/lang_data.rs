@@ -175,3 +175,849 @@ pub const LANG_ENTRIES: [(&str, &str); 715] = [
     ("Soga", "xog"), ("Liberia Kpelle", "xpe"), ("Yakut", "sah"), ("Yao", "yao"), ("Yapese", "yap"), ("Yoruba", "yo"), ("Cree", "cr"), ("Sichuan Yi", "ii"), ("Zeeuws", "zea"), ("Standard Moroccan Tamazight", "zgh"), ("Zhuang", "za"), ("Chinese", "zh"), ("Chinese", "zh"), ("Chinese", "zh"), ("Chinese", "zh"), ("Zande (individual language)", "zne"), ("Zulu", "zu"), 
     ("Zaza", "zza"), 
 ];
+
+#[rustfmt::skip]
+pub const LIKELY_SUBTAGS: [(&str, &str); 1254] = [
+    ("aa", "aa-Latn-ET"), ("ab", "ab-Cyrl-GE"), ("ace", "ace-Latn-ID"), ("ach", "ach-Latn-UG"),
+    ("ada", "ada-Latn-GH"), ("ady", "ady-Cyrl-RU"), ("ae", "ae-Avst-IR"), ("aeb", "aeb-Arab-TN"),
+    ("af", "af-Latn-ZA"), ("agq", "agq-Latn-CM"), ("ak", "ak-Latn-GH"), ("akk", "akk-Xsux-IQ"),
+    ("aln", "aln-Latn-XK"), ("alt", "alt-Cyrl-RU"), ("am", "am-Ethi-ET"), ("an", "an-Latn-ES"),
+    ("ar", "ar-Arab-EG"), ("arc", "arc-Armi-IR"), ("arc-Nbat", "arc-Nbat-JO"),
+    ("arc-Palm", "arc-Palm-SY"), ("arn", "arn-Latn-CL"), ("aro", "aro-Latn-BO"),
+    ("arq", "arq-Arab-DZ"), ("ars", "ars-Arab-SA"), ("ary", "ary-Arab-MA"), ("arz", "arz-Arab-EG"),
+    ("as", "as-Beng-IN"), ("asa", "asa-Latn-TZ"), ("ase", "ase-Sgnw-US"), ("ast", "ast-Latn-ES"),
+    ("av", "av-Cyrl-RU"), ("awa", "awa-Deva-IN"), ("ay", "ay-Latn-BO"), ("az", "az-Latn-AZ"),
+    ("az-Arab", "az-Arab-IR"), ("az-IQ", "az-Arab-IQ"), ("az-IR", "az-Arab-IR"),
+    ("az-RU", "az-Cyrl-RU"), ("ba", "ba-Cyrl-RU"), ("bal", "bal-Arab-PK"), ("ban", "ban-Latn-ID"),
+    ("bar", "bar-Latn-AT"), ("bas", "bas-Latn-CM"), ("bax", "bax-Bamu-CM"), ("bbc", "bbc-Latn-ID"),
+    ("bbj", "bbj-Latn-CM"), ("be", "be-Cyrl-BY"), ("bej", "bej-Arab-SD"), ("bem", "bem-Latn-ZM"),
+    ("bew", "bew-Latn-ID"), ("bez", "bez-Latn-TZ"), ("bfd", "bfd-Latn-CM"), ("bfq", "bfq-Taml-IN"),
+    ("bg", "bg-Cyrl-BG"), ("bgc", "bgc-Deva-IN"), ("bgn", "bgn-Arab-PK"), ("bho", "bho-Deva-IN"),
+    ("bi", "bi-Latn-VU"), ("bik", "bik-Latn-PH"), ("bin", "bin-Latn-NG"), ("bjn", "bjn-Latn-ID"),
+    ("bkm", "bkm-Latn-CM"), ("bla", "bla-Latn-CA"), ("bm", "bm-Latn-ML"), ("bn", "bn-Beng-BD"),
+    ("bo", "bo-Tibt-CN"), ("bpy", "bpy-Beng-IN"), ("bqi", "bqi-Arab-IR"), ("br", "br-Latn-FR"),
+    ("bra", "bra-Deva-IN"), ("brh", "brh-Arab-PK"), ("brx", "brx-Deva-IN"), ("bs", "bs-Latn-BA"),
+    ("bss", "bss-Latn-CM"), ("bua", "bua-Cyrl-RU"), ("bug", "bug-Latn-ID"), ("bum", "bum-Latn-CM"),
+    ("byn", "byn-Ethi-ER"), ("byv", "byv-Latn-CM"), ("ca", "ca-Latn-ES"), ("cad", "cad-Latn-US"),
+    ("cch", "cch-Latn-NG"), ("ccp", "ccp-Cakm-BD"), ("ce", "ce-Cyrl-RU"), ("ceb", "ceb-Latn-PH"),
+    ("cgg", "cgg-Latn-UG"), ("ch", "ch-Latn-GU"), ("chk", "chk-Latn-FM"), ("chm", "chm-Cyrl-RU"),
+    ("cho", "cho-Latn-US"), ("chp", "chp-Latn-CA"), ("chr", "chr-Cher-US"), ("ckb", "ckb-Arab-IQ"),
+    ("co", "co-Latn-FR"), ("cop", "cop-Copt-EG"), ("cps", "cps-Latn-PH"), ("cr", "cr-Cans-CA"),
+    ("crh", "crh-Cyrl-UA"), ("cs", "cs-Latn-CZ"), ("csb", "csb-Latn-PL"), ("cu", "cu-Cyrl-RU"),
+    ("cu-Glag", "cu-Glag-BG"), ("cv", "cv-Cyrl-RU"), ("cy", "cy-Latn-GB"), ("da", "da-Latn-DK"),
+    ("dak", "dak-Latn-US"), ("dar", "dar-Cyrl-RU"), ("dav", "dav-Latn-KE"), ("de", "de-Latn-DE"),
+    ("den", "den-Latn-CA"), ("dgr", "dgr-Latn-CA"), ("dje", "dje-Latn-NE"), ("doi", "doi-Deva-IN"),
+    ("dsb", "dsb-Latn-DE"), ("dtp", "dtp-Latn-MY"), ("dua", "dua-Latn-CM"), ("dv", "dv-Thaa-MV"),
+    ("dyo", "dyo-Latn-SN"), ("dyu", "dyu-Latn-BF"), ("dz", "dz-Tibt-BT"), ("dzg", "dzg-Latn-ZZ"),
+    ("ebu", "ebu-Latn-KE"), ("ee", "ee-Latn-GH"), ("efi", "efi-Latn-NG"), ("egl", "egl-Latn-IT"),
+    ("egy", "egy-Egyp-EG"), ("eka", "eka-Latn-ZZ"), ("el", "el-Grek-GR"), ("en", "en-Latn-US"),
+    ("en-Shaw", "en-Shaw-GB"), ("es", "es-Latn-ES"), ("esu", "esu-Latn-US"), ("et", "et-Latn-EE"),
+    ("eu", "eu-Latn-ES"), ("ewo", "ewo-Latn-CM"), ("ext", "ext-Latn-ES"), ("fa", "fa-Arab-IR"),
+    ("fan", "fan-Latn-GQ"), ("ff", "ff-Latn-SN"), ("ff-Adlm", "ff-Adlm-GN"), ("fi", "fi-Latn-FI"),
+    ("fil", "fil-Latn-PH"), ("fit", "fit-Latn-SE"), ("fj", "fj-Latn-FJ"), ("fo", "fo-Latn-FO"),
+    ("fon", "fon-Latn-BJ"), ("fr", "fr-Latn-FR"), ("frc", "frc-Latn-US"), ("frp", "frp-Latn-FR"),
+    ("frr", "frr-Latn-DE"), ("frs", "frs-Latn-DE"), ("fur", "fur-Latn-IT"), ("fy", "fy-Latn-NL"),
+    ("ga", "ga-Latn-IE"), ("gaa", "gaa-Latn-GH"), ("gag", "gag-Latn-MD"), ("gan", "gan-Hans-CN"),
+    ("gay", "gay-Latn-ID"), ("gba", "gba-Latn-ZZ"), ("gbz", "gbz-Arab-IR"), ("gd", "gd-Latn-GB"),
+    ("gez", "gez-Ethi-ET"), ("gil", "gil-Latn-KI"), ("gl", "gl-Latn-ES"), ("glk", "glk-Arab-IR"),
+    ("gn", "gn-Latn-PY"), ("gom", "gom-Deva-IN"), ("gon", "gon-Telu-IN"), ("gor", "gor-Latn-ID"),
+    ("got", "got-Goth-UA"), ("grb", "grb-Latn-ZZ"), ("grc", "grc-Cprt-CY"),
+    ("grc-Linb", "grc-Linb-GR"), ("gsw", "gsw-Latn-CH"), ("gu", "gu-Gujr-IN"),
+    ("guc", "guc-Latn-CO"), ("gur", "gur-Latn-GH"), ("guz", "guz-Latn-KE"), ("gv", "gv-Latn-IM"),
+    ("gwi", "gwi-Latn-CA"), ("ha", "ha-Latn-NG"), ("ha-CM", "ha-Arab-CM"), ("ha-SD", "ha-Arab-SD"),
+    ("hak", "hak-Hans-CN"), ("haw", "haw-Latn-US"), ("he", "he-Hebr-IL"), ("hi", "hi-Deva-IN"),
+    ("hif", "hif-Latn-FJ"), ("hil", "hil-Latn-PH"), ("ho", "ho-Latn-PG"), ("hr", "hr-Latn-HR"),
+    ("hsb", "hsb-Latn-DE"), ("hsn", "hsn-Hans-CN"), ("ht", "ht-Latn-HT"), ("hu", "hu-Latn-HU"),
+    ("hy", "hy-Armn-AM"), ("hz", "hz-Latn-NA"), ("iba", "iba-Latn-MY"), ("ibb", "ibb-Latn-NG"),
+    ("id", "id-Latn-ID"), ("ig", "ig-Latn-NG"), ("ii", "ii-Yiii-CN"), ("ik", "ik-Latn-US"),
+    ("ilo", "ilo-Latn-PH"), ("inh", "inh-Cyrl-RU"), ("is", "is-Latn-IS"), ("it", "it-Latn-IT"),
+    ("iu", "iu-Cans-CA"), ("izh", "izh-Latn-RU"), ("ja", "ja-Jpan-JP"), ("jam", "jam-Latn-JM"),
+    ("jgo", "jgo-Latn-CM"), ("jmc", "jmc-Latn-TZ"), ("jut", "jut-Latn-DK"), ("jv", "jv-Latn-ID"),
+    ("ka", "ka-Geor-GE"), ("kaa", "kaa-Cyrl-UZ"), ("kab", "kab-Latn-DZ"), ("kac", "kac-Latn-MM"),
+    ("kaj", "kaj-Latn-NG"), ("kam", "kam-Latn-KE"), ("kaw", "kaw-Kawi-ID"), ("kbd", "kbd-Cyrl-RU"),
+    ("kcg", "kcg-Latn-NG"), ("kde", "kde-Latn-TZ"), ("kea", "kea-Latn-CV"), ("ken", "ken-Latn-CM"),
+    ("kfo", "kfo-Latn-CI"), ("kg", "kg-Latn-CD"), ("kgp", "kgp-Latn-BR"), ("kha", "kha-Latn-IN"),
+    ("khq", "khq-Latn-ML"), ("khw", "khw-Arab-PK"), ("ki", "ki-Latn-KE"), ("kiu", "kiu-Latn-TR"),
+    ("kj", "kj-Latn-NA"), ("kk", "kk-Cyrl-KZ"), ("kk-AF", "kk-Arab-AF"), ("kk-Arab", "kk-Arab-CN"),
+    ("kk-CN", "kk-Arab-CN"), ("kk-IR", "kk-Arab-IR"), ("kk-MN", "kk-Arab-MN"),
+    ("kkj", "kkj-Latn-CM"), ("kl", "kl-Latn-GL"), ("kln", "kln-Latn-KE"), ("km", "km-Khmr-KH"),
+    ("kmb", "kmb-Latn-AO"), ("kn", "kn-Knda-IN"), ("ko", "ko-Kore-KR"), ("koi", "koi-Cyrl-RU"),
+    ("kok", "kok-Deva-IN"), ("kos", "kos-Latn-FM"), ("kpe", "kpe-Latn-LR"), ("kr", "kr-Latn-ZZ"),
+    ("krc", "krc-Cyrl-RU"), ("kri", "kri-Latn-SL"), ("krj", "krj-Latn-PH"), ("krl", "krl-Latn-RU"),
+    ("kru", "kru-Deva-IN"), ("ks", "ks-Arab-IN"), ("ksb", "ksb-Latn-TZ"), ("ksf", "ksf-Latn-CM"),
+    ("ksh", "ksh-Latn-DE"), ("ku", "ku-Latn-TR"), ("ku-Arab", "ku-Arab-IQ"),
+    ("ku-LB", "ku-Arab-LB"), ("ku-Yezi", "ku-Yezi-GE"), ("kum", "kum-Cyrl-RU"),
+    ("kv", "kv-Cyrl-RU"), ("kw", "kw-Latn-GB"), ("ky", "ky-Cyrl-KG"), ("ky-Arab", "ky-Arab-CN"),
+    ("ky-CN", "ky-Arab-CN"), ("ky-Latn", "ky-Latn-TR"), ("ky-TR", "ky-Latn-TR"),
+    ("la", "la-Latn-VA"), ("lad", "lad-Hebr-IL"), ("lag", "lag-Latn-TZ"), ("lah", "lah-Arab-PK"),
+    ("lb", "lb-Latn-LU"), ("lez", "lez-Cyrl-RU"), ("lg", "lg-Latn-UG"), ("li", "li-Latn-NL"),
+    ("lij", "lij-Latn-IT"), ("lkt", "lkt-Latn-US"), ("lmo", "lmo-Latn-IT"), ("ln", "ln-Latn-CD"),
+    ("lo", "lo-Laoo-LA"), ("lol", "lol-Latn-CD"), ("loz", "loz-Latn-ZM"), ("lrc", "lrc-Arab-IR"),
+    ("lt", "lt-Latn-LT"), ("ltg", "ltg-Latn-LV"), ("lu", "lu-Latn-CD"), ("lua", "lua-Latn-CD"),
+    ("luo", "luo-Latn-KE"), ("luy", "luy-Latn-KE"), ("lv", "lv-Latn-LV"), ("lzh", "lzh-Hans-CN"),
+    ("lzz", "lzz-Latn-TR"), ("mad", "mad-Latn-ID"), ("maf", "maf-Latn-CM"), ("mag", "mag-Deva-IN"),
+    ("mai", "mai-Deva-IN"), ("mak", "mak-Latn-ID"), ("man", "man-Latn-GM"),
+    ("man-GN", "man-Nkoo-GN"), ("man-Nkoo", "man-Nkoo-GN"), ("mas", "mas-Latn-KE"),
+    ("mde", "mde-Arab-ZZ"), ("mdf", "mdf-Cyrl-RU"), ("mdh", "mdh-Latn-PH"), ("mdr", "mdr-Latn-ID"),
+    ("men", "men-Latn-SL"), ("mer", "mer-Latn-KE"), ("mfe", "mfe-Latn-MU"), ("mg", "mg-Latn-MG"),
+    ("mgh", "mgh-Latn-MZ"), ("mgo", "mgo-Latn-CM"), ("mh", "mh-Latn-MH"), ("mi", "mi-Latn-NZ"),
+    ("mic", "mic-Latn-CA"), ("min", "min-Latn-ID"), ("mk", "mk-Cyrl-MK"), ("ml", "ml-Mlym-IN"),
+    ("mn", "mn-Cyrl-MN"), ("mn-CN", "mn-Mong-CN"), ("mn-Mong", "mn-Mong-CN"),
+    ("mni", "mni-Beng-IN"), ("moh", "moh-Latn-CA"), ("mos", "mos-Latn-BF"), ("mr", "mr-Deva-IN"),
+    ("mrj", "mrj-Cyrl-RU"), ("ms", "ms-Latn-MY"), ("ms-CC", "ms-Arab-CC"), ("mt", "mt-Latn-MT"),
+    ("mua", "mua-Latn-CM"), ("mus", "mus-Latn-US"), ("mwr", "mwr-Deva-IN"), ("mwv", "mwv-Latn-ID"),
+    ("my", "my-Mymr-MM"), ("myv", "myv-Cyrl-RU"), ("mzn", "mzn-Arab-IR"), ("na", "na-Latn-NR"),
+    ("nan", "nan-Hans-CN"), ("nap", "nap-Latn-IT"), ("naq", "naq-Latn-NA"), ("nb", "nb-Latn-NO"),
+    ("nd", "nd-Latn-ZW"), ("nds", "nds-Latn-DE"), ("ne", "ne-Deva-NP"), ("new", "new-Deva-NP"),
+    ("ng", "ng-Latn-NA"), ("niu", "niu-Latn-NU"), ("njo", "njo-Latn-IN"), ("nl", "nl-Latn-NL"),
+    ("nmg", "nmg-Latn-CM"), ("nn", "nn-Latn-NO"), ("nnh", "nnh-Latn-CM"), ("no", "no-Latn-NO"),
+    ("non", "non-Runr-SE"), ("nqo", "nqo-Nkoo-GN"), ("nr", "nr-Latn-ZA"), ("nso", "nso-Latn-ZA"),
+    ("nus", "nus-Latn-SS"), ("nv", "nv-Latn-US"), ("ny", "ny-Latn-MW"), ("nym", "nym-Latn-TZ"),
+    ("nyn", "nyn-Latn-UG"), ("nzi", "nzi-Latn-GH"), ("oc", "oc-Latn-FR"), ("oj", "oj-Cans-CA"),
+    ("om", "om-Latn-ET"), ("or", "or-Orya-IN"), ("os", "os-Cyrl-GE"), ("osa", "osa-Osge-US"),
+    ("ota", "ota-Arab-ZZ"), ("pa", "pa-Guru-IN"), ("pa-Arab", "pa-Arab-PK"),
+    ("pa-PK", "pa-Arab-PK"), ("pag", "pag-Latn-PH"), ("pal", "pal-Phli-IR"),
+    ("pal-Phlp", "pal-Phlp-CN"), ("pam", "pam-Latn-PH"), ("pap", "pap-Latn-AW"),
+    ("pau", "pau-Latn-PW"), ("pcd", "pcd-Latn-FR"), ("pcm", "pcm-Latn-NG"), ("pdc", "pdc-Latn-US"),
+    ("pdt", "pdt-Latn-CA"), ("peo", "peo-Xpeo-IR"), ("pfl", "pfl-Latn-DE"), ("phn", "phn-Phnx-LB"),
+    ("pl", "pl-Latn-PL"), ("pms", "pms-Latn-IT"), ("pnt", "pnt-Grek-GR"), ("pon", "pon-Latn-FM"),
+    ("ps", "ps-Arab-AF"), ("pt", "pt-Latn-BR"), ("qu", "qu-Latn-PE"), ("quc", "quc-Latn-GT"),
+    ("qug", "qug-Latn-EC"), ("raj", "raj-Deva-IN"), ("rgn", "rgn-Latn-IT"), ("rif", "rif-Tfng-MA"),
+    ("rif-NL", "rif-Latn-NL"), ("rm", "rm-Latn-CH"), ("rn", "rn-Latn-BI"), ("ro", "ro-Latn-RO"),
+    ("rof", "rof-Latn-TZ"), ("rtm", "rtm-Latn-FJ"), ("ru", "ru-Cyrl-RU"), ("rue", "rue-Cyrl-UA"),
+    ("rug", "rug-Latn-SB"), ("rw", "rw-Latn-RW"), ("rwk", "rwk-Latn-TZ"), ("sa", "sa-Deva-IN"),
+    ("sah", "sah-Cyrl-RU"), ("saq", "saq-Latn-KE"), ("sas", "sas-Latn-ID"), ("sat", "sat-Olck-IN"),
+    ("saz", "saz-Saur-IN"), ("sba", "sba-Latn-ZZ"), ("sbp", "sbp-Latn-TZ"), ("sc", "sc-Latn-IT"),
+    ("scn", "scn-Latn-IT"), ("sco", "sco-Latn-GB"), ("sd", "sd-Arab-PK"),
+    ("sd-Deva", "sd-Deva-IN"), ("sd-IN", "sd-Deva-IN"), ("sd-Khoj", "sd-Khoj-IN"),
+    ("sd-Sind", "sd-Sind-IN"), ("sdc", "sdc-Latn-IT"), ("sdh", "sdh-Arab-IR"),
+    ("se", "se-Latn-NO"), ("seh", "seh-Latn-MZ"), ("sei", "sei-Latn-MX"), ("ses", "ses-Latn-ML"),
+    ("sg", "sg-Latn-CF"), ("sga", "sga-Ogam-IE"), ("sgs", "sgs-Latn-LT"), ("shi", "shi-Tfng-MA"),
+    ("shn", "shn-Mymr-MM"), ("shu", "shu-Arab-ZZ"), ("si", "si-Sinh-LK"), ("sid", "sid-Latn-ET"),
+    ("sk", "sk-Latn-SK"), ("sl", "sl-Latn-SI"), ("sli", "sli-Latn-PL"), ("sly", "sly-Latn-ID"),
+    ("sm", "sm-Latn-WS"), ("sma", "sma-Latn-SE"), ("smj", "smj-Latn-SE"), ("smn", "smn-Latn-FI"),
+    ("sms", "sms-Latn-FI"), ("sn", "sn-Latn-ZW"), ("snk", "snk-Latn-ML"), ("so", "so-Latn-SO"),
+    ("sog", "sog-Sogd-UZ"), ("sq", "sq-Latn-AL"), ("sr", "sr-Cyrl-RS"), ("sr-ME", "sr-Latn-ME"),
+    ("sr-RO", "sr-Latn-RO"), ("sr-RU", "sr-Latn-RU"), ("sr-TR", "sr-Latn-TR"),
+    ("srn", "srn-Latn-SR"), ("srr", "srr-Latn-SN"), ("ss", "ss-Latn-ZA"), ("ssy", "ssy-Latn-ER"),
+    ("st", "st-Latn-ZA"), ("stq", "stq-Latn-DE"), ("su", "su-Latn-ID"), ("suk", "suk-Latn-TZ"),
+    ("sus", "sus-Latn-GN"), ("sv", "sv-Latn-SE"), ("sw", "sw-Latn-TZ"), ("swb", "swb-Arab-YT"),
+    ("syr", "syr-Syrc-IQ"), ("szl", "szl-Latn-PL"), ("ta", "ta-Taml-IN"), ("tcy", "tcy-Knda-IN"),
+    ("te", "te-Telu-IN"), ("tem", "tem-Latn-SL"), ("teo", "teo-Latn-UG"), ("tet", "tet-Latn-TL"),
+    ("tg", "tg-Cyrl-TJ"), ("tg-Arab", "tg-Arab-PK"), ("tg-PK", "tg-Arab-PK"), ("th", "th-Thai-TH"),
+    ("ti", "ti-Ethi-ET"), ("tig", "tig-Ethi-ER"), ("tiv", "tiv-Latn-NG"), ("tk", "tk-Latn-TM"),
+    ("tkl", "tkl-Latn-TK"), ("tkr", "tkr-Latn-AZ"), ("tly", "tly-Latn-AZ"), ("tmh", "tmh-Latn-NE"),
+    ("tn", "tn-Latn-ZA"), ("to", "to-Latn-TO"), ("tog", "tog-Latn-MW"), ("tpi", "tpi-Latn-PG"),
+    ("tr", "tr-Latn-TR"), ("tru", "tru-Latn-TR"), ("trv", "trv-Latn-TW"), ("ts", "ts-Latn-ZA"),
+    ("tsd", "tsd-Grek-GR"), ("tt", "tt-Cyrl-RU"), ("ttt", "ttt-Latn-AZ"), ("tum", "tum-Latn-MW"),
+    ("tvl", "tvl-Latn-TV"), ("twq", "twq-Latn-NE"), ("ty", "ty-Latn-PF"), ("tyv", "tyv-Cyrl-RU"),
+    ("tzm", "tzm-Latn-MA"), ("udm", "udm-Cyrl-RU"), ("ug", "ug-Arab-CN"),
+    ("ug-Cyrl", "ug-Cyrl-KZ"), ("ug-KZ", "ug-Cyrl-KZ"), ("ug-MN", "ug-Cyrl-MN"),
+    ("uga", "uga-Ugar-SY"), ("uk", "uk-Cyrl-UA"), ("umb", "umb-Latn-AO"), ("und", "en-Latn-US"),
+    ("und-AD", "ca-Latn-AD"), ("und-AE", "ar-Arab-AE"), ("und-AF", "fa-Arab-AF"),
+    ("und-AG", "en-Latn-AG"), ("und-AI", "en-Latn-AI"), ("und-AL", "sq-Latn-AL"),
+    ("und-AM", "hy-Armn-AM"), ("und-AO", "pt-Latn-AO"), ("und-AQ", "und-Latn-AQ"),
+    ("und-AR", "es-Latn-AR"), ("und-AS", "sm-Latn-AS"), ("und-AT", "de-Latn-AT"),
+    ("und-AU", "en-Latn-AU"), ("und-AW", "nl-Latn-AW"), ("und-AX", "sv-Latn-AX"),
+    ("und-AZ", "az-Latn-AZ"), ("und-Adlm", "ff-Adlm-GN"), ("und-Aghb", "udi-Aghb-RU"),
+    ("und-Ahom", "aho-Ahom-IN"), ("und-Arab", "ar-Arab-EG"), ("und-Aran", "en-Aran-US"),
+    ("und-Armi", "arc-Armi-IR"), ("und-Armn", "hy-Armn-AM"), ("und-Avst", "ae-Avst-IR"),
+    ("und-BA", "bs-Latn-BA"), ("und-BB", "en-Latn-BB"), ("und-BD", "bn-Beng-BD"),
+    ("und-BE", "nl-Latn-BE"), ("und-BF", "fr-Latn-BF"), ("und-BG", "bg-Cyrl-BG"),
+    ("und-BH", "ar-Arab-BH"), ("und-BI", "rn-Latn-BI"), ("und-BJ", "fr-Latn-BJ"),
+    ("und-BL", "fr-Latn-BL"), ("und-BM", "en-Latn-BM"), ("und-BN", "ms-Latn-BN"),
+    ("und-BO", "es-Latn-BO"), ("und-BQ", "pap-Latn-BQ"), ("und-BR", "pt-Latn-BR"),
+    ("und-BS", "en-Latn-BS"), ("und-BT", "dz-Tibt-BT"), ("und-BV", "und-Latn-BV"),
+    ("und-BW", "en-Latn-BW"), ("und-BY", "be-Cyrl-BY"), ("und-BZ", "en-Latn-BZ"),
+    ("und-Bali", "ban-Bali-ID"), ("und-Bamu", "bax-Bamu-CM"), ("und-Bass", "bsq-Bass-LR"),
+    ("und-Batk", "bbc-Batk-ID"), ("und-Beng", "bn-Beng-BD"), ("und-Bhks", "sa-Bhks-IN"),
+    ("und-Bopo", "zh-Bopo-TW"), ("und-Brah", "pka-Brah-IN"), ("und-Brai", "fr-Brai-FR"),
+    ("und-Bugi", "bug-Bugi-ID"), ("und-Buhd", "bku-Buhd-PH"), ("und-CA", "en-Latn-CA"),
+    ("und-CC", "en-Latn-CC"), ("und-CD", "sw-Latn-CD"), ("und-CF", "fr-Latn-CF"),
+    ("und-CG", "fr-Latn-CG"), ("und-CH", "de-Latn-CH"), ("und-CI", "fr-Latn-CI"),
+    ("und-CK", "en-Latn-CK"), ("und-CL", "es-Latn-CL"), ("und-CM", "fr-Latn-CM"),
+    ("und-CN", "zh-Hans-CN"), ("und-CO", "es-Latn-CO"), ("und-CR", "es-Latn-CR"),
+    ("und-CU", "es-Latn-CU"), ("und-CV", "pt-Latn-CV"), ("und-CW", "pap-Latn-CW"),
+    ("und-CX", "en-Latn-CX"), ("und-CY", "el-Grek-CY"), ("und-CZ", "cs-Latn-CZ"),
+    ("und-Cakm", "ccp-Cakm-BD"), ("und-Cans", "iu-Cans-CA"), ("und-Cari", "xcr-Cari-TR"),
+    ("und-Cham", "cjm-Cham-VN"), ("und-Cher", "chr-Cher-US"), ("und-Chrs", "xco-Chrs-UZ"),
+    ("und-Copt", "cop-Copt-EG"), ("und-Cprt", "grc-Cprt-CY"), ("und-Cyrl", "ru-Cyrl-RU"),
+    ("und-DE", "de-Latn-DE"), ("und-DG", "en-Latn-DG"), ("und-DJ", "aa-Latn-DJ"),
+    ("und-DK", "da-Latn-DK"), ("und-DM", "en-Latn-DM"), ("und-DO", "es-Latn-DO"),
+    ("und-DZ", "ar-Arab-DZ"), ("und-Deva", "hi-Deva-IN"), ("und-Diak", "dv-Diak-MV"),
+    ("und-Dogr", "doi-Dogr-IN"), ("und-Dsrt", "en-Dsrt-US"), ("und-Dupl", "fr-Dupl-FR"),
+    ("und-EA", "es-Latn-EA"), ("und-EC", "es-Latn-EC"), ("und-EE", "et-Latn-EE"),
+    ("und-EG", "ar-Arab-EG"), ("und-EH", "ar-Arab-EH"), ("und-ER", "ti-Ethi-ER"),
+    ("und-ES", "es-Latn-ES"), ("und-ET", "am-Ethi-ET"), ("und-Egyp", "egy-Egyp-EG"),
+    ("und-Elba", "sq-Elba-AL"), ("und-Elym", "arc-Elym-IR"), ("und-Ethi", "am-Ethi-ET"),
+    ("und-FI", "fi-Latn-FI"), ("und-FJ", "en-Latn-FJ"), ("und-FK", "en-Latn-FK"),
+    ("und-FM", "en-Latn-FM"), ("und-FO", "fo-Latn-FO"), ("und-FR", "fr-Latn-FR"),
+    ("und-GA", "fr-Latn-GA"), ("und-GB", "en-Latn-GB"), ("und-GD", "en-Latn-GD"),
+    ("und-GE", "ka-Geor-GE"), ("und-GF", "fr-Latn-GF"), ("und-GG", "en-Latn-GG"),
+    ("und-GH", "ak-Latn-GH"), ("und-GI", "en-Latn-GI"), ("und-GL", "kl-Latn-GL"),
+    ("und-GM", "en-Latn-GM"), ("und-GN", "fr-Latn-GN"), ("und-GP", "fr-Latn-GP"),
+    ("und-GQ", "es-Latn-GQ"), ("und-GR", "el-Grek-GR"), ("und-GS", "und-Latn-GS"),
+    ("und-GT", "es-Latn-GT"), ("und-GU", "en-Latn-GU"), ("und-GW", "pt-Latn-GW"),
+    ("und-GY", "en-Latn-GY"), ("und-Geor", "ka-Geor-GE"), ("und-Glag", "cu-Glag-BG"),
+    ("und-Gong", "wsg-Gong-IN"), ("und-Gonm", "esg-Gonm-IN"), ("und-Goth", "got-Goth-UA"),
+    ("und-Gran", "sa-Gran-IN"), ("und-Grek", "el-Grek-GR"), ("und-Gujr", "gu-Gujr-IN"),
+    ("und-Guru", "pa-Guru-IN"), ("und-HK", "zh-Hant-HK"), ("und-HM", "und-Latn-HM"),
+    ("und-HN", "es-Latn-HN"), ("und-HR", "hr-Latn-HR"), ("und-HT", "ht-Latn-HT"),
+    ("und-HU", "hu-Latn-HU"), ("und-Hang", "ko-Hang-KR"), ("und-Hani", "zh-Hani-CN"),
+    ("und-Hano", "hnn-Hano-PH"), ("und-Hans", "zh-Hans-CN"), ("und-Hant", "zh-Hant-TW"),
+    ("und-Hatr", "en-Hatr-US"), ("und-Hebr", "he-Hebr-IL"), ("und-Hira", "ja-Hira-JP"),
+    ("und-Hluw", "hlu-Hluw-TR"), ("und-Hmng", "hnj-Hmng-LA"), ("und-Hmnp", "hnj-Hmnp-US"),
+    ("und-Hrkt", "en-Hrkt-US"), ("und-Hung", "hu-Hung-HU"), ("und-IC", "es-Latn-IC"),
+    ("und-ID", "id-Latn-ID"), ("und-IE", "en-Latn-IE"), ("und-IL", "he-Hebr-IL"),
+    ("und-IM", "en-Latn-IM"), ("und-IN", "hi-Deva-IN"), ("und-IO", "en-Latn-IO"),
+    ("und-IQ", "ar-Arab-IQ"), ("und-IR", "fa-Arab-IR"), ("und-IS", "is-Latn-IS"),
+    ("und-IT", "it-Latn-IT"), ("und-Ital", "ett-Ital-IT"), ("und-JE", "en-Latn-JE"),
+    ("und-JM", "en-Latn-JM"), ("und-JO", "ar-Arab-JO"), ("und-JP", "ja-Jpan-JP"),
+    ("und-Java", "jv-Java-ID"), ("und-Jpan", "ja-Jpan-JP"), ("und-KE", "sw-Latn-KE"),
+    ("und-KG", "ky-Cyrl-KG"), ("und-KH", "km-Khmr-KH"), ("und-KI", "en-Latn-KI"),
+    ("und-KM", "ar-Arab-KM"), ("und-KN", "en-Latn-KN"), ("und-KP", "ko-Kore-KP"),
+    ("und-KR", "ko-Kore-KR"), ("und-KW", "ar-Arab-KW"), ("und-KY", "en-Latn-KY"),
+    ("und-KZ", "ru-Cyrl-KZ"), ("und-Kali", "eky-Kali-MM"), ("und-Kana", "ja-Kana-JP"),
+    ("und-Khar", "pra-Khar-PK"), ("und-Khmr", "km-Khmr-KH"), ("und-Khoj", "sd-Khoj-IN"),
+    ("und-Kits", "zkt-Kits-CN"), ("und-Knda", "kn-Knda-IN"), ("und-Kore", "ko-Kore-KR"),
+    ("und-Kthi", "bho-Kthi-IN"), ("und-LA", "lo-Laoo-LA"), ("und-LB", "ar-Arab-LB"),
+    ("und-LC", "en-Latn-LC"), ("und-LI", "de-Latn-LI"), ("und-LK", "si-Sinh-LK"),
+    ("und-LR", "en-Latn-LR"), ("und-LS", "st-Latn-LS"), ("und-LT", "lt-Latn-LT"),
+    ("und-LU", "fr-Latn-LU"), ("und-LV", "lv-Latn-LV"), ("und-LY", "ar-Arab-LY"),
+    ("und-Lana", "nod-Lana-TH"), ("und-Laoo", "lo-Laoo-LA"), ("und-Latf", "en-Latf-US"),
+    ("und-Latg", "en-Latg-US"), ("und-Latn", "en-Latn-US"), ("und-Lepc", "lep-Lepc-IN"),
+    ("und-Limb", "lif-Limb-IN"), ("und-Lina", "lab-Lina-GR"), ("und-Linb", "grc-Linb-GR"),
+    ("und-Lisu", "lis-Lisu-CN"), ("und-Lyci", "xlc-Lyci-TR"), ("und-Lydi", "xld-Lydi-TR"),
+    ("und-MA", "ar-Arab-MA"), ("und-MC", "fr-Latn-MC"), ("und-MD", "ro-Latn-MD"),
+    ("und-ME", "sr-Latn-ME"), ("und-MF", "fr-Latn-MF"), ("und-MG", "mg-Latn-MG"),
+    ("und-MH", "en-Latn-MH"), ("und-MK", "mk-Cyrl-MK"), ("und-ML", "bm-Latn-ML"),
+    ("und-MM", "my-Mymr-MM"), ("und-MN", "mn-Cyrl-MN"), ("und-MO", "zh-Hant-MO"),
+    ("und-MP", "en-Latn-MP"), ("und-MQ", "fr-Latn-MQ"), ("und-MR", "ar-Arab-MR"),
+    ("und-MS", "en-Latn-MS"), ("und-MT", "mt-Latn-MT"), ("und-MU", "mfe-Latn-MU"),
+    ("und-MV", "dv-Thaa-MV"), ("und-MW", "en-Latn-MW"), ("und-MX", "es-Latn-MX"),
+    ("und-MY", "ms-Latn-MY"), ("und-MZ", "pt-Latn-MZ"), ("und-Mahj", "hi-Mahj-IN"),
+    ("und-Maka", "mak-Maka-ID"), ("und-Mand", "myz-Mand-IR"), ("und-Mani", "xmn-Mani-CN"),
+    ("und-Marc", "bo-Marc-CN"), ("und-Medf", "dmf-Medf-NG"), ("und-Mend", "men-Mend-SL"),
+    ("und-Merc", "xmr-Merc-SD"), ("und-Mero", "xmr-Mero-SD"), ("und-Mlym", "ml-Mlym-IN"),
+    ("und-Modi", "mr-Modi-IN"), ("und-Mong", "mn-Mong-CN"), ("und-Mroo", "mro-Mroo-BD"),
+    ("und-Mtei", "mni-Mtei-IN"), ("und-Mult", "skr-Mult-PK"), ("und-Mymr", "my-Mymr-MM"),
+    ("und-NA", "af-Latn-NA"), ("und-NC", "fr-Latn-NC"), ("und-NE", "ha-Latn-NE"),
+    ("und-NF", "en-Latn-NF"), ("und-NG", "en-Latn-NG"), ("und-NI", "es-Latn-NI"),
+    ("und-NL", "nl-Latn-NL"), ("und-NO", "nb-Latn-NO"), ("und-NP", "ne-Deva-NP"),
+    ("und-NR", "en-Latn-NR"), ("und-NU", "en-Latn-NU"), ("und-NZ", "en-Latn-NZ"),
+    ("und-Nand", "sa-Nand-IN"), ("und-Narb", "xna-Narb-SA"), ("und-Nbat", "arc-Nbat-JO"),
+    ("und-Newa", "new-Newa-NP"), ("und-Nkoo", "man-Nkoo-GN"), ("und-Nshu", "zhx-Nshu-CN"),
+    ("und-OM", "ar-Arab-OM"), ("und-Ogam", "sga-Ogam-IE"), ("und-Olck", "sat-Olck-IN"),
+    ("und-Orkh", "otk-Orkh-MN"), ("und-Orya", "or-Orya-IN"), ("und-Osge", "osa-Osge-US"),
+    ("und-Osma", "so-Osma-SO"), ("und-PA", "es-Latn-PA"), ("und-PE", "es-Latn-PE"),
+    ("und-PF", "fr-Latn-PF"), ("und-PG", "tpi-Latn-PG"), ("und-PH", "fil-Latn-PH"),
+    ("und-PK", "ur-Arab-PK"), ("und-PL", "pl-Latn-PL"), ("und-PM", "fr-Latn-PM"),
+    ("und-PN", "en-Latn-PN"), ("und-PR", "es-Latn-PR"), ("und-PS", "ar-Arab-PS"),
+    ("und-PT", "pt-Latn-PT"), ("und-PW", "pau-Latn-PW"), ("und-PY", "gn-Latn-PY"),
+    ("und-Palm", "arc-Palm-SY"), ("und-Pauc", "ctd-Pauc-MM"), ("und-Perm", "kv-Perm-RU"),
+    ("und-Phag", "lzh-Phag-CN"), ("und-Phli", "pal-Phli-IR"), ("und-Phlp", "pal-Phlp-CN"),
+    ("und-Phnx", "phn-Phnx-LB"), ("und-Plrd", "hmd-Plrd-CN"), ("und-Prti", "xpr-Prti-IR"),
+    ("und-QA", "ar-Arab-QA"), ("und-RE", "fr-Latn-RE"), ("und-RO", "ro-Latn-RO"),
+    ("und-RS", "sr-Cyrl-RS"), ("und-RU", "ru-Cyrl-RU"), ("und-RW", "rw-Latn-RW"),
+    ("und-Rjng", "rej-Rjng-ID"), ("und-Rohg", "rhg-Rohg-MM"), ("und-Runr", "non-Runr-SE"),
+    ("und-SA", "ar-Arab-SA"), ("und-SB", "en-Latn-SB"), ("und-SC", "fr-Latn-SC"),
+    ("und-SD", "ar-Arab-SD"), ("und-SE", "sv-Latn-SE"), ("und-SG", "en-Latn-SG"),
+    ("und-SH", "en-Latn-SH"), ("und-SI", "sl-Latn-SI"), ("und-SJ", "nb-Latn-SJ"),
+    ("und-SK", "sk-Latn-SK"), ("und-SL", "en-Latn-SL"), ("und-SM", "it-Latn-SM"),
+    ("und-SN", "fr-Latn-SN"), ("und-SO", "so-Latn-SO"), ("und-SR", "nl-Latn-SR"),
+    ("und-SS", "en-Latn-SS"), ("und-ST", "pt-Latn-ST"), ("und-SV", "es-Latn-SV"),
+    ("und-SX", "en-Latn-SX"), ("und-SY", "ar-Arab-SY"), ("und-SZ", "en-Latn-SZ"),
+    ("und-Samr", "smp-Samr-IL"), ("und-Sarb", "xsa-Sarb-YE"), ("und-Saur", "saz-Saur-IN"),
+    ("und-Sgnw", "ase-Sgnw-US"), ("und-Shaw", "en-Shaw-GB"), ("und-Shrd", "sa-Shrd-IN"),
+    ("und-Sidd", "sa-Sidd-IN"), ("und-Sind", "sd-Sind-IN"), ("und-Sinh", "si-Sinh-LK"),
+    ("und-Sogd", "sog-Sogd-UZ"), ("und-Sogo", "sog-Sogo-UZ"), ("und-Sora", "srb-Sora-IN"),
+    ("und-Soyo", "cmg-Soyo-MN"), ("und-Sund", "su-Sund-ID"), ("und-Sylo", "syl-Sylo-BD"),
+    ("und-Syrc", "syr-Syrc-IQ"), ("und-Syre", "en-Syre-US"), ("und-Syrj", "en-Syrj-US"),
+    ("und-Syrn", "en-Syrn-US"), ("und-TC", "en-Latn-TC"), ("und-TD", "fr-Latn-TD"),
+    ("und-TF", "fr-Latn-TF"), ("und-TG", "fr-Latn-TG"), ("und-TH", "th-Thai-TH"),
+    ("und-TJ", "tg-Cyrl-TJ"), ("und-TK", "tkl-Latn-TK"), ("und-TL", "pt-Latn-TL"),
+    ("und-TM", "tk-Latn-TM"), ("und-TN", "ar-Arab-TN"), ("und-TO", "to-Latn-TO"),
+    ("und-TR", "tr-Latn-TR"), ("und-TT", "en-Latn-TT"), ("und-TV", "tvl-Latn-TV"),
+    ("und-TW", "zh-Hant-TW"), ("und-TZ", "sw-Latn-TZ"), ("und-Tagb", "tbw-Tagb-PH"),
+    ("und-Takr", "doi-Takr-IN"), ("und-Tale", "tdd-Tale-CN"), ("und-Talu", "khb-Talu-CN"),
+    ("und-Taml", "ta-Taml-IN"), ("und-Tang", "txg-Tang-CN"), ("und-Tavt", "blt-Tavt-VN"),
+    ("und-Telu", "te-Telu-IN"), ("und-Tfng", "zgh-Tfng-MA"), ("und-Tglg", "fil-Tglg-PH"),
+    ("und-Thaa", "dv-Thaa-MV"), ("und-Thai", "th-Thai-TH"), ("und-Tibt", "bo-Tibt-CN"),
+    ("und-Tirh", "mai-Tirh-IN"), ("und-UA", "uk-Cyrl-UA"), ("und-UG", "sw-Latn-UG"),
+    ("und-UM", "en-Latn-UM"), ("und-US", "en-Latn-US"), ("und-UY", "es-Latn-UY"),
+    ("und-UZ", "uz-Latn-UZ"), ("und-Ugar", "uga-Ugar-SY"), ("und-VA", "it-Latn-VA"),
+    ("und-VC", "en-Latn-VC"), ("und-VE", "es-Latn-VE"), ("und-VG", "en-Latn-VG"),
+    ("und-VI", "en-Latn-VI"), ("und-VN", "vi-Latn-VN"), ("und-VU", "bi-Latn-VU"),
+    ("und-Vaii", "vai-Vaii-LR"), ("und-WF", "fr-Latn-WF"), ("und-WS", "sm-Latn-WS"),
+    ("und-Wara", "hoc-Wara-IN"), ("und-Wcho", "nnp-Wcho-IN"), ("und-XK", "sq-Latn-XK"),
+    ("und-Xpeo", "peo-Xpeo-IR"), ("und-Xsux", "akk-Xsux-IQ"), ("und-YE", "ar-Arab-YE"),
+    ("und-YT", "fr-Latn-YT"), ("und-Yezi", "ku-Yezi-GE"), ("und-Yiii", "ii-Yiii-CN"),
+    ("und-ZA", "en-Latn-ZA"), ("und-ZM", "en-Latn-ZM"), ("und-ZW", "sn-Latn-ZW"),
+    ("und-Zanb", "cmg-Zanb-MN"), ("und-Zmth", "en-Zmth-US"), ("und-Zsye", "en-Zsye-US"),
+    ("und-Zsym", "en-Zsym-US"), ("ur", "ur-Arab-PK"), ("uz", "uz-Latn-UZ"),
+    ("uz-AF", "uz-Arab-AF"), ("uz-Arab", "uz-Arab-AF"), ("uz-CN", "uz-Cyrl-CN"),
+    ("vai", "vai-Vaii-LR"), ("ve", "ve-Latn-ZA"), ("vec", "vec-Latn-IT"), ("vep", "vep-Latn-RU"),
+    ("vi", "vi-Latn-VN"), ("vls", "vls-Latn-BE"), ("vmf", "vmf-Latn-DE"), ("vot", "vot-Latn-RU"),
+    ("vro", "vro-Latn-EE"), ("vun", "vun-Latn-TZ"), ("wa", "wa-Latn-BE"), ("wae", "wae-Latn-CH"),
+    ("wal", "wal-Ethi-ET"), ("war", "war-Latn-PH"), ("wbp", "wbp-Latn-AU"), ("wo", "wo-Latn-SN"),
+    ("wuu", "wuu-Hans-CN"), ("xh", "xh-Latn-ZA"), ("xmf", "xmf-Geor-GE"), ("xog", "xog-Latn-UG"),
+    ("yao", "yao-Latn-MZ"), ("yap", "yap-Latn-FM"), ("yav", "yav-Latn-CM"), ("ybb", "ybb-Latn-CM"),
+    ("yi-AD", "yi-Hebr-AD"), ("yi-AE", "yi-Hebr-AE"), ("yi-AF", "yi-Hebr-AF"),
+    ("yi-AG", "yi-Hebr-AG"), ("yi-AI", "yi-Hebr-AI"), ("yi-AL", "yi-Hebr-AL"),
+    ("yi-AM", "yi-Hebr-AM"), ("yi-AO", "yi-Hebr-AO"), ("yi-AQ", "yi-Hebr-AQ"),
+    ("yi-AR", "yi-Hebr-AR"), ("yi-AS", "yi-Hebr-AS"), ("yi-AT", "yi-Hebr-AT"),
+    ("yi-AU", "yi-Hebr-AU"), ("yi-AW", "yi-Hebr-AW"), ("yi-AX", "yi-Hebr-AX"),
+    ("yi-AZ", "yi-Hebr-AZ"), ("yi-BA", "yi-Hebr-BA"), ("yi-BB", "yi-Hebr-BB"),
+    ("yi-BD", "yi-Hebr-BD"), ("yi-BE", "yi-Hebr-BE"), ("yi-BF", "yi-Hebr-BF"),
+    ("yi-BG", "yi-Hebr-BG"), ("yi-BH", "yi-Hebr-BH"), ("yi-BI", "yi-Hebr-BI"),
+    ("yi-BJ", "yi-Hebr-BJ"), ("yi-BL", "yi-Hebr-BL"), ("yi-BM", "yi-Hebr-BM"),
+    ("yi-BN", "yi-Hebr-BN"), ("yi-BO", "yi-Hebr-BO"), ("yi-BQ", "yi-Hebr-BQ"),
+    ("yi-BR", "yi-Hebr-BR"), ("yi-BS", "yi-Hebr-BS"), ("yi-BT", "yi-Hebr-BT"),
+    ("yi-BV", "yi-Hebr-BV"), ("yi-BW", "yi-Hebr-BW"), ("yi-BY", "yi-Hebr-BY"),
+    ("yi-BZ", "yi-Hebr-BZ"), ("yi-CA", "yi-Hebr-CA"), ("yi-CC", "yi-Hebr-CC"),
+    ("yi-CD", "yi-Hebr-CD"), ("yi-CF", "yi-Hebr-CF"), ("yi-CG", "yi-Hebr-CG"),
+    ("yi-CH", "yi-Hebr-CH"), ("yi-CI", "yi-Hebr-CI"), ("yi-CK", "yi-Hebr-CK"),
+    ("yi-CL", "yi-Hebr-CL"), ("yi-CM", "yi-Hebr-CM"), ("yi-CN", "yi-Hebr-CN"),
+    ("yi-CO", "yi-Hebr-CO"), ("yi-CR", "yi-Hebr-CR"), ("yi-CU", "yi-Hebr-CU"),
+    ("yi-CV", "yi-Hebr-CV"), ("yi-CW", "yi-Hebr-CW"), ("yi-CX", "yi-Hebr-CX"),
+    ("yi-CY", "yi-Hebr-CY"), ("yi-CZ", "yi-Hebr-CZ"), ("yi-DE", "yi-Hebr-DE"),
+    ("yi-DG", "yi-Hebr-DG"), ("yi-DJ", "yi-Hebr-DJ"), ("yi-DK", "yi-Hebr-DK"),
+    ("yi-DM", "yi-Hebr-DM"), ("yi-DO", "yi-Hebr-DO"), ("yi-DZ", "yi-Hebr-DZ"),
+    ("yi-EA", "yi-Hebr-EA"), ("yi-EC", "yi-Hebr-EC"), ("yi-EE", "yi-Hebr-EE"),
+    ("yi-EG", "yi-Hebr-EG"), ("yi-EH", "yi-Hebr-EH"), ("yi-ER", "yi-Hebr-ER"),
+    ("yi-ES", "yi-Hebr-ES"), ("yi-ET", "yi-Hebr-ET"), ("yi-FI", "yi-Hebr-FI"),
+    ("yi-FJ", "yi-Hebr-FJ"), ("yi-FK", "yi-Hebr-FK"), ("yi-FM", "yi-Hebr-FM"),
+    ("yi-FO", "yi-Hebr-FO"), ("yi-FR", "yi-Hebr-FR"), ("yi-GA", "yi-Hebr-GA"),
+    ("yi-GB", "yi-Hebr-GB"), ("yi-GD", "yi-Hebr-GD"), ("yi-GE", "yi-Hebr-GE"),
+    ("yi-GF", "yi-Hebr-GF"), ("yi-GG", "yi-Hebr-GG"), ("yi-GH", "yi-Hebr-GH"),
+    ("yi-GI", "yi-Hebr-GI"), ("yi-GL", "yi-Hebr-GL"), ("yi-GM", "yi-Hebr-GM"),
+    ("yi-GN", "yi-Hebr-GN"), ("yi-GP", "yi-Hebr-GP"), ("yi-GQ", "yi-Hebr-GQ"),
+    ("yi-GR", "yi-Hebr-GR"), ("yi-GS", "yi-Hebr-GS"), ("yi-GT", "yi-Hebr-GT"),
+    ("yi-GU", "yi-Hebr-GU"), ("yi-GW", "yi-Hebr-GW"), ("yi-GY", "yi-Hebr-GY"),
+    ("yi-HK", "yi-Hebr-HK"), ("yi-HM", "yi-Hebr-HM"), ("yi-HN", "yi-Hebr-HN"),
+    ("yi-HR", "yi-Hebr-HR"), ("yi-HT", "yi-Hebr-HT"), ("yi-HU", "yi-Hebr-HU"),
+    ("yi-IC", "yi-Hebr-IC"), ("yi-ID", "yi-Hebr-ID"), ("yi-IE", "yi-Hebr-IE"),
+    ("yi-IL", "yi-Hebr-IL"), ("yi-IM", "yi-Hebr-IM"), ("yi-IN", "yi-Hebr-IN"),
+    ("yi-IO", "yi-Hebr-IO"), ("yi-IQ", "yi-Hebr-IQ"), ("yi-IR", "yi-Hebr-IR"),
+    ("yi-IS", "yi-Hebr-IS"), ("yi-IT", "yi-Hebr-IT"), ("yi-JE", "yi-Hebr-JE"),
+    ("yi-JM", "yi-Hebr-JM"), ("yi-JO", "yi-Hebr-JO"), ("yi-JP", "yi-Hebr-JP"),
+    ("yi-KE", "yi-Hebr-KE"), ("yi-KG", "yi-Hebr-KG"), ("yi-KH", "yi-Hebr-KH"),
+    ("yi-KI", "yi-Hebr-KI"), ("yi-KM", "yi-Hebr-KM"), ("yi-KN", "yi-Hebr-KN"),
+    ("yi-KP", "yi-Hebr-KP"), ("yi-KR", "yi-Hebr-KR"), ("yi-KW", "yi-Hebr-KW"),
+    ("yi-KY", "yi-Hebr-KY"), ("yi-KZ", "yi-Hebr-KZ"), ("yi-LA", "yi-Hebr-LA"),
+    ("yi-LB", "yi-Hebr-LB"), ("yi-LC", "yi-Hebr-LC"), ("yi-LI", "yi-Hebr-LI"),
+    ("yi-LK", "yi-Hebr-LK"), ("yi-LR", "yi-Hebr-LR"), ("yi-LS", "yi-Hebr-LS"),
+    ("yi-LT", "yi-Hebr-LT"), ("yi-LU", "yi-Hebr-LU"), ("yi-LV", "yi-Hebr-LV"),
+    ("yi-LY", "yi-Hebr-LY"), ("yi-MA", "yi-Hebr-MA"), ("yi-MC", "yi-Hebr-MC"),
+    ("yi-MD", "yi-Hebr-MD"), ("yi-ME", "yi-Hebr-ME"), ("yi-MF", "yi-Hebr-MF"),
+    ("yi-MG", "yi-Hebr-MG"), ("yi-MH", "yi-Hebr-MH"), ("yi-MK", "yi-Hebr-MK"),
+    ("yi-ML", "yi-Hebr-ML"), ("yi-MM", "yi-Hebr-MM"), ("yi-MN", "yi-Hebr-MN"),
+    ("yi-MO", "yi-Hebr-MO"), ("yi-MP", "yi-Hebr-MP"), ("yi-MQ", "yi-Hebr-MQ"),
+    ("yi-MR", "yi-Hebr-MR"), ("yi-MS", "yi-Hebr-MS"), ("yi-MT", "yi-Hebr-MT"),
+    ("yi-MU", "yi-Hebr-MU"), ("yi-MV", "yi-Hebr-MV"), ("yi-MW", "yi-Hebr-MW"),
+    ("yi-MX", "yi-Hebr-MX"), ("yi-MY", "yi-Hebr-MY"), ("yi-MZ", "yi-Hebr-MZ"),
+    ("yi-NA", "yi-Hebr-NA"), ("yi-NC", "yi-Hebr-NC"), ("yi-NE", "yi-Hebr-NE"),
+    ("yi-NF", "yi-Hebr-NF"), ("yi-NG", "yi-Hebr-NG"), ("yi-NI", "yi-Hebr-NI"),
+    ("yi-NL", "yi-Hebr-NL"), ("yi-NO", "yi-Hebr-NO"), ("yi-NP", "yi-Hebr-NP"),
+    ("yi-NR", "yi-Hebr-NR"), ("yi-NU", "yi-Hebr-NU"), ("yi-NZ", "yi-Hebr-NZ"),
+    ("yi-OM", "yi-Hebr-OM"), ("yi-PA", "yi-Hebr-PA"), ("yi-PE", "yi-Hebr-PE"),
+    ("yi-PF", "yi-Hebr-PF"), ("yi-PG", "yi-Hebr-PG"), ("yi-PH", "yi-Hebr-PH"),
+    ("yi-PK", "yi-Hebr-PK"), ("yi-PL", "yi-Hebr-PL"), ("yi-PM", "yi-Hebr-PM"),
+    ("yi-PN", "yi-Hebr-PN"), ("yi-PR", "yi-Hebr-PR"), ("yi-PS", "yi-Hebr-PS"),
+    ("yi-PT", "yi-Hebr-PT"), ("yi-PW", "yi-Hebr-PW"), ("yi-PY", "yi-Hebr-PY"),
+    ("yi-QA", "yi-Hebr-QA"), ("yi-RE", "yi-Hebr-RE"), ("yi-RO", "yi-Hebr-RO"),
+    ("yi-RS", "yi-Hebr-RS"), ("yi-RU", "yi-Hebr-RU"), ("yi-RW", "yi-Hebr-RW"),
+    ("yi-SA", "yi-Hebr-SA"), ("yi-SB", "yi-Hebr-SB"), ("yi-SC", "yi-Hebr-SC"),
+    ("yi-SD", "yi-Hebr-SD"), ("yi-SE", "yi-Hebr-SE"), ("yi-SG", "yi-Hebr-SG"),
+    ("yi-SH", "yi-Hebr-SH"), ("yi-SI", "yi-Hebr-SI"), ("yi-SJ", "yi-Hebr-SJ"),
+    ("yi-SK", "yi-Hebr-SK"), ("yi-SL", "yi-Hebr-SL"), ("yi-SM", "yi-Hebr-SM"),
+    ("yi-SN", "yi-Hebr-SN"), ("yi-SO", "yi-Hebr-SO"), ("yi-SR", "yi-Hebr-SR"),
+    ("yi-SS", "yi-Hebr-SS"), ("yi-ST", "yi-Hebr-ST"), ("yi-SV", "yi-Hebr-SV"),
+    ("yi-SX", "yi-Hebr-SX"), ("yi-SY", "yi-Hebr-SY"), ("yi-SZ", "yi-Hebr-SZ"),
+    ("yi-TC", "yi-Hebr-TC"), ("yi-TD", "yi-Hebr-TD"), ("yi-TF", "yi-Hebr-TF"),
+    ("yi-TG", "yi-Hebr-TG"), ("yi-TH", "yi-Hebr-TH"), ("yi-TJ", "yi-Hebr-TJ"),
+    ("yi-TK", "yi-Hebr-TK"), ("yi-TL", "yi-Hebr-TL"), ("yi-TM", "yi-Hebr-TM"),
+    ("yi-TN", "yi-Hebr-TN"), ("yi-TO", "yi-Hebr-TO"), ("yi-TR", "yi-Hebr-TR"),
+    ("yi-TT", "yi-Hebr-TT"), ("yi-TV", "yi-Hebr-TV"), ("yi-TW", "yi-Hebr-TW"),
+    ("yi-TZ", "yi-Hebr-TZ"), ("yi-UA", "yi-Hebr-UA"), ("yi-UG", "yi-Hebr-UG"),
+    ("yi-UM", "yi-Hebr-UM"), ("yi-US", "yi-Hebr-US"), ("yi-UY", "yi-Hebr-UY"),
+    ("yi-UZ", "yi-Hebr-UZ"), ("yi-VA", "yi-Hebr-VA"), ("yi-VC", "yi-Hebr-VC"),
+    ("yi-VE", "yi-Hebr-VE"), ("yi-VG", "yi-Hebr-VG"), ("yi-VI", "yi-Hebr-VI"),
+    ("yi-VN", "yi-Hebr-VN"), ("yi-VU", "yi-Hebr-VU"), ("yi-WF", "yi-Hebr-WF"),
+    ("yi-WS", "yi-Hebr-WS"), ("yi-XK", "yi-Hebr-XK"), ("yi-YE", "yi-Hebr-YE"),
+    ("yi-YT", "yi-Hebr-YT"), ("yi-ZA", "yi-Hebr-ZA"), ("yi-ZM", "yi-Hebr-ZM"),
+    ("yi-ZW", "yi-Hebr-ZW"), ("yo", "yo-Latn-NG"), ("yrl", "yrl-Latn-BR"), ("yue", "yue-Hant-HK"),
+    ("yue-CN", "yue-Hans-CN"), ("yue-Hans", "yue-Hans-CN"), ("za", "za-Latn-CN"),
+    ("zea", "zea-Latn-NL"), ("zgh", "zgh-Tfng-MA"), ("zh", "zh-Hans-CN"), ("zh-AU", "zh-Hant-AU"),
+    ("zh-BN", "zh-Hant-BN"), ("zh-Bopo", "zh-Bopo-TW"), ("zh-GB", "zh-Hant-GB"),
+    ("zh-GF", "zh-Hant-GF"), ("zh-HK", "zh-Hant-HK"), ("zh-Hant", "zh-Hant-TW"),
+    ("zh-ID", "zh-Hant-ID"), ("zh-MO", "zh-Hant-MO"), ("zh-PA", "zh-Hant-PA"),
+    ("zh-PF", "zh-Hant-PF"), ("zh-PH", "zh-Hant-PH"), ("zh-SR", "zh-Hant-SR"),
+    ("zh-TH", "zh-Hant-TH"), ("zh-TW", "zh-Hant-TW"), ("zh-US", "zh-Hant-US"),
+    ("zh-VN", "zh-Hant-VN"), ("zu", "zu-Latn-ZA"), ("zza", "zza-Latn-TR"),
+];
+
+#[rustfmt::skip]
+pub const REGION_PARENTS: [(u32, u32); 284] = [
+    (t3(b"002"), t3(b"001")), (t3(b"005"), t3(b"019")), (t3(b"009"), t3(b"001")),
+    (t3(b"011"), t3(b"002")), (t3(b"013"), t3(b"019")), (t3(b"014"), t3(b"002")),
+    (t3(b"015"), t3(b"002")), (t3(b"017"), t3(b"002")), (t3(b"018"), t3(b"002")),
+    (t3(b"019"), t3(b"001")), (t3(b"021"), t3(b"019")), (t3(b"029"), t3(b"019")),
+    (t3(b"030"), t3(b"142")), (t3(b"034"), t3(b"142")), (t3(b"035"), t3(b"142")),
+    (t3(b"039"), t3(b"150")), (t3(b"053"), t3(b"009")), (t3(b"054"), t3(b"009")),
+    (t3(b"057"), t3(b"009")), (t3(b"061"), t3(b"009")), (t3(b"142"), t3(b"001")),
+    (t3(b"143"), t3(b"142")), (t3(b"145"), t3(b"142")), (t3(b"150"), t3(b"001")),
+    (t3(b"151"), t3(b"150")), (t3(b"154"), t3(b"150")), (t3(b"155"), t3(b"150")),
+    (t3(b"AC "), t3(b"QO ")), (t3(b"AD "), t3(b"039")), (t3(b"AE "), t3(b"145")),
+    (t3(b"AF "), t3(b"034")), (t3(b"AG "), t3(b"029")), (t3(b"AI "), t3(b"029")),
+    (t3(b"AL "), t3(b"039")), (t3(b"AM "), t3(b"145")), (t3(b"AO "), t3(b"017")),
+    (t3(b"AQ "), t3(b"QO ")), (t3(b"AR "), t3(b"005")), (t3(b"AS "), t3(b"061")),
+    (t3(b"AT "), t3(b"155")), (t3(b"AU "), t3(b"053")), (t3(b"AW "), t3(b"029")),
+    (t3(b"AX "), t3(b"154")), (t3(b"AZ "), t3(b"145")), (t3(b"BA "), t3(b"039")),
+    (t3(b"BB "), t3(b"029")), (t3(b"BD "), t3(b"034")), (t3(b"BE "), t3(b"155")),
+    (t3(b"BF "), t3(b"011")), (t3(b"BG "), t3(b"151")), (t3(b"BH "), t3(b"145")),
+    (t3(b"BI "), t3(b"014")), (t3(b"BJ "), t3(b"011")), (t3(b"BL "), t3(b"029")),
+    (t3(b"BM "), t3(b"021")), (t3(b"BN "), t3(b"035")), (t3(b"BO "), t3(b"005")),
+    (t3(b"BQ "), t3(b"029")), (t3(b"BR "), t3(b"005")), (t3(b"BS "), t3(b"029")),
+    (t3(b"BT "), t3(b"034")), (t3(b"BV "), t3(b"005")), (t3(b"BW "), t3(b"018")),
+    (t3(b"BY "), t3(b"151")), (t3(b"BZ "), t3(b"013")), (t3(b"CA "), t3(b"021")),
+    (t3(b"CC "), t3(b"053")), (t3(b"CD "), t3(b"017")), (t3(b"CF "), t3(b"017")),
+    (t3(b"CG "), t3(b"017")), (t3(b"CH "), t3(b"155")), (t3(b"CI "), t3(b"011")),
+    (t3(b"CK "), t3(b"061")), (t3(b"CL "), t3(b"005")), (t3(b"CM "), t3(b"017")),
+    (t3(b"CN "), t3(b"030")), (t3(b"CO "), t3(b"005")), (t3(b"CP "), t3(b"QO ")),
+    (t3(b"CR "), t3(b"013")), (t3(b"CU "), t3(b"029")), (t3(b"CV "), t3(b"011")),
+    (t3(b"CW "), t3(b"029")), (t3(b"CX "), t3(b"053")), (t3(b"CY "), t3(b"145")),
+    (t3(b"CZ "), t3(b"151")), (t3(b"DE "), t3(b"155")), (t3(b"DG "), t3(b"QO ")),
+    (t3(b"DJ "), t3(b"014")), (t3(b"DK "), t3(b"154")), (t3(b"DM "), t3(b"029")),
+    (t3(b"DO "), t3(b"029")), (t3(b"DZ "), t3(b"015")), (t3(b"EA "), t3(b"015")),
+    (t3(b"EC "), t3(b"005")), (t3(b"EE "), t3(b"154")), (t3(b"EG "), t3(b"015")),
+    (t3(b"EH "), t3(b"015")), (t3(b"ER "), t3(b"014")), (t3(b"ES "), t3(b"039")),
+    (t3(b"ET "), t3(b"014")), (t3(b"FI "), t3(b"154")), (t3(b"FJ "), t3(b"054")),
+    (t3(b"FK "), t3(b"005")), (t3(b"FM "), t3(b"057")), (t3(b"FO "), t3(b"154")),
+    (t3(b"FR "), t3(b"155")), (t3(b"GA "), t3(b"017")), (t3(b"GB "), t3(b"154")),
+    (t3(b"GD "), t3(b"029")), (t3(b"GE "), t3(b"145")), (t3(b"GF "), t3(b"005")),
+    (t3(b"GG "), t3(b"154")), (t3(b"GH "), t3(b"011")), (t3(b"GI "), t3(b"039")),
+    (t3(b"GL "), t3(b"021")), (t3(b"GM "), t3(b"011")), (t3(b"GN "), t3(b"011")),
+    (t3(b"GP "), t3(b"029")), (t3(b"GQ "), t3(b"017")), (t3(b"GR "), t3(b"039")),
+    (t3(b"GS "), t3(b"005")), (t3(b"GT "), t3(b"013")), (t3(b"GU "), t3(b"057")),
+    (t3(b"GW "), t3(b"011")), (t3(b"GY "), t3(b"005")), (t3(b"HK "), t3(b"030")),
+    (t3(b"HM "), t3(b"053")), (t3(b"HN "), t3(b"013")), (t3(b"HR "), t3(b"039")),
+    (t3(b"HT "), t3(b"029")), (t3(b"HU "), t3(b"151")), (t3(b"IC "), t3(b"015")),
+    (t3(b"ID "), t3(b"035")), (t3(b"IE "), t3(b"154")), (t3(b"IL "), t3(b"145")),
+    (t3(b"IM "), t3(b"154")), (t3(b"IN "), t3(b"034")), (t3(b"IO "), t3(b"014")),
+    (t3(b"IQ "), t3(b"145")), (t3(b"IR "), t3(b"034")), (t3(b"IS "), t3(b"154")),
+    (t3(b"IT "), t3(b"039")), (t3(b"JE "), t3(b"154")), (t3(b"JM "), t3(b"029")),
+    (t3(b"JO "), t3(b"145")), (t3(b"JP "), t3(b"030")), (t3(b"KE "), t3(b"014")),
+    (t3(b"KG "), t3(b"143")), (t3(b"KH "), t3(b"035")), (t3(b"KI "), t3(b"057")),
+    (t3(b"KM "), t3(b"014")), (t3(b"KN "), t3(b"029")), (t3(b"KP "), t3(b"030")),
+    (t3(b"KR "), t3(b"030")), (t3(b"KW "), t3(b"145")), (t3(b"KY "), t3(b"029")),
+    (t3(b"KZ "), t3(b"143")), (t3(b"LA "), t3(b"035")), (t3(b"LB "), t3(b"145")),
+    (t3(b"LC "), t3(b"029")), (t3(b"LI "), t3(b"155")), (t3(b"LK "), t3(b"034")),
+    (t3(b"LR "), t3(b"011")), (t3(b"LS "), t3(b"018")), (t3(b"LT "), t3(b"154")),
+    (t3(b"LU "), t3(b"155")), (t3(b"LV "), t3(b"154")), (t3(b"LY "), t3(b"015")),
+    (t3(b"MA "), t3(b"015")), (t3(b"MC "), t3(b"155")), (t3(b"MD "), t3(b"151")),
+    (t3(b"ME "), t3(b"039")), (t3(b"MF "), t3(b"029")), (t3(b"MG "), t3(b"014")),
+    (t3(b"MH "), t3(b"057")), (t3(b"MK "), t3(b"039")), (t3(b"ML "), t3(b"011")),
+    (t3(b"MM "), t3(b"035")), (t3(b"MN "), t3(b"030")), (t3(b"MO "), t3(b"030")),
+    (t3(b"MP "), t3(b"057")), (t3(b"MQ "), t3(b"029")), (t3(b"MR "), t3(b"011")),
+    (t3(b"MS "), t3(b"029")), (t3(b"MT "), t3(b"039")), (t3(b"MU "), t3(b"014")),
+    (t3(b"MV "), t3(b"034")), (t3(b"MW "), t3(b"014")), (t3(b"MX "), t3(b"013")),
+    (t3(b"MY "), t3(b"035")), (t3(b"MZ "), t3(b"014")), (t3(b"NA "), t3(b"018")),
+    (t3(b"NC "), t3(b"054")), (t3(b"NE "), t3(b"011")), (t3(b"NF "), t3(b"053")),
+    (t3(b"NG "), t3(b"011")), (t3(b"NI "), t3(b"013")), (t3(b"NL "), t3(b"155")),
+    (t3(b"NO "), t3(b"154")), (t3(b"NP "), t3(b"034")), (t3(b"NR "), t3(b"057")),
+    (t3(b"NU "), t3(b"061")), (t3(b"NZ "), t3(b"053")), (t3(b"OM "), t3(b"145")),
+    (t3(b"PA "), t3(b"013")), (t3(b"PE "), t3(b"005")), (t3(b"PF "), t3(b"061")),
+    (t3(b"PG "), t3(b"054")), (t3(b"PH "), t3(b"035")), (t3(b"PK "), t3(b"034")),
+    (t3(b"PL "), t3(b"151")), (t3(b"PM "), t3(b"021")), (t3(b"PN "), t3(b"061")),
+    (t3(b"PR "), t3(b"029")), (t3(b"PS "), t3(b"145")), (t3(b"PT "), t3(b"039")),
+    (t3(b"PW "), t3(b"057")), (t3(b"PY "), t3(b"005")), (t3(b"QA "), t3(b"145")),
+    (t3(b"QO "), t3(b"009")), (t3(b"RE "), t3(b"014")), (t3(b"RO "), t3(b"151")),
+    (t3(b"RS "), t3(b"039")), (t3(b"RU "), t3(b"151")), (t3(b"RW "), t3(b"014")),
+    (t3(b"SA "), t3(b"145")), (t3(b"SB "), t3(b"054")), (t3(b"SC "), t3(b"014")),
+    (t3(b"SD "), t3(b"015")), (t3(b"SE "), t3(b"154")), (t3(b"SG "), t3(b"035")),
+    (t3(b"SH "), t3(b"011")), (t3(b"SI "), t3(b"039")), (t3(b"SJ "), t3(b"154")),
+    (t3(b"SK "), t3(b"151")), (t3(b"SL "), t3(b"011")), (t3(b"SM "), t3(b"039")),
+    (t3(b"SN "), t3(b"011")), (t3(b"SO "), t3(b"014")), (t3(b"SR "), t3(b"005")),
+    (t3(b"SS "), t3(b"014")), (t3(b"ST "), t3(b"017")), (t3(b"SV "), t3(b"013")),
+    (t3(b"SX "), t3(b"029")), (t3(b"SY "), t3(b"145")), (t3(b"SZ "), t3(b"018")),
+    (t3(b"TA "), t3(b"QO ")), (t3(b"TC "), t3(b"029")), (t3(b"TD "), t3(b"017")),
+    (t3(b"TF "), t3(b"014")), (t3(b"TG "), t3(b"011")), (t3(b"TH "), t3(b"035")),
+    (t3(b"TJ "), t3(b"143")), (t3(b"TK "), t3(b"061")), (t3(b"TL "), t3(b"035")),
+    (t3(b"TM "), t3(b"143")), (t3(b"TN "), t3(b"015")), (t3(b"TO "), t3(b"061")),
+    (t3(b"TR "), t3(b"145")), (t3(b"TT "), t3(b"029")), (t3(b"TV "), t3(b"061")),
+    (t3(b"TW "), t3(b"030")), (t3(b"TZ "), t3(b"014")), (t3(b"UA "), t3(b"151")),
+    (t3(b"UG "), t3(b"014")), (t3(b"UM "), t3(b"057")), (t3(b"US "), t3(b"021")),
+    (t3(b"UY "), t3(b"005")), (t3(b"UZ "), t3(b"143")), (t3(b"VA "), t3(b"039")),
+    (t3(b"VC "), t3(b"029")), (t3(b"VE "), t3(b"005")), (t3(b"VG "), t3(b"029")),
+    (t3(b"VI "), t3(b"029")), (t3(b"VN "), t3(b"035")), (t3(b"VU "), t3(b"054")),
+    (t3(b"WF "), t3(b"061")), (t3(b"WS "), t3(b"061")), (t3(b"XK "), t3(b"039")),
+    (t3(b"YE "), t3(b"145")), (t3(b"YT "), t3(b"014")), (t3(b"ZA "), t3(b"018")),
+    (t3(b"ZM "), t3(b"014")), (t3(b"ZW "), t3(b"014")),
+];
+
+#[rustfmt::skip]
+pub const REGION_GROUPINGS: [(u32, u32); 249] = [
+    (t3(b"003"), t3(b"013")), (t3(b"003"), t3(b"021")), (t3(b"003"), t3(b"029")),
+    (t3(b"202"), t3(b"011")), (t3(b"202"), t3(b"014")), (t3(b"202"), t3(b"017")),
+    (t3(b"202"), t3(b"018")), (t3(b"419"), t3(b"005")), (t3(b"419"), t3(b"013")),
+    (t3(b"419"), t3(b"029")), (t3(b"EU "), t3(b"AT ")), (t3(b"EU "), t3(b"BE ")),
+    (t3(b"EU "), t3(b"BG ")), (t3(b"EU "), t3(b"CY ")), (t3(b"EU "), t3(b"CZ ")),
+    (t3(b"EU "), t3(b"DE ")), (t3(b"EU "), t3(b"DK ")), (t3(b"EU "), t3(b"EE ")),
+    (t3(b"EU "), t3(b"ES ")), (t3(b"EU "), t3(b"FI ")), (t3(b"EU "), t3(b"FR ")),
+    (t3(b"EU "), t3(b"GR ")), (t3(b"EU "), t3(b"HR ")), (t3(b"EU "), t3(b"HU ")),
+    (t3(b"EU "), t3(b"IE ")), (t3(b"EU "), t3(b"IT ")), (t3(b"EU "), t3(b"LT ")),
+    (t3(b"EU "), t3(b"LU ")), (t3(b"EU "), t3(b"LV ")), (t3(b"EU "), t3(b"MT ")),
+    (t3(b"EU "), t3(b"NL ")), (t3(b"EU "), t3(b"PL ")), (t3(b"EU "), t3(b"PT ")),
+    (t3(b"EU "), t3(b"RO ")), (t3(b"EU "), t3(b"SE ")), (t3(b"EU "), t3(b"SI ")),
+    (t3(b"EU "), t3(b"SK ")), (t3(b"EZ "), t3(b"AT ")), (t3(b"EZ "), t3(b"BE ")),
+    (t3(b"EZ "), t3(b"CY ")), (t3(b"EZ "), t3(b"DE ")), (t3(b"EZ "), t3(b"EE ")),
+    (t3(b"EZ "), t3(b"ES ")), (t3(b"EZ "), t3(b"FI ")), (t3(b"EZ "), t3(b"FR ")),
+    (t3(b"EZ "), t3(b"GR ")), (t3(b"EZ "), t3(b"IE ")), (t3(b"EZ "), t3(b"IT ")),
+    (t3(b"EZ "), t3(b"LT ")), (t3(b"EZ "), t3(b"LU ")), (t3(b"EZ "), t3(b"LV ")),
+    (t3(b"EZ "), t3(b"MT ")), (t3(b"EZ "), t3(b"NL ")), (t3(b"EZ "), t3(b"PT ")),
+    (t3(b"EZ "), t3(b"SI ")), (t3(b"EZ "), t3(b"SK ")), (t3(b"UN "), t3(b"AD ")),
+    (t3(b"UN "), t3(b"AE ")), (t3(b"UN "), t3(b"AF ")), (t3(b"UN "), t3(b"AG ")),
+    (t3(b"UN "), t3(b"AL ")), (t3(b"UN "), t3(b"AM ")), (t3(b"UN "), t3(b"AO ")),
+    (t3(b"UN "), t3(b"AR ")), (t3(b"UN "), t3(b"AT ")), (t3(b"UN "), t3(b"AU ")),
+    (t3(b"UN "), t3(b"AZ ")), (t3(b"UN "), t3(b"BA ")), (t3(b"UN "), t3(b"BB ")),
+    (t3(b"UN "), t3(b"BD ")), (t3(b"UN "), t3(b"BE ")), (t3(b"UN "), t3(b"BF ")),
+    (t3(b"UN "), t3(b"BG ")), (t3(b"UN "), t3(b"BH ")), (t3(b"UN "), t3(b"BI ")),
+    (t3(b"UN "), t3(b"BJ ")), (t3(b"UN "), t3(b"BN ")), (t3(b"UN "), t3(b"BO ")),
+    (t3(b"UN "), t3(b"BR ")), (t3(b"UN "), t3(b"BS ")), (t3(b"UN "), t3(b"BT ")),
+    (t3(b"UN "), t3(b"BW ")), (t3(b"UN "), t3(b"BY ")), (t3(b"UN "), t3(b"BZ ")),
+    (t3(b"UN "), t3(b"CA ")), (t3(b"UN "), t3(b"CD ")), (t3(b"UN "), t3(b"CF ")),
+    (t3(b"UN "), t3(b"CG ")), (t3(b"UN "), t3(b"CH ")), (t3(b"UN "), t3(b"CI ")),
+    (t3(b"UN "), t3(b"CL ")), (t3(b"UN "), t3(b"CM ")), (t3(b"UN "), t3(b"CN ")),
+    (t3(b"UN "), t3(b"CO ")), (t3(b"UN "), t3(b"CR ")), (t3(b"UN "), t3(b"CU ")),
+    (t3(b"UN "), t3(b"CV ")), (t3(b"UN "), t3(b"CY ")), (t3(b"UN "), t3(b"CZ ")),
+    (t3(b"UN "), t3(b"DE ")), (t3(b"UN "), t3(b"DJ ")), (t3(b"UN "), t3(b"DK ")),
+    (t3(b"UN "), t3(b"DM ")), (t3(b"UN "), t3(b"DO ")), (t3(b"UN "), t3(b"DZ ")),
+    (t3(b"UN "), t3(b"EC ")), (t3(b"UN "), t3(b"EE ")), (t3(b"UN "), t3(b"EG ")),
+    (t3(b"UN "), t3(b"ER ")), (t3(b"UN "), t3(b"ES ")), (t3(b"UN "), t3(b"ET ")),
+    (t3(b"UN "), t3(b"FI ")), (t3(b"UN "), t3(b"FJ ")), (t3(b"UN "), t3(b"FM ")),
+    (t3(b"UN "), t3(b"FR ")), (t3(b"UN "), t3(b"GA ")), (t3(b"UN "), t3(b"GB ")),
+    (t3(b"UN "), t3(b"GD ")), (t3(b"UN "), t3(b"GE ")), (t3(b"UN "), t3(b"GH ")),
+    (t3(b"UN "), t3(b"GM ")), (t3(b"UN "), t3(b"GN ")), (t3(b"UN "), t3(b"GQ ")),
+    (t3(b"UN "), t3(b"GR ")), (t3(b"UN "), t3(b"GT ")), (t3(b"UN "), t3(b"GW ")),
+    (t3(b"UN "), t3(b"GY ")), (t3(b"UN "), t3(b"HN ")), (t3(b"UN "), t3(b"HR ")),
+    (t3(b"UN "), t3(b"HT ")), (t3(b"UN "), t3(b"HU ")), (t3(b"UN "), t3(b"ID ")),
+    (t3(b"UN "), t3(b"IE ")), (t3(b"UN "), t3(b"IL ")), (t3(b"UN "), t3(b"IN ")),
+    (t3(b"UN "), t3(b"IQ ")), (t3(b"UN "), t3(b"IR ")), (t3(b"UN "), t3(b"IS ")),
+    (t3(b"UN "), t3(b"IT ")), (t3(b"UN "), t3(b"JM ")), (t3(b"UN "), t3(b"JO ")),
+    (t3(b"UN "), t3(b"JP ")), (t3(b"UN "), t3(b"KE ")), (t3(b"UN "), t3(b"KG ")),
+    (t3(b"UN "), t3(b"KH ")), (t3(b"UN "), t3(b"KI ")), (t3(b"UN "), t3(b"KM ")),
+    (t3(b"UN "), t3(b"KN ")), (t3(b"UN "), t3(b"KP ")), (t3(b"UN "), t3(b"KR ")),
+    (t3(b"UN "), t3(b"KW ")), (t3(b"UN "), t3(b"KZ ")), (t3(b"UN "), t3(b"LA ")),
+    (t3(b"UN "), t3(b"LB ")), (t3(b"UN "), t3(b"LC ")), (t3(b"UN "), t3(b"LI ")),
+    (t3(b"UN "), t3(b"LK ")), (t3(b"UN "), t3(b"LR ")), (t3(b"UN "), t3(b"LS ")),
+    (t3(b"UN "), t3(b"LT ")), (t3(b"UN "), t3(b"LU ")), (t3(b"UN "), t3(b"LV ")),
+    (t3(b"UN "), t3(b"LY ")), (t3(b"UN "), t3(b"MA ")), (t3(b"UN "), t3(b"MC ")),
+    (t3(b"UN "), t3(b"MD ")), (t3(b"UN "), t3(b"ME ")), (t3(b"UN "), t3(b"MG ")),
+    (t3(b"UN "), t3(b"MH ")), (t3(b"UN "), t3(b"MK ")), (t3(b"UN "), t3(b"ML ")),
+    (t3(b"UN "), t3(b"MM ")), (t3(b"UN "), t3(b"MN ")), (t3(b"UN "), t3(b"MR ")),
+    (t3(b"UN "), t3(b"MT ")), (t3(b"UN "), t3(b"MU ")), (t3(b"UN "), t3(b"MV ")),
+    (t3(b"UN "), t3(b"MW ")), (t3(b"UN "), t3(b"MX ")), (t3(b"UN "), t3(b"MY ")),
+    (t3(b"UN "), t3(b"MZ ")), (t3(b"UN "), t3(b"NA ")), (t3(b"UN "), t3(b"NE ")),
+    (t3(b"UN "), t3(b"NG ")), (t3(b"UN "), t3(b"NI ")), (t3(b"UN "), t3(b"NL ")),
+    (t3(b"UN "), t3(b"NO ")), (t3(b"UN "), t3(b"NP ")), (t3(b"UN "), t3(b"NR ")),
+    (t3(b"UN "), t3(b"NZ ")), (t3(b"UN "), t3(b"OM ")), (t3(b"UN "), t3(b"PA ")),
+    (t3(b"UN "), t3(b"PE ")), (t3(b"UN "), t3(b"PG ")), (t3(b"UN "), t3(b"PH ")),
+    (t3(b"UN "), t3(b"PK ")), (t3(b"UN "), t3(b"PL ")), (t3(b"UN "), t3(b"PT ")),
+    (t3(b"UN "), t3(b"PW ")), (t3(b"UN "), t3(b"PY ")), (t3(b"UN "), t3(b"QA ")),
+    (t3(b"UN "), t3(b"RO ")), (t3(b"UN "), t3(b"RS ")), (t3(b"UN "), t3(b"RU ")),
+    (t3(b"UN "), t3(b"RW ")), (t3(b"UN "), t3(b"SA ")), (t3(b"UN "), t3(b"SB ")),
+    (t3(b"UN "), t3(b"SC ")), (t3(b"UN "), t3(b"SD ")), (t3(b"UN "), t3(b"SE ")),
+    (t3(b"UN "), t3(b"SG ")), (t3(b"UN "), t3(b"SI ")), (t3(b"UN "), t3(b"SK ")),
+    (t3(b"UN "), t3(b"SL ")), (t3(b"UN "), t3(b"SM ")), (t3(b"UN "), t3(b"SN ")),
+    (t3(b"UN "), t3(b"SO ")), (t3(b"UN "), t3(b"SR ")), (t3(b"UN "), t3(b"SS ")),
+    (t3(b"UN "), t3(b"ST ")), (t3(b"UN "), t3(b"SV ")), (t3(b"UN "), t3(b"SY ")),
+    (t3(b"UN "), t3(b"SZ ")), (t3(b"UN "), t3(b"TD ")), (t3(b"UN "), t3(b"TG ")),
+    (t3(b"UN "), t3(b"TH ")), (t3(b"UN "), t3(b"TJ ")), (t3(b"UN "), t3(b"TL ")),
+    (t3(b"UN "), t3(b"TM ")), (t3(b"UN "), t3(b"TN ")), (t3(b"UN "), t3(b"TO ")),
+    (t3(b"UN "), t3(b"TR ")), (t3(b"UN "), t3(b"TT ")), (t3(b"UN "), t3(b"TV ")),
+    (t3(b"UN "), t3(b"TZ ")), (t3(b"UN "), t3(b"UA ")), (t3(b"UN "), t3(b"UG ")),
+    (t3(b"UN "), t3(b"US ")), (t3(b"UN "), t3(b"UY ")), (t3(b"UN "), t3(b"UZ ")),
+    (t3(b"UN "), t3(b"VC ")), (t3(b"UN "), t3(b"VE ")), (t3(b"UN "), t3(b"VN ")),
+    (t3(b"UN "), t3(b"VU ")), (t3(b"UN "), t3(b"WS ")), (t3(b"UN "), t3(b"YE ")),
+    (t3(b"UN "), t3(b"ZA ")), (t3(b"UN "), t3(b"ZM ")), (t3(b"UN "), t3(b"ZW ")),
+];
+
+#[rustfmt::skip]
+pub const SUPPRESS_SCRIPTS: [(&str, &str); 134] = [
+    ("ab", "Cyrl"), ("af", "Latn"), ("am", "Ethi"), ("ar", "Arab"), ("as", "Beng"), ("ay", "Latn"),
+    ("be", "Cyrl"), ("bg", "Cyrl"), ("bn", "Beng"), ("bs", "Latn"), ("ca", "Latn"), ("ch", "Latn"),
+    ("cs", "Latn"), ("cy", "Latn"), ("da", "Latn"), ("de", "Latn"), ("dsb", "Latn"),
+    ("dv", "Thaa"), ("dz", "Tibt"), ("el", "Grek"), ("en", "Latn"), ("eo", "Latn"), ("es", "Latn"),
+    ("et", "Latn"), ("eu", "Latn"), ("fa", "Arab"), ("fi", "Latn"), ("fj", "Latn"), ("fo", "Latn"),
+    ("fr", "Latn"), ("frr", "Latn"), ("frs", "Latn"), ("fy", "Latn"), ("ga", "Latn"),
+    ("gl", "Latn"), ("gn", "Latn"), ("gsw", "Latn"), ("gu", "Gujr"), ("gv", "Latn"),
+    ("he", "Hebr"), ("hi", "Deva"), ("hr", "Latn"), ("hsb", "Latn"), ("ht", "Latn"),
+    ("hu", "Latn"), ("hy", "Armn"), ("id", "Latn"), ("in", "Latn"), ("is", "Latn"), ("it", "Latn"),
+    ("iw", "Hebr"), ("ja", "Jpan"), ("ka", "Geor"), ("kk", "Cyrl"), ("kl", "Latn"), ("km", "Khmr"),
+    ("kn", "Knda"), ("ko", "Kore"), ("kok", "Deva"), ("la", "Latn"), ("lb", "Latn"),
+    ("ln", "Latn"), ("lo", "Laoo"), ("lt", "Latn"), ("lv", "Latn"), ("mai", "Deva"),
+    ("men", "Latn"), ("mg", "Latn"), ("mh", "Latn"), ("mk", "Cyrl"), ("ml", "Mlym"),
+    ("mo", "Latn"), ("mr", "Deva"), ("ms", "Latn"), ("mt", "Latn"), ("my", "Mymr"), ("na", "Latn"),
+    ("nb", "Latn"), ("nd", "Latn"), ("nds", "Latn"), ("ne", "Deva"), ("niu", "Latn"),
+    ("nl", "Latn"), ("nn", "Latn"), ("no", "Latn"), ("nqo", "Nkoo"), ("nr", "Latn"),
+    ("nso", "Latn"), ("ny", "Latn"), ("om", "Latn"), ("or", "Orya"), ("pa", "Guru"),
+    ("pl", "Latn"), ("ps", "Arab"), ("pt", "Latn"), ("qu", "Latn"), ("rm", "Latn"), ("rn", "Latn"),
+    ("ro", "Latn"), ("ru", "Cyrl"), ("rw", "Latn"), ("sg", "Latn"), ("si", "Sinh"), ("sk", "Latn"),
+    ("sl", "Latn"), ("sm", "Latn"), ("so", "Latn"), ("sq", "Latn"), ("ss", "Latn"), ("st", "Latn"),
+    ("sv", "Latn"), ("sw", "Latn"), ("ta", "Taml"), ("te", "Telu"), ("tem", "Latn"),
+    ("th", "Thai"), ("ti", "Ethi"), ("tkl", "Latn"), ("tl", "Latn"), ("tmh", "Latn"),
+    ("tn", "Latn"), ("to", "Latn"), ("tpi", "Latn"), ("tr", "Latn"), ("ts", "Latn"),
+    ("tvl", "Latn"), ("uk", "Cyrl"), ("ur", "Arab"), ("ve", "Latn"), ("vi", "Latn"),
+    ("xh", "Latn"), ("yi", "Hebr"), ("zbl", "Blis"), ("zu", "Latn"),
+];
+
+#[rustfmt::skip]
+pub const ISO639_ALPHA3: [(u16, u32); 183] = [
+    (t2(b"aa"), t3(b"aar")), (t2(b"ab"), t3(b"abk")), (t2(b"ae"), t3(b"ave")),
+    (t2(b"af"), t3(b"afr")), (t2(b"ak"), t3(b"aka")), (t2(b"am"), t3(b"amh")),
+    (t2(b"an"), t3(b"arg")), (t2(b"ar"), t3(b"ara")), (t2(b"as"), t3(b"asm")),
+    (t2(b"av"), t3(b"ava")), (t2(b"ay"), t3(b"aym")), (t2(b"az"), t3(b"aze")),
+    (t2(b"ba"), t3(b"bak")), (t2(b"be"), t3(b"bel")), (t2(b"bg"), t3(b"bul")),
+    (t2(b"bi"), t3(b"bis")), (t2(b"bm"), t3(b"bam")), (t2(b"bn"), t3(b"ben")),
+    (t2(b"bo"), t3(b"bod")), (t2(b"br"), t3(b"bre")), (t2(b"bs"), t3(b"bos")),
+    (t2(b"ca"), t3(b"cat")), (t2(b"ce"), t3(b"che")), (t2(b"ch"), t3(b"cha")),
+    (t2(b"co"), t3(b"cos")), (t2(b"cr"), t3(b"cre")), (t2(b"cs"), t3(b"ces")),
+    (t2(b"cu"), t3(b"chu")), (t2(b"cv"), t3(b"chv")), (t2(b"cy"), t3(b"cym")),
+    (t2(b"da"), t3(b"dan")), (t2(b"de"), t3(b"deu")), (t2(b"dv"), t3(b"div")),
+    (t2(b"dz"), t3(b"dzo")), (t2(b"ee"), t3(b"ewe")), (t2(b"el"), t3(b"ell")),
+    (t2(b"en"), t3(b"eng")), (t2(b"eo"), t3(b"epo")), (t2(b"es"), t3(b"spa")),
+    (t2(b"et"), t3(b"est")), (t2(b"eu"), t3(b"eus")), (t2(b"fa"), t3(b"fas")),
+    (t2(b"ff"), t3(b"ful")), (t2(b"fi"), t3(b"fin")), (t2(b"fj"), t3(b"fij")),
+    (t2(b"fo"), t3(b"fao")), (t2(b"fr"), t3(b"fra")), (t2(b"fy"), t3(b"fry")),
+    (t2(b"ga"), t3(b"gle")), (t2(b"gd"), t3(b"gla")), (t2(b"gl"), t3(b"glg")),
+    (t2(b"gn"), t3(b"grn")), (t2(b"gu"), t3(b"guj")), (t2(b"gv"), t3(b"glv")),
+    (t2(b"ha"), t3(b"hau")), (t2(b"he"), t3(b"heb")), (t2(b"hi"), t3(b"hin")),
+    (t2(b"ho"), t3(b"hmo")), (t2(b"hr"), t3(b"hrv")), (t2(b"ht"), t3(b"hat")),
+    (t2(b"hu"), t3(b"hun")), (t2(b"hy"), t3(b"hye")), (t2(b"hz"), t3(b"her")),
+    (t2(b"ia"), t3(b"ina")), (t2(b"id"), t3(b"ind")), (t2(b"ie"), t3(b"ile")),
+    (t2(b"ig"), t3(b"ibo")), (t2(b"ii"), t3(b"iii")), (t2(b"ik"), t3(b"ipk")),
+    (t2(b"io"), t3(b"ido")), (t2(b"is"), t3(b"isl")), (t2(b"it"), t3(b"ita")),
+    (t2(b"iu"), t3(b"iku")), (t2(b"ja"), t3(b"jpn")), (t2(b"jv"), t3(b"jav")),
+    (t2(b"ka"), t3(b"kat")), (t2(b"kg"), t3(b"kon")), (t2(b"ki"), t3(b"kik")),
+    (t2(b"kj"), t3(b"kua")), (t2(b"kk"), t3(b"kaz")), (t2(b"kl"), t3(b"kal")),
+    (t2(b"km"), t3(b"khm")), (t2(b"kn"), t3(b"kan")), (t2(b"ko"), t3(b"kor")),
+    (t2(b"kr"), t3(b"kau")), (t2(b"ks"), t3(b"kas")), (t2(b"ku"), t3(b"kur")),
+    (t2(b"kv"), t3(b"kom")), (t2(b"kw"), t3(b"cor")), (t2(b"ky"), t3(b"kir")),
+    (t2(b"la"), t3(b"lat")), (t2(b"lb"), t3(b"ltz")), (t2(b"lg"), t3(b"lug")),
+    (t2(b"li"), t3(b"lim")), (t2(b"ln"), t3(b"lin")), (t2(b"lo"), t3(b"lao")),
+    (t2(b"lt"), t3(b"lit")), (t2(b"lu"), t3(b"lub")), (t2(b"lv"), t3(b"lav")),
+    (t2(b"mg"), t3(b"mlg")), (t2(b"mh"), t3(b"mah")), (t2(b"mi"), t3(b"mri")),
+    (t2(b"mk"), t3(b"mkd")), (t2(b"ml"), t3(b"mal")), (t2(b"mn"), t3(b"mon")),
+    (t2(b"mr"), t3(b"mar")), (t2(b"ms"), t3(b"msa")), (t2(b"mt"), t3(b"mlt")),
+    (t2(b"my"), t3(b"mya")), (t2(b"na"), t3(b"nau")), (t2(b"nb"), t3(b"nob")),
+    (t2(b"nd"), t3(b"nde")), (t2(b"ne"), t3(b"nep")), (t2(b"ng"), t3(b"ndo")),
+    (t2(b"nl"), t3(b"nld")), (t2(b"nn"), t3(b"nno")), (t2(b"no"), t3(b"nor")),
+    (t2(b"nr"), t3(b"nbl")), (t2(b"nv"), t3(b"nav")), (t2(b"ny"), t3(b"nya")),
+    (t2(b"oc"), t3(b"oci")), (t2(b"oj"), t3(b"oji")), (t2(b"om"), t3(b"orm")),
+    (t2(b"or"), t3(b"ori")), (t2(b"os"), t3(b"oss")), (t2(b"pa"), t3(b"pan")),
+    (t2(b"pi"), t3(b"pli")), (t2(b"pl"), t3(b"pol")), (t2(b"ps"), t3(b"pus")),
+    (t2(b"pt"), t3(b"por")), (t2(b"qu"), t3(b"que")), (t2(b"rm"), t3(b"roh")),
+    (t2(b"rn"), t3(b"run")), (t2(b"ro"), t3(b"ron")), (t2(b"ru"), t3(b"rus")),
+    (t2(b"rw"), t3(b"kin")), (t2(b"sa"), t3(b"san")), (t2(b"sc"), t3(b"srd")),
+    (t2(b"sd"), t3(b"snd")), (t2(b"se"), t3(b"sme")), (t2(b"sg"), t3(b"sag")),
+    (t2(b"si"), t3(b"sin")), (t2(b"sk"), t3(b"slk")), (t2(b"sl"), t3(b"slv")),
+    (t2(b"sm"), t3(b"smo")), (t2(b"sn"), t3(b"sna")), (t2(b"so"), t3(b"som")),
+    (t2(b"sq"), t3(b"sqi")), (t2(b"sr"), t3(b"srp")), (t2(b"ss"), t3(b"ssw")),
+    (t2(b"st"), t3(b"sot")), (t2(b"su"), t3(b"sun")), (t2(b"sv"), t3(b"swe")),
+    (t2(b"sw"), t3(b"swa")), (t2(b"ta"), t3(b"tam")), (t2(b"te"), t3(b"tel")),
+    (t2(b"tg"), t3(b"tgk")), (t2(b"th"), t3(b"tha")), (t2(b"ti"), t3(b"tir")),
+    (t2(b"tk"), t3(b"tuk")), (t2(b"tl"), t3(b"tgl")), (t2(b"tn"), t3(b"tsn")),
+    (t2(b"to"), t3(b"ton")), (t2(b"tr"), t3(b"tur")), (t2(b"ts"), t3(b"tso")),
+    (t2(b"tt"), t3(b"tat")), (t2(b"tw"), t3(b"twi")), (t2(b"ty"), t3(b"tah")),
+    (t2(b"ug"), t3(b"uig")), (t2(b"uk"), t3(b"ukr")), (t2(b"ur"), t3(b"urd")),
+    (t2(b"uz"), t3(b"uzb")), (t2(b"ve"), t3(b"ven")), (t2(b"vi"), t3(b"vie")),
+    (t2(b"vo"), t3(b"vol")), (t2(b"wa"), t3(b"wln")), (t2(b"wo"), t3(b"wol")),
+    (t2(b"xh"), t3(b"xho")), (t2(b"yi"), t3(b"yid")), (t2(b"yo"), t3(b"yor")),
+    (t2(b"za"), t3(b"zha")), (t2(b"zh"), t3(b"zho")), (t2(b"zu"), t3(b"zul")),
+];
+
+#[rustfmt::skip]
+pub const ISO639_ALPHA2: [(u32, u16); 183] = [
+    (t3(b"aar"), t2(b"aa")), (t3(b"abk"), t2(b"ab")), (t3(b"afr"), t2(b"af")),
+    (t3(b"aka"), t2(b"ak")), (t3(b"amh"), t2(b"am")), (t3(b"ara"), t2(b"ar")),
+    (t3(b"arg"), t2(b"an")), (t3(b"asm"), t2(b"as")), (t3(b"ava"), t2(b"av")),
+    (t3(b"ave"), t2(b"ae")), (t3(b"aym"), t2(b"ay")), (t3(b"aze"), t2(b"az")),
+    (t3(b"bak"), t2(b"ba")), (t3(b"bam"), t2(b"bm")), (t3(b"bel"), t2(b"be")),
+    (t3(b"ben"), t2(b"bn")), (t3(b"bis"), t2(b"bi")), (t3(b"bod"), t2(b"bo")),
+    (t3(b"bos"), t2(b"bs")), (t3(b"bre"), t2(b"br")), (t3(b"bul"), t2(b"bg")),
+    (t3(b"cat"), t2(b"ca")), (t3(b"ces"), t2(b"cs")), (t3(b"cha"), t2(b"ch")),
+    (t3(b"che"), t2(b"ce")), (t3(b"chu"), t2(b"cu")), (t3(b"chv"), t2(b"cv")),
+    (t3(b"cor"), t2(b"kw")), (t3(b"cos"), t2(b"co")), (t3(b"cre"), t2(b"cr")),
+    (t3(b"cym"), t2(b"cy")), (t3(b"dan"), t2(b"da")), (t3(b"deu"), t2(b"de")),
+    (t3(b"div"), t2(b"dv")), (t3(b"dzo"), t2(b"dz")), (t3(b"ell"), t2(b"el")),
+    (t3(b"eng"), t2(b"en")), (t3(b"epo"), t2(b"eo")), (t3(b"est"), t2(b"et")),
+    (t3(b"eus"), t2(b"eu")), (t3(b"ewe"), t2(b"ee")), (t3(b"fao"), t2(b"fo")),
+    (t3(b"fas"), t2(b"fa")), (t3(b"fij"), t2(b"fj")), (t3(b"fin"), t2(b"fi")),
+    (t3(b"fra"), t2(b"fr")), (t3(b"fry"), t2(b"fy")), (t3(b"ful"), t2(b"ff")),
+    (t3(b"gla"), t2(b"gd")), (t3(b"gle"), t2(b"ga")), (t3(b"glg"), t2(b"gl")),
+    (t3(b"glv"), t2(b"gv")), (t3(b"grn"), t2(b"gn")), (t3(b"guj"), t2(b"gu")),
+    (t3(b"hat"), t2(b"ht")), (t3(b"hau"), t2(b"ha")), (t3(b"heb"), t2(b"he")),
+    (t3(b"her"), t2(b"hz")), (t3(b"hin"), t2(b"hi")), (t3(b"hmo"), t2(b"ho")),
+    (t3(b"hrv"), t2(b"hr")), (t3(b"hun"), t2(b"hu")), (t3(b"hye"), t2(b"hy")),
+    (t3(b"ibo"), t2(b"ig")), (t3(b"ido"), t2(b"io")), (t3(b"iii"), t2(b"ii")),
+    (t3(b"iku"), t2(b"iu")), (t3(b"ile"), t2(b"ie")), (t3(b"ina"), t2(b"ia")),
+    (t3(b"ind"), t2(b"id")), (t3(b"ipk"), t2(b"ik")), (t3(b"isl"), t2(b"is")),
+    (t3(b"ita"), t2(b"it")), (t3(b"jav"), t2(b"jv")), (t3(b"jpn"), t2(b"ja")),
+    (t3(b"kal"), t2(b"kl")), (t3(b"kan"), t2(b"kn")), (t3(b"kas"), t2(b"ks")),
+    (t3(b"kat"), t2(b"ka")), (t3(b"kau"), t2(b"kr")), (t3(b"kaz"), t2(b"kk")),
+    (t3(b"khm"), t2(b"km")), (t3(b"kik"), t2(b"ki")), (t3(b"kin"), t2(b"rw")),
+    (t3(b"kir"), t2(b"ky")), (t3(b"kom"), t2(b"kv")), (t3(b"kon"), t2(b"kg")),
+    (t3(b"kor"), t2(b"ko")), (t3(b"kua"), t2(b"kj")), (t3(b"kur"), t2(b"ku")),
+    (t3(b"lao"), t2(b"lo")), (t3(b"lat"), t2(b"la")), (t3(b"lav"), t2(b"lv")),
+    (t3(b"lim"), t2(b"li")), (t3(b"lin"), t2(b"ln")), (t3(b"lit"), t2(b"lt")),
+    (t3(b"ltz"), t2(b"lb")), (t3(b"lub"), t2(b"lu")), (t3(b"lug"), t2(b"lg")),
+    (t3(b"mah"), t2(b"mh")), (t3(b"mal"), t2(b"ml")), (t3(b"mar"), t2(b"mr")),
+    (t3(b"mkd"), t2(b"mk")), (t3(b"mlg"), t2(b"mg")), (t3(b"mlt"), t2(b"mt")),
+    (t3(b"mon"), t2(b"mn")), (t3(b"mri"), t2(b"mi")), (t3(b"msa"), t2(b"ms")),
+    (t3(b"mya"), t2(b"my")), (t3(b"nau"), t2(b"na")), (t3(b"nav"), t2(b"nv")),
+    (t3(b"nbl"), t2(b"nr")), (t3(b"nde"), t2(b"nd")), (t3(b"ndo"), t2(b"ng")),
+    (t3(b"nep"), t2(b"ne")), (t3(b"nld"), t2(b"nl")), (t3(b"nno"), t2(b"nn")),
+    (t3(b"nob"), t2(b"nb")), (t3(b"nor"), t2(b"no")), (t3(b"nya"), t2(b"ny")),
+    (t3(b"oci"), t2(b"oc")), (t3(b"oji"), t2(b"oj")), (t3(b"ori"), t2(b"or")),
+    (t3(b"orm"), t2(b"om")), (t3(b"oss"), t2(b"os")), (t3(b"pan"), t2(b"pa")),
+    (t3(b"pli"), t2(b"pi")), (t3(b"pol"), t2(b"pl")), (t3(b"por"), t2(b"pt")),
+    (t3(b"pus"), t2(b"ps")), (t3(b"que"), t2(b"qu")), (t3(b"roh"), t2(b"rm")),
+    (t3(b"ron"), t2(b"ro")), (t3(b"run"), t2(b"rn")), (t3(b"rus"), t2(b"ru")),
+    (t3(b"sag"), t2(b"sg")), (t3(b"san"), t2(b"sa")), (t3(b"sin"), t2(b"si")),
+    (t3(b"slk"), t2(b"sk")), (t3(b"slv"), t2(b"sl")), (t3(b"sme"), t2(b"se")),
+    (t3(b"smo"), t2(b"sm")), (t3(b"sna"), t2(b"sn")), (t3(b"snd"), t2(b"sd")),
+    (t3(b"som"), t2(b"so")), (t3(b"sot"), t2(b"st")), (t3(b"spa"), t2(b"es")),
+    (t3(b"sqi"), t2(b"sq")), (t3(b"srd"), t2(b"sc")), (t3(b"srp"), t2(b"sr")),
+    (t3(b"ssw"), t2(b"ss")), (t3(b"sun"), t2(b"su")), (t3(b"swa"), t2(b"sw")),
+    (t3(b"swe"), t2(b"sv")), (t3(b"tah"), t2(b"ty")), (t3(b"tam"), t2(b"ta")),
+    (t3(b"tat"), t2(b"tt")), (t3(b"tel"), t2(b"te")), (t3(b"tgk"), t2(b"tg")),
+    (t3(b"tgl"), t2(b"tl")), (t3(b"tha"), t2(b"th")), (t3(b"tir"), t2(b"ti")),
+    (t3(b"ton"), t2(b"to")), (t3(b"tsn"), t2(b"tn")), (t3(b"tso"), t2(b"ts")),
+    (t3(b"tuk"), t2(b"tk")), (t3(b"tur"), t2(b"tr")), (t3(b"twi"), t2(b"tw")),
+    (t3(b"uig"), t2(b"ug")), (t3(b"ukr"), t2(b"uk")), (t3(b"urd"), t2(b"ur")),
+    (t3(b"uzb"), t2(b"uz")), (t3(b"ven"), t2(b"ve")), (t3(b"vie"), t2(b"vi")),
+    (t3(b"vol"), t2(b"vo")), (t3(b"wln"), t2(b"wa")), (t3(b"wol"), t2(b"wo")),
+    (t3(b"xho"), t2(b"xh")), (t3(b"yid"), t2(b"yi")), (t3(b"yor"), t2(b"yo")),
+    (t3(b"zha"), t2(b"za")), (t3(b"zho"), t2(b"zh")), (t3(b"zul"), t2(b"zu")),
+];
+
+#[rustfmt::skip]
+pub const ISO639_BIBLIOGRAPHIC: [(u32, u32); 20] = [
+    (t3(b"alb"), t3(b"sqi")), (t3(b"arm"), t3(b"hye")), (t3(b"baq"), t3(b"eus")),
+    (t3(b"bur"), t3(b"mya")), (t3(b"chi"), t3(b"zho")), (t3(b"cze"), t3(b"ces")),
+    (t3(b"dut"), t3(b"nld")), (t3(b"fre"), t3(b"fra")), (t3(b"geo"), t3(b"kat")),
+    (t3(b"ger"), t3(b"deu")), (t3(b"gre"), t3(b"ell")), (t3(b"ice"), t3(b"isl")),
+    (t3(b"mac"), t3(b"mkd")), (t3(b"mao"), t3(b"mri")), (t3(b"may"), t3(b"msa")),
+    (t3(b"per"), t3(b"fas")), (t3(b"rum"), t3(b"ron")), (t3(b"slo"), t3(b"slk")),
+    (t3(b"tib"), t3(b"bod")), (t3(b"wel"), t3(b"cym")),
+];
+
+#[rustfmt::skip]
+pub const ISO3166_CODES: [(u16, &str, u16); 249] = [
+    (t2(b"AD"), "AND", 20), (t2(b"AE"), "ARE", 784), (t2(b"AF"), "AFG", 4), (t2(b"AG"), "ATG", 28),
+    (t2(b"AI"), "AIA", 660), (t2(b"AL"), "ALB", 8), (t2(b"AM"), "ARM", 51), (t2(b"AO"), "AGO", 24),
+    (t2(b"AQ"), "ATA", 10), (t2(b"AR"), "ARG", 32), (t2(b"AS"), "ASM", 16), (t2(b"AT"), "AUT", 40),
+    (t2(b"AU"), "AUS", 36), (t2(b"AW"), "ABW", 533), (t2(b"AX"), "ALA", 248),
+    (t2(b"AZ"), "AZE", 31), (t2(b"BA"), "BIH", 70), (t2(b"BB"), "BRB", 52), (t2(b"BD"), "BGD", 50),
+    (t2(b"BE"), "BEL", 56), (t2(b"BF"), "BFA", 854), (t2(b"BG"), "BGR", 100),
+    (t2(b"BH"), "BHR", 48), (t2(b"BI"), "BDI", 108), (t2(b"BJ"), "BEN", 204),
+    (t2(b"BL"), "BLM", 652), (t2(b"BM"), "BMU", 60), (t2(b"BN"), "BRN", 96),
+    (t2(b"BO"), "BOL", 68), (t2(b"BQ"), "BES", 535), (t2(b"BR"), "BRA", 76),
+    (t2(b"BS"), "BHS", 44), (t2(b"BT"), "BTN", 64), (t2(b"BV"), "BVT", 74), (t2(b"BW"), "BWA", 72),
+    (t2(b"BY"), "BLR", 112), (t2(b"BZ"), "BLZ", 84), (t2(b"CA"), "CAN", 124),
+    (t2(b"CC"), "CCK", 166), (t2(b"CD"), "COD", 180), (t2(b"CF"), "CAF", 140),
+    (t2(b"CG"), "COG", 178), (t2(b"CH"), "CHE", 756), (t2(b"CI"), "CIV", 384),
+    (t2(b"CK"), "COK", 184), (t2(b"CL"), "CHL", 152), (t2(b"CM"), "CMR", 120),
+    (t2(b"CN"), "CHN", 156), (t2(b"CO"), "COL", 170), (t2(b"CR"), "CRI", 188),
+    (t2(b"CU"), "CUB", 192), (t2(b"CV"), "CPV", 132), (t2(b"CW"), "CUW", 531),
+    (t2(b"CX"), "CXR", 162), (t2(b"CY"), "CYP", 196), (t2(b"CZ"), "CZE", 203),
+    (t2(b"DE"), "DEU", 276), (t2(b"DJ"), "DJI", 262), (t2(b"DK"), "DNK", 208),
+    (t2(b"DM"), "DMA", 212), (t2(b"DO"), "DOM", 214), (t2(b"DZ"), "DZA", 12),
+    (t2(b"EC"), "ECU", 218), (t2(b"EE"), "EST", 233), (t2(b"EG"), "EGY", 818),
+    (t2(b"EH"), "ESH", 732), (t2(b"ER"), "ERI", 232), (t2(b"ES"), "ESP", 724),
+    (t2(b"ET"), "ETH", 231), (t2(b"FI"), "FIN", 246), (t2(b"FJ"), "FJI", 242),
+    (t2(b"FK"), "FLK", 238), (t2(b"FM"), "FSM", 583), (t2(b"FO"), "FRO", 234),
+    (t2(b"FR"), "FRA", 250), (t2(b"GA"), "GAB", 266), (t2(b"GB"), "GBR", 826),
+    (t2(b"GD"), "GRD", 308), (t2(b"GE"), "GEO", 268), (t2(b"GF"), "GUF", 254),
+    (t2(b"GG"), "GGY", 831), (t2(b"GH"), "GHA", 288), (t2(b"GI"), "GIB", 292),
+    (t2(b"GL"), "GRL", 304), (t2(b"GM"), "GMB", 270), (t2(b"GN"), "GIN", 324),
+    (t2(b"GP"), "GLP", 312), (t2(b"GQ"), "GNQ", 226), (t2(b"GR"), "GRC", 300),
+    (t2(b"GS"), "SGS", 239), (t2(b"GT"), "GTM", 320), (t2(b"GU"), "GUM", 316),
+    (t2(b"GW"), "GNB", 624), (t2(b"GY"), "GUY", 328), (t2(b"HK"), "HKG", 344),
+    (t2(b"HM"), "HMD", 334), (t2(b"HN"), "HND", 340), (t2(b"HR"), "HRV", 191),
+    (t2(b"HT"), "HTI", 332), (t2(b"HU"), "HUN", 348), (t2(b"ID"), "IDN", 360),
+    (t2(b"IE"), "IRL", 372), (t2(b"IL"), "ISR", 376), (t2(b"IM"), "IMN", 833),
+    (t2(b"IN"), "IND", 356), (t2(b"IO"), "IOT", 86), (t2(b"IQ"), "IRQ", 368),
+    (t2(b"IR"), "IRN", 364), (t2(b"IS"), "ISL", 352), (t2(b"IT"), "ITA", 380),
+    (t2(b"JE"), "JEY", 832), (t2(b"JM"), "JAM", 388), (t2(b"JO"), "JOR", 400),
+    (t2(b"JP"), "JPN", 392), (t2(b"KE"), "KEN", 404), (t2(b"KG"), "KGZ", 417),
+    (t2(b"KH"), "KHM", 116), (t2(b"KI"), "KIR", 296), (t2(b"KM"), "COM", 174),
+    (t2(b"KN"), "KNA", 659), (t2(b"KP"), "PRK", 408), (t2(b"KR"), "KOR", 410),
+    (t2(b"KW"), "KWT", 414), (t2(b"KY"), "CYM", 136), (t2(b"KZ"), "KAZ", 398),
+    (t2(b"LA"), "LAO", 418), (t2(b"LB"), "LBN", 422), (t2(b"LC"), "LCA", 662),
+    (t2(b"LI"), "LIE", 438), (t2(b"LK"), "LKA", 144), (t2(b"LR"), "LBR", 430),
+    (t2(b"LS"), "LSO", 426), (t2(b"LT"), "LTU", 440), (t2(b"LU"), "LUX", 442),
+    (t2(b"LV"), "LVA", 428), (t2(b"LY"), "LBY", 434), (t2(b"MA"), "MAR", 504),
+    (t2(b"MC"), "MCO", 492), (t2(b"MD"), "MDA", 498), (t2(b"ME"), "MNE", 499),
+    (t2(b"MF"), "MAF", 663), (t2(b"MG"), "MDG", 450), (t2(b"MH"), "MHL", 584),
+    (t2(b"MK"), "MKD", 807), (t2(b"ML"), "MLI", 466), (t2(b"MM"), "MMR", 104),
+    (t2(b"MN"), "MNG", 496), (t2(b"MO"), "MAC", 446), (t2(b"MP"), "MNP", 580),
+    (t2(b"MQ"), "MTQ", 474), (t2(b"MR"), "MRT", 478), (t2(b"MS"), "MSR", 500),
+    (t2(b"MT"), "MLT", 470), (t2(b"MU"), "MUS", 480), (t2(b"MV"), "MDV", 462),
+    (t2(b"MW"), "MWI", 454), (t2(b"MX"), "MEX", 484), (t2(b"MY"), "MYS", 458),
+    (t2(b"MZ"), "MOZ", 508), (t2(b"NA"), "NAM", 516), (t2(b"NC"), "NCL", 540),
+    (t2(b"NE"), "NER", 562), (t2(b"NF"), "NFK", 574), (t2(b"NG"), "NGA", 566),
+    (t2(b"NI"), "NIC", 558), (t2(b"NL"), "NLD", 528), (t2(b"NO"), "NOR", 578),
+    (t2(b"NP"), "NPL", 524), (t2(b"NR"), "NRU", 520), (t2(b"NU"), "NIU", 570),
+    (t2(b"NZ"), "NZL", 554), (t2(b"OM"), "OMN", 512), (t2(b"PA"), "PAN", 591),
+    (t2(b"PE"), "PER", 604), (t2(b"PF"), "PYF", 258), (t2(b"PG"), "PNG", 598),
+    (t2(b"PH"), "PHL", 608), (t2(b"PK"), "PAK", 586), (t2(b"PL"), "POL", 616),
+    (t2(b"PM"), "SPM", 666), (t2(b"PN"), "PCN", 612), (t2(b"PR"), "PRI", 630),
+    (t2(b"PS"), "PSE", 275), (t2(b"PT"), "PRT", 620), (t2(b"PW"), "PLW", 585),
+    (t2(b"PY"), "PRY", 600), (t2(b"QA"), "QAT", 634), (t2(b"RE"), "REU", 638),
+    (t2(b"RO"), "ROU", 642), (t2(b"RS"), "SRB", 688), (t2(b"RU"), "RUS", 643),
+    (t2(b"RW"), "RWA", 646), (t2(b"SA"), "SAU", 682), (t2(b"SB"), "SLB", 90),
+    (t2(b"SC"), "SYC", 690), (t2(b"SD"), "SDN", 729), (t2(b"SE"), "SWE", 752),
+    (t2(b"SG"), "SGP", 702), (t2(b"SH"), "SHN", 654), (t2(b"SI"), "SVN", 705),
+    (t2(b"SJ"), "SJM", 744), (t2(b"SK"), "SVK", 703), (t2(b"SL"), "SLE", 694),
+    (t2(b"SM"), "SMR", 674), (t2(b"SN"), "SEN", 686), (t2(b"SO"), "SOM", 706),
+    (t2(b"SR"), "SUR", 740), (t2(b"SS"), "SSD", 728), (t2(b"ST"), "STP", 678),
+    (t2(b"SV"), "SLV", 222), (t2(b"SX"), "SXM", 534), (t2(b"SY"), "SYR", 760),
+    (t2(b"SZ"), "SWZ", 748), (t2(b"TC"), "TCA", 796), (t2(b"TD"), "TCD", 148),
+    (t2(b"TF"), "ATF", 260), (t2(b"TG"), "TGO", 768), (t2(b"TH"), "THA", 764),
+    (t2(b"TJ"), "TJK", 762), (t2(b"TK"), "TKL", 772), (t2(b"TL"), "TLS", 626),
+    (t2(b"TM"), "TKM", 795), (t2(b"TN"), "TUN", 788), (t2(b"TO"), "TON", 776),
+    (t2(b"TR"), "TUR", 792), (t2(b"TT"), "TTO", 780), (t2(b"TV"), "TUV", 798),
+    (t2(b"TW"), "TWN", 158), (t2(b"TZ"), "TZA", 834), (t2(b"UA"), "UKR", 804),
+    (t2(b"UG"), "UGA", 800), (t2(b"UM"), "UMI", 581), (t2(b"US"), "USA", 840),
+    (t2(b"UY"), "URY", 858), (t2(b"UZ"), "UZB", 860), (t2(b"VA"), "VAT", 336),
+    (t2(b"VC"), "VCT", 670), (t2(b"VE"), "VEN", 862), (t2(b"VG"), "VGB", 92),
+    (t2(b"VI"), "VIR", 850), (t2(b"VN"), "VNM", 704), (t2(b"VU"), "VUT", 548),
+    (t2(b"WF"), "WLF", 876), (t2(b"WS"), "WSM", 882), (t2(b"YE"), "YEM", 887),
+    (t2(b"YT"), "MYT", 175), (t2(b"ZA"), "ZAF", 710), (t2(b"ZM"), "ZMB", 894),
+    (t2(b"ZW"), "ZWE", 716),
+];
+
+#[rustfmt::skip]
+pub const NUMBERING_SYSTEMS: [(&str, &str, &str); 56] = [
+    ("ar", "arab", "arab"), ("ar-AE", "latn", "arab"), ("ar-DZ", "latn", "arab"),
+    ("ar-EH", "latn", "arab"), ("ar-LY", "latn", "arab"), ("ar-MA", "latn", "arab"),
+    ("ar-TN", "latn", "arab"), ("as", "beng", "beng"), ("bgc", "deva", "latn"),
+    ("bho", "deva", "latn"), ("bn", "beng", "beng"), ("bo", "latn", "tibt"),
+    ("brx", "latn", "deva"), ("ccp", "cakm", "cakm"), ("ckb", "arab", "arab"),
+    ("doi", "latn", "deva"), ("dz", "tibt", "tibt"), ("fa", "arabext", "arabext"),
+    ("ff-Adlm", "adlm", "adlm"), ("gu", "latn", "gujr"), ("hi", "latn", "deva"),
+    ("hi-Latn", "latn", "latn"), ("jv", "latn", "java"), ("km", "latn", "khmr"),
+    ("kn", "latn", "knda"), ("kok", "latn", "deva"), ("ks", "arabext", "arabext"),
+    ("ks-Deva", "latn", "latn"), ("lo", "latn", "laoo"), ("lrc", "arabext", "arabext"),
+    ("mai", "latn", "deva"), ("ml", "latn", "mlym"), ("mni", "beng", "latn"),
+    ("mr", "deva", "deva"), ("my", "mymr", "mymr"), ("mzn", "arabext", "arabext"),
+    ("ne", "deva", "deva"), ("or", "latn", "orya"), ("pa", "latn", "guru"),
+    ("pa-Arab", "arabext", "arabext"), ("ps", "arabext", "arabext"), ("raj", "deva", "latn"),
+    ("sa", "deva", "deva"), ("sat", "olck", "latn"), ("sd", "arab", "arab"),
+    ("sd-Deva", "latn", "latn"), ("ta", "latn", "tamldec"), ("te", "latn", "telu"),
+    ("th", "latn", "thai"), ("ug", "latn", "arabext"), ("ur", "latn", "arabext"),
+    ("ur-IN", "arabext", "arabext"), ("uz-Arab", "arabext", "arabext"), ("vai", "latn", "vaii"),
+    ("yue", "latn", "hanidec"), ("zh", "latn", "hanidec"),
+];
@@ -0,0 +1,115 @@
+//! Language matching based on the CLDR language distance model.
+
+use super::Locale;
+
+/// Distance at or above which two locales are not considered a match.
+pub const MATCH_THRESHOLD: u32 = 50;
+
+/// Distance between locales with different languages.
+const LANGUAGE_DISTANCE: u32 = 80;
+
+/// Distance between locales with different scripts.
+const SCRIPT_DISTANCE: u32 = 50;
+
+/// Distance between locales with different regions.
+const REGION_DISTANCE: u32 = 4;
+
+/// Pairs of closely related languages as (desired, supported, distance).
+#[rustfmt::skip]
+const LANGUAGE_PAIRS: [(&str, &str, u32); 7] = [
+    ("bs", "hr", 4), ("hr", "bs", 4), ("nb", "no", 1), ("nn", "nb", 10),
+    ("nn", "no", 10), ("no", "nb", 1), ("no", "nn", 10),
+];
+
+/// Pairs of scripts for a language that are partially intelligible as
+/// (language, desired, supported, distance).
+const SCRIPT_PAIRS: [(&str, &str, &str, u32); 1] = [("zh", "Hant", "Hans", 19)];
+
+/// Regions in the Americas (UN M.49 area 019).
+const AMERICAS: &str = "AGAIARAWBBBLBMBOBQBRBSBZCACLCOCRCUCWDMDOECFKGDGFGLGPGTGYHNHTJMKNKYLCMFMQMSMXNIPAPEPMPRPYSRSVSXTCTTUSUYVCVEVGVI";
+
+/// Region partitions for languages with strongly diverging regional
+/// variants. Regions on the same side of a partition are closer than
+/// regions on opposite sides.
+const REGION_GROUPS: [(&str, &str); 4] = [
+    ("en", "ASCAGUMHMPPHPRUMUSVI"),
+    ("es", AMERICAS),
+    ("pt", AMERICAS),
+    ("zh", "HKMO"),
+];
+
+/// Regional variants that are preferred as a match for other regions on
+/// the same side of a partition.
+const PARADIGM_LOCALES: [&str; 5] = ["en-US", "en-GB", "es-ES", "pt-BR", "pt-PT"];
+
+/// Returns the distance between a desired and a supported locale. Both
+/// locales are maximized before comparison. A distance of zero indicates
+/// an exact match while a distance at or above [`MATCH_THRESHOLD`]
+/// indicates that the supported locale is not a suitable substitute.
+pub fn match_distance(desired: &Locale, supported: &Locale) -> u32 {
+    let desired = desired.maximize();
+    let supported = supported.maximize();
+    let lang = desired.language();
+    let mut distance = 0;
+    if lang != supported.language() {
+        distance += LANGUAGE_PAIRS
+            .iter()
+            .find(|x| x.0 == lang && x.1 == supported.language())
+            .map(|x| x.2)
+            .unwrap_or(LANGUAGE_DISTANCE);
+    }
+    let script = desired.script().unwrap_or("");
+    let supported_script = supported.script().unwrap_or("");
+    if script != supported_script {
+        distance += SCRIPT_PAIRS
+            .iter()
+            .find(|x| x.0 == lang && x.1 == script && x.2 == supported_script)
+            .map(|x| x.3)
+            .unwrap_or(SCRIPT_DISTANCE);
+    }
+    let region = desired.region().unwrap_or("");
+    let supported_region = supported.region().unwrap_or("");
+    if region != supported_region {
+        distance += REGION_DISTANCE;
+        if let Some((_, group)) = REGION_GROUPS.iter().find(|x| x.0 == lang) {
+            if in_group(group, region) != in_group(group, supported_region) {
+                distance += 1;
+            } else if is_paradigm(supported.language(), supported_region) {
+                distance -= 1;
+            }
+        }
+    }
+    distance
+}
+
+/// Returns the available locale that best matches the requested locale, or
+/// `None` if no available locale is within [`MATCH_THRESHOLD`]. Earlier
+/// entries are preferred when several locales are equally close.
+///
+/// For example, `en-NZ` matches `en-GB` in preference to `en-US`.
+pub fn negotiate<'a>(requested: &Locale, available: &'a [Locale]) -> Option<&'a Locale> {
+    let mut best = None;
+    let mut best_distance = MATCH_THRESHOLD;
+    for locale in available {
+        let distance = match_distance(requested, locale);
+        if distance < best_distance {
+            best = Some(locale);
+            best_distance = distance;
+            if distance == 0 {
+                break;
+            }
+        }
+    }
+    best
+}
+
+fn in_group(group: &str, region: &str) -> bool {
+    region.len() == 2 && group.as_bytes().chunks(2).any(|x| x == region.as_bytes())
+}
+
+fn is_paradigm(lang: &str, region: &str) -> bool {
+    PARADIGM_LOCALES.iter().any(|x| {
+        let (l, r) = x.split_at(x.len() - 2);
+        l.strip_suffix('-') == Some(lang) && r == region
+    })
+}
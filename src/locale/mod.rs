@@ -1,13 +1,46 @@
 //! Locale parsing support.
 
+mod accept;
+mod extension;
+#[allow(clippy::large_const_arrays)]
 mod lang_data;
+mod matching;
+mod numbering;
+mod region;
+#[cfg(feature = "registry")]
+#[allow(clippy::large_const_arrays)]
+mod registry_data;
 mod subtag;
+#[cfg(feature = "registry")]
+mod validate;
 
+pub use accept::{
+    negotiate_accept_language, parse_accept_language, AcceptLanguage, MAX_ACCEPT_LANGUAGE_ENTRIES,
+};
+pub use extension::{
+    unicode_keywords, Transform, TransformFields, TransformKey, UnicodeKey, UnicodeKeywords,
+};
+pub use matching::{match_distance, negotiate, MATCH_THRESHOLD};
+pub use numbering::{NumberingSystem, SubstituteDigits};
+pub use region::{Parents, Region};
 pub use subtag::*;
+#[cfg(feature = "registry")]
+pub use validate::{is_valid, validate, ValidationError, ValidationErrorKind};
 
+use super::unicode::Script;
 use core::fmt;
+use core::str::{FromStr, Split};
 use lang_data::*;
 
+/// Maximum number of bytes available for storing the variant, extension
+/// and private-use subtags of a locale.
+pub const MAX_LOCALE_EXTRA_LEN: usize = 64;
+
+/// Maximum length in bytes of a language tag that can be represented by a
+/// locale: the language, script and region subtags with separators,
+/// followed by a separator and the variant and extension subtags.
+pub const MAX_LOCALE_TAG_LEN: usize = 13 + MAX_LOCALE_EXTRA_LEN;
+
 /// Languages in the unified Han block.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 #[repr(u8)]
@@ -19,31 +52,48 @@ pub enum Han {
     Korean = 4,
 }
 
-/// Simplified representation of a locale describing primary language, script
-/// and region.
+/// Owned representation of a locale describing primary language, script,
+/// region, variants and extensions.
+///
+/// Variant, extension and private-use subtags are stored in a fixed size
+/// buffer of [`MAX_LOCALE_EXTRA_LEN`] bytes. Tags with subtags that do not
+/// fit are rejected by [`parse`](Self::parse).
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Locale {
     language: [u8; 3],
     script: [u8; 4],
-    region: [u8; 2],
+    region: [u8; 3],
+    extra: [u8; MAX_LOCALE_EXTRA_LEN],
     lang_len: u8,
     script_len: u8,
     region_len: u8,
+    variants_len: u8,
+    extra_len: u8,
     han: Han,
     name_index: u16,
     tag: Option<u32>,
 }
 
 impl Locale {
-    /// Parses a language tag.
+    /// Parses a language tag. Grandfathered tags such as `i-klingon` are
+    /// replaced by their preferred values.
+    ///
+    /// Returns `None` if the tag is malformed or if its variant, extension
+    /// and private-use subtags exceed [`MAX_LOCALE_EXTRA_LEN`] bytes.
     pub fn parse(tag: &str) -> Option<Self> {
+        if let Some(preferred) = legacy_preferred_value(tag) {
+            return Self::parse(preferred);
+        }
         let mut lang = Self {
             language: [0; 3],
-            region: [0; 2],
+            region: [0; 3],
             script: [0; 4],
+            extra: [0; MAX_LOCALE_EXTRA_LEN],
             lang_len: 0,
             region_len: 0,
             script_len: 0,
+            variants_len: 0,
+            extra_len: 0,
             han: Han::None,
             name_index: 0xFFFF,
             tag: None,
@@ -71,7 +121,7 @@ impl Locale {
                             lang.lang_len = 2;
                             let key = tag2(&[a, b]);
                             if let Ok(index) = LANG_BY_TAG2.binary_search_by(|x| x.0.cmp(&key)) {
-                                lang_index = (*LANG_BY_TAG2.get(index)?).1
+                                lang_index = LANG_BY_TAG2.get(index)?.1
                             }
                         }
                         3 => {
@@ -85,19 +135,17 @@ impl Locale {
                             lang.lang_len = 3;
                             let key = tag3(&[a, b, c]);
                             if let Ok(index) = LANG_BY_TAG3.binary_search_by(|x| x.0.cmp(&key)) {
-                                lang_index = (*LANG_BY_TAG3.get(index)?).1 as u16
+                                lang_index = LANG_BY_TAG3.get(index)?.1 as u16
                             }
                         }
                         _ => return None,
                     };
                 }
                 1 => match len {
-                    2 => {
-                        let a = bytes[0].to_ascii_uppercase();
-                        let b = bytes[1].to_ascii_uppercase();
-                        lang.region[0] = a;
-                        lang.region[1] = b;
-                        lang.region_len = 2;
+                    2 | 3 => {
+                        if !lang.set_region(part) {
+                            break;
+                        }
                         has_region = true;
                     }
                     4 => {
@@ -114,26 +162,35 @@ impl Locale {
                     _ => break,
                 },
                 2 => {
-                    if has_region || len != 2 {
+                    if has_region || !lang.set_region(part) {
                         break;
                     }
-                    let a = bytes[0].to_ascii_uppercase();
-                    let b = bytes[1].to_ascii_uppercase();
-                    lang.region[0] = a;
-                    lang.region[1] = b;
-                    lang.region_len = 2;
                     has_region = true;
                 }
                 _ => break,
             }
         }
+        for subtag in subtags(tag) {
+            match subtag {
+                Subtag::Variant(variant) => {
+                    // Variants always precede extensions.
+                    lang.push_extra(variant.as_str())?;
+                    lang.variants_len = lang.extra_len;
+                }
+                Subtag::Extension(s) | Subtag::Private(s) => lang.push_extra(s)?,
+                _ => {}
+            }
+        }
         lang.name_index = lang_index;
         if lang_index != 0xFFFF {
             lang.tag = Some(*LANG_TAGS.get(lang_index as usize)?);
         } else if zh {
-            let (tag, han) = match lang.script().unwrap_or("") {
-                "Hant" => (tag4(b"ZHT "), Han::Traditional),
-                "Hans" => (tag4(b"ZHS "), Han::Simplified),
+            let (tag, han) = match (lang.script().unwrap_or(""), lang.region().unwrap_or("")) {
+                ("Hant", "HK") | ("Hant", "MO") => (tag4(b"ZHH "), Han::Traditional),
+                ("Hant", _) => (tag4(b"ZHT "), Han::Traditional),
+                ("Hans", _) => (tag4(b"ZHS "), Han::Simplified),
+                (_, "CN") | (_, "SG") => (tag4(b"ZHS "), Han::Simplified),
+                (_, "HK") | (_, "MO") => (tag4(b"ZHH "), Han::Traditional),
                 _ => (tag4(b"ZHT "), Han::Traditional),
             };
             lang.tag = Some(tag);
@@ -153,12 +210,14 @@ impl Locale {
             return Self::parse("zh-Hant");
         } else if tag == tag4(b"ZHS ") {
             return Self::parse("zh-Hans");
+        } else if tag == tag4(b"ZHH ") {
+            return Self::parse("zh-HK");
         }
         let name_index = match LANG_TAGS.binary_search_by(|x| x.cmp(&tag)) {
             Ok(index) => index,
             _ => return None,
         };
-        return Self::parse(LANG_ENTRIES.get(name_index)?.1);
+        Self::parse(LANG_ENTRIES.get(name_index)?.1)
     }
 
     /// Returns the primary language subtag.
@@ -182,22 +241,411 @@ impl Locale {
 
     /// Returns the region subtag.
     pub fn region(&self) -> Option<&str> {
-        Some(if self.region_len == 2 {
-            unsafe { core::str::from_utf8_unchecked(&self.region) }
+        Some(if self.region_len != 0 {
+            unsafe { core::str::from_utf8_unchecked(&self.region[..self.region_len as usize]) }
         } else {
             return None;
         })
     }
 
+    /// Returns a copy of the locale with the region subtag replaced. This
+    /// can be combined with [`Region::parents`] for regional fallback.
+    pub fn with_region(&self, region: Option<Region>) -> Self {
+        let region = region.as_ref().map(|r| r.as_str());
+        self.with_subtags(self.language(), self.script(), region)
+            .unwrap_or(*self)
+    }
+
+    /// Returns an iterator over the variant subtags.
+    pub fn variants(&self) -> Variants<'_> {
+        Variants(self.extra_str(0, self.variants_len).split('-'))
+    }
+
+    /// Returns an iterator over the extensions and private-use subtags as
+    /// pairs of singleton and value. For example, `en-u-co-phonebk-x-foo`
+    /// yields `('u', "co-phonebk")` followed by `('x', "foo")`.
+    pub fn extensions(&self) -> Extensions<'_> {
+        Extensions(self.extra_str(self.variants_len, self.extra_len))
+    }
+
+    /// Returns the value of the extension with the specified singleton.
+    pub fn extension(&self, singleton: char) -> Option<&str> {
+        let singleton = singleton.to_ascii_lowercase();
+        self.extensions()
+            .find(|(s, _)| *s == singleton)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the keywords of the Unicode locale extension.
+    /// For example, `th-u-nu-thai-lb-strict` yields `(Numbers, "thai")`
+    /// followed by `(LineBreak, "strict")`.
+    pub fn unicode_keywords(&self) -> UnicodeKeywords<'_> {
+        unicode_keywords(self.extension('u').unwrap_or(""))
+    }
+
+    /// Returns the value of the specified keyword in the Unicode locale
+    /// extension.
+    pub fn unicode_keyword(&self, key: UnicodeKey) -> Option<&str> {
+        self.unicode_keywords()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the numbering system used for digits in the locale. See
+    /// [`NumberingSystem::from_locale`] for details.
+    pub fn numbering_system(&self) -> NumberingSystem {
+        NumberingSystem::from_locale(self)
+    }
+
+    /// Returns the transformed content extension, if present.
+    pub fn transform(&self) -> Option<Transform<'_>> {
+        self.extension('t').map(Transform::parse)
+    }
+
     /// Returns the Han language.
     pub fn han(&self) -> Han {
         self.han
     }
 
     /// Returns the associated OpenType language tag.
+    ///
+    /// For Chinese, the script subtag takes precedence, followed by the
+    /// region: `zh-CN` and `zh-SG` map to `ZHS`, `zh-HK` and `zh-MO` map to
+    /// `ZHH` and all others map to `ZHT`.
     pub fn to_opentype(self) -> Option<u32> {
         self.tag
     }
+
+    /// Returns the locale with the likely script and region subtags added
+    /// according to the CLDR likely subtags data. For example, `zh` is
+    /// maximized to `zh-Hans-CN` and `sr-ME` to `sr-Latn-ME`. Variant and
+    /// extension subtags are preserved.
+    ///
+    /// Returns a copy of the locale if no likely subtags are available.
+    pub fn maximize(&self) -> Self {
+        let lang = self.language();
+        let script = self.script();
+        let region = self.region();
+        let likely = region
+            .and_then(|r| script.and_then(|s| likely_subtags(lang, Some(s), Some(r))))
+            .or_else(|| region.and_then(|r| likely_subtags(lang, None, Some(r))))
+            .or_else(|| script.and_then(|s| likely_subtags(lang, Some(s), None)))
+            .or_else(|| likely_subtags(lang, None, None))
+            .or_else(|| script.and_then(|s| likely_subtags("und", Some(s), None)));
+        let likely = match likely {
+            Some(likely) => likely,
+            _ => {
+                // Fall back to the suppressed script so that maximization is
+                // consistent with canonicalization.
+                return match (script, self.suppress_script()) {
+                    (None, Some(suppressed)) => self
+                        .with_subtags(lang, Some(suppressed), region)
+                        .unwrap_or(*self),
+                    _ => *self,
+                };
+            }
+        };
+        let mut parts = likely.split('-');
+        let (likely_lang, likely_script, likely_region) = (
+            parts.next().unwrap_or(""),
+            parts.next().unwrap_or(""),
+            parts.next().unwrap_or(""),
+        );
+        let lang = if lang == "und" { likely_lang } else { lang };
+        self.with_subtags(
+            lang,
+            Some(script.unwrap_or(likely_script)),
+            Some(region.unwrap_or(likely_region)),
+        )
+        .unwrap_or(*self)
+    }
+
+    /// Returns the locale with the script and region subtags removed where
+    /// they can be recovered by [`maximize`](Self::maximize). For example,
+    /// `zh-Hans-CN` is minimized to `zh` and `zh-Hant-TW` to `zh-TW`.
+    /// Variant and extension subtags are preserved.
+    pub fn minimize(&self) -> Self {
+        let max = self.maximize();
+        let lang = max.language();
+        let trials = [(None, None), (None, max.region()), (max.script(), None)];
+        for (script, region) in trials.iter() {
+            if let Some(trial) = self.with_subtags(lang, *script, *region) {
+                if trial.maximize().same_subtags(&max) {
+                    return trial;
+                }
+            }
+        }
+        max
+    }
+
+    /// Returns the script that the language of the locale is overwhelmingly
+    /// written in, as specified by the Suppress-Script field of the IANA
+    /// registry. For example, this is `Latn` for `en`. Script subtags
+    /// matching this value are redundant.
+    pub fn suppress_script(&self) -> Option<&'static str> {
+        let lang = self.language();
+        let index = SUPPRESS_SCRIPTS.binary_search_by(|x| x.0.cmp(lang)).ok()?;
+        Some(SUPPRESS_SCRIPTS.get(index)?.1)
+    }
+
+    /// Returns the locale in canonical form with three letter ISO 639-2
+    /// language codes and numeric country codes replaced by their two letter
+    /// equivalents and a redundant script subtag removed. For example,
+    /// `ger-276` is canonicalized to `de-DE` and `en-Latn-US` to `en-US`
+    /// while `sr-Latn` is unchanged.
+    pub fn canonicalize(&self) -> Self {
+        let mut locale = *self;
+        let language = Language::new(self.language()).map(|x| x.canonicalize());
+        let region = self
+            .region()
+            .and_then(Region::new)
+            .map(|x| x.canonicalize());
+        let language = language.as_ref().map_or(self.language(), |x| x.as_str());
+        let region = region.as_ref().map(|x| x.as_str());
+        if language != self.language() || region != self.region() {
+            locale = self
+                .with_subtags(language, self.script(), region)
+                .unwrap_or(*self);
+        }
+        match (locale.script(), locale.suppress_script()) {
+            (Some(script), Some(suppressed)) if script == suppressed => locale
+                .with_subtags(locale.language(), None, locale.region())
+                .unwrap_or(locale),
+            _ => locale,
+        }
+    }
+
+    /// Returns the principal Unicode script used to write the locale. This
+    /// is derived from the script subtag if present and from the likely
+    /// script for the language and region otherwise, so `sr` resolves to
+    /// Cyrillic while `sr-ME` resolves to Latin.
+    ///
+    /// Compound script codes resolve to their principal component: `Jpan`,
+    /// `Hans` and `Hant` to Han and `Kore` to Hangul. Use
+    /// [`scripts`](Self::scripts) for the full set.
+    pub fn default_script(&self) -> Option<Script> {
+        self.scripts().next()
+    }
+
+    /// Returns an iterator over the Unicode scripts used to write the
+    /// locale, beginning with the [`default_script`](Self::default_script).
+    /// For example, `ja` yields Han, Hiragana and Katakana.
+    pub fn scripts(&self) -> Scripts {
+        let max;
+        let code = match self.script() {
+            Some(script) => script,
+            None => {
+                max = self.maximize();
+                match max.script() {
+                    Some(script) => script,
+                    None => return Scripts::default(),
+                }
+            }
+        };
+        use Script::*;
+        let (scripts, len) = match code {
+            "Jpan" => ([Han, Hiragana, Katakana], 3),
+            "Kore" => ([Hangul, Han, Unknown], 2),
+            "Hanb" => ([Han, Bopomofo, Unknown], 2),
+            "Hrkt" => ([Hiragana, Katakana, Unknown], 2),
+            "Hans" | "Hant" => ([Han, Unknown, Unknown], 1),
+            "Aran" => ([Arabic, Unknown, Unknown], 1),
+            "Cyrs" => ([Cyrillic, Unknown, Unknown], 1),
+            "Geok" => ([Georgian, Unknown, Unknown], 1),
+            "Latf" | "Latg" => ([Latin, Unknown, Unknown], 1),
+            "Syre" | "Syrj" | "Syrn" => ([Syriac, Unknown, Unknown], 1),
+            "Zmth" | "Zsye" | "Zsym" | "Zxxx" | "Zyyy" => ([Common, Unknown, Unknown], 1),
+            _ => {
+                let bytes = code.as_bytes();
+                match Script::from_iso15924_code([bytes[0], bytes[1], bytes[2], bytes[3]]) {
+                    Some(script) => ([script, Unknown, Unknown], 1),
+                    None => return Scripts::default(),
+                }
+            }
+        };
+        Scripts {
+            scripts,
+            len,
+            pos: 0,
+        }
+    }
+
+    /// Returns true if the language, script and region subtags of both
+    /// locales are equal.
+    fn same_subtags(&self, other: &Self) -> bool {
+        self.language() == other.language()
+            && self.script() == other.script()
+            && self.region() == other.region()
+    }
+
+    /// Builds a new locale from the specified subtags along with the
+    /// variant and extension subtags of this locale.
+    fn with_subtags(&self, lang: &str, script: Option<&str>, region: Option<&str>) -> Option<Self> {
+        let mut buf = TagBuf::new();
+        buf.push(lang);
+        for subtag in script.iter().chain(region.iter()) {
+            buf.push(subtag);
+        }
+        buf.push(self.extra_str(0, self.extra_len));
+        Self::parse(buf.as_str())
+    }
+
+    /// Sets the region from a two letter or three digit subtag.
+    fn set_region(&mut self, subtag: &str) -> bool {
+        match Region::new(subtag) {
+            Some(region) => {
+                let bytes = region.as_str().as_bytes();
+                self.region[..bytes.len()].copy_from_slice(bytes);
+                self.region_len = bytes.len() as u8;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn extra_str(&self, start: u8, end: u8) -> &str {
+        // SAFETY: The extra buffer only contains ASCII subtags that were
+        // validated by the subtag parser.
+        unsafe { core::str::from_utf8_unchecked(&self.extra[start as usize..end as usize]) }
+    }
+
+    /// Appends a subtag to the extra buffer. Returns `None` if it does not
+    /// fit.
+    fn push_extra(&mut self, subtag: &str) -> Option<()> {
+        let len = self.extra_len as usize;
+        let sep = (len != 0) as usize;
+        let bytes = subtag.as_bytes();
+        if !subtag.is_ascii() || len + sep + bytes.len() > MAX_LOCALE_EXTRA_LEN {
+            return None;
+        }
+        if sep != 0 {
+            self.extra[len] = b'-';
+        }
+        for (i, b) in bytes.iter().enumerate() {
+            self.extra[len + sep + i] = b.to_ascii_lowercase();
+        }
+        self.extra_len = (len + sep + bytes.len()) as u8;
+        Some(())
+    }
+}
+
+/// Iterator over the Unicode scripts used to write a locale. This iterator
+/// is created by the [`Locale::scripts`] method.
+#[derive(Copy, Clone)]
+pub struct Scripts {
+    scripts: [Script; 3],
+    len: u8,
+    pos: u8,
+}
+
+impl Default for Scripts {
+    fn default() -> Self {
+        Self {
+            scripts: [Script::Unknown; 3],
+            len: 0,
+            pos: 0,
+        }
+    }
+}
+
+impl Iterator for Scripts {
+    type Item = Script;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.len {
+            return None;
+        }
+        self.pos += 1;
+        Some(self.scripts[self.pos as usize - 1])
+    }
+}
+
+/// Returns the likely subtags for the specified language, script and
+/// region.
+fn likely_subtags(lang: &str, script: Option<&str>, region: Option<&str>) -> Option<&'static str> {
+    let mut key = TagBuf::new();
+    key.push(lang);
+    for subtag in script.iter().chain(region.iter()) {
+        key.push(subtag);
+    }
+    let key = key.as_str();
+    let index = LIKELY_SUBTAGS.binary_search_by(|x| x.0.cmp(key)).ok()?;
+    Some(LIKELY_SUBTAGS.get(index)?.1)
+}
+
+/// Fixed size buffer for assembling a language tag.
+struct TagBuf {
+    buf: [u8; MAX_LOCALE_TAG_LEN],
+    len: usize,
+}
+
+impl TagBuf {
+    fn new() -> Self {
+        Self {
+            buf: [0; MAX_LOCALE_TAG_LEN],
+            len: 0,
+        }
+    }
+
+    /// Appends a hyphen separated subtag, ignoring empty subtags.
+    fn push(&mut self, subtag: &str) {
+        let bytes = subtag.as_bytes();
+        let sep = (self.len != 0) as usize;
+        if bytes.is_empty() || self.len + sep + bytes.len() > self.buf.len() {
+            return;
+        }
+        if sep != 0 {
+            self.buf[self.len] = b'-';
+        }
+        let start = self.len + sep;
+        self.buf[start..start + bytes.len()].copy_from_slice(bytes);
+        self.len = start + bytes.len();
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+}
+
+/// Iterator over the variant subtags of a locale. This iterator is created
+/// by the [`Locale::variants`] method.
+#[derive(Clone)]
+pub struct Variants<'a>(Split<'a, char>);
+
+impl<'a> Iterator for Variants<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find(|s| !s.is_empty())
+    }
+}
+
+/// Iterator over the extensions of a locale. This iterator is created by
+/// the [`Locale::extensions`] method.
+#[derive(Clone)]
+pub struct Extensions<'a>(&'a str);
+
+impl<'a> Iterator for Extensions<'a> {
+    type Item = (char, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.0.strip_prefix('-').unwrap_or(self.0);
+        let singleton = *s.as_bytes().first()? as char;
+        let body = s.get(2..).unwrap_or("");
+        let mut end = body.len();
+        if singleton != 'x' {
+            let mut pos = 0;
+            for part in body.split('-') {
+                if part.len() == 1 {
+                    end = pos.max(1) - 1;
+                    break;
+                }
+                pos += part.len() + 1;
+            }
+        }
+        self.0 = &body[end..];
+        Some((singleton, &body[..end]))
+    }
 }
 
 impl fmt::Display for Locale {
@@ -209,13 +657,21 @@ impl fmt::Display for Locale {
         if let Some(region) = self.region() {
             write!(f, "-{}", region)?;
         }
-        if let Some(name) = self.language_name() {
-            write!(f, " ({})", name)?;
+        if self.extra_len != 0 {
+            write!(f, "-{}", self.extra_str(0, self.extra_len))?;
         }
         Ok(())
     }
 }
 
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or(ParseLocaleError)
+    }
+}
+
 impl fmt::Debug for Locale {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.language())?;
@@ -225,6 +681,9 @@ impl fmt::Debug for Locale {
         if let Some(region) = self.region() {
             write!(f, "-{}", region)?;
         }
+        if self.extra_len != 0 {
+            write!(f, "-{}", self.extra_str(0, self.extra_len))?;
+        }
         if let Some(tag) = self.tag {
             let tag = tag.to_be_bytes();
             if let Ok(s) = core::str::from_utf8(&tag) {
@@ -237,3 +696,149 @@ impl fmt::Debug for Locale {
         Ok(())
     }
 }
+
+/// Error returned when parsing a malformed locale or subtag.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ParseLocaleError;
+
+impl fmt::Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "malformed locale")
+    }
+}
+
+/// Generates a locale with an arbitrary language, an optional script and
+/// region, and optional `lb` and `lw` keywords that tailor line breaking.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Locale {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut tag = [0u8; 40];
+        let mut len = 0;
+        let mut push = |bytes: &[u8]| {
+            tag[len..len + bytes.len()].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+        let lang_len = u.int_in_range(2..=3)?;
+        for _ in 0..lang_len {
+            push(&[u.int_in_range(b'a'..=b'z')?]);
+        }
+        if let Some(script) = Option::<Script>::arbitrary(u)? {
+            push(b"-");
+            push(script.short_name().as_bytes());
+        }
+        if u.arbitrary()? {
+            push(b"-");
+            if u.arbitrary()? {
+                for _ in 0..2 {
+                    push(&[u.int_in_range(b'A'..=b'Z')?]);
+                }
+            } else {
+                for _ in 0..3 {
+                    push(&[u.int_in_range(b'0'..=b'9')?]);
+                }
+            }
+        }
+        let lb = if u.arbitrary()? {
+            Some(*u.choose(&["strict", "normal", "loose"])?)
+        } else {
+            None
+        };
+        let lw = if u.arbitrary()? {
+            Some(*u.choose(&["normal", "breakall", "keepall", "phrase"])?)
+        } else {
+            None
+        };
+        if lb.is_some() || lw.is_some() {
+            push(b"-u");
+        }
+        if let Some(lb) = lb {
+            push(b"-lb-");
+            push(lb.as_bytes());
+        }
+        if let Some(lw) = lw {
+            push(b"-lw-");
+            push(lw.as_bytes());
+        }
+        core::str::from_utf8(&tag[..len])
+            .ok()
+            .and_then(Self::parse)
+            .ok_or(arbitrary::Error::IncorrectFormat)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Locale {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Locale {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::<Self>::new("a language tag"))
+    }
+}
+
+/// Visitor that deserializes a value from a string using its [`FromStr`]
+/// implementation.
+#[cfg(feature = "serde")]
+pub(crate) struct FromStrVisitor<T> {
+    expecting: &'static str,
+    _marker: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> FromStrVisitor<T> {
+    pub(crate) fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            _marker: core::marker::PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: FromStr> serde::de::Visitor<'de> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse()
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_extensions() {
+        let locale = Locale::parse("ja-JP-u-ca-japanese-lb-strict-lw-keepall").unwrap();
+        assert_eq!(
+            locale.unicode_keyword(UnicodeKey::Calendar),
+            Some("japanese")
+        );
+        assert_eq!(
+            locale.unicode_keyword(UnicodeKey::LineBreak),
+            Some("strict")
+        );
+        assert_eq!(
+            locale.unicode_keyword(UnicodeKey::LineBreakWord),
+            Some("keepall")
+        );
+    }
+
+    #[test]
+    fn extra_overflow() {
+        let tag = "en-x-aaaaaaaa-bbbbbbbb-cccccccc-dddddddd-eeeeeeee-ffffffff-gggggggg-hhhhhhhh";
+        assert!(Locale::parse(tag).is_none());
+        assert!(tag.parse::<Locale>().is_err());
+        let locale = Locale::parse(&tag[..58]).unwrap();
+        assert_eq!(locale.extension('x'), Some(&tag[5..58]));
+    }
+}
@@ -0,0 +1,331 @@
+//! Numbering systems and digit substitution.
+
+use super::lang_data::NUMBERING_SYSTEMS;
+use super::{Locale, ParseLocaleError, TagBuf, UnicodeKey};
+use core::fmt;
+use core::str::FromStr;
+
+/// Decimal numbering system identified by a CLDR numbering system name.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub enum NumberingSystem {
+    /// ASCII digits (`latn`).
+    #[default]
+    Latin,
+    /// Adlam digits (`adlm`).
+    Adlam,
+    /// Arabic-Indic digits (`arab`).
+    Arabic,
+    /// Extended Arabic-Indic digits used for Persian and Urdu (`arabext`).
+    ArabicExtended,
+    /// Balinese digits (`bali`).
+    Balinese,
+    /// Bengali digits (`beng`).
+    Bengali,
+    /// Chakma digits (`cakm`).
+    Chakma,
+    /// Devanagari digits (`deva`).
+    Devanagari,
+    /// Full width digits (`fullwide`).
+    FullWidth,
+    /// Gujarati digits (`gujr`).
+    Gujarati,
+    /// Gurmukhi digits (`guru`).
+    Gurmukhi,
+    /// Positional decimal system using Han numerals (`hanidec`).
+    HanDecimal,
+    /// Javanese digits (`java`).
+    Javanese,
+    /// Kannada digits (`knda`).
+    Kannada,
+    /// Kayah Li digits (`kali`).
+    KayahLi,
+    /// Khmer digits (`khmr`).
+    Khmer,
+    /// Lao digits (`laoo`).
+    Lao,
+    /// Lepcha digits (`lepc`).
+    Lepcha,
+    /// Limbu digits (`limb`).
+    Limbu,
+    /// Malayalam digits (`mlym`).
+    Malayalam,
+    /// Meetei Mayek digits (`mtei`).
+    MeeteiMayek,
+    /// Mongolian digits (`mong`).
+    Mongolian,
+    /// Myanmar digits (`mymr`).
+    Myanmar,
+    /// Myanmar Shan digits (`mymrshan`).
+    MyanmarShan,
+    /// New Tai Lue digits (`talu`).
+    NewTaiLue,
+    /// N'Ko digits (`nkoo`).
+    Nko,
+    /// Ol Chiki digits (`olck`).
+    OlChiki,
+    /// Oriya digits (`orya`).
+    Oriya,
+    /// Osmanya digits (`osma`).
+    Osmanya,
+    /// Saurashtra digits (`saur`).
+    Saurashtra,
+    /// Sundanese digits (`sund`).
+    Sundanese,
+    /// Modern Tamil decimal digits (`tamldec`).
+    Tamil,
+    /// Telugu digits (`telu`).
+    Telugu,
+    /// Thai digits (`thai`).
+    Thai,
+    /// Tibetan digits (`tibt`).
+    Tibetan,
+    /// Vai digits (`vaii`).
+    Vai,
+}
+
+/// Han numerals for the decimal digits in the `hanidec` system.
+const HAN_DIGITS: [char; 10] = ['〇', '一', '二', '三', '四', '五', '六', '七', '八', '九'];
+
+impl NumberingSystem {
+    /// Returns the numbering system for the specified CLDR name such as
+    /// `arab` or `deva`. The comparison is case insensitive. Algorithmic
+    /// systems and the `native`, `traditio` and `finance` aliases are not
+    /// supported and yield `None`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        use NumberingSystem::*;
+        let mut buf = [0u8; 8];
+        let bytes = name.as_bytes();
+        if bytes.len() > buf.len() {
+            return None;
+        }
+        for (d, s) in buf.iter_mut().zip(bytes) {
+            *d = s.to_ascii_lowercase();
+        }
+        Some(match &buf[..bytes.len()] {
+            b"latn" => Latin,
+            b"adlm" => Adlam,
+            b"arab" => Arabic,
+            b"arabext" => ArabicExtended,
+            b"bali" => Balinese,
+            b"beng" => Bengali,
+            b"cakm" => Chakma,
+            b"deva" => Devanagari,
+            b"fullwide" => FullWidth,
+            b"gujr" => Gujarati,
+            b"guru" => Gurmukhi,
+            b"hanidec" => HanDecimal,
+            b"java" => Javanese,
+            b"knda" => Kannada,
+            b"kali" => KayahLi,
+            b"khmr" => Khmer,
+            b"laoo" => Lao,
+            b"lepc" => Lepcha,
+            b"limb" => Limbu,
+            b"mlym" => Malayalam,
+            b"mtei" => MeeteiMayek,
+            b"mong" => Mongolian,
+            b"mymr" => Myanmar,
+            b"mymrshan" => MyanmarShan,
+            b"talu" => NewTaiLue,
+            b"nkoo" => Nko,
+            b"olck" => OlChiki,
+            b"orya" => Oriya,
+            b"osma" => Osmanya,
+            b"saur" => Saurashtra,
+            b"sund" => Sundanese,
+            b"tamldec" => Tamil,
+            b"telu" => Telugu,
+            b"thai" => Thai,
+            b"tibt" => Tibetan,
+            b"vaii" => Vai,
+            _ => return None,
+        })
+    }
+
+    /// Returns the CLDR name of the numbering system.
+    pub fn name(&self) -> &'static str {
+        self.info().0
+    }
+
+    /// Returns the character for the specified decimal digit or `None` if
+    /// the value is greater than nine.
+    pub fn digit(&self, value: u32) -> Option<char> {
+        if value > 9 {
+            return None;
+        }
+        match self {
+            Self::HanDecimal => Some(HAN_DIGITS[value as usize]),
+            _ => char::from_u32(self.info().1 + value),
+        }
+    }
+
+    /// Returns the character with ASCII digits replaced by the digits of
+    /// this numbering system. All other characters are returned unchanged.
+    pub fn substitute(&self, ch: char) -> char {
+        ch.to_digit(10)
+            .filter(|_| ch.is_ascii_digit())
+            .and_then(|value| self.digit(value))
+            .unwrap_or(ch)
+    }
+
+    /// Returns an iterator that replaces the ASCII digits in a sequence of
+    /// characters with the digits of this numbering system.
+    ///
+    /// This is intended to be applied to text before cluster analysis so
+    /// that the substituted digits are shaped with the surrounding script.
+    pub fn substitute_digits<I>(self, chars: I) -> SubstituteDigits<I::IntoIter>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        SubstituteDigits {
+            system: self,
+            chars: chars.into_iter(),
+        }
+    }
+
+    /// Returns the default numbering system for the specified locale. An
+    /// explicit `-u-nu-` keyword naming a supported system takes precedence
+    /// and the `native` keyword selects the native digits of the locale.
+    /// Otherwise, the CLDR default is used, so `ar-EG` yields Arabic-Indic
+    /// digits, `fa` yields extended Arabic-Indic digits and `hi` yields
+    /// ASCII digits.
+    pub fn from_locale(locale: &Locale) -> Self {
+        let keyword = locale.unicode_keyword(UnicodeKey::Numbers);
+        if let Some(system) = keyword.and_then(Self::from_name) {
+            return system;
+        }
+        let native = matches!(keyword, Some(k) if k.eq_ignore_ascii_case("native"));
+        let (default, native_name) = match find_systems(&locale.maximize()) {
+            Some(entry) => entry,
+            _ => return Self::Latin,
+        };
+        // Native digits fall back to the default when they are not defined
+        // for the locale.
+        let name = if native && native_name != "latn" {
+            native_name
+        } else {
+            default
+        };
+        Self::from_name(name).unwrap_or_default()
+    }
+
+    fn info(&self) -> (&'static str, u32) {
+        use NumberingSystem::*;
+        match self {
+            Latin => ("latn", 0x30),
+            Adlam => ("adlm", 0x1E950),
+            Arabic => ("arab", 0x660),
+            ArabicExtended => ("arabext", 0x6F0),
+            Balinese => ("bali", 0x1B50),
+            Bengali => ("beng", 0x9E6),
+            Chakma => ("cakm", 0x11136),
+            Devanagari => ("deva", 0x966),
+            FullWidth => ("fullwide", 0xFF10),
+            Gujarati => ("gujr", 0xAE6),
+            Gurmukhi => ("guru", 0xA66),
+            HanDecimal => ("hanidec", 0x3007),
+            Javanese => ("java", 0xA9D0),
+            Kannada => ("knda", 0xCE6),
+            KayahLi => ("kali", 0xA900),
+            Khmer => ("khmr", 0x17E0),
+            Lao => ("laoo", 0xED0),
+            Lepcha => ("lepc", 0x1C40),
+            Limbu => ("limb", 0x1946),
+            Malayalam => ("mlym", 0xD66),
+            MeeteiMayek => ("mtei", 0xABF0),
+            Mongolian => ("mong", 0x1810),
+            Myanmar => ("mymr", 0x1040),
+            MyanmarShan => ("mymrshan", 0x1090),
+            NewTaiLue => ("talu", 0x19D0),
+            Nko => ("nkoo", 0x7C0),
+            OlChiki => ("olck", 0x1C50),
+            Oriya => ("orya", 0xB66),
+            Osmanya => ("osma", 0x104A0),
+            Saurashtra => ("saur", 0xA8D0),
+            Sundanese => ("sund", 0x1BB0),
+            Tamil => ("tamldec", 0xBE6),
+            Telugu => ("telu", 0xC66),
+            Thai => ("thai", 0xE50),
+            Tibetan => ("tibt", 0xF20),
+            Vai => ("vaii", 0xA620),
+        }
+    }
+}
+
+impl FromStr for NumberingSystem {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for NumberingSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Iterator that substitutes ASCII digits. This iterator is created by the
+/// [`NumberingSystem::substitute_digits`] method.
+#[derive(Clone)]
+pub struct SubstituteDigits<I> {
+    system: NumberingSystem,
+    chars: I,
+}
+
+impl<I: Iterator<Item = char>> Iterator for SubstituteDigits<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.system.substitute(self.chars.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+/// Returns the default and native numbering system names for a maximized
+/// locale, falling back from the most specific subtags.
+fn find_systems(locale: &Locale) -> Option<(&'static str, &'static str)> {
+    let lang = locale.language();
+    let (script, region) = (locale.script(), locale.region());
+    let candidates = [
+        (script, region),
+        (script, None),
+        (None, region),
+        (None, None),
+    ];
+    for (script, region) in candidates.iter() {
+        let mut key = TagBuf::new();
+        key.push(lang);
+        for subtag in script.iter().chain(region.iter()) {
+            key.push(subtag);
+        }
+        let key = key.as_str();
+        if let Ok(index) = NUMBERING_SYSTEMS.binary_search_by(|x| x.0.cmp(key)) {
+            let entry = NUMBERING_SYSTEMS[index];
+            return Some((entry.1, entry.2));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_locale() {
+        let system = |tag| NumberingSystem::from_locale(&Locale::parse(tag).unwrap());
+        assert_eq!(system("en"), NumberingSystem::Latin);
+        assert_eq!(system("hi"), NumberingSystem::Latin);
+        assert_eq!(system("hi-u-nu-native"), NumberingSystem::Devanagari);
+        assert_eq!(system("HI-U-NU-NATIVE"), NumberingSystem::Devanagari);
+        assert_eq!(system("en-u-nu-native"), NumberingSystem::Latin);
+        assert_eq!(system("ar-EG"), NumberingSystem::Arabic);
+        assert_eq!(system("fa"), NumberingSystem::ArabicExtended);
+        assert_eq!(system("ar-EG-u-nu-latn"), NumberingSystem::Latin);
+    }
+}
@@ -0,0 +1,240 @@
+//! Region subtags and territory containment.
+
+use super::lang_data::{tag2, tag3, ISO3166_CODES, REGION_GROUPINGS, REGION_PARENTS};
+use super::ParseLocaleError;
+use core::fmt;
+use core::str::FromStr;
+
+/// Region subtag identifying a country, territory or macro-region such as
+/// `DE` or `150` (Europe).
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Region {
+    code: [u8; 3],
+    len: u8,
+}
+
+impl Region {
+    /// Creates a region from a two letter ISO 3166-1 code or a three digit
+    /// UN M.49 code. Letters are converted to uppercase. This can be used in
+    /// constant expressions.
+    pub const fn new(code: &str) -> Option<Self> {
+        let bytes = code.as_bytes();
+        match bytes.len() {
+            2 if bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_alphabetic() => Some(Self {
+                code: [
+                    bytes[0].to_ascii_uppercase(),
+                    bytes[1].to_ascii_uppercase(),
+                    b' ',
+                ],
+                len: 2,
+            }),
+            3 if bytes[0].is_ascii_digit()
+                && bytes[1].is_ascii_digit()
+                && bytes[2].is_ascii_digit() =>
+            {
+                Some(Self {
+                    code: [bytes[0], bytes[1], bytes[2]],
+                    len: 3,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Creates a region from an ISO 3166-1 alpha-3 code such as `DEU`. The
+    /// comparison is case insensitive.
+    pub fn from_alpha3(code: &str) -> Option<Self> {
+        let entry = ISO3166_CODES
+            .iter()
+            .find(|x| x.1.eq_ignore_ascii_case(code))?;
+        Some(Self::from_tag2(entry.0))
+    }
+
+    /// Creates a region from an ISO 3166-1 numeric or UN M.49 code. Codes
+    /// assigned to countries and territories yield the corresponding two
+    /// letter region, so `276` yields `DE`, while macro-regions such as
+    /// `419` (Latin America) are retained. Returns `None` for unknown codes.
+    pub fn from_numeric(code: u16) -> Option<Self> {
+        if let Some(entry) = ISO3166_CODES.iter().find(|x| x.2 == code) {
+            return Some(Self::from_tag2(entry.0));
+        }
+        if code > 999 {
+            return None;
+        }
+        let region = Self {
+            code: [
+                b'0' + (code / 100) as u8,
+                b'0' + (code / 10 % 10) as u8,
+                b'0' + (code % 10) as u8,
+            ],
+            len: 3,
+        };
+        // Groupings such as 419 are outside of the containment hierarchy.
+        let key = tag3(&region.code);
+        if code == 1 || region.parent().is_some() || REGION_GROUPINGS.iter().any(|x| x.0 == key) {
+            Some(region)
+        } else {
+            None
+        }
+    }
+
+    /// Creates a region from an ISO 3166-1 alpha-2, alpha-3 or numeric code
+    /// or a UN M.49 code, normalized to the form used in language tags. For
+    /// example, `DE`, `deu` and `276` all yield `DE`.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let bytes = code.as_bytes();
+        match bytes.len() {
+            2 => Self::new(code),
+            3 if bytes.iter().all(|b| b.is_ascii_digit()) => Self::from_numeric(code.parse().ok()?),
+            3 => Self::from_alpha3(code),
+            _ => None,
+        }
+    }
+
+    /// Returns the region code.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The code is validated as ASCII on construction.
+        unsafe { core::str::from_utf8_unchecked(&self.code[..self.len as usize]) }
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code for the region, such as `DEU`
+    /// for `DE`. Returns `None` for macro-regions and for codes that are not
+    /// assigned in ISO 3166-1.
+    pub fn to_alpha3(&self) -> Option<&'static str> {
+        self.iso_entry().map(|x| x.1)
+    }
+
+    /// Returns the numeric code for the region. This is the ISO 3166-1
+    /// numeric code for countries and territories, such as `276` for `DE`,
+    /// and the UN M.49 code for macro-regions.
+    pub fn to_numeric(&self) -> Option<u16> {
+        if self.len == 3 {
+            let [a, b, c] = self.code;
+            return Some((a - b'0') as u16 * 100 + (b - b'0') as u16 * 10 + (c - b'0') as u16);
+        }
+        self.iso_entry().map(|x| x.2)
+    }
+
+    /// Returns the region in canonical form where numeric codes for
+    /// countries and territories are replaced by their two letter
+    /// equivalents. For example, `276` is canonicalized to `DE`.
+    pub fn canonicalize(&self) -> Self {
+        match self.to_numeric() {
+            Some(code) if self.len == 3 => ISO3166_CODES
+                .iter()
+                .find(|x| x.2 == code)
+                .map(|x| Self::from_tag2(x.0))
+                .unwrap_or(*self),
+            _ => *self,
+        }
+    }
+
+    /// Returns true if the region is a macro-region identified by a UN M.49
+    /// code.
+    pub fn is_macro_region(&self) -> bool {
+        self.len == 3
+    }
+
+    /// Returns the region that directly contains this one in the CLDR
+    /// territory containment hierarchy. For example, the parent of `DE` is
+    /// `155` (Western Europe). Returns `None` for `001` (World) and for
+    /// unknown regions.
+    pub fn parent(&self) -> Option<Self> {
+        let key = tag3(&self.code);
+        let index = REGION_PARENTS.binary_search_by(|x| x.0.cmp(&key)).ok()?;
+        Some(Self::from_tag(REGION_PARENTS.get(index)?.1))
+    }
+
+    /// Returns an iterator over the regions that contain this one, from the
+    /// nearest to `001` (World). For example, `DE` yields `155`, `150` and
+    /// `001`.
+    ///
+    /// This is useful for regional fallback where a resource for `en-DE` may
+    /// be substituted with one for `en-150`.
+    pub fn parents(&self) -> Parents {
+        Parents { region: *self }
+    }
+
+    /// Returns true if this region contains the other region, either
+    /// directly or through intermediate regions. Groupings such as `EU` and
+    /// `419` (Latin America) that are outside of the primary hierarchy are
+    /// also considered. A region does not contain itself.
+    pub fn contains(&self, other: Region) -> bool {
+        let key = tag3(&self.code);
+        let start = REGION_GROUPINGS.partition_point(|x| x.0 < key);
+        let members = &REGION_GROUPINGS[start..];
+        let members = &members[..members.partition_point(|x| x.0 == key)];
+        let mut region = Some(other);
+        let mut first = true;
+        while let Some(r) = region {
+            if !first && r == *self {
+                return true;
+            }
+            let tag = tag3(&r.code);
+            if members.iter().any(|x| x.1 == tag) {
+                return true;
+            }
+            first = false;
+            region = r.parent();
+        }
+        false
+    }
+
+    fn iso_entry(&self) -> Option<&'static (u16, &'static str, u16)> {
+        if self.len != 2 {
+            return None;
+        }
+        let key = tag2(&[self.code[0], self.code[1]]);
+        let index = ISO3166_CODES.binary_search_by(|x| x.0.cmp(&key)).ok()?;
+        ISO3166_CODES.get(index)
+    }
+
+    fn from_tag2(tag: u16) -> Self {
+        Self {
+            code: [(tag >> 8) as u8, tag as u8, b' '],
+            len: 2,
+        }
+    }
+
+    fn from_tag(tag: u32) -> Self {
+        let code = [(tag >> 16) as u8, (tag >> 8) as u8, tag as u8];
+        let len = if code[2] == b' ' { 2 } else { 3 };
+        Self { code, len }
+    }
+}
+
+impl FromStr for Region {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Region({})", self.as_str())
+    }
+}
+
+/// Iterator over the regions that contain a region. This iterator is
+/// created by the [`Region::parents`] method.
+#[derive(Copy, Clone)]
+pub struct Parents {
+    region: Region,
+}
+
+impl Iterator for Parents {
+    type Item = Region;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.region = self.region.parent()?;
+        Some(self.region)
+    }
+}
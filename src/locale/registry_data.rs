@@ -0,0 +1,1128 @@
+//! Subtags from the IANA language subtag registry, excluding the private
+//! use ranges.
+
+use super::lang_data::{tag3 as t3, tag4 as t4};
+
+#[rustfmt::skip]
+pub const REGISTRY_LANGUAGES: [u32; 8212] = [
+    t3(b"aa "), t3(b"aaa"), t3(b"aab"), t3(b"aac"), t3(b"aad"), t3(b"aae"), t3(b"aaf"), t3(b"aag"),
+    t3(b"aah"), t3(b"aai"), t3(b"aak"), t3(b"aal"), t3(b"aam"), t3(b"aan"), t3(b"aao"), t3(b"aap"),
+    t3(b"aaq"), t3(b"aas"), t3(b"aat"), t3(b"aau"), t3(b"aav"), t3(b"aaw"), t3(b"aax"), t3(b"aaz"),
+    t3(b"ab "), t3(b"aba"), t3(b"abb"), t3(b"abc"), t3(b"abd"), t3(b"abe"), t3(b"abf"), t3(b"abg"),
+    t3(b"abh"), t3(b"abi"), t3(b"abj"), t3(b"abl"), t3(b"abm"), t3(b"abn"), t3(b"abo"), t3(b"abp"),
+    t3(b"abq"), t3(b"abr"), t3(b"abs"), t3(b"abt"), t3(b"abu"), t3(b"abv"), t3(b"abw"), t3(b"abx"),
+    t3(b"aby"), t3(b"abz"), t3(b"aca"), t3(b"acb"), t3(b"acd"), t3(b"ace"), t3(b"acf"), t3(b"ach"),
+    t3(b"aci"), t3(b"ack"), t3(b"acl"), t3(b"acm"), t3(b"acn"), t3(b"acp"), t3(b"acq"), t3(b"acr"),
+    t3(b"acs"), t3(b"act"), t3(b"acu"), t3(b"acv"), t3(b"acw"), t3(b"acx"), t3(b"acy"), t3(b"acz"),
+    t3(b"ada"), t3(b"adb"), t3(b"add"), t3(b"ade"), t3(b"adf"), t3(b"adg"), t3(b"adh"), t3(b"adi"),
+    t3(b"adj"), t3(b"adl"), t3(b"adn"), t3(b"ado"), t3(b"adp"), t3(b"adq"), t3(b"adr"), t3(b"ads"),
+    t3(b"adt"), t3(b"adu"), t3(b"adw"), t3(b"adx"), t3(b"ady"), t3(b"adz"), t3(b"ae "), t3(b"aea"),
+    t3(b"aeb"), t3(b"aec"), t3(b"aed"), t3(b"aee"), t3(b"aek"), t3(b"ael"), t3(b"aem"), t3(b"aen"),
+    t3(b"aeq"), t3(b"aer"), t3(b"aes"), t3(b"aeu"), t3(b"aew"), t3(b"aey"), t3(b"aez"), t3(b"af "),
+    t3(b"afa"), t3(b"afb"), t3(b"afd"), t3(b"afe"), t3(b"afg"), t3(b"afh"), t3(b"afi"), t3(b"afk"),
+    t3(b"afn"), t3(b"afo"), t3(b"afp"), t3(b"afs"), t3(b"aft"), t3(b"afu"), t3(b"afz"), t3(b"aga"),
+    t3(b"agb"), t3(b"agc"), t3(b"agd"), t3(b"age"), t3(b"agf"), t3(b"agg"), t3(b"agh"), t3(b"agi"),
+    t3(b"agj"), t3(b"agk"), t3(b"agl"), t3(b"agm"), t3(b"agn"), t3(b"ago"), t3(b"agp"), t3(b"agq"),
+    t3(b"agr"), t3(b"ags"), t3(b"agt"), t3(b"agu"), t3(b"agv"), t3(b"agw"), t3(b"agx"), t3(b"agy"),
+    t3(b"agz"), t3(b"aha"), t3(b"ahb"), t3(b"ahg"), t3(b"ahh"), t3(b"ahi"), t3(b"ahk"), t3(b"ahl"),
+    t3(b"ahm"), t3(b"ahn"), t3(b"aho"), t3(b"ahp"), t3(b"ahr"), t3(b"ahs"), t3(b"aht"), t3(b"aia"),
+    t3(b"aib"), t3(b"aic"), t3(b"aid"), t3(b"aie"), t3(b"aif"), t3(b"aig"), t3(b"aih"), t3(b"aii"),
+    t3(b"aij"), t3(b"aik"), t3(b"ail"), t3(b"aim"), t3(b"ain"), t3(b"aio"), t3(b"aip"), t3(b"aiq"),
+    t3(b"air"), t3(b"ais"), t3(b"ait"), t3(b"aiw"), t3(b"aix"), t3(b"aiy"), t3(b"aja"), t3(b"ajg"),
+    t3(b"aji"), t3(b"ajn"), t3(b"ajp"), t3(b"ajt"), t3(b"aju"), t3(b"ajw"), t3(b"ajz"), t3(b"ak "),
+    t3(b"akb"), t3(b"akc"), t3(b"akd"), t3(b"ake"), t3(b"akf"), t3(b"akg"), t3(b"akh"), t3(b"aki"),
+    t3(b"akj"), t3(b"akk"), t3(b"akl"), t3(b"akm"), t3(b"ako"), t3(b"akp"), t3(b"akq"), t3(b"akr"),
+    t3(b"aks"), t3(b"akt"), t3(b"aku"), t3(b"akv"), t3(b"akw"), t3(b"akx"), t3(b"aky"), t3(b"akz"),
+    t3(b"ala"), t3(b"alc"), t3(b"ald"), t3(b"ale"), t3(b"alf"), t3(b"alg"), t3(b"alh"), t3(b"ali"),
+    t3(b"alj"), t3(b"alk"), t3(b"all"), t3(b"alm"), t3(b"aln"), t3(b"alo"), t3(b"alp"), t3(b"alq"),
+    t3(b"alr"), t3(b"als"), t3(b"alt"), t3(b"alu"), t3(b"alv"), t3(b"alw"), t3(b"alx"), t3(b"aly"),
+    t3(b"alz"), t3(b"am "), t3(b"ama"), t3(b"amb"), t3(b"amc"), t3(b"ame"), t3(b"amf"), t3(b"amg"),
+    t3(b"ami"), t3(b"amj"), t3(b"amk"), t3(b"aml"), t3(b"amm"), t3(b"amn"), t3(b"amo"), t3(b"amp"),
+    t3(b"amq"), t3(b"amr"), t3(b"ams"), t3(b"amt"), t3(b"amu"), t3(b"amv"), t3(b"amw"), t3(b"amx"),
+    t3(b"amy"), t3(b"amz"), t3(b"an "), t3(b"ana"), t3(b"anb"), t3(b"anc"), t3(b"and"), t3(b"ane"),
+    t3(b"anf"), t3(b"ang"), t3(b"anh"), t3(b"ani"), t3(b"anj"), t3(b"ank"), t3(b"anl"), t3(b"anm"),
+    t3(b"ann"), t3(b"ano"), t3(b"anp"), t3(b"anq"), t3(b"anr"), t3(b"ans"), t3(b"ant"), t3(b"anu"),
+    t3(b"anv"), t3(b"anw"), t3(b"anx"), t3(b"any"), t3(b"anz"), t3(b"aoa"), t3(b"aob"), t3(b"aoc"),
+    t3(b"aod"), t3(b"aoe"), t3(b"aof"), t3(b"aog"), t3(b"aoh"), t3(b"aoi"), t3(b"aoj"), t3(b"aok"),
+    t3(b"aol"), t3(b"aom"), t3(b"aon"), t3(b"aor"), t3(b"aos"), t3(b"aot"), t3(b"aou"), t3(b"aox"),
+    t3(b"aoz"), t3(b"apa"), t3(b"apb"), t3(b"apc"), t3(b"apd"), t3(b"ape"), t3(b"apf"), t3(b"apg"),
+    t3(b"aph"), t3(b"api"), t3(b"apj"), t3(b"apk"), t3(b"apl"), t3(b"apm"), t3(b"apn"), t3(b"apo"),
+    t3(b"app"), t3(b"apq"), t3(b"apr"), t3(b"aps"), t3(b"apt"), t3(b"apu"), t3(b"apv"), t3(b"apw"),
+    t3(b"apx"), t3(b"apy"), t3(b"apz"), t3(b"aqa"), t3(b"aqc"), t3(b"aqd"), t3(b"aqg"), t3(b"aqk"),
+    t3(b"aql"), t3(b"aqm"), t3(b"aqn"), t3(b"aqp"), t3(b"aqr"), t3(b"aqt"), t3(b"aqz"), t3(b"ar "),
+    t3(b"arb"), t3(b"arc"), t3(b"ard"), t3(b"are"), t3(b"arh"), t3(b"ari"), t3(b"arj"), t3(b"ark"),
+    t3(b"arl"), t3(b"arn"), t3(b"aro"), t3(b"arp"), t3(b"arq"), t3(b"arr"), t3(b"ars"), t3(b"art"),
+    t3(b"aru"), t3(b"arv"), t3(b"arw"), t3(b"arx"), t3(b"ary"), t3(b"arz"), t3(b"as "), t3(b"asa"),
+    t3(b"asb"), t3(b"asc"), t3(b"asd"), t3(b"ase"), t3(b"asf"), t3(b"asg"), t3(b"ash"), t3(b"asi"),
+    t3(b"asj"), t3(b"ask"), t3(b"asl"), t3(b"asn"), t3(b"aso"), t3(b"asp"), t3(b"asq"), t3(b"asr"),
+    t3(b"ass"), t3(b"ast"), t3(b"asu"), t3(b"asv"), t3(b"asw"), t3(b"asx"), t3(b"asy"), t3(b"asz"),
+    t3(b"ata"), t3(b"atb"), t3(b"atc"), t3(b"atd"), t3(b"ate"), t3(b"atg"), t3(b"ath"), t3(b"ati"),
+    t3(b"atj"), t3(b"atk"), t3(b"atl"), t3(b"atm"), t3(b"atn"), t3(b"ato"), t3(b"atp"), t3(b"atq"),
+    t3(b"atr"), t3(b"ats"), t3(b"att"), t3(b"atu"), t3(b"atv"), t3(b"atw"), t3(b"atx"), t3(b"aty"),
+    t3(b"atz"), t3(b"aua"), t3(b"aub"), t3(b"auc"), t3(b"aud"), t3(b"aue"), t3(b"auf"), t3(b"aug"),
+    t3(b"auh"), t3(b"aui"), t3(b"auj"), t3(b"auk"), t3(b"aul"), t3(b"aum"), t3(b"aun"), t3(b"auo"),
+    t3(b"aup"), t3(b"auq"), t3(b"aur"), t3(b"aus"), t3(b"aut"), t3(b"auu"), t3(b"auw"), t3(b"aux"),
+    t3(b"auy"), t3(b"auz"), t3(b"av "), t3(b"avb"), t3(b"avd"), t3(b"avi"), t3(b"avk"), t3(b"avl"),
+    t3(b"avm"), t3(b"avn"), t3(b"avo"), t3(b"avs"), t3(b"avt"), t3(b"avu"), t3(b"avv"), t3(b"awa"),
+    t3(b"awb"), t3(b"awc"), t3(b"awd"), t3(b"awe"), t3(b"awg"), t3(b"awh"), t3(b"awi"), t3(b"awk"),
+    t3(b"awm"), t3(b"awn"), t3(b"awo"), t3(b"awr"), t3(b"aws"), t3(b"awt"), t3(b"awu"), t3(b"awv"),
+    t3(b"aww"), t3(b"awx"), t3(b"awy"), t3(b"axb"), t3(b"axe"), t3(b"axg"), t3(b"axk"), t3(b"axl"),
+    t3(b"axm"), t3(b"axx"), t3(b"ay "), t3(b"aya"), t3(b"ayb"), t3(b"ayc"), t3(b"ayd"), t3(b"aye"),
+    t3(b"ayg"), t3(b"ayh"), t3(b"ayi"), t3(b"ayk"), t3(b"ayl"), t3(b"ayn"), t3(b"ayo"), t3(b"ayp"),
+    t3(b"ayq"), t3(b"ayr"), t3(b"ays"), t3(b"ayt"), t3(b"ayu"), t3(b"ayx"), t3(b"ayy"), t3(b"ayz"),
+    t3(b"az "), t3(b"aza"), t3(b"azb"), t3(b"azc"), t3(b"azd"), t3(b"azg"), t3(b"azj"), t3(b"azm"),
+    t3(b"azn"), t3(b"azo"), t3(b"azt"), t3(b"azz"), t3(b"ba "), t3(b"baa"), t3(b"bab"), t3(b"bac"),
+    t3(b"bad"), t3(b"bae"), t3(b"baf"), t3(b"bag"), t3(b"bah"), t3(b"bai"), t3(b"baj"), t3(b"bal"),
+    t3(b"ban"), t3(b"bao"), t3(b"bap"), t3(b"bar"), t3(b"bas"), t3(b"bat"), t3(b"bau"), t3(b"bav"),
+    t3(b"baw"), t3(b"bax"), t3(b"bay"), t3(b"baz"), t3(b"bba"), t3(b"bbb"), t3(b"bbc"), t3(b"bbd"),
+    t3(b"bbe"), t3(b"bbf"), t3(b"bbg"), t3(b"bbh"), t3(b"bbi"), t3(b"bbj"), t3(b"bbk"), t3(b"bbl"),
+    t3(b"bbm"), t3(b"bbn"), t3(b"bbo"), t3(b"bbp"), t3(b"bbq"), t3(b"bbr"), t3(b"bbs"), t3(b"bbt"),
+    t3(b"bbu"), t3(b"bbv"), t3(b"bbw"), t3(b"bbx"), t3(b"bby"), t3(b"bbz"), t3(b"bca"), t3(b"bcb"),
+    t3(b"bcc"), t3(b"bcd"), t3(b"bce"), t3(b"bcf"), t3(b"bcg"), t3(b"bch"), t3(b"bci"), t3(b"bcj"),
+    t3(b"bck"), t3(b"bcl"), t3(b"bcm"), t3(b"bcn"), t3(b"bco"), t3(b"bcp"), t3(b"bcq"), t3(b"bcr"),
+    t3(b"bcs"), t3(b"bct"), t3(b"bcu"), t3(b"bcv"), t3(b"bcw"), t3(b"bcy"), t3(b"bcz"), t3(b"bda"),
+    t3(b"bdb"), t3(b"bdc"), t3(b"bdd"), t3(b"bde"), t3(b"bdf"), t3(b"bdg"), t3(b"bdh"), t3(b"bdi"),
+    t3(b"bdj"), t3(b"bdk"), t3(b"bdl"), t3(b"bdm"), t3(b"bdn"), t3(b"bdo"), t3(b"bdp"), t3(b"bdq"),
+    t3(b"bdr"), t3(b"bds"), t3(b"bdt"), t3(b"bdu"), t3(b"bdv"), t3(b"bdw"), t3(b"bdx"), t3(b"bdy"),
+    t3(b"bdz"), t3(b"be "), t3(b"bea"), t3(b"beb"), t3(b"bec"), t3(b"bed"), t3(b"bee"), t3(b"bef"),
+    t3(b"beg"), t3(b"beh"), t3(b"bei"), t3(b"bej"), t3(b"bek"), t3(b"bem"), t3(b"beo"), t3(b"bep"),
+    t3(b"beq"), t3(b"ber"), t3(b"bes"), t3(b"bet"), t3(b"beu"), t3(b"bev"), t3(b"bew"), t3(b"bex"),
+    t3(b"bey"), t3(b"bez"), t3(b"bfa"), t3(b"bfb"), t3(b"bfc"), t3(b"bfd"), t3(b"bfe"), t3(b"bff"),
+    t3(b"bfg"), t3(b"bfh"), t3(b"bfi"), t3(b"bfj"), t3(b"bfk"), t3(b"bfl"), t3(b"bfm"), t3(b"bfn"),
+    t3(b"bfo"), t3(b"bfp"), t3(b"bfq"), t3(b"bfr"), t3(b"bfs"), t3(b"bft"), t3(b"bfu"), t3(b"bfw"),
+    t3(b"bfx"), t3(b"bfy"), t3(b"bfz"), t3(b"bg "), t3(b"bga"), t3(b"bgb"), t3(b"bgc"), t3(b"bgd"),
+    t3(b"bge"), t3(b"bgf"), t3(b"bgg"), t3(b"bgi"), t3(b"bgj"), t3(b"bgk"), t3(b"bgl"), t3(b"bgm"),
+    t3(b"bgn"), t3(b"bgo"), t3(b"bgp"), t3(b"bgq"), t3(b"bgr"), t3(b"bgs"), t3(b"bgt"), t3(b"bgu"),
+    t3(b"bgv"), t3(b"bgw"), t3(b"bgx"), t3(b"bgy"), t3(b"bgz"), t3(b"bh "), t3(b"bha"), t3(b"bhb"),
+    t3(b"bhc"), t3(b"bhd"), t3(b"bhe"), t3(b"bhf"), t3(b"bhg"), t3(b"bhh"), t3(b"bhi"), t3(b"bhj"),
+    t3(b"bhk"), t3(b"bhl"), t3(b"bhm"), t3(b"bhn"), t3(b"bho"), t3(b"bhp"), t3(b"bhq"), t3(b"bhr"),
+    t3(b"bhs"), t3(b"bht"), t3(b"bhu"), t3(b"bhv"), t3(b"bhw"), t3(b"bhx"), t3(b"bhy"), t3(b"bhz"),
+    t3(b"bi "), t3(b"bia"), t3(b"bib"), t3(b"bic"), t3(b"bid"), t3(b"bie"), t3(b"bif"), t3(b"big"),
+    t3(b"bij"), t3(b"bik"), t3(b"bil"), t3(b"bim"), t3(b"bin"), t3(b"bio"), t3(b"bip"), t3(b"biq"),
+    t3(b"bir"), t3(b"bit"), t3(b"biu"), t3(b"biv"), t3(b"biw"), t3(b"bix"), t3(b"biy"), t3(b"biz"),
+    t3(b"bja"), t3(b"bjb"), t3(b"bjc"), t3(b"bjd"), t3(b"bje"), t3(b"bjf"), t3(b"bjg"), t3(b"bjh"),
+    t3(b"bji"), t3(b"bjj"), t3(b"bjk"), t3(b"bjl"), t3(b"bjm"), t3(b"bjn"), t3(b"bjo"), t3(b"bjp"),
+    t3(b"bjq"), t3(b"bjr"), t3(b"bjs"), t3(b"bjt"), t3(b"bju"), t3(b"bjv"), t3(b"bjw"), t3(b"bjx"),
+    t3(b"bjy"), t3(b"bjz"), t3(b"bka"), t3(b"bkb"), t3(b"bkc"), t3(b"bkd"), t3(b"bkf"), t3(b"bkg"),
+    t3(b"bkh"), t3(b"bki"), t3(b"bkj"), t3(b"bkk"), t3(b"bkl"), t3(b"bkm"), t3(b"bkn"), t3(b"bko"),
+    t3(b"bkp"), t3(b"bkq"), t3(b"bkr"), t3(b"bks"), t3(b"bkt"), t3(b"bku"), t3(b"bkv"), t3(b"bkw"),
+    t3(b"bkx"), t3(b"bky"), t3(b"bkz"), t3(b"bla"), t3(b"blb"), t3(b"blc"), t3(b"bld"), t3(b"ble"),
+    t3(b"blf"), t3(b"blg"), t3(b"blh"), t3(b"bli"), t3(b"blj"), t3(b"blk"), t3(b"bll"), t3(b"blm"),
+    t3(b"bln"), t3(b"blo"), t3(b"blp"), t3(b"blq"), t3(b"blr"), t3(b"bls"), t3(b"blt"), t3(b"blv"),
+    t3(b"blw"), t3(b"blx"), t3(b"bly"), t3(b"blz"), t3(b"bm "), t3(b"bma"), t3(b"bmb"), t3(b"bmc"),
+    t3(b"bmd"), t3(b"bme"), t3(b"bmf"), t3(b"bmg"), t3(b"bmh"), t3(b"bmi"), t3(b"bmj"), t3(b"bmk"),
+    t3(b"bml"), t3(b"bmm"), t3(b"bmn"), t3(b"bmo"), t3(b"bmp"), t3(b"bmq"), t3(b"bmr"), t3(b"bms"),
+    t3(b"bmt"), t3(b"bmu"), t3(b"bmv"), t3(b"bmw"), t3(b"bmx"), t3(b"bmy"), t3(b"bmz"), t3(b"bn "),
+    t3(b"bna"), t3(b"bnb"), t3(b"bnc"), t3(b"bnd"), t3(b"bne"), t3(b"bnf"), t3(b"bng"), t3(b"bni"),
+    t3(b"bnj"), t3(b"bnk"), t3(b"bnl"), t3(b"bnm"), t3(b"bnn"), t3(b"bno"), t3(b"bnp"), t3(b"bnq"),
+    t3(b"bnr"), t3(b"bns"), t3(b"bnt"), t3(b"bnu"), t3(b"bnv"), t3(b"bnw"), t3(b"bnx"), t3(b"bny"),
+    t3(b"bnz"), t3(b"bo "), t3(b"boa"), t3(b"bob"), t3(b"boe"), t3(b"bof"), t3(b"bog"), t3(b"boh"),
+    t3(b"boi"), t3(b"boj"), t3(b"bok"), t3(b"bol"), t3(b"bom"), t3(b"bon"), t3(b"boo"), t3(b"bop"),
+    t3(b"boq"), t3(b"bor"), t3(b"bot"), t3(b"bou"), t3(b"bov"), t3(b"bow"), t3(b"box"), t3(b"boy"),
+    t3(b"boz"), t3(b"bpa"), t3(b"bpb"), t3(b"bpd"), t3(b"bpe"), t3(b"bpg"), t3(b"bph"), t3(b"bpi"),
+    t3(b"bpj"), t3(b"bpk"), t3(b"bpl"), t3(b"bpm"), t3(b"bpn"), t3(b"bpo"), t3(b"bpp"), t3(b"bpq"),
+    t3(b"bpr"), t3(b"bps"), t3(b"bpt"), t3(b"bpu"), t3(b"bpv"), t3(b"bpw"), t3(b"bpx"), t3(b"bpy"),
+    t3(b"bpz"), t3(b"bqa"), t3(b"bqb"), t3(b"bqc"), t3(b"bqd"), t3(b"bqf"), t3(b"bqg"), t3(b"bqh"),
+    t3(b"bqi"), t3(b"bqj"), t3(b"bqk"), t3(b"bql"), t3(b"bqm"), t3(b"bqn"), t3(b"bqo"), t3(b"bqp"),
+    t3(b"bqq"), t3(b"bqr"), t3(b"bqs"), t3(b"bqt"), t3(b"bqu"), t3(b"bqv"), t3(b"bqw"), t3(b"bqx"),
+    t3(b"bqy"), t3(b"bqz"), t3(b"br "), t3(b"bra"), t3(b"brb"), t3(b"brc"), t3(b"brd"), t3(b"brf"),
+    t3(b"brg"), t3(b"brh"), t3(b"bri"), t3(b"brj"), t3(b"brk"), t3(b"brl"), t3(b"brm"), t3(b"brn"),
+    t3(b"bro"), t3(b"brp"), t3(b"brq"), t3(b"brr"), t3(b"brs"), t3(b"brt"), t3(b"bru"), t3(b"brv"),
+    t3(b"brw"), t3(b"brx"), t3(b"bry"), t3(b"brz"), t3(b"bs "), t3(b"bsa"), t3(b"bsb"), t3(b"bsc"),
+    t3(b"bse"), t3(b"bsf"), t3(b"bsg"), t3(b"bsh"), t3(b"bsi"), t3(b"bsj"), t3(b"bsk"), t3(b"bsl"),
+    t3(b"bsm"), t3(b"bsn"), t3(b"bso"), t3(b"bsp"), t3(b"bsq"), t3(b"bsr"), t3(b"bss"), t3(b"bst"),
+    t3(b"bsu"), t3(b"bsv"), t3(b"bsw"), t3(b"bsx"), t3(b"bsy"), t3(b"bta"), t3(b"btb"), t3(b"btc"),
+    t3(b"btd"), t3(b"bte"), t3(b"btf"), t3(b"btg"), t3(b"bth"), t3(b"bti"), t3(b"btj"), t3(b"btk"),
+    t3(b"btl"), t3(b"btm"), t3(b"btn"), t3(b"bto"), t3(b"btp"), t3(b"btq"), t3(b"btr"), t3(b"bts"),
+    t3(b"btt"), t3(b"btu"), t3(b"btv"), t3(b"btw"), t3(b"btx"), t3(b"bty"), t3(b"btz"), t3(b"bua"),
+    t3(b"bub"), t3(b"buc"), t3(b"bud"), t3(b"bue"), t3(b"buf"), t3(b"bug"), t3(b"buh"), t3(b"bui"),
+    t3(b"buj"), t3(b"buk"), t3(b"bum"), t3(b"bun"), t3(b"buo"), t3(b"bup"), t3(b"buq"), t3(b"bus"),
+    t3(b"but"), t3(b"buu"), t3(b"buv"), t3(b"buw"), t3(b"bux"), t3(b"buy"), t3(b"buz"), t3(b"bva"),
+    t3(b"bvb"), t3(b"bvc"), t3(b"bvd"), t3(b"bve"), t3(b"bvf"), t3(b"bvg"), t3(b"bvh"), t3(b"bvi"),
+    t3(b"bvj"), t3(b"bvk"), t3(b"bvl"), t3(b"bvm"), t3(b"bvn"), t3(b"bvo"), t3(b"bvp"), t3(b"bvq"),
+    t3(b"bvr"), t3(b"bvt"), t3(b"bvu"), t3(b"bvv"), t3(b"bvw"), t3(b"bvx"), t3(b"bvy"), t3(b"bvz"),
+    t3(b"bwa"), t3(b"bwb"), t3(b"bwc"), t3(b"bwd"), t3(b"bwe"), t3(b"bwf"), t3(b"bwg"), t3(b"bwh"),
+    t3(b"bwi"), t3(b"bwj"), t3(b"bwk"), t3(b"bwl"), t3(b"bwm"), t3(b"bwn"), t3(b"bwo"), t3(b"bwp"),
+    t3(b"bwq"), t3(b"bwr"), t3(b"bws"), t3(b"bwt"), t3(b"bwu"), t3(b"bww"), t3(b"bwx"), t3(b"bwy"),
+    t3(b"bwz"), t3(b"bxa"), t3(b"bxb"), t3(b"bxc"), t3(b"bxd"), t3(b"bxe"), t3(b"bxf"), t3(b"bxg"),
+    t3(b"bxh"), t3(b"bxi"), t3(b"bxj"), t3(b"bxk"), t3(b"bxl"), t3(b"bxm"), t3(b"bxn"), t3(b"bxo"),
+    t3(b"bxp"), t3(b"bxq"), t3(b"bxr"), t3(b"bxs"), t3(b"bxu"), t3(b"bxv"), t3(b"bxw"), t3(b"bxx"),
+    t3(b"bxz"), t3(b"bya"), t3(b"byb"), t3(b"byc"), t3(b"byd"), t3(b"bye"), t3(b"byf"), t3(b"byg"),
+    t3(b"byh"), t3(b"byi"), t3(b"byj"), t3(b"byk"), t3(b"byl"), t3(b"bym"), t3(b"byn"), t3(b"byo"),
+    t3(b"byp"), t3(b"byq"), t3(b"byr"), t3(b"bys"), t3(b"byt"), t3(b"byv"), t3(b"byw"), t3(b"byx"),
+    t3(b"byy"), t3(b"byz"), t3(b"bza"), t3(b"bzb"), t3(b"bzc"), t3(b"bzd"), t3(b"bze"), t3(b"bzf"),
+    t3(b"bzg"), t3(b"bzh"), t3(b"bzi"), t3(b"bzj"), t3(b"bzk"), t3(b"bzl"), t3(b"bzm"), t3(b"bzn"),
+    t3(b"bzo"), t3(b"bzp"), t3(b"bzq"), t3(b"bzr"), t3(b"bzs"), t3(b"bzt"), t3(b"bzu"), t3(b"bzv"),
+    t3(b"bzw"), t3(b"bzx"), t3(b"bzy"), t3(b"bzz"), t3(b"ca "), t3(b"caa"), t3(b"cab"), t3(b"cac"),
+    t3(b"cad"), t3(b"cae"), t3(b"caf"), t3(b"cag"), t3(b"cah"), t3(b"cai"), t3(b"caj"), t3(b"cak"),
+    t3(b"cal"), t3(b"cam"), t3(b"can"), t3(b"cao"), t3(b"cap"), t3(b"caq"), t3(b"car"), t3(b"cas"),
+    t3(b"cau"), t3(b"cav"), t3(b"caw"), t3(b"cax"), t3(b"cay"), t3(b"caz"), t3(b"cba"), t3(b"cbb"),
+    t3(b"cbc"), t3(b"cbd"), t3(b"cbe"), t3(b"cbg"), t3(b"cbh"), t3(b"cbi"), t3(b"cbj"), t3(b"cbk"),
+    t3(b"cbl"), t3(b"cbn"), t3(b"cbo"), t3(b"cbq"), t3(b"cbr"), t3(b"cbs"), t3(b"cbt"), t3(b"cbu"),
+    t3(b"cbv"), t3(b"cbw"), t3(b"cby"), t3(b"cca"), t3(b"ccc"), t3(b"ccd"), t3(b"cce"), t3(b"ccg"),
+    t3(b"cch"), t3(b"ccj"), t3(b"ccl"), t3(b"ccm"), t3(b"ccn"), t3(b"cco"), t3(b"ccp"), t3(b"ccq"),
+    t3(b"ccr"), t3(b"ccs"), t3(b"cda"), t3(b"cdc"), t3(b"cdd"), t3(b"cde"), t3(b"cdf"), t3(b"cdg"),
+    t3(b"cdh"), t3(b"cdi"), t3(b"cdj"), t3(b"cdm"), t3(b"cdn"), t3(b"cdo"), t3(b"cdr"), t3(b"cds"),
+    t3(b"cdy"), t3(b"cdz"), t3(b"ce "), t3(b"cea"), t3(b"ceb"), t3(b"ceg"), t3(b"cek"), t3(b"cel"),
+    t3(b"cen"), t3(b"cet"), t3(b"cey"), t3(b"cfa"), t3(b"cfd"), t3(b"cfg"), t3(b"cfm"), t3(b"cga"),
+    t3(b"cgc"), t3(b"cgg"), t3(b"cgk"), t3(b"ch "), t3(b"chb"), t3(b"chc"), t3(b"chd"), t3(b"chf"),
+    t3(b"chg"), t3(b"chh"), t3(b"chj"), t3(b"chk"), t3(b"chl"), t3(b"chm"), t3(b"chn"), t3(b"cho"),
+    t3(b"chp"), t3(b"chq"), t3(b"chr"), t3(b"cht"), t3(b"chw"), t3(b"chx"), t3(b"chy"), t3(b"chz"),
+    t3(b"cia"), t3(b"cib"), t3(b"cic"), t3(b"cid"), t3(b"cie"), t3(b"cih"), t3(b"cik"), t3(b"cim"),
+    t3(b"cin"), t3(b"cip"), t3(b"cir"), t3(b"ciw"), t3(b"ciy"), t3(b"cja"), t3(b"cje"), t3(b"cjh"),
+    t3(b"cji"), t3(b"cjk"), t3(b"cjm"), t3(b"cjn"), t3(b"cjo"), t3(b"cjp"), t3(b"cjr"), t3(b"cjs"),
+    t3(b"cjv"), t3(b"cjy"), t3(b"cka"), t3(b"ckb"), t3(b"ckh"), t3(b"ckl"), t3(b"ckm"), t3(b"ckn"),
+    t3(b"cko"), t3(b"ckq"), t3(b"ckr"), t3(b"cks"), t3(b"ckt"), t3(b"cku"), t3(b"ckv"), t3(b"ckx"),
+    t3(b"cky"), t3(b"ckz"), t3(b"cla"), t3(b"clc"), t3(b"cld"), t3(b"cle"), t3(b"clh"), t3(b"cli"),
+    t3(b"clj"), t3(b"clk"), t3(b"cll"), t3(b"clm"), t3(b"clo"), t3(b"clt"), t3(b"clu"), t3(b"clw"),
+    t3(b"cly"), t3(b"cma"), t3(b"cmc"), t3(b"cme"), t3(b"cmg"), t3(b"cmi"), t3(b"cmk"), t3(b"cml"),
+    t3(b"cmm"), t3(b"cmn"), t3(b"cmo"), t3(b"cmr"), t3(b"cms"), t3(b"cmt"), t3(b"cna"), t3(b"cnb"),
+    t3(b"cnc"), t3(b"cng"), t3(b"cnh"), t3(b"cni"), t3(b"cnk"), t3(b"cnl"), t3(b"cno"), t3(b"cnp"),
+    t3(b"cnr"), t3(b"cns"), t3(b"cnt"), t3(b"cnu"), t3(b"cnw"), t3(b"cnx"), t3(b"co "), t3(b"coa"),
+    t3(b"cob"), t3(b"coc"), t3(b"cod"), t3(b"coe"), t3(b"cof"), t3(b"cog"), t3(b"coh"), t3(b"coj"),
+    t3(b"cok"), t3(b"col"), t3(b"com"), t3(b"con"), t3(b"coo"), t3(b"cop"), t3(b"coq"), t3(b"cot"),
+    t3(b"cou"), t3(b"cov"), t3(b"cow"), t3(b"cox"), t3(b"coy"), t3(b"coz"), t3(b"cpa"), t3(b"cpb"),
+    t3(b"cpc"), t3(b"cpe"), t3(b"cpf"), t3(b"cpg"), t3(b"cpi"), t3(b"cpn"), t3(b"cpo"), t3(b"cpp"),
+    t3(b"cps"), t3(b"cpu"), t3(b"cpx"), t3(b"cpy"), t3(b"cqd"), t3(b"cqu"), t3(b"cr "), t3(b"cra"),
+    t3(b"crb"), t3(b"crc"), t3(b"crd"), t3(b"crf"), t3(b"crg"), t3(b"crh"), t3(b"cri"), t3(b"crj"),
+    t3(b"crk"), t3(b"crl"), t3(b"crm"), t3(b"crn"), t3(b"cro"), t3(b"crp"), t3(b"crq"), t3(b"crr"),
+    t3(b"crs"), t3(b"crt"), t3(b"crv"), t3(b"crw"), t3(b"crx"), t3(b"cry"), t3(b"crz"), t3(b"cs "),
+    t3(b"csa"), t3(b"csb"), t3(b"csc"), t3(b"csd"), t3(b"cse"), t3(b"csf"), t3(b"csg"), t3(b"csh"),
+    t3(b"csi"), t3(b"csj"), t3(b"csk"), t3(b"csl"), t3(b"csm"), t3(b"csn"), t3(b"cso"), t3(b"csp"),
+    t3(b"csq"), t3(b"csr"), t3(b"css"), t3(b"cst"), t3(b"csu"), t3(b"csv"), t3(b"csw"), t3(b"csx"),
+    t3(b"csy"), t3(b"csz"), t3(b"cta"), t3(b"ctc"), t3(b"ctd"), t3(b"cte"), t3(b"ctg"), t3(b"cth"),
+    t3(b"ctl"), t3(b"ctm"), t3(b"ctn"), t3(b"cto"), t3(b"ctp"), t3(b"cts"), t3(b"ctt"), t3(b"ctu"),
+    t3(b"cty"), t3(b"ctz"), t3(b"cu "), t3(b"cua"), t3(b"cub"), t3(b"cuc"), t3(b"cug"), t3(b"cuh"),
+    t3(b"cui"), t3(b"cuj"), t3(b"cuk"), t3(b"cul"), t3(b"cum"), t3(b"cuo"), t3(b"cup"), t3(b"cuq"),
+    t3(b"cur"), t3(b"cus"), t3(b"cut"), t3(b"cuu"), t3(b"cuv"), t3(b"cuw"), t3(b"cux"), t3(b"cuy"),
+    t3(b"cv "), t3(b"cvg"), t3(b"cvn"), t3(b"cwa"), t3(b"cwb"), t3(b"cwd"), t3(b"cwe"), t3(b"cwg"),
+    t3(b"cwt"), t3(b"cy "), t3(b"cya"), t3(b"cyb"), t3(b"cyo"), t3(b"czh"), t3(b"czk"), t3(b"czn"),
+    t3(b"czo"), t3(b"czt"), t3(b"da "), t3(b"daa"), t3(b"dac"), t3(b"dad"), t3(b"dae"), t3(b"daf"),
+    t3(b"dag"), t3(b"dah"), t3(b"dai"), t3(b"daj"), t3(b"dak"), t3(b"dal"), t3(b"dam"), t3(b"dao"),
+    t3(b"dap"), t3(b"daq"), t3(b"dar"), t3(b"das"), t3(b"dau"), t3(b"dav"), t3(b"daw"), t3(b"dax"),
+    t3(b"day"), t3(b"daz"), t3(b"dba"), t3(b"dbb"), t3(b"dbd"), t3(b"dbe"), t3(b"dbf"), t3(b"dbg"),
+    t3(b"dbi"), t3(b"dbj"), t3(b"dbl"), t3(b"dbm"), t3(b"dbn"), t3(b"dbo"), t3(b"dbp"), t3(b"dbq"),
+    t3(b"dbr"), t3(b"dbt"), t3(b"dbu"), t3(b"dbv"), t3(b"dbw"), t3(b"dby"), t3(b"dcc"), t3(b"dcr"),
+    t3(b"dda"), t3(b"ddd"), t3(b"dde"), t3(b"ddg"), t3(b"ddi"), t3(b"ddj"), t3(b"ddn"), t3(b"ddo"),
+    t3(b"ddr"), t3(b"dds"), t3(b"ddw"), t3(b"de "), t3(b"dec"), t3(b"ded"), t3(b"dee"), t3(b"def"),
+    t3(b"deg"), t3(b"deh"), t3(b"dei"), t3(b"dek"), t3(b"del"), t3(b"dem"), t3(b"den"), t3(b"dep"),
+    t3(b"deq"), t3(b"der"), t3(b"des"), t3(b"dev"), t3(b"dez"), t3(b"dga"), t3(b"dgb"), t3(b"dgc"),
+    t3(b"dgd"), t3(b"dge"), t3(b"dgg"), t3(b"dgh"), t3(b"dgi"), t3(b"dgk"), t3(b"dgl"), t3(b"dgn"),
+    t3(b"dgo"), t3(b"dgr"), t3(b"dgs"), t3(b"dgt"), t3(b"dgu"), t3(b"dgw"), t3(b"dgx"), t3(b"dgz"),
+    t3(b"dha"), t3(b"dhd"), t3(b"dhg"), t3(b"dhi"), t3(b"dhl"), t3(b"dhm"), t3(b"dhn"), t3(b"dho"),
+    t3(b"dhr"), t3(b"dhs"), t3(b"dhu"), t3(b"dhv"), t3(b"dhw"), t3(b"dhx"), t3(b"dia"), t3(b"dib"),
+    t3(b"dic"), t3(b"did"), t3(b"dif"), t3(b"dig"), t3(b"dih"), t3(b"dii"), t3(b"dij"), t3(b"dik"),
+    t3(b"dil"), t3(b"dim"), t3(b"din"), t3(b"dio"), t3(b"dip"), t3(b"diq"), t3(b"dir"), t3(b"dis"),
+    t3(b"dit"), t3(b"diu"), t3(b"diw"), t3(b"dix"), t3(b"diy"), t3(b"diz"), t3(b"dja"), t3(b"djb"),
+    t3(b"djc"), t3(b"djd"), t3(b"dje"), t3(b"djf"), t3(b"dji"), t3(b"djj"), t3(b"djk"), t3(b"djl"),
+    t3(b"djm"), t3(b"djn"), t3(b"djo"), t3(b"djr"), t3(b"dju"), t3(b"djw"), t3(b"dka"), t3(b"dkg"),
+    t3(b"dkk"), t3(b"dkl"), t3(b"dkr"), t3(b"dks"), t3(b"dkx"), t3(b"dlg"), t3(b"dlk"), t3(b"dlm"),
+    t3(b"dln"), t3(b"dma"), t3(b"dmb"), t3(b"dmc"), t3(b"dmd"), t3(b"dme"), t3(b"dmf"), t3(b"dmg"),
+    t3(b"dmk"), t3(b"dml"), t3(b"dmm"), t3(b"dmn"), t3(b"dmo"), t3(b"dmr"), t3(b"dms"), t3(b"dmu"),
+    t3(b"dmv"), t3(b"dmw"), t3(b"dmx"), t3(b"dmy"), t3(b"dna"), t3(b"dnd"), t3(b"dne"), t3(b"dng"),
+    t3(b"dni"), t3(b"dnj"), t3(b"dnk"), t3(b"dnn"), t3(b"dno"), t3(b"dnr"), t3(b"dnt"), t3(b"dnu"),
+    t3(b"dnv"), t3(b"dnw"), t3(b"dny"), t3(b"doa"), t3(b"dob"), t3(b"doc"), t3(b"doe"), t3(b"dof"),
+    t3(b"doh"), t3(b"doi"), t3(b"dok"), t3(b"dol"), t3(b"don"), t3(b"doo"), t3(b"dop"), t3(b"doq"),
+    t3(b"dor"), t3(b"dos"), t3(b"dot"), t3(b"dov"), t3(b"dow"), t3(b"dox"), t3(b"doy"), t3(b"doz"),
+    t3(b"dpp"), t3(b"dra"), t3(b"drb"), t3(b"drc"), t3(b"drd"), t3(b"dre"), t3(b"drg"), t3(b"drh"),
+    t3(b"dri"), t3(b"drl"), t3(b"drn"), t3(b"dro"), t3(b"drq"), t3(b"drr"), t3(b"drs"), t3(b"drt"),
+    t3(b"dru"), t3(b"drw"), t3(b"dry"), t3(b"dsb"), t3(b"dse"), t3(b"dsh"), t3(b"dsi"), t3(b"dsl"),
+    t3(b"dsn"), t3(b"dso"), t3(b"dsq"), t3(b"dta"), t3(b"dtb"), t3(b"dtd"), t3(b"dth"), t3(b"dti"),
+    t3(b"dtk"), t3(b"dtm"), t3(b"dtn"), t3(b"dto"), t3(b"dtp"), t3(b"dtr"), t3(b"dts"), t3(b"dtt"),
+    t3(b"dtu"), t3(b"dty"), t3(b"dua"), t3(b"dub"), t3(b"duc"), t3(b"dud"), t3(b"due"), t3(b"duf"),
+    t3(b"dug"), t3(b"duh"), t3(b"dui"), t3(b"duj"), t3(b"duk"), t3(b"dul"), t3(b"dum"), t3(b"dun"),
+    t3(b"duo"), t3(b"dup"), t3(b"duq"), t3(b"dur"), t3(b"dus"), t3(b"duu"), t3(b"duv"), t3(b"duw"),
+    t3(b"dux"), t3(b"duy"), t3(b"duz"), t3(b"dv "), t3(b"dva"), t3(b"dwa"), t3(b"dwk"), t3(b"dwl"),
+    t3(b"dwr"), t3(b"dws"), t3(b"dwu"), t3(b"dww"), t3(b"dwy"), t3(b"dwz"), t3(b"dya"), t3(b"dyb"),
+    t3(b"dyd"), t3(b"dyg"), t3(b"dyi"), t3(b"dym"), t3(b"dyn"), t3(b"dyo"), t3(b"dyu"), t3(b"dyy"),
+    t3(b"dz "), t3(b"dza"), t3(b"dzd"), t3(b"dze"), t3(b"dzg"), t3(b"dzl"), t3(b"dzn"), t3(b"eaa"),
+    t3(b"ebc"), t3(b"ebg"), t3(b"ebk"), t3(b"ebo"), t3(b"ebr"), t3(b"ebu"), t3(b"ecr"), t3(b"ecs"),
+    t3(b"ecy"), t3(b"ee "), t3(b"eee"), t3(b"efa"), t3(b"efe"), t3(b"efi"), t3(b"ega"), t3(b"egl"),
+    t3(b"ego"), t3(b"egx"), t3(b"egy"), t3(b"ehs"), t3(b"ehu"), t3(b"eip"), t3(b"eit"), t3(b"eiv"),
+    t3(b"eja"), t3(b"eka"), t3(b"ekc"), t3(b"eke"), t3(b"ekg"), t3(b"eki"), t3(b"ekk"), t3(b"ekl"),
+    t3(b"ekm"), t3(b"eko"), t3(b"ekp"), t3(b"ekr"), t3(b"eky"), t3(b"el "), t3(b"ele"), t3(b"elh"),
+    t3(b"eli"), t3(b"elk"), t3(b"elm"), t3(b"elo"), t3(b"elp"), t3(b"elu"), t3(b"elx"), t3(b"ema"),
+    t3(b"emb"), t3(b"eme"), t3(b"emg"), t3(b"emi"), t3(b"emk"), t3(b"emm"), t3(b"emn"), t3(b"emo"),
+    t3(b"emp"), t3(b"emq"), t3(b"ems"), t3(b"emu"), t3(b"emw"), t3(b"emx"), t3(b"emy"), t3(b"emz"),
+    t3(b"en "), t3(b"ena"), t3(b"enb"), t3(b"enc"), t3(b"end"), t3(b"enf"), t3(b"enh"), t3(b"enl"),
+    t3(b"enm"), t3(b"enn"), t3(b"eno"), t3(b"enq"), t3(b"enr"), t3(b"enu"), t3(b"env"), t3(b"enw"),
+    t3(b"enx"), t3(b"eo "), t3(b"eot"), t3(b"epi"), t3(b"era"), t3(b"erg"), t3(b"erh"), t3(b"eri"),
+    t3(b"erk"), t3(b"ero"), t3(b"err"), t3(b"ers"), t3(b"ert"), t3(b"erw"), t3(b"es "), t3(b"ese"),
+    t3(b"esg"), t3(b"esh"), t3(b"esi"), t3(b"esk"), t3(b"esl"), t3(b"esm"), t3(b"esn"), t3(b"eso"),
+    t3(b"esq"), t3(b"ess"), t3(b"esu"), t3(b"esx"), t3(b"esy"), t3(b"et "), t3(b"etb"), t3(b"etc"),
+    t3(b"eth"), t3(b"etn"), t3(b"eto"), t3(b"etr"), t3(b"ets"), t3(b"ett"), t3(b"etu"), t3(b"etx"),
+    t3(b"etz"), t3(b"eu "), t3(b"euq"), t3(b"eve"), t3(b"evh"), t3(b"evn"), t3(b"ewo"), t3(b"ext"),
+    t3(b"eya"), t3(b"eyo"), t3(b"eza"), t3(b"eze"), t3(b"fa "), t3(b"faa"), t3(b"fab"), t3(b"fad"),
+    t3(b"faf"), t3(b"fag"), t3(b"fah"), t3(b"fai"), t3(b"faj"), t3(b"fak"), t3(b"fal"), t3(b"fam"),
+    t3(b"fan"), t3(b"fap"), t3(b"far"), t3(b"fat"), t3(b"fau"), t3(b"fax"), t3(b"fay"), t3(b"faz"),
+    t3(b"fbl"), t3(b"fcs"), t3(b"fer"), t3(b"ff "), t3(b"ffi"), t3(b"ffm"), t3(b"fgr"), t3(b"fi "),
+    t3(b"fia"), t3(b"fie"), t3(b"fif"), t3(b"fil"), t3(b"fip"), t3(b"fir"), t3(b"fit"), t3(b"fiu"),
+    t3(b"fiw"), t3(b"fj "), t3(b"fkk"), t3(b"fkv"), t3(b"fla"), t3(b"flh"), t3(b"fli"), t3(b"fll"),
+    t3(b"fln"), t3(b"flr"), t3(b"fly"), t3(b"fmp"), t3(b"fmu"), t3(b"fnb"), t3(b"fng"), t3(b"fni"),
+    t3(b"fo "), t3(b"fod"), t3(b"foi"), t3(b"fom"), t3(b"fon"), t3(b"for"), t3(b"fos"), t3(b"fox"),
+    t3(b"fpe"), t3(b"fqs"), t3(b"fr "), t3(b"frc"), t3(b"frd"), t3(b"frk"), t3(b"frm"), t3(b"fro"),
+    t3(b"frp"), t3(b"frq"), t3(b"frr"), t3(b"frs"), t3(b"frt"), t3(b"fse"), t3(b"fsl"), t3(b"fss"),
+    t3(b"fub"), t3(b"fuc"), t3(b"fud"), t3(b"fue"), t3(b"fuf"), t3(b"fuh"), t3(b"fui"), t3(b"fuj"),
+    t3(b"fum"), t3(b"fun"), t3(b"fuq"), t3(b"fur"), t3(b"fut"), t3(b"fuu"), t3(b"fuv"), t3(b"fuy"),
+    t3(b"fvr"), t3(b"fwa"), t3(b"fwe"), t3(b"fy "), t3(b"ga "), t3(b"gaa"), t3(b"gab"), t3(b"gac"),
+    t3(b"gad"), t3(b"gae"), t3(b"gaf"), t3(b"gag"), t3(b"gah"), t3(b"gai"), t3(b"gaj"), t3(b"gak"),
+    t3(b"gal"), t3(b"gam"), t3(b"gan"), t3(b"gao"), t3(b"gap"), t3(b"gaq"), t3(b"gar"), t3(b"gas"),
+    t3(b"gat"), t3(b"gau"), t3(b"gav"), t3(b"gaw"), t3(b"gax"), t3(b"gay"), t3(b"gaz"), t3(b"gba"),
+    t3(b"gbb"), t3(b"gbc"), t3(b"gbd"), t3(b"gbe"), t3(b"gbf"), t3(b"gbg"), t3(b"gbh"), t3(b"gbi"),
+    t3(b"gbj"), t3(b"gbk"), t3(b"gbl"), t3(b"gbm"), t3(b"gbn"), t3(b"gbo"), t3(b"gbp"), t3(b"gbq"),
+    t3(b"gbr"), t3(b"gbs"), t3(b"gbu"), t3(b"gbv"), t3(b"gbw"), t3(b"gbx"), t3(b"gby"), t3(b"gbz"),
+    t3(b"gcc"), t3(b"gcd"), t3(b"gce"), t3(b"gcf"), t3(b"gcl"), t3(b"gcn"), t3(b"gcr"), t3(b"gct"),
+    t3(b"gd "), t3(b"gda"), t3(b"gdb"), t3(b"gdc"), t3(b"gdd"), t3(b"gde"), t3(b"gdf"), t3(b"gdg"),
+    t3(b"gdh"), t3(b"gdi"), t3(b"gdj"), t3(b"gdk"), t3(b"gdl"), t3(b"gdm"), t3(b"gdn"), t3(b"gdo"),
+    t3(b"gdq"), t3(b"gdr"), t3(b"gds"), t3(b"gdt"), t3(b"gdu"), t3(b"gdx"), t3(b"gea"), t3(b"geb"),
+    t3(b"gec"), t3(b"ged"), t3(b"gef"), t3(b"geg"), t3(b"geh"), t3(b"gei"), t3(b"gej"), t3(b"gek"),
+    t3(b"gel"), t3(b"gem"), t3(b"geq"), t3(b"ges"), t3(b"gev"), t3(b"gew"), t3(b"gex"), t3(b"gey"),
+    t3(b"gez"), t3(b"gfk"), t3(b"gft"), t3(b"gfx"), t3(b"gga"), t3(b"ggb"), t3(b"ggd"), t3(b"gge"),
+    t3(b"ggg"), t3(b"ggk"), t3(b"ggl"), t3(b"ggn"), t3(b"ggo"), t3(b"ggr"), t3(b"ggt"), t3(b"ggu"),
+    t3(b"ggw"), t3(b"gha"), t3(b"ghc"), t3(b"ghe"), t3(b"ghh"), t3(b"ghk"), t3(b"ghl"), t3(b"ghn"),
+    t3(b"gho"), t3(b"ghr"), t3(b"ghs"), t3(b"ght"), t3(b"gia"), t3(b"gib"), t3(b"gic"), t3(b"gid"),
+    t3(b"gie"), t3(b"gig"), t3(b"gih"), t3(b"gii"), t3(b"gil"), t3(b"gim"), t3(b"gin"), t3(b"gio"),
+    t3(b"gip"), t3(b"giq"), t3(b"gir"), t3(b"gis"), t3(b"git"), t3(b"giu"), t3(b"giw"), t3(b"gix"),
+    t3(b"giy"), t3(b"giz"), t3(b"gji"), t3(b"gjk"), t3(b"gjm"), t3(b"gjn"), t3(b"gjr"), t3(b"gju"),
+    t3(b"gka"), t3(b"gkd"), t3(b"gke"), t3(b"gkn"), t3(b"gko"), t3(b"gkp"), t3(b"gku"), t3(b"gl "),
+    t3(b"glb"), t3(b"glc"), t3(b"gld"), t3(b"glh"), t3(b"gli"), t3(b"glj"), t3(b"glk"), t3(b"gll"),
+    t3(b"glo"), t3(b"glr"), t3(b"glu"), t3(b"glw"), t3(b"gly"), t3(b"gma"), t3(b"gmb"), t3(b"gmd"),
+    t3(b"gme"), t3(b"gmg"), t3(b"gmh"), t3(b"gml"), t3(b"gmm"), t3(b"gmn"), t3(b"gmq"), t3(b"gmr"),
+    t3(b"gmu"), t3(b"gmv"), t3(b"gmw"), t3(b"gmx"), t3(b"gmy"), t3(b"gmz"), t3(b"gn "), t3(b"gna"),
+    t3(b"gnb"), t3(b"gnc"), t3(b"gnd"), t3(b"gne"), t3(b"gng"), t3(b"gnh"), t3(b"gni"), t3(b"gnj"),
+    t3(b"gnk"), t3(b"gnl"), t3(b"gnm"), t3(b"gnn"), t3(b"gno"), t3(b"gnq"), t3(b"gnr"), t3(b"gnt"),
+    t3(b"gnu"), t3(b"gnw"), t3(b"gnz"), t3(b"goa"), t3(b"gob"), t3(b"goc"), t3(b"god"), t3(b"goe"),
+    t3(b"gof"), t3(b"gog"), t3(b"goh"), t3(b"goi"), t3(b"goj"), t3(b"gok"), t3(b"gol"), t3(b"gom"),
+    t3(b"gon"), t3(b"goo"), t3(b"gop"), t3(b"goq"), t3(b"gor"), t3(b"gos"), t3(b"got"), t3(b"gou"),
+    t3(b"gow"), t3(b"gox"), t3(b"goy"), t3(b"goz"), t3(b"gpa"), t3(b"gpe"), t3(b"gpn"), t3(b"gqa"),
+    t3(b"gqi"), t3(b"gqn"), t3(b"gqr"), t3(b"gqu"), t3(b"gra"), t3(b"grb"), t3(b"grc"), t3(b"grd"),
+    t3(b"grg"), t3(b"grh"), t3(b"gri"), t3(b"grj"), t3(b"grk"), t3(b"grm"), t3(b"gro"), t3(b"grq"),
+    t3(b"grr"), t3(b"grs"), t3(b"grt"), t3(b"gru"), t3(b"grv"), t3(b"grw"), t3(b"grx"), t3(b"gry"),
+    t3(b"grz"), t3(b"gse"), t3(b"gsg"), t3(b"gsl"), t3(b"gsm"), t3(b"gsn"), t3(b"gso"), t3(b"gsp"),
+    t3(b"gss"), t3(b"gsw"), t3(b"gta"), t3(b"gti"), t3(b"gtu"), t3(b"gu "), t3(b"gua"), t3(b"gub"),
+    t3(b"guc"), t3(b"gud"), t3(b"gue"), t3(b"guf"), t3(b"gug"), t3(b"guh"), t3(b"gui"), t3(b"guk"),
+    t3(b"gul"), t3(b"gum"), t3(b"gun"), t3(b"guo"), t3(b"gup"), t3(b"guq"), t3(b"gur"), t3(b"gus"),
+    t3(b"gut"), t3(b"guu"), t3(b"guv"), t3(b"guw"), t3(b"gux"), t3(b"guz"), t3(b"gv "), t3(b"gva"),
+    t3(b"gvc"), t3(b"gve"), t3(b"gvf"), t3(b"gvj"), t3(b"gvl"), t3(b"gvm"), t3(b"gvn"), t3(b"gvo"),
+    t3(b"gvp"), t3(b"gvr"), t3(b"gvs"), t3(b"gvy"), t3(b"gwa"), t3(b"gwb"), t3(b"gwc"), t3(b"gwd"),
+    t3(b"gwe"), t3(b"gwf"), t3(b"gwg"), t3(b"gwi"), t3(b"gwj"), t3(b"gwm"), t3(b"gwn"), t3(b"gwr"),
+    t3(b"gwt"), t3(b"gwu"), t3(b"gww"), t3(b"gwx"), t3(b"gxx"), t3(b"gya"), t3(b"gyb"), t3(b"gyd"),
+    t3(b"gye"), t3(b"gyf"), t3(b"gyg"), t3(b"gyi"), t3(b"gyl"), t3(b"gym"), t3(b"gyn"), t3(b"gyo"),
+    t3(b"gyr"), t3(b"gyy"), t3(b"gyz"), t3(b"gza"), t3(b"gzi"), t3(b"gzn"), t3(b"ha "), t3(b"haa"),
+    t3(b"hab"), t3(b"hac"), t3(b"had"), t3(b"hae"), t3(b"haf"), t3(b"hag"), t3(b"hah"), t3(b"hai"),
+    t3(b"haj"), t3(b"hak"), t3(b"hal"), t3(b"ham"), t3(b"han"), t3(b"hao"), t3(b"hap"), t3(b"haq"),
+    t3(b"har"), t3(b"has"), t3(b"hav"), t3(b"haw"), t3(b"hax"), t3(b"hay"), t3(b"haz"), t3(b"hba"),
+    t3(b"hbb"), t3(b"hbn"), t3(b"hbo"), t3(b"hbu"), t3(b"hca"), t3(b"hch"), t3(b"hdn"), t3(b"hds"),
+    t3(b"hdy"), t3(b"he "), t3(b"hea"), t3(b"hed"), t3(b"heg"), t3(b"heh"), t3(b"hei"), t3(b"hem"),
+    t3(b"hgm"), t3(b"hgw"), t3(b"hhi"), t3(b"hhr"), t3(b"hhy"), t3(b"hi "), t3(b"hia"), t3(b"hib"),
+    t3(b"hid"), t3(b"hif"), t3(b"hig"), t3(b"hih"), t3(b"hii"), t3(b"hij"), t3(b"hik"), t3(b"hil"),
+    t3(b"him"), t3(b"hio"), t3(b"hir"), t3(b"hit"), t3(b"hiw"), t3(b"hix"), t3(b"hji"), t3(b"hka"),
+    t3(b"hke"), t3(b"hkh"), t3(b"hkk"), t3(b"hkn"), t3(b"hks"), t3(b"hla"), t3(b"hlb"), t3(b"hld"),
+    t3(b"hle"), t3(b"hlt"), t3(b"hlu"), t3(b"hma"), t3(b"hmb"), t3(b"hmc"), t3(b"hmd"), t3(b"hme"),
+    t3(b"hmf"), t3(b"hmg"), t3(b"hmh"), t3(b"hmi"), t3(b"hmj"), t3(b"hmk"), t3(b"hml"), t3(b"hmm"),
+    t3(b"hmn"), t3(b"hmp"), t3(b"hmq"), t3(b"hmr"), t3(b"hms"), t3(b"hmt"), t3(b"hmu"), t3(b"hmv"),
+    t3(b"hmw"), t3(b"hmx"), t3(b"hmy"), t3(b"hmz"), t3(b"hna"), t3(b"hnd"), t3(b"hne"), t3(b"hng"),
+    t3(b"hnh"), t3(b"hni"), t3(b"hnj"), t3(b"hnn"), t3(b"hno"), t3(b"hns"), t3(b"hnu"), t3(b"ho "),
+    t3(b"hoa"), t3(b"hob"), t3(b"hoc"), t3(b"hod"), t3(b"hoe"), t3(b"hoh"), t3(b"hoi"), t3(b"hoj"),
+    t3(b"hok"), t3(b"hol"), t3(b"hom"), t3(b"hoo"), t3(b"hop"), t3(b"hor"), t3(b"hos"), t3(b"hot"),
+    t3(b"hov"), t3(b"how"), t3(b"hoy"), t3(b"hoz"), t3(b"hpo"), t3(b"hps"), t3(b"hr "), t3(b"hra"),
+    t3(b"hrc"), t3(b"hre"), t3(b"hrk"), t3(b"hrm"), t3(b"hro"), t3(b"hrp"), t3(b"hrr"), t3(b"hrt"),
+    t3(b"hru"), t3(b"hrw"), t3(b"hrx"), t3(b"hrz"), t3(b"hsb"), t3(b"hsh"), t3(b"hsl"), t3(b"hsn"),
+    t3(b"hss"), t3(b"ht "), t3(b"hti"), t3(b"hto"), t3(b"hts"), t3(b"htu"), t3(b"htx"), t3(b"hu "),
+    t3(b"hub"), t3(b"huc"), t3(b"hud"), t3(b"hue"), t3(b"huf"), t3(b"hug"), t3(b"huh"), t3(b"hui"),
+    t3(b"huj"), t3(b"huk"), t3(b"hul"), t3(b"hum"), t3(b"huo"), t3(b"hup"), t3(b"huq"), t3(b"hur"),
+    t3(b"hus"), t3(b"hut"), t3(b"huu"), t3(b"huv"), t3(b"huw"), t3(b"hux"), t3(b"huy"), t3(b"huz"),
+    t3(b"hvc"), t3(b"hve"), t3(b"hvk"), t3(b"hvn"), t3(b"hvv"), t3(b"hwa"), t3(b"hwc"), t3(b"hwo"),
+    t3(b"hy "), t3(b"hya"), t3(b"hyw"), t3(b"hyx"), t3(b"hz "), t3(b"ia "), t3(b"iai"), t3(b"ian"),
+    t3(b"iap"), t3(b"iar"), t3(b"iba"), t3(b"ibb"), t3(b"ibd"), t3(b"ibe"), t3(b"ibg"), t3(b"ibh"),
+    t3(b"ibi"), t3(b"ibl"), t3(b"ibm"), t3(b"ibn"), t3(b"ibr"), t3(b"ibu"), t3(b"iby"), t3(b"ica"),
+    t3(b"ich"), t3(b"icl"), t3(b"icr"), t3(b"id "), t3(b"ida"), t3(b"idb"), t3(b"idc"), t3(b"idd"),
+    t3(b"ide"), t3(b"idi"), t3(b"idr"), t3(b"ids"), t3(b"idt"), t3(b"idu"), t3(b"ie "), t3(b"ifa"),
+    t3(b"ifb"), t3(b"ife"), t3(b"iff"), t3(b"ifk"), t3(b"ifm"), t3(b"ifu"), t3(b"ify"), t3(b"ig "),
+    t3(b"igb"), t3(b"ige"), t3(b"igg"), t3(b"igl"), t3(b"igm"), t3(b"ign"), t3(b"igo"), t3(b"igs"),
+    t3(b"igw"), t3(b"ihb"), t3(b"ihi"), t3(b"ihp"), t3(b"ihw"), t3(b"ii "), t3(b"iin"), t3(b"iir"),
+    t3(b"ijc"), t3(b"ije"), t3(b"ijj"), t3(b"ijn"), t3(b"ijo"), t3(b"ijs"), t3(b"ik "), t3(b"ike"),
+    t3(b"iki"), t3(b"ikk"), t3(b"ikl"), t3(b"iko"), t3(b"ikp"), t3(b"ikr"), t3(b"iks"), t3(b"ikt"),
+    t3(b"ikv"), t3(b"ikw"), t3(b"ikx"), t3(b"ikz"), t3(b"ila"), t3(b"ilb"), t3(b"ilg"), t3(b"ili"),
+    t3(b"ilk"), t3(b"ill"), t3(b"ilm"), t3(b"ilo"), t3(b"ilp"), t3(b"ils"), t3(b"ilu"), t3(b"ilv"),
+    t3(b"ilw"), t3(b"ima"), t3(b"ime"), t3(b"imi"), t3(b"iml"), t3(b"imn"), t3(b"imo"), t3(b"imr"),
+    t3(b"ims"), t3(b"imy"), t3(b"in "), t3(b"inb"), t3(b"inc"), t3(b"ine"), t3(b"ing"), t3(b"inh"),
+    t3(b"inj"), t3(b"inl"), t3(b"inm"), t3(b"inn"), t3(b"ino"), t3(b"inp"), t3(b"ins"), t3(b"int"),
+    t3(b"inz"), t3(b"io "), t3(b"ior"), t3(b"iou"), t3(b"iow"), t3(b"ipi"), t3(b"ipo"), t3(b"iqu"),
+    t3(b"iqw"), t3(b"ira"), t3(b"ire"), t3(b"irh"), t3(b"iri"), t3(b"irk"), t3(b"irn"), t3(b"iro"),
+    t3(b"irr"), t3(b"iru"), t3(b"irx"), t3(b"iry"), t3(b"is "), t3(b"isa"), t3(b"isc"), t3(b"isd"),
+    t3(b"ise"), t3(b"isg"), t3(b"ish"), t3(b"isi"), t3(b"isk"), t3(b"ism"), t3(b"isn"), t3(b"iso"),
+    t3(b"isr"), t3(b"ist"), t3(b"isu"), t3(b"it "), t3(b"itb"), t3(b"itc"), t3(b"itd"), t3(b"ite"),
+    t3(b"iti"), t3(b"itk"), t3(b"itl"), t3(b"itm"), t3(b"ito"), t3(b"itr"), t3(b"its"), t3(b"itt"),
+    t3(b"itv"), t3(b"itw"), t3(b"itx"), t3(b"ity"), t3(b"itz"), t3(b"iu "), t3(b"ium"), t3(b"ivb"),
+    t3(b"ivv"), t3(b"iw "), t3(b"iwk"), t3(b"iwm"), t3(b"iwo"), t3(b"iws"), t3(b"ixc"), t3(b"ixl"),
+    t3(b"iya"), t3(b"iyo"), t3(b"iyx"), t3(b"izh"), t3(b"izi"), t3(b"izr"), t3(b"izz"), t3(b"ja "),
+    t3(b"jaa"), t3(b"jab"), t3(b"jac"), t3(b"jad"), t3(b"jae"), t3(b"jaf"), t3(b"jah"), t3(b"jaj"),
+    t3(b"jak"), t3(b"jal"), t3(b"jam"), t3(b"jan"), t3(b"jao"), t3(b"jaq"), t3(b"jar"), t3(b"jas"),
+    t3(b"jat"), t3(b"jau"), t3(b"jax"), t3(b"jay"), t3(b"jaz"), t3(b"jbe"), t3(b"jbi"), t3(b"jbj"),
+    t3(b"jbk"), t3(b"jbm"), t3(b"jbn"), t3(b"jbo"), t3(b"jbr"), t3(b"jbt"), t3(b"jbu"), t3(b"jbw"),
+    t3(b"jcs"), t3(b"jct"), t3(b"jda"), t3(b"jdg"), t3(b"jdt"), t3(b"jeb"), t3(b"jee"), t3(b"jeg"),
+    t3(b"jeh"), t3(b"jei"), t3(b"jek"), t3(b"jel"), t3(b"jen"), t3(b"jer"), t3(b"jet"), t3(b"jeu"),
+    t3(b"jgb"), t3(b"jge"), t3(b"jgk"), t3(b"jgo"), t3(b"jhi"), t3(b"jhs"), t3(b"ji "), t3(b"jia"),
+    t3(b"jib"), t3(b"jic"), t3(b"jid"), t3(b"jie"), t3(b"jig"), t3(b"jih"), t3(b"jii"), t3(b"jil"),
+    t3(b"jim"), t3(b"jio"), t3(b"jiq"), t3(b"jit"), t3(b"jiu"), t3(b"jiv"), t3(b"jiy"), t3(b"jje"),
+    t3(b"jjr"), t3(b"jka"), t3(b"jkm"), t3(b"jko"), t3(b"jkp"), t3(b"jkr"), t3(b"jks"), t3(b"jku"),
+    t3(b"jle"), t3(b"jls"), t3(b"jma"), t3(b"jmb"), t3(b"jmc"), t3(b"jmd"), t3(b"jmi"), t3(b"jml"),
+    t3(b"jmn"), t3(b"jmr"), t3(b"jms"), t3(b"jmw"), t3(b"jmx"), t3(b"jna"), t3(b"jnd"), t3(b"jng"),
+    t3(b"jni"), t3(b"jnj"), t3(b"jnl"), t3(b"jns"), t3(b"job"), t3(b"jod"), t3(b"jog"), t3(b"jor"),
+    t3(b"jos"), t3(b"jow"), t3(b"jpa"), t3(b"jpr"), t3(b"jpx"), t3(b"jqr"), t3(b"jra"), t3(b"jrb"),
+    t3(b"jrr"), t3(b"jrt"), t3(b"jru"), t3(b"jsl"), t3(b"jua"), t3(b"jub"), t3(b"juc"), t3(b"jud"),
+    t3(b"juh"), t3(b"jui"), t3(b"juk"), t3(b"jul"), t3(b"jum"), t3(b"jun"), t3(b"juo"), t3(b"jup"),
+    t3(b"jur"), t3(b"jus"), t3(b"jut"), t3(b"juu"), t3(b"juw"), t3(b"juy"), t3(b"jv "), t3(b"jvd"),
+    t3(b"jvn"), t3(b"jw "), t3(b"jwi"), t3(b"jya"), t3(b"jye"), t3(b"jyy"), t3(b"ka "), t3(b"kaa"),
+    t3(b"kab"), t3(b"kac"), t3(b"kad"), t3(b"kae"), t3(b"kaf"), t3(b"kag"), t3(b"kah"), t3(b"kai"),
+    t3(b"kaj"), t3(b"kak"), t3(b"kam"), t3(b"kao"), t3(b"kap"), t3(b"kaq"), t3(b"kar"), t3(b"kav"),
+    t3(b"kaw"), t3(b"kax"), t3(b"kay"), t3(b"kba"), t3(b"kbb"), t3(b"kbc"), t3(b"kbd"), t3(b"kbe"),
+    t3(b"kbf"), t3(b"kbg"), t3(b"kbh"), t3(b"kbi"), t3(b"kbj"), t3(b"kbk"), t3(b"kbl"), t3(b"kbm"),
+    t3(b"kbn"), t3(b"kbo"), t3(b"kbp"), t3(b"kbq"), t3(b"kbr"), t3(b"kbs"), t3(b"kbt"), t3(b"kbu"),
+    t3(b"kbv"), t3(b"kbw"), t3(b"kbx"), t3(b"kby"), t3(b"kbz"), t3(b"kca"), t3(b"kcb"), t3(b"kcc"),
+    t3(b"kcd"), t3(b"kce"), t3(b"kcf"), t3(b"kcg"), t3(b"kch"), t3(b"kci"), t3(b"kcj"), t3(b"kck"),
+    t3(b"kcl"), t3(b"kcm"), t3(b"kcn"), t3(b"kco"), t3(b"kcp"), t3(b"kcq"), t3(b"kcr"), t3(b"kcs"),
+    t3(b"kct"), t3(b"kcu"), t3(b"kcv"), t3(b"kcw"), t3(b"kcx"), t3(b"kcy"), t3(b"kcz"), t3(b"kda"),
+    t3(b"kdc"), t3(b"kdd"), t3(b"kde"), t3(b"kdf"), t3(b"kdg"), t3(b"kdh"), t3(b"kdi"), t3(b"kdj"),
+    t3(b"kdk"), t3(b"kdl"), t3(b"kdm"), t3(b"kdn"), t3(b"kdo"), t3(b"kdp"), t3(b"kdq"), t3(b"kdr"),
+    t3(b"kdt"), t3(b"kdu"), t3(b"kdv"), t3(b"kdw"), t3(b"kdx"), t3(b"kdy"), t3(b"kdz"), t3(b"kea"),
+    t3(b"keb"), t3(b"kec"), t3(b"ked"), t3(b"kee"), t3(b"kef"), t3(b"keg"), t3(b"keh"), t3(b"kei"),
+    t3(b"kej"), t3(b"kek"), t3(b"kel"), t3(b"kem"), t3(b"ken"), t3(b"keo"), t3(b"kep"), t3(b"keq"),
+    t3(b"ker"), t3(b"kes"), t3(b"ket"), t3(b"keu"), t3(b"kev"), t3(b"kew"), t3(b"kex"), t3(b"key"),
+    t3(b"kez"), t3(b"kfa"), t3(b"kfb"), t3(b"kfc"), t3(b"kfd"), t3(b"kfe"), t3(b"kff"), t3(b"kfg"),
+    t3(b"kfh"), t3(b"kfi"), t3(b"kfj"), t3(b"kfk"), t3(b"kfl"), t3(b"kfm"), t3(b"kfn"), t3(b"kfo"),
+    t3(b"kfp"), t3(b"kfq"), t3(b"kfr"), t3(b"kfs"), t3(b"kft"), t3(b"kfu"), t3(b"kfv"), t3(b"kfw"),
+    t3(b"kfx"), t3(b"kfy"), t3(b"kfz"), t3(b"kg "), t3(b"kga"), t3(b"kgb"), t3(b"kgc"), t3(b"kgd"),
+    t3(b"kge"), t3(b"kgf"), t3(b"kgg"), t3(b"kgh"), t3(b"kgi"), t3(b"kgj"), t3(b"kgk"), t3(b"kgl"),
+    t3(b"kgm"), t3(b"kgn"), t3(b"kgo"), t3(b"kgp"), t3(b"kgq"), t3(b"kgr"), t3(b"kgs"), t3(b"kgt"),
+    t3(b"kgu"), t3(b"kgv"), t3(b"kgw"), t3(b"kgx"), t3(b"kgy"), t3(b"kha"), t3(b"khb"), t3(b"khc"),
+    t3(b"khd"), t3(b"khe"), t3(b"khf"), t3(b"khg"), t3(b"khh"), t3(b"khi"), t3(b"khj"), t3(b"khk"),
+    t3(b"khl"), t3(b"khn"), t3(b"kho"), t3(b"khp"), t3(b"khq"), t3(b"khr"), t3(b"khs"), t3(b"kht"),
+    t3(b"khu"), t3(b"khv"), t3(b"khw"), t3(b"khx"), t3(b"khy"), t3(b"khz"), t3(b"ki "), t3(b"kia"),
+    t3(b"kib"), t3(b"kic"), t3(b"kid"), t3(b"kie"), t3(b"kif"), t3(b"kig"), t3(b"kih"), t3(b"kii"),
+    t3(b"kij"), t3(b"kil"), t3(b"kim"), t3(b"kio"), t3(b"kip"), t3(b"kiq"), t3(b"kis"), t3(b"kit"),
+    t3(b"kiu"), t3(b"kiv"), t3(b"kiw"), t3(b"kix"), t3(b"kiy"), t3(b"kiz"), t3(b"kj "), t3(b"kja"),
+    t3(b"kjb"), t3(b"kjc"), t3(b"kjd"), t3(b"kje"), t3(b"kjf"), t3(b"kjg"), t3(b"kjh"), t3(b"kji"),
+    t3(b"kjj"), t3(b"kjk"), t3(b"kjl"), t3(b"kjm"), t3(b"kjn"), t3(b"kjo"), t3(b"kjp"), t3(b"kjq"),
+    t3(b"kjr"), t3(b"kjs"), t3(b"kjt"), t3(b"kju"), t3(b"kjv"), t3(b"kjx"), t3(b"kjy"), t3(b"kjz"),
+    t3(b"kk "), t3(b"kka"), t3(b"kkb"), t3(b"kkc"), t3(b"kkd"), t3(b"kke"), t3(b"kkf"), t3(b"kkg"),
+    t3(b"kkh"), t3(b"kki"), t3(b"kkj"), t3(b"kkk"), t3(b"kkl"), t3(b"kkm"), t3(b"kkn"), t3(b"kko"),
+    t3(b"kkp"), t3(b"kkq"), t3(b"kkr"), t3(b"kks"), t3(b"kkt"), t3(b"kku"), t3(b"kkv"), t3(b"kkw"),
+    t3(b"kkx"), t3(b"kky"), t3(b"kkz"), t3(b"kl "), t3(b"kla"), t3(b"klb"), t3(b"klc"), t3(b"kld"),
+    t3(b"kle"), t3(b"klf"), t3(b"klg"), t3(b"klh"), t3(b"kli"), t3(b"klj"), t3(b"klk"), t3(b"kll"),
+    t3(b"klm"), t3(b"kln"), t3(b"klo"), t3(b"klp"), t3(b"klq"), t3(b"klr"), t3(b"kls"), t3(b"klt"),
+    t3(b"klu"), t3(b"klv"), t3(b"klw"), t3(b"klx"), t3(b"kly"), t3(b"klz"), t3(b"km "), t3(b"kma"),
+    t3(b"kmb"), t3(b"kmc"), t3(b"kmd"), t3(b"kme"), t3(b"kmf"), t3(b"kmg"), t3(b"kmh"), t3(b"kmi"),
+    t3(b"kmj"), t3(b"kmk"), t3(b"kml"), t3(b"kmm"), t3(b"kmn"), t3(b"kmo"), t3(b"kmp"), t3(b"kmq"),
+    t3(b"kmr"), t3(b"kms"), t3(b"kmt"), t3(b"kmu"), t3(b"kmv"), t3(b"kmw"), t3(b"kmx"), t3(b"kmy"),
+    t3(b"kmz"), t3(b"kn "), t3(b"kna"), t3(b"knb"), t3(b"knc"), t3(b"knd"), t3(b"kne"), t3(b"knf"),
+    t3(b"kng"), t3(b"kni"), t3(b"knj"), t3(b"knk"), t3(b"knl"), t3(b"knm"), t3(b"knn"), t3(b"kno"),
+    t3(b"knp"), t3(b"knq"), t3(b"knr"), t3(b"kns"), t3(b"knt"), t3(b"knu"), t3(b"knv"), t3(b"knw"),
+    t3(b"knx"), t3(b"kny"), t3(b"knz"), t3(b"ko "), t3(b"koa"), t3(b"koc"), t3(b"kod"), t3(b"koe"),
+    t3(b"kof"), t3(b"kog"), t3(b"koh"), t3(b"koi"), t3(b"koj"), t3(b"kok"), t3(b"kol"), t3(b"koo"),
+    t3(b"kop"), t3(b"koq"), t3(b"kos"), t3(b"kot"), t3(b"kou"), t3(b"kov"), t3(b"kow"), t3(b"kox"),
+    t3(b"koy"), t3(b"koz"), t3(b"kpa"), t3(b"kpb"), t3(b"kpc"), t3(b"kpd"), t3(b"kpe"), t3(b"kpf"),
+    t3(b"kpg"), t3(b"kph"), t3(b"kpi"), t3(b"kpj"), t3(b"kpk"), t3(b"kpl"), t3(b"kpm"), t3(b"kpn"),
+    t3(b"kpo"), t3(b"kpp"), t3(b"kpq"), t3(b"kpr"), t3(b"kps"), t3(b"kpt"), t3(b"kpu"), t3(b"kpv"),
+    t3(b"kpw"), t3(b"kpx"), t3(b"kpy"), t3(b"kpz"), t3(b"kqa"), t3(b"kqb"), t3(b"kqc"), t3(b"kqd"),
+    t3(b"kqe"), t3(b"kqf"), t3(b"kqg"), t3(b"kqh"), t3(b"kqi"), t3(b"kqj"), t3(b"kqk"), t3(b"kql"),
+    t3(b"kqm"), t3(b"kqn"), t3(b"kqo"), t3(b"kqp"), t3(b"kqq"), t3(b"kqr"), t3(b"kqs"), t3(b"kqt"),
+    t3(b"kqu"), t3(b"kqv"), t3(b"kqw"), t3(b"kqx"), t3(b"kqy"), t3(b"kqz"), t3(b"kr "), t3(b"kra"),
+    t3(b"krb"), t3(b"krc"), t3(b"krd"), t3(b"kre"), t3(b"krf"), t3(b"krh"), t3(b"kri"), t3(b"krj"),
+    t3(b"krk"), t3(b"krl"), t3(b"krm"), t3(b"krn"), t3(b"kro"), t3(b"krp"), t3(b"krr"), t3(b"krs"),
+    t3(b"krt"), t3(b"kru"), t3(b"krv"), t3(b"krw"), t3(b"krx"), t3(b"kry"), t3(b"krz"), t3(b"ks "),
+    t3(b"ksa"), t3(b"ksb"), t3(b"ksc"), t3(b"ksd"), t3(b"kse"), t3(b"ksf"), t3(b"ksg"), t3(b"ksh"),
+    t3(b"ksi"), t3(b"ksj"), t3(b"ksk"), t3(b"ksl"), t3(b"ksm"), t3(b"ksn"), t3(b"kso"), t3(b"ksp"),
+    t3(b"ksq"), t3(b"ksr"), t3(b"kss"), t3(b"kst"), t3(b"ksu"), t3(b"ksv"), t3(b"ksw"), t3(b"ksx"),
+    t3(b"ksy"), t3(b"ksz"), t3(b"kta"), t3(b"ktb"), t3(b"ktc"), t3(b"ktd"), t3(b"kte"), t3(b"ktf"),
+    t3(b"ktg"), t3(b"kth"), t3(b"kti"), t3(b"ktj"), t3(b"ktk"), t3(b"ktl"), t3(b"ktm"), t3(b"ktn"),
+    t3(b"kto"), t3(b"ktp"), t3(b"ktq"), t3(b"ktr"), t3(b"kts"), t3(b"ktt"), t3(b"ktu"), t3(b"ktv"),
+    t3(b"ktw"), t3(b"ktx"), t3(b"kty"), t3(b"ktz"), t3(b"ku "), t3(b"kub"), t3(b"kuc"), t3(b"kud"),
+    t3(b"kue"), t3(b"kuf"), t3(b"kug"), t3(b"kuh"), t3(b"kui"), t3(b"kuj"), t3(b"kuk"), t3(b"kul"),
+    t3(b"kum"), t3(b"kun"), t3(b"kuo"), t3(b"kup"), t3(b"kuq"), t3(b"kus"), t3(b"kut"), t3(b"kuu"),
+    t3(b"kuv"), t3(b"kuw"), t3(b"kux"), t3(b"kuy"), t3(b"kuz"), t3(b"kv "), t3(b"kva"), t3(b"kvb"),
+    t3(b"kvc"), t3(b"kvd"), t3(b"kve"), t3(b"kvf"), t3(b"kvg"), t3(b"kvh"), t3(b"kvi"), t3(b"kvj"),
+    t3(b"kvk"), t3(b"kvl"), t3(b"kvm"), t3(b"kvn"), t3(b"kvo"), t3(b"kvp"), t3(b"kvq"), t3(b"kvr"),
+    t3(b"kvs"), t3(b"kvt"), t3(b"kvu"), t3(b"kvv"), t3(b"kvw"), t3(b"kvx"), t3(b"kvy"), t3(b"kvz"),
+    t3(b"kw "), t3(b"kwa"), t3(b"kwb"), t3(b"kwc"), t3(b"kwd"), t3(b"kwe"), t3(b"kwf"), t3(b"kwg"),
+    t3(b"kwh"), t3(b"kwi"), t3(b"kwj"), t3(b"kwk"), t3(b"kwl"), t3(b"kwm"), t3(b"kwn"), t3(b"kwo"),
+    t3(b"kwp"), t3(b"kwq"), t3(b"kwr"), t3(b"kws"), t3(b"kwt"), t3(b"kwu"), t3(b"kwv"), t3(b"kww"),
+    t3(b"kwx"), t3(b"kwy"), t3(b"kwz"), t3(b"kxa"), t3(b"kxb"), t3(b"kxc"), t3(b"kxd"), t3(b"kxe"),
+    t3(b"kxf"), t3(b"kxh"), t3(b"kxi"), t3(b"kxj"), t3(b"kxk"), t3(b"kxl"), t3(b"kxm"), t3(b"kxn"),
+    t3(b"kxo"), t3(b"kxp"), t3(b"kxq"), t3(b"kxr"), t3(b"kxs"), t3(b"kxt"), t3(b"kxu"), t3(b"kxv"),
+    t3(b"kxw"), t3(b"kxx"), t3(b"kxy"), t3(b"kxz"), t3(b"ky "), t3(b"kya"), t3(b"kyb"), t3(b"kyc"),
+    t3(b"kyd"), t3(b"kye"), t3(b"kyf"), t3(b"kyg"), t3(b"kyh"), t3(b"kyi"), t3(b"kyj"), t3(b"kyk"),
+    t3(b"kyl"), t3(b"kym"), t3(b"kyn"), t3(b"kyo"), t3(b"kyp"), t3(b"kyq"), t3(b"kyr"), t3(b"kys"),
+    t3(b"kyt"), t3(b"kyu"), t3(b"kyv"), t3(b"kyw"), t3(b"kyx"), t3(b"kyy"), t3(b"kyz"), t3(b"kza"),
+    t3(b"kzb"), t3(b"kzc"), t3(b"kzd"), t3(b"kze"), t3(b"kzf"), t3(b"kzg"), t3(b"kzh"), t3(b"kzi"),
+    t3(b"kzj"), t3(b"kzk"), t3(b"kzl"), t3(b"kzm"), t3(b"kzn"), t3(b"kzo"), t3(b"kzp"), t3(b"kzq"),
+    t3(b"kzr"), t3(b"kzs"), t3(b"kzt"), t3(b"kzu"), t3(b"kzv"), t3(b"kzw"), t3(b"kzx"), t3(b"kzy"),
+    t3(b"kzz"), t3(b"la "), t3(b"laa"), t3(b"lab"), t3(b"lac"), t3(b"lad"), t3(b"lae"), t3(b"laf"),
+    t3(b"lag"), t3(b"lah"), t3(b"lai"), t3(b"laj"), t3(b"lak"), t3(b"lal"), t3(b"lam"), t3(b"lan"),
+    t3(b"lap"), t3(b"laq"), t3(b"lar"), t3(b"las"), t3(b"lau"), t3(b"law"), t3(b"lax"), t3(b"lay"),
+    t3(b"laz"), t3(b"lb "), t3(b"lba"), t3(b"lbb"), t3(b"lbc"), t3(b"lbe"), t3(b"lbf"), t3(b"lbg"),
+    t3(b"lbi"), t3(b"lbj"), t3(b"lbk"), t3(b"lbl"), t3(b"lbm"), t3(b"lbn"), t3(b"lbo"), t3(b"lbq"),
+    t3(b"lbr"), t3(b"lbs"), t3(b"lbt"), t3(b"lbu"), t3(b"lbv"), t3(b"lbw"), t3(b"lbx"), t3(b"lby"),
+    t3(b"lbz"), t3(b"lcc"), t3(b"lcd"), t3(b"lce"), t3(b"lcf"), t3(b"lch"), t3(b"lcl"), t3(b"lcm"),
+    t3(b"lcp"), t3(b"lcq"), t3(b"lcs"), t3(b"lda"), t3(b"ldb"), t3(b"ldd"), t3(b"ldg"), t3(b"ldh"),
+    t3(b"ldi"), t3(b"ldj"), t3(b"ldk"), t3(b"ldl"), t3(b"ldm"), t3(b"ldn"), t3(b"ldo"), t3(b"ldp"),
+    t3(b"ldq"), t3(b"lea"), t3(b"leb"), t3(b"lec"), t3(b"led"), t3(b"lee"), t3(b"lef"), t3(b"leg"),
+    t3(b"leh"), t3(b"lei"), t3(b"lej"), t3(b"lek"), t3(b"lel"), t3(b"lem"), t3(b"len"), t3(b"leo"),
+    t3(b"lep"), t3(b"leq"), t3(b"ler"), t3(b"les"), t3(b"let"), t3(b"leu"), t3(b"lev"), t3(b"lew"),
+    t3(b"lex"), t3(b"ley"), t3(b"lez"), t3(b"lfa"), t3(b"lfn"), t3(b"lg "), t3(b"lga"), t3(b"lgb"),
+    t3(b"lgg"), t3(b"lgh"), t3(b"lgi"), t3(b"lgk"), t3(b"lgl"), t3(b"lgm"), t3(b"lgn"), t3(b"lgq"),
+    t3(b"lgr"), t3(b"lgt"), t3(b"lgu"), t3(b"lgz"), t3(b"lha"), t3(b"lhh"), t3(b"lhi"), t3(b"lhl"),
+    t3(b"lhm"), t3(b"lhn"), t3(b"lhp"), t3(b"lhs"), t3(b"lht"), t3(b"lhu"), t3(b"li "), t3(b"lia"),
+    t3(b"lib"), t3(b"lic"), t3(b"lid"), t3(b"lie"), t3(b"lif"), t3(b"lig"), t3(b"lih"), t3(b"lii"),
+    t3(b"lij"), t3(b"lik"), t3(b"lil"), t3(b"lio"), t3(b"lip"), t3(b"liq"), t3(b"lir"), t3(b"lis"),
+    t3(b"liu"), t3(b"liv"), t3(b"liw"), t3(b"lix"), t3(b"liy"), t3(b"liz"), t3(b"lja"), t3(b"lje"),
+    t3(b"lji"), t3(b"ljl"), t3(b"ljp"), t3(b"ljw"), t3(b"ljx"), t3(b"lka"), t3(b"lkb"), t3(b"lkc"),
+    t3(b"lkd"), t3(b"lke"), t3(b"lkh"), t3(b"lki"), t3(b"lkj"), t3(b"lkl"), t3(b"lkm"), t3(b"lkn"),
+    t3(b"lko"), t3(b"lkr"), t3(b"lks"), t3(b"lkt"), t3(b"lku"), t3(b"lky"), t3(b"lla"), t3(b"llb"),
+    t3(b"llc"), t3(b"lld"), t3(b"lle"), t3(b"llf"), t3(b"llg"), t3(b"llh"), t3(b"lli"), t3(b"llj"),
+    t3(b"llk"), t3(b"lll"), t3(b"llm"), t3(b"lln"), t3(b"llo"), t3(b"llp"), t3(b"llq"), t3(b"lls"),
+    t3(b"llu"), t3(b"llx"), t3(b"lma"), t3(b"lmb"), t3(b"lmc"), t3(b"lmd"), t3(b"lme"), t3(b"lmf"),
+    t3(b"lmg"), t3(b"lmh"), t3(b"lmi"), t3(b"lmj"), t3(b"lmk"), t3(b"lml"), t3(b"lmm"), t3(b"lmn"),
+    t3(b"lmo"), t3(b"lmp"), t3(b"lmq"), t3(b"lmr"), t3(b"lmu"), t3(b"lmv"), t3(b"lmw"), t3(b"lmx"),
+    t3(b"lmy"), t3(b"lmz"), t3(b"ln "), t3(b"lna"), t3(b"lnb"), t3(b"lnd"), t3(b"lng"), t3(b"lnh"),
+    t3(b"lni"), t3(b"lnj"), t3(b"lnl"), t3(b"lnm"), t3(b"lnn"), t3(b"lno"), t3(b"lns"), t3(b"lnu"),
+    t3(b"lnw"), t3(b"lnz"), t3(b"lo "), t3(b"loa"), t3(b"lob"), t3(b"loc"), t3(b"loe"), t3(b"lof"),
+    t3(b"log"), t3(b"loh"), t3(b"loi"), t3(b"loj"), t3(b"lok"), t3(b"lol"), t3(b"lom"), t3(b"lon"),
+    t3(b"loo"), t3(b"lop"), t3(b"loq"), t3(b"lor"), t3(b"los"), t3(b"lot"), t3(b"lou"), t3(b"lov"),
+    t3(b"low"), t3(b"lox"), t3(b"loy"), t3(b"loz"), t3(b"lpa"), t3(b"lpe"), t3(b"lpn"), t3(b"lpo"),
+    t3(b"lpx"), t3(b"lra"), t3(b"lrc"), t3(b"lre"), t3(b"lrg"), t3(b"lri"), t3(b"lrk"), t3(b"lrl"),
+    t3(b"lrm"), t3(b"lrn"), t3(b"lro"), t3(b"lrr"), t3(b"lrt"), t3(b"lrv"), t3(b"lrz"), t3(b"lsa"),
+    t3(b"lsb"), t3(b"lsd"), t3(b"lse"), t3(b"lsg"), t3(b"lsh"), t3(b"lsi"), t3(b"lsl"), t3(b"lsm"),
+    t3(b"lsn"), t3(b"lso"), t3(b"lsp"), t3(b"lsr"), t3(b"lss"), t3(b"lst"), t3(b"lsv"), t3(b"lsy"),
+    t3(b"lt "), t3(b"ltc"), t3(b"ltg"), t3(b"lth"), t3(b"lti"), t3(b"ltn"), t3(b"lto"), t3(b"lts"),
+    t3(b"ltu"), t3(b"lu "), t3(b"lua"), t3(b"luc"), t3(b"lud"), t3(b"lue"), t3(b"luf"), t3(b"lui"),
+    t3(b"luj"), t3(b"luk"), t3(b"lul"), t3(b"lum"), t3(b"lun"), t3(b"luo"), t3(b"lup"), t3(b"luq"),
+    t3(b"lur"), t3(b"lus"), t3(b"lut"), t3(b"luu"), t3(b"luv"), t3(b"luw"), t3(b"luy"), t3(b"luz"),
+    t3(b"lv "), t3(b"lva"), t3(b"lvi"), t3(b"lvk"), t3(b"lvs"), t3(b"lvu"), t3(b"lwa"), t3(b"lwe"),
+    t3(b"lwg"), t3(b"lwh"), t3(b"lwl"), t3(b"lwm"), t3(b"lwo"), t3(b"lws"), t3(b"lwt"), t3(b"lwu"),
+    t3(b"lww"), t3(b"lxm"), t3(b"lya"), t3(b"lyg"), t3(b"lyn"), t3(b"lzh"), t3(b"lzl"), t3(b"lzn"),
+    t3(b"lzz"), t3(b"maa"), t3(b"mab"), t3(b"mad"), t3(b"mae"), t3(b"maf"), t3(b"mag"), t3(b"mai"),
+    t3(b"maj"), t3(b"mak"), t3(b"mam"), t3(b"man"), t3(b"map"), t3(b"maq"), t3(b"mas"), t3(b"mat"),
+    t3(b"mau"), t3(b"mav"), t3(b"maw"), t3(b"max"), t3(b"maz"), t3(b"mba"), t3(b"mbb"), t3(b"mbc"),
+    t3(b"mbd"), t3(b"mbe"), t3(b"mbf"), t3(b"mbh"), t3(b"mbi"), t3(b"mbj"), t3(b"mbk"), t3(b"mbl"),
+    t3(b"mbm"), t3(b"mbn"), t3(b"mbo"), t3(b"mbp"), t3(b"mbq"), t3(b"mbr"), t3(b"mbs"), t3(b"mbt"),
+    t3(b"mbu"), t3(b"mbv"), t3(b"mbw"), t3(b"mbx"), t3(b"mby"), t3(b"mbz"), t3(b"mca"), t3(b"mcb"),
+    t3(b"mcc"), t3(b"mcd"), t3(b"mce"), t3(b"mcf"), t3(b"mcg"), t3(b"mch"), t3(b"mci"), t3(b"mcj"),
+    t3(b"mck"), t3(b"mcl"), t3(b"mcm"), t3(b"mcn"), t3(b"mco"), t3(b"mcp"), t3(b"mcq"), t3(b"mcr"),
+    t3(b"mcs"), t3(b"mct"), t3(b"mcu"), t3(b"mcv"), t3(b"mcw"), t3(b"mcx"), t3(b"mcy"), t3(b"mcz"),
+    t3(b"mda"), t3(b"mdb"), t3(b"mdc"), t3(b"mdd"), t3(b"mde"), t3(b"mdf"), t3(b"mdg"), t3(b"mdh"),
+    t3(b"mdi"), t3(b"mdj"), t3(b"mdk"), t3(b"mdl"), t3(b"mdm"), t3(b"mdn"), t3(b"mdp"), t3(b"mdq"),
+    t3(b"mdr"), t3(b"mds"), t3(b"mdt"), t3(b"mdu"), t3(b"mdv"), t3(b"mdw"), t3(b"mdx"), t3(b"mdy"),
+    t3(b"mdz"), t3(b"mea"), t3(b"meb"), t3(b"mec"), t3(b"med"), t3(b"mee"), t3(b"mef"), t3(b"meg"),
+    t3(b"meh"), t3(b"mei"), t3(b"mej"), t3(b"mek"), t3(b"mel"), t3(b"mem"), t3(b"men"), t3(b"meo"),
+    t3(b"mep"), t3(b"meq"), t3(b"mer"), t3(b"mes"), t3(b"met"), t3(b"meu"), t3(b"mev"), t3(b"mew"),
+    t3(b"mey"), t3(b"mez"), t3(b"mfa"), t3(b"mfb"), t3(b"mfc"), t3(b"mfd"), t3(b"mfe"), t3(b"mff"),
+    t3(b"mfg"), t3(b"mfh"), t3(b"mfi"), t3(b"mfj"), t3(b"mfk"), t3(b"mfl"), t3(b"mfm"), t3(b"mfn"),
+    t3(b"mfo"), t3(b"mfp"), t3(b"mfq"), t3(b"mfr"), t3(b"mfs"), t3(b"mft"), t3(b"mfu"), t3(b"mfv"),
+    t3(b"mfw"), t3(b"mfx"), t3(b"mfy"), t3(b"mfz"), t3(b"mg "), t3(b"mga"), t3(b"mgb"), t3(b"mgc"),
+    t3(b"mgd"), t3(b"mge"), t3(b"mgf"), t3(b"mgg"), t3(b"mgh"), t3(b"mgi"), t3(b"mgj"), t3(b"mgk"),
+    t3(b"mgl"), t3(b"mgm"), t3(b"mgn"), t3(b"mgo"), t3(b"mgp"), t3(b"mgq"), t3(b"mgr"), t3(b"mgs"),
+    t3(b"mgt"), t3(b"mgu"), t3(b"mgv"), t3(b"mgw"), t3(b"mgx"), t3(b"mgy"), t3(b"mgz"), t3(b"mh "),
+    t3(b"mha"), t3(b"mhb"), t3(b"mhc"), t3(b"mhd"), t3(b"mhe"), t3(b"mhf"), t3(b"mhg"), t3(b"mhh"),
+    t3(b"mhi"), t3(b"mhj"), t3(b"mhk"), t3(b"mhl"), t3(b"mhm"), t3(b"mhn"), t3(b"mho"), t3(b"mhp"),
+    t3(b"mhq"), t3(b"mhr"), t3(b"mhs"), t3(b"mht"), t3(b"mhu"), t3(b"mhw"), t3(b"mhx"), t3(b"mhy"),
+    t3(b"mhz"), t3(b"mi "), t3(b"mia"), t3(b"mib"), t3(b"mic"), t3(b"mid"), t3(b"mie"), t3(b"mif"),
+    t3(b"mig"), t3(b"mih"), t3(b"mii"), t3(b"mij"), t3(b"mik"), t3(b"mil"), t3(b"mim"), t3(b"min"),
+    t3(b"mio"), t3(b"mip"), t3(b"miq"), t3(b"mir"), t3(b"mis"), t3(b"mit"), t3(b"miu"), t3(b"miw"),
+    t3(b"mix"), t3(b"miy"), t3(b"miz"), t3(b"mja"), t3(b"mjb"), t3(b"mjc"), t3(b"mjd"), t3(b"mje"),
+    t3(b"mjg"), t3(b"mjh"), t3(b"mji"), t3(b"mjj"), t3(b"mjk"), t3(b"mjl"), t3(b"mjm"), t3(b"mjn"),
+    t3(b"mjo"), t3(b"mjp"), t3(b"mjq"), t3(b"mjr"), t3(b"mjs"), t3(b"mjt"), t3(b"mju"), t3(b"mjv"),
+    t3(b"mjw"), t3(b"mjx"), t3(b"mjy"), t3(b"mjz"), t3(b"mk "), t3(b"mka"), t3(b"mkb"), t3(b"mkc"),
+    t3(b"mke"), t3(b"mkf"), t3(b"mkg"), t3(b"mkh"), t3(b"mki"), t3(b"mkj"), t3(b"mkk"), t3(b"mkl"),
+    t3(b"mkm"), t3(b"mkn"), t3(b"mko"), t3(b"mkp"), t3(b"mkq"), t3(b"mkr"), t3(b"mks"), t3(b"mkt"),
+    t3(b"mku"), t3(b"mkv"), t3(b"mkw"), t3(b"mkx"), t3(b"mky"), t3(b"mkz"), t3(b"ml "), t3(b"mla"),
+    t3(b"mlb"), t3(b"mlc"), t3(b"mld"), t3(b"mle"), t3(b"mlf"), t3(b"mlh"), t3(b"mli"), t3(b"mlj"),
+    t3(b"mlk"), t3(b"mll"), t3(b"mlm"), t3(b"mln"), t3(b"mlo"), t3(b"mlp"), t3(b"mlq"), t3(b"mlr"),
+    t3(b"mls"), t3(b"mlu"), t3(b"mlv"), t3(b"mlw"), t3(b"mlx"), t3(b"mlz"), t3(b"mma"), t3(b"mmb"),
+    t3(b"mmc"), t3(b"mmd"), t3(b"mme"), t3(b"mmf"), t3(b"mmg"), t3(b"mmh"), t3(b"mmi"), t3(b"mmj"),
+    t3(b"mmk"), t3(b"mml"), t3(b"mmm"), t3(b"mmn"), t3(b"mmo"), t3(b"mmp"), t3(b"mmq"), t3(b"mmr"),
+    t3(b"mmt"), t3(b"mmu"), t3(b"mmv"), t3(b"mmw"), t3(b"mmx"), t3(b"mmy"), t3(b"mmz"), t3(b"mn "),
+    t3(b"mna"), t3(b"mnb"), t3(b"mnc"), t3(b"mnd"), t3(b"mne"), t3(b"mnf"), t3(b"mng"), t3(b"mnh"),
+    t3(b"mni"), t3(b"mnj"), t3(b"mnk"), t3(b"mnl"), t3(b"mnm"), t3(b"mnn"), t3(b"mno"), t3(b"mnp"),
+    t3(b"mnq"), t3(b"mnr"), t3(b"mns"), t3(b"mnt"), t3(b"mnu"), t3(b"mnv"), t3(b"mnw"), t3(b"mnx"),
+    t3(b"mny"), t3(b"mnz"), t3(b"mo "), t3(b"moa"), t3(b"moc"), t3(b"mod"), t3(b"moe"), t3(b"mof"),
+    t3(b"mog"), t3(b"moh"), t3(b"moi"), t3(b"moj"), t3(b"mok"), t3(b"mom"), t3(b"moo"), t3(b"mop"),
+    t3(b"moq"), t3(b"mor"), t3(b"mos"), t3(b"mot"), t3(b"mou"), t3(b"mov"), t3(b"mow"), t3(b"mox"),
+    t3(b"moy"), t3(b"moz"), t3(b"mpa"), t3(b"mpb"), t3(b"mpc"), t3(b"mpd"), t3(b"mpe"), t3(b"mpg"),
+    t3(b"mph"), t3(b"mpi"), t3(b"mpj"), t3(b"mpk"), t3(b"mpl"), t3(b"mpm"), t3(b"mpn"), t3(b"mpo"),
+    t3(b"mpp"), t3(b"mpq"), t3(b"mpr"), t3(b"mps"), t3(b"mpt"), t3(b"mpu"), t3(b"mpv"), t3(b"mpw"),
+    t3(b"mpx"), t3(b"mpy"), t3(b"mpz"), t3(b"mqa"), t3(b"mqb"), t3(b"mqc"), t3(b"mqe"), t3(b"mqf"),
+    t3(b"mqg"), t3(b"mqh"), t3(b"mqi"), t3(b"mqj"), t3(b"mqk"), t3(b"mql"), t3(b"mqm"), t3(b"mqn"),
+    t3(b"mqo"), t3(b"mqp"), t3(b"mqq"), t3(b"mqr"), t3(b"mqs"), t3(b"mqt"), t3(b"mqu"), t3(b"mqv"),
+    t3(b"mqw"), t3(b"mqx"), t3(b"mqy"), t3(b"mqz"), t3(b"mr "), t3(b"mra"), t3(b"mrb"), t3(b"mrc"),
+    t3(b"mrd"), t3(b"mre"), t3(b"mrf"), t3(b"mrg"), t3(b"mrh"), t3(b"mrj"), t3(b"mrk"), t3(b"mrl"),
+    t3(b"mrm"), t3(b"mrn"), t3(b"mro"), t3(b"mrp"), t3(b"mrq"), t3(b"mrr"), t3(b"mrs"), t3(b"mrt"),
+    t3(b"mru"), t3(b"mrv"), t3(b"mrw"), t3(b"mrx"), t3(b"mry"), t3(b"mrz"), t3(b"ms "), t3(b"msb"),
+    t3(b"msc"), t3(b"msd"), t3(b"mse"), t3(b"msf"), t3(b"msg"), t3(b"msh"), t3(b"msi"), t3(b"msj"),
+    t3(b"msk"), t3(b"msl"), t3(b"msm"), t3(b"msn"), t3(b"mso"), t3(b"msp"), t3(b"msq"), t3(b"msr"),
+    t3(b"mss"), t3(b"mst"), t3(b"msu"), t3(b"msv"), t3(b"msw"), t3(b"msx"), t3(b"msy"), t3(b"msz"),
+    t3(b"mt "), t3(b"mta"), t3(b"mtb"), t3(b"mtc"), t3(b"mtd"), t3(b"mte"), t3(b"mtf"), t3(b"mtg"),
+    t3(b"mth"), t3(b"mti"), t3(b"mtj"), t3(b"mtk"), t3(b"mtl"), t3(b"mtm"), t3(b"mtn"), t3(b"mto"),
+    t3(b"mtp"), t3(b"mtq"), t3(b"mtr"), t3(b"mts"), t3(b"mtt"), t3(b"mtu"), t3(b"mtv"), t3(b"mtw"),
+    t3(b"mtx"), t3(b"mty"), t3(b"mua"), t3(b"mub"), t3(b"muc"), t3(b"mud"), t3(b"mue"), t3(b"mug"),
+    t3(b"muh"), t3(b"mui"), t3(b"muj"), t3(b"muk"), t3(b"mul"), t3(b"mum"), t3(b"mun"), t3(b"muo"),
+    t3(b"mup"), t3(b"muq"), t3(b"mur"), t3(b"mus"), t3(b"mut"), t3(b"muu"), t3(b"muv"), t3(b"mux"),
+    t3(b"muy"), t3(b"muz"), t3(b"mva"), t3(b"mvb"), t3(b"mvd"), t3(b"mve"), t3(b"mvf"), t3(b"mvg"),
+    t3(b"mvh"), t3(b"mvi"), t3(b"mvk"), t3(b"mvl"), t3(b"mvm"), t3(b"mvn"), t3(b"mvo"), t3(b"mvp"),
+    t3(b"mvq"), t3(b"mvr"), t3(b"mvs"), t3(b"mvt"), t3(b"mvu"), t3(b"mvv"), t3(b"mvw"), t3(b"mvx"),
+    t3(b"mvy"), t3(b"mvz"), t3(b"mwa"), t3(b"mwb"), t3(b"mwc"), t3(b"mwd"), t3(b"mwe"), t3(b"mwf"),
+    t3(b"mwg"), t3(b"mwh"), t3(b"mwi"), t3(b"mwj"), t3(b"mwk"), t3(b"mwl"), t3(b"mwm"), t3(b"mwn"),
+    t3(b"mwo"), t3(b"mwp"), t3(b"mwq"), t3(b"mwr"), t3(b"mws"), t3(b"mwt"), t3(b"mwu"), t3(b"mwv"),
+    t3(b"mww"), t3(b"mwx"), t3(b"mwy"), t3(b"mwz"), t3(b"mxa"), t3(b"mxb"), t3(b"mxc"), t3(b"mxd"),
+    t3(b"mxe"), t3(b"mxf"), t3(b"mxg"), t3(b"mxh"), t3(b"mxi"), t3(b"mxj"), t3(b"mxk"), t3(b"mxl"),
+    t3(b"mxm"), t3(b"mxn"), t3(b"mxo"), t3(b"mxp"), t3(b"mxq"), t3(b"mxr"), t3(b"mxs"), t3(b"mxt"),
+    t3(b"mxu"), t3(b"mxv"), t3(b"mxw"), t3(b"mxx"), t3(b"mxy"), t3(b"mxz"), t3(b"my "), t3(b"myb"),
+    t3(b"myc"), t3(b"myd"), t3(b"mye"), t3(b"myf"), t3(b"myg"), t3(b"myh"), t3(b"myi"), t3(b"myj"),
+    t3(b"myk"), t3(b"myl"), t3(b"mym"), t3(b"myn"), t3(b"myo"), t3(b"myp"), t3(b"myq"), t3(b"myr"),
+    t3(b"mys"), t3(b"myt"), t3(b"myu"), t3(b"myv"), t3(b"myw"), t3(b"myx"), t3(b"myy"), t3(b"myz"),
+    t3(b"mza"), t3(b"mzb"), t3(b"mzc"), t3(b"mzd"), t3(b"mze"), t3(b"mzg"), t3(b"mzh"), t3(b"mzi"),
+    t3(b"mzj"), t3(b"mzk"), t3(b"mzl"), t3(b"mzm"), t3(b"mzn"), t3(b"mzo"), t3(b"mzp"), t3(b"mzq"),
+    t3(b"mzr"), t3(b"mzs"), t3(b"mzt"), t3(b"mzu"), t3(b"mzv"), t3(b"mzw"), t3(b"mzx"), t3(b"mzy"),
+    t3(b"mzz"), t3(b"na "), t3(b"naa"), t3(b"nab"), t3(b"nac"), t3(b"nad"), t3(b"nae"), t3(b"naf"),
+    t3(b"nag"), t3(b"nah"), t3(b"nai"), t3(b"naj"), t3(b"nak"), t3(b"nal"), t3(b"nam"), t3(b"nan"),
+    t3(b"nao"), t3(b"nap"), t3(b"naq"), t3(b"nar"), t3(b"nas"), t3(b"nat"), t3(b"naw"), t3(b"nax"),
+    t3(b"nay"), t3(b"naz"), t3(b"nb "), t3(b"nba"), t3(b"nbb"), t3(b"nbc"), t3(b"nbd"), t3(b"nbe"),
+    t3(b"nbf"), t3(b"nbg"), t3(b"nbh"), t3(b"nbi"), t3(b"nbj"), t3(b"nbk"), t3(b"nbm"), t3(b"nbn"),
+    t3(b"nbo"), t3(b"nbp"), t3(b"nbq"), t3(b"nbr"), t3(b"nbs"), t3(b"nbt"), t3(b"nbu"), t3(b"nbv"),
+    t3(b"nbw"), t3(b"nbx"), t3(b"nby"), t3(b"nca"), t3(b"ncb"), t3(b"ncc"), t3(b"ncd"), t3(b"nce"),
+    t3(b"ncf"), t3(b"ncg"), t3(b"nch"), t3(b"nci"), t3(b"ncj"), t3(b"nck"), t3(b"ncl"), t3(b"ncm"),
+    t3(b"ncn"), t3(b"nco"), t3(b"ncp"), t3(b"ncq"), t3(b"ncr"), t3(b"ncs"), t3(b"nct"), t3(b"ncu"),
+    t3(b"ncx"), t3(b"ncz"), t3(b"nd "), t3(b"nda"), t3(b"ndb"), t3(b"ndc"), t3(b"ndd"), t3(b"ndf"),
+    t3(b"ndg"), t3(b"ndh"), t3(b"ndi"), t3(b"ndj"), t3(b"ndk"), t3(b"ndl"), t3(b"ndm"), t3(b"ndn"),
+    t3(b"ndp"), t3(b"ndq"), t3(b"ndr"), t3(b"nds"), t3(b"ndt"), t3(b"ndu"), t3(b"ndv"), t3(b"ndw"),
+    t3(b"ndx"), t3(b"ndy"), t3(b"ndz"), t3(b"ne "), t3(b"nea"), t3(b"neb"), t3(b"nec"), t3(b"ned"),
+    t3(b"nee"), t3(b"nef"), t3(b"neg"), t3(b"neh"), t3(b"nei"), t3(b"nej"), t3(b"nek"), t3(b"nem"),
+    t3(b"nen"), t3(b"neo"), t3(b"neq"), t3(b"ner"), t3(b"nes"), t3(b"net"), t3(b"neu"), t3(b"nev"),
+    t3(b"new"), t3(b"nex"), t3(b"ney"), t3(b"nez"), t3(b"nfa"), t3(b"nfd"), t3(b"nfl"), t3(b"nfr"),
+    t3(b"nfu"), t3(b"ng "), t3(b"nga"), t3(b"ngb"), t3(b"ngc"), t3(b"ngd"), t3(b"nge"), t3(b"ngf"),
+    t3(b"ngg"), t3(b"ngh"), t3(b"ngi"), t3(b"ngj"), t3(b"ngk"), t3(b"ngl"), t3(b"ngm"), t3(b"ngn"),
+    t3(b"ngo"), t3(b"ngp"), t3(b"ngq"), t3(b"ngr"), t3(b"ngs"), t3(b"ngt"), t3(b"ngu"), t3(b"ngv"),
+    t3(b"ngw"), t3(b"ngx"), t3(b"ngy"), t3(b"ngz"), t3(b"nha"), t3(b"nhb"), t3(b"nhc"), t3(b"nhd"),
+    t3(b"nhe"), t3(b"nhf"), t3(b"nhg"), t3(b"nhh"), t3(b"nhi"), t3(b"nhk"), t3(b"nhm"), t3(b"nhn"),
+    t3(b"nho"), t3(b"nhp"), t3(b"nhq"), t3(b"nhr"), t3(b"nht"), t3(b"nhu"), t3(b"nhv"), t3(b"nhw"),
+    t3(b"nhx"), t3(b"nhy"), t3(b"nhz"), t3(b"nia"), t3(b"nib"), t3(b"nic"), t3(b"nid"), t3(b"nie"),
+    t3(b"nif"), t3(b"nig"), t3(b"nih"), t3(b"nii"), t3(b"nij"), t3(b"nik"), t3(b"nil"), t3(b"nim"),
+    t3(b"nin"), t3(b"nio"), t3(b"niq"), t3(b"nir"), t3(b"nis"), t3(b"nit"), t3(b"niu"), t3(b"niv"),
+    t3(b"niw"), t3(b"nix"), t3(b"niy"), t3(b"niz"), t3(b"nja"), t3(b"njb"), t3(b"njd"), t3(b"njh"),
+    t3(b"nji"), t3(b"njj"), t3(b"njl"), t3(b"njm"), t3(b"njn"), t3(b"njo"), t3(b"njr"), t3(b"njs"),
+    t3(b"njt"), t3(b"nju"), t3(b"njx"), t3(b"njy"), t3(b"njz"), t3(b"nka"), t3(b"nkb"), t3(b"nkc"),
+    t3(b"nkd"), t3(b"nke"), t3(b"nkf"), t3(b"nkg"), t3(b"nkh"), t3(b"nki"), t3(b"nkj"), t3(b"nkk"),
+    t3(b"nkm"), t3(b"nkn"), t3(b"nko"), t3(b"nkp"), t3(b"nkq"), t3(b"nkr"), t3(b"nks"), t3(b"nkt"),
+    t3(b"nku"), t3(b"nkv"), t3(b"nkw"), t3(b"nkx"), t3(b"nkz"), t3(b"nl "), t3(b"nla"), t3(b"nlc"),
+    t3(b"nle"), t3(b"nlg"), t3(b"nli"), t3(b"nlj"), t3(b"nlk"), t3(b"nll"), t3(b"nlm"), t3(b"nln"),
+    t3(b"nlo"), t3(b"nlq"), t3(b"nlr"), t3(b"nlu"), t3(b"nlv"), t3(b"nlw"), t3(b"nlx"), t3(b"nly"),
+    t3(b"nlz"), t3(b"nma"), t3(b"nmb"), t3(b"nmc"), t3(b"nmd"), t3(b"nme"), t3(b"nmf"), t3(b"nmg"),
+    t3(b"nmh"), t3(b"nmi"), t3(b"nmj"), t3(b"nmk"), t3(b"nml"), t3(b"nmm"), t3(b"nmn"), t3(b"nmo"),
+    t3(b"nmp"), t3(b"nmq"), t3(b"nmr"), t3(b"nms"), t3(b"nmt"), t3(b"nmu"), t3(b"nmv"), t3(b"nmw"),
+    t3(b"nmx"), t3(b"nmy"), t3(b"nmz"), t3(b"nn "), t3(b"nna"), t3(b"nnb"), t3(b"nnc"), t3(b"nnd"),
+    t3(b"nne"), t3(b"nnf"), t3(b"nng"), t3(b"nnh"), t3(b"nni"), t3(b"nnj"), t3(b"nnk"), t3(b"nnl"),
+    t3(b"nnm"), t3(b"nnn"), t3(b"nnp"), t3(b"nnq"), t3(b"nnr"), t3(b"nns"), t3(b"nnt"), t3(b"nnu"),
+    t3(b"nnv"), t3(b"nnw"), t3(b"nnx"), t3(b"nny"), t3(b"nnz"), t3(b"no "), t3(b"noa"), t3(b"noc"),
+    t3(b"nod"), t3(b"noe"), t3(b"nof"), t3(b"nog"), t3(b"noh"), t3(b"noi"), t3(b"noj"), t3(b"nok"),
+    t3(b"nol"), t3(b"nom"), t3(b"non"), t3(b"noo"), t3(b"nop"), t3(b"noq"), t3(b"nos"), t3(b"not"),
+    t3(b"nou"), t3(b"nov"), t3(b"now"), t3(b"noy"), t3(b"noz"), t3(b"npa"), t3(b"npb"), t3(b"npg"),
+    t3(b"nph"), t3(b"npi"), t3(b"npl"), t3(b"npn"), t3(b"npo"), t3(b"nps"), t3(b"npu"), t3(b"npx"),
+    t3(b"npy"), t3(b"nqg"), t3(b"nqk"), t3(b"nql"), t3(b"nqm"), t3(b"nqn"), t3(b"nqo"), t3(b"nqq"),
+    t3(b"nqt"), t3(b"nqy"), t3(b"nr "), t3(b"nra"), t3(b"nrb"), t3(b"nrc"), t3(b"nre"), t3(b"nrf"),
+    t3(b"nrg"), t3(b"nri"), t3(b"nrk"), t3(b"nrl"), t3(b"nrm"), t3(b"nrn"), t3(b"nrp"), t3(b"nrr"),
+    t3(b"nrt"), t3(b"nru"), t3(b"nrx"), t3(b"nrz"), t3(b"nsa"), t3(b"nsb"), t3(b"nsc"), t3(b"nsd"),
+    t3(b"nse"), t3(b"nsf"), t3(b"nsg"), t3(b"nsh"), t3(b"nsi"), t3(b"nsk"), t3(b"nsl"), t3(b"nsm"),
+    t3(b"nsn"), t3(b"nso"), t3(b"nsp"), t3(b"nsq"), t3(b"nsr"), t3(b"nss"), t3(b"nst"), t3(b"nsu"),
+    t3(b"nsv"), t3(b"nsw"), t3(b"nsx"), t3(b"nsy"), t3(b"nsz"), t3(b"ntd"), t3(b"nte"), t3(b"ntg"),
+    t3(b"nti"), t3(b"ntj"), t3(b"ntk"), t3(b"ntm"), t3(b"nto"), t3(b"ntp"), t3(b"ntr"), t3(b"nts"),
+    t3(b"ntu"), t3(b"ntw"), t3(b"ntx"), t3(b"nty"), t3(b"ntz"), t3(b"nua"), t3(b"nub"), t3(b"nuc"),
+    t3(b"nud"), t3(b"nue"), t3(b"nuf"), t3(b"nug"), t3(b"nuh"), t3(b"nui"), t3(b"nuj"), t3(b"nuk"),
+    t3(b"nul"), t3(b"num"), t3(b"nun"), t3(b"nuo"), t3(b"nup"), t3(b"nuq"), t3(b"nur"), t3(b"nus"),
+    t3(b"nut"), t3(b"nuu"), t3(b"nuv"), t3(b"nuw"), t3(b"nux"), t3(b"nuy"), t3(b"nuz"), t3(b"nv "),
+    t3(b"nvh"), t3(b"nvm"), t3(b"nvo"), t3(b"nwa"), t3(b"nwb"), t3(b"nwc"), t3(b"nwe"), t3(b"nwg"),
+    t3(b"nwi"), t3(b"nwm"), t3(b"nwo"), t3(b"nwr"), t3(b"nwx"), t3(b"nwy"), t3(b"nxa"), t3(b"nxd"),
+    t3(b"nxe"), t3(b"nxg"), t3(b"nxi"), t3(b"nxk"), t3(b"nxl"), t3(b"nxm"), t3(b"nxn"), t3(b"nxo"),
+    t3(b"nxq"), t3(b"nxr"), t3(b"nxu"), t3(b"nxx"), t3(b"ny "), t3(b"nyb"), t3(b"nyc"), t3(b"nyd"),
+    t3(b"nye"), t3(b"nyf"), t3(b"nyg"), t3(b"nyh"), t3(b"nyi"), t3(b"nyj"), t3(b"nyk"), t3(b"nyl"),
+    t3(b"nym"), t3(b"nyn"), t3(b"nyo"), t3(b"nyp"), t3(b"nyq"), t3(b"nyr"), t3(b"nys"), t3(b"nyt"),
+    t3(b"nyu"), t3(b"nyv"), t3(b"nyw"), t3(b"nyx"), t3(b"nyy"), t3(b"nza"), t3(b"nzb"), t3(b"nzd"),
+    t3(b"nzi"), t3(b"nzk"), t3(b"nzm"), t3(b"nzs"), t3(b"nzu"), t3(b"nzy"), t3(b"nzz"), t3(b"oaa"),
+    t3(b"oac"), t3(b"oar"), t3(b"oav"), t3(b"obi"), t3(b"obk"), t3(b"obl"), t3(b"obm"), t3(b"obo"),
+    t3(b"obr"), t3(b"obt"), t3(b"obu"), t3(b"oc "), t3(b"oca"), t3(b"och"), t3(b"ocm"), t3(b"oco"),
+    t3(b"ocu"), t3(b"oda"), t3(b"odk"), t3(b"odt"), t3(b"odu"), t3(b"ofo"), t3(b"ofs"), t3(b"ofu"),
+    t3(b"ogb"), t3(b"ogc"), t3(b"oge"), t3(b"ogg"), t3(b"ogo"), t3(b"ogu"), t3(b"oht"), t3(b"ohu"),
+    t3(b"oia"), t3(b"oin"), t3(b"oj "), t3(b"ojb"), t3(b"ojc"), t3(b"ojg"), t3(b"ojp"), t3(b"ojs"),
+    t3(b"ojv"), t3(b"ojw"), t3(b"oka"), t3(b"okb"), t3(b"okc"), t3(b"okd"), t3(b"oke"), t3(b"okg"),
+    t3(b"okh"), t3(b"oki"), t3(b"okj"), t3(b"okk"), t3(b"okl"), t3(b"okm"), t3(b"okn"), t3(b"oko"),
+    t3(b"okr"), t3(b"oks"), t3(b"oku"), t3(b"okv"), t3(b"okx"), t3(b"okz"), t3(b"ola"), t3(b"old"),
+    t3(b"ole"), t3(b"olk"), t3(b"olm"), t3(b"olo"), t3(b"olr"), t3(b"olt"), t3(b"olu"), t3(b"om "),
+    t3(b"oma"), t3(b"omb"), t3(b"omc"), t3(b"ome"), t3(b"omg"), t3(b"omi"), t3(b"omk"), t3(b"oml"),
+    t3(b"omn"), t3(b"omo"), t3(b"omp"), t3(b"omq"), t3(b"omr"), t3(b"omt"), t3(b"omu"), t3(b"omv"),
+    t3(b"omw"), t3(b"omx"), t3(b"omy"), t3(b"ona"), t3(b"onb"), t3(b"one"), t3(b"ong"), t3(b"oni"),
+    t3(b"onj"), t3(b"onk"), t3(b"onn"), t3(b"ono"), t3(b"onp"), t3(b"onr"), t3(b"ons"), t3(b"ont"),
+    t3(b"onu"), t3(b"onw"), t3(b"onx"), t3(b"ood"), t3(b"oog"), t3(b"oon"), t3(b"oor"), t3(b"oos"),
+    t3(b"opa"), t3(b"opk"), t3(b"opm"), t3(b"opo"), t3(b"opt"), t3(b"opy"), t3(b"or "), t3(b"ora"),
+    t3(b"orc"), t3(b"ore"), t3(b"org"), t3(b"orh"), t3(b"orn"), t3(b"oro"), t3(b"orr"), t3(b"ors"),
+    t3(b"ort"), t3(b"oru"), t3(b"orv"), t3(b"orw"), t3(b"orx"), t3(b"ory"), t3(b"orz"), t3(b"os "),
+    t3(b"osa"), t3(b"osc"), t3(b"osi"), t3(b"osn"), t3(b"oso"), t3(b"osp"), t3(b"ost"), t3(b"osu"),
+    t3(b"osx"), t3(b"ota"), t3(b"otb"), t3(b"otd"), t3(b"ote"), t3(b"oti"), t3(b"otk"), t3(b"otl"),
+    t3(b"otm"), t3(b"otn"), t3(b"oto"), t3(b"otq"), t3(b"otr"), t3(b"ots"), t3(b"ott"), t3(b"otu"),
+    t3(b"otw"), t3(b"otx"), t3(b"oty"), t3(b"otz"), t3(b"oua"), t3(b"oub"), t3(b"oue"), t3(b"oui"),
+    t3(b"oum"), t3(b"oun"), t3(b"ovd"), t3(b"owi"), t3(b"owl"), t3(b"oyb"), t3(b"oyd"), t3(b"oym"),
+    t3(b"oyy"), t3(b"ozm"), t3(b"pa "), t3(b"paa"), t3(b"pab"), t3(b"pac"), t3(b"pad"), t3(b"pae"),
+    t3(b"paf"), t3(b"pag"), t3(b"pah"), t3(b"pai"), t3(b"pak"), t3(b"pal"), t3(b"pam"), t3(b"pao"),
+    t3(b"pap"), t3(b"paq"), t3(b"par"), t3(b"pas"), t3(b"pat"), t3(b"pau"), t3(b"pav"), t3(b"paw"),
+    t3(b"pax"), t3(b"pay"), t3(b"paz"), t3(b"pbb"), t3(b"pbc"), t3(b"pbe"), t3(b"pbf"), t3(b"pbg"),
+    t3(b"pbh"), t3(b"pbi"), t3(b"pbl"), t3(b"pbm"), t3(b"pbn"), t3(b"pbo"), t3(b"pbp"), t3(b"pbr"),
+    t3(b"pbs"), t3(b"pbt"), t3(b"pbu"), t3(b"pbv"), t3(b"pby"), t3(b"pbz"), t3(b"pca"), t3(b"pcb"),
+    t3(b"pcc"), t3(b"pcd"), t3(b"pce"), t3(b"pcf"), t3(b"pcg"), t3(b"pch"), t3(b"pci"), t3(b"pcj"),
+    t3(b"pck"), t3(b"pcl"), t3(b"pcm"), t3(b"pcn"), t3(b"pcp"), t3(b"pcr"), t3(b"pcw"), t3(b"pda"),
+    t3(b"pdc"), t3(b"pdi"), t3(b"pdn"), t3(b"pdo"), t3(b"pdt"), t3(b"pdu"), t3(b"pea"), t3(b"peb"),
+    t3(b"ped"), t3(b"pee"), t3(b"pef"), t3(b"peg"), t3(b"peh"), t3(b"pei"), t3(b"pej"), t3(b"pek"),
+    t3(b"pel"), t3(b"pem"), t3(b"peo"), t3(b"pep"), t3(b"peq"), t3(b"pes"), t3(b"pev"), t3(b"pex"),
+    t3(b"pey"), t3(b"pez"), t3(b"pfa"), t3(b"pfe"), t3(b"pfl"), t3(b"pga"), t3(b"pgd"), t3(b"pgg"),
+    t3(b"pgi"), t3(b"pgk"), t3(b"pgl"), t3(b"pgn"), t3(b"pgs"), t3(b"pgu"), t3(b"pgy"), t3(b"pgz"),
+    t3(b"pha"), t3(b"phd"), t3(b"phg"), t3(b"phh"), t3(b"phi"), t3(b"phk"), t3(b"phl"), t3(b"phm"),
+    t3(b"phn"), t3(b"pho"), t3(b"phq"), t3(b"phr"), t3(b"pht"), t3(b"phu"), t3(b"phv"), t3(b"phw"),
+    t3(b"pi "), t3(b"pia"), t3(b"pib"), t3(b"pic"), t3(b"pid"), t3(b"pie"), t3(b"pif"), t3(b"pig"),
+    t3(b"pih"), t3(b"pii"), t3(b"pij"), t3(b"pil"), t3(b"pim"), t3(b"pin"), t3(b"pio"), t3(b"pip"),
+    t3(b"pir"), t3(b"pis"), t3(b"pit"), t3(b"piu"), t3(b"piv"), t3(b"piw"), t3(b"pix"), t3(b"piy"),
+    t3(b"piz"), t3(b"pjt"), t3(b"pka"), t3(b"pkb"), t3(b"pkc"), t3(b"pkg"), t3(b"pkh"), t3(b"pkn"),
+    t3(b"pko"), t3(b"pkp"), t3(b"pkr"), t3(b"pks"), t3(b"pkt"), t3(b"pku"), t3(b"pl "), t3(b"pla"),
+    t3(b"plb"), t3(b"plc"), t3(b"pld"), t3(b"ple"), t3(b"plf"), t3(b"plg"), t3(b"plh"), t3(b"plj"),
+    t3(b"plk"), t3(b"pll"), t3(b"pln"), t3(b"plo"), t3(b"plp"), t3(b"plq"), t3(b"plr"), t3(b"pls"),
+    t3(b"plt"), t3(b"plu"), t3(b"plv"), t3(b"plw"), t3(b"ply"), t3(b"plz"), t3(b"pma"), t3(b"pmb"),
+    t3(b"pmc"), t3(b"pmd"), t3(b"pme"), t3(b"pmf"), t3(b"pmh"), t3(b"pmi"), t3(b"pmj"), t3(b"pmk"),
+    t3(b"pml"), t3(b"pmm"), t3(b"pmn"), t3(b"pmo"), t3(b"pmq"), t3(b"pmr"), t3(b"pms"), t3(b"pmt"),
+    t3(b"pmu"), t3(b"pmw"), t3(b"pmx"), t3(b"pmy"), t3(b"pmz"), t3(b"pna"), t3(b"pnb"), t3(b"pnc"),
+    t3(b"pnd"), t3(b"pne"), t3(b"png"), t3(b"pnh"), t3(b"pni"), t3(b"pnj"), t3(b"pnk"), t3(b"pnl"),
+    t3(b"pnm"), t3(b"pnn"), t3(b"pno"), t3(b"pnp"), t3(b"pnq"), t3(b"pnr"), t3(b"pns"), t3(b"pnt"),
+    t3(b"pnu"), t3(b"pnv"), t3(b"pnw"), t3(b"pnx"), t3(b"pny"), t3(b"pnz"), t3(b"poc"), t3(b"pod"),
+    t3(b"poe"), t3(b"pof"), t3(b"pog"), t3(b"poh"), t3(b"poi"), t3(b"pok"), t3(b"pom"), t3(b"pon"),
+    t3(b"poo"), t3(b"pop"), t3(b"poq"), t3(b"pos"), t3(b"pot"), t3(b"pov"), t3(b"pow"), t3(b"pox"),
+    t3(b"poy"), t3(b"poz"), t3(b"ppa"), t3(b"ppe"), t3(b"ppi"), t3(b"ppk"), t3(b"ppl"), t3(b"ppm"),
+    t3(b"ppn"), t3(b"ppo"), t3(b"ppp"), t3(b"ppq"), t3(b"ppr"), t3(b"pps"), t3(b"ppt"), t3(b"ppu"),
+    t3(b"pqa"), t3(b"pqe"), t3(b"pqm"), t3(b"pqw"), t3(b"pra"), t3(b"prb"), t3(b"prc"), t3(b"prd"),
+    t3(b"pre"), t3(b"prf"), t3(b"prg"), t3(b"prh"), t3(b"pri"), t3(b"prk"), t3(b"prl"), t3(b"prm"),
+    t3(b"prn"), t3(b"pro"), t3(b"prp"), t3(b"prq"), t3(b"prr"), t3(b"prs"), t3(b"prt"), t3(b"pru"),
+    t3(b"prw"), t3(b"prx"), t3(b"pry"), t3(b"prz"), t3(b"ps "), t3(b"psa"), t3(b"psc"), t3(b"psd"),
+    t3(b"pse"), t3(b"psg"), t3(b"psh"), t3(b"psi"), t3(b"psl"), t3(b"psm"), t3(b"psn"), t3(b"pso"),
+    t3(b"psp"), t3(b"psq"), t3(b"psr"), t3(b"pss"), t3(b"pst"), t3(b"psu"), t3(b"psw"), t3(b"psy"),
+    t3(b"pt "), t3(b"pta"), t3(b"pth"), t3(b"pti"), t3(b"ptn"), t3(b"pto"), t3(b"ptp"), t3(b"ptq"),
+    t3(b"ptr"), t3(b"ptt"), t3(b"ptu"), t3(b"ptv"), t3(b"ptw"), t3(b"pty"), t3(b"pua"), t3(b"pub"),
+    t3(b"puc"), t3(b"pud"), t3(b"pue"), t3(b"puf"), t3(b"pug"), t3(b"pui"), t3(b"puj"), t3(b"puk"),
+    t3(b"pum"), t3(b"puo"), t3(b"pup"), t3(b"puq"), t3(b"pur"), t3(b"put"), t3(b"puu"), t3(b"puw"),
+    t3(b"pux"), t3(b"puy"), t3(b"puz"), t3(b"pwa"), t3(b"pwb"), t3(b"pwg"), t3(b"pwi"), t3(b"pwm"),
+    t3(b"pwn"), t3(b"pwo"), t3(b"pwr"), t3(b"pww"), t3(b"pxm"), t3(b"pye"), t3(b"pym"), t3(b"pyn"),
+    t3(b"pys"), t3(b"pyu"), t3(b"pyx"), t3(b"pyy"), t3(b"pzn"), t3(b"qu "), t3(b"qua"), t3(b"qub"),
+    t3(b"quc"), t3(b"qud"), t3(b"quf"), t3(b"qug"), t3(b"quh"), t3(b"qui"), t3(b"quk"), t3(b"qul"),
+    t3(b"qum"), t3(b"qun"), t3(b"qup"), t3(b"quq"), t3(b"qur"), t3(b"qus"), t3(b"quv"), t3(b"quw"),
+    t3(b"qux"), t3(b"quy"), t3(b"quz"), t3(b"qva"), t3(b"qvc"), t3(b"qve"), t3(b"qvh"), t3(b"qvi"),
+    t3(b"qvj"), t3(b"qvl"), t3(b"qvm"), t3(b"qvn"), t3(b"qvo"), t3(b"qvp"), t3(b"qvs"), t3(b"qvw"),
+    t3(b"qvy"), t3(b"qvz"), t3(b"qwa"), t3(b"qwc"), t3(b"qwe"), t3(b"qwh"), t3(b"qwm"), t3(b"qws"),
+    t3(b"qwt"), t3(b"qxa"), t3(b"qxc"), t3(b"qxh"), t3(b"qxl"), t3(b"qxn"), t3(b"qxo"), t3(b"qxp"),
+    t3(b"qxq"), t3(b"qxr"), t3(b"qxs"), t3(b"qxt"), t3(b"qxu"), t3(b"qxw"), t3(b"qya"), t3(b"qyp"),
+    t3(b"raa"), t3(b"rab"), t3(b"rac"), t3(b"rad"), t3(b"raf"), t3(b"rag"), t3(b"rah"), t3(b"rai"),
+    t3(b"raj"), t3(b"rak"), t3(b"ral"), t3(b"ram"), t3(b"ran"), t3(b"rao"), t3(b"rap"), t3(b"raq"),
+    t3(b"rar"), t3(b"ras"), t3(b"rat"), t3(b"rau"), t3(b"rav"), t3(b"raw"), t3(b"rax"), t3(b"ray"),
+    t3(b"raz"), t3(b"rbb"), t3(b"rbk"), t3(b"rbl"), t3(b"rbp"), t3(b"rcf"), t3(b"rdb"), t3(b"rea"),
+    t3(b"reb"), t3(b"ree"), t3(b"reg"), t3(b"rei"), t3(b"rej"), t3(b"rel"), t3(b"rem"), t3(b"ren"),
+    t3(b"rer"), t3(b"res"), t3(b"ret"), t3(b"rey"), t3(b"rga"), t3(b"rge"), t3(b"rgk"), t3(b"rgn"),
+    t3(b"rgr"), t3(b"rgs"), t3(b"rgu"), t3(b"rhg"), t3(b"rhp"), t3(b"ria"), t3(b"rie"), t3(b"rif"),
+    t3(b"ril"), t3(b"rim"), t3(b"rin"), t3(b"rir"), t3(b"rit"), t3(b"riu"), t3(b"rjg"), t3(b"rji"),
+    t3(b"rjs"), t3(b"rka"), t3(b"rkb"), t3(b"rkh"), t3(b"rki"), t3(b"rkm"), t3(b"rkt"), t3(b"rkw"),
+    t3(b"rm "), t3(b"rma"), t3(b"rmb"), t3(b"rmc"), t3(b"rmd"), t3(b"rme"), t3(b"rmf"), t3(b"rmg"),
+    t3(b"rmh"), t3(b"rmi"), t3(b"rmk"), t3(b"rml"), t3(b"rmm"), t3(b"rmn"), t3(b"rmo"), t3(b"rmp"),
+    t3(b"rmq"), t3(b"rmr"), t3(b"rms"), t3(b"rmt"), t3(b"rmu"), t3(b"rmv"), t3(b"rmw"), t3(b"rmx"),
+    t3(b"rmy"), t3(b"rmz"), t3(b"rn "), t3(b"rna"), t3(b"rnd"), t3(b"rng"), t3(b"rnl"), t3(b"rnn"),
+    t3(b"rnp"), t3(b"rnr"), t3(b"rnw"), t3(b"ro "), t3(b"roa"), t3(b"rob"), t3(b"roc"), t3(b"rod"),
+    t3(b"roe"), t3(b"rof"), t3(b"rog"), t3(b"rol"), t3(b"rom"), t3(b"roo"), t3(b"rop"), t3(b"ror"),
+    t3(b"rou"), t3(b"row"), t3(b"rpn"), t3(b"rpt"), t3(b"rri"), t3(b"rro"), t3(b"rrt"), t3(b"rsb"),
+    t3(b"rsi"), t3(b"rsl"), t3(b"rsm"), t3(b"rtc"), t3(b"rth"), t3(b"rtm"), t3(b"rts"), t3(b"rtw"),
+    t3(b"ru "), t3(b"rub"), t3(b"ruc"), t3(b"rue"), t3(b"ruf"), t3(b"rug"), t3(b"ruh"), t3(b"rui"),
+    t3(b"ruk"), t3(b"ruo"), t3(b"rup"), t3(b"ruq"), t3(b"rut"), t3(b"ruu"), t3(b"ruy"), t3(b"ruz"),
+    t3(b"rw "), t3(b"rwa"), t3(b"rwk"), t3(b"rwl"), t3(b"rwm"), t3(b"rwo"), t3(b"rwr"), t3(b"rxd"),
+    t3(b"rxw"), t3(b"ryn"), t3(b"rys"), t3(b"ryu"), t3(b"rzh"), t3(b"sa "), t3(b"saa"), t3(b"sab"),
+    t3(b"sac"), t3(b"sad"), t3(b"sae"), t3(b"saf"), t3(b"sah"), t3(b"sai"), t3(b"saj"), t3(b"sak"),
+    t3(b"sal"), t3(b"sam"), t3(b"sao"), t3(b"sap"), t3(b"saq"), t3(b"sar"), t3(b"sas"), t3(b"sat"),
+    t3(b"sau"), t3(b"sav"), t3(b"saw"), t3(b"sax"), t3(b"say"), t3(b"saz"), t3(b"sba"), t3(b"sbb"),
+    t3(b"sbc"), t3(b"sbd"), t3(b"sbe"), t3(b"sbf"), t3(b"sbg"), t3(b"sbh"), t3(b"sbi"), t3(b"sbj"),
+    t3(b"sbk"), t3(b"sbl"), t3(b"sbm"), t3(b"sbn"), t3(b"sbo"), t3(b"sbp"), t3(b"sbq"), t3(b"sbr"),
+    t3(b"sbs"), t3(b"sbt"), t3(b"sbu"), t3(b"sbv"), t3(b"sbw"), t3(b"sbx"), t3(b"sby"), t3(b"sbz"),
+    t3(b"sc "), t3(b"sca"), t3(b"scb"), t3(b"sce"), t3(b"scf"), t3(b"scg"), t3(b"sch"), t3(b"sci"),
+    t3(b"sck"), t3(b"scl"), t3(b"scn"), t3(b"sco"), t3(b"scp"), t3(b"scq"), t3(b"scs"), t3(b"sct"),
+    t3(b"scu"), t3(b"scv"), t3(b"scw"), t3(b"scx"), t3(b"sd "), t3(b"sda"), t3(b"sdb"), t3(b"sdc"),
+    t3(b"sde"), t3(b"sdf"), t3(b"sdg"), t3(b"sdh"), t3(b"sdj"), t3(b"sdk"), t3(b"sdl"), t3(b"sdm"),
+    t3(b"sdn"), t3(b"sdo"), t3(b"sdp"), t3(b"sdq"), t3(b"sdr"), t3(b"sds"), t3(b"sdt"), t3(b"sdu"),
+    t3(b"sdv"), t3(b"sdx"), t3(b"sdz"), t3(b"se "), t3(b"sea"), t3(b"seb"), t3(b"sec"), t3(b"sed"),
+    t3(b"see"), t3(b"sef"), t3(b"seg"), t3(b"seh"), t3(b"sei"), t3(b"sej"), t3(b"sek"), t3(b"sel"),
+    t3(b"sem"), t3(b"sen"), t3(b"seo"), t3(b"sep"), t3(b"seq"), t3(b"ser"), t3(b"ses"), t3(b"set"),
+    t3(b"seu"), t3(b"sev"), t3(b"sew"), t3(b"sey"), t3(b"sez"), t3(b"sfb"), t3(b"sfe"), t3(b"sfm"),
+    t3(b"sfs"), t3(b"sfw"), t3(b"sg "), t3(b"sga"), t3(b"sgb"), t3(b"sgc"), t3(b"sgd"), t3(b"sge"),
+    t3(b"sgg"), t3(b"sgh"), t3(b"sgi"), t3(b"sgj"), t3(b"sgk"), t3(b"sgl"), t3(b"sgm"), t3(b"sgn"),
+    t3(b"sgo"), t3(b"sgp"), t3(b"sgr"), t3(b"sgs"), t3(b"sgt"), t3(b"sgu"), t3(b"sgw"), t3(b"sgx"),
+    t3(b"sgy"), t3(b"sgz"), t3(b"sh "), t3(b"sha"), t3(b"shb"), t3(b"shc"), t3(b"shd"), t3(b"she"),
+    t3(b"shg"), t3(b"shh"), t3(b"shi"), t3(b"shj"), t3(b"shk"), t3(b"shl"), t3(b"shm"), t3(b"shn"),
+    t3(b"sho"), t3(b"shp"), t3(b"shq"), t3(b"shr"), t3(b"shs"), t3(b"sht"), t3(b"shu"), t3(b"shv"),
+    t3(b"shw"), t3(b"shx"), t3(b"shy"), t3(b"shz"), t3(b"si "), t3(b"sia"), t3(b"sib"), t3(b"sid"),
+    t3(b"sie"), t3(b"sif"), t3(b"sig"), t3(b"sih"), t3(b"sii"), t3(b"sij"), t3(b"sik"), t3(b"sil"),
+    t3(b"sim"), t3(b"sio"), t3(b"sip"), t3(b"siq"), t3(b"sir"), t3(b"sis"), t3(b"sit"), t3(b"siu"),
+    t3(b"siv"), t3(b"siw"), t3(b"six"), t3(b"siy"), t3(b"siz"), t3(b"sja"), t3(b"sjb"), t3(b"sjd"),
+    t3(b"sje"), t3(b"sjg"), t3(b"sjk"), t3(b"sjl"), t3(b"sjm"), t3(b"sjn"), t3(b"sjo"), t3(b"sjp"),
+    t3(b"sjr"), t3(b"sjs"), t3(b"sjt"), t3(b"sju"), t3(b"sjw"), t3(b"sk "), t3(b"ska"), t3(b"skb"),
+    t3(b"skc"), t3(b"skd"), t3(b"ske"), t3(b"skf"), t3(b"skg"), t3(b"skh"), t3(b"ski"), t3(b"skj"),
+    t3(b"skk"), t3(b"skm"), t3(b"skn"), t3(b"sko"), t3(b"skp"), t3(b"skq"), t3(b"skr"), t3(b"sks"),
+    t3(b"skt"), t3(b"sku"), t3(b"skv"), t3(b"skw"), t3(b"skx"), t3(b"sky"), t3(b"skz"), t3(b"sl "),
+    t3(b"sla"), t3(b"slc"), t3(b"sld"), t3(b"sle"), t3(b"slf"), t3(b"slg"), t3(b"slh"), t3(b"sli"),
+    t3(b"slj"), t3(b"sll"), t3(b"slm"), t3(b"sln"), t3(b"slp"), t3(b"slq"), t3(b"slr"), t3(b"sls"),
+    t3(b"slt"), t3(b"slu"), t3(b"slw"), t3(b"slx"), t3(b"sly"), t3(b"slz"), t3(b"sm "), t3(b"sma"),
+    t3(b"smb"), t3(b"smc"), t3(b"smd"), t3(b"smf"), t3(b"smg"), t3(b"smh"), t3(b"smi"), t3(b"smj"),
+    t3(b"smk"), t3(b"sml"), t3(b"smm"), t3(b"smn"), t3(b"smp"), t3(b"smq"), t3(b"smr"), t3(b"sms"),
+    t3(b"smt"), t3(b"smu"), t3(b"smv"), t3(b"smw"), t3(b"smx"), t3(b"smy"), t3(b"smz"), t3(b"sn "),
+    t3(b"snb"), t3(b"snc"), t3(b"sne"), t3(b"snf"), t3(b"sng"), t3(b"snh"), t3(b"sni"), t3(b"snj"),
+    t3(b"snk"), t3(b"snl"), t3(b"snm"), t3(b"snn"), t3(b"sno"), t3(b"snp"), t3(b"snq"), t3(b"snr"),
+    t3(b"sns"), t3(b"snu"), t3(b"snv"), t3(b"snw"), t3(b"snx"), t3(b"sny"), t3(b"snz"), t3(b"so "),
+    t3(b"soa"), t3(b"sob"), t3(b"soc"), t3(b"sod"), t3(b"soe"), t3(b"sog"), t3(b"soh"), t3(b"soi"),
+    t3(b"soj"), t3(b"sok"), t3(b"sol"), t3(b"son"), t3(b"soo"), t3(b"sop"), t3(b"soq"), t3(b"sor"),
+    t3(b"sos"), t3(b"sou"), t3(b"sov"), t3(b"sow"), t3(b"sox"), t3(b"soy"), t3(b"soz"), t3(b"spb"),
+    t3(b"spc"), t3(b"spd"), t3(b"spe"), t3(b"spg"), t3(b"spi"), t3(b"spk"), t3(b"spl"), t3(b"spm"),
+    t3(b"spn"), t3(b"spo"), t3(b"spp"), t3(b"spq"), t3(b"spr"), t3(b"sps"), t3(b"spt"), t3(b"spu"),
+    t3(b"spv"), t3(b"spx"), t3(b"spy"), t3(b"sq "), t3(b"sqa"), t3(b"sqh"), t3(b"sqj"), t3(b"sqk"),
+    t3(b"sqm"), t3(b"sqn"), t3(b"sqo"), t3(b"sqq"), t3(b"sqr"), t3(b"sqs"), t3(b"sqt"), t3(b"squ"),
+    t3(b"sqx"), t3(b"sr "), t3(b"sra"), t3(b"srb"), t3(b"src"), t3(b"sre"), t3(b"srf"), t3(b"srg"),
+    t3(b"srh"), t3(b"sri"), t3(b"srk"), t3(b"srl"), t3(b"srm"), t3(b"srn"), t3(b"sro"), t3(b"srq"),
+    t3(b"srr"), t3(b"srs"), t3(b"srt"), t3(b"sru"), t3(b"srv"), t3(b"srw"), t3(b"srx"), t3(b"sry"),
+    t3(b"srz"), t3(b"ss "), t3(b"ssa"), t3(b"ssb"), t3(b"ssc"), t3(b"ssd"), t3(b"sse"), t3(b"ssf"),
+    t3(b"ssg"), t3(b"ssh"), t3(b"ssi"), t3(b"ssj"), t3(b"ssk"), t3(b"ssl"), t3(b"ssm"), t3(b"ssn"),
+    t3(b"sso"), t3(b"ssp"), t3(b"ssq"), t3(b"ssr"), t3(b"sss"), t3(b"sst"), t3(b"ssu"), t3(b"ssv"),
+    t3(b"ssx"), t3(b"ssy"), t3(b"ssz"), t3(b"st "), t3(b"sta"), t3(b"stb"), t3(b"std"), t3(b"ste"),
+    t3(b"stf"), t3(b"stg"), t3(b"sth"), t3(b"sti"), t3(b"stj"), t3(b"stk"), t3(b"stl"), t3(b"stm"),
+    t3(b"stn"), t3(b"sto"), t3(b"stp"), t3(b"stq"), t3(b"str"), t3(b"sts"), t3(b"stt"), t3(b"stu"),
+    t3(b"stv"), t3(b"stw"), t3(b"sty"), t3(b"su "), t3(b"sua"), t3(b"sub"), t3(b"suc"), t3(b"sue"),
+    t3(b"sug"), t3(b"sui"), t3(b"suj"), t3(b"suk"), t3(b"sul"), t3(b"sum"), t3(b"suo"), t3(b"suq"),
+    t3(b"sur"), t3(b"sus"), t3(b"sut"), t3(b"suv"), t3(b"suw"), t3(b"sux"), t3(b"suy"), t3(b"suz"),
+    t3(b"sv "), t3(b"sva"), t3(b"svb"), t3(b"svc"), t3(b"sve"), t3(b"svk"), t3(b"svm"), t3(b"svr"),
+    t3(b"svs"), t3(b"svx"), t3(b"sw "), t3(b"swb"), t3(b"swc"), t3(b"swf"), t3(b"swg"), t3(b"swh"),
+    t3(b"swi"), t3(b"swj"), t3(b"swk"), t3(b"swl"), t3(b"swm"), t3(b"swn"), t3(b"swo"), t3(b"swp"),
+    t3(b"swq"), t3(b"swr"), t3(b"sws"), t3(b"swt"), t3(b"swu"), t3(b"swv"), t3(b"sww"), t3(b"swx"),
+    t3(b"swy"), t3(b"sxb"), t3(b"sxc"), t3(b"sxe"), t3(b"sxg"), t3(b"sxk"), t3(b"sxl"), t3(b"sxm"),
+    t3(b"sxn"), t3(b"sxo"), t3(b"sxr"), t3(b"sxs"), t3(b"sxu"), t3(b"sxw"), t3(b"sya"), t3(b"syb"),
+    t3(b"syc"), t3(b"syd"), t3(b"syi"), t3(b"syk"), t3(b"syl"), t3(b"sym"), t3(b"syn"), t3(b"syo"),
+    t3(b"syr"), t3(b"sys"), t3(b"syw"), t3(b"syx"), t3(b"syy"), t3(b"sza"), t3(b"szb"), t3(b"szc"),
+    t3(b"szd"), t3(b"sze"), t3(b"szg"), t3(b"szl"), t3(b"szn"), t3(b"szp"), t3(b"szs"), t3(b"szv"),
+    t3(b"szw"), t3(b"szy"), t3(b"ta "), t3(b"taa"), t3(b"tab"), t3(b"tac"), t3(b"tad"), t3(b"tae"),
+    t3(b"taf"), t3(b"tag"), t3(b"tai"), t3(b"taj"), t3(b"tak"), t3(b"tal"), t3(b"tan"), t3(b"tao"),
+    t3(b"tap"), t3(b"taq"), t3(b"tar"), t3(b"tas"), t3(b"tau"), t3(b"tav"), t3(b"taw"), t3(b"tax"),
+    t3(b"tay"), t3(b"taz"), t3(b"tba"), t3(b"tbb"), t3(b"tbc"), t3(b"tbd"), t3(b"tbe"), t3(b"tbf"),
+    t3(b"tbg"), t3(b"tbh"), t3(b"tbi"), t3(b"tbj"), t3(b"tbk"), t3(b"tbl"), t3(b"tbm"), t3(b"tbn"),
+    t3(b"tbo"), t3(b"tbp"), t3(b"tbq"), t3(b"tbr"), t3(b"tbs"), t3(b"tbt"), t3(b"tbu"), t3(b"tbv"),
+    t3(b"tbw"), t3(b"tbx"), t3(b"tby"), t3(b"tbz"), t3(b"tca"), t3(b"tcb"), t3(b"tcc"), t3(b"tcd"),
+    t3(b"tce"), t3(b"tcf"), t3(b"tcg"), t3(b"tch"), t3(b"tci"), t3(b"tck"), t3(b"tcl"), t3(b"tcm"),
+    t3(b"tcn"), t3(b"tco"), t3(b"tcp"), t3(b"tcq"), t3(b"tcs"), t3(b"tct"), t3(b"tcu"), t3(b"tcw"),
+    t3(b"tcx"), t3(b"tcy"), t3(b"tcz"), t3(b"tda"), t3(b"tdb"), t3(b"tdc"), t3(b"tdd"), t3(b"tde"),
+    t3(b"tdf"), t3(b"tdg"), t3(b"tdh"), t3(b"tdi"), t3(b"tdj"), t3(b"tdk"), t3(b"tdl"), t3(b"tdm"),
+    t3(b"tdn"), t3(b"tdo"), t3(b"tdq"), t3(b"tdr"), t3(b"tds"), t3(b"tdt"), t3(b"tdu"), t3(b"tdv"),
+    t3(b"tdx"), t3(b"tdy"), t3(b"te "), t3(b"tea"), t3(b"teb"), t3(b"tec"), t3(b"ted"), t3(b"tee"),
+    t3(b"tef"), t3(b"teg"), t3(b"teh"), t3(b"tei"), t3(b"tek"), t3(b"tem"), t3(b"ten"), t3(b"teo"),
+    t3(b"tep"), t3(b"teq"), t3(b"ter"), t3(b"tes"), t3(b"tet"), t3(b"teu"), t3(b"tev"), t3(b"tew"),
+    t3(b"tex"), t3(b"tey"), t3(b"tez"), t3(b"tfi"), t3(b"tfn"), t3(b"tfo"), t3(b"tfr"), t3(b"tft"),
+    t3(b"tg "), t3(b"tga"), t3(b"tgb"), t3(b"tgc"), t3(b"tgd"), t3(b"tge"), t3(b"tgf"), t3(b"tgg"),
+    t3(b"tgh"), t3(b"tgi"), t3(b"tgj"), t3(b"tgn"), t3(b"tgo"), t3(b"tgp"), t3(b"tgq"), t3(b"tgr"),
+    t3(b"tgs"), t3(b"tgt"), t3(b"tgu"), t3(b"tgv"), t3(b"tgw"), t3(b"tgx"), t3(b"tgy"), t3(b"tgz"),
+    t3(b"th "), t3(b"thc"), t3(b"thd"), t3(b"the"), t3(b"thf"), t3(b"thh"), t3(b"thi"), t3(b"thk"),
+    t3(b"thl"), t3(b"thm"), t3(b"thn"), t3(b"thp"), t3(b"thq"), t3(b"thr"), t3(b"ths"), t3(b"tht"),
+    t3(b"thu"), t3(b"thv"), t3(b"thw"), t3(b"thx"), t3(b"thy"), t3(b"thz"), t3(b"ti "), t3(b"tia"),
+    t3(b"tic"), t3(b"tid"), t3(b"tie"), t3(b"tif"), t3(b"tig"), t3(b"tih"), t3(b"tii"), t3(b"tij"),
+    t3(b"tik"), t3(b"til"), t3(b"tim"), t3(b"tin"), t3(b"tio"), t3(b"tip"), t3(b"tiq"), t3(b"tis"),
+    t3(b"tit"), t3(b"tiu"), t3(b"tiv"), t3(b"tiw"), t3(b"tix"), t3(b"tiy"), t3(b"tiz"), t3(b"tja"),
+    t3(b"tjg"), t3(b"tji"), t3(b"tjj"), t3(b"tjl"), t3(b"tjm"), t3(b"tjn"), t3(b"tjo"), t3(b"tjp"),
+    t3(b"tjs"), t3(b"tju"), t3(b"tjw"), t3(b"tk "), t3(b"tka"), t3(b"tkb"), t3(b"tkd"), t3(b"tke"),
+    t3(b"tkf"), t3(b"tkg"), t3(b"tkk"), t3(b"tkl"), t3(b"tkm"), t3(b"tkn"), t3(b"tkp"), t3(b"tkq"),
+    t3(b"tkr"), t3(b"tks"), t3(b"tkt"), t3(b"tku"), t3(b"tkv"), t3(b"tkw"), t3(b"tkx"), t3(b"tkz"),
+    t3(b"tl "), t3(b"tla"), t3(b"tlb"), t3(b"tlc"), t3(b"tld"), t3(b"tlf"), t3(b"tlg"), t3(b"tlh"),
+    t3(b"tli"), t3(b"tlj"), t3(b"tlk"), t3(b"tll"), t3(b"tlm"), t3(b"tln"), t3(b"tlo"), t3(b"tlp"),
+    t3(b"tlq"), t3(b"tlr"), t3(b"tls"), t3(b"tlt"), t3(b"tlu"), t3(b"tlv"), t3(b"tlw"), t3(b"tlx"),
+    t3(b"tly"), t3(b"tma"), t3(b"tmb"), t3(b"tmc"), t3(b"tmd"), t3(b"tme"), t3(b"tmf"), t3(b"tmg"),
+    t3(b"tmh"), t3(b"tmi"), t3(b"tmj"), t3(b"tmk"), t3(b"tml"), t3(b"tmm"), t3(b"tmn"), t3(b"tmo"),
+    t3(b"tmp"), t3(b"tmq"), t3(b"tmr"), t3(b"tms"), t3(b"tmt"), t3(b"tmu"), t3(b"tmv"), t3(b"tmw"),
+    t3(b"tmy"), t3(b"tmz"), t3(b"tn "), t3(b"tna"), t3(b"tnb"), t3(b"tnc"), t3(b"tnd"), t3(b"tne"),
+    t3(b"tnf"), t3(b"tng"), t3(b"tnh"), t3(b"tni"), t3(b"tnk"), t3(b"tnl"), t3(b"tnm"), t3(b"tnn"),
+    t3(b"tno"), t3(b"tnp"), t3(b"tnq"), t3(b"tnr"), t3(b"tns"), t3(b"tnt"), t3(b"tnu"), t3(b"tnv"),
+    t3(b"tnw"), t3(b"tnx"), t3(b"tny"), t3(b"tnz"), t3(b"to "), t3(b"tob"), t3(b"toc"), t3(b"tod"),
+    t3(b"toe"), t3(b"tof"), t3(b"tog"), t3(b"toh"), t3(b"toi"), t3(b"toj"), t3(b"tol"), t3(b"tom"),
+    t3(b"too"), t3(b"top"), t3(b"toq"), t3(b"tor"), t3(b"tos"), t3(b"tou"), t3(b"tov"), t3(b"tow"),
+    t3(b"tox"), t3(b"toy"), t3(b"toz"), t3(b"tpa"), t3(b"tpc"), t3(b"tpe"), t3(b"tpf"), t3(b"tpg"),
+    t3(b"tpi"), t3(b"tpj"), t3(b"tpk"), t3(b"tpl"), t3(b"tpm"), t3(b"tpn"), t3(b"tpo"), t3(b"tpp"),
+    t3(b"tpq"), t3(b"tpr"), t3(b"tpt"), t3(b"tpu"), t3(b"tpv"), t3(b"tpw"), t3(b"tpx"), t3(b"tpy"),
+    t3(b"tpz"), t3(b"tqb"), t3(b"tql"), t3(b"tqm"), t3(b"tqn"), t3(b"tqo"), t3(b"tqp"), t3(b"tqq"),
+    t3(b"tqr"), t3(b"tqt"), t3(b"tqu"), t3(b"tqw"), t3(b"tr "), t3(b"tra"), t3(b"trb"), t3(b"trc"),
+    t3(b"trd"), t3(b"tre"), t3(b"trf"), t3(b"trg"), t3(b"trh"), t3(b"tri"), t3(b"trj"), t3(b"trk"),
+    t3(b"trl"), t3(b"trm"), t3(b"trn"), t3(b"tro"), t3(b"trp"), t3(b"trq"), t3(b"trr"), t3(b"trs"),
+    t3(b"trt"), t3(b"tru"), t3(b"trv"), t3(b"trw"), t3(b"trx"), t3(b"try"), t3(b"trz"), t3(b"ts "),
+    t3(b"tsa"), t3(b"tsb"), t3(b"tsc"), t3(b"tsd"), t3(b"tse"), t3(b"tsf"), t3(b"tsg"), t3(b"tsh"),
+    t3(b"tsi"), t3(b"tsj"), t3(b"tsk"), t3(b"tsl"), t3(b"tsm"), t3(b"tsp"), t3(b"tsq"), t3(b"tsr"),
+    t3(b"tss"), t3(b"tst"), t3(b"tsu"), t3(b"tsv"), t3(b"tsw"), t3(b"tsx"), t3(b"tsy"), t3(b"tsz"),
+    t3(b"tt "), t3(b"tta"), t3(b"ttb"), t3(b"ttc"), t3(b"ttd"), t3(b"tte"), t3(b"ttf"), t3(b"ttg"),
+    t3(b"tth"), t3(b"tti"), t3(b"ttj"), t3(b"ttk"), t3(b"ttl"), t3(b"ttm"), t3(b"ttn"), t3(b"tto"),
+    t3(b"ttp"), t3(b"ttq"), t3(b"ttr"), t3(b"tts"), t3(b"ttt"), t3(b"ttu"), t3(b"ttv"), t3(b"ttw"),
+    t3(b"tty"), t3(b"ttz"), t3(b"tua"), t3(b"tub"), t3(b"tuc"), t3(b"tud"), t3(b"tue"), t3(b"tuf"),
+    t3(b"tug"), t3(b"tuh"), t3(b"tui"), t3(b"tuj"), t3(b"tul"), t3(b"tum"), t3(b"tun"), t3(b"tuo"),
+    t3(b"tup"), t3(b"tuq"), t3(b"tus"), t3(b"tut"), t3(b"tuu"), t3(b"tuv"), t3(b"tuw"), t3(b"tux"),
+    t3(b"tuy"), t3(b"tuz"), t3(b"tva"), t3(b"tvd"), t3(b"tve"), t3(b"tvk"), t3(b"tvl"), t3(b"tvm"),
+    t3(b"tvn"), t3(b"tvo"), t3(b"tvs"), t3(b"tvt"), t3(b"tvu"), t3(b"tvw"), t3(b"tvx"), t3(b"tvy"),
+    t3(b"tw "), t3(b"twa"), t3(b"twb"), t3(b"twc"), t3(b"twd"), t3(b"twe"), t3(b"twf"), t3(b"twg"),
+    t3(b"twh"), t3(b"twl"), t3(b"twm"), t3(b"twn"), t3(b"two"), t3(b"twp"), t3(b"twq"), t3(b"twr"),
+    t3(b"twt"), t3(b"twu"), t3(b"tww"), t3(b"twx"), t3(b"twy"), t3(b"txa"), t3(b"txb"), t3(b"txc"),
+    t3(b"txe"), t3(b"txg"), t3(b"txh"), t3(b"txi"), t3(b"txj"), t3(b"txm"), t3(b"txn"), t3(b"txo"),
+    t3(b"txq"), t3(b"txr"), t3(b"txs"), t3(b"txt"), t3(b"txu"), t3(b"txx"), t3(b"txy"), t3(b"ty "),
+    t3(b"tya"), t3(b"tye"), t3(b"tyh"), t3(b"tyi"), t3(b"tyj"), t3(b"tyl"), t3(b"tyn"), t3(b"typ"),
+    t3(b"tyr"), t3(b"tys"), t3(b"tyt"), t3(b"tyu"), t3(b"tyv"), t3(b"tyx"), t3(b"tyy"), t3(b"tyz"),
+    t3(b"tza"), t3(b"tzh"), t3(b"tzj"), t3(b"tzl"), t3(b"tzm"), t3(b"tzn"), t3(b"tzo"), t3(b"tzx"),
+    t3(b"uam"), t3(b"uan"), t3(b"uar"), t3(b"uba"), t3(b"ubi"), t3(b"ubl"), t3(b"ubr"), t3(b"ubu"),
+    t3(b"uby"), t3(b"uda"), t3(b"ude"), t3(b"udg"), t3(b"udi"), t3(b"udj"), t3(b"udl"), t3(b"udm"),
+    t3(b"udu"), t3(b"ues"), t3(b"ufi"), t3(b"ug "), t3(b"uga"), t3(b"ugb"), t3(b"uge"), t3(b"ugn"),
+    t3(b"ugo"), t3(b"ugy"), t3(b"uha"), t3(b"uhn"), t3(b"uis"), t3(b"uiv"), t3(b"uji"), t3(b"uk "),
+    t3(b"uka"), t3(b"ukg"), t3(b"ukh"), t3(b"uki"), t3(b"ukk"), t3(b"ukl"), t3(b"ukp"), t3(b"ukq"),
+    t3(b"uks"), t3(b"uku"), t3(b"ukv"), t3(b"ukw"), t3(b"uky"), t3(b"ula"), t3(b"ulb"), t3(b"ulc"),
+    t3(b"ule"), t3(b"ulf"), t3(b"uli"), t3(b"ulk"), t3(b"ull"), t3(b"ulm"), t3(b"uln"), t3(b"ulu"),
+    t3(b"ulw"), t3(b"uma"), t3(b"umb"), t3(b"umc"), t3(b"umd"), t3(b"umg"), t3(b"umi"), t3(b"umm"),
+    t3(b"umn"), t3(b"umo"), t3(b"ump"), t3(b"umr"), t3(b"ums"), t3(b"umu"), t3(b"una"), t3(b"und"),
+    t3(b"une"), t3(b"ung"), t3(b"uni"), t3(b"unk"), t3(b"unm"), t3(b"unn"), t3(b"unp"), t3(b"unr"),
+    t3(b"unu"), t3(b"unx"), t3(b"unz"), t3(b"uok"), t3(b"upi"), t3(b"upv"), t3(b"ur "), t3(b"ura"),
+    t3(b"urb"), t3(b"urc"), t3(b"ure"), t3(b"urf"), t3(b"urg"), t3(b"urh"), t3(b"uri"), t3(b"urj"),
+    t3(b"urk"), t3(b"url"), t3(b"urm"), t3(b"urn"), t3(b"uro"), t3(b"urp"), t3(b"urr"), t3(b"urt"),
+    t3(b"uru"), t3(b"urv"), t3(b"urw"), t3(b"urx"), t3(b"ury"), t3(b"urz"), t3(b"usa"), t3(b"ush"),
+    t3(b"usi"), t3(b"usk"), t3(b"usp"), t3(b"uss"), t3(b"usu"), t3(b"uta"), t3(b"ute"), t3(b"uth"),
+    t3(b"utp"), t3(b"utr"), t3(b"utu"), t3(b"uum"), t3(b"uun"), t3(b"uur"), t3(b"uuu"), t3(b"uve"),
+    t3(b"uvh"), t3(b"uvl"), t3(b"uwa"), t3(b"uya"), t3(b"uz "), t3(b"uzn"), t3(b"uzs"), t3(b"vaa"),
+    t3(b"vae"), t3(b"vaf"), t3(b"vag"), t3(b"vah"), t3(b"vai"), t3(b"vaj"), t3(b"val"), t3(b"vam"),
+    t3(b"van"), t3(b"vao"), t3(b"vap"), t3(b"var"), t3(b"vas"), t3(b"vau"), t3(b"vav"), t3(b"vay"),
+    t3(b"vbb"), t3(b"vbk"), t3(b"ve "), t3(b"vec"), t3(b"ved"), t3(b"vel"), t3(b"vem"), t3(b"veo"),
+    t3(b"vep"), t3(b"ver"), t3(b"vgr"), t3(b"vgt"), t3(b"vi "), t3(b"vic"), t3(b"vid"), t3(b"vif"),
+    t3(b"vig"), t3(b"vil"), t3(b"vin"), t3(b"vis"), t3(b"vit"), t3(b"viv"), t3(b"vka"), t3(b"vki"),
+    t3(b"vkj"), t3(b"vkk"), t3(b"vkl"), t3(b"vkm"), t3(b"vkn"), t3(b"vko"), t3(b"vkp"), t3(b"vkt"),
+    t3(b"vku"), t3(b"vkz"), t3(b"vlp"), t3(b"vls"), t3(b"vma"), t3(b"vmb"), t3(b"vmc"), t3(b"vmd"),
+    t3(b"vme"), t3(b"vmf"), t3(b"vmg"), t3(b"vmh"), t3(b"vmi"), t3(b"vmj"), t3(b"vmk"), t3(b"vml"),
+    t3(b"vmm"), t3(b"vmp"), t3(b"vmq"), t3(b"vmr"), t3(b"vms"), t3(b"vmu"), t3(b"vmv"), t3(b"vmw"),
+    t3(b"vmx"), t3(b"vmy"), t3(b"vmz"), t3(b"vnk"), t3(b"vnm"), t3(b"vnp"), t3(b"vo "), t3(b"vor"),
+    t3(b"vot"), t3(b"vra"), t3(b"vro"), t3(b"vrs"), t3(b"vrt"), t3(b"vsi"), t3(b"vsl"), t3(b"vsv"),
+    t3(b"vto"), t3(b"vum"), t3(b"vun"), t3(b"vut"), t3(b"vwa"), t3(b"wa "), t3(b"waa"), t3(b"wab"),
+    t3(b"wac"), t3(b"wad"), t3(b"wae"), t3(b"waf"), t3(b"wag"), t3(b"wah"), t3(b"wai"), t3(b"waj"),
+    t3(b"wak"), t3(b"wal"), t3(b"wam"), t3(b"wan"), t3(b"wao"), t3(b"wap"), t3(b"waq"), t3(b"war"),
+    t3(b"was"), t3(b"wat"), t3(b"wau"), t3(b"wav"), t3(b"waw"), t3(b"wax"), t3(b"way"), t3(b"waz"),
+    t3(b"wba"), t3(b"wbb"), t3(b"wbe"), t3(b"wbf"), t3(b"wbh"), t3(b"wbi"), t3(b"wbj"), t3(b"wbk"),
+    t3(b"wbl"), t3(b"wbm"), t3(b"wbp"), t3(b"wbq"), t3(b"wbr"), t3(b"wbs"), t3(b"wbt"), t3(b"wbv"),
+    t3(b"wbw"), t3(b"wca"), t3(b"wci"), t3(b"wdd"), t3(b"wdg"), t3(b"wdj"), t3(b"wdk"), t3(b"wdu"),
+    t3(b"wdy"), t3(b"wea"), t3(b"wec"), t3(b"wed"), t3(b"weg"), t3(b"weh"), t3(b"wei"), t3(b"wem"),
+    t3(b"wen"), t3(b"weo"), t3(b"wep"), t3(b"wer"), t3(b"wes"), t3(b"wet"), t3(b"weu"), t3(b"wew"),
+    t3(b"wfg"), t3(b"wga"), t3(b"wgb"), t3(b"wgg"), t3(b"wgi"), t3(b"wgo"), t3(b"wgu"), t3(b"wgw"),
+    t3(b"wgy"), t3(b"wha"), t3(b"whg"), t3(b"whk"), t3(b"whu"), t3(b"wib"), t3(b"wic"), t3(b"wie"),
+    t3(b"wif"), t3(b"wig"), t3(b"wih"), t3(b"wii"), t3(b"wij"), t3(b"wik"), t3(b"wil"), t3(b"wim"),
+    t3(b"win"), t3(b"wir"), t3(b"wit"), t3(b"wiu"), t3(b"wiv"), t3(b"wiw"), t3(b"wiy"), t3(b"wja"),
+    t3(b"wji"), t3(b"wka"), t3(b"wkb"), t3(b"wkd"), t3(b"wkl"), t3(b"wkr"), t3(b"wku"), t3(b"wkw"),
+    t3(b"wky"), t3(b"wla"), t3(b"wlc"), t3(b"wle"), t3(b"wlg"), t3(b"wlh"), t3(b"wli"), t3(b"wlk"),
+    t3(b"wll"), t3(b"wlm"), t3(b"wlo"), t3(b"wlr"), t3(b"wls"), t3(b"wlu"), t3(b"wlv"), t3(b"wlw"),
+    t3(b"wlx"), t3(b"wly"), t3(b"wma"), t3(b"wmb"), t3(b"wmc"), t3(b"wmd"), t3(b"wme"), t3(b"wmg"),
+    t3(b"wmh"), t3(b"wmi"), t3(b"wmm"), t3(b"wmn"), t3(b"wmo"), t3(b"wms"), t3(b"wmt"), t3(b"wmw"),
+    t3(b"wmx"), t3(b"wnb"), t3(b"wnc"), t3(b"wnd"), t3(b"wne"), t3(b"wng"), t3(b"wni"), t3(b"wnk"),
+    t3(b"wnm"), t3(b"wnn"), t3(b"wno"), t3(b"wnp"), t3(b"wnu"), t3(b"wnw"), t3(b"wny"), t3(b"wo "),
+    t3(b"woa"), t3(b"wob"), t3(b"woc"), t3(b"wod"), t3(b"woe"), t3(b"wof"), t3(b"wog"), t3(b"woi"),
+    t3(b"wok"), t3(b"wom"), t3(b"won"), t3(b"woo"), t3(b"wor"), t3(b"wos"), t3(b"wow"), t3(b"woy"),
+    t3(b"wpc"), t3(b"wra"), t3(b"wrb"), t3(b"wrd"), t3(b"wrg"), t3(b"wrh"), t3(b"wri"), t3(b"wrk"),
+    t3(b"wrl"), t3(b"wrm"), t3(b"wrn"), t3(b"wro"), t3(b"wrp"), t3(b"wrr"), t3(b"wrs"), t3(b"wru"),
+    t3(b"wrv"), t3(b"wrw"), t3(b"wrx"), t3(b"wry"), t3(b"wrz"), t3(b"wsa"), t3(b"wsg"), t3(b"wsi"),
+    t3(b"wsk"), t3(b"wsr"), t3(b"wss"), t3(b"wsu"), t3(b"wsv"), t3(b"wtf"), t3(b"wth"), t3(b"wti"),
+    t3(b"wtk"), t3(b"wtm"), t3(b"wtw"), t3(b"wua"), t3(b"wub"), t3(b"wud"), t3(b"wuh"), t3(b"wul"),
+    t3(b"wum"), t3(b"wun"), t3(b"wur"), t3(b"wut"), t3(b"wuu"), t3(b"wuv"), t3(b"wux"), t3(b"wuy"),
+    t3(b"wwa"), t3(b"wwb"), t3(b"wwo"), t3(b"wwr"), t3(b"www"), t3(b"wxa"), t3(b"wxw"), t3(b"wya"),
+    t3(b"wyb"), t3(b"wyi"), t3(b"wym"), t3(b"wyr"), t3(b"wyy"), t3(b"xaa"), t3(b"xab"), t3(b"xac"),
+    t3(b"xad"), t3(b"xae"), t3(b"xag"), t3(b"xai"), t3(b"xaj"), t3(b"xak"), t3(b"xal"), t3(b"xam"),
+    t3(b"xan"), t3(b"xao"), t3(b"xap"), t3(b"xaq"), t3(b"xar"), t3(b"xas"), t3(b"xat"), t3(b"xau"),
+    t3(b"xav"), t3(b"xaw"), t3(b"xay"), t3(b"xba"), t3(b"xbb"), t3(b"xbc"), t3(b"xbd"), t3(b"xbe"),
+    t3(b"xbg"), t3(b"xbi"), t3(b"xbj"), t3(b"xbm"), t3(b"xbn"), t3(b"xbo"), t3(b"xbp"), t3(b"xbr"),
+    t3(b"xbw"), t3(b"xbx"), t3(b"xby"), t3(b"xcb"), t3(b"xcc"), t3(b"xce"), t3(b"xcg"), t3(b"xch"),
+    t3(b"xcl"), t3(b"xcm"), t3(b"xcn"), t3(b"xco"), t3(b"xcr"), t3(b"xct"), t3(b"xcu"), t3(b"xcv"),
+    t3(b"xcw"), t3(b"xcy"), t3(b"xda"), t3(b"xdc"), t3(b"xdk"), t3(b"xdm"), t3(b"xdo"), t3(b"xdy"),
+    t3(b"xeb"), t3(b"xed"), t3(b"xeg"), t3(b"xel"), t3(b"xem"), t3(b"xep"), t3(b"xer"), t3(b"xes"),
+    t3(b"xet"), t3(b"xeu"), t3(b"xfa"), t3(b"xga"), t3(b"xgb"), t3(b"xgd"), t3(b"xgf"), t3(b"xgg"),
+    t3(b"xgi"), t3(b"xgl"), t3(b"xgm"), t3(b"xgn"), t3(b"xgr"), t3(b"xgu"), t3(b"xgw"), t3(b"xh "),
+    t3(b"xha"), t3(b"xhc"), t3(b"xhd"), t3(b"xhe"), t3(b"xhr"), t3(b"xht"), t3(b"xhu"), t3(b"xhv"),
+    t3(b"xia"), t3(b"xib"), t3(b"xii"), t3(b"xil"), t3(b"xin"), t3(b"xip"), t3(b"xir"), t3(b"xis"),
+    t3(b"xiv"), t3(b"xiy"), t3(b"xjb"), t3(b"xjt"), t3(b"xka"), t3(b"xkb"), t3(b"xkc"), t3(b"xkd"),
+    t3(b"xke"), t3(b"xkf"), t3(b"xkg"), t3(b"xkh"), t3(b"xki"), t3(b"xkj"), t3(b"xkk"), t3(b"xkl"),
+    t3(b"xkn"), t3(b"xko"), t3(b"xkp"), t3(b"xkq"), t3(b"xkr"), t3(b"xks"), t3(b"xkt"), t3(b"xku"),
+    t3(b"xkv"), t3(b"xkw"), t3(b"xkx"), t3(b"xky"), t3(b"xkz"), t3(b"xla"), t3(b"xlb"), t3(b"xlc"),
+    t3(b"xld"), t3(b"xle"), t3(b"xlg"), t3(b"xli"), t3(b"xln"), t3(b"xlo"), t3(b"xlp"), t3(b"xls"),
+    t3(b"xlu"), t3(b"xly"), t3(b"xma"), t3(b"xmb"), t3(b"xmc"), t3(b"xmd"), t3(b"xme"), t3(b"xmf"),
+    t3(b"xmg"), t3(b"xmh"), t3(b"xmj"), t3(b"xmk"), t3(b"xml"), t3(b"xmm"), t3(b"xmn"), t3(b"xmo"),
+    t3(b"xmp"), t3(b"xmq"), t3(b"xmr"), t3(b"xms"), t3(b"xmt"), t3(b"xmu"), t3(b"xmv"), t3(b"xmw"),
+    t3(b"xmx"), t3(b"xmy"), t3(b"xmz"), t3(b"xna"), t3(b"xnb"), t3(b"xnd"), t3(b"xng"), t3(b"xnh"),
+    t3(b"xni"), t3(b"xnj"), t3(b"xnk"), t3(b"xnm"), t3(b"xnn"), t3(b"xno"), t3(b"xnq"), t3(b"xnr"),
+    t3(b"xns"), t3(b"xnt"), t3(b"xnu"), t3(b"xny"), t3(b"xnz"), t3(b"xoc"), t3(b"xod"), t3(b"xog"),
+    t3(b"xoi"), t3(b"xok"), t3(b"xom"), t3(b"xon"), t3(b"xoo"), t3(b"xop"), t3(b"xor"), t3(b"xow"),
+    t3(b"xpa"), t3(b"xpb"), t3(b"xpc"), t3(b"xpd"), t3(b"xpe"), t3(b"xpf"), t3(b"xpg"), t3(b"xph"),
+    t3(b"xpi"), t3(b"xpj"), t3(b"xpk"), t3(b"xpl"), t3(b"xpm"), t3(b"xpn"), t3(b"xpo"), t3(b"xpp"),
+    t3(b"xpq"), t3(b"xpr"), t3(b"xps"), t3(b"xpt"), t3(b"xpu"), t3(b"xpv"), t3(b"xpw"), t3(b"xpx"),
+    t3(b"xpy"), t3(b"xpz"), t3(b"xqa"), t3(b"xqt"), t3(b"xra"), t3(b"xrb"), t3(b"xrd"), t3(b"xre"),
+    t3(b"xrg"), t3(b"xri"), t3(b"xrm"), t3(b"xrn"), t3(b"xrq"), t3(b"xrr"), t3(b"xrt"), t3(b"xru"),
+    t3(b"xrw"), t3(b"xsa"), t3(b"xsb"), t3(b"xsc"), t3(b"xsd"), t3(b"xse"), t3(b"xsh"), t3(b"xsi"),
+    t3(b"xsj"), t3(b"xsl"), t3(b"xsm"), t3(b"xsn"), t3(b"xso"), t3(b"xsp"), t3(b"xsq"), t3(b"xsr"),
+    t3(b"xss"), t3(b"xsu"), t3(b"xsv"), t3(b"xsy"), t3(b"xta"), t3(b"xtb"), t3(b"xtc"), t3(b"xtd"),
+    t3(b"xte"), t3(b"xtg"), t3(b"xth"), t3(b"xti"), t3(b"xtj"), t3(b"xtl"), t3(b"xtm"), t3(b"xtn"),
+    t3(b"xto"), t3(b"xtp"), t3(b"xtq"), t3(b"xtr"), t3(b"xts"), t3(b"xtt"), t3(b"xtu"), t3(b"xtv"),
+    t3(b"xtw"), t3(b"xty"), t3(b"xtz"), t3(b"xua"), t3(b"xub"), t3(b"xud"), t3(b"xug"), t3(b"xuj"),
+    t3(b"xul"), t3(b"xum"), t3(b"xun"), t3(b"xuo"), t3(b"xup"), t3(b"xur"), t3(b"xut"), t3(b"xuu"),
+    t3(b"xve"), t3(b"xvi"), t3(b"xvn"), t3(b"xvo"), t3(b"xvs"), t3(b"xwa"), t3(b"xwc"), t3(b"xwd"),
+    t3(b"xwe"), t3(b"xwg"), t3(b"xwj"), t3(b"xwk"), t3(b"xwl"), t3(b"xwo"), t3(b"xwr"), t3(b"xwt"),
+    t3(b"xww"), t3(b"xxb"), t3(b"xxk"), t3(b"xxm"), t3(b"xxr"), t3(b"xxt"), t3(b"xya"), t3(b"xyb"),
+    t3(b"xyj"), t3(b"xyk"), t3(b"xyl"), t3(b"xyt"), t3(b"xyy"), t3(b"xzh"), t3(b"xzm"), t3(b"xzp"),
+    t3(b"yaa"), t3(b"yab"), t3(b"yac"), t3(b"yad"), t3(b"yae"), t3(b"yaf"), t3(b"yag"), t3(b"yah"),
+    t3(b"yai"), t3(b"yaj"), t3(b"yak"), t3(b"yal"), t3(b"yam"), t3(b"yan"), t3(b"yao"), t3(b"yap"),
+    t3(b"yaq"), t3(b"yar"), t3(b"yas"), t3(b"yat"), t3(b"yau"), t3(b"yav"), t3(b"yaw"), t3(b"yax"),
+    t3(b"yay"), t3(b"yaz"), t3(b"yba"), t3(b"ybb"), t3(b"ybd"), t3(b"ybe"), t3(b"ybh"), t3(b"ybi"),
+    t3(b"ybj"), t3(b"ybk"), t3(b"ybl"), t3(b"ybm"), t3(b"ybn"), t3(b"ybo"), t3(b"ybx"), t3(b"yby"),
+    t3(b"ych"), t3(b"ycl"), t3(b"ycn"), t3(b"ycp"), t3(b"yda"), t3(b"ydd"), t3(b"yde"), t3(b"ydg"),
+    t3(b"ydk"), t3(b"yds"), t3(b"yea"), t3(b"yec"), t3(b"yee"), t3(b"yei"), t3(b"yej"), t3(b"yel"),
+    t3(b"yen"), t3(b"yer"), t3(b"yes"), t3(b"yet"), t3(b"yeu"), t3(b"yev"), t3(b"yey"), t3(b"yga"),
+    t3(b"ygi"), t3(b"ygl"), t3(b"ygm"), t3(b"ygp"), t3(b"ygr"), t3(b"ygs"), t3(b"ygu"), t3(b"ygw"),
+    t3(b"yha"), t3(b"yhd"), t3(b"yhl"), t3(b"yhs"), t3(b"yi "), t3(b"yia"), t3(b"yif"), t3(b"yig"),
+    t3(b"yih"), t3(b"yii"), t3(b"yij"), t3(b"yik"), t3(b"yil"), t3(b"yim"), t3(b"yin"), t3(b"yip"),
+    t3(b"yiq"), t3(b"yir"), t3(b"yis"), t3(b"yit"), t3(b"yiu"), t3(b"yiv"), t3(b"yix"), t3(b"yiy"),
+    t3(b"yiz"), t3(b"yka"), t3(b"ykg"), t3(b"yki"), t3(b"ykk"), t3(b"ykl"), t3(b"ykm"), t3(b"ykn"),
+    t3(b"yko"), t3(b"ykr"), t3(b"ykt"), t3(b"yku"), t3(b"yky"), t3(b"yla"), t3(b"ylb"), t3(b"yle"),
+    t3(b"ylg"), t3(b"yli"), t3(b"yll"), t3(b"ylm"), t3(b"yln"), t3(b"ylo"), t3(b"ylr"), t3(b"ylu"),
+    t3(b"yly"), t3(b"yma"), t3(b"ymb"), t3(b"ymc"), t3(b"ymd"), t3(b"yme"), t3(b"ymg"), t3(b"ymh"),
+    t3(b"ymi"), t3(b"ymk"), t3(b"yml"), t3(b"ymm"), t3(b"ymn"), t3(b"ymo"), t3(b"ymp"), t3(b"ymq"),
+    t3(b"ymr"), t3(b"yms"), t3(b"ymt"), t3(b"ymx"), t3(b"ymz"), t3(b"yna"), t3(b"ynd"), t3(b"yne"),
+    t3(b"yng"), t3(b"ynh"), t3(b"ynk"), t3(b"ynl"), t3(b"ynn"), t3(b"yno"), t3(b"ynq"), t3(b"yns"),
+    t3(b"ynu"), t3(b"yo "), t3(b"yob"), t3(b"yog"), t3(b"yoi"), t3(b"yok"), t3(b"yol"), t3(b"yom"),
+    t3(b"yon"), t3(b"yos"), t3(b"yot"), t3(b"yox"), t3(b"yoy"), t3(b"ypa"), t3(b"ypb"), t3(b"ypg"),
+    t3(b"yph"), t3(b"ypk"), t3(b"ypm"), t3(b"ypn"), t3(b"ypo"), t3(b"ypp"), t3(b"ypz"), t3(b"yra"),
+    t3(b"yrb"), t3(b"yre"), t3(b"yri"), t3(b"yrk"), t3(b"yrl"), t3(b"yrm"), t3(b"yrn"), t3(b"yro"),
+    t3(b"yrs"), t3(b"yrw"), t3(b"yry"), t3(b"ysc"), t3(b"ysd"), t3(b"ysg"), t3(b"ysl"), t3(b"ysm"),
+    t3(b"ysn"), t3(b"yso"), t3(b"ysp"), t3(b"ysr"), t3(b"yss"), t3(b"ysy"), t3(b"yta"), t3(b"ytl"),
+    t3(b"ytp"), t3(b"ytw"), t3(b"yty"), t3(b"yua"), t3(b"yub"), t3(b"yuc"), t3(b"yud"), t3(b"yue"),
+    t3(b"yuf"), t3(b"yug"), t3(b"yui"), t3(b"yuj"), t3(b"yuk"), t3(b"yul"), t3(b"yum"), t3(b"yun"),
+    t3(b"yup"), t3(b"yuq"), t3(b"yur"), t3(b"yut"), t3(b"yuu"), t3(b"yuw"), t3(b"yux"), t3(b"yuy"),
+    t3(b"yuz"), t3(b"yva"), t3(b"yvt"), t3(b"ywa"), t3(b"ywg"), t3(b"ywl"), t3(b"ywn"), t3(b"ywq"),
+    t3(b"ywr"), t3(b"ywt"), t3(b"ywu"), t3(b"yww"), t3(b"yxa"), t3(b"yxg"), t3(b"yxl"), t3(b"yxm"),
+    t3(b"yxu"), t3(b"yxy"), t3(b"yyr"), t3(b"yyu"), t3(b"yyz"), t3(b"yzg"), t3(b"yzk"), t3(b"za "),
+    t3(b"zaa"), t3(b"zab"), t3(b"zac"), t3(b"zad"), t3(b"zae"), t3(b"zaf"), t3(b"zag"), t3(b"zah"),
+    t3(b"zai"), t3(b"zaj"), t3(b"zak"), t3(b"zal"), t3(b"zam"), t3(b"zao"), t3(b"zap"), t3(b"zaq"),
+    t3(b"zar"), t3(b"zas"), t3(b"zat"), t3(b"zau"), t3(b"zav"), t3(b"zaw"), t3(b"zax"), t3(b"zay"),
+    t3(b"zaz"), t3(b"zba"), t3(b"zbc"), t3(b"zbe"), t3(b"zbl"), t3(b"zbt"), t3(b"zbu"), t3(b"zbw"),
+    t3(b"zca"), t3(b"zch"), t3(b"zdj"), t3(b"zea"), t3(b"zeg"), t3(b"zeh"), t3(b"zen"), t3(b"zga"),
+    t3(b"zgb"), t3(b"zgh"), t3(b"zgm"), t3(b"zgn"), t3(b"zgr"), t3(b"zh "), t3(b"zhb"), t3(b"zhd"),
+    t3(b"zhi"), t3(b"zhn"), t3(b"zhw"), t3(b"zhx"), t3(b"zia"), t3(b"zib"), t3(b"zik"), t3(b"zil"),
+    t3(b"zim"), t3(b"zin"), t3(b"zir"), t3(b"ziw"), t3(b"ziz"), t3(b"zka"), t3(b"zkb"), t3(b"zkd"),
+    t3(b"zkg"), t3(b"zkh"), t3(b"zkk"), t3(b"zkn"), t3(b"zko"), t3(b"zkp"), t3(b"zkr"), t3(b"zkt"),
+    t3(b"zku"), t3(b"zkv"), t3(b"zkz"), t3(b"zla"), t3(b"zle"), t3(b"zlj"), t3(b"zlm"), t3(b"zln"),
+    t3(b"zlq"), t3(b"zls"), t3(b"zlw"), t3(b"zma"), t3(b"zmb"), t3(b"zmc"), t3(b"zmd"), t3(b"zme"),
+    t3(b"zmf"), t3(b"zmg"), t3(b"zmh"), t3(b"zmi"), t3(b"zmj"), t3(b"zmk"), t3(b"zml"), t3(b"zmm"),
+    t3(b"zmn"), t3(b"zmo"), t3(b"zmp"), t3(b"zmq"), t3(b"zmr"), t3(b"zms"), t3(b"zmt"), t3(b"zmu"),
+    t3(b"zmv"), t3(b"zmw"), t3(b"zmx"), t3(b"zmy"), t3(b"zmz"), t3(b"zna"), t3(b"znd"), t3(b"zne"),
+    t3(b"zng"), t3(b"znk"), t3(b"zns"), t3(b"zoc"), t3(b"zoh"), t3(b"zom"), t3(b"zoo"), t3(b"zoq"),
+    t3(b"zor"), t3(b"zos"), t3(b"zpa"), t3(b"zpb"), t3(b"zpc"), t3(b"zpd"), t3(b"zpe"), t3(b"zpf"),
+    t3(b"zpg"), t3(b"zph"), t3(b"zpi"), t3(b"zpj"), t3(b"zpk"), t3(b"zpl"), t3(b"zpm"), t3(b"zpn"),
+    t3(b"zpo"), t3(b"zpp"), t3(b"zpq"), t3(b"zpr"), t3(b"zps"), t3(b"zpt"), t3(b"zpu"), t3(b"zpv"),
+    t3(b"zpw"), t3(b"zpx"), t3(b"zpy"), t3(b"zpz"), t3(b"zqe"), t3(b"zra"), t3(b"zrg"), t3(b"zrn"),
+    t3(b"zro"), t3(b"zrp"), t3(b"zrs"), t3(b"zsa"), t3(b"zsk"), t3(b"zsl"), t3(b"zsm"), t3(b"zsr"),
+    t3(b"zsu"), t3(b"zte"), t3(b"ztg"), t3(b"ztl"), t3(b"ztm"), t3(b"ztn"), t3(b"ztp"), t3(b"ztq"),
+    t3(b"zts"), t3(b"ztt"), t3(b"ztu"), t3(b"ztx"), t3(b"zty"), t3(b"zu "), t3(b"zua"), t3(b"zuh"),
+    t3(b"zum"), t3(b"zun"), t3(b"zuy"), t3(b"zwa"), t3(b"zxx"), t3(b"zyb"), t3(b"zyg"), t3(b"zyj"),
+    t3(b"zyn"), t3(b"zyp"), t3(b"zza"), t3(b"zzj"),
+];
+
+#[rustfmt::skip]
+pub const REGISTRY_SCRIPTS: [u32; 208] = [
+    t4(b"adlm"), t4(b"afak"), t4(b"aghb"), t4(b"ahom"), t4(b"arab"), t4(b"aran"), t4(b"armi"),
+    t4(b"armn"), t4(b"avst"), t4(b"bali"), t4(b"bamu"), t4(b"bass"), t4(b"batk"), t4(b"beng"),
+    t4(b"bhks"), t4(b"blis"), t4(b"bopo"), t4(b"brah"), t4(b"brai"), t4(b"bugi"), t4(b"buhd"),
+    t4(b"cakm"), t4(b"cans"), t4(b"cari"), t4(b"cham"), t4(b"cher"), t4(b"chrs"), t4(b"cirt"),
+    t4(b"copt"), t4(b"cpmn"), t4(b"cprt"), t4(b"cyrl"), t4(b"cyrs"), t4(b"deva"), t4(b"diak"),
+    t4(b"dogr"), t4(b"dsrt"), t4(b"dupl"), t4(b"egyd"), t4(b"egyh"), t4(b"egyp"), t4(b"elba"),
+    t4(b"elym"), t4(b"ethi"), t4(b"geok"), t4(b"geor"), t4(b"glag"), t4(b"gong"), t4(b"gonm"),
+    t4(b"goth"), t4(b"gran"), t4(b"grek"), t4(b"gujr"), t4(b"guru"), t4(b"hanb"), t4(b"hang"),
+    t4(b"hani"), t4(b"hano"), t4(b"hans"), t4(b"hant"), t4(b"hatr"), t4(b"hebr"), t4(b"hira"),
+    t4(b"hluw"), t4(b"hmng"), t4(b"hmnp"), t4(b"hrkt"), t4(b"hung"), t4(b"inds"), t4(b"ital"),
+    t4(b"jamo"), t4(b"java"), t4(b"jpan"), t4(b"jurc"), t4(b"kali"), t4(b"kana"), t4(b"khar"),
+    t4(b"khmr"), t4(b"khoj"), t4(b"kitl"), t4(b"kits"), t4(b"knda"), t4(b"kore"), t4(b"kpel"),
+    t4(b"kthi"), t4(b"lana"), t4(b"laoo"), t4(b"latf"), t4(b"latg"), t4(b"latn"), t4(b"leke"),
+    t4(b"lepc"), t4(b"limb"), t4(b"lina"), t4(b"linb"), t4(b"lisu"), t4(b"loma"), t4(b"lyci"),
+    t4(b"lydi"), t4(b"mahj"), t4(b"maka"), t4(b"mand"), t4(b"mani"), t4(b"marc"), t4(b"maya"),
+    t4(b"medf"), t4(b"mend"), t4(b"merc"), t4(b"mero"), t4(b"mlym"), t4(b"modi"), t4(b"mong"),
+    t4(b"moon"), t4(b"mroo"), t4(b"mtei"), t4(b"mult"), t4(b"mymr"), t4(b"nand"), t4(b"narb"),
+    t4(b"nbat"), t4(b"newa"), t4(b"nkdb"), t4(b"nkgb"), t4(b"nkoo"), t4(b"nshu"), t4(b"ogam"),
+    t4(b"olck"), t4(b"orkh"), t4(b"orya"), t4(b"osge"), t4(b"osma"), t4(b"ougr"), t4(b"palm"),
+    t4(b"pauc"), t4(b"pcun"), t4(b"pelm"), t4(b"perm"), t4(b"phag"), t4(b"phli"), t4(b"phlp"),
+    t4(b"phlv"), t4(b"phnx"), t4(b"piqd"), t4(b"plrd"), t4(b"prti"), t4(b"psin"), t4(b"ranj"),
+    t4(b"rjng"), t4(b"rohg"), t4(b"roro"), t4(b"runr"), t4(b"samr"), t4(b"sara"), t4(b"sarb"),
+    t4(b"saur"), t4(b"sgnw"), t4(b"shaw"), t4(b"shrd"), t4(b"shui"), t4(b"sidd"), t4(b"sind"),
+    t4(b"sinh"), t4(b"sogd"), t4(b"sogo"), t4(b"sora"), t4(b"soyo"), t4(b"sund"), t4(b"sylo"),
+    t4(b"syrc"), t4(b"syre"), t4(b"syrj"), t4(b"syrn"), t4(b"tagb"), t4(b"takr"), t4(b"tale"),
+    t4(b"talu"), t4(b"taml"), t4(b"tang"), t4(b"tavt"), t4(b"telu"), t4(b"teng"), t4(b"tfng"),
+    t4(b"tglg"), t4(b"thaa"), t4(b"thai"), t4(b"tibt"), t4(b"tirh"), t4(b"tnsa"), t4(b"toto"),
+    t4(b"ugar"), t4(b"vaii"), t4(b"visp"), t4(b"vith"), t4(b"wara"), t4(b"wcho"), t4(b"wole"),
+    t4(b"xpeo"), t4(b"xsux"), t4(b"yezi"), t4(b"yiii"), t4(b"zanb"), t4(b"zinh"), t4(b"zmth"),
+    t4(b"zsye"), t4(b"zsym"), t4(b"zxxx"), t4(b"zyyy"), t4(b"zzzz"),
+];
+
+#[rustfmt::skip]
+pub const REGISTRY_REGIONS: [u32; 302] = [
+    t3(b"001"), t3(b"002"), t3(b"003"), t3(b"005"), t3(b"009"), t3(b"011"), t3(b"013"), t3(b"014"),
+    t3(b"015"), t3(b"017"), t3(b"018"), t3(b"019"), t3(b"021"), t3(b"029"), t3(b"030"), t3(b"034"),
+    t3(b"035"), t3(b"039"), t3(b"053"), t3(b"054"), t3(b"057"), t3(b"061"), t3(b"142"), t3(b"143"),
+    t3(b"145"), t3(b"150"), t3(b"151"), t3(b"154"), t3(b"155"), t3(b"202"), t3(b"419"), t3(b"aa "),
+    t3(b"ac "), t3(b"ad "), t3(b"ae "), t3(b"af "), t3(b"ag "), t3(b"ai "), t3(b"al "), t3(b"am "),
+    t3(b"an "), t3(b"ao "), t3(b"aq "), t3(b"ar "), t3(b"as "), t3(b"at "), t3(b"au "), t3(b"aw "),
+    t3(b"ax "), t3(b"az "), t3(b"ba "), t3(b"bb "), t3(b"bd "), t3(b"be "), t3(b"bf "), t3(b"bg "),
+    t3(b"bh "), t3(b"bi "), t3(b"bj "), t3(b"bl "), t3(b"bm "), t3(b"bn "), t3(b"bo "), t3(b"bq "),
+    t3(b"br "), t3(b"bs "), t3(b"bt "), t3(b"bu "), t3(b"bv "), t3(b"bw "), t3(b"by "), t3(b"bz "),
+    t3(b"ca "), t3(b"cc "), t3(b"cd "), t3(b"cf "), t3(b"cg "), t3(b"ch "), t3(b"ci "), t3(b"ck "),
+    t3(b"cl "), t3(b"cm "), t3(b"cn "), t3(b"co "), t3(b"cp "), t3(b"cr "), t3(b"cs "), t3(b"cu "),
+    t3(b"cv "), t3(b"cw "), t3(b"cx "), t3(b"cy "), t3(b"cz "), t3(b"dd "), t3(b"de "), t3(b"dg "),
+    t3(b"dj "), t3(b"dk "), t3(b"dm "), t3(b"do "), t3(b"dz "), t3(b"ea "), t3(b"ec "), t3(b"ee "),
+    t3(b"eg "), t3(b"eh "), t3(b"er "), t3(b"es "), t3(b"et "), t3(b"eu "), t3(b"ez "), t3(b"fi "),
+    t3(b"fj "), t3(b"fk "), t3(b"fm "), t3(b"fo "), t3(b"fr "), t3(b"fx "), t3(b"ga "), t3(b"gb "),
+    t3(b"gd "), t3(b"ge "), t3(b"gf "), t3(b"gg "), t3(b"gh "), t3(b"gi "), t3(b"gl "), t3(b"gm "),
+    t3(b"gn "), t3(b"gp "), t3(b"gq "), t3(b"gr "), t3(b"gs "), t3(b"gt "), t3(b"gu "), t3(b"gw "),
+    t3(b"gy "), t3(b"hk "), t3(b"hm "), t3(b"hn "), t3(b"hr "), t3(b"ht "), t3(b"hu "), t3(b"ic "),
+    t3(b"id "), t3(b"ie "), t3(b"il "), t3(b"im "), t3(b"in "), t3(b"io "), t3(b"iq "), t3(b"ir "),
+    t3(b"is "), t3(b"it "), t3(b"je "), t3(b"jm "), t3(b"jo "), t3(b"jp "), t3(b"ke "), t3(b"kg "),
+    t3(b"kh "), t3(b"ki "), t3(b"km "), t3(b"kn "), t3(b"kp "), t3(b"kr "), t3(b"kw "), t3(b"ky "),
+    t3(b"kz "), t3(b"la "), t3(b"lb "), t3(b"lc "), t3(b"li "), t3(b"lk "), t3(b"lr "), t3(b"ls "),
+    t3(b"lt "), t3(b"lu "), t3(b"lv "), t3(b"ly "), t3(b"ma "), t3(b"mc "), t3(b"md "), t3(b"me "),
+    t3(b"mf "), t3(b"mg "), t3(b"mh "), t3(b"mk "), t3(b"ml "), t3(b"mm "), t3(b"mn "), t3(b"mo "),
+    t3(b"mp "), t3(b"mq "), t3(b"mr "), t3(b"ms "), t3(b"mt "), t3(b"mu "), t3(b"mv "), t3(b"mw "),
+    t3(b"mx "), t3(b"my "), t3(b"mz "), t3(b"na "), t3(b"nc "), t3(b"ne "), t3(b"nf "), t3(b"ng "),
+    t3(b"ni "), t3(b"nl "), t3(b"no "), t3(b"np "), t3(b"nr "), t3(b"nt "), t3(b"nu "), t3(b"nz "),
+    t3(b"om "), t3(b"pa "), t3(b"pe "), t3(b"pf "), t3(b"pg "), t3(b"ph "), t3(b"pk "), t3(b"pl "),
+    t3(b"pm "), t3(b"pn "), t3(b"pr "), t3(b"ps "), t3(b"pt "), t3(b"pw "), t3(b"py "), t3(b"qa "),
+    t3(b"re "), t3(b"ro "), t3(b"rs "), t3(b"ru "), t3(b"rw "), t3(b"sa "), t3(b"sb "), t3(b"sc "),
+    t3(b"sd "), t3(b"se "), t3(b"sg "), t3(b"sh "), t3(b"si "), t3(b"sj "), t3(b"sk "), t3(b"sl "),
+    t3(b"sm "), t3(b"sn "), t3(b"so "), t3(b"sr "), t3(b"ss "), t3(b"st "), t3(b"su "), t3(b"sv "),
+    t3(b"sx "), t3(b"sy "), t3(b"sz "), t3(b"ta "), t3(b"tc "), t3(b"td "), t3(b"tf "), t3(b"tg "),
+    t3(b"th "), t3(b"tj "), t3(b"tk "), t3(b"tl "), t3(b"tm "), t3(b"tn "), t3(b"to "), t3(b"tp "),
+    t3(b"tr "), t3(b"tt "), t3(b"tv "), t3(b"tw "), t3(b"tz "), t3(b"ua "), t3(b"ug "), t3(b"um "),
+    t3(b"un "), t3(b"us "), t3(b"uy "), t3(b"uz "), t3(b"va "), t3(b"vc "), t3(b"ve "), t3(b"vg "),
+    t3(b"vi "), t3(b"vn "), t3(b"vu "), t3(b"wf "), t3(b"ws "), t3(b"yd "), t3(b"ye "), t3(b"yt "),
+    t3(b"yu "), t3(b"za "), t3(b"zm "), t3(b"zr "), t3(b"zw "), t3(b"zz "),
+];
+
+#[rustfmt::skip]
+pub const REGISTRY_VARIANTS: [&str; 106] = [
+    "1606nict", "1694acad", "1901", "1959acad", "1994", "1996", "abl1943", "akuapem", "alalc97",
+    "aluku", "ao1990", "aranes", "arevela", "arevmda", "arkaika", "asante", "auvern", "baku1926",
+    "balanka", "barla", "basiceng", "bauddha", "biscayan", "biske", "bohoric", "boont", "bornholm",
+    "cisaup", "colb1945", "cornu", "creiss", "dajnko", "ekavsk", "emodeng", "fonipa", "fonkirsh",
+    "fonnapa", "fonupa", "fonxsamp", "gascon", "grclass", "grital", "grmistr", "hepburn", "heploc",
+    "hognorsk", "hsistemo", "ijekavsk", "itihasa", "ivanchov", "jauer", "jyutping", "kkcor",
+    "kociewie", "kscor", "laukika", "lemosin", "lengadoc", "lipaw", "luna1918", "metelko",
+    "monoton", "ndyuka", "nedis", "newfound", "nicard", "njiva", "nulik", "osojs", "oxendict",
+    "pahawh2", "pahawh3", "pahawh4", "pamaka", "peano", "petr1708", "pinyin", "polyton", "provenc",
+    "puter", "rigik", "rozaj", "rumgr", "scotland", "scouse", "simple", "solba", "sotav",
+    "spanglis", "surmiran", "sursilv", "sutsilv", "tarask", "tongyong", "tunumiit", "uccor",
+    "ucrcor", "ulster", "unifon", "vaidika", "valencia", "vallader", "vecdruka", "vivaraup",
+    "wadegile", "xsistemo",
+];
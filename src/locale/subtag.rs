@@ -1,33 +1,312 @@
+use super::lang_data::{tag2, tag3, ISO639_ALPHA2, ISO639_ALPHA3, ISO639_BIBLIOGRAPHIC};
+use super::{ParseLocaleError, Region};
+use core::fmt::{self, Write};
 use core::iter::Peekable;
-use core::str::Split;
+use core::str::{FromStr, Split};
 
 /// Subtag in a locale.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum Subtag<'a> {
     /// Primary language subtag.
-    Language(&'a str),
+    Language(Language),
     /// Script subtag.
     Script(&'a str),
     /// Region subtag.
-    Region(&'a str),
+    Region(Region),
     /// Variant subtag.
-    Variant(&'a str),
+    Variant(Variant),
     /// Extension subtag.
     Extension(&'a str),
     /// Private-use subtag.
     Private(&'a str),
+    /// Grandfathered tag registered before the current syntax. This is
+    /// always the complete tag. See [`legacy_preferred_value`] for the
+    /// modern equivalent.
+    Legacy(&'a str),
+}
+
+/// Primary language subtag such as `en` or `yue`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Language([u8; 3]);
+
+impl Language {
+    /// Creates a language from a two or three letter subtag. Letters are
+    /// converted to lowercase. This can be used in constant expressions.
+    pub const fn new(subtag: &str) -> Option<Self> {
+        let bytes = subtag.as_bytes();
+        if bytes.len() < 2 || bytes.len() > 3 {
+            return None;
+        }
+        let mut code = [0; 3];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_alphabetic() {
+                return None;
+            }
+            code[i] = bytes[i].to_ascii_lowercase();
+            i += 1;
+        }
+        Some(Self(code))
+    }
+
+    /// Returns the language subtag.
+    pub fn as_str(&self) -> &str {
+        let len = if self.0[2] == 0 { 2 } else { 3 };
+        // SAFETY: The subtag is validated as ASCII on construction.
+        unsafe { core::str::from_utf8_unchecked(&self.0[..len]) }
+    }
+
+    /// Returns the two letter ISO 639-1 code for the language. Three letter
+    /// ISO 639-2 codes are converted, so both `deu` (terminological) and
+    /// `ger` (bibliographic) yield `de`. Returns `None` for languages
+    /// without a two letter code.
+    pub fn to_alpha2(&self) -> Option<Self> {
+        if self.0[2] == 0 {
+            return Some(*self);
+        }
+        let key = tag3(&self.to_alpha3()?.0);
+        let index = ISO639_ALPHA2.binary_search_by(|x| x.0.cmp(&key)).ok()?;
+        let code = ISO639_ALPHA2.get(index)?.1;
+        Some(Self([(code >> 8) as u8, code as u8, 0]))
+    }
+
+    /// Returns the three letter ISO 639-2 terminological code for the
+    /// language, which is also the ISO 639-3 code. Two letter and
+    /// bibliographic codes are converted, so both `de` and `ger` yield
+    /// `deu`. Returns `None` for two letter codes that are not part of
+    /// ISO 639-1.
+    pub fn to_alpha3(&self) -> Option<Self> {
+        if self.0[2] == 0 {
+            let key = tag2(&[self.0[0], self.0[1]]);
+            let index = ISO639_ALPHA3.binary_search_by(|x| x.0.cmp(&key)).ok()?;
+            return Some(Self::from_tag3(ISO639_ALPHA3.get(index)?.1));
+        }
+        let key = tag3(&self.0);
+        Some(
+            match ISO639_BIBLIOGRAPHIC.binary_search_by(|x| x.0.cmp(&key)) {
+                Ok(index) => Self::from_tag3(ISO639_BIBLIOGRAPHIC[index].1),
+                _ => *self,
+            },
+        )
+    }
+
+    /// Returns the three letter ISO 639-2 bibliographic code for the
+    /// language. This differs from the terminological code for only a few
+    /// languages such as German, where `de` yields `ger` rather than `deu`.
+    pub fn to_bibliographic(&self) -> Option<Self> {
+        let alpha3 = self.to_alpha3()?;
+        let key = tag3(&alpha3.0);
+        Some(match ISO639_BIBLIOGRAPHIC.iter().find(|x| x.1 == key) {
+            Some(entry) => Self::from_tag3(entry.0),
+            _ => alpha3,
+        })
+    }
+
+    /// Returns the language with the code preferred by BCP 47, which is the
+    /// two letter code if one exists and the terminological code otherwise.
+    /// For example, `ger` and `deu` are canonicalized to `de`.
+    pub fn canonicalize(&self) -> Self {
+        self.to_alpha2()
+            .or_else(|| self.to_alpha3())
+            .unwrap_or(*self)
+    }
+
+    fn from_tag3(tag: u32) -> Self {
+        Self([(tag >> 16) as u8, (tag >> 8) as u8, tag as u8])
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Language({})", self.as_str())
+    }
+}
+
+/// Variant subtag such as `1996` or `rozaj`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Variant([u8; 8]);
+
+impl Variant {
+    /// Creates a variant from a subtag of five to eight alphanumeric
+    /// characters or four characters beginning with a digit. Letters are
+    /// converted to lowercase. This can be used in constant expressions.
+    pub const fn new(subtag: &str) -> Option<Self> {
+        let bytes = subtag.as_bytes();
+        let len = bytes.len();
+        if len < 4 || len > 8 || (len == 4 && !bytes[0].is_ascii_digit()) {
+            return None;
+        }
+        let mut code = [0; 8];
+        let mut i = 0;
+        while i < len {
+            if !bytes[i].is_ascii_alphanumeric() {
+                return None;
+            }
+            code[i] = bytes[i].to_ascii_lowercase();
+            i += 1;
+        }
+        Some(Self(code))
+    }
+
+    /// Returns the variant subtag.
+    pub fn as_str(&self) -> &str {
+        let mut len = 8;
+        while self.0[len - 1] == 0 {
+            len -= 1;
+        }
+        // SAFETY: The subtag is validated as ASCII on construction.
+        unsafe { core::str::from_utf8_unchecked(&self.0[..len]) }
+    }
+}
+
+impl FromStr for Variant {
+    type Err = ParseLocaleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s).ok_or(ParseLocaleError)
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Variant({})", self.as_str())
+    }
+}
+
+/// Grandfathered tags from the IANA language subtag registry along with
+/// their preferred values. Tags without a preferred value are deprecated
+/// with no replacement.
+#[rustfmt::skip]
+const LEGACY_TAGS: [(&str, Option<&str>); 26] = [
+    ("art-lojban", Some("jbo")), ("cel-gaulish", None), ("en-GB-oed", Some("en-GB-oxendict")),
+    ("i-ami", Some("ami")), ("i-bnn", Some("bnn")), ("i-default", None), ("i-enochian", None),
+    ("i-hak", Some("hak")), ("i-klingon", Some("tlh")), ("i-lux", Some("lb")), ("i-mingo", None),
+    ("i-navajo", Some("nv")), ("i-pwn", Some("pwn")), ("i-tao", Some("tao")), ("i-tay", Some("tay")),
+    ("i-tsu", Some("tsu")), ("no-bok", Some("nb")), ("no-nyn", Some("nn")), ("sgn-BE-FR", Some("sfb")),
+    ("sgn-BE-NL", Some("vgt")), ("sgn-CH-DE", Some("sgg")), ("zh-guoyu", Some("cmn")),
+    ("zh-hakka", Some("hak")), ("zh-min", None), ("zh-min-nan", Some("nan")), ("zh-xiang", Some("hsn")),
+];
+
+fn find_legacy(tag: &str) -> Option<Option<&'static str>> {
+    find_legacy_entry(tag).map(|x| x.1)
+}
+
+fn find_legacy_entry(tag: &str) -> Option<&'static (&'static str, Option<&'static str>)> {
+    LEGACY_TAGS.iter().find(|x| x.0.eq_ignore_ascii_case(tag))
+}
+
+/// Returns true if the specified tag is a grandfathered tag such as
+/// `i-klingon` or `zh-min-nan`.
+pub fn is_legacy(tag: &str) -> bool {
+    find_legacy(tag).is_some()
+}
+
+/// Returns the preferred modern value for a grandfathered tag. For example,
+/// `i-klingon` maps to `tlh` and `zh-min-nan` to `nan`. Returns `None` if
+/// the tag is not grandfathered or has no replacement.
+pub fn legacy_preferred_value(tag: &str) -> Option<&'static str> {
+    find_legacy(tag)?
 }
 
 /// Returns an iterator that yields subtags of the specified locale.
 pub fn subtags<'a>(locale: &'a str) -> Subtags<'a> {
+    let stage = if is_legacy(locale) {
+        ParseStage::Legacy
+    } else {
+        ParseStage::Language
+    };
     Subtags {
-        stage: ParseStage::Language,
+        stage,
         source: locale,
         parts: locale.split('-').peekable(),
         pos: 0,
     }
 }
 
+/// Returns a value that formats the specified tag with the canonical case
+/// for each subtag: lowercase languages, title case scripts and uppercase
+/// regions with all other subtags in lowercase. For example, `EN-latn-us`
+/// is formatted as `en-Latn-US`.
+///
+/// Grandfathered tags are formatted as they appear in the registry.
+pub fn canonical_case(tag: &str) -> CanonicalCase<'_> {
+    CanonicalCase(tag)
+}
+
+/// Formats a tag with canonical case. This is created by the
+/// [`canonical_case`] function.
+#[derive(Copy, Clone, Debug)]
+pub struct CanonicalCase<'a>(&'a str);
+
+impl fmt::Display for CanonicalCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(entry) = find_legacy_entry(self.0) {
+            return f.write_str(entry.0);
+        }
+        let mut iter = subtags(self.0);
+        let mut first = true;
+        for subtag in &mut iter {
+            if !first {
+                f.write_char('-')?;
+            }
+            first = false;
+            match subtag {
+                Subtag::Script(s) => {
+                    for (i, c) in s.chars().enumerate() {
+                        f.write_char(if i == 0 {
+                            c.to_ascii_uppercase()
+                        } else {
+                            c.to_ascii_lowercase()
+                        })?;
+                    }
+                }
+                Subtag::Language(language) => f.write_str(language.as_str())?,
+                Subtag::Region(region) => f.write_str(region.as_str())?,
+                Subtag::Variant(variant) => f.write_str(variant.as_str())?,
+                Subtag::Extension(s) | Subtag::Private(s) | Subtag::Legacy(s) => {
+                    write_mapped(f, s, char::to_ascii_lowercase)?
+                }
+            }
+        }
+        // Malformed trailing subtags are preserved in lowercase.
+        let rest = iter.remainder();
+        if !rest.is_empty() {
+            if !first {
+                f.write_char('-')?;
+            }
+            write_mapped(f, rest, char::to_ascii_lowercase)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_mapped(f: &mut fmt::Formatter, s: &str, map: fn(&char) -> char) -> fmt::Result {
+    for c in s.chars() {
+        f.write_char(map(&c))?;
+    }
+    Ok(())
+}
+
 /// Iterator over the subtags in a locale.
 #[derive(Clone)]
 pub struct Subtags<'a> {
@@ -53,15 +332,16 @@ impl<'a> Iterator for Subtags<'a> {
         let start = self.pos;
         loop {
             match self.stage {
+                ParseStage::Legacy => {
+                    for _ in &mut self.parts {}
+                    self.pos = self.source.len();
+                    return Some(Subtag::Legacy(self.source));
+                }
                 ParseStage::Language => {
                     self.stage = ParseStage::Script;
-                    match part_len {
-                        2 | 3 => {
-                            self.pos += part_len + 1;
-                            return Some(Subtag::Language(part));
-                        }
-                        _ => return None,
-                    }
+                    let language = Language::new(part)?;
+                    self.pos += part_len + 1;
+                    return Some(Subtag::Language(language));
                 }
                 ParseStage::Script => {
                     self.stage = ParseStage::Region;
@@ -72,43 +352,19 @@ impl<'a> Iterator for Subtags<'a> {
                 }
                 ParseStage::Region => {
                     self.stage = ParseStage::Variant;
-                    match part_len {
-                        2 => {
-                            if part.as_bytes().iter().all(|ch| ch.is_ascii_alphabetic()) {
-                                self.pos += part_len + 1;
-                                return Some(Subtag::Region(part));
-                            }
-                        }
-                        3 => {
-                            if part.as_bytes().iter().all(|ch| ch.is_ascii_digit()) {
-                                self.pos += part_len + 1;
-                                return Some(Subtag::Region(part));
-                            }
-                        }
-                        _ => {}
+                    if let Some(region) = Region::new(part) {
+                        self.pos += part_len + 1;
+                        return Some(Subtag::Region(region));
                     }
                 }
                 ParseStage::Variant => match part_len {
-                    4 => {
-                        if part.as_bytes().iter().enumerate().all(|(i, ch)| {
-                            (i == 0 && ch.is_ascii_digit()) || (i > 0 && ch.is_ascii_alphanumeric())
-                        }) {
-                            self.pos += part_len + 1;
-                            return Some(Subtag::Variant(part));
-                        } else {
-                            return None;
-                        }
-                    }
-                    5..=8 => {
-                        if part.as_bytes().iter().all(|ch| ch.is_ascii_alphanumeric()) {
-                            self.pos += part_len + 1;
-                            return Some(Subtag::Variant(part));
-                        } else {
-                            return None;
-                        }
+                    4..=8 => {
+                        let variant = Variant::new(part)?;
+                        self.pos += part_len + 1;
+                        return Some(Subtag::Variant(variant));
                     }
                     1 => {
-                        self.stage = if part.as_bytes()[0] == b'x' {
+                        self.stage = if part.as_bytes()[0].eq_ignore_ascii_case(&b'x') {
                             ParseStage::Private
                         } else {
                             ParseStage::Extension
@@ -129,7 +385,7 @@ impl<'a> Iterator for Subtags<'a> {
                                 end += subpart_len + 1;
                             }
                             1 => {
-                                if subpart.as_bytes()[0] == b'x' {
+                                if subpart.as_bytes()[0].eq_ignore_ascii_case(&b'x') {
                                     self.stage = ParseStage::Private;
                                 }
                                 break;
@@ -143,7 +399,7 @@ impl<'a> Iterator for Subtags<'a> {
                     return Some(Subtag::Extension(tag));
                 }
                 ParseStage::Private => {
-                    if part_len != 1 || part.as_bytes()[0] != b'x' {
+                    if part_len != 1 || !part.as_bytes()[0].eq_ignore_ascii_case(&b'x') {
                         return None;
                     }
                     let mut end = start + part_len + 1;
@@ -155,7 +411,7 @@ impl<'a> Iterator for Subtags<'a> {
                                 end += subpart_len + 1;
                             }
                             1 => {
-                                if subpart.as_bytes()[0] == b'x' {
+                                if subpart.as_bytes()[0].eq_ignore_ascii_case(&b'x') {
                                     break;
                                 } else {
                                     self.parts.next();
@@ -177,6 +433,7 @@ impl<'a> Iterator for Subtags<'a> {
 
 #[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
 enum ParseStage {
+    Legacy,
     Language,
     Script,
     Region,
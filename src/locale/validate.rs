@@ -0,0 +1,257 @@
+//! Validation of language tags against the IANA language subtag registry.
+
+use super::lang_data::{tag3, tag4};
+use super::registry_data::*;
+use super::{is_legacy, subtags, Subtag};
+use core::fmt;
+
+/// Kind of error produced when validating a language tag.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ValidationErrorKind {
+    /// The tag is not well formed.
+    Malformed,
+    /// The primary language subtag is not registered.
+    UnknownLanguage,
+    /// The script subtag is not registered.
+    UnknownScript,
+    /// The region subtag is not registered.
+    UnknownRegion,
+    /// The variant subtag is not registered.
+    UnknownVariant,
+    /// The variant subtag appears more than once.
+    DuplicateVariant,
+    /// The extension singleton appears more than once.
+    DuplicateExtension,
+}
+
+/// Error returned when validating a language tag. This identifies the
+/// offending subtag along with its byte offset in the tag.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ValidationError<'a> {
+    /// Kind of the error.
+    pub kind: ValidationErrorKind,
+    /// Offending subtag. For malformed tags, this is the remainder of the
+    /// tag from the point where parsing failed.
+    pub subtag: &'a str,
+    /// Byte offset of the subtag in the tag.
+    pub offset: usize,
+}
+
+impl fmt::Display for ValidationError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ValidationErrorKind::*;
+        let what = match self.kind {
+            Malformed => "malformed subtag",
+            UnknownLanguage => "unknown language subtag",
+            UnknownScript => "unknown script subtag",
+            UnknownRegion => "unknown region subtag",
+            UnknownVariant => "unknown variant subtag",
+            DuplicateVariant => "duplicate variant subtag",
+            DuplicateExtension => "duplicate extension",
+        };
+        write!(f, "{} \"{}\" at offset {}", what, self.subtag, self.offset)
+    }
+}
+
+/// Checks that a language tag is valid. A valid tag is well formed and
+/// consists of language, script, region and variant subtags that are present
+/// in the IANA language subtag registry, with no duplicate variants or
+/// extensions. Private use subtags and ranges, including tags such as
+/// `x-foo` that consist only of private use subtags, are always accepted.
+/// The contents of extensions are only checked for well-formedness: each
+/// singleton must be followed by at least one subtag of two to eight
+/// characters, or one to eight characters for private use.
+pub fn validate(tag: &str) -> Result<(), ValidationError<'_>> {
+    use ValidationErrorKind::*;
+    if is_legacy(tag) {
+        return Ok(());
+    }
+    let error = |kind, offset: usize, len: usize| {
+        Err(ValidationError {
+            kind,
+            subtag: &tag[offset..offset + len],
+            offset,
+        })
+    };
+    if tag.starts_with(['x', 'X']) && tag.get(1..2) == Some("-") {
+        if !is_well_formed_extension(tag, 1) {
+            return error(Malformed, 0, tag.len());
+        }
+        return Ok(());
+    }
+    let mut iter = subtags(tag);
+    let mut variants = 0u128;
+    let mut singletons = 0u128;
+    loop {
+        // Typed subtags are not slices of the tag, so track the offset of
+        // each one through the remainder.
+        let offset = tag.len() - iter.remainder().len();
+        let subtag = match iter.next() {
+            Some(subtag) => subtag,
+            _ => break,
+        };
+        match subtag {
+            Subtag::Language(language) => {
+                let s = language.as_str();
+                if !is_valid_language(s) {
+                    return error(UnknownLanguage, offset, s.len());
+                }
+            }
+            Subtag::Script(s) => {
+                if !is_valid_script(s) {
+                    return error(UnknownScript, offset, s.len());
+                }
+            }
+            Subtag::Region(region) => {
+                let s = region.as_str();
+                if !is_valid_region(s) {
+                    return error(UnknownRegion, offset, s.len());
+                }
+            }
+            Subtag::Variant(variant) => {
+                let s = variant.as_str();
+                let index = match find_variant(s) {
+                    Some(index) => index,
+                    _ => return error(UnknownVariant, offset, s.len()),
+                };
+                // The registry contains fewer than 128 variants.
+                let bit = 1u128 << index;
+                if variants & bit != 0 {
+                    return error(DuplicateVariant, offset, s.len());
+                }
+                variants |= bit;
+            }
+            Subtag::Extension(s) => {
+                if !is_well_formed_extension(s, 2) {
+                    return error(Malformed, offset, s.len());
+                }
+                let singleton = s.as_bytes()[0].to_ascii_lowercase();
+                let bit = 1u128 << (singleton & 0x7F);
+                if singletons & bit != 0 {
+                    return error(DuplicateExtension, offset, s.len());
+                }
+                singletons |= bit;
+            }
+            Subtag::Private(s) => {
+                if !is_well_formed_extension(s, 1) {
+                    return error(Malformed, offset, s.len());
+                }
+            }
+            Subtag::Legacy(_) => {}
+        }
+    }
+    let rest = iter.remainder();
+    if !rest.is_empty() || tag.is_empty() || tag.ends_with('-') {
+        let offset = tag.len() - rest.len();
+        return Err(ValidationError {
+            kind: Malformed,
+            subtag: rest,
+            offset,
+        });
+    }
+    Ok(())
+}
+
+/// Returns true if the tag is valid.
+pub fn is_valid(tag: &str) -> bool {
+    validate(tag).is_ok()
+}
+
+/// Returns true if the extension or private use sequence beginning with a
+/// singleton is followed by at least one subtag, where each subtag consists
+/// of at least the specified number and at most eight alphanumeric
+/// characters.
+fn is_well_formed_extension(s: &str, min_len: usize) -> bool {
+    let mut parts = s.split('-').skip(1).peekable();
+    parts.peek().is_some()
+        && parts.all(|part| {
+            (min_len..=8).contains(&part.len()) && part.bytes().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+fn is_valid_language(s: &str) -> bool {
+    let b = s.as_bytes();
+    let key = match b.len() {
+        2 => [b[0], b[1], b' '],
+        3 => [b[0], b[1], b[2]],
+        _ => return false,
+    };
+    let key = key.map(|c| c.to_ascii_lowercase());
+    // Private use range qaa..qtz.
+    if key[0] == b'q' && (b'a'..=b't').contains(&key[1]) && key[2].is_ascii_lowercase() {
+        return true;
+    }
+    REGISTRY_LANGUAGES.binary_search(&tag3(&key)).is_ok()
+}
+
+fn is_valid_script(s: &str) -> bool {
+    let b = s.as_bytes();
+    if b.len() != 4 {
+        return false;
+    }
+    let key = [b[0], b[1], b[2], b[3]].map(|c| c.to_ascii_lowercase());
+    // Private use range Qaaa..Qabx.
+    if &key[..2] == b"qa" && (key[2] == b'a' || (key[2] == b'b' && key[3] <= b'x')) {
+        return true;
+    }
+    REGISTRY_SCRIPTS.binary_search(&tag4(&key)).is_ok()
+}
+
+fn is_valid_region(s: &str) -> bool {
+    let b = s.as_bytes();
+    let key = match b.len() {
+        2 => [b[0], b[1], b' '],
+        3 => [b[0], b[1], b[2]],
+        _ => return false,
+    };
+    let key = key.map(|c| c.to_ascii_lowercase());
+    // Private use ranges QM..QZ and XA..XZ.
+    if key[2] == b' ' && ((key[0] == b'q' && key[1] >= b'm') || key[0] == b'x') {
+        return true;
+    }
+    REGISTRY_REGIONS.binary_search(&tag3(&key)).is_ok()
+}
+
+fn find_variant(s: &str) -> Option<usize> {
+    REGISTRY_VARIANTS
+        .binary_search_by(|v| v.bytes().cmp(s.bytes().map(|c| c.to_ascii_lowercase())))
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extensions() {
+        assert!(is_valid("en-u-co-phonebk"));
+        assert!(is_valid("en-a-bbb-x-a-ccc"));
+        for tag in ["en-a", "en-u", "en-x", "en-a-x-foo", "en-u-c-x-foo"] {
+            let err = validate(tag).unwrap_err();
+            assert_eq!(err.kind, ValidationErrorKind::Malformed, "{}", tag);
+        }
+        let err = validate("en-a-x-foo").unwrap_err();
+        assert_eq!((err.subtag, err.offset), ("a", 3));
+    }
+
+    #[test]
+    fn private_use_only() {
+        assert!(is_valid("x-foo"));
+        assert!(is_valid("X-a-b12345"));
+        assert!(!is_valid("x"));
+        assert!(!is_valid("x-"));
+        assert!(!is_valid("x-toolongsubtag"));
+    }
+
+    #[test]
+    fn registry() {
+        assert!(is_valid("sr-Latn-RS"));
+        assert!(is_valid("de-CH-1996"));
+        let err = validate("de-DE-1996-1996").unwrap_err();
+        assert_eq!(err.kind, ValidationErrorKind::DuplicateVariant);
+        assert_eq!(
+            validate("zz-Latn").unwrap_err().kind,
+            ValidationErrorKind::UnknownLanguage
+        );
+    }
+}
@@ -1,8 +1,39 @@
 //! Paragraph level text analysis.
 
-use super::cluster::CharInfo;
-use super::unicode::{Codepoint, LineBreak, Properties, WordBreak};
+#[cfg(feature = "alloc")]
+mod buffer;
+#[cfg(all(feature = "alloc", feature = "bidi"))]
+mod controls;
+mod direction;
+mod itemize;
+#[cfg(feature = "alloc")]
+mod lines;
+mod mirror;
+mod vertical;
+
+#[cfg(feature = "alloc")]
+pub use buffer::AnalysisBuffer;
+#[cfg(all(feature = "alloc", feature = "bidi"))]
+pub use controls::{
+    check_bidi_nesting, has_unbalanced_bidi_controls, repair_bidi_nesting, sanitize_bidi_controls,
+    scan_bidi_controls, BidiControl, ControlStatus, NestingIssue, NestingIssueKind, SanitizeMode,
+    MAX_DEPTH,
+};
+pub use direction::{detect_direction, estimate_direction, DirectionConfidence, DirectionEstimate};
+pub use itemize::{itemize, Item, Items};
+#[cfg(feature = "alloc")]
+pub use lines::{BreakLines, Line, LineOverflow};
+pub use mirror::{mirror_rtl_runs, MirrorRtlRuns};
+pub use vertical::{tate_chu_yoko, TateChuYoko, TateChuYokoKind, TateChuYokoRun};
+
+use super::cluster::{CharInfo, SourceChar};
+use super::locale::{Locale, UnicodeKey};
+use super::unicode::provider::{LookupCache, PropertyProvider, StaticProvider};
+use super::unicode::{Category, Codepoint, LineBreak, Properties, WordBreak};
 use core::borrow::Borrow;
+use core::fmt;
+use core::ops::{Range, RangeInclusive};
+use core::str::Chars;
 
 /// Line boundary state for a character or cluster.
 #[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
@@ -11,10 +42,14 @@ pub enum LineBoundary {
     None = 0,
     /// Optional line boundary.
     Soft = 1,
-    /// Mandatory line boundarty.
+    /// Mandatory line boundary. The kind of break is determined by the
+    /// preceding character with [`MandatoryBreak::from_char`].
     Hard = 2,
 }
 
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(LineBoundary, Hard);
+
 impl LineBoundary {
     pub(crate) fn from_raw(raw: u16) -> Self {
         match raw {
@@ -25,6 +60,556 @@ impl LineBoundary {
     }
 }
 
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineBoundary {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(*u.choose(&[Self::None, Self::Soft, Self::Hard])?)
+    }
+}
+
+/// Kind of a mandatory line break, which is determined by the character
+/// that precedes a hard line boundary.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MandatoryBreak {
+    /// Carriage return, line feed or CRLF.
+    Newline,
+    /// Next line (U+0085).
+    NextLine,
+    /// Line separator (U+2028).
+    LineSeparator,
+    /// Paragraph separator (U+2029).
+    ParagraphSeparator,
+    /// Form feed (U+000C).
+    FormFeed,
+    /// Line tabulation (U+000B).
+    LineTabulation,
+}
+
+impl MandatoryBreak {
+    /// Returns the kind of mandatory break that follows the specified
+    /// character or `None` if the character is not a line terminator.
+    ///
+    /// A carriage return that is followed by a line feed does not break on
+    /// its own, so callers should only query the character that precedes a
+    /// hard line boundary.
+    pub fn from_char(ch: char) -> Option<Self> {
+        Some(match ch {
+            '\r' | '\n' => Self::Newline,
+            '\u{85}' => Self::NextLine,
+            '\u{2028}' => Self::LineSeparator,
+            '\u{2029}' => Self::ParagraphSeparator,
+            '\u{c}' => Self::FormFeed,
+            '\u{b}' => Self::LineTabulation,
+            _ => return None,
+        })
+    }
+
+    /// Returns true if the break separates paragraphs. This corresponds to
+    /// the paragraph separators of the BiDi algorithm, while the other
+    /// kinds only end a line within a paragraph.
+    pub fn is_paragraph_separator(self) -> bool {
+        matches!(
+            self,
+            Self::Newline | Self::NextLine | Self::ParagraphSeparator
+        )
+    }
+}
+
+/// Strictness of the line breaking rules. This corresponds to the `lb`
+/// keyword of the Unicode locale extension and the CSS `line-break`
+/// property.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum LineBreakStyle {
+    /// Prohibits breaks before small kana and the prolonged sound mark.
+    /// This is the default behavior of the Unicode line breaking algorithm.
+    #[default]
+    Strict,
+    /// Allows breaks before small kana and the prolonged sound mark.
+    Normal,
+    /// Additionally allows breaks before iteration marks, certain hyphens
+    /// and centered punctuation and between inseparable characters.
+    Loose,
+}
+
+impl LineBreakStyle {
+    /// Returns the style for the specified `lb` keyword value.
+    pub fn from_keyword(value: &str) -> Option<Self> {
+        Some(match value {
+            "strict" => Self::Strict,
+            "normal" => Self::Normal,
+            "loose" => Self::Loose,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineBreakStyle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(*u.choose(&[Self::Strict, Self::Normal, Self::Loose])?)
+    }
+}
+
+/// Treatment of line breaks within words. This corresponds to the `lw`
+/// keyword of the Unicode locale extension and the CSS `word-break`
+/// property.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum LineBreakWordStyle {
+    /// Uses the default rules for breaks within words.
+    #[default]
+    Normal,
+    /// Allows breaks between any letters, including within words of
+    /// non-ideographic scripts.
+    BreakAll,
+    /// Prohibits breaks between ideographic and Hangul characters.
+    KeepAll,
+}
+
+impl LineBreakWordStyle {
+    /// Returns the style for the specified `lw` keyword value. The `phrase`
+    /// value requires phrase segmentation and is treated as `Normal`.
+    pub fn from_keyword(value: &str) -> Option<Self> {
+        Some(match value {
+            "normal" | "phrase" => Self::Normal,
+            "breakall" => Self::BreakAll,
+            "keepall" => Self::KeepAll,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineBreakWordStyle {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(*u.choose(&[Self::Normal, Self::BreakAll, Self::KeepAll])?)
+    }
+}
+
+/// Options for tailoring line boundary analysis.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct LineBreakOptions {
+    /// Strictness of the line breaking rules.
+    pub style: LineBreakStyle,
+    /// Treatment of line breaks within words.
+    pub word_style: LineBreakWordStyle,
+    /// Line break classes that replace those of the character data.
+    pub overrides: LineBreakOverrides,
+    /// Tailoring of the characters prohibited at the start or end of a
+    /// line.
+    pub kinsoku: Kinsoku,
+    /// Allows breaks after `/`, `.` and `-` within long tokens that look
+    /// like URLs, paths or identifiers, so that they can be wrapped instead
+    /// of overflowing the line. A token is a run of at least
+    /// [`URL_BREAK_MIN_LEN`] non-whitespace characters that contains one of
+    /// these separators, and breaks are only added before a letter or
+    /// digit.
+    pub url_breaks: bool,
+}
+
+/// Minimum number of characters in a token that receives additional break
+/// opportunities when [`LineBreakOptions::url_breaks`] is enabled.
+pub const URL_BREAK_MIN_LEN: usize = 16;
+
+impl LineBreakOptions {
+    /// Returns the line breaking options for the specified locale.
+    ///
+    /// The `lb` and `lw` keywords of the Unicode locale extension take
+    /// precedence. Otherwise, Chinese and Japanese use the `Normal` style
+    /// and all other languages use the `Strict` style.
+    pub fn from_locale(locale: &Locale) -> Self {
+        let style = locale
+            .unicode_keyword(UnicodeKey::LineBreak)
+            .and_then(LineBreakStyle::from_keyword)
+            .unwrap_or_else(|| match locale.language() {
+                "ja" | "zh" | "yue" => LineBreakStyle::Normal,
+                _ => LineBreakStyle::Strict,
+            });
+        let word_style = locale
+            .unicode_keyword(UnicodeKey::LineBreakWord)
+            .and_then(LineBreakWordStyle::from_keyword)
+            .unwrap_or_default();
+        Self {
+            style,
+            word_style,
+            overrides: LineBreakOverrides::new(),
+            kinsoku: Kinsoku::new(),
+            url_breaks: false,
+        }
+    }
+
+    /// Returns the tailored line break class for a character.
+    fn tailor(&self, ch: char, lb: LineBreak) -> LineBreak {
+        use LineBreak::*;
+        if !self.overrides.is_empty() && !is_mandatory(lb) {
+            if let Some(lb) = self.overrides.get(ch) {
+                return lb;
+            }
+        }
+        let lb = match (self.style, lb) {
+            (LineBreakStyle::Strict, _) => lb,
+            (_, CJ) => ID,
+            (LineBreakStyle::Loose, NS) => match ch as u32 {
+                // Iteration marks and hyphens.
+                0x3005 | 0x303B | 0x309D | 0x309E | 0x30FD | 0x30FE | 0x301C | 0x30A0 => ID,
+                // Centered punctuation.
+                0x30FB | 0xFF1A | 0xFF1B | 0xFF65 | 0x203C | 0x2047..=0x2049 => ID,
+                _ => lb,
+            },
+            (LineBreakStyle::Loose, BA) if matches!(ch as u32, 0x2010 | 0x2013) => ID,
+            (LineBreakStyle::Loose, EX) if matches!(ch as u32, 0xFF01 | 0xFF1F) => ID,
+            (LineBreakStyle::Loose, IN) => ID,
+            _ => lb,
+        };
+        let lb = if self.kinsoku.is_empty() {
+            lb
+        } else {
+            self.kinsoku.tailor(ch, lb)
+        };
+        match (self.word_style, lb) {
+            (LineBreakWordStyle::BreakAll, AL) | (LineBreakWordStyle::BreakAll, HL) => ID,
+            (LineBreakWordStyle::BreakAll, NU) => ID,
+            // Resolve as in rule LB1 so that combining marks stay attached
+            // to their base letters.
+            (LineBreakWordStyle::BreakAll, SA) => match ch.properties().category() {
+                Category::NonspacingMark | Category::SpacingMark => CM,
+                _ => ID,
+            },
+            (LineBreakWordStyle::KeepAll, ID) | (LineBreakWordStyle::KeepAll, CJ) => AL,
+            (LineBreakWordStyle::KeepAll, H2) | (LineBreakWordStyle::KeepAll, H3) => AL,
+            (LineBreakWordStyle::KeepAll, JL) | (LineBreakWordStyle::KeepAll, JV) => AL,
+            (LineBreakWordStyle::KeepAll, JT) => AL,
+            _ => lb,
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineBreakOptions {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            style: LineBreakStyle::arbitrary(u)?,
+            word_style: LineBreakWordStyle::arbitrary(u)?,
+            overrides: LineBreakOverrides::arbitrary(u)?,
+            kinsoku: Kinsoku::arbitrary(u)?,
+            url_breaks: bool::arbitrary(u)?,
+        })
+    }
+}
+
+/// Maximum number of ranges in a set of line break overrides.
+const MAX_OVERRIDES: usize = 16;
+
+/// Small table of line break classes that replace the classes from the
+/// character data for ranges of code points, for example to allow breaks
+/// after `/` in URLs or to tailor the treatment of hyphens.
+///
+/// Overrides are consulted before the character data and take precedence
+/// over the tailoring of [`LineBreakStyle`] and [`LineBreakWordStyle`].
+/// When ranges overlap, the range inserted last wins. They only affect line
+/// boundary analysis and the line break class reported by the properties of
+/// a character is unchanged. Characters with mandatory breaks cannot be
+/// overridden, so paragraph boundaries always match the character data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LineBreakOverrides {
+    ranges: [(u32, u32, LineBreak); MAX_OVERRIDES],
+    len: u8,
+}
+
+impl LineBreakOverrides {
+    /// Maximum number of ranges.
+    pub const CAPACITY: usize = MAX_OVERRIDES;
+
+    /// Creates an empty set of overrides.
+    pub const fn new() -> Self {
+        Self {
+            ranges: [(0, 0, LineBreak::XX); MAX_OVERRIDES],
+            len: 0,
+        }
+    }
+
+    /// Assigns a line break class to a range of code points. Returns an
+    /// error if the table is full or if the class is one of the mandatory
+    /// break classes `BK`, `CR`, `LF` or `NL`.
+    pub fn insert(
+        &mut self,
+        range: RangeInclusive<char>,
+        class: LineBreak,
+    ) -> Result<(), LineBreakOverrideError> {
+        let len = self.len as usize;
+        if len == MAX_OVERRIDES || is_mandatory(class) {
+            return Err(LineBreakOverrideError);
+        }
+        self.ranges[len] = (*range.start() as u32, *range.end() as u32, class);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the overrides with the specified class assigned to a range
+    /// of code points. See [`insert`](Self::insert).
+    pub fn with(
+        mut self,
+        range: RangeInclusive<char>,
+        class: LineBreak,
+    ) -> Result<Self, LineBreakOverrideError> {
+        self.insert(range, class)?;
+        Ok(self)
+    }
+
+    /// Returns the overriding line break class for a character.
+    pub fn get(&self, ch: char) -> Option<LineBreak> {
+        let cp = ch as u32;
+        self.ranges[..self.len as usize]
+            .iter()
+            .rev()
+            .find(|range| (range.0..=range.1).contains(&cp))
+            .map(|range| range.2)
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all overrides.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for LineBreakOverrides {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineBreakOverrides {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        use LineBreak::*;
+        let mut overrides = Self::new();
+        for _ in 0..u.int_in_range(0..=4)? {
+            let start = char::arbitrary(u)?;
+            let end = char::from_u32(start as u32 + u.int_in_range(0..=0x7F)?).unwrap_or(start);
+            let class = *u.choose(&[AL, BA, BB, CL, GL, HY, ID, IS, NS, OP, SY, XX])?;
+            let _ = overrides.insert(start..=end, class);
+        }
+        Ok(overrides)
+    }
+}
+
+/// Error returned when a line break override cannot be added.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct LineBreakOverrideError;
+
+impl fmt::Display for LineBreakOverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line break overrides are full or assign a mandatory break"
+        )
+    }
+}
+
+/// Maximum number of ranges in a kinsoku table.
+const MAX_KINSOKU: usize = 16;
+
+/// Rule assigned to a range of characters by a [`Kinsoku`] table.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum KinsokuRule {
+    /// Prohibits the character at the start of a line.
+    NoStart,
+    /// Prohibits the character at the end of a line.
+    NoEnd,
+    /// Removes the character from the prohibited sets, allowing breaks on
+    /// either side of it.
+    Allow,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KinsokuRule {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(*u.choose(&[Self::NoStart, Self::NoEnd, Self::Allow])?)
+    }
+}
+
+/// Tailoring of the characters prohibited at the start or end of a line,
+/// known as kinsoku shori in Japanese typesetting.
+///
+/// The default sets are derived from the line break classes after the
+/// tailoring of [`LineBreakStyle`]: closing punctuation, non-starters,
+/// exclamations and infix separators (`CL`, `CP`, `NS`, `EX`, `IS` and,
+/// with the `Strict` style, `CJ`) may not start a line and opening
+/// punctuation (`OP`) may not end one. See
+/// [`is_default_no_start`](Self::is_default_no_start) and
+/// [`is_default_no_end`](Self::is_default_no_end).
+///
+/// The table extends the default sets with ranges of code points or, once
+/// [`replace_defaults`](Self::replace_defaults) is called, replaces them so
+/// that only the listed characters are prohibited. Publishers differ in
+/// their treatment of characters such as small kana, the prolonged sound
+/// mark and iteration marks, which are commonly moved between the sets.
+/// Like [`LineBreakOverrides`], the table only affects line boundary
+/// analysis and the range inserted last wins when ranges overlap.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Kinsoku {
+    ranges: [(u32, u32, KinsokuRule); MAX_KINSOKU],
+    len: u8,
+    replace: bool,
+}
+
+impl Kinsoku {
+    /// Maximum number of ranges.
+    pub const CAPACITY: usize = MAX_KINSOKU;
+
+    /// Creates a table that uses the default sets.
+    pub const fn new() -> Self {
+        Self {
+            ranges: [(0, 0, KinsokuRule::Allow); MAX_KINSOKU],
+            len: 0,
+            replace: false,
+        }
+    }
+
+    /// Returns true if characters of the specified line break class may not
+    /// start a line by default.
+    pub fn is_default_no_start(lb: LineBreak) -> bool {
+        use LineBreak::*;
+        matches!(lb, CL | CP | NS | EX | IS | CJ)
+    }
+
+    /// Returns true if characters of the specified line break class may not
+    /// end a line by default.
+    pub fn is_default_no_end(lb: LineBreak) -> bool {
+        lb == LineBreak::OP
+    }
+
+    /// Assigns a rule to a range of code points. Returns an error if the
+    /// table is full.
+    pub fn insert(
+        &mut self,
+        range: RangeInclusive<char>,
+        rule: KinsokuRule,
+    ) -> Result<(), KinsokuError> {
+        let len = self.len as usize;
+        if len == MAX_KINSOKU {
+            return Err(KinsokuError);
+        }
+        self.ranges[len] = (*range.start() as u32, *range.end() as u32, rule);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the table with the specified rule assigned to a range of
+    /// code points. See [`insert`](Self::insert).
+    pub fn with(
+        mut self,
+        range: RangeInclusive<char>,
+        rule: KinsokuRule,
+    ) -> Result<Self, KinsokuError> {
+        self.insert(range, rule)?;
+        Ok(self)
+    }
+
+    /// Replaces the default sets, so that characters in the default sets
+    /// without a rule in the table are no longer prohibited at either end
+    /// of a line.
+    pub fn replace_defaults(mut self) -> Self {
+        self.replace = true;
+        self
+    }
+
+    /// Returns true if the default sets are replaced.
+    pub fn replaces_defaults(&self) -> bool {
+        self.replace
+    }
+
+    /// Returns the rule assigned to a character by the table.
+    pub fn get(&self, ch: char) -> Option<KinsokuRule> {
+        let cp = ch as u32;
+        self.ranges[..self.len as usize]
+            .iter()
+            .rev()
+            .find(|range| (range.0..=range.1).contains(&cp))
+            .map(|range| range.2)
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns true if the table neither contains ranges nor replaces the
+    /// default sets.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0 && !self.replace
+    }
+
+    /// Removes all ranges and restores the default sets.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns the tailored line break class for a character. Prohibited
+    /// characters are treated as non-starters or opening punctuation and
+    /// allowed characters as ideographs, which permit breaks on either
+    /// side.
+    fn tailor(&self, ch: char, lb: LineBreak) -> LineBreak {
+        let is_default = Self::is_default_no_start(lb) || Self::is_default_no_end(lb);
+        match self.get(ch) {
+            Some(KinsokuRule::NoStart) => LineBreak::NS,
+            Some(KinsokuRule::NoEnd) => LineBreak::OP,
+            Some(KinsokuRule::Allow) if is_default => LineBreak::ID,
+            None if self.replace && is_default => LineBreak::ID,
+            _ => lb,
+        }
+    }
+}
+
+impl Default for Kinsoku {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Kinsoku {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut kinsoku = Self::new();
+        for _ in 0..u.int_in_range(0..=4)? {
+            let start = char::arbitrary(u)?;
+            let end = char::from_u32(start as u32 + u.int_in_range(0..=0x7F)?).unwrap_or(start);
+            let _ = kinsoku.insert(start..=end, KinsokuRule::arbitrary(u)?);
+        }
+        if bool::arbitrary(u)? {
+            kinsoku = kinsoku.replace_defaults();
+        }
+        Ok(kinsoku)
+    }
+}
+
+/// Error returned when a kinsoku table is full.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct KinsokuError;
+
+impl fmt::Display for KinsokuError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "kinsoku table is full")
+    }
+}
+
+/// Returns true if the line break class is a mandatory break.
+fn is_mandatory(lb: LineBreak) -> bool {
+    matches!(
+        lb,
+        LineBreak::BK | LineBreak::CR | LineBreak::LF | LineBreak::NL
+    )
+}
+
 /// Returns an iterator yielding Unicode properties and boundary analysis for
 /// each character in the specified sequence.
 pub fn paragraph_info<I>(chars: I) -> ParagraphInfo<I::IntoIter>
@@ -36,30 +621,66 @@ where
     ParagraphInfo {
         chars: chars.into_iter(),
         state: BoundaryState::new(),
+        provider: StaticProvider,
     }
 }
 
 /// Iterator that yields Unicode properties and boundary analysis.
 /// This iterator is created by the [`paragraph_info`] function.
 #[derive(Clone)]
-pub struct ParagraphInfo<I> {
+pub struct ParagraphInfo<I, P = StaticProvider> {
     chars: I,
     state: BoundaryState,
+    provider: P,
 }
 
-impl<I> Iterator for ParagraphInfo<I>
+impl<I, P> Iterator for ParagraphInfo<I, P>
 where
     I: Iterator + Clone,
     I::Item: Borrow<char>,
+    P: PropertyProvider,
 {
     type Item = CharInfo;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.state.next(&mut self.chars)
+        self.state.next(&mut self.chars, &self.provider)
     }
 }
 
-impl<I> ParagraphInfo<I> {
+impl<I, P> ParagraphInfo<I, P> {
+    /// Sets the source of character properties. The static tables are used
+    /// by default.
+    pub fn with_provider<Q: PropertyProvider>(self, provider: Q) -> ParagraphInfo<I, Q> {
+        ParagraphInfo {
+            chars: self.chars,
+            state: self.state,
+            provider,
+        }
+    }
+
+    /// Sets the options for line boundary analysis.
+    pub fn with_line_break_options(mut self, options: LineBreakOptions) -> Self {
+        self.state.line_options = options;
+        self
+    }
+
+    /// Sets the options for line boundary analysis from the specified
+    /// locale. See [`LineBreakOptions::from_locale`].
+    pub fn with_locale(self, locale: &Locale) -> Self {
+        self.with_line_break_options(LineBreakOptions::from_locale(locale))
+    }
+
+    /// Disables word and line boundary analysis so that only character
+    /// properties are computed.
+    ///
+    /// This is useful when the boundaries are not needed up front, such as
+    /// for hit testing. The boundaries for individual paragraphs can be
+    /// computed on demand with [`analyze_boundaries`].
+    pub fn without_boundaries(mut self) -> Self {
+        self.state.boundaries = false;
+        self
+    }
+
     /// Returns true if the analysis indicates that BiDi resolution is
     /// required.
     pub fn needs_bidi_resolution(&self) -> bool {
@@ -67,6 +688,158 @@ impl<I> ParagraphInfo<I> {
     }
 }
 
+impl<'a> ParagraphInfo<Chars<'a>> {
+    /// Fills the buffer with the analysis of the following characters and
+    /// returns the number of characters written. A result of zero indicates
+    /// that the text is exhausted.
+    ///
+    /// This produces the same results as the iterator, but runs of ASCII
+    /// text are detected a word at a time and analyzed directly from the
+    /// underlying bytes, avoiding per character decoding and property
+    /// lookup. The general path resumes at the first non-ASCII character.
+    pub fn next_batch(&mut self, infos: &mut [CharInfo]) -> usize {
+        let mut count = 0;
+        while count < infos.len() {
+            let text = self.chars.as_str();
+            let bytes = text.as_bytes();
+            let len = ascii_len(&bytes[..bytes.len().min(infos.len() - count)]);
+            if len == 0 {
+                match self.state.next(&mut self.chars, &StaticProvider) {
+                    Some(info) => {
+                        infos[count] = info;
+                        count += 1;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+            for (i, (&byte, info)) in bytes[..len]
+                .iter()
+                .zip(&mut infos[count..count + len])
+                .enumerate()
+            {
+                let props = Properties::from_ascii(byte);
+                if !self.state.boundaries {
+                    *info = CharInfo::from(props);
+                    continue;
+                }
+                let line = self
+                    .state
+                    .check_line(byte as char, props, || text[i + 1..].chars());
+                let word = self.state.check_word(props, || match bytes.get(i + 1) {
+                    Some(&next) if next < 0x80 => Some(Properties::from_ascii(next).word_break()),
+                    _ => text[i + 1..]
+                        .chars()
+                        .next()
+                        .map(|c| c.properties().word_break()),
+                });
+                // ASCII characters never require BiDi resolution.
+                *info = CharInfo::new(props, word, line);
+            }
+            count += len;
+            self.chars = text[len..].chars();
+        }
+        count
+    }
+}
+
+/// Computes word and line boundaries for the paragraph containing the
+/// character at the specified index and returns the range of the paragraph.
+///
+/// This produces the same results as full analysis with the specified
+/// options and is intended for characters that were analyzed without
+/// boundaries (see [`ParagraphInfo::without_boundaries`]). Paragraphs are
+/// terminated by characters with a mandatory line break, so the analysis can
+/// be performed for each paragraph independently when the boundaries are
+/// first required. The returned range can be used to track the paragraphs
+/// that have been analyzed.
+pub fn analyze_boundaries(
+    chars: &mut [SourceChar],
+    index: usize,
+    options: LineBreakOptions,
+) -> Range<usize> {
+    let index = index.min(chars.len());
+    let mut start = index;
+    while start > 0 && !is_paragraph_end(chars, start - 1) {
+        start -= 1;
+    }
+    let mut end = index;
+    while end < chars.len() && !is_paragraph_end(chars, end) {
+        end += 1;
+    }
+    let end = (end + 1).min(chars.len());
+    let mut state = BoundaryState::new();
+    state.line_options = options;
+    for i in start..end {
+        let (ch, props) = (chars[i].ch, chars[i].info.properties());
+        let mut line = state.check_line(ch, props, || chars[i + 1..].iter().map(|c| c.ch));
+        let word = state.check_word(props, || chars.get(i + 1).map(|c| c.info.word_break()));
+        if i == start && start != 0 {
+            // Mandatory break following the previous paragraph.
+            line = LineBoundary::Hard;
+        }
+        chars[i].info.set_word_boundary(word);
+        chars[i].info.set_line_boundary(line);
+    }
+    start..end
+}
+
+/// Returns true if the character at the specified index terminates a
+/// paragraph.
+// Option::is_none_or requires Rust 1.82.
+#[allow(clippy::unnecessary_map_or)]
+fn is_paragraph_end(chars: &[SourceChar], index: usize) -> bool {
+    match chars[index].info.line_break() {
+        LineBreak::BK | LineBreak::LF | LineBreak::NL => true,
+        LineBreak::CR => chars
+            .get(index + 1)
+            .map_or(true, |c| c.info.line_break() != LineBreak::LF),
+        _ => false,
+    }
+}
+
+/// Returns the length of the ASCII prefix of the bytes.
+fn ascii_len(bytes: &[u8]) -> usize {
+    const WORD: usize = core::mem::size_of::<usize>();
+    const HIGH_BITS: usize = usize::MAX / 0xFF * 0x80;
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    let mut len = ascii_len_sse2(bytes);
+    #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+    let mut len = 0;
+    while len + WORD <= bytes.len() {
+        let mut word = [0; WORD];
+        word.copy_from_slice(&bytes[len..len + WORD]);
+        if usize::from_ne_bytes(word) & HIGH_BITS != 0 {
+            break;
+        }
+        len += WORD;
+    }
+    while len < bytes.len() && bytes[len] < 0x80 {
+        len += 1;
+    }
+    len
+}
+
+/// Returns the length of the ASCII prefix of the bytes, scanning in 16 byte
+/// chunks. Any remainder shorter than a chunk is left to the caller.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn ascii_len_sse2(bytes: &[u8]) -> usize {
+    use core::arch::x86_64::{__m128i, _mm_loadu_si128, _mm_movemask_epi8};
+    let mut len = 0;
+    while len + 16 <= bytes.len() {
+        // SAFETY: SSE2 is available on all x86_64 targets and the unaligned
+        // load is within the bounds of the slice.
+        let mask = unsafe {
+            _mm_movemask_epi8(_mm_loadu_si128(bytes.as_ptr().add(len) as *const __m128i))
+        };
+        if mask != 0 {
+            return len + mask.trailing_zeros() as usize;
+        }
+        len += 16;
+    }
+    len
+}
+
 #[derive(Clone)]
 struct BoundaryState {
     prev: WordBreak,
@@ -77,6 +850,13 @@ struct BoundaryState {
     line_state: (u8, bool),
     first: bool,
     needs_bidi: bool,
+    boundaries: bool,
+    line_options: LineBreakOptions,
+    /// Whether the current token receives URL break opportunities or
+    /// `None` between tokens.
+    url_token: Option<bool>,
+    after_separator: bool,
+    cache: LookupCache,
 }
 
 impl BoundaryState {
@@ -90,6 +870,11 @@ impl BoundaryState {
             line_state: (SOT, false),
             first: true,
             needs_bidi: false,
+            boundaries: true,
+            line_options: LineBreakOptions::default(),
+            url_token: None,
+            after_separator: false,
+            cache: LookupCache::new(),
         }
     }
 
@@ -98,11 +883,10 @@ impl BoundaryState {
         self.emoji = false;
     }
 
-    fn check_word<I>(&mut self, props: Properties, iter: &mut I) -> bool
-    where
-        I: Iterator + Clone,
-        I::Item: Borrow<char>,
-    {
+    /// Returns true if there is a word boundary before the character. The
+    /// closure yields the word break class of the following character for
+    /// rules that require lookahead.
+    fn check_word(&mut self, props: Properties, next: impl Fn() -> Option<WordBreak>) -> bool {
         use WordBreak::*;
         let b = props.word_break();
         let emoji = props.is_extended_pictographic();
@@ -165,11 +949,7 @@ impl BoundaryState {
             if b_mask & (ML.mask() | MID_NUM_LET_Q) != 0 {
                 // AHLetter	× (MidLetter | MidNumLetQ) AHLetter
                 // AHLetter (MidLetter | MidNumLetQ) × AHLetter
-                if let Some(c) = iter
-                    .clone()
-                    .next()
-                    .map(|p| p.borrow().properties().word_break())
-                {
+                if let Some(c) = next() {
                     if c.mask() & AH_LETTER != 0 {
                         self.prevent_next = true;
                         self.reset_state();
@@ -186,11 +966,7 @@ impl BoundaryState {
             if b == DQ {
                 // Hebrew_Letter × Double_Quote Hebrew_Letter
                 // Hebrew_Letter Double_Quote × Hebrew_Letter
-                if let Some(c) = iter
-                    .clone()
-                    .next()
-                    .map(|p| p.borrow().properties().word_break())
-                {
+                if let Some(c) = next() {
                     if c == HL {
                         self.prevent_next = true;
                         self.reset_state();
@@ -207,11 +983,7 @@ impl BoundaryState {
                 return false;
             }
             if b_mask & (MN.mask() | MID_NUM_LET_Q) != 0 {
-                if let Some(c) = iter
-                    .clone()
-                    .next()
-                    .map(|p| p.borrow().properties().word_break())
-                {
+                if let Some(c) = next() {
                     // Numeric (MidNum | MidNumLetQ) × Numeric
                     // Numeric × (MidNum | MidNumLetQ) Numeric
                     if c == NU {
@@ -264,9 +1036,19 @@ impl BoundaryState {
         true
     }
 
-    fn check_line(&mut self, props: Properties) -> LineBoundary {
+    /// Returns the line boundary before the character. The closure yields
+    /// the following characters for the detection of URL tokens.
+    fn check_line<T>(
+        &mut self,
+        ch: char,
+        props: Properties,
+        following: impl FnOnce() -> T,
+    ) -> LineBoundary
+    where
+        T: Iterator<Item = char>,
+    {
         let state = self.line_state;
-        let lb = props.line_break();
+        let lb = self.line_options.tailor(ch, props.line_break());
         let val = PAIR_TABLE[state.0 as usize][lb as usize];
         let mode = if val & MANDATORY_BREAK_BIT != 0 {
             LineBoundary::Hard
@@ -279,18 +1061,72 @@ impl BoundaryState {
             val & !(ALLOWED_BREAK_BIT | MANDATORY_BREAK_BIT),
             lb == LineBreak::ZWJ,
         );
+        if self.line_options.url_breaks {
+            self.check_url(ch, mode, following)
+        } else {
+            mode
+        }
+    }
+
+    /// Adds a break opportunity following a separator within a URL token.
+    fn check_url<T>(
+        &mut self,
+        ch: char,
+        mode: LineBoundary,
+        following: impl FnOnce() -> T,
+    ) -> LineBoundary
+    where
+        T: Iterator<Item = char>,
+    {
+        let is_separator = |c: char| matches!(c, '/' | '.' | '-');
+        if ch.is_whitespace() {
+            self.url_token = None;
+            self.after_separator = false;
+            return mode;
+        }
+        let url_token = *self.url_token.get_or_insert_with(|| {
+            // Tokens end at whitespace, which includes every character with
+            // a mandatory break, so the lookahead stays within the
+            // paragraph.
+            let mut len = 1;
+            let mut has_separator = is_separator(ch);
+            for c in following().take_while(|c| !c.is_whitespace()) {
+                len += 1;
+                has_separator |= is_separator(c);
+            }
+            len >= URL_BREAK_MIN_LEN && has_separator
+        });
+        let mode = if url_token
+            && self.after_separator
+            && mode == LineBoundary::None
+            && ch.is_alphanumeric()
+        {
+            LineBoundary::Soft
+        } else {
+            mode
+        };
+        self.after_separator = is_separator(ch);
         mode
     }
 
-    fn next<I>(&mut self, iter: &mut I) -> Option<CharInfo>
+    fn next<I, P>(&mut self, iter: &mut I, provider: &P) -> Option<CharInfo>
     where
         I: Iterator + Clone,
         I::Item: Borrow<char>,
+        P: PropertyProvider,
     {
-        let props = iter.next()?.borrow().properties();
-        let line = self.check_line(props);
-        let word = self.check_word(props, iter);
+        let ch = *iter.next()?.borrow();
+        let props = provider.properties_with_cache(ch as u32, &mut self.cache);
         self.needs_bidi = self.needs_bidi || props.bidi_class().needs_resolution();
+        if !self.boundaries {
+            return Some(CharInfo::from(props));
+        }
+        let line = self.check_line(ch, props, || iter.clone().map(|c| *c.borrow()));
+        let word = self.check_word(props, || {
+            iter.clone()
+                .next()
+                .map(|c| provider.properties(*c.borrow() as u32).word_break())
+        });
         Some(CharInfo::new(props, word, line))
     }
 }
@@ -356,3 +1192,40 @@ const PAIR_TABLE: [[u8; 44]; 53] = [
     [1,1,2,3,4,5,134,28,8,51,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,33,34,1,1,37,38,39,1,41,51,235,],
     [129,129,130,3,132,5,134,28,8,52,10,11,140,141,14,15,144,145,146,19,148,149,22,151,152,153,26,27,28,157,158,159,160,33,162,129,129,37,38,39,129,41,52,235,],
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_boundaries<const N: usize>(text: &str, options: LineBreakOptions) -> [LineBoundary; N] {
+        let mut boundaries = [LineBoundary::None; N];
+        let infos = paragraph_info(text.chars()).with_line_break_options(options);
+        for (boundary, info) in boundaries.iter_mut().zip(infos) {
+            *boundary = info.line_boundary();
+        }
+        boundaries
+    }
+
+    #[test]
+    fn break_all_keeps_marks() {
+        let options = LineBreakOptions {
+            word_style: LineBreakWordStyle::BreakAll,
+            ..LineBreakOptions::default()
+        };
+        use LineBoundary::*;
+        assert_eq!(line_boundaries("กัน", options), [None, None, Soft]);
+        assert_eq!(line_boundaries("ab", options), [None, Soft]);
+        assert_eq!(
+            line_boundaries("กัน", LineBreakOptions::default()),
+            [None, None, None]
+        );
+    }
+
+    #[test]
+    fn locale_options() {
+        let locale = Locale::parse("ja-JP-u-ca-japanese-lb-strict-lw-keepall").unwrap();
+        let options = LineBreakOptions::from_locale(&locale);
+        assert_eq!(options.style, LineBreakStyle::Strict);
+        assert_eq!(options.word_style, LineBreakWordStyle::KeepAll);
+    }
+}
@@ -0,0 +1,421 @@
+//! Reusable container for analysis results.
+
+use super::{
+    mirror_rtl_runs, paragraph_info, BreakLines, LineBoundary, LineBreakOptions, MandatoryBreak,
+    MirrorRtlRuns,
+};
+use crate::cluster::{CharInfo, Cluster, Parser, SourceChar};
+#[cfg(feature = "parallel")]
+use crate::unicode::{Codepoint, LineBreak};
+use crate::unicode::{JoiningType, Script};
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Analysis of a text stored as parallel arrays with one entry per
+/// character.
+///
+/// The buffer holds the characters, their byte offsets, character
+/// information with boundary analysis, BiDi embedding levels and cluster
+/// boundaries. Each is accessible as a slice indexed by character and
+/// characters can be located by byte offset.
+///
+/// The buffer is intended to be reused. Filling it with new text retains
+/// the existing allocations, so analysis of text that is updated
+/// frequently, such as once per frame, does not allocate once the buffer
+/// has grown to accommodate the largest text.
+#[derive(Clone, Default)]
+pub struct AnalysisBuffer {
+    chars: Vec<char>,
+    offsets: Vec<usize>,
+    infos: Vec<CharInfo>,
+    levels: Vec<u8>,
+    cluster_starts: Vec<bool>,
+    text_len: usize,
+    needs_bidi: bool,
+}
+
+impl AnalysisBuffer {
+    /// Creates a new empty buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes all characters from the buffer while retaining the allocated
+    /// storage.
+    pub fn clear(&mut self) {
+        self.chars.clear();
+        self.offsets.clear();
+        self.infos.clear();
+        self.levels.clear();
+        self.cluster_starts.clear();
+        self.text_len = 0;
+        self.needs_bidi = false;
+    }
+
+    /// Replaces the contents of the buffer with the analysis of the
+    /// specified text. Clusters are formed according to the rules of the
+    /// specified script as with [`Parser::from_str`].
+    ///
+    /// BiDi levels are not resolved and are initialized to zero. They can
+    /// be populated with the results of an external implementation of the
+    /// BiDi algorithm using [`levels_mut`](Self::levels_mut) when
+    /// [`needs_bidi_resolution`](Self::needs_bidi_resolution) returns true.
+    pub fn fill(&mut self, text: &str, script: Script, options: LineBreakOptions) {
+        self.clear();
+        self.resize(text.chars().count(), text.len());
+        self.needs_bidi = self.segment().analyze(text, 0, script, options);
+    }
+
+    /// Replaces the contents of the buffer with the analysis of the
+    /// specified text, analyzing paragraphs in parallel.
+    ///
+    /// The text is divided into segments at paragraph boundaries, which
+    /// reset the state of boundary analysis and cluster parsing, so the
+    /// results are the same as those produced by [`fill`](Self::fill).
+    /// Text without paragraph breaks is analyzed on a single thread.
+    #[cfg(feature = "parallel")]
+    pub fn par_fill(&mut self, text: &str, script: Script, options: LineBreakOptions) {
+        use rayon::prelude::*;
+        let target_len = (text.len() / (rayon::current_num_threads() * 4)).max(MIN_SEGMENT_LEN);
+        let ranges = split_paragraphs(text, target_len);
+        let counts = ranges
+            .par_iter()
+            .map(|range| text[range.clone()].chars().count())
+            .collect::<Vec<_>>();
+        self.clear();
+        self.resize(counts.iter().sum(), text.len());
+        let mut rest = self.segment();
+        let mut parts = Vec::with_capacity(ranges.len());
+        for (range, count) in ranges.into_iter().zip(counts) {
+            parts.push((range, rest.split_front(count)));
+        }
+        self.needs_bidi = parts
+            .into_par_iter()
+            .map(|(range, segment)| {
+                segment.analyze(&text[range.clone()], range.start, script, options)
+            })
+            .reduce(|| false, |a, b| a || b);
+    }
+
+    fn resize(&mut self, len: usize, text_len: usize) {
+        self.chars.resize(len, '\0');
+        self.offsets.resize(len, 0);
+        self.infos.resize(len, CharInfo::default());
+        self.levels.resize(len, 0);
+        self.cluster_starts.resize(len, false);
+        self.text_len = text_len;
+    }
+
+    fn segment(&mut self) -> Segment<'_> {
+        Segment {
+            chars: &mut self.chars,
+            offsets: &mut self.offsets,
+            infos: &mut self.infos,
+            cluster_starts: &mut self.cluster_starts,
+        }
+    }
+
+    /// Returns the number of characters in the buffer.
+    pub fn len(&self) -> usize {
+        self.chars.len()
+    }
+
+    /// Returns true if the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.chars.is_empty()
+    }
+
+    /// Returns the characters of the text.
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    /// Returns the byte offset of each character in the text.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the character information for each character.
+    pub fn infos(&self) -> &[CharInfo] {
+        &self.infos
+    }
+
+    /// Returns the BiDi embedding level for each character.
+    pub fn levels(&self) -> &[u8] {
+        &self.levels
+    }
+
+    /// Returns a mutable reference to the BiDi embedding level for each
+    /// character.
+    pub fn levels_mut(&mut self) -> &mut [u8] {
+        &mut self.levels
+    }
+
+    /// Returns an iterator yielding the characters in the buffer with those
+    /// in right-to-left runs mirrored according to the resolved levels. See
+    /// [`mirror_rtl_runs`](super::mirror_rtl_runs).
+    pub fn mirrored_chars(&self) -> MirrorRtlRuns<'_> {
+        mirror_rtl_runs(&self.chars, &self.levels)
+    }
+
+    /// Returns a flag for each character that is true if the character
+    /// begins a cluster.
+    pub fn cluster_starts(&self) -> &[bool] {
+        &self.cluster_starts
+    }
+
+    /// Returns true if the analysis indicates that BiDi resolution is
+    /// required.
+    pub fn needs_bidi_resolution(&self) -> bool {
+        self.needs_bidi
+    }
+
+    /// Returns the index of the character that contains the specified byte
+    /// offset or `None` if the offset is outside of the text.
+    pub fn index_of(&self, offset: usize) -> Option<usize> {
+        if offset >= self.text_len {
+            return None;
+        }
+        Some(self.offsets.partition_point(|&o| o <= offset) - 1)
+    }
+
+    /// Returns the byte range of the character at the specified index.
+    pub fn byte_range(&self, index: usize) -> Range<usize> {
+        let start = self.offsets[index];
+        start..start + self.chars[index].len_utf8()
+    }
+
+    /// Returns the kind of the mandatory break that precedes the character
+    /// at the specified index or `None` if the character does not follow a
+    /// hard line boundary. An index equal to the length of the buffer
+    /// refers to a break at the end of the text.
+    pub fn mandatory_break(&self, index: usize) -> Option<MandatoryBreak> {
+        if index == 0 || index > self.chars.len() {
+            return None;
+        }
+        if index < self.chars.len() && self.infos[index].line_boundary() != LineBoundary::Hard {
+            return None;
+        }
+        MandatoryBreak::from_char(self.chars[index - 1])
+    }
+
+    /// Returns true if letter spacing may be inserted before the character
+    /// at the specified index.
+    ///
+    /// Spacing is only inserted between clusters and never before a
+    /// combining mark, which may begin a cluster that was split. It is also
+    /// prohibited between cursively joined characters, as in Arabic, since
+    /// it would break the joining. Transparent characters are skipped when
+    /// determining whether the adjacent characters join. Join causing
+    /// characters, such as U+200D ZERO WIDTH JOINER and U+0640 ARABIC
+    /// TATWEEL, have the dual joining type in the character data, so they
+    /// join on both sides.
+    pub fn allows_letter_spacing(&self, index: usize) -> bool {
+        if index == 0 || index >= self.chars.len() || !self.cluster_starts[index] {
+            return false;
+        }
+        if self.infos[index].properties().category().is_mark() {
+            return false;
+        }
+        let joining = |info: &CharInfo| {
+            let joining_type = info.properties().joining_type();
+            Some(joining_type).filter(|&t| t != JoiningType::T)
+        };
+        use JoiningType::*;
+        let joins_following = matches!(
+            self.infos[..index].iter().rev().find_map(joining),
+            Some(D | L)
+        );
+        let joins_preceding = matches!(
+            self.infos[index..].iter().find_map(joining),
+            Some(D | R | Alaph | DalathRish)
+        );
+        !(joins_following && joins_preceding)
+    }
+
+    /// Returns an iterator that divides the text into lines no wider than
+    /// the specified width where possible. The closure is invoked with the
+    /// byte range of each cluster and returns its width. See [`BreakLines`].
+    pub fn break_lines<F>(&self, max_width: f32, measure: F) -> BreakLines<'_, F>
+    where
+        F: FnMut(Range<usize>) -> f32,
+    {
+        BreakLines::new(self, max_width, measure)
+    }
+
+    /// Returns the range of character indices for the cluster that contains
+    /// the character at the specified index.
+    pub fn cluster_range(&self, index: usize) -> Range<usize> {
+        let starts = &self.cluster_starts;
+        let start = starts[..=index].iter().rposition(|&s| s).unwrap_or(0);
+        let end = starts[index + 1..]
+            .iter()
+            .position(|&s| s)
+            .map_or(starts.len(), |i| index + 1 + i);
+        start..end
+    }
+}
+
+/// Mutable view of a range of characters in the buffer.
+struct Segment<'a> {
+    chars: &'a mut [char],
+    offsets: &'a mut [usize],
+    infos: &'a mut [CharInfo],
+    cluster_starts: &'a mut [bool],
+}
+
+impl<'a> Segment<'a> {
+    /// Removes the specified number of characters from the front of the
+    /// segment and returns them as a new segment.
+    #[cfg(feature = "parallel")]
+    fn split_front(&mut self, count: usize) -> Segment<'a> {
+        use core::mem::take;
+        let (chars, rest_chars) = take(&mut self.chars).split_at_mut(count);
+        let (offsets, rest_offsets) = take(&mut self.offsets).split_at_mut(count);
+        let (infos, rest_infos) = take(&mut self.infos).split_at_mut(count);
+        let (starts, rest_starts) = take(&mut self.cluster_starts).split_at_mut(count);
+        self.chars = rest_chars;
+        self.offsets = rest_offsets;
+        self.infos = rest_infos;
+        self.cluster_starts = rest_starts;
+        Segment {
+            chars,
+            offsets,
+            infos,
+            cluster_starts: starts,
+        }
+    }
+
+    /// Analyzes the text, which begins at the specified byte offset and must
+    /// contain exactly the number of characters in the segment. Returns true
+    /// if BiDi resolution is required.
+    fn analyze(self, text: &str, base: usize, script: Script, options: LineBreakOptions) -> bool {
+        let len = self.chars.len();
+        for ((ch, offset), (o, c)) in self
+            .chars
+            .iter_mut()
+            .zip(self.offsets.iter_mut())
+            .zip(text.char_indices())
+        {
+            *ch = c;
+            *offset = base + o;
+        }
+        let mut analysis = paragraph_info(text.chars()).with_line_break_options(options);
+        let mut filled = 0;
+        while filled < len {
+            let count = analysis.next_batch(&mut self.infos[filled..]);
+            if count == 0 {
+                break;
+            }
+            filled += count;
+        }
+        if base != 0 && len != 0 {
+            // Mandatory break following the previous paragraph.
+            self.infos[0].set_line_boundary(LineBoundary::Hard);
+        }
+        let source = self
+            .chars
+            .iter()
+            .zip(self.offsets.iter())
+            .zip(self.infos.iter())
+            .map(|((&ch, &offset), &info)| SourceChar {
+                ch,
+                offset,
+                len: ch.len_utf8() as u8,
+                info,
+                data: 0,
+            });
+        let mut parser = Parser::new(script, source);
+        let mut cluster = Cluster::new();
+        let mut index = 0;
+        while parser.next(&mut cluster) {
+            let start = cluster.range().start;
+            while index < len && self.offsets[index] < start {
+                index += 1;
+            }
+            if index < len {
+                self.cluster_starts[index] = true;
+            }
+        }
+        analysis.needs_bidi_resolution()
+    }
+}
+
+/// Minimum length in bytes of a segment for parallel analysis.
+#[cfg(feature = "parallel")]
+const MIN_SEGMENT_LEN: usize = 16 * 1024;
+
+/// Divides the text into ranges of at least the target length that end at
+/// paragraph boundaries.
+#[cfg(feature = "parallel")]
+// Option::is_none_or requires Rust 1.82.
+#[allow(clippy::unnecessary_map_or)]
+fn split_paragraphs(text: &str, target_len: usize) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    while start < text.len() {
+        let mut pos = start + target_len;
+        if pos >= text.len() {
+            break;
+        }
+        while !text.is_char_boundary(pos) {
+            pos += 1;
+        }
+        let mut chars = text[pos..].char_indices().peekable();
+        let mut end = None;
+        while let Some((i, ch)) = chars.next() {
+            let is_end = match ch.line_break() {
+                LineBreak::BK | LineBreak::LF | LineBreak::NL => true,
+                LineBreak::CR => chars.peek().map_or(true, |&(_, c)| c != '\n'),
+                _ => false,
+            };
+            if is_end {
+                end = Some(pos + i + ch.len_utf8());
+                break;
+            }
+        }
+        match end {
+            Some(end) if end < text.len() => {
+                ranges.push(start..end);
+                start = end;
+            }
+            _ => break,
+        }
+    }
+    ranges.push(start..text.len());
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letter_spacing<const N: usize>(text: &str) -> [bool; N] {
+        let mut buffer = AnalysisBuffer::new();
+        buffer.fill(text, Script::Arabic, LineBreakOptions::default());
+        assert_eq!(buffer.len(), N);
+        let mut allowed = [false; N];
+        for (i, allowed) in allowed.iter_mut().enumerate() {
+            *allowed = buffer.allows_letter_spacing(i);
+        }
+        allowed
+    }
+
+    #[test]
+    fn letter_spacing_joining() {
+        // Beh, tatweel, beh.
+        assert_eq!(letter_spacing("\u{628}\u{640}\u{628}"), [false; 3]);
+        // Alef does not join the following character, but the joiner does.
+        assert_eq!(letter_spacing("\u{627}\u{628}"), [false, true]);
+        assert_eq!(letter_spacing("\u{627}\u{200D}\u{628}"), [false; 3]);
+        // The non-joiner prevents joining.
+        assert_eq!(
+            letter_spacing("\u{628}\u{200C}\u{628}"),
+            [false, false, true]
+        );
+        // Fatha is transparent.
+        assert_eq!(letter_spacing("\u{628}\u{64E}\u{628}"), [false; 3]);
+        assert_eq!(letter_spacing("ab c"), [false, true, true, true]);
+    }
+}
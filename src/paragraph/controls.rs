@@ -0,0 +1,421 @@
+//! Detection and sanitization of directional formatting characters.
+
+use crate::unicode::{BidiClass, Codepoint};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const PDF: char = '\u{202C}';
+const PDI: char = '\u{2069}';
+
+/// Maximum explicit embedding level of the Unicode Bidirectional Algorithm.
+pub const MAX_DEPTH: u8 = 125;
+
+/// Directional formatting character found in text by
+/// [`scan_bidi_controls`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct BidiControl {
+    /// Byte offset of the character in the text.
+    pub offset: usize,
+    /// The character.
+    pub ch: char,
+    /// BiDi class of the character, which is one of the embedding, override
+    /// and isolate initiators or terminators.
+    pub class: BidiClass,
+    /// Whether the character is matched with an initiator or terminator.
+    pub status: ControlStatus,
+}
+
+impl BidiControl {
+    /// Returns true if the character begins an embedding, override or
+    /// isolate.
+    pub fn is_initiator(&self) -> bool {
+        !matches!(self.class, BidiClass::PDF | BidiClass::PDI)
+    }
+
+    /// Returns true if the character is a directional override (LRO or
+    /// RLO), which reorders the following characters regardless of their
+    /// own direction and is the most common means of disguising text.
+    pub fn is_override(&self) -> bool {
+        matches!(self.class, BidiClass::LRO | BidiClass::RLO)
+    }
+
+    /// Returns true if the character is unmatched or unterminated.
+    pub fn is_unbalanced(&self) -> bool {
+        self.status != ControlStatus::Matched
+    }
+}
+
+/// Matching status of a directional formatting character, as defined by
+/// rule BD9 of the Unicode Bidirectional Algorithm.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ControlStatus {
+    /// Initiator with a matching terminator or terminator with a matching
+    /// initiator.
+    Matched,
+    /// Terminator without a matching initiator. Such characters are
+    /// ignored by the algorithm.
+    Unmatched,
+    /// Initiator without a matching terminator, so its effect extends to
+    /// the end of the paragraph or, for an embedding or override, to the
+    /// end of the enclosing isolate.
+    Unterminated,
+}
+
+/// Treatment of directional formatting characters by
+/// [`sanitize_bidi_controls`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SanitizeMode {
+    /// Removes all directional formatting characters.
+    Strip,
+    /// Removes unmatched terminators and inserts terminators for
+    /// unterminated initiators, so that no embedding, override or isolate
+    /// extends beyond the point where it would otherwise be closed
+    /// implicitly.
+    Balance,
+}
+
+/// Problem with the nesting of directional formatting characters reported
+/// by [`check_bidi_nesting`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct NestingIssue {
+    /// Byte offset of the character in the text.
+    pub offset: usize,
+    /// The character.
+    pub ch: char,
+    /// Kind of the problem.
+    pub kind: NestingIssueKind,
+}
+
+/// Kind of a problem with the nesting of directional formatting characters.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum NestingIssueKind {
+    /// PDF that does not close an embedding or override.
+    UnmatchedPdf,
+    /// PDI that does not close an isolate.
+    UnmatchedPdi,
+    /// Embedding or override that is not closed by a PDF.
+    UnterminatedEmbedding,
+    /// Isolate that is not closed by a PDI.
+    UnterminatedIsolate,
+    /// Initiator that would raise the embedding level above [`MAX_DEPTH`]
+    /// and is therefore ignored, along with its terminator.
+    DepthExceeded,
+}
+
+/// Returns the directional formatting characters in the text along with
+/// their matching status.
+///
+/// Initiators and terminators are matched within each paragraph according
+/// to rule BD9 of the Unicode Bidirectional Algorithm. Text that contains
+/// unbalanced characters, particularly overrides, may be displayed in an
+/// order that differs from its logical order, which can be used to disguise
+/// the content of source code and other text that is reviewed visually.
+///
+/// This relies on the BiDi classes of the `bidi` feature.
+pub fn scan_bidi_controls(text: &str) -> Vec<BidiControl> {
+    let mut controls = Vec::new();
+    // Indices of the open initiators in `controls`.
+    let mut stack = Vec::new();
+    for (offset, ch) in text.char_indices() {
+        let class = ch.bidi_class();
+        match class {
+            BidiClass::LRE
+            | BidiClass::RLE
+            | BidiClass::LRO
+            | BidiClass::RLO
+            | BidiClass::LRI
+            | BidiClass::RLI
+            | BidiClass::FSI => {
+                stack.push(controls.len());
+            }
+            BidiClass::PDF => {
+                // An embedding can only be closed within the isolate that
+                // contains it.
+                let status = match stack.last() {
+                    Some(&index) if !is_isolate(controls[index]) => {
+                        stack.pop();
+                        controls[index].status = ControlStatus::Matched;
+                        ControlStatus::Matched
+                    }
+                    _ => ControlStatus::Unmatched,
+                };
+                controls.push(BidiControl {
+                    offset,
+                    ch,
+                    class,
+                    status,
+                });
+                continue;
+            }
+            BidiClass::PDI => {
+                // Closing an isolate also closes the embeddings within it,
+                // which remain unterminated.
+                let status = match stack.iter().rposition(|&i| is_isolate(controls[i])) {
+                    Some(pos) => {
+                        controls[stack[pos]].status = ControlStatus::Matched;
+                        stack.truncate(pos);
+                        ControlStatus::Matched
+                    }
+                    None => ControlStatus::Unmatched,
+                };
+                controls.push(BidiControl {
+                    offset,
+                    ch,
+                    class,
+                    status,
+                });
+                continue;
+            }
+            BidiClass::B => {
+                stack.clear();
+                continue;
+            }
+            _ => continue,
+        }
+        controls.push(BidiControl {
+            offset,
+            ch,
+            class,
+            status: ControlStatus::Unterminated,
+        });
+    }
+    controls
+}
+
+/// Returns true if the text contains directional formatting characters that
+/// are unmatched or unterminated. See [`scan_bidi_controls`].
+pub fn has_unbalanced_bidi_controls(text: &str) -> bool {
+    scan_bidi_controls(text).iter().any(|c| c.is_unbalanced())
+}
+
+/// Returns the problems with the nesting of directional formatting
+/// characters in the text, ordered by offset.
+///
+/// In addition to the unbalanced characters reported by
+/// [`scan_bidi_controls`], this determines the embedding levels according
+/// to rules X1-X8 of the Unicode Bidirectional Algorithm and reports the
+/// initiators that exceed the depth limit. The paragraph level and the
+/// direction of first strong isolates are resolved from the text.
+pub fn check_bidi_nesting(text: &str) -> Vec<NestingIssue> {
+    let overflows = overflowing_initiators(text);
+    let mut issues = Vec::new();
+    for control in scan_bidi_controls(text) {
+        let kind = match (control.status, control.class) {
+            (ControlStatus::Matched, _) => None,
+            (_, BidiClass::PDF) => Some(NestingIssueKind::UnmatchedPdf),
+            (_, BidiClass::PDI) => Some(NestingIssueKind::UnmatchedPdi),
+            (_, class) if is_isolate_class(class) => Some(NestingIssueKind::UnterminatedIsolate),
+            _ => Some(NestingIssueKind::UnterminatedEmbedding),
+        };
+        let overflow = if overflows.binary_search(&control.offset).is_ok() {
+            Some(NestingIssueKind::DepthExceeded)
+        } else {
+            None
+        };
+        for kind in kind.into_iter().chain(overflow) {
+            issues.push(NestingIssue {
+                offset: control.offset,
+                ch: control.ch,
+                kind,
+            });
+        }
+    }
+    issues
+}
+
+/// Returns a copy of the text with the nesting problems reported by
+/// [`check_bidi_nesting`] repaired.
+///
+/// Unmatched terminators are removed, initiators that exceed the depth
+/// limit are removed along with their terminators and open embeddings and
+/// isolates are closed at the end of the enclosing isolate or paragraph, as
+/// with [`SanitizeMode::Balance`]. The result displays the same as the
+/// original text, but can be embedded in other text or split into
+/// paragraphs without affecting the surrounding content.
+pub fn repair_bidi_nesting(text: &str) -> String {
+    rewrite(text, SanitizeMode::Balance, &overflowing_initiators(text))
+}
+
+/// Returns a copy of the text with directional formatting characters
+/// removed or balanced according to the specified mode.
+///
+/// When balancing, the terminators are inserted in the reverse order of
+/// the open initiators, before the PDI that closes an enclosing isolate or
+/// before the paragraph separator that ends the paragraph.
+pub fn sanitize_bidi_controls(text: &str, mode: SanitizeMode) -> String {
+    rewrite(text, mode, &[])
+}
+
+/// Rewrites the text according to the mode, also removing the initiators
+/// at the specified sorted offsets along with their terminators.
+fn rewrite(text: &str, mode: SanitizeMode, removed: &[usize]) -> String {
+    let mut result = String::with_capacity(text.len());
+    // Open initiators and whether they were removed.
+    let mut stack: Vec<(BidiClass, bool)> = Vec::new();
+    let controls = scan_bidi_controls(text);
+    let mut controls = controls.iter().peekable();
+    for (offset, ch) in text.char_indices() {
+        let control = match controls.peek() {
+            Some(control) if control.offset == offset => controls.next(),
+            _ => None,
+        };
+        if let Some(control) = control {
+            if mode == SanitizeMode::Strip || control.status == ControlStatus::Unmatched {
+                continue;
+            }
+            match control.class {
+                BidiClass::PDF => {
+                    if let Some((_, true)) = stack.pop() {
+                        continue;
+                    }
+                }
+                BidiClass::PDI => {
+                    let mut keep = true;
+                    while let Some((class, removed)) = stack.pop() {
+                        if is_isolate_class(class) {
+                            keep = !removed;
+                            break;
+                        }
+                        if !removed {
+                            result.push(PDF);
+                        }
+                    }
+                    if !keep {
+                        continue;
+                    }
+                }
+                class => {
+                    let remove = removed.binary_search(&offset).is_ok();
+                    stack.push((class, remove));
+                    if remove {
+                        continue;
+                    }
+                }
+            }
+        } else if mode == SanitizeMode::Balance && ch.bidi_class() == BidiClass::B {
+            close_all(&mut result, &mut stack);
+        }
+        result.push(ch);
+    }
+    close_all(&mut result, &mut stack);
+    result
+}
+
+/// Appends terminators for each of the open initiators that were not
+/// removed.
+fn close_all(result: &mut String, stack: &mut Vec<(BidiClass, bool)>) {
+    while let Some((class, removed)) = stack.pop() {
+        if !removed {
+            result.push(if is_isolate_class(class) { PDI } else { PDF });
+        }
+    }
+}
+
+/// Returns the offsets of the initiators that are ignored by rules X2-X5c
+/// because they exceed the depth limit or are nested within an isolate that
+/// does.
+fn overflowing_initiators(text: &str) -> Vec<usize> {
+    let mut offsets = Vec::new();
+    // Embedding levels and whether each entry is an isolate.
+    let mut stack: Vec<(u8, bool)> = Vec::new();
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+    let mut paragraph_start = true;
+    for (offset, ch) in text.char_indices() {
+        if paragraph_start {
+            let level = first_strong_is_rtl(&text[offset..], false) as u8;
+            stack.clear();
+            stack.push((level, false));
+            overflow_isolates = 0;
+            overflow_embeddings = 0;
+            valid_isolates = 0;
+            paragraph_start = false;
+        }
+        let class = ch.bidi_class();
+        let rtl = match class {
+            BidiClass::RLE | BidiClass::RLO | BidiClass::RLI => true,
+            BidiClass::LRE | BidiClass::LRO | BidiClass::LRI => false,
+            BidiClass::FSI => first_strong_is_rtl(&text[offset + ch.len_utf8()..], true),
+            BidiClass::PDF => {
+                if overflow_isolates == 0 {
+                    if overflow_embeddings > 0 {
+                        overflow_embeddings -= 1;
+                    } else if stack.len() > 1 && !stack[stack.len() - 1].1 {
+                        stack.pop();
+                    }
+                }
+                continue;
+            }
+            BidiClass::PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while let Some((_, isolate)) = stack.pop() {
+                        if isolate {
+                            break;
+                        }
+                    }
+                    valid_isolates -= 1;
+                }
+                continue;
+            }
+            BidiClass::B => {
+                paragraph_start = true;
+                continue;
+            }
+            _ => continue,
+        };
+        let isolate = is_isolate_class(class);
+        let level = stack[stack.len() - 1].0;
+        let level = if rtl {
+            (level + 1) | 1
+        } else {
+            (level + 2) & !1
+        };
+        if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+            stack.push((level, isolate));
+            if isolate {
+                valid_isolates += 1;
+            }
+        } else {
+            offsets.push(offset);
+            if isolate {
+                overflow_isolates += 1;
+            } else if overflow_isolates == 0 {
+                overflow_embeddings += 1;
+            }
+        }
+    }
+    offsets
+}
+
+/// Returns true if the first strong character of the text, skipping
+/// isolates, is right-to-left according to rules P2 and P3. The search ends
+/// at a paragraph separator or, when `isolate` is true, at the PDI that
+/// closes the isolate containing the text.
+fn first_strong_is_rtl(text: &str, isolate: bool) -> bool {
+    let mut depth = 0usize;
+    for ch in text.chars() {
+        match ch.bidi_class() {
+            BidiClass::L if depth == 0 => return false,
+            BidiClass::R | BidiClass::AL if depth == 0 => return true,
+            BidiClass::LRI | BidiClass::RLI | BidiClass::FSI => depth += 1,
+            BidiClass::PDI if depth > 0 => depth -= 1,
+            BidiClass::PDI if isolate => return false,
+            BidiClass::B => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn is_isolate(control: BidiControl) -> bool {
+    is_isolate_class(control.class)
+}
+
+fn is_isolate_class(class: BidiClass) -> bool {
+    matches!(class, BidiClass::LRI | BidiClass::RLI | BidiClass::FSI)
+}
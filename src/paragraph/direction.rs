@@ -0,0 +1,88 @@
+//! Detection of the direction of short strings.
+
+use crate::unicode::{BidiClass, Codepoint, Direction};
+
+/// Returns the direction of the text determined by its first strong
+/// character, skipping the content of isolates as in rules P2 and P3 of the
+/// Unicode Bidirectional Algorithm. Text without strong characters is left
+/// to right.
+///
+/// Unlike the paragraph level, the search continues past paragraph
+/// separators, so a message that begins with a line of digits or emoji
+/// takes its direction from the following lines.
+///
+/// This always returns [`Direction::LeftToRight`] when the `bidi` feature is
+/// disabled.
+pub fn detect_direction(text: &str) -> Direction {
+    strong_directions(text)
+        .next()
+        .unwrap_or(Direction::LeftToRight)
+}
+
+/// Returns the direction of the text as determined by [`detect_direction`]
+/// along with an indication of how reliable it is.
+///
+/// This is useful for choosing the alignment of user generated strings,
+/// such as chat messages, where text that mixes directions or lacks strong
+/// characters may be better aligned according to the surrounding interface.
+pub fn estimate_direction(text: &str) -> DirectionEstimate {
+    let mut strong = strong_directions(text);
+    let direction = match strong.next() {
+        Some(direction) => direction,
+        None => {
+            return DirectionEstimate {
+                direction: Direction::LeftToRight,
+                confidence: DirectionConfidence::Neutral,
+            }
+        }
+    };
+    let confidence = if strong.all(|other| other == direction) {
+        DirectionConfidence::Uniform
+    } else {
+        DirectionConfidence::Mixed
+    };
+    DirectionEstimate {
+        direction,
+        confidence,
+    }
+}
+
+/// Direction of a string returned by [`estimate_direction`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DirectionEstimate {
+    /// Direction of the first strong character.
+    pub direction: Direction,
+    /// Reliability of the direction.
+    pub confidence: DirectionConfidence,
+}
+
+/// Reliability of a detected direction.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum DirectionConfidence {
+    /// The text contains no strong characters outside of isolates, so the
+    /// direction is the left-to-right default.
+    Neutral,
+    /// The text contains strong characters of both directions outside of
+    /// isolates.
+    Mixed,
+    /// All strong characters outside of isolates have the same direction.
+    Uniform,
+}
+
+/// Returns an iterator yielding the direction of each strong character
+/// that is not within an isolate. Unterminated isolates end at the next
+/// paragraph separator.
+fn strong_directions(text: &str) -> impl Iterator<Item = Direction> + '_ {
+    let mut depth = 0usize;
+    text.chars().filter_map(move |ch| {
+        match ch.bidi_class() {
+            BidiClass::L if depth == 0 => return Some(Direction::LeftToRight),
+            BidiClass::R | BidiClass::AL if depth == 0 => return Some(Direction::RightToLeft),
+            BidiClass::LRI | BidiClass::RLI | BidiClass::FSI => depth += 1,
+            BidiClass::PDI => depth = depth.saturating_sub(1),
+            BidiClass::B => depth = 0,
+            _ => {}
+        }
+        None
+    })
+}
@@ -0,0 +1,187 @@
+//! Division of text into items for shaping.
+
+use crate::locale::Locale;
+use crate::unicode::{BracketType, Codepoint, Direction, Script};
+use core::ops::Range;
+
+/// Maximum number of open brackets tracked when resolving the script of
+/// paired brackets. Brackets nested more deeply are not paired.
+const MAX_BRACKETS: usize = 32;
+
+/// Run of text with a uniform script, language and direction.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Item {
+    /// Byte range of the item in the text.
+    pub range: Range<usize>,
+    /// Script of the item. Characters of the `Common` and `Inherited`
+    /// scripts take the script of the surrounding text.
+    pub script: Script,
+    /// Direction of the item.
+    pub direction: Direction,
+    /// Resolved BiDi embedding level of the item.
+    pub level: u8,
+    /// Locale of the item, which is the locale provided to [`itemize`] if
+    /// it is written in the script of the item.
+    pub language: Option<Locale>,
+}
+
+/// Returns an iterator yielding the items of the text, which are the
+/// maximal runs of characters with the same script and BiDi embedding level
+/// ready to be shaped.
+///
+/// The levels are the resolved BiDi embedding levels of the characters,
+/// such as those held by an `AnalysisBuffer`, with one entry per
+/// character. The level determines the direction of each item.
+///
+/// Characters of the `Common`, `Inherited` and `Unknown` scripts take the
+/// script of the preceding characters in the same level run, or of the
+/// following characters at the start of the run, except that a closing
+/// bracket takes the script of its paired opening bracket. Runs made up
+/// entirely of such characters take the script of the preceding item or,
+/// at the start of the text, the default script of the locale. The locale
+/// is assigned as the language of the items written in one of its scripts.
+///
+/// # Panics
+///
+/// Panics during iteration if the number of levels is less than the number
+/// of characters.
+pub fn itemize<'a>(text: &'a str, levels: &'a [u8], locale: Option<&Locale>) -> Items<'a> {
+    let script = locale
+        .and_then(|locale| locale.default_script())
+        .unwrap_or(Script::Common);
+    Items {
+        text,
+        levels,
+        offset: 0,
+        index: 0,
+        locale: locale.copied(),
+        script,
+        brackets: [('\0', None); MAX_BRACKETS],
+        bracket_len: 0,
+    }
+}
+
+/// Iterator over the items of a text. This iterator is created by the
+/// [`itemize`] function.
+#[derive(Clone)]
+pub struct Items<'a> {
+    text: &'a str,
+    levels: &'a [u8],
+    /// Byte offset of the next item.
+    offset: usize,
+    /// Character index of the next item.
+    index: usize,
+    locale: Option<Locale>,
+    /// Script of the previous item.
+    script: Script,
+    /// Closing brackets paired with open brackets and the script of the
+    /// open bracket, which is `None` while unresolved in the current item.
+    brackets: [(char, Option<Script>); MAX_BRACKETS],
+    bracket_len: usize,
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+        let level = self.levels[self.index];
+        let mut script = None;
+        let mut end = rest.len();
+        let mut count = 0;
+        for (offset, ch) in rest.char_indices() {
+            if self.levels[self.index + count] != level {
+                end = offset;
+                break;
+            }
+            let resolved = match ch.script() {
+                Script::Common | Script::Inherited | Script::Unknown => {
+                    match self.bracket(ch, script) {
+                        Ok(resolved) => resolved,
+                        Err(()) => {
+                            end = offset;
+                            break;
+                        }
+                    }
+                }
+                specific => Some(specific),
+            };
+            match (script, resolved) {
+                (Some(script), Some(resolved)) if script != resolved => {
+                    end = offset;
+                    break;
+                }
+                (None, Some(resolved)) => {
+                    script = Some(resolved);
+                    self.resolve_brackets(resolved);
+                }
+                _ => {}
+            }
+            count += 1;
+        }
+        let script = script.unwrap_or(self.script);
+        self.resolve_brackets(script);
+        self.script = script;
+        let language = self
+            .locale
+            .filter(|locale| script == Script::Common || locale.scripts().any(|s| s == script));
+        let start = self.offset;
+        self.offset += end;
+        self.index += count;
+        Some(Item {
+            range: start..self.offset,
+            script,
+            direction: if level & 1 != 0 {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            },
+            level,
+            language,
+        })
+    }
+}
+
+impl<'a> Items<'a> {
+    /// Tracks a character without a specific script as a possible bracket.
+    /// Returns the script of the paired opening bracket for a closing
+    /// bracket or an error if that script differs from the script of the
+    /// current item, which must then end before the character.
+    fn bracket(&mut self, ch: char, script: Option<Script>) -> Result<Option<Script>, ()> {
+        match ch.bracket_type() {
+            BracketType::Open(close) => {
+                if self.bracket_len < MAX_BRACKETS {
+                    self.brackets[self.bracket_len] = (close, script);
+                    self.bracket_len += 1;
+                }
+                Ok(None)
+            }
+            BracketType::Close(_) => {
+                let open = self.brackets[..self.bracket_len]
+                    .iter()
+                    .rposition(|&(close, _)| close == ch);
+                let pos = match open {
+                    Some(pos) => pos,
+                    None => return Ok(None),
+                };
+                let resolved = self.brackets[pos].1;
+                if script.is_some() && resolved.is_some() && script != resolved {
+                    return Err(());
+                }
+                self.bracket_len = pos;
+                Ok(resolved)
+            }
+            BracketType::None => Ok(None),
+        }
+    }
+
+    /// Assigns the script to the open brackets of the current item.
+    fn resolve_brackets(&mut self, script: Script) {
+        for bracket in &mut self.brackets[..self.bracket_len] {
+            bracket.1.get_or_insert(script);
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Greedy line breaking.
+
+use super::{AnalysisBuffer, LineBoundary, MandatoryBreak};
+use crate::unicode::{Codepoint, LineBreak};
+use core::ops::Range;
+
+/// Line produced by [`BreakLines`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Line {
+    /// Byte range of the line, including trailing whitespace and any line
+    /// terminator.
+    pub range: Range<usize>,
+    /// Byte offset of the end of the content of the line, which excludes
+    /// trailing whitespace and any line terminator.
+    pub content_end: usize,
+    /// Width of the content of the line.
+    pub width: f32,
+    /// Kind of the mandatory break that ends the line or `None` if the line
+    /// ends at a break opportunity or at the end of the text.
+    pub mandatory_break: Option<MandatoryBreak>,
+    /// True if the content is wider than the maximum width because the line
+    /// contains no suitable break opportunity.
+    pub is_overflowing: bool,
+}
+
+/// Treatment of content that does not fit within the maximum width and
+/// contains no break opportunity.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum LineOverflow {
+    /// Extends the line beyond the maximum width up to the next break
+    /// opportunity. This corresponds to the CSS `overflow-wrap: normal`
+    /// behavior.
+    #[default]
+    Allow,
+    /// Breaks the line between clusters. A line always contains at least
+    /// one cluster, which may be wider than the maximum width. This
+    /// corresponds to the CSS `overflow-wrap: anywhere` behavior.
+    BreakClusters,
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for LineOverflow {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(*u.choose(&[Self::Allow, Self::BreakClusters])?)
+    }
+}
+
+/// Iterator that divides the text of an analysis buffer into lines. This
+/// iterator is created by the [`AnalysisBuffer::break_lines`] method.
+///
+/// Lines are filled greedily, breaking at the last opportunity before the
+/// content exceeds the maximum width. Mandatory breaks always end a line.
+/// Trailing whitespace does not contribute to the width of a line, so it
+/// hangs past the maximum width instead of causing a break.
+pub struct BreakLines<'a, F> {
+    buffer: &'a AnalysisBuffer,
+    max_width: f32,
+    measure: F,
+    overflow: LineOverflow,
+    /// Character index of the start of the current line.
+    start: usize,
+    /// Character index of the next cluster.
+    pos: usize,
+    /// Width of the current line.
+    width: f32,
+    /// Width and end of the current line excluding trailing whitespace.
+    content_width: f32,
+    content_end: usize,
+    /// Last break opportunity in the current line.
+    candidate: Option<Candidate>,
+    /// Width of the next cluster if it has been measured.
+    measured: Option<f32>,
+}
+
+/// State of a line at a break opportunity.
+#[derive(Copy, Clone)]
+struct Candidate {
+    pos: usize,
+    width: f32,
+    content_width: f32,
+    content_end: usize,
+}
+
+impl<'a, F> BreakLines<'a, F>
+where
+    F: FnMut(Range<usize>) -> f32,
+{
+    pub(super) fn new(buffer: &'a AnalysisBuffer, max_width: f32, measure: F) -> Self {
+        Self {
+            buffer,
+            max_width,
+            measure,
+            overflow: LineOverflow::default(),
+            start: 0,
+            pos: 0,
+            width: 0.0,
+            content_width: 0.0,
+            content_end: 0,
+            candidate: None,
+            measured: None,
+        }
+    }
+
+    /// Sets the treatment of content that does not fit within the maximum
+    /// width.
+    pub fn with_overflow(mut self, overflow: LineOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Ends the current line before the character at the specified index
+    /// and returns it.
+    fn emit(&mut self, end: usize, mandatory_break: Option<MandatoryBreak>) -> Line {
+        let line = Line {
+            range: self.offset(self.start)..self.offset(end),
+            content_end: self.offset(self.content_end),
+            width: self.content_width,
+            mandatory_break,
+            is_overflowing: self.content_width > self.max_width,
+        };
+        self.start = end;
+        self.width = 0.0;
+        self.content_width = 0.0;
+        self.content_end = end;
+        self.candidate = None;
+        line
+    }
+
+    /// Ends the current line at the last break opportunity and moves the
+    /// content that follows it to the next line.
+    fn emit_candidate(&mut self, candidate: Candidate) -> Line {
+        let (width, content_width, content_end) =
+            (self.width, self.content_width, self.content_end);
+        self.width = candidate.width;
+        self.content_width = candidate.content_width;
+        self.content_end = candidate.content_end;
+        let line = self.emit(candidate.pos, None);
+        self.width = width - candidate.width;
+        if content_end > candidate.pos {
+            self.content_width = content_width - candidate.width;
+            self.content_end = content_end;
+        }
+        line
+    }
+
+    /// Returns the byte offset of the character at the specified index,
+    /// which may be equal to the length of the buffer.
+    fn offset(&self, index: usize) -> usize {
+        match index.checked_sub(1) {
+            Some(prev) => self.buffer.byte_range(prev).end,
+            None => 0,
+        }
+    }
+}
+
+impl<'a, F> Iterator for BreakLines<'a, F>
+where
+    F: FnMut(Range<usize>) -> f32,
+{
+    type Item = Line;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let buffer = self.buffer;
+        let len = buffer.len();
+        if self.start == len {
+            return None;
+        }
+        loop {
+            let pos = self.pos;
+            if pos == len {
+                return Some(self.emit(len, buffer.mandatory_break(len)));
+            }
+            let end = buffer.cluster_range(pos).end;
+            if pos > self.start {
+                match buffer.infos()[pos].line_boundary() {
+                    LineBoundary::Hard => return Some(self.emit(pos, buffer.mandatory_break(pos))),
+                    LineBoundary::Soft => {
+                        self.candidate = Some(Candidate {
+                            pos,
+                            width: self.width,
+                            content_width: self.content_width,
+                            content_end: self.content_end,
+                        })
+                    }
+                    LineBoundary::None => {}
+                }
+            }
+            let width = match self.measured.take() {
+                Some(width) => width,
+                None => {
+                    let range = buffer.offsets()[pos]..self.offset(end);
+                    (self.measure)(range)
+                }
+            };
+            let hangs = buffer.chars()[pos..end].iter().all(|&ch| is_hanging(ch));
+            if !hangs && pos > self.start && self.width + width > self.max_width {
+                if let Some(candidate) = self.candidate {
+                    self.measured = Some(width);
+                    return Some(self.emit_candidate(candidate));
+                }
+                if self.overflow == LineOverflow::BreakClusters {
+                    self.measured = Some(width);
+                    return Some(self.emit(pos, None));
+                }
+            }
+            self.width += width;
+            if !hangs {
+                self.content_width = self.width;
+                self.content_end = end;
+            }
+            self.pos = end;
+        }
+    }
+}
+
+/// Returns true if the character may extend past the end of a line.
+fn is_hanging(ch: char) -> bool {
+    let props = ch.properties();
+    props.is_whitespace() && props.line_break() != LineBreak::GL
+}
@@ -0,0 +1,73 @@
+//! Mirroring of characters in right-to-left runs.
+
+use crate::unicode::{BracketType, Codepoint};
+
+/// Returns an iterator yielding the characters with the mirrored form
+/// substituted for each character in a right-to-left run, as required by
+/// rule L4 of the Unicode Bidirectional Algorithm.
+///
+/// The levels are the resolved BiDi embedding levels of the characters, and
+/// the characters at odd levels form the right-to-left runs. Paired
+/// brackets are replaced with their pairs and other characters with their
+/// mirroring glyphs. This allows renderers to display mirrored characters
+/// correctly without a shaper that applies the OpenType `rtlm` feature.
+///
+/// Characters are yielded unchanged when the `bidi` feature is disabled.
+///
+/// # Panics
+///
+/// Panics if the number of levels is less than the number of characters.
+pub fn mirror_rtl_runs<'a>(chars: &'a [char], levels: &'a [u8]) -> MirrorRtlRuns<'a> {
+    assert!(levels.len() >= chars.len());
+    MirrorRtlRuns {
+        chars,
+        levels: &levels[..chars.len()],
+        run: 0,
+        rtl: false,
+    }
+}
+
+/// Iterator over characters with those in right-to-left runs mirrored. This
+/// iterator is created by the [`mirror_rtl_runs`] function.
+#[derive(Clone)]
+pub struct MirrorRtlRuns<'a> {
+    chars: &'a [char],
+    levels: &'a [u8],
+    /// Number of characters remaining in the current run.
+    run: usize,
+    rtl: bool,
+}
+
+impl<'a> Iterator for MirrorRtlRuns<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&ch, rest) = self.chars.split_first()?;
+        if self.run == 0 {
+            self.rtl = self.levels[0] & 1 != 0;
+            self.run = self
+                .levels
+                .iter()
+                .take_while(|&&level| (level & 1 != 0) == self.rtl)
+                .count();
+        }
+        self.chars = rest;
+        self.levels = &self.levels[1..];
+        self.run -= 1;
+        Some(if self.rtl { mirror(ch) } else { ch })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.chars.len(), Some(self.chars.len()))
+    }
+}
+
+impl<'a> ExactSizeIterator for MirrorRtlRuns<'a> {}
+
+fn mirror(ch: char) -> char {
+    let props = ch.properties();
+    match props.bracket_type(ch) {
+        BracketType::Open(other) | BracketType::Close(other) => other,
+        BracketType::None => props.mirror(ch).unwrap_or(ch),
+    }
+}
@@ -0,0 +1,145 @@
+//! Analysis for vertical text layout.
+
+use crate::unicode::{Codepoint, LineBreak, Script};
+use core::ops::Range;
+
+/// Returns an iterator yielding the runs of the text that are candidates
+/// for tate-chū-yoko, the horizontal composition of short runs of digits or
+/// Latin letters within vertical CJK text.
+///
+/// A candidate is a maximal run of at most `max_len` narrow digits, narrow
+/// Latin letters or the punctuation `!` and `?` that is bounded on both
+/// sides by wide characters or the ends of the text. Runs that adjoin
+/// spaces or other narrow characters, such as words within a Latin
+/// sentence, are not candidates. Typical values of `max_len` are two to
+/// four.
+///
+/// The East Asian Width property is not included in the character data, so
+/// width is inferred from the line break class, which assigns fullwidth and
+/// ideographic characters to the `ID` class and related classes. Fullwidth
+/// digits and letters are therefore wide and are not candidates, since they
+/// are already displayed upright.
+pub fn tate_chu_yoko(text: &str, max_len: usize) -> TateChuYoko<'_> {
+    TateChuYoko {
+        text,
+        max_len,
+        offset: 0,
+        after_wide: true,
+    }
+}
+
+/// Run of characters that is a candidate for tate-chū-yoko.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TateChuYokoRun {
+    /// Byte range of the run in the text.
+    pub range: Range<usize>,
+    /// Number of characters in the run.
+    pub len: usize,
+    /// Kind of the characters in the run.
+    pub kind: TateChuYokoKind,
+}
+
+/// Kind of the characters in a tate-chū-yoko candidate. Layout engines
+/// commonly compose only digits horizontally and rotate other runs.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TateChuYokoKind {
+    /// Decimal digits, such as the day of a date.
+    Digits,
+    /// Latin letters, such as an abbreviation.
+    Letters,
+    /// Exclamation and question marks, such as `!?`.
+    Punctuation,
+    /// Combination of the other kinds.
+    Mixed,
+}
+
+impl TateChuYokoKind {
+    fn merge(self, other: Self) -> Self {
+        if self == other {
+            self
+        } else {
+            Self::Mixed
+        }
+    }
+}
+
+/// Iterator over the tate-chū-yoko candidates of a text. This iterator is
+/// created by the [`tate_chu_yoko`] function.
+#[derive(Clone)]
+pub struct TateChuYoko<'a> {
+    text: &'a str,
+    max_len: usize,
+    /// Byte offset of the next character.
+    offset: usize,
+    /// True if the previous character is wide or at the start of the text.
+    after_wide: bool,
+}
+
+impl<'a> Iterator for TateChuYoko<'a> {
+    type Item = TateChuYokoRun;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ch) = self.text[self.offset..].chars().next() {
+            let mut kind = match candidate_kind(ch) {
+                Some(kind) => kind,
+                None => {
+                    self.after_wide = is_wide(ch);
+                    self.offset += ch.len_utf8();
+                    continue;
+                }
+            };
+            let start = self.offset;
+            let mut len = 0;
+            let mut followed_by_wide = true;
+            for ch in self.text[start..].chars() {
+                match candidate_kind(ch) {
+                    Some(other) => {
+                        kind = kind.merge(other);
+                        len += 1;
+                        self.offset += ch.len_utf8();
+                    }
+                    None => {
+                        followed_by_wide = is_wide(ch);
+                        break;
+                    }
+                }
+            }
+            let preceded_by_wide = self.after_wide;
+            self.after_wide = false;
+            if preceded_by_wide && followed_by_wide && len <= self.max_len {
+                return Some(TateChuYokoRun {
+                    range: start..self.offset,
+                    len,
+                    kind,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Returns the kind of a character that may be part of a candidate.
+fn candidate_kind(ch: char) -> Option<TateChuYokoKind> {
+    let props = ch.properties();
+    match props.line_break() {
+        LineBreak::NU if props.category().is_number() => Some(TateChuYokoKind::Digits),
+        LineBreak::AL if props.script() == Script::Latin && props.category().is_letter() => {
+            Some(TateChuYokoKind::Letters)
+        }
+        _ if matches!(ch, '!' | '?') => Some(TateChuYokoKind::Punctuation),
+        _ => None,
+    }
+}
+
+/// Returns true if the character is displayed with the width of an
+/// ideograph.
+fn is_wide(ch: char) -> bool {
+    use LineBreak::*;
+    match ch.properties().line_break() {
+        ID | CJ | H2 | H3 | JL | JV | JT => true,
+        // Fullwidth punctuation, which shares its classes with narrow
+        // punctuation.
+        OP | CL | CP | NS | EX | IS => ch >= '\u{3000}',
+        _ => false,
+    }
+}
@@ -2,96 +2,307 @@
 Unicode character properties.
 */
 
+pub mod data;
+#[cfg(feature = "emoji-sequences")]
+pub mod emoji;
+pub mod provider;
+#[cfg(feature = "unihan")]
+pub mod unihan;
+
 pub use super::compose::Decompose;
 #[doc(inline)]
 pub use super::unicode_data::{
-    BidiClass, Block, Category, ClusterBreak, JoiningType, LineBreak, Script, WordBreak,
-    UNICODE_VERSION,
+    BidiClass, Block, Category, ClusterBreak, DecompositionType, JoiningType, LineBreak,
+    MyanmarClass, Script, UseClass, WordBreak, UNICODE_VERSION,
 };
 
 use super::compose::{compose_pair, decompose, decompose_compat};
+#[cfg(feature = "blocks")]
+use super::unicode_data::BLOCK_RANGES;
 use super::unicode_data::{
-    get_record_index, MyanmarClass, Record, UseClass, BRACKETS, MIRRORS, RECORDS, SCRIPTS_BY_TAG,
-    SCRIPT_COMPLEXITY, SCRIPT_NAMES, SCRIPT_TAGS,
+    get_record_index, Record, RecordCache, RECORDS, SCRIPTS_BY_TAG, SCRIPT_COMPLEXITY,
+    SCRIPT_ISO15924_NUMBERS, SCRIPT_NAMES, SCRIPT_RTL, SCRIPT_SHORT_NAMES, SCRIPT_TAGS,
 };
 
-use core::char::from_u32_unchecked;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::ops::RangeInclusive;
+use core::str::{Chars, FromStr};
 
 const RECORD_MASK: u16 = 0x1FFF;
 const BOUNDARY_SHIFT: u16 = 13;
 
 /// Compact, constant time reference to Unicode properties for a character.
 #[derive(Copy, Clone, PartialEq, Eq, Default)]
+#[repr(transparent)]
 pub struct Properties(u16);
 
+/// Properties for ASCII characters, which avoids the trie lookup for the
+/// most common case.
+const ASCII_PROPERTIES: [Properties; 128] = {
+    let mut props = [Properties(0); 128];
+    let mut i = 0;
+    while i < 128 {
+        props[i] = Properties(get_record_index(i) as u16);
+        i += 1;
+    }
+    props
+};
+
 impl Properties {
     fn new(ch: u32) -> Self {
+        if ch < 0x80 {
+            return ASCII_PROPERTIES[ch as usize];
+        }
         Self(get_record_index(ch as usize) as u16)
     }
 
+    /// Returns the properties for a code point or an error if it is not a
+    /// Unicode scalar value.
+    ///
+    /// `TryFrom<u32>` is provided by the conversion from `u32`, which
+    /// cannot fail because invalid values are replaced.
+    pub fn try_from_u32(ch: u32) -> Result<Self, InvalidScalarError> {
+        char::from_u32(ch).map(Self::from).ok_or(InvalidScalarError)
+    }
+
+    /// Returns the properties for a character, reusing the result of a
+    /// previous lookup when the character shares its record.
+    #[inline]
+    pub(crate) fn with_cache(ch: u32, cache: &mut RecordCache) -> Self {
+        if ch < 0x80 {
+            return ASCII_PROPERTIES[ch as usize];
+        }
+        Self(cache.get(ch as usize) as u16)
+    }
+
+    /// Returns the properties for an ASCII byte.
+    #[inline]
+    pub(crate) fn from_ascii(byte: u8) -> Self {
+        ASCII_PROPERTIES[(byte & 0x7F) as usize]
+    }
+
     /// Returns the category of the character.
     pub fn category(self) -> Category {
-        self.record().category
+        self.record().category()
     }
 
-    /// Returns the unicode block that contains the character.
+    /// Returns the unicode block that contains the character. This is
+    /// always [`Block::NoBlock`] when the `blocks` feature is disabled.
     pub fn block(self) -> Block {
-        self.record().block
+        #[cfg(feature = "blocks")]
+        return self.record().block;
+        #[cfg(not(feature = "blocks"))]
+        return Block::NoBlock;
     }
 
     /// Returns the script to which the character belongs.
     pub fn script(self) -> Script {
-        self.record().script
+        self.record().script()
     }
 
     /// Returns the canonical combining class of the character.
     pub fn combining_class(self) -> u8 {
-        self.record().combining_class
+        self.record().combining_class()
     }
 
-    /// Returns the bidirectional type of the character.
+    /// Returns the bidirectional type of the character. This is always
+    /// [`BidiClass::L`] when the `bidi` feature is disabled.
     pub fn bidi_class(self) -> BidiClass {
-        self.record().bidi_class
+        #[cfg(feature = "bidi")]
+        return self.record().bidi_class();
+        #[cfg(not(feature = "bidi"))]
+        return BidiClass::L;
     }
 
     /// Returns the joining type of the character.
     pub fn joining_type(self) -> JoiningType {
-        self.record().joining_type
+        self.record().joining_type()
     }
 
     /// Returns the cluster break property for the character.
     pub fn cluster_break(self) -> ClusterBreak {
-        self.record().cluster_break
+        self.record().cluster_break()
     }
 
     /// Returns the word break property for the character.
     pub fn word_break(self) -> WordBreak {
-        self.record().word_break
+        self.record().word_break()
     }
 
     /// Returns the line break property for the character.
     pub fn line_break(self) -> LineBreak {
-        self.record().line_break
+        self.record().line_break()
     }
 
-    /// Returns true if the character is an emoji.
+    /// Returns the decomposition type of the character.
+    pub fn decomposition_type(self) -> DecompositionType {
+        self.record().decomposition_type()
+    }
+
+    /// Returns the Universal Shaping Engine class of the character. This is
+    /// always [`UseClass::O`] when the `complex-scripts` feature is disabled.
+    pub fn use_class(self) -> UseClass {
+        #[cfg(feature = "complex-scripts")]
+        return self.record().use_class();
+        #[cfg(not(feature = "complex-scripts"))]
+        return UseClass::O;
+    }
+
+    /// Returns the Myanmar shaping class of the character. This is always
+    /// [`MyanmarClass::O`] when the `complex-scripts` feature is disabled.
+    pub fn myanmar_class(self) -> MyanmarClass {
+        #[cfg(feature = "complex-scripts")]
+        return self.record().myanmar_class();
+        #[cfg(not(feature = "complex-scripts"))]
+        return MyanmarClass::O;
+    }
+
+    /// Returns true if the character is an emoji. This is always false when
+    /// the `emoji` feature is disabled.
     pub fn is_emoji(self) -> bool {
-        self.record().flags.is_emoji()
+        cfg!(feature = "emoji") && self.record().flags.is_emoji()
     }
 
     /// Returns true if the character is an extended pictographic symbol.
+    /// This is always false when the `emoji` feature is disabled.
     pub fn is_extended_pictographic(self) -> bool {
-        self.record().flags.is_extended_pictographic()
+        cfg!(feature = "emoji") && self.record().flags.is_extended_pictographic()
     }
 
-    /// Returns true if the character is an opening bracket.
+    /// Returns true if the character is an opening bracket. This is always
+    /// false when the `bidi` feature is disabled.
     pub fn is_open_bracket(self) -> bool {
-        self.record().flags.is_open_bracket()
+        cfg!(feature = "bidi") && self.record().flags.is_open_bracket()
     }
 
-    /// Returns true if the character is a closing bracket.
+    /// Returns true if the character is a closing bracket. This is always
+    /// false when the `bidi` feature is disabled.
     pub fn is_close_bracket(self) -> bool {
-        self.record().flags.is_close_bracket()
+        cfg!(feature = "bidi") && self.record().flags.is_close_bracket()
+    }
+
+    /// Returns true if the character has the `Default_Ignorable_Code_Point`
+    /// property.
+    ///
+    /// This is the derived property from `DerivedCoreProperties.txt`:
+    /// `Other_Default_Ignorable_Code_Point + Cf + Variation_Selector`, minus
+    /// `White_Space`, U+FFF9..U+FFFB, U+13430..U+13438 and
+    /// `Prepended_Concatenation_Mark`. Characters with this property should
+    /// be rendered invisibly (with a zero advance) when not otherwise
+    /// supported by a font.
+    pub fn is_default_ignorable(self) -> bool {
+        self.record().flags.is_default_ignorable()
+    }
+
+    /// Returns true if the character is a noncharacter (U+FDD0..U+FDEF and
+    /// the last two code points of each plane).
+    pub fn is_noncharacter(self) -> bool {
+        self.record().flags.is_noncharacter()
+    }
+
+    /// Returns true if the character has the `Deprecated` property.
+    pub fn is_deprecated(self) -> bool {
+        self.record().flags.is_deprecated()
+    }
+
+    /// Returns true if the character has the derived `Math` property.
+    pub fn is_math(self) -> bool {
+        self.record().flags.is_math()
+    }
+
+    /// Returns true if the character has the `White_Space` property.
+    pub fn is_whitespace(self) -> bool {
+        self.record().flags.is_whitespace()
+    }
+
+    /// Returns true if the character has the derived `Alphabetic` property.
+    pub fn is_alphabetic(self) -> bool {
+        self.record().flags.is_alphabetic()
+    }
+
+    /// Returns the set of derived core properties for the character.
+    pub fn core_properties(self) -> CoreProperties {
+        let flags = self.record().flags;
+        let mut bits = 0;
+        if flags.is_alphabetic() {
+            bits |= CoreProperties::ALPHABETIC.0;
+        }
+        if flags.is_case_ignorable() {
+            bits |= CoreProperties::CASE_IGNORABLE.0;
+        }
+        if flags.is_cased() {
+            bits |= CoreProperties::CASED.0;
+        }
+        if flags.is_default_ignorable() {
+            bits |= CoreProperties::DEFAULT_IGNORABLE.0;
+        }
+        CoreProperties(bits)
+    }
+
+    /// Returns the bracket type of the character from which these
+    /// properties were obtained.
+    ///
+    /// This and the following methods that accept a character allow the
+    /// results of a previous property lookup, such as the properties held
+    /// by a [`CharInfo`](crate::cluster::CharInfo), to be reused. The
+    /// character should be the one that produced the properties; for any
+    /// other character the result is unspecified but never invalid.
+    pub fn bracket_type(self, ch: char) -> BracketType {
+        match self.closing_bracket(ch) {
+            Some(other) => BracketType::Open(other),
+            _ => match self.opening_bracket(ch) {
+                Some(other) => BracketType::Close(other),
+                _ => BracketType::None,
+            },
+        }
+    }
+
+    /// If the character is a closing bracket, returns its opening bracket
+    /// pair.
+    pub fn opening_bracket(self, ch: char) -> Option<char> {
+        if self.is_close_bracket() {
+            mirror_of(ch, self.record())
+        } else {
+            None
+        }
+    }
+
+    /// If the character is an opening bracket, returns its closing bracket
+    /// pair.
+    pub fn closing_bracket(self, ch: char) -> Option<char> {
+        if self.is_open_bracket() {
+            mirror_of(ch, self.record())
+        } else {
+            None
+        }
+    }
+
+    /// Returns the mirror of the character, if any.
+    pub fn mirror(self, ch: char) -> Option<char> {
+        mirror_of(ch, self.record())
+    }
+
+    /// Returns the canonical decomposition of the character. The
+    /// decomposition table is only consulted for characters with a
+    /// canonical decomposition type.
+    pub fn decompose(self, ch: char) -> Decompose {
+        if self.decomposition_type() == DecompositionType::Canonical {
+            decompose(ch)
+        } else {
+            Decompose::single(ch)
+        }
+    }
+
+    /// Returns the compatibility decomposition of the character. The
+    /// decomposition tables are only consulted for characters with a
+    /// decomposition type.
+    pub fn decompose_compatible(self, ch: char) -> Decompose {
+        if self.decomposition_type() != DecompositionType::None {
+            decompose_compat(ch)
+        } else {
+            Decompose::single(ch)
+        }
     }
 
     pub(crate) fn is_ignorable(self) -> bool {
@@ -106,6 +317,34 @@ impl Properties {
         self.record().flags.contributes_to_shaping()
     }
 
+    /// Returns a code point with the same properties. Some records belong
+    /// only to surrogate code points, so this is not always a `char`.
+    #[cfg(feature = "serde")]
+    pub(crate) fn codepoint(self) -> u32 {
+        crate::unicode_data::RECORD_CODEPOINTS[(self.0 & RECORD_MASK) as usize]
+    }
+
+    /// Returns the properties of a code point, including surrogates, or
+    /// `None` if it is above U+10FFFF. This is the inverse of
+    /// [`codepoint`](Self::codepoint).
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_codepoint(codepoint: u32) -> Option<Self> {
+        if codepoint > 0x10FFFF {
+            return None;
+        }
+        Some(Self::new(codepoint))
+    }
+
+    /// Returns properties from raw bits or `None` if the record index is
+    /// out of bounds.
+    #[cfg(feature = "bytemuck")]
+    pub(crate) fn from_raw(raw: u16) -> Option<Self> {
+        if (raw & RECORD_MASK) as usize >= RECORDS.len() {
+            return None;
+        }
+        Some(Self(raw))
+    }
+
     pub(crate) fn with_boundary(mut self, b: u16) -> Self {
         self.set_boundary(b);
         self
@@ -119,23 +358,25 @@ impl Properties {
         self.0 = (self.0 & RECORD_MASK) | (boundary & 0b111) << BOUNDARY_SHIFT;
     }
 
-    pub(crate) fn use_class(self) -> (UseClass, bool, bool) {
+    #[cfg(feature = "complex-scripts")]
+    pub(crate) fn use_class_info(self) -> (UseClass, bool, bool) {
         let r = self.record();
         (
-            r.use_class,
+            r.use_class(),
             r.flags.needs_decomp(),
-            r.flags.is_extended_pictographic(),
+            self.is_extended_pictographic(),
         )
     }
 
-    pub(crate) fn myanmar_class(self) -> (MyanmarClass, bool) {
+    #[cfg(feature = "complex-scripts")]
+    pub(crate) fn myanmar_class_info(self) -> (MyanmarClass, bool) {
         let r = self.record();
-        (r.myanmar_class, r.flags.is_extended_pictographic())
+        (r.myanmar_class(), self.is_extended_pictographic())
     }
 
     pub(crate) fn cluster_class(self) -> (ClusterBreak, bool) {
         let r = self.record();
-        (r.cluster_break, r.flags.is_extended_pictographic())
+        (r.cluster_break(), self.is_extended_pictographic())
     }
 
     #[inline(always)]
@@ -158,15 +399,78 @@ impl From<&'_ char> for Properties {
     }
 }
 
+/// Code points that are not Unicode scalar values, which are surrogates and
+/// values above U+10FFFF, have the properties of U+FFFD REPLACEMENT
+/// CHARACTER. This matches the treatment of unpaired surrogates in UTF-16
+/// text. Use [`Properties::try_from_u32`] to reject such values instead.
 impl From<u32> for Properties {
     fn from(ch: u32) -> Self {
-        Self::new(ch)
+        Self::from(char::from_u32(ch).unwrap_or(REPLACEMENT_CHARACTER))
     }
 }
 
 impl From<&'_ u32> for Properties {
     fn from(ch: &'_ u32) -> Self {
-        Self::new(*ch as u32)
+        Self::from(*ch)
+    }
+}
+
+// Properties are not `Pod` because an arbitrary bit pattern may refer to a
+// record that does not exist. They can be freely cast to bytes and cast
+// back from bytes with validation.
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Properties {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::NoUninit for Properties {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::CheckedBitPattern for Properties {
+    type Bits = u16;
+
+    fn is_valid_bit_pattern(bits: &u16) -> bool {
+        Self::from_raw(*bits).is_some()
+    }
+}
+
+/// Returns an iterator yielding the properties of each character in the
+/// specified string.
+///
+/// Runs of characters that share properties, such as CJK ideographs, are
+/// resolved with a single comparison rather than a full table lookup for
+/// each character.
+pub fn properties_of(s: &str) -> PropertiesOf<'_> {
+    PropertiesOf {
+        chars: s.chars(),
+        cache: RecordCache::new(),
+    }
+}
+
+/// Iterator that yields the properties of each character in a string.
+/// This iterator is created by the [`properties_of`] function.
+#[derive(Clone)]
+pub struct PropertiesOf<'a> {
+    chars: Chars<'a>,
+    cache: RecordCache,
+}
+
+impl<'a> PropertiesOf<'a> {
+    /// Returns the remaining text.
+    pub fn as_str(&self) -> &'a str {
+        self.chars.as_str()
+    }
+}
+
+impl Iterator for PropertiesOf<'_> {
+    type Item = Properties;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.chars.next()?;
+        Some(Properties::with_cache(ch as u32, &mut self.cache))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
     }
 }
 
@@ -220,6 +524,21 @@ pub trait Codepoint: Sized + Copy {
         self.properties().line_break()
     }
 
+    /// Returns the decomposition type of the character.
+    fn decomposition_type(self) -> DecompositionType {
+        self.properties().decomposition_type()
+    }
+
+    /// Returns the Universal Shaping Engine class of the character.
+    fn use_class(self) -> UseClass {
+        self.properties().use_class()
+    }
+
+    /// Returns the Myanmar shaping class of the character.
+    fn myanmar_class(self) -> MyanmarClass {
+        self.properties().myanmar_class()
+    }
+
     /// Returns true if the character is an emoji.
     fn is_emoji(self) -> bool {
         self.properties().is_emoji()
@@ -230,6 +549,46 @@ pub trait Codepoint: Sized + Copy {
         self.properties().is_extended_pictographic()
     }
 
+    /// Returns true if the character has the `Default_Ignorable_Code_Point`
+    /// property.
+    fn is_default_ignorable(self) -> bool {
+        self.properties().is_default_ignorable()
+    }
+
+    /// Returns true if the character is a noncharacter.
+    fn is_noncharacter(self) -> bool {
+        self.properties().is_noncharacter()
+    }
+
+    /// Returns true if the character is deprecated.
+    fn is_deprecated(self) -> bool {
+        self.properties().is_deprecated()
+    }
+
+    /// Returns true if the character is a mathematical symbol.
+    fn is_math(self) -> bool {
+        self.properties().is_math()
+    }
+
+    /// Returns true if the character is whitespace.
+    ///
+    /// This agrees with the cluster level classification: a cluster
+    /// beginning with a character for which this returns true will report
+    /// a [`Whitespace`](crate::cluster::Whitespace) other than `None`.
+    fn is_whitespace(self) -> bool {
+        self.properties().is_whitespace()
+    }
+
+    /// Returns true if the character is alphabetic.
+    fn is_alphabetic(self) -> bool {
+        self.properties().is_alphabetic()
+    }
+
+    /// Returns the set of derived core properties for the character.
+    fn core_properties(self) -> CoreProperties {
+        self.properties().core_properties()
+    }
+
     /// Returns the bracket type of the character.
     fn bracket_type(self) -> BracketType;
 
@@ -260,37 +619,19 @@ impl Codepoint for char {
     }
 
     fn bracket_type(self) -> BracketType {
-        match self.closing_bracket() {
-            Some(other) => BracketType::Open(other),
-            _ => match self.opening_bracket() {
-                Some(other) => BracketType::Close(other),
-                _ => BracketType::None,
-            },
-        }
+        self.properties().bracket_type(self)
     }
 
     fn opening_bracket(self) -> Option<char> {
-        let c = self as u32;
-        if let Ok(idx) = BRACKETS.binary_search_by(|x| (x.1 as u32).cmp(&c)) {
-            return Some(unsafe { from_u32_unchecked(BRACKETS[idx].0 as u32) });
-        }
-        None
+        self.properties().opening_bracket(self)
     }
 
     fn closing_bracket(self) -> Option<char> {
-        let c = self as u32;
-        if let Ok(idx) = BRACKETS.binary_search_by(|x| (x.0 as u32).cmp(&c)) {
-            return Some(unsafe { from_u32_unchecked(BRACKETS[idx].1 as u32) });
-        }
-        None
+        self.properties().closing_bracket(self)
     }
 
     fn mirror(self) -> Option<char> {
-        let c = self as u32;
-        if let Ok(idx) = MIRRORS.binary_search_by(|x| (x.0 as u32).cmp(&c)) {
-            return Some(unsafe { from_u32_unchecked(MIRRORS[idx].1 as u32) });
-        }
-        None
+        self.properties().mirror(self)
     }
 
     fn compose(a: char, b: char) -> Option<char> {
@@ -306,6 +647,28 @@ impl Codepoint for char {
     }
 }
 
+/// Applies the mirroring offset stored in the record for a character. Paired
+/// brackets are always mirrors of each other, so this is also used to resolve
+/// bracket pairs. Returns `None` if the character has no mirror, if the
+/// offset does not produce a valid character or if the `bidi` feature is
+/// disabled.
+#[cfg(feature = "bidi")]
+#[inline]
+fn mirror_of(ch: char, record: &Record) -> Option<char> {
+    if record.mirror() == 0 {
+        return None;
+    }
+    // The offset only produces a valid character when applied to the
+    // character from which the record was obtained.
+    char::from_u32((ch as i32 + record.mirror() as i32) as u32)
+}
+
+#[cfg(not(feature = "bidi"))]
+#[inline]
+fn mirror_of(_ch: char, _record: &Record) -> Option<char> {
+    None
+}
+
 /// Bracket type of a character.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum BracketType {
@@ -317,9 +680,323 @@ pub enum BracketType {
     Close(char),
 }
 
+/// Set of derived core properties of a character.
+///
+/// These are the properties from `DerivedCoreProperties.txt` that are not
+/// expressible in terms of the general category alone.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct CoreProperties(u8);
+
+impl CoreProperties {
+    /// The `Alphabetic` property.
+    pub const ALPHABETIC: Self = Self(1);
+    /// The `Case_Ignorable` property.
+    pub const CASE_IGNORABLE: Self = Self(2);
+    /// The `Cased` property.
+    pub const CASED: Self = Self(4);
+    /// The `Default_Ignorable_Code_Point` property.
+    pub const DEFAULT_IGNORABLE: Self = Self(8);
+
+    /// Returns an empty set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the raw bits of the set.
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Returns true if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the set contains all properties in `other`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the set contains any property in `other`.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl core::ops::BitOr for CoreProperties {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for CoreProperties {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl core::ops::BitAnd for CoreProperties {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Category {
+    /// Returns true if the category is a letter (`L`).
+    pub fn is_letter(self) -> bool {
+        use Category::*;
+        matches!(
+            self,
+            Letter
+                | CasedLetter
+                | LowercaseLetter
+                | ModifierLetter
+                | OtherLetter
+                | TitlecaseLetter
+                | UppercaseLetter
+        )
+    }
+
+    /// Returns true if the category is a mark (`M`).
+    pub fn is_mark(self) -> bool {
+        use Category::*;
+        matches!(self, Mark | SpacingMark | EnclosingMark | NonspacingMark)
+    }
+
+    /// Returns true if the category is a number (`N`).
+    pub fn is_number(self) -> bool {
+        use Category::*;
+        matches!(self, Number | DecimalNumber | LetterNumber | OtherNumber)
+    }
+
+    /// Returns true if the category is punctuation (`P`).
+    pub fn is_punctuation(self) -> bool {
+        use Category::*;
+        matches!(
+            self,
+            Punctuation
+                | ConnectorPunctuation
+                | DashPunctuation
+                | ClosePunctuation
+                | FinalPunctuation
+                | InitialPunctuation
+                | OtherPunctuation
+                | OpenPunctuation
+        )
+    }
+
+    /// Returns true if the category is a symbol (`S`).
+    pub fn is_symbol(self) -> bool {
+        use Category::*;
+        matches!(
+            self,
+            Symbol | CurrencySymbol | ModifierSymbol | MathSymbol | OtherSymbol
+        )
+    }
+
+    /// Returns true if the category is a separator (`Z`).
+    pub fn is_separator(self) -> bool {
+        use Category::*;
+        matches!(
+            self,
+            Separator | LineSeparator | ParagraphSeparator | SpaceSeparator
+        )
+    }
+
+    /// Returns true if the category is other (`C`): control, format,
+    /// unassigned, private use or surrogate.
+    pub fn is_other(self) -> bool {
+        use Category::*;
+        matches!(
+            self,
+            Other | Control | Format | Unassigned | PrivateUse | Surrogate
+        )
+    }
+
+    const fn mask(self) -> u64 {
+        1 << (self as u64)
+    }
+}
+
+/// Set of general categories for filtering characters.
+///
+/// Each group value of [`Category`] (such as [`Category::Letter`]) converts
+/// to the set of its member categories.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default, Debug)]
+pub struct GeneralCategoryGroup(u64);
+
+impl GeneralCategoryGroup {
+    /// Cased letters (`LC`).
+    pub const CASED_LETTER: Self = Self(
+        Category::LowercaseLetter.mask()
+            | Category::TitlecaseLetter.mask()
+            | Category::UppercaseLetter.mask(),
+    );
+    /// Letters (`L`).
+    pub const LETTER: Self =
+        Self(Self::CASED_LETTER.0 | Category::ModifierLetter.mask() | Category::OtherLetter.mask());
+    /// Marks (`M`).
+    pub const MARK: Self = Self(
+        Category::SpacingMark.mask()
+            | Category::EnclosingMark.mask()
+            | Category::NonspacingMark.mask(),
+    );
+    /// Numbers (`N`).
+    pub const NUMBER: Self = Self(
+        Category::DecimalNumber.mask()
+            | Category::LetterNumber.mask()
+            | Category::OtherNumber.mask(),
+    );
+    /// Punctuation (`P`).
+    pub const PUNCTUATION: Self = Self(
+        Category::ConnectorPunctuation.mask()
+            | Category::DashPunctuation.mask()
+            | Category::ClosePunctuation.mask()
+            | Category::FinalPunctuation.mask()
+            | Category::InitialPunctuation.mask()
+            | Category::OtherPunctuation.mask()
+            | Category::OpenPunctuation.mask(),
+    );
+    /// Symbols (`S`).
+    pub const SYMBOL: Self = Self(
+        Category::CurrencySymbol.mask()
+            | Category::ModifierSymbol.mask()
+            | Category::MathSymbol.mask()
+            | Category::OtherSymbol.mask(),
+    );
+    /// Separators (`Z`).
+    pub const SEPARATOR: Self = Self(
+        Category::LineSeparator.mask()
+            | Category::ParagraphSeparator.mask()
+            | Category::SpaceSeparator.mask(),
+    );
+    /// Other (`C`).
+    pub const OTHER: Self = Self(
+        Category::Control.mask()
+            | Category::Format.mask()
+            | Category::Unassigned.mask()
+            | Category::PrivateUse.mask()
+            | Category::Surrogate.mask(),
+    );
+    /// All categories.
+    pub const ALL: Self = Self(
+        Self::LETTER.0
+            | Self::MARK.0
+            | Self::NUMBER.0
+            | Self::PUNCTUATION.0
+            | Self::SYMBOL.0
+            | Self::SEPARATOR.0
+            | Self::OTHER.0,
+    );
+
+    /// Returns an empty set.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the raw bits of the set.
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if the set is empty.
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if the set contains all categories in `other`.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if the set contains any category in `other`.
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Returns true if the set contains the specified category. For group
+    /// values of [`Category`], this requires all member categories.
+    pub fn contains_category(self, category: Category) -> bool {
+        self.contains(category.into())
+    }
+}
+
+impl From<Category> for GeneralCategoryGroup {
+    fn from(category: Category) -> Self {
+        use Category::*;
+        match category {
+            Other => Self::OTHER,
+            Letter => Self::LETTER,
+            CasedLetter => Self::CASED_LETTER,
+            Mark => Self::MARK,
+            Number => Self::NUMBER,
+            Punctuation => Self::PUNCTUATION,
+            Symbol => Self::SYMBOL,
+            Separator => Self::SEPARATOR,
+            _ => Self(category.mask()),
+        }
+    }
+}
+
+impl core::ops::BitOr for GeneralCategoryGroup {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitOrAssign for GeneralCategoryGroup {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl core::ops::BitAnd for GeneralCategoryGroup {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl Block {
+    /// Returns the block that contains the specified character.
+    pub fn of(ch: char) -> Self {
+        Properties::from(ch).block()
+    }
+
+    /// Returns an iterator over all blocks. This is empty when the `blocks`
+    /// feature is disabled.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        #[cfg(feature = "blocks")]
+        let len = BLOCK_RANGES.len() as u16;
+        #[cfg(not(feature = "blocks"))]
+        let len = 0;
+        // SAFETY: Block discriminants are contiguous and the ranges table has
+        // an entry for each one.
+        (0..len).map(|i| unsafe { core::mem::transmute::<u16, Self>(i) })
+    }
+
+    /// Returns the range of code points allocated to the block. The range
+    /// is empty for [`Block::NoBlock`] and when the `blocks` feature is
+    /// disabled.
+    pub fn range(self) -> RangeInclusive<u32> {
+        #[cfg(feature = "blocks")]
+        let (start, end) = BLOCK_RANGES[self as usize];
+        #[cfg(not(feature = "blocks"))]
+        let (start, end) = (1, 0);
+        start..=end
+    }
+}
+
 impl Script {
-    /// Returns the script associated with the specified OpenType language
-    /// tag.
+    /// Returns the script associated with the specified OpenType script
+    /// tag. Both the original and v2 tags of the Indic scripts are accepted.
     pub fn from_opentype(tag: u32) -> Option<Self> {
         match SCRIPTS_BY_TAG.binary_search_by(|x| x.0.cmp(&tag)) {
             Ok(index) => Some(SCRIPTS_BY_TAG[index].1),
@@ -327,11 +1004,53 @@ impl Script {
         }
     }
 
+    /// Returns an iterator over all scripts.
+    pub fn all() -> impl Iterator<Item = Self> + Clone {
+        // SAFETY: Script discriminants are contiguous and the names table has
+        // an entry for each one.
+        (0..SCRIPT_NAMES.len() as u8).map(|i| unsafe { core::mem::transmute::<u8, Self>(i) })
+    }
+
+    /// Returns the script with the specified name. This accepts both long
+    /// names ("Old_Italic") and short aliases ("Ital") and uses loose
+    /// matching, ignoring case, whitespace, underscores and hyphens.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .find(|script| loose_eq(script.name(), name) || loose_eq(script.short_name(), name))
+    }
+
     /// Returns the name of the script.
     pub fn name(self) -> &'static str {
         SCRIPT_NAMES[self as usize]
     }
 
+    /// Returns the short alias of the script.
+    pub fn short_name(self) -> &'static str {
+        SCRIPT_SHORT_NAMES[self as usize]
+    }
+
+    /// Returns the script with the specified ISO 15924 code. The comparison
+    /// is case insensitive.
+    pub fn from_iso15924_code(code: [u8; 4]) -> Option<Self> {
+        Self::all().find(|script| script.iso15924_code().eq_ignore_ascii_case(&code))
+    }
+
+    /// Returns the script with the specified ISO 15924 numeric code.
+    pub fn from_iso15924_number(number: u16) -> Option<Self> {
+        Self::all().find(|script| script.iso15924_number() == number)
+    }
+
+    /// Returns the four letter ISO 15924 code for the script.
+    pub fn iso15924_code(self) -> [u8; 4] {
+        let code = SCRIPT_SHORT_NAMES[self as usize].as_bytes();
+        [code[0], code[1], code[2], code[3]]
+    }
+
+    /// Returns the ISO 15924 numeric code for the script.
+    pub fn iso15924_number(self) -> u16 {
+        SCRIPT_ISO15924_NUMBERS[self as usize]
+    }
+
     /// Returns true if the script requires complex shaping.
     pub fn is_complex(self) -> bool {
         SCRIPT_COMPLEXITY[self as usize]
@@ -353,12 +1072,161 @@ impl Script {
         )
     }
 
-    /// Returns the script as an OpenType tag.
+    /// Returns the script as an OpenType tag. For scripts with multiple
+    /// tags, this is the preferred (newest) one.
     pub fn to_opentype(self) -> u32 {
-        SCRIPT_TAGS[self as usize]
+        SCRIPT_TAGS[self as usize][0]
+    }
+
+    /// Returns the OpenType tags for the script in the order that a shaper
+    /// should try them. For the Indic scripts, this is the v2 tag (such
+    /// as `dev2`) followed by the original tag (`deva`).
+    pub fn opentype_tags(self) -> &'static [u32] {
+        let tags = &SCRIPT_TAGS[self as usize];
+        let len = if tags[1] != 0 { 2 } else { 1 };
+        &tags[..len]
+    }
+
+    /// Returns true if word boundaries in the script cannot be determined
+    /// from Unicode properties alone and require dictionary or statistical
+    /// segmentation.
+    ///
+    /// This includes the scripts whose characters have the complex context
+    /// (`SA`) line break class along with Han and the Japanese kana
+    /// scripts.
+    pub fn requires_dictionary_segmentation(self) -> bool {
+        matches!(
+            self,
+            Script::Thai
+                | Script::Lao
+                | Script::Khmer
+                | Script::Myanmar
+                | Script::TaiLe
+                | Script::NewTaiLue
+                | Script::TaiTham
+                | Script::TaiViet
+                | Script::Ahom
+                | Script::Han
+                | Script::Hiragana
+                | Script::Katakana
+        )
+    }
+
+    /// Returns true if the script is written from right to left.
+    pub fn is_rtl(self) -> bool {
+        SCRIPT_RTL[self as usize]
+    }
+
+    /// Returns the default horizontal direction of the script or `None` for
+    /// scripts that take their direction from context (`Common`,
+    /// `Inherited` and `Unknown`).
+    pub fn horizontal_direction(self) -> Option<Direction> {
+        match self {
+            Script::Common | Script::Inherited | Script::Unknown => None,
+            _ if self.is_rtl() => Some(Direction::RightToLeft),
+            _ => Some(Direction::LeftToRight),
+        }
+    }
+}
+
+impl FromStr for Script {
+    type Err = ParseScriptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(ParseScriptError)
+    }
+}
+
+/// Error returned when a code point is not a Unicode scalar value.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct InvalidScalarError;
+
+impl fmt::Display for InvalidScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Unicode scalar value")
     }
 }
 
+/// Error returned when parsing an unrecognized script name.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ParseScriptError;
+
+impl fmt::Display for ParseScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown script name")
+    }
+}
+
+/// Scripts are serialized as their short aliases, which are the ISO 15924
+/// codes.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Script {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.short_name())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Script {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(crate::locale::FromStrVisitor::<Self>::new("a script name"))
+    }
+}
+
+#[cfg(feature = "unicode-script")]
+impl From<Script> for unicode_script::Script {
+    fn from(script: Script) -> Self {
+        Self::from_short_name(script.short_name()).unwrap_or(Self::Unknown)
+    }
+}
+
+/// Scripts that were added in versions of Unicode newer than the data in
+/// this crate are converted to [`Script::Unknown`].
+#[cfg(feature = "unicode-script")]
+impl From<unicode_script::Script> for Script {
+    fn from(script: unicode_script::Script) -> Self {
+        Self::from_iso15924_code(script.as_iso15924_tag().to_be_bytes()).unwrap_or(Self::Unknown)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Script {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let index = u.choose_index(SCRIPT_NAMES.len())?;
+        Ok(Self::all().nth(index).unwrap_or(Self::Unknown))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(Category, SpaceSeparator);
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(BidiClass, WS);
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(LineBreak, ZWJ);
+#[cfg(feature = "serde")]
+impl_serde_discriminant!(WordBreak, ZWJ);
+
+/// Compares property value names using the loose matching rule (UAX44-LM3).
+fn loose_eq(a: &str, b: &str) -> bool {
+    let significant = |c: &u8| !matches!(c, b' ' | b'_' | b'-');
+    a.bytes()
+        .filter(significant)
+        .map(|c| c.to_ascii_lowercase())
+        .eq(b
+            .bytes()
+            .filter(significant)
+            .map(|c| c.to_ascii_lowercase()))
+}
+
+/// Horizontal writing direction.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    /// Left to right.
+    LeftToRight,
+    /// Right to left.
+    RightToLeft,
+}
+
 impl WordBreak {
     pub(crate) const fn mask(self) -> u32 {
         1 << (self as u32)
@@ -381,4 +1249,60 @@ impl BidiClass {
         const BIDI_MASK: u32 = EXPLICIT_MASK | R.mask() | AL.mask() | AN.mask();
         self.mask() & BIDI_MASK != 0
     }
+
+    /// Returns true if the bidi class is strong (L, R or AL).
+    pub fn is_strong(self) -> bool {
+        use BidiClass::*;
+        const STRONG_MASK: u32 = L.mask() | R.mask() | AL.mask();
+        self.mask() & STRONG_MASK != 0
+    }
+
+    /// Returns true if the bidi class is weak (EN, ES, ET, AN, CS, NSM or
+    /// BN).
+    pub fn is_weak(self) -> bool {
+        use BidiClass::*;
+        const WEAK_MASK: u32 =
+            EN.mask() | ES.mask() | ET.mask() | AN.mask() | CS.mask() | NSM.mask() | BN.mask();
+        self.mask() & WEAK_MASK != 0
+    }
+
+    /// Returns true if the bidi class is neutral (B, S, WS or ON).
+    pub fn is_neutral(self) -> bool {
+        use BidiClass::*;
+        const NEUTRAL_MASK: u32 = B.mask() | S.mask() | WS.mask() | ON.mask();
+        self.mask() & NEUTRAL_MASK != 0
+    }
+
+    /// Returns true if the bidi class is an explicit formatting class
+    /// (embeddings, overrides, isolates and their terminators).
+    pub fn is_explicit(self) -> bool {
+        use BidiClass::*;
+        const EXPLICIT_MASK: u32 = LRE.mask()
+            | LRO.mask()
+            | RLE.mask()
+            | RLO.mask()
+            | PDF.mask()
+            | LRI.mask()
+            | RLI.mask()
+            | FSI.mask()
+            | PDI.mask();
+        self.mask() & EXPLICIT_MASK != 0
+    }
+
+    /// Returns true if the bidi class is an isolate initiator (LRI, RLI or
+    /// FSI).
+    pub fn is_isolate_initiator(self) -> bool {
+        use BidiClass::*;
+        const ISOLATE_MASK: u32 = LRI.mask() | RLI.mask() | FSI.mask();
+        self.mask() & ISOLATE_MASK != 0
+    }
+
+    /// Returns true if characters of this bidi class are removed by rule X9
+    /// of the bidirectional algorithm (embeddings, overrides, PDF and BN).
+    pub fn is_removed_by_x9(self) -> bool {
+        use BidiClass::*;
+        const X9_MASK: u32 =
+            RLE.mask() | LRE.mask() | RLO.mask() | LRO.mask() | PDF.mask() | BN.mask();
+        self.mask() & X9_MASK != 0
+    }
 }
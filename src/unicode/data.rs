@@ -0,0 +1,301 @@
+/*!
+Read-only access to the generated Unicode data tables.
+
+This allows tools for visualization and validation to enumerate the
+properties as seen by this crate rather than parsing the Unicode Character
+Database directly. Enumeration is not intended for use during text
+analysis and some functions scan the full range of code points.
+*/
+
+use super::{Block, Properties, Script};
+use crate::compose::compose_pair;
+use crate::unicode_data::{
+    get_record_index, RecordCache, COMPOSE0, COMPOSE1, DATA_SOURCES, RECORDS, UNICODE_VERSION,
+};
+use core::ops::RangeInclusive;
+
+/// The largest Unicode code point.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+/// Returns the versions of the data from which the tables were generated.
+pub fn data_versions() -> DataVersions {
+    DataVersions {
+        unicode: UNICODE_VERSION,
+        emoji: source_version("Emoji"),
+        cluster_break: source_version("Grapheme_Cluster_Break"),
+        word_break: source_version("Word_Break"),
+        line_break: source_version("Line_Break"),
+        use_class: source_version("USE_Class"),
+        myanmar_class: source_version("Myanmar_Class"),
+    }
+}
+
+fn source_version(property: &str) -> (u8, u8, u8) {
+    DATA_SOURCES
+        .iter()
+        .find(|source| source.0 == property)
+        .map(|source| source.2)
+        .unwrap_or(UNICODE_VERSION)
+}
+
+/// Versions of the data from which the tables were generated.
+///
+/// Each property is normally derived from the Unicode Character Database
+/// of the same version, but the emoji data and the classes of the shaping
+/// models are versioned separately. The classes of the Universal Shaping
+/// Engine and the Myanmar shaping model are taken from a baseline version
+/// and derived from the Indic categories for characters assigned later.
+/// Locale specific data is not part of the generated tables and is not
+/// covered here.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DataVersions {
+    /// Version of the Unicode Character Database.
+    pub unicode: (u8, u8, u8),
+    /// Version of the emoji data.
+    pub emoji: (u8, u8, u8),
+    /// Version of the grapheme cluster break property.
+    pub cluster_break: (u8, u8, u8),
+    /// Version of the word break property.
+    pub word_break: (u8, u8, u8),
+    /// Version of the line break property.
+    pub line_break: (u8, u8, u8),
+    /// Baseline version of the Universal Shaping Engine classes.
+    pub use_class: (u8, u8, u8),
+    /// Baseline version of the Myanmar shaping classes.
+    pub myanmar_class: (u8, u8, u8),
+}
+
+impl DataVersions {
+    /// Returns an iterator yielding the source of each property in the
+    /// tables.
+    pub fn sources(&self) -> impl Iterator<Item = DataSource> + Clone {
+        DATA_SOURCES
+            .iter()
+            .map(|&(property, file, version)| DataSource {
+                property,
+                file,
+                version,
+            })
+    }
+
+    /// Returns the source of the property with the specified name as it
+    /// appears in the Unicode Character Database, for example
+    /// `"Line_Break"`.
+    pub fn source(&self, property: &str) -> Option<DataSource> {
+        self.sources().find(|source| source.property == property)
+    }
+}
+
+/// File from which a property in the tables was derived.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct DataSource {
+    /// Name of the property.
+    pub property: &'static str,
+    /// Name of the data file, such as `"LineBreak.txt"`.
+    pub file: &'static str,
+    /// Version declared by the data file.
+    pub version: (u8, u8, u8),
+}
+
+/// Returns the number of distinct property records.
+///
+/// Each code point maps to one record and code points with identical
+/// properties share a record.
+pub fn record_count() -> usize {
+    RECORDS.len()
+}
+
+/// Returns the index of the property record for the specified code point.
+/// Code points outside of the Unicode range map to record zero.
+pub fn record_index(codepoint: u32) -> usize {
+    get_record_index(codepoint as usize)
+}
+
+/// Returns the properties of the record at the specified index or `None`
+/// if the index is out of bounds.
+pub fn record(index: usize) -> Option<Properties> {
+    if index < RECORDS.len() {
+        Some(Properties(index as u16))
+    } else {
+        None
+    }
+}
+
+/// Returns an iterator yielding the properties of each record in order of
+/// record index.
+pub fn records() -> impl Iterator<Item = Properties> + Clone {
+    (0..RECORDS.len() as u16).map(Properties)
+}
+
+/// Returns an iterator yielding maximal ranges of code points that share a
+/// script, in code point order. The ranges cover all code points, including
+/// those that are unassigned and have the [`Script::Unknown`] script.
+pub fn script_ranges() -> ScriptRanges {
+    ScriptRanges {
+        next: 0,
+        cache: RecordCache::new(),
+    }
+}
+
+/// Iterator that yields ranges of code points that share a script. This
+/// iterator is created by the [`script_ranges`] function.
+#[derive(Clone)]
+pub struct ScriptRanges {
+    next: u32,
+    cache: RecordCache,
+}
+
+impl Iterator for ScriptRanges {
+    type Item = (RangeInclusive<u32>, Script);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next > MAX_CODEPOINT {
+            return None;
+        }
+        let start = self.next;
+        let script = Properties::with_cache(start, &mut self.cache).script();
+        let mut end = start;
+        while end < MAX_CODEPOINT
+            && Properties::with_cache(end + 1, &mut self.cache).script() == script
+        {
+            end += 1;
+        }
+        self.next = end + 1;
+        Some((start..=end, script))
+    }
+}
+
+/// Returns an iterator yielding the range of code points allocated to each
+/// block, in code point order. This is empty when the `blocks` feature is
+/// disabled.
+pub fn block_ranges() -> BlockRanges {
+    BlockRanges { next: 0 }
+}
+
+/// Iterator that yields the ranges of code points allocated to blocks. This
+/// iterator is created by the [`block_ranges`] function.
+#[derive(Clone)]
+pub struct BlockRanges {
+    next: u32,
+}
+
+impl Iterator for BlockRanges {
+    type Item = (RangeInclusive<u32>, Block);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Blocks are ordered by name, so find the first block that begins
+        // at or after the end of the previous one.
+        let block = Block::all()
+            .filter(|block| !block.range().is_empty() && *block.range().start() >= self.next)
+            .min_by_key(|block| *block.range().start())?;
+        let range = block.range();
+        self.next = range.end() + 1;
+        Some((range, block))
+    }
+}
+
+/// Returns an iterator yielding each pair of opening and closing paired
+/// brackets, in code point order of the opening bracket.
+#[cfg(feature = "bidi")]
+pub fn bracket_pairs() -> BracketPairs {
+    BracketPairs { next: 0 }
+}
+
+/// Iterator that yields pairs of opening and closing brackets. This
+/// iterator is created by the [`bracket_pairs`] function.
+#[cfg(feature = "bidi")]
+#[derive(Clone)]
+pub struct BracketPairs {
+    next: u32,
+}
+
+#[cfg(feature = "bidi")]
+impl Iterator for BracketPairs {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next <= MAX_CODEPOINT {
+            let cp = self.next;
+            self.next += 1;
+            let ch = match char::from_u32(cp) {
+                Some(ch) => ch,
+                None => continue,
+            };
+            if let Some(close) = Properties::from(ch).closing_bracket(ch) {
+                return Some((ch, close));
+            }
+        }
+        None
+    }
+}
+
+/// Returns an iterator yielding each pair of characters with a primary
+/// composite, along with the composite. Algorithmic compositions of Hangul
+/// syllables are not included.
+pub fn composition_pairs() -> CompositionPairs {
+    CompositionPairs {
+        first: Starters::default(),
+        second: Starters::default(),
+        current: None,
+    }
+}
+
+/// Iterator that yields pairs of characters with their primary composite.
+/// This iterator is created by the [`composition_pairs`] function.
+#[derive(Clone)]
+pub struct CompositionPairs {
+    first: Starters,
+    second: Starters,
+    current: Option<char>,
+}
+
+impl Iterator for CompositionPairs {
+    type Item = (char, char, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = match self.current {
+                Some(first) => first,
+                None => {
+                    let first = self.first.next(&COMPOSE0)?;
+                    self.current = Some(first);
+                    self.second = Starters::default();
+                    first
+                }
+            };
+            match self.second.next(&COMPOSE1) {
+                Some(second) => {
+                    if let Some(composite) = compose_pair(first, second) {
+                        return Some((first, second, composite));
+                    }
+                }
+                None => self.current = None,
+            }
+        }
+    }
+}
+
+/// Cursor over the characters of a composition table, which is a sequence
+/// of ranges terminated by an entry with a zero start.
+#[derive(Copy, Clone, Default)]
+struct Starters {
+    entry: usize,
+    offset: u32,
+}
+
+impl Starters {
+    fn next(&mut self, table: &[(u32, u16, u16)]) -> Option<char> {
+        let &(start, len, _) = table.get(self.entry)?;
+        if start == 0 {
+            return None;
+        }
+        let cp = start + self.offset;
+        if self.offset < len as u32 {
+            self.offset += 1;
+        } else {
+            self.entry += 1;
+            self.offset = 0;
+        }
+        char::from_u32(cp)
+    }
+}
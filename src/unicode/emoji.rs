@@ -0,0 +1,196 @@
+/*!
+Qualification status of emoji sequences.
+
+The set of recommended (RGI) emoji sequences is derived from the
+`emoji-test.txt` file of Unicode Technical Standard #51, which lists every
+emoji sequence along with forms that omit presentation selectors. A
+sequence that is not fully qualified should be displayed with emoji
+presentation only once the missing `U+FE0F VARIATION SELECTOR-16` is
+inserted, which is the form produced by [`fully_qualified`].
+*/
+
+use crate::unicode_data::{EMOJI_SEQUENCES, EMOJI_SEQUENCE_CHARS};
+use core::cmp::Ordering;
+use core::ops::Range;
+
+const PRESENTATION_SELECTOR: u32 = 0xFE0F;
+
+/// Qualification status of an emoji sequence.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Qualification {
+    /// Character that is only used as a component of other sequences, such
+    /// as a skin tone modifier.
+    Component,
+    /// Sequence with all presentation selectors required for emoji
+    /// presentation.
+    FullyQualified,
+    /// Sequence with the initial presentation selector but lacking others.
+    MinimallyQualified,
+    /// Sequence lacking the initial presentation selector.
+    Unqualified,
+}
+
+impl Qualification {
+    fn from_raw(raw: u8) -> Self {
+        match raw {
+            0 => Self::Component,
+            1 => Self::FullyQualified,
+            2 => Self::MinimallyQualified,
+            _ => Self::Unqualified,
+        }
+    }
+
+    /// Returns true if presentation selectors must be inserted to display
+    /// the sequence as emoji.
+    pub fn needs_presentation_selector(self) -> bool {
+        matches!(self, Self::MinimallyQualified | Self::Unqualified)
+    }
+}
+
+/// Returns the qualification status of the specified sequence or `None` if
+/// it is not an emoji sequence.
+pub fn qualification(sequence: &[char]) -> Option<Qualification> {
+    lookup(sequence.iter().copied())
+}
+
+/// Returns the fully qualified form of the specified emoji sequence. The
+/// sequence may lack or contain any of the presentation selectors.
+pub fn fully_qualified(sequence: &[char]) -> Option<Sequence> {
+    let group = group(sequence.iter().copied())?;
+    // Entries with the same key are ordered by status, so a fully qualified
+    // form precedes the other forms.
+    let entry = &EMOJI_SEQUENCES[group.start];
+    if Qualification::from_raw(entry.2) != Qualification::FullyQualified {
+        return None;
+    }
+    Some(Sequence {
+        chars: chars(entry),
+    })
+}
+
+/// Returns an iterator yielding each emoji sequence in the set along with
+/// its qualification status.
+pub fn sequences() -> impl Iterator<Item = (Sequence, Qualification)> + Clone {
+    EMOJI_SEQUENCES.iter().map(|entry| {
+        let sequence = Sequence {
+            chars: chars(entry),
+        };
+        (sequence, Qualification::from_raw(entry.2))
+    })
+}
+
+/// Iterator over the characters of an emoji sequence. This iterator is
+/// created by the [`fully_qualified`] and [`sequences`] functions.
+#[derive(Clone)]
+pub struct Sequence {
+    chars: &'static [u32],
+}
+
+impl Iterator for Sequence {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&first, rest) = self.chars.split_first()?;
+        self.chars = rest;
+        char::from_u32(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.chars.len(), Some(self.chars.len()))
+    }
+}
+
+impl ExactSizeIterator for Sequence {}
+
+pub(crate) fn lookup(sequence: impl Iterator<Item = char> + Clone) -> Option<Qualification> {
+    let group = group(sequence.clone())?;
+    EMOJI_SEQUENCES[group]
+        .iter()
+        .find(|entry| {
+            chars(entry)
+                .iter()
+                .copied()
+                .eq(sequence.clone().map(|c| c as u32))
+        })
+        .map(|entry| Qualification::from_raw(entry.2))
+}
+
+/// Returns the range of entries that match the sequence after removing
+/// presentation selectors.
+fn group(sequence: impl Iterator<Item = char> + Clone) -> Option<Range<usize>> {
+    let key = sequence
+        .map(|c| c as u32)
+        .filter(|&cp| cp != PRESENTATION_SELECTOR);
+    let compare = |entry: &(u16, u8, u8)| {
+        let entry_key = chars(entry)
+            .iter()
+            .copied()
+            .filter(|&cp| cp != PRESENTATION_SELECTOR);
+        entry_key.cmp(key.clone())
+    };
+    let start = EMOJI_SEQUENCES.partition_point(|entry| compare(entry) == Ordering::Less);
+    let len = EMOJI_SEQUENCES[start..]
+        .iter()
+        .take_while(|entry| compare(entry) == Ordering::Equal)
+        .count();
+    if len == 0 {
+        None
+    } else {
+        Some(start..start + len)
+    }
+}
+
+fn chars(entry: &(u16, u8, u8)) -> &'static [u32] {
+    let start = entry.0 as usize;
+    &EMOJI_SEQUENCE_CHARS[start..start + entry.1 as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spot_check() {
+        let fully = [
+            &['\u{1F600}'][..],
+            &['\u{1F44D}', '\u{1F3FD}'],
+            &['\u{1F469}', '\u{200D}', '\u{1F4BB}'],
+            &['\u{1F3F3}', '\u{FE0F}', '\u{200D}', '\u{1F308}'],
+            &[
+                '\u{1F468}',
+                '\u{200D}',
+                '\u{1F469}',
+                '\u{200D}',
+                '\u{1F467}',
+            ],
+        ];
+        for sequence in &fully {
+            assert_eq!(
+                qualification(sequence),
+                Some(Qualification::FullyQualified),
+                "{:?}",
+                sequence
+            );
+            assert!(fully_qualified(sequence)
+                .unwrap()
+                .eq(sequence.iter().copied()));
+        }
+        let rainbow = ['\u{1F3F3}', '\u{200D}', '\u{1F308}'];
+        assert_eq!(qualification(&rainbow), Some(Qualification::Unqualified));
+        assert!(fully_qualified(&rainbow)
+            .unwrap()
+            .eq(fully[3].iter().copied()));
+        assert_eq!(
+            qualification(&['\u{1F3FD}']),
+            Some(Qualification::Component)
+        );
+        assert_eq!(qualification(&['a']), None);
+    }
+
+    #[test]
+    fn complete() {
+        let count = |status| sequences().filter(|s| s.1 == status).count();
+        assert!(count(Qualification::FullyQualified) > 3000);
+        assert!(count(Qualification::Component) >= 9);
+    }
+}
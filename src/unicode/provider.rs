@@ -0,0 +1,206 @@
+/*!
+Pluggable sources of character properties.
+
+The static tables compiled into this crate are the default source of
+properties. A [`PropertyProvider`] allows alternate assignments to be
+supplied at runtime, for example to trim the data to a set of scripts, to
+assign properties to characters added in a newer version of Unicode or to
+build test fixtures.
+
+Properties are references to the records of the static tables, which are
+enumerated by [`records`](super::data::records). A provider chooses the
+record for each code point, so it can assign any combination of properties
+that exists in those tables.
+*/
+
+use super::data::{record, record_count};
+use super::Properties;
+use crate::unicode_data::RecordCache;
+use core::char::REPLACEMENT_CHARACTER;
+use core::fmt;
+use core::ops::RangeInclusive;
+
+const ENTRY_SIZE: usize = 10;
+
+/// Source of Unicode properties for code points.
+pub trait PropertyProvider {
+    /// Returns the properties for the specified code point.
+    fn properties(&self, codepoint: u32) -> Properties;
+
+    /// Returns the properties for a code point in a sequence of lookups
+    /// that share the specified cache. The default implementation ignores
+    /// the cache.
+    fn properties_with_cache(&self, codepoint: u32, cache: &mut LookupCache) -> Properties {
+        let _ = cache;
+        self.properties(codepoint)
+    }
+}
+
+impl<P: PropertyProvider + ?Sized> PropertyProvider for &'_ P {
+    fn properties(&self, codepoint: u32) -> Properties {
+        (**self).properties(codepoint)
+    }
+
+    fn properties_with_cache(&self, codepoint: u32, cache: &mut LookupCache) -> Properties {
+        (**self).properties_with_cache(codepoint, cache)
+    }
+}
+
+/// State that is retained across the lookups for a sequence of code points.
+#[derive(Copy, Clone)]
+pub struct LookupCache(pub(crate) RecordCache);
+
+impl LookupCache {
+    /// Creates a new empty cache.
+    pub const fn new() -> Self {
+        Self(RecordCache::new())
+    }
+}
+
+impl Default for LookupCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Provider for the static tables compiled into this crate.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct StaticProvider;
+
+impl PropertyProvider for StaticProvider {
+    fn properties(&self, codepoint: u32) -> Properties {
+        Properties::from(codepoint)
+    }
+
+    fn properties_with_cache(&self, codepoint: u32, cache: &mut LookupCache) -> Properties {
+        // Invalid code points are replaced as in the conversion from u32.
+        let codepoint =
+            char::from_u32(codepoint).map_or(REPLACEMENT_CHARACTER as u32, |_| codepoint);
+        Properties::with_cache(codepoint, &mut cache.0)
+    }
+}
+
+/// Provider that maps ranges of code points to records in a table loaded
+/// at runtime, falling back to another provider for code points that are
+/// not listed.
+///
+/// The table is a sequence of entries of [`ENTRY_SIZE`](Self::ENTRY_SIZE)
+/// bytes, each holding the first and last code point of a range as 32-bit
+/// little endian integers followed by a 16-bit little endian record index.
+/// Ranges must be sorted and must not overlap. Entries can be produced with
+/// the [`entry`](Self::entry) function.
+#[derive(Copy, Clone, Debug)]
+pub struct TableProvider<'a, P = StaticProvider> {
+    table: &'a [u8],
+    fallback: P,
+}
+
+impl<'a> TableProvider<'a> {
+    /// Size of a table entry in bytes.
+    pub const ENTRY_SIZE: usize = ENTRY_SIZE;
+
+    /// Creates a provider from the specified table, using the static tables
+    /// for code points that are not listed. Returns an error if the table
+    /// is malformed or refers to a record that does not exist.
+    pub fn from_bytes(table: &'a [u8]) -> Result<Self, InvalidTableError> {
+        let entries = table.chunks_exact(ENTRY_SIZE);
+        if !entries.remainder().is_empty() {
+            return Err(InvalidTableError);
+        }
+        let mut next = 0;
+        for bytes in entries {
+            let (range, index) = read_entry(bytes);
+            if *range.start() < next
+                || range.start() > range.end()
+                || *range.end() > 0x10FFFF
+                || index as usize >= record_count()
+            {
+                return Err(InvalidTableError);
+            }
+            next = range.end() + 1;
+        }
+        Ok(Self {
+            table,
+            fallback: StaticProvider,
+        })
+    }
+
+    /// Encodes an entry that assigns the record at the specified index to
+    /// a range of code points.
+    pub fn entry(range: RangeInclusive<u32>, index: u16) -> [u8; ENTRY_SIZE] {
+        let mut bytes = [0; ENTRY_SIZE];
+        bytes[0..4].copy_from_slice(&range.start().to_le_bytes());
+        bytes[4..8].copy_from_slice(&range.end().to_le_bytes());
+        bytes[8..10].copy_from_slice(&index.to_le_bytes());
+        bytes
+    }
+}
+
+impl<'a, P> TableProvider<'a, P> {
+    /// Sets the provider for code points that are not listed in the table.
+    pub fn with_fallback<Q: PropertyProvider>(self, fallback: Q) -> TableProvider<'a, Q> {
+        TableProvider {
+            table: self.table,
+            fallback,
+        }
+    }
+
+    /// Returns the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.table.len() / ENTRY_SIZE
+    }
+
+    /// Returns true if the table is empty.
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Returns the record index assigned to the code point by the table.
+    fn lookup(&self, codepoint: u32) -> Option<u16> {
+        let (mut lo, mut hi) = (0, self.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let offset = mid * ENTRY_SIZE;
+            let (range, index) = read_entry(&self.table[offset..]);
+            if codepoint < *range.start() {
+                hi = mid;
+            } else if codepoint > *range.end() {
+                lo = mid + 1;
+            } else {
+                return Some(index);
+            }
+        }
+        None
+    }
+}
+
+impl<P: PropertyProvider> PropertyProvider for TableProvider<'_, P> {
+    fn properties(&self, codepoint: u32) -> Properties {
+        self.lookup(codepoint)
+            .and_then(|index| record(index as usize))
+            .unwrap_or_else(|| self.fallback.properties(codepoint))
+    }
+
+    fn properties_with_cache(&self, codepoint: u32, cache: &mut LookupCache) -> Properties {
+        self.lookup(codepoint)
+            .and_then(|index| record(index as usize))
+            .unwrap_or_else(|| self.fallback.properties_with_cache(codepoint, cache))
+    }
+}
+
+fn read_entry(bytes: &[u8]) -> (RangeInclusive<u32>, u16) {
+    let u32_at =
+        |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+    let index = u16::from_le_bytes([bytes[8], bytes[9]]);
+    (u32_at(0)..=u32_at(4), index)
+}
+
+/// Error returned when a property table is malformed.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct InvalidTableError;
+
+impl fmt::Display for InvalidTableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid property table")
+    }
+}
@@ -0,0 +1,97 @@
+/*!
+Properties of CJK ideographs from the Unihan database.
+
+This is a small subset of the database that is useful for layout and
+search: the total stroke count, which is commonly used for sorting, the
+simplified and traditional variants and whether a character has a Mandarin
+reading.
+*/
+
+use crate::unihan_data::{unihan_value, MANDARIN, SIMPLIFIED_VARIANTS, TRADITIONAL_VARIANTS};
+
+/// Returns the total number of strokes of the ideograph (`kTotalStrokes`)
+/// or `None` if the character is not listed. Where the count differs by
+/// region, this is the count for mainland China.
+pub fn total_strokes(ch: char) -> Option<u8> {
+    match unihan_value(ch as usize) as u8 & !MANDARIN {
+        0 => None,
+        count => Some(count),
+    }
+}
+
+/// Returns true if the ideograph has a Mandarin reading (`kMandarin`).
+pub fn has_mandarin_reading(ch: char) -> bool {
+    unihan_value(ch as usize) as u8 & MANDARIN != 0
+}
+
+/// Returns an iterator yielding the simplified variants of the ideograph
+/// (`kSimplifiedVariant`). Characters that are their own simplified form
+/// have no variants.
+pub fn simplified_variants(ch: char) -> Variants {
+    Variants::new(&SIMPLIFIED_VARIANTS, ch)
+}
+
+/// Returns an iterator yielding the traditional variants of the ideograph
+/// (`kTraditionalVariant`). Characters that are their own traditional form
+/// have no variants.
+pub fn traditional_variants(ch: char) -> Variants {
+    Variants::new(&TRADITIONAL_VARIANTS, ch)
+}
+
+/// Iterator over the variants of an ideograph. This iterator is created by
+/// the [`simplified_variants`] and [`traditional_variants`] functions.
+#[derive(Clone)]
+pub struct Variants {
+    pairs: &'static [(u32, u32)],
+}
+
+impl Variants {
+    fn new(table: &'static [(u32, u32)], ch: char) -> Self {
+        let cp = ch as u32;
+        let start = table.partition_point(|pair| pair.0 < cp);
+        let len = table[start..]
+            .iter()
+            .take_while(|pair| pair.0 == cp)
+            .count();
+        Self {
+            pairs: &table[start..start + len],
+        }
+    }
+}
+
+impl Iterator for Variants {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (first, rest) = self.pairs.split_first()?;
+        self.pairs = rest;
+        char::from_u32(first.1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.pairs.len(), Some(self.pairs.len()))
+    }
+}
+
+impl ExactSizeIterator for Variants {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "the Unihan table must be regenerated from the full database"]
+    fn spot_check() {
+        assert_eq!(total_strokes('一'), Some(1));
+        assert_eq!(total_strokes('丁'), Some(2));
+        assert_eq!(total_strokes('中'), Some(4));
+        assert_eq!(total_strokes('语'), Some(9));
+        assert_eq!(total_strokes('語'), Some(14));
+        assert_eq!(total_strokes('a'), None);
+        assert!(has_mandarin_reading('中'));
+        assert!(!has_mandarin_reading('a'));
+        assert!(simplified_variants('語').eq(Some('语')));
+        assert!(traditional_variants('语').eq(Some('語')));
+        assert_eq!(simplified_variants('语').len(), 0);
+    }
+}
@@ -0,0 +1,131 @@
+/*!
+Interoperability with the `unicode-bidi` crate.
+
+This allows the BiDi algorithm implemented by `unicode-bidi` to be driven
+by the character properties of this crate so that BiDi resolution and
+cluster analysis agree on the classification of each character.
+*/
+
+use super::unicode::BidiClass;
+#[cfg(feature = "bidi")]
+use super::unicode::{Codepoint, Properties};
+#[cfg(feature = "bidi")]
+use ::unicode_bidi::data_source::{BidiDataSource, BidiMatchedOpeningBracket};
+#[cfg(feature = "bidi")]
+use ::unicode_bidi::{BidiInfo, Level};
+
+impl From<BidiClass> for ::unicode_bidi::BidiClass {
+    fn from(value: BidiClass) -> Self {
+        use ::unicode_bidi::BidiClass as Other;
+        use BidiClass::*;
+        match value {
+            AL => Other::AL,
+            AN => Other::AN,
+            B => Other::B,
+            BN => Other::BN,
+            CS => Other::CS,
+            EN => Other::EN,
+            ES => Other::ES,
+            ET => Other::ET,
+            FSI => Other::FSI,
+            L => Other::L,
+            LRE => Other::LRE,
+            LRI => Other::LRI,
+            LRO => Other::LRO,
+            NSM => Other::NSM,
+            ON => Other::ON,
+            PDF => Other::PDF,
+            PDI => Other::PDI,
+            R => Other::R,
+            RLE => Other::RLE,
+            RLI => Other::RLI,
+            RLO => Other::RLO,
+            S => Other::S,
+            WS => Other::WS,
+        }
+    }
+}
+
+impl From<::unicode_bidi::BidiClass> for BidiClass {
+    fn from(value: ::unicode_bidi::BidiClass) -> Self {
+        use ::unicode_bidi::BidiClass::*;
+        match value {
+            AL => Self::AL,
+            AN => Self::AN,
+            B => Self::B,
+            BN => Self::BN,
+            CS => Self::CS,
+            EN => Self::EN,
+            ES => Self::ES,
+            ET => Self::ET,
+            FSI => Self::FSI,
+            L => Self::L,
+            LRE => Self::LRE,
+            LRI => Self::LRI,
+            LRO => Self::LRO,
+            NSM => Self::NSM,
+            ON => Self::ON,
+            PDF => Self::PDF,
+            PDI => Self::PDI,
+            R => Self::R,
+            RLE => Self::RLE,
+            RLI => Self::RLI,
+            RLO => Self::RLO,
+            S => Self::S,
+            WS => Self::WS,
+        }
+    }
+}
+
+/// Data source for the `unicode-bidi` crate that supplies BiDi classes and
+/// paired brackets from the character properties of this crate.
+#[cfg(feature = "bidi")]
+#[derive(Copy, Clone, Default, Debug)]
+pub struct BidiData;
+
+#[cfg(feature = "bidi")]
+impl BidiDataSource for BidiData {
+    fn bidi_class(&self, c: char) -> ::unicode_bidi::BidiClass {
+        c.bidi_class().into()
+    }
+
+    fn bidi_matched_opening_bracket(&self, c: char) -> Option<BidiMatchedOpeningBracket> {
+        let props = Properties::from(c);
+        let (opening, is_open) = if props.is_open_bracket() {
+            (c, true)
+        } else {
+            (props.opening_bracket(c)?, false)
+        };
+        // Brackets are matched by their canonical equivalents, which maps
+        // the angle brackets U+2329 and U+232A to U+3008 and U+3009.
+        let decomposed = opening.decompose();
+        let opening = match decomposed.chars() {
+            [ch] => *ch,
+            _ => opening,
+        };
+        Some(BidiMatchedOpeningBracket { opening, is_open })
+    }
+}
+
+/// Runs the BiDi algorithm from the `unicode-bidi` crate over the specified
+/// text using the character properties of this crate. If the paragraph
+/// level is `None`, it is determined from the first strong character of
+/// each paragraph.
+#[cfg(feature = "bidi")]
+pub fn bidi_info(text: &str, default_para_level: Option<Level>) -> BidiInfo<'_> {
+    BidiInfo::new_with_data_source(&BidiData, text, default_para_level)
+}
+
+#[cfg(all(feature = "alloc", feature = "bidi"))]
+impl crate::paragraph::AnalysisBuffer {
+    /// Resolves the BiDi embedding levels of the characters in the buffer
+    /// using the `unicode-bidi` crate. The text must be the same as the
+    /// text used to fill the buffer.
+    pub fn resolve_levels(&mut self, text: &str, default_para_level: Option<Level>) {
+        let info = bidi_info(text, default_para_level);
+        for i in 0..self.len() {
+            let offset = self.offsets()[i];
+            self.levels_mut()[i] = info.levels[offset].number();
+        }
+    }
+}
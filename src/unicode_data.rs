@@ -5,6 +5,42 @@
 /// The version of the Unicode Character Database used to generate character properties.
 pub const UNICODE_VERSION: (u8, u8, u8) = (13, 0, 0);
 
+/// Source files of the character properties along with their versions.
+#[rustfmt::skip]
+pub const DATA_SOURCES: [(&str, &str, (u8, u8, u8)); 31] = [
+    ("General_Category", "UnicodeData.txt", (13, 0, 0)),
+    ("Canonical_Combining_Class", "UnicodeData.txt", (13, 0, 0)),
+    ("Bidi_Class", "UnicodeData.txt", (13, 0, 0)),
+    ("Decomposition_Mapping", "UnicodeData.txt", (13, 0, 0)),
+    ("Block", "Blocks.txt", (13, 0, 0)),
+    ("Script", "Scripts.txt", (13, 0, 0)),
+    ("Property_Value_Aliases", "PropertyValueAliases.txt", (13, 0, 0)),
+    ("Joining_Type", "ArabicShaping.txt", (13, 0, 0)),
+    ("Grapheme_Cluster_Break", "GraphemeBreakProperty.txt", (13, 0, 0)),
+    ("Word_Break", "WordBreakProperty.txt", (13, 0, 0)),
+    ("Line_Break", "LineBreak.txt", (13, 0, 0)),
+    ("Bidi_Mirroring_Glyph", "BidiMirroring.txt", (13, 0, 0)),
+    ("Bidi_Paired_Bracket_Type", "BidiBrackets.txt", (13, 0, 0)),
+    ("Emoji", "emoji-data.txt", (13, 0, 0)),
+    ("Extended_Pictographic", "emoji-data.txt", (13, 0, 0)),
+    ("Emoji_Qualification", "emoji-test.txt", (13, 0, 0)),
+    ("Default_Ignorable_Code_Point", "DerivedCoreProperties.txt", (13, 0, 0)),
+    ("Math", "DerivedCoreProperties.txt", (13, 0, 0)),
+    ("Alphabetic", "DerivedCoreProperties.txt", (13, 0, 0)),
+    ("Case_Ignorable", "DerivedCoreProperties.txt", (13, 0, 0)),
+    ("Cased", "DerivedCoreProperties.txt", (13, 0, 0)),
+    ("Noncharacter_Code_Point", "PropList.txt", (13, 0, 0)),
+    ("Deprecated", "PropList.txt", (13, 0, 0)),
+    ("White_Space", "PropList.txt", (13, 0, 0)),
+    ("Full_Composition_Exclusion", "DerivedNormalizationProps.txt", (13, 0, 0)),
+    ("kTotalStrokes", "Unihan_IRGSources.txt", (13, 0, 0)),
+    ("kMandarin", "Unihan_Readings.txt", (13, 0, 0)),
+    ("kSimplifiedVariant", "Unihan_Variants.txt", (13, 0, 0)),
+    ("kTraditionalVariant", "Unihan_Variants.txt", (13, 0, 0)),
+    ("USE_Class", "UseClass.txt", (13, 0, 0)),
+    ("Myanmar_Class", "MyanmarClass.txt", (13, 0, 0)),
+];
+
 /// Unicode general category property.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[repr(u8)]
@@ -727,6 +763,31 @@ pub enum JoiningType {
     T = 6,
 }
 
+/// Decomposition type property.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(u8)]
+pub enum DecompositionType {
+    None = 0,
+    Canonical = 1,
+    Compat = 2,
+    Circle = 3,
+    Final = 4,
+    Font = 5,
+    Fraction = 6,
+    Initial = 7,
+    Isolated = 8,
+    Medial = 9,
+    Narrow = 10,
+    NoBreak = 11,
+    Small = 12,
+    Square = 13,
+    Sub = 14,
+    Super = 15,
+    Vertical = 16,
+    Wide = 17,
+}
+
+/// Character class for the Universal Shaping Engine.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[repr(u8)]
 pub enum UseClass {
@@ -769,6 +830,7 @@ pub enum UseClass {
     ZWNJ = 36,
 }
 
+/// Character class for the Myanmar shaping model.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 #[repr(u8)]
 pub enum MyanmarClass {
@@ -802,64 +864,90 @@ pub enum MyanmarClass {
 }
 
 #[rustfmt::skip]
-pub const SCRIPT_TAGS: [u32; 157] = [
-    (97<<24|100<<16|108<<8|109), (97<<24|103<<16|104<<8|98), (97<<24|104<<16|111<<8|109),
-    (97<<24|114<<16|97<<8|98), (97<<24|114<<16|109<<8|105), (97<<24|114<<16|109<<8|110),
-    (97<<24|118<<16|115<<8|116), (98<<24|97<<16|108<<8|105), (98<<24|97<<16|109<<8|117),
-    (98<<24|97<<16|115<<8|115), (98<<24|97<<16|116<<8|107), (98<<24|110<<16|103<<8|50),
-    (98<<24|104<<16|107<<8|115), (98<<24|111<<16|112<<8|111), (98<<24|114<<16|97<<8|104),
-    (98<<24|114<<16|97<<8|105), (98<<24|117<<16|103<<8|105), (98<<24|117<<16|104<<8|100),
-    (99<<24|97<<16|107<<8|109), (99<<24|97<<16|110<<8|115), (99<<24|97<<16|114<<8|105),
-    (99<<24|104<<16|97<<8|109), (99<<24|104<<16|101<<8|114), (99<<24|104<<16|114<<8|115),
-    (99<<24|111<<16|112<<8|116), (99<<24|112<<16|114<<8|116), (99<<24|121<<16|114<<8|108),
-    (100<<24|101<<16|118<<8|50), (100<<24|105<<16|97<<8|107), (100<<24|111<<16|103<<8|114),
-    (100<<24|115<<16|114<<8|116), (100<<24|117<<16|112<<8|108), (101<<24|103<<16|121<<8|112),
-    (101<<24|108<<16|98<<8|97), (101<<24|108<<16|121<<8|109), (101<<24|116<<16|104<<8|105),
-    (103<<24|101<<16|111<<8|114), (103<<24|108<<16|97<<8|103), (103<<24|111<<16|110<<8|103),
-    (103<<24|111<<16|110<<8|109), (103<<24|111<<16|116<<8|104), (103<<24|114<<16|97<<8|110),
-    (103<<24|114<<16|101<<8|107), (103<<24|106<<16|114<<8|50), (103<<24|117<<16|114<<8|50),
-    (104<<24|97<<16|110<<8|103), (104<<24|97<<16|110<<8|105), (104<<24|97<<16|110<<8|111),
-    (104<<24|97<<16|116<<8|114), (104<<24|101<<16|98<<8|114), (104<<24|105<<16|114<<8|97),
-    (104<<24|108<<16|117<<8|119), (104<<24|109<<16|110<<8|103), (104<<24|109<<16|110<<8|112),
-    (104<<24|117<<16|110<<8|103), (105<<24|116<<16|97<<8|108), (106<<24|97<<16|118<<8|97),
-    (107<<24|97<<16|108<<8|105), (107<<24|97<<16|110<<8|97), (107<<24|104<<16|97<<8|114),
-    (107<<24|104<<16|109<<8|114), (107<<24|104<<16|111<<8|106), (107<<24|105<<16|116<<8|115),
-    (107<<24|110<<16|100<<8|50), (107<<24|116<<16|104<<8|105), (108<<24|97<<16|110<<8|97),
-    (108<<24|97<<16|111<<8|111), (108<<24|97<<16|116<<8|110), (108<<24|101<<16|112<<8|99),
-    (108<<24|105<<16|109<<8|98), (108<<24|105<<16|110<<8|97), (108<<24|105<<16|110<<8|98),
-    (108<<24|105<<16|115<<8|117), (108<<24|121<<16|99<<8|105), (108<<24|121<<16|100<<8|105),
-    (109<<24|97<<16|104<<8|106), (109<<24|97<<16|107<<8|97), (109<<24|97<<16|110<<8|100),
-    (109<<24|97<<16|110<<8|105), (109<<24|97<<16|114<<8|99), (109<<24|101<<16|100<<8|102),
-    (109<<24|101<<16|110<<8|100), (109<<24|101<<16|114<<8|99), (109<<24|101<<16|114<<8|111),
-    (109<<24|108<<16|109<<8|50), (109<<24|111<<16|100<<8|105), (109<<24|111<<16|110<<8|103),
-    (109<<24|114<<16|111<<8|111), (109<<24|116<<16|101<<8|105), (109<<24|117<<16|108<<8|116),
-    (109<<24|121<<16|109<<8|50), (110<<24|97<<16|110<<8|100), (110<<24|97<<16|114<<8|98),
-    (110<<24|98<<16|97<<8|116), (110<<24|101<<16|119<<8|97), (110<<24|107<<16|111<<8|111),
-    (110<<24|115<<16|104<<8|117), (111<<24|103<<16|97<<8|109), (111<<24|108<<16|99<<8|107),
-    (111<<24|114<<16|107<<8|104), (111<<24|114<<16|121<<8|50), (111<<24|115<<16|103<<8|101),
-    (111<<24|115<<16|109<<8|97), (112<<24|97<<16|108<<8|109), (112<<24|97<<16|117<<8|99),
-    (112<<24|101<<16|114<<8|109), (112<<24|104<<16|97<<8|103), (112<<24|104<<16|108<<8|105),
-    (112<<24|104<<16|108<<8|112), (112<<24|104<<16|110<<8|120), (109<<24|105<<16|97<<8|111),
-    (112<<24|114<<16|116<<8|105), (114<<24|106<<16|110<<8|103), (114<<24|111<<16|104<<8|103),
-    (114<<24|117<<16|110<<8|114), (115<<24|97<<16|109<<8|114), (115<<24|97<<16|114<<8|98),
-    (115<<24|97<<16|117<<8|114), (115<<24|103<<16|110<<8|119), (115<<24|104<<16|97<<8|119),
-    (115<<24|104<<16|114<<8|100), (115<<24|105<<16|100<<8|100), (115<<24|105<<16|110<<8|100),
-    (115<<24|105<<16|110<<8|104), (115<<24|111<<16|103<<8|100), (115<<24|111<<16|103<<8|111),
-    (115<<24|111<<16|114<<8|97), (115<<24|111<<16|121<<8|111), (115<<24|117<<16|110<<8|100),
-    (115<<24|121<<16|108<<8|111), (115<<24|121<<16|114<<8|99), (116<<24|97<<16|103<<8|98),
-    (116<<24|97<<16|107<<8|114), (116<<24|97<<16|108<<8|101), (116<<24|97<<16|108<<8|117),
-    (116<<24|109<<16|108<<8|50), (116<<24|97<<16|110<<8|103), (116<<24|97<<16|118<<8|116),
-    (116<<24|101<<16|108<<8|117), (116<<24|102<<16|110<<8|103), (116<<24|103<<16|108<<8|103),
-    (116<<24|104<<16|97<<8|97), (116<<24|104<<16|97<<8|105), (116<<24|105<<16|98<<8|116),
-    (116<<24|105<<16|114<<8|104), (117<<24|103<<16|97<<8|114), (118<<24|97<<16|105<<8|105),
-    (119<<24|97<<16|114<<8|97), (119<<24|99<<16|104<<8|111), (120<<24|112<<16|101<<8|111),
-    (120<<24|115<<16|117<<8|120), (121<<24|101<<16|122<<8|105), (121<<24|105<<16|105<<8|105),
-    (122<<24|97<<16|110<<8|98), (122<<24|105<<16|110<<8|104), (122<<24|121<<16|121<<8|121),
-    (122<<24|122<<16|122<<8|122),
+pub const SCRIPT_TAGS: [[u32; 2]; 157] = [
+    [(97<<24|100<<16|108<<8|109), 0], [(97<<24|103<<16|104<<8|98), 0],
+    [(97<<24|104<<16|111<<8|109), 0], [(97<<24|114<<16|97<<8|98), 0],
+    [(97<<24|114<<16|109<<8|105), 0], [(97<<24|114<<16|109<<8|110), 0],
+    [(97<<24|118<<16|115<<8|116), 0], [(98<<24|97<<16|108<<8|105), 0],
+    [(98<<24|97<<16|109<<8|117), 0], [(98<<24|97<<16|115<<8|115), 0],
+    [(98<<24|97<<16|116<<8|107), 0], [(98<<24|110<<16|103<<8|50), (98<<24|101<<16|110<<8|103)],
+    [(98<<24|104<<16|107<<8|115), 0], [(98<<24|111<<16|112<<8|111), 0],
+    [(98<<24|114<<16|97<<8|104), 0], [(98<<24|114<<16|97<<8|105), 0],
+    [(98<<24|117<<16|103<<8|105), 0], [(98<<24|117<<16|104<<8|100), 0],
+    [(99<<24|97<<16|107<<8|109), 0], [(99<<24|97<<16|110<<8|115), 0],
+    [(99<<24|97<<16|114<<8|105), 0], [(99<<24|104<<16|97<<8|109), 0],
+    [(99<<24|104<<16|101<<8|114), 0], [(99<<24|104<<16|114<<8|115), 0],
+    [(99<<24|111<<16|112<<8|116), 0], [(99<<24|112<<16|114<<8|116), 0],
+    [(99<<24|121<<16|114<<8|108), 0], [(100<<24|101<<16|118<<8|50), (100<<24|101<<16|118<<8|97)],
+    [(100<<24|105<<16|97<<8|107), 0], [(100<<24|111<<16|103<<8|114), 0],
+    [(100<<24|115<<16|114<<8|116), 0], [(100<<24|117<<16|112<<8|108), 0],
+    [(101<<24|103<<16|121<<8|112), 0], [(101<<24|108<<16|98<<8|97), 0],
+    [(101<<24|108<<16|121<<8|109), 0], [(101<<24|116<<16|104<<8|105), 0],
+    [(103<<24|101<<16|111<<8|114), 0], [(103<<24|108<<16|97<<8|103), 0],
+    [(103<<24|111<<16|110<<8|103), 0], [(103<<24|111<<16|110<<8|109), 0],
+    [(103<<24|111<<16|116<<8|104), 0], [(103<<24|114<<16|97<<8|110), 0],
+    [(103<<24|114<<16|101<<8|107), 0], [(103<<24|106<<16|114<<8|50), (103<<24|117<<16|106<<8|114)],
+    [(103<<24|117<<16|114<<8|50), (103<<24|117<<16|114<<8|117)], [(104<<24|97<<16|110<<8|103), 0],
+    [(104<<24|97<<16|110<<8|105), 0], [(104<<24|97<<16|110<<8|111), 0],
+    [(104<<24|97<<16|116<<8|114), 0], [(104<<24|101<<16|98<<8|114), 0],
+    [(107<<24|97<<16|110<<8|97), 0], [(104<<24|108<<16|117<<8|119), 0],
+    [(104<<24|109<<16|110<<8|103), 0], [(104<<24|109<<16|110<<8|112), 0],
+    [(104<<24|117<<16|110<<8|103), 0], [(105<<24|116<<16|97<<8|108), 0],
+    [(106<<24|97<<16|118<<8|97), 0], [(107<<24|97<<16|108<<8|105), 0],
+    [(107<<24|97<<16|110<<8|97), 0], [(107<<24|104<<16|97<<8|114), 0],
+    [(107<<24|104<<16|109<<8|114), 0], [(107<<24|104<<16|111<<8|106), 0],
+    [(107<<24|105<<16|116<<8|115), 0], [(107<<24|110<<16|100<<8|50), (107<<24|110<<16|100<<8|97)],
+    [(107<<24|116<<16|104<<8|105), 0], [(108<<24|97<<16|110<<8|97), 0],
+    [(108<<24|97<<16|111<<8|32), 0], [(108<<24|97<<16|116<<8|110), 0],
+    [(108<<24|101<<16|112<<8|99), 0], [(108<<24|105<<16|109<<8|98), 0],
+    [(108<<24|105<<16|110<<8|97), 0], [(108<<24|105<<16|110<<8|98), 0],
+    [(108<<24|105<<16|115<<8|117), 0], [(108<<24|121<<16|99<<8|105), 0],
+    [(108<<24|121<<16|100<<8|105), 0], [(109<<24|97<<16|104<<8|106), 0],
+    [(109<<24|97<<16|107<<8|97), 0], [(109<<24|97<<16|110<<8|100), 0],
+    [(109<<24|97<<16|110<<8|105), 0], [(109<<24|97<<16|114<<8|99), 0],
+    [(109<<24|101<<16|100<<8|102), 0], [(109<<24|101<<16|110<<8|100), 0],
+    [(109<<24|101<<16|114<<8|99), 0], [(109<<24|101<<16|114<<8|111), 0],
+    [(109<<24|108<<16|109<<8|50), (109<<24|108<<16|121<<8|109)], [(109<<24|111<<16|100<<8|105), 0],
+    [(109<<24|111<<16|110<<8|103), 0], [(109<<24|114<<16|111<<8|111), 0],
+    [(109<<24|116<<16|101<<8|105), 0], [(109<<24|117<<16|108<<8|116), 0],
+    [(109<<24|121<<16|109<<8|50), (109<<24|121<<16|109<<8|114)], [(110<<24|97<<16|110<<8|100), 0],
+    [(110<<24|97<<16|114<<8|98), 0], [(110<<24|98<<16|97<<8|116), 0],
+    [(110<<24|101<<16|119<<8|97), 0], [(110<<24|107<<16|111<<8|32), 0],
+    [(110<<24|115<<16|104<<8|117), 0], [(111<<24|103<<16|97<<8|109), 0],
+    [(111<<24|108<<16|99<<8|107), 0], [(111<<24|114<<16|107<<8|104), 0],
+    [(111<<24|114<<16|121<<8|50), (111<<24|114<<16|121<<8|97)], [(111<<24|115<<16|103<<8|101), 0],
+    [(111<<24|115<<16|109<<8|97), 0], [(112<<24|97<<16|108<<8|109), 0],
+    [(112<<24|97<<16|117<<8|99), 0], [(112<<24|101<<16|114<<8|109), 0],
+    [(112<<24|104<<16|97<<8|103), 0], [(112<<24|104<<16|108<<8|105), 0],
+    [(112<<24|104<<16|108<<8|112), 0], [(112<<24|104<<16|110<<8|120), 0],
+    [(112<<24|108<<16|114<<8|100), 0], [(112<<24|114<<16|116<<8|105), 0],
+    [(114<<24|106<<16|110<<8|103), 0], [(114<<24|111<<16|104<<8|103), 0],
+    [(114<<24|117<<16|110<<8|114), 0], [(115<<24|97<<16|109<<8|114), 0],
+    [(115<<24|97<<16|114<<8|98), 0], [(115<<24|97<<16|117<<8|114), 0],
+    [(115<<24|103<<16|110<<8|119), 0], [(115<<24|104<<16|97<<8|119), 0],
+    [(115<<24|104<<16|114<<8|100), 0], [(115<<24|105<<16|100<<8|100), 0],
+    [(115<<24|105<<16|110<<8|100), 0], [(115<<24|105<<16|110<<8|104), 0],
+    [(115<<24|111<<16|103<<8|100), 0], [(115<<24|111<<16|103<<8|111), 0],
+    [(115<<24|111<<16|114<<8|97), 0], [(115<<24|111<<16|121<<8|111), 0],
+    [(115<<24|117<<16|110<<8|100), 0], [(115<<24|121<<16|108<<8|111), 0],
+    [(115<<24|121<<16|114<<8|99), 0], [(116<<24|97<<16|103<<8|98), 0],
+    [(116<<24|97<<16|107<<8|114), 0], [(116<<24|97<<16|108<<8|101), 0],
+    [(116<<24|97<<16|108<<8|117), 0], [(116<<24|109<<16|108<<8|50), (116<<24|97<<16|109<<8|108)],
+    [(116<<24|97<<16|110<<8|103), 0], [(116<<24|97<<16|118<<8|116), 0],
+    [(116<<24|101<<16|108<<8|50), (116<<24|101<<16|108<<8|117)], [(116<<24|102<<16|110<<8|103), 0],
+    [(116<<24|103<<16|108<<8|103), 0], [(116<<24|104<<16|97<<8|97), 0],
+    [(116<<24|104<<16|97<<8|105), 0], [(116<<24|105<<16|98<<8|116), 0],
+    [(116<<24|105<<16|114<<8|104), 0], [(117<<24|103<<16|97<<8|114), 0],
+    [(118<<24|97<<16|105<<8|32), 0], [(119<<24|97<<16|114<<8|97), 0],
+    [(119<<24|99<<16|104<<8|111), 0], [(120<<24|112<<16|101<<8|111), 0],
+    [(120<<24|115<<16|117<<8|120), 0], [(121<<24|101<<16|122<<8|105), 0],
+    [(121<<24|105<<16|32<<8|32), 0], [(122<<24|97<<16|110<<8|98), 0],
+    [(122<<24|105<<16|110<<8|104), 0], [(122<<24|121<<16|121<<8|121), 0],
+    [(122<<24|122<<16|122<<8|122), 0],
 ];
 
 #[rustfmt::skip]
-pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
+pub const SCRIPTS_BY_TAG: [(u32, Script); 166] = [
     ((97<<24|100<<16|108<<8|109), Script::Adlam),
     ((97<<24|103<<16|104<<8|98), Script::CaucasianAlbanian),
     ((97<<24|104<<16|111<<8|109), Script::Ahom), ((97<<24|114<<16|97<<8|98), Script::Arabic),
@@ -867,11 +955,12 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((97<<24|114<<16|109<<8|110), Script::Armenian),
     ((97<<24|118<<16|115<<8|116), Script::Avestan), ((98<<24|97<<16|108<<8|105), Script::Balinese),
     ((98<<24|97<<16|109<<8|117), Script::Bamum), ((98<<24|97<<16|115<<8|115), Script::BassaVah),
-    ((98<<24|97<<16|116<<8|107), Script::Batak), ((98<<24|110<<16|103<<8|50), Script::Bengali),
+    ((98<<24|97<<16|116<<8|107), Script::Batak), ((98<<24|101<<16|110<<8|103), Script::Bengali),
     ((98<<24|104<<16|107<<8|115), Script::Bhaiksuki),
-    ((98<<24|111<<16|112<<8|111), Script::Bopomofo), ((98<<24|114<<16|97<<8|104), Script::Brahmi),
-    ((98<<24|114<<16|97<<8|105), Script::Braille), ((98<<24|117<<16|103<<8|105), Script::Buginese),
-    ((98<<24|117<<16|104<<8|100), Script::Buhid), ((99<<24|97<<16|107<<8|109), Script::Chakma),
+    ((98<<24|110<<16|103<<8|50), Script::Bengali), ((98<<24|111<<16|112<<8|111), Script::Bopomofo),
+    ((98<<24|114<<16|97<<8|104), Script::Brahmi), ((98<<24|114<<16|97<<8|105), Script::Braille),
+    ((98<<24|117<<16|103<<8|105), Script::Buginese), ((98<<24|117<<16|104<<8|100), Script::Buhid),
+    ((99<<24|97<<16|107<<8|109), Script::Chakma),
     ((99<<24|97<<16|110<<8|115), Script::CanadianAboriginal),
     ((99<<24|97<<16|114<<8|105), Script::Carian), ((99<<24|104<<16|97<<8|109), Script::Cham),
     ((99<<24|104<<16|101<<8|114), Script::Cherokee),
@@ -879,6 +968,7 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((99<<24|111<<16|112<<8|116), Script::Coptic), ((99<<24|112<<16|114<<8|116), Script::Cypriot),
     ((99<<24|121<<16|114<<8|108), Script::Cyrillic),
     ((100<<24|101<<16|118<<8|50), Script::Devanagari),
+    ((100<<24|101<<16|118<<8|97), Script::Devanagari),
     ((100<<24|105<<16|97<<8|107), Script::DivesAkuru),
     ((100<<24|111<<16|103<<8|114), Script::Dogra), ((100<<24|115<<16|114<<8|116), Script::Deseret),
     ((100<<24|117<<16|112<<8|108), Script::Duployan),
@@ -886,15 +976,18 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((101<<24|108<<16|98<<8|97), Script::Elbasan), ((101<<24|108<<16|121<<8|109), Script::Elymaic),
     ((101<<24|116<<16|104<<8|105), Script::Ethiopic),
     ((103<<24|101<<16|111<<8|114), Script::Georgian),
+    ((103<<24|106<<16|114<<8|50), Script::Gujarati),
     ((103<<24|108<<16|97<<8|103), Script::Glagolitic),
     ((103<<24|111<<16|110<<8|103), Script::GunjalaGondi),
     ((103<<24|111<<16|110<<8|109), Script::MasaramGondi),
     ((103<<24|111<<16|116<<8|104), Script::Gothic), ((103<<24|114<<16|97<<8|110), Script::Grantha),
-    ((103<<24|114<<16|101<<8|107), Script::Greek), ((103<<24|106<<16|114<<8|50), Script::Gujarati),
-    ((103<<24|117<<16|114<<8|50), Script::Gurmukhi), ((104<<24|97<<16|110<<8|103), Script::Hangul),
-    ((104<<24|97<<16|110<<8|105), Script::Han), ((104<<24|97<<16|110<<8|111), Script::Hanunoo),
-    ((104<<24|97<<16|116<<8|114), Script::Hatran), ((104<<24|101<<16|98<<8|114), Script::Hebrew),
-    ((104<<24|105<<16|114<<8|97), Script::Hiragana),
+    ((103<<24|114<<16|101<<8|107), Script::Greek),
+    ((103<<24|117<<16|106<<8|114), Script::Gujarati),
+    ((103<<24|117<<16|114<<8|50), Script::Gurmukhi),
+    ((103<<24|117<<16|114<<8|117), Script::Gurmukhi),
+    ((104<<24|97<<16|110<<8|103), Script::Hangul), ((104<<24|97<<16|110<<8|105), Script::Han),
+    ((104<<24|97<<16|110<<8|111), Script::Hanunoo), ((104<<24|97<<16|116<<8|114), Script::Hatran),
+    ((104<<24|101<<16|98<<8|114), Script::Hebrew),
     ((104<<24|108<<16|117<<8|119), Script::AnatolianHieroglyphs),
     ((104<<24|109<<16|110<<8|103), Script::PahawhHmong),
     ((104<<24|109<<16|110<<8|112), Script::NyiakengPuachueHmong),
@@ -905,12 +998,13 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((107<<24|104<<16|97<<8|114), Script::Kharoshthi),
     ((107<<24|104<<16|109<<8|114), Script::Khmer), ((107<<24|104<<16|111<<8|106), Script::Khojki),
     ((107<<24|105<<16|116<<8|115), Script::KhitanSmallScript),
-    ((107<<24|110<<16|100<<8|50), Script::Kannada), ((107<<24|116<<16|104<<8|105), Script::Kaithi),
-    ((108<<24|97<<16|110<<8|97), Script::TaiTham), ((108<<24|97<<16|111<<8|111), Script::Lao),
-    ((108<<24|97<<16|116<<8|110), Script::Latin), ((108<<24|101<<16|112<<8|99), Script::Lepcha),
-    ((108<<24|105<<16|109<<8|98), Script::Limbu), ((108<<24|105<<16|110<<8|97), Script::LinearA),
-    ((108<<24|105<<16|110<<8|98), Script::LinearB), ((108<<24|105<<16|115<<8|117), Script::Lisu),
-    ((108<<24|121<<16|99<<8|105), Script::Lycian), ((108<<24|121<<16|100<<8|105), Script::Lydian),
+    ((107<<24|110<<16|100<<8|50), Script::Kannada), ((107<<24|110<<16|100<<8|97), Script::Kannada),
+    ((107<<24|116<<16|104<<8|105), Script::Kaithi), ((108<<24|97<<16|110<<8|97), Script::TaiTham),
+    ((108<<24|97<<16|111<<8|32), Script::Lao), ((108<<24|97<<16|116<<8|110), Script::Latin),
+    ((108<<24|101<<16|112<<8|99), Script::Lepcha), ((108<<24|105<<16|109<<8|98), Script::Limbu),
+    ((108<<24|105<<16|110<<8|97), Script::LinearA), ((108<<24|105<<16|110<<8|98), Script::LinearB),
+    ((108<<24|105<<16|115<<8|117), Script::Lisu), ((108<<24|121<<16|99<<8|105), Script::Lycian),
+    ((108<<24|121<<16|100<<8|105), Script::Lydian),
     ((109<<24|97<<16|104<<8|106), Script::Mahajani), ((109<<24|97<<16|107<<8|97), Script::Makasar),
     ((109<<24|97<<16|110<<8|100), Script::Mandaic),
     ((109<<24|97<<16|110<<8|105), Script::Manichaean),
@@ -919,19 +1013,22 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((109<<24|101<<16|110<<8|100), Script::MendeKikakui),
     ((109<<24|101<<16|114<<8|99), Script::MeroiticCursive),
     ((109<<24|101<<16|114<<8|111), Script::MeroiticHieroglyphs),
-    ((109<<24|108<<16|109<<8|50), Script::Malayalam), ((109<<24|111<<16|100<<8|105), Script::Modi),
+    ((109<<24|108<<16|109<<8|50), Script::Malayalam),
+    ((109<<24|108<<16|121<<8|109), Script::Malayalam),
+    ((109<<24|111<<16|100<<8|105), Script::Modi),
     ((109<<24|111<<16|110<<8|103), Script::Mongolian), ((109<<24|114<<16|111<<8|111), Script::Mro),
     ((109<<24|116<<16|101<<8|105), Script::MeeteiMayek),
     ((109<<24|117<<16|108<<8|116), Script::Multani),
     ((109<<24|121<<16|109<<8|50), Script::Myanmar),
+    ((109<<24|121<<16|109<<8|114), Script::Myanmar),
     ((110<<24|97<<16|110<<8|100), Script::Nandinagari),
     ((110<<24|97<<16|114<<8|98), Script::OldNorthArabian),
     ((110<<24|98<<16|97<<8|116), Script::Nabataean), ((110<<24|101<<16|119<<8|97), Script::Newa),
-    ((110<<24|107<<16|111<<8|111), Script::Nko), ((110<<24|115<<16|104<<8|117), Script::Nushu),
+    ((110<<24|107<<16|111<<8|32), Script::Nko), ((110<<24|115<<16|104<<8|117), Script::Nushu),
     ((111<<24|103<<16|97<<8|109), Script::Ogham), ((111<<24|108<<16|99<<8|107), Script::OlChiki),
     ((111<<24|114<<16|107<<8|104), Script::OldTurkic),
-    ((111<<24|114<<16|121<<8|50), Script::Oriya), ((111<<24|115<<16|103<<8|101), Script::Osage),
-    ((111<<24|115<<16|109<<8|97), Script::Osmanya),
+    ((111<<24|114<<16|121<<8|50), Script::Oriya), ((111<<24|114<<16|121<<8|97), Script::Oriya),
+    ((111<<24|115<<16|103<<8|101), Script::Osage), ((111<<24|115<<16|109<<8|97), Script::Osmanya),
     ((112<<24|97<<16|108<<8|109), Script::Palmyrene),
     ((112<<24|97<<16|117<<8|99), Script::PauCinHau),
     ((112<<24|101<<16|114<<8|109), Script::OldPermic),
@@ -939,7 +1036,7 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((112<<24|104<<16|108<<8|105), Script::InscriptionalPahlavi),
     ((112<<24|104<<16|108<<8|112), Script::PsalterPahlavi),
     ((112<<24|104<<16|110<<8|120), Script::Phoenician),
-    ((109<<24|105<<16|97<<8|111), Script::Miao),
+    ((112<<24|108<<16|114<<8|100), Script::Miao),
     ((112<<24|114<<16|116<<8|105), Script::InscriptionalParthian),
     ((114<<24|106<<16|110<<8|103), Script::Rejang),
     ((114<<24|111<<16|104<<8|103), Script::HanifiRohingya),
@@ -961,19 +1058,19 @@ pub const SCRIPTS_BY_TAG: [(u32, Script); 157] = [
     ((115<<24|121<<16|108<<8|111), Script::SylotiNagri),
     ((115<<24|121<<16|114<<8|99), Script::Syriac), ((116<<24|97<<16|103<<8|98), Script::Tagbanwa),
     ((116<<24|97<<16|107<<8|114), Script::Takri), ((116<<24|97<<16|108<<8|101), Script::TaiLe),
-    ((116<<24|97<<16|108<<8|117), Script::NewTaiLue), ((116<<24|109<<16|108<<8|50), Script::Tamil),
+    ((116<<24|97<<16|108<<8|117), Script::NewTaiLue), ((116<<24|97<<16|109<<8|108), Script::Tamil),
     ((116<<24|97<<16|110<<8|103), Script::Tangut), ((116<<24|97<<16|118<<8|116), Script::TaiViet),
-    ((116<<24|101<<16|108<<8|117), Script::Telugu),
+    ((116<<24|101<<16|108<<8|50), Script::Telugu), ((116<<24|101<<16|108<<8|117), Script::Telugu),
     ((116<<24|102<<16|110<<8|103), Script::Tifinagh),
     ((116<<24|103<<16|108<<8|103), Script::Tagalog), ((116<<24|104<<16|97<<8|97), Script::Thaana),
     ((116<<24|104<<16|97<<8|105), Script::Thai), ((116<<24|105<<16|98<<8|116), Script::Tibetan),
-    ((116<<24|105<<16|114<<8|104), Script::Tirhuta),
-    ((117<<24|103<<16|97<<8|114), Script::Ugaritic), ((118<<24|97<<16|105<<8|105), Script::Vai),
+    ((116<<24|105<<16|114<<8|104), Script::Tirhuta), ((116<<24|109<<16|108<<8|50), Script::Tamil),
+    ((117<<24|103<<16|97<<8|114), Script::Ugaritic), ((118<<24|97<<16|105<<8|32), Script::Vai),
     ((119<<24|97<<16|114<<8|97), Script::WarangCiti),
     ((119<<24|99<<16|104<<8|111), Script::Wancho),
     ((120<<24|112<<16|101<<8|111), Script::OldPersian),
     ((120<<24|115<<16|117<<8|120), Script::Cuneiform),
-    ((121<<24|101<<16|122<<8|105), Script::Yezidi), ((121<<24|105<<16|105<<8|105), Script::Yi),
+    ((121<<24|101<<16|122<<8|105), Script::Yezidi), ((121<<24|105<<16|32<<8|32), Script::Yi),
     ((122<<24|97<<16|110<<8|98), Script::ZanabazarSquare),
     ((122<<24|105<<16|110<<8|104), Script::Inherited),
     ((122<<24|121<<16|121<<8|121), Script::Common),
@@ -1006,6 +1103,37 @@ pub const SCRIPT_NAMES: [&str; 157] = [
     "Zanabazar Square", "Inherited", "Common", "Unknown",
 ];
 
+#[rustfmt::skip]
+pub const SCRIPT_SHORT_NAMES: [&str; 157] = [
+    "Adlm", "Aghb", "Ahom", "Arab", "Armi", "Armn", "Avst", "Bali", "Bamu", "Bass", "Batk", "Beng",
+    "Bhks", "Bopo", "Brah", "Brai", "Bugi", "Buhd", "Cakm", "Cans", "Cari", "Cham", "Cher", "Chrs",
+    "Copt", "Cprt", "Cyrl", "Deva", "Diak", "Dogr", "Dsrt", "Dupl", "Egyp", "Elba", "Elym", "Ethi",
+    "Geor", "Glag", "Gong", "Gonm", "Goth", "Gran", "Grek", "Gujr", "Guru", "Hang", "Hani", "Hano",
+    "Hatr", "Hebr", "Hira", "Hluw", "Hmng", "Hmnp", "Hung", "Ital", "Java", "Kali", "Kana", "Khar",
+    "Khmr", "Khoj", "Kits", "Knda", "Kthi", "Lana", "Laoo", "Latn", "Lepc", "Limb", "Lina", "Linb",
+    "Lisu", "Lyci", "Lydi", "Mahj", "Maka", "Mand", "Mani", "Marc", "Medf", "Mend", "Merc", "Mero",
+    "Mlym", "Modi", "Mong", "Mroo", "Mtei", "Mult", "Mymr", "Nand", "Narb", "Nbat", "Newa", "Nkoo",
+    "Nshu", "Ogam", "Olck", "Orkh", "Orya", "Osge", "Osma", "Palm", "Pauc", "Perm", "Phag", "Phli",
+    "Phlp", "Phnx", "Plrd", "Prti", "Rjng", "Rohg", "Runr", "Samr", "Sarb", "Saur", "Sgnw", "Shaw",
+    "Shrd", "Sidd", "Sind", "Sinh", "Sogd", "Sogo", "Sora", "Soyo", "Sund", "Sylo", "Syrc", "Tagb",
+    "Takr", "Tale", "Talu", "Taml", "Tang", "Tavt", "Telu", "Tfng", "Tglg", "Thaa", "Thai", "Tibt",
+    "Tirh", "Ugar", "Vaii", "Wara", "Wcho", "Xpeo", "Xsux", "Yezi", "Yiii", "Zanb", "Zinh", "Zyyy",
+    "Zzzz",
+];
+
+#[rustfmt::skip]
+pub const SCRIPT_ISO15924_NUMBERS: [u16; 157] = [
+    166, 239, 338, 160, 124, 230, 134, 360, 435, 259, 365, 325, 334, 285, 300, 570, 367, 372, 349,
+    440, 201, 358, 445, 109, 204, 403, 220, 315, 342, 328, 250, 755, 50, 226, 128, 430, 240, 225,
+    312, 313, 206, 343, 200, 320, 310, 286, 500, 371, 127, 125, 410, 80, 450, 451, 176, 210, 361,
+    357, 411, 305, 355, 322, 288, 345, 317, 351, 356, 215, 335, 336, 400, 401, 399, 202, 116, 314,
+    366, 140, 139, 332, 265, 438, 101, 100, 347, 324, 145, 199, 337, 323, 350, 311, 106, 159, 333,
+    165, 499, 212, 261, 175, 327, 219, 260, 126, 263, 227, 331, 131, 132, 115, 282, 130, 363, 167,
+    211, 123, 105, 344, 95, 281, 319, 302, 318, 348, 141, 142, 398, 329, 362, 316, 135, 373, 321,
+    353, 354, 346, 520, 359, 340, 120, 370, 170, 352, 330, 326, 40, 470, 262, 283, 30, 20, 192,
+    460, 339, 994, 998, 999,
+];
+
 #[rustfmt::skip]
 pub const SCRIPT_COMPLEXITY: [bool; 157] = [
     true, false, true, false, false, false, false, true, false, false, true, true, true, false,
@@ -1022,107 +1150,78 @@ pub const SCRIPT_COMPLEXITY: [bool; 157] = [
 ];
 
 #[rustfmt::skip]
-pub const BRACKETS: [(u16, u16); 60] = [
-    (0x0028, 0x0029), (0x005B, 0x005D), (0x007B, 0x007D), (0x0F3A, 0x0F3B), (0x0F3C, 0x0F3D),
-    (0x169B, 0x169C), (0x2045, 0x2046), (0x207D, 0x207E), (0x208D, 0x208E), (0x2308, 0x2309),
-    (0x230A, 0x230B), (0x2329, 0x232A), (0x2768, 0x2769), (0x276A, 0x276B), (0x276C, 0x276D),
-    (0x276E, 0x276F), (0x2770, 0x2771), (0x2772, 0x2773), (0x2774, 0x2775), (0x27C5, 0x27C6),
-    (0x27E6, 0x27E7), (0x27E8, 0x27E9), (0x27EA, 0x27EB), (0x27EC, 0x27ED), (0x27EE, 0x27EF),
-    (0x2983, 0x2984), (0x2985, 0x2986), (0x2987, 0x2988), (0x2989, 0x298A), (0x298B, 0x298C),
-    (0x298D, 0x2990), (0x298F, 0x298E), (0x2991, 0x2992), (0x2993, 0x2994), (0x2995, 0x2996),
-    (0x2997, 0x2998), (0x29D8, 0x29D9), (0x29DA, 0x29DB), (0x29FC, 0x29FD), (0x2E22, 0x2E23),
-    (0x2E24, 0x2E25), (0x2E26, 0x2E27), (0x2E28, 0x2E29), (0x3008, 0x3009), (0x300A, 0x300B),
-    (0x300C, 0x300D), (0x300E, 0x300F), (0x3010, 0x3011), (0x3014, 0x3015), (0x3016, 0x3017),
-    (0x3018, 0x3019), (0x301A, 0x301B), (0xFE59, 0xFE5A), (0xFE5B, 0xFE5C), (0xFE5D, 0xFE5E),
-    (0xFF08, 0xFF09), (0xFF3B, 0xFF3D), (0xFF5B, 0xFF5D), (0xFF5F, 0xFF60), (0xFF62, 0xFF63),
+pub const SCRIPT_RTL: [bool; 157] = [
+    true, false, false, true, true, false, true, false, false, false, false, false, false, false,
+    false, false, false, false, false, false, false, false, false, true, false, true, false, false,
+    false, false, false, false, false, false, true, false, false, false, false, false, false,
+    false, false, false, false, false, false, false, true, true, false, false, false, false, true,
+    false, false, false, false, true, false, false, false, false, false, false, false, false,
+    false, false, false, false, false, false, true, false, false, true, true, false, false, true,
+    true, true, false, false, false, false, false, false, false, false, true, true, false, true,
+    false, false, false, true, false, false, false, true, false, false, false, true, true, true,
+    false, true, false, true, false, true, true, false, false, false, false, false, false, false,
+    true, true, false, false, false, false, true, false, false, false, false, false, false, false,
+    false, false, false, true, false, false, false, false, false, false, false, false, false, true,
+    false, false, false, false, false,
 ];
 
 #[rustfmt::skip]
-pub const MIRRORS: [(u16, u16); 420] = [
-    (0x0028, 0x0029), (0x0029, 0x0028), (0x003C, 0x003E), (0x003E, 0x003C), (0x005B, 0x005D),
-    (0x005D, 0x005B), (0x007B, 0x007D), (0x007D, 0x007B), (0x00AB, 0x00BB), (0x00BB, 0x00AB),
-    (0x0F3A, 0x0F3B), (0x0F3B, 0x0F3A), (0x0F3C, 0x0F3D), (0x0F3D, 0x0F3C), (0x169B, 0x169C),
-    (0x169C, 0x169B), (0x2039, 0x203A), (0x203A, 0x2039), (0x2045, 0x2046), (0x2046, 0x2045),
-    (0x207D, 0x207E), (0x207E, 0x207D), (0x208D, 0x208E), (0x208E, 0x208D), (0x2208, 0x220B),
-    (0x2209, 0x220C), (0x220A, 0x220D), (0x220B, 0x2208), (0x220C, 0x2209), (0x220D, 0x220A),
-    (0x2215, 0x29F5), (0x221F, 0x2BFE), (0x2220, 0x29A3), (0x2221, 0x299B), (0x2222, 0x29A0),
-    (0x2224, 0x2AEE), (0x223C, 0x223D), (0x223D, 0x223C), (0x2243, 0x22CD), (0x2245, 0x224C),
-    (0x224C, 0x2245), (0x2252, 0x2253), (0x2253, 0x2252), (0x2254, 0x2255), (0x2255, 0x2254),
-    (0x2264, 0x2265), (0x2265, 0x2264), (0x2266, 0x2267), (0x2267, 0x2266), (0x2268, 0x2269),
-    (0x2269, 0x2268), (0x226A, 0x226B), (0x226B, 0x226A), (0x226E, 0x226F), (0x226F, 0x226E),
-    (0x2270, 0x2271), (0x2271, 0x2270), (0x2272, 0x2273), (0x2273, 0x2272), (0x2274, 0x2275),
-    (0x2275, 0x2274), (0x2276, 0x2277), (0x2277, 0x2276), (0x2278, 0x2279), (0x2279, 0x2278),
-    (0x227A, 0x227B), (0x227B, 0x227A), (0x227C, 0x227D), (0x227D, 0x227C), (0x227E, 0x227F),
-    (0x227F, 0x227E), (0x2280, 0x2281), (0x2281, 0x2280), (0x2282, 0x2283), (0x2283, 0x2282),
-    (0x2284, 0x2285), (0x2285, 0x2284), (0x2286, 0x2287), (0x2287, 0x2286), (0x2288, 0x2289),
-    (0x2289, 0x2288), (0x228A, 0x228B), (0x228B, 0x228A), (0x228F, 0x2290), (0x2290, 0x228F),
-    (0x2291, 0x2292), (0x2292, 0x2291), (0x2298, 0x29B8), (0x22A2, 0x22A3), (0x22A3, 0x22A2),
-    (0x22A6, 0x2ADE), (0x22A8, 0x2AE4), (0x22A9, 0x2AE3), (0x22AB, 0x2AE5), (0x22B0, 0x22B1),
-    (0x22B1, 0x22B0), (0x22B2, 0x22B3), (0x22B3, 0x22B2), (0x22B4, 0x22B5), (0x22B5, 0x22B4),
-    (0x22B6, 0x22B7), (0x22B7, 0x22B6), (0x22B8, 0x27DC), (0x22C9, 0x22CA), (0x22CA, 0x22C9),
-    (0x22CB, 0x22CC), (0x22CC, 0x22CB), (0x22CD, 0x2243), (0x22D0, 0x22D1), (0x22D1, 0x22D0),
-    (0x22D6, 0x22D7), (0x22D7, 0x22D6), (0x22D8, 0x22D9), (0x22D9, 0x22D8), (0x22DA, 0x22DB),
-    (0x22DB, 0x22DA), (0x22DC, 0x22DD), (0x22DD, 0x22DC), (0x22DE, 0x22DF), (0x22DF, 0x22DE),
-    (0x22E0, 0x22E1), (0x22E1, 0x22E0), (0x22E2, 0x22E3), (0x22E3, 0x22E2), (0x22E4, 0x22E5),
-    (0x22E5, 0x22E4), (0x22E6, 0x22E7), (0x22E7, 0x22E6), (0x22E8, 0x22E9), (0x22E9, 0x22E8),
-    (0x22EA, 0x22EB), (0x22EB, 0x22EA), (0x22EC, 0x22ED), (0x22ED, 0x22EC), (0x22F0, 0x22F1),
-    (0x22F1, 0x22F0), (0x22F2, 0x22FA), (0x22F3, 0x22FB), (0x22F4, 0x22FC), (0x22F6, 0x22FD),
-    (0x22F7, 0x22FE), (0x22FA, 0x22F2), (0x22FB, 0x22F3), (0x22FC, 0x22F4), (0x22FD, 0x22F6),
-    (0x22FE, 0x22F7), (0x2308, 0x2309), (0x2309, 0x2308), (0x230A, 0x230B), (0x230B, 0x230A),
-    (0x2329, 0x232A), (0x232A, 0x2329), (0x2768, 0x2769), (0x2769, 0x2768), (0x276A, 0x276B),
-    (0x276B, 0x276A), (0x276C, 0x276D), (0x276D, 0x276C), (0x276E, 0x276F), (0x276F, 0x276E),
-    (0x2770, 0x2771), (0x2771, 0x2770), (0x2772, 0x2773), (0x2773, 0x2772), (0x2774, 0x2775),
-    (0x2775, 0x2774), (0x27C3, 0x27C4), (0x27C4, 0x27C3), (0x27C5, 0x27C6), (0x27C6, 0x27C5),
-    (0x27C8, 0x27C9), (0x27C9, 0x27C8), (0x27CB, 0x27CD), (0x27CD, 0x27CB), (0x27D5, 0x27D6),
-    (0x27D6, 0x27D5), (0x27DC, 0x22B8), (0x27DD, 0x27DE), (0x27DE, 0x27DD), (0x27E2, 0x27E3),
-    (0x27E3, 0x27E2), (0x27E4, 0x27E5), (0x27E5, 0x27E4), (0x27E6, 0x27E7), (0x27E7, 0x27E6),
-    (0x27E8, 0x27E9), (0x27E9, 0x27E8), (0x27EA, 0x27EB), (0x27EB, 0x27EA), (0x27EC, 0x27ED),
-    (0x27ED, 0x27EC), (0x27EE, 0x27EF), (0x27EF, 0x27EE), (0x2983, 0x2984), (0x2984, 0x2983),
-    (0x2985, 0x2986), (0x2986, 0x2985), (0x2987, 0x2988), (0x2988, 0x2987), (0x2989, 0x298A),
-    (0x298A, 0x2989), (0x298B, 0x298C), (0x298C, 0x298B), (0x298D, 0x2990), (0x298E, 0x298F),
-    (0x298F, 0x298E), (0x2990, 0x298D), (0x2991, 0x2992), (0x2992, 0x2991), (0x2993, 0x2994),
-    (0x2994, 0x2993), (0x2995, 0x2996), (0x2996, 0x2995), (0x2997, 0x2998), (0x2998, 0x2997),
-    (0x299B, 0x2221), (0x29A0, 0x2222), (0x29A3, 0x2220), (0x29A4, 0x29A5), (0x29A5, 0x29A4),
-    (0x29A8, 0x29A9), (0x29A9, 0x29A8), (0x29AA, 0x29AB), (0x29AB, 0x29AA), (0x29AC, 0x29AD),
-    (0x29AD, 0x29AC), (0x29AE, 0x29AF), (0x29AF, 0x29AE), (0x29B8, 0x2298), (0x29C0, 0x29C1),
-    (0x29C1, 0x29C0), (0x29C4, 0x29C5), (0x29C5, 0x29C4), (0x29CF, 0x29D0), (0x29D0, 0x29CF),
-    (0x29D1, 0x29D2), (0x29D2, 0x29D1), (0x29D4, 0x29D5), (0x29D5, 0x29D4), (0x29D8, 0x29D9),
-    (0x29D9, 0x29D8), (0x29DA, 0x29DB), (0x29DB, 0x29DA), (0x29E8, 0x29E9), (0x29E9, 0x29E8),
-    (0x29F5, 0x2215), (0x29F8, 0x29F9), (0x29F9, 0x29F8), (0x29FC, 0x29FD), (0x29FD, 0x29FC),
-    (0x2A2B, 0x2A2C), (0x2A2C, 0x2A2B), (0x2A2D, 0x2A2E), (0x2A2E, 0x2A2D), (0x2A34, 0x2A35),
-    (0x2A35, 0x2A34), (0x2A3C, 0x2A3D), (0x2A3D, 0x2A3C), (0x2A64, 0x2A65), (0x2A65, 0x2A64),
-    (0x2A79, 0x2A7A), (0x2A7A, 0x2A79), (0x2A7B, 0x2A7C), (0x2A7C, 0x2A7B), (0x2A7D, 0x2A7E),
-    (0x2A7E, 0x2A7D), (0x2A7F, 0x2A80), (0x2A80, 0x2A7F), (0x2A81, 0x2A82), (0x2A82, 0x2A81),
-    (0x2A83, 0x2A84), (0x2A84, 0x2A83), (0x2A85, 0x2A86), (0x2A86, 0x2A85), (0x2A87, 0x2A88),
-    (0x2A88, 0x2A87), (0x2A89, 0x2A8A), (0x2A8A, 0x2A89), (0x2A8B, 0x2A8C), (0x2A8C, 0x2A8B),
-    (0x2A8D, 0x2A8E), (0x2A8E, 0x2A8D), (0x2A8F, 0x2A90), (0x2A90, 0x2A8F), (0x2A91, 0x2A92),
-    (0x2A92, 0x2A91), (0x2A93, 0x2A94), (0x2A94, 0x2A93), (0x2A95, 0x2A96), (0x2A96, 0x2A95),
-    (0x2A97, 0x2A98), (0x2A98, 0x2A97), (0x2A99, 0x2A9A), (0x2A9A, 0x2A99), (0x2A9B, 0x2A9C),
-    (0x2A9C, 0x2A9B), (0x2A9D, 0x2A9E), (0x2A9E, 0x2A9D), (0x2A9F, 0x2AA0), (0x2AA0, 0x2A9F),
-    (0x2AA1, 0x2AA2), (0x2AA2, 0x2AA1), (0x2AA6, 0x2AA7), (0x2AA7, 0x2AA6), (0x2AA8, 0x2AA9),
-    (0x2AA9, 0x2AA8), (0x2AAA, 0x2AAB), (0x2AAB, 0x2AAA), (0x2AAC, 0x2AAD), (0x2AAD, 0x2AAC),
-    (0x2AAF, 0x2AB0), (0x2AB0, 0x2AAF), (0x2AB1, 0x2AB2), (0x2AB2, 0x2AB1), (0x2AB3, 0x2AB4),
-    (0x2AB4, 0x2AB3), (0x2AB5, 0x2AB6), (0x2AB6, 0x2AB5), (0x2AB7, 0x2AB8), (0x2AB8, 0x2AB7),
-    (0x2AB9, 0x2ABA), (0x2ABA, 0x2AB9), (0x2ABB, 0x2ABC), (0x2ABC, 0x2ABB), (0x2ABD, 0x2ABE),
-    (0x2ABE, 0x2ABD), (0x2ABF, 0x2AC0), (0x2AC0, 0x2ABF), (0x2AC1, 0x2AC2), (0x2AC2, 0x2AC1),
-    (0x2AC3, 0x2AC4), (0x2AC4, 0x2AC3), (0x2AC5, 0x2AC6), (0x2AC6, 0x2AC5), (0x2AC7, 0x2AC8),
-    (0x2AC8, 0x2AC7), (0x2AC9, 0x2ACA), (0x2ACA, 0x2AC9), (0x2ACB, 0x2ACC), (0x2ACC, 0x2ACB),
-    (0x2ACD, 0x2ACE), (0x2ACE, 0x2ACD), (0x2ACF, 0x2AD0), (0x2AD0, 0x2ACF), (0x2AD1, 0x2AD2),
-    (0x2AD2, 0x2AD1), (0x2AD3, 0x2AD4), (0x2AD4, 0x2AD3), (0x2AD5, 0x2AD6), (0x2AD6, 0x2AD5),
-    (0x2ADE, 0x22A6), (0x2AE3, 0x22A9), (0x2AE4, 0x22A8), (0x2AE5, 0x22AB), (0x2AEC, 0x2AED),
-    (0x2AED, 0x2AEC), (0x2AEE, 0x2224), (0x2AF7, 0x2AF8), (0x2AF8, 0x2AF7), (0x2AF9, 0x2AFA),
-    (0x2AFA, 0x2AF9), (0x2BFE, 0x221F), (0x2E02, 0x2E03), (0x2E03, 0x2E02), (0x2E04, 0x2E05),
-    (0x2E05, 0x2E04), (0x2E09, 0x2E0A), (0x2E0A, 0x2E09), (0x2E0C, 0x2E0D), (0x2E0D, 0x2E0C),
-    (0x2E1C, 0x2E1D), (0x2E1D, 0x2E1C), (0x2E20, 0x2E21), (0x2E21, 0x2E20), (0x2E22, 0x2E23),
-    (0x2E23, 0x2E22), (0x2E24, 0x2E25), (0x2E25, 0x2E24), (0x2E26, 0x2E27), (0x2E27, 0x2E26),
-    (0x2E28, 0x2E29), (0x2E29, 0x2E28), (0x3008, 0x3009), (0x3009, 0x3008), (0x300A, 0x300B),
-    (0x300B, 0x300A), (0x300C, 0x300D), (0x300D, 0x300C), (0x300E, 0x300F), (0x300F, 0x300E),
-    (0x3010, 0x3011), (0x3011, 0x3010), (0x3014, 0x3015), (0x3015, 0x3014), (0x3016, 0x3017),
-    (0x3017, 0x3016), (0x3018, 0x3019), (0x3019, 0x3018), (0x301A, 0x301B), (0x301B, 0x301A),
-    (0xFE59, 0xFE5A), (0xFE5A, 0xFE59), (0xFE5B, 0xFE5C), (0xFE5C, 0xFE5B), (0xFE5D, 0xFE5E),
-    (0xFE5E, 0xFE5D), (0xFE64, 0xFE65), (0xFE65, 0xFE64), (0xFF08, 0xFF09), (0xFF09, 0xFF08),
-    (0xFF1C, 0xFF1E), (0xFF1E, 0xFF1C), (0xFF3B, 0xFF3D), (0xFF3D, 0xFF3B), (0xFF5B, 0xFF5D),
-    (0xFF5D, 0xFF5B), (0xFF5F, 0xFF60), (0xFF60, 0xFF5F), (0xFF62, 0xFF63), (0xFF63, 0xFF62),
+pub const BLOCK_RANGES: [(u32, u32); 309] = [
+    (125184, 125279), (65792, 65855), (71424, 71487), (128768, 128895), (64256, 64335),
+    (82944, 83583), (119296, 119375), (65856, 65935), (65936, 65999), (1536, 1791), (2208, 2303),
+    (126464, 126719), (64336, 65023), (65136, 65279), (1872, 1919), (1328, 1423), (8592, 8703),
+    (0, 127), (68352, 68415), (6912, 7039), (42656, 42751), (92160, 92735), (92880, 92927),
+    (7104, 7167), (2432, 2559), (72704, 72815), (9600, 9631), (12544, 12591), (12704, 12735),
+    (9472, 9599), (69632, 69759), (10240, 10495), (6656, 6687), (5952, 5983), (118784, 119039),
+    (66208, 66271), (66864, 66927), (69888, 69967), (43520, 43615), (5024, 5119), (43888, 43967),
+    (129536, 129647), (69552, 69599), (19968, 40959), (13056, 13311), (65072, 65103),
+    (63744, 64255), (194560, 195103), (13312, 19903), (131072, 173791), (173824, 177983),
+    (177984, 178207), (178208, 183983), (183984, 191471), (196608, 201551), (11904, 12031),
+    (12736, 12783), (12592, 12687), (9216, 9279), (11392, 11519), (66272, 66303), (119648, 119679),
+    (73728, 74751), (8352, 8399), (67584, 67647), (1024, 1279), (11744, 11775), (42560, 42655),
+    (7296, 7311), (1280, 1327), (66560, 66639), (2304, 2431), (43232, 43263), (768, 879),
+    (6832, 6911), (7616, 7679), (9984, 10175), (71936, 72031), (71680, 71759), (127024, 127135),
+    (113664, 113823), (74880, 75087), (78896, 78911), (77824, 78895), (66816, 66863),
+    (69600, 69631), (128512, 128591), (9312, 9471), (127232, 127487), (127488, 127743),
+    (4608, 4991), (11648, 11743), (43776, 43823), (4992, 5023), (9632, 9727), (128896, 129023),
+    (4256, 4351), (7312, 7359), (11520, 11567), (11264, 11359), (122880, 122927), (66352, 66383),
+    (70400, 70527), (7936, 8191), (2688, 2815), (73056, 73135), (2560, 2687), (65056, 65071),
+    (44032, 55215), (68864, 68927), (5920, 5951), (67808, 67839), (1424, 1535), (56192, 56319),
+    (55296, 56191), (12352, 12447), (12272, 12287), (67648, 67679), (43056, 43071),
+    (126064, 126143), (68448, 68479), (68416, 68447), (592, 687), (4352, 4607), (43360, 43391),
+    (55216, 55295), (43392, 43487), (69760, 69839), (110848, 110895), (110592, 110847),
+    (12688, 12703), (12032, 12255), (3200, 3327), (12448, 12543), (12784, 12799), (43264, 43311),
+    (68096, 68191), (101120, 101631), (6016, 6143), (6624, 6655), (70144, 70223), (70320, 70399),
+    (3712, 3839), (128, 255), (256, 383), (7680, 7935), (384, 591), (11360, 11391), (42784, 43007),
+    (43824, 43887), (7168, 7247), (8448, 8527), (6400, 6479), (67072, 67455), (65664, 65791),
+    (65536, 65663), (42192, 42239), (73648, 73663), (56320, 57343), (66176, 66207), (67872, 67903),
+    (69968, 70015), (126976, 127023), (73440, 73471), (3328, 3455), (2112, 2143), (68288, 68351),
+    (72816, 72895), (72960, 73055), (119808, 120831), (8704, 8959), (119520, 119551),
+    (93760, 93855), (43968, 44031), (43744, 43775), (124928, 125151), (68000, 68095),
+    (67968, 67999), (93952, 94111), (10176, 10223), (10624, 10751), (9728, 9983), (8960, 9215),
+    (71168, 71263), (688, 767), (42752, 42783), (6144, 6319), (71264, 71295), (92736, 92783),
+    (70272, 70319), (119040, 119295), (4096, 4255), (43616, 43647), (43488, 43519), (67712, 67759),
+    (72096, 72191), (1, 0), (6528, 6623), (70656, 70783), (1984, 2047), (8528, 8591),
+    (110960, 111359), (123136, 123215), (9280, 9311), (5760, 5791), (7248, 7295), (68736, 68863),
+    (66304, 66351), (68224, 68255), (66384, 66431), (66464, 66527), (69376, 69423), (68192, 68223),
+    (68608, 68687), (2816, 2943), (128592, 128639), (66736, 66815), (66688, 66735),
+    (126208, 126287), (92928, 93071), (67680, 67711), (72384, 72447), (66000, 66047),
+    (67840, 67871), (7424, 7551), (7552, 7615), (127136, 127231), (68480, 68527), (57344, 63743),
+    (8192, 8303), (43312, 43359), (69216, 69247), (5792, 5887), (2048, 2111), (43136, 43231),
+    (70016, 70111), (66640, 66687), (113824, 113839), (71040, 71167), (3456, 3583), (70112, 70143),
+    (65104, 65135), (110896, 110959), (69424, 69487), (69840, 69887), (72272, 72367),
+    (65520, 65535), (7040, 7103), (7360, 7375), (10224, 10239), (10496, 10623), (129024, 129279),
+    (10752, 11007), (983040, 1048575), (1048576, 1114111), (11776, 11903), (120832, 121519),
+    (43008, 43055), (1792, 1871), (2144, 2159), (5888, 5919), (5984, 6015), (917504, 917631),
+    (6480, 6527), (6688, 6831), (43648, 43743), (119552, 119647), (71296, 71375), (2944, 3071),
+    (73664, 73727), (94208, 100351), (100352, 101119), (101632, 101775), (3072, 3199),
+    (1920, 1983), (3584, 3711), (3840, 4095), (11568, 11647), (70784, 70879), (5120, 5759),
+    (6320, 6399), (66432, 66463), (42240, 42559), (7376, 7423), (65040, 65055), (65024, 65039),
+    (917760, 917999), (123584, 123647), (71840, 71935), (69248, 69311), (42128, 42191),
+    (40960, 42127), (19904, 19967), (72192, 72271), (880, 1023), (8304, 8351), (8400, 8447),
+    (11008, 11263), (12288, 12351), (12800, 13055), (43072, 43135), (65280, 65519), (74752, 74879),
+    (94176, 94207), (127744, 128511), (128640, 128767), (129280, 129535), (129648, 129791),
+    (129792, 130047),
 ];
 
 #[rustfmt::skip]
@@ -1191,6 +1290,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 190, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1203,6 +1303,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1216,6 +1317,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 190, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1228,6 +1330,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1241,6 +1344,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 190, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1253,6 +1357,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1266,6 +1371,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 190, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1278,6 +1384,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1291,6 +1398,7 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 190, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1303,6 +1411,8 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
+    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 191, 192, 193, 193, 193, 193, 193, 193,
+    193, 193, 193, 193, 193, 193, 193, 193, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
@@ -1312,1271 +1422,1189 @@ const RECORD_INDEX0: [u8; 4352] = [
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 190, 191, 192, 192, 192, 192, 192, 192,
-    192, 192, 192, 192, 192, 192, 192, 192, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
     112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112, 112,
-    112, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193, 193,
-    193, 193, 193, 193, 193, 193, 193, 193, 193, 194, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 195, 196,
+    190, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194, 194,
+    194, 194, 194, 194, 194, 194, 194, 194, 194, 195, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196,
+    196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 196, 197,
 ];
 
 #[rustfmt::skip]
-const RECORD_INDEX1: [u16; 6304] = [
-    0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 8, 9, 10, 11, 11, 12, 13, 14, 14, 14, 15, 16, 17, 18, 19, 19, 20,
-    21, 22, 22, 23, 22, 24, 24, 24, 24, 24, 24, 24, 25, 25, 26, 24, 24, 24, 24, 24, 27, 28, 29, 30,
-    31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 40, 41, 40, 40, 40, 40, 40, 40, 40, 42, 43, 44, 40, 45,
-    45, 45, 45, 45, 45, 45, 45, 46, 45, 45, 45, 47, 48, 49, 50, 51, 52, 53, 54, 55, 55, 56, 56, 57,
-    58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 56, 68, 69, 70, 71, 72, 73, 74, 75, 76, 76, 76, 77, 78,
-    79, 80, 81, 82, 83, 84, 84, 84, 84, 84, 84, 85, 85, 85, 85, 85, 85, 86, 86, 86, 86, 87, 88, 86,
-    86, 86, 86, 86, 86, 89, 90, 86, 86, 86, 86, 86, 86, 91, 91, 91, 91, 91, 91, 92, 93, 93, 93, 94,
-    95, 96, 96, 96, 96, 96, 97, 98, 99, 100, 101, 102, 103, 104, 105, 106, 106, 106, 107, 108, 105,
-    109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 123, 116, 116, 124, 124,
-    125, 116, 116, 116, 116, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139,
-    140, 141, 142, 143, 144, 145, 144, 146, 147, 148, 149, 149, 149, 149, 150, 151, 152, 153, 154,
-    155, 156, 156, 156, 157, 158, 159, 160, 160, 161, 162, 163, 164, 165, 166, 167, 168, 169, 170,
-    171, 172, 173, 173, 173, 173, 173, 173, 174, 175, 176, 177, 174, 178, 179, 180, 181, 182, 183,
-    184, 185, 186, 186, 186, 186, 186, 186, 187, 188, 189, 190, 186, 191, 192, 193, 186, 194, 195,
-    196, 197, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207, 208, 209, 210, 211, 212, 212,
-    213, 214, 215, 216, 217, 218, 219, 220, 221, 222, 223, 224, 225, 226, 227, 227, 228, 229, 230,
-    231, 232, 233, 234, 235, 236, 237, 238, 239, 240, 241, 242, 242, 243, 244, 245, 246, 247, 248,
-    249, 250, 251, 252, 253, 254, 255, 256, 257, 258, 255, 259, 260, 261, 262, 263, 264, 265, 266,
-    267, 268, 269, 270, 271, 272, 272, 271, 272, 273, 274, 275, 276, 277, 278, 279, 280, 281, 282,
-    283, 284, 285, 285, 284, 286, 287, 288, 289, 290, 291, 292, 293, 294, 295, 296, 297, 298, 299,
-    299, 299, 299, 300, 301, 302, 303, 304, 305, 306, 307, 308, 309, 310, 311, 312, 310, 310, 313,
-    314, 311, 315, 316, 317, 318, 319, 320, 321, 322, 323, 323, 323, 323, 324, 325, 326, 327, 328,
-    329, 330, 331, 331, 331, 331, 332, 333, 334, 334, 335, 336, 337, 338, 339, 340, 341, 342, 343,
-    343, 343, 343, 344, 345, 346, 347, 348, 349, 350, 351, 352, 353, 352, 352, 352, 354, 355, 356,
-    357, 358, 359, 360, 359, 359, 359, 361, 362, 363, 364, 365, 366, 366, 366, 366, 367, 367, 367,
-    367, 368, 369, 370, 371, 372, 373, 374, 375, 376, 377, 378, 367, 379, 380, 381, 382, 383, 383,
-    383, 383, 384, 385, 386, 386, 386, 386, 386, 387, 388, 388, 388, 388, 388, 388, 388, 388, 388,
-    388, 388, 388, 389, 389, 389, 389, 389, 389, 389, 389, 389, 390, 390, 390, 390, 390, 390, 390,
-    390, 390, 390, 390, 391, 391, 391, 391, 391, 391, 391, 391, 391, 392, 393, 392, 391, 391, 391,
-    391, 391, 392, 391, 391, 391, 391, 392, 393, 392, 391, 393, 391, 391, 391, 391, 391, 391, 391,
-    392, 391, 391, 391, 391, 391, 391, 391, 391, 394, 395, 396, 397, 398, 399, 399, 400, 401, 402,
-    402, 402, 402, 402, 402, 402, 402, 402, 402, 403, 404, 405, 406, 406, 406, 406, 406, 406, 406,
-    406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406,
-    406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406,
-    406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406,
-    406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 406, 407, 406, 406, 408, 409, 409, 410,
-    411, 411, 411, 411, 411, 411, 411, 411, 411, 412, 413, 414, 415, 416, 417, 418, 419, 419, 420,
-    421, 422, 422, 423, 424, 425, 426, 427, 428, 429, 429, 429, 429, 429, 429, 430, 431, 432, 433,
-    434, 435, 436, 437, 438, 439, 440, 441, 442, 443, 444, 444, 444, 444, 445, 446, 446, 446, 446,
-    446, 446, 447, 448, 446, 446, 446, 446, 449, 450, 450, 450, 450, 450, 450, 450, 450, 451, 452,
-    453, 454, 454, 455, 456, 457, 458, 459, 460, 461, 462, 462, 462, 463, 464, 465, 466, 466, 466,
-    466, 466, 467, 466, 466, 466, 468, 469, 470, 471, 471, 471, 471, 472, 472, 473, 474, 475, 475,
-    475, 475, 475, 475, 476, 477, 478, 479, 480, 481, 482, 483, 482, 483, 484, 485, 486, 487, 488,
-    489, 489, 489, 489, 489, 489, 489, 490, 491, 491, 491, 491, 491, 492, 493, 494, 495, 496, 497,
-    498, 499, 500, 501, 502, 503, 503, 503, 504, 505, 506, 507, 508, 508, 508, 508, 509, 510, 511,
-    512, 513, 513, 513, 513, 514, 515, 516, 517, 518, 519, 520, 521, 522, 522, 522, 523, 524, 525,
-    526, 526, 526, 526, 526, 527, 528, 529, 530, 531, 532, 533, 534, 535, 536, 536, 536, 536, 537,
-    538, 539, 539, 539, 539, 539, 540, 541, 542, 536, 543, 544, 544, 544, 545, 546, 546, 546, 547,
-    548, 549, 550, 551, 551, 551, 552, 553, 554, 554, 554, 554, 554, 554, 554, 554, 554, 554, 554,
-    554, 554, 554, 554, 554, 554, 554, 555, 556, 554, 554, 554, 554, 554, 554, 554, 554, 554, 554,
-    554, 554, 557, 558, 559, 560, 557, 558, 557, 558, 559, 560, 557, 561, 557, 558, 557, 559, 557,
-    562, 557, 562, 557, 562, 563, 564, 565, 566, 567, 568, 557, 569, 570, 571, 572, 573, 574, 575,
-    576, 577, 578, 579, 580, 581, 582, 583, 584, 585, 586, 587, 588, 589, 590, 591, 592, 593, 594,
-    595, 596, 596, 597, 598, 599, 600, 601, 602, 603, 604, 605, 606, 607, 608, 609, 610, 611, 612,
-    613, 614, 615, 616, 615, 617, 618, 619, 620, 621, 622, 623, 624, 624, 624, 625, 626, 624, 624,
-    624, 627, 628, 629, 630, 631, 632, 633, 634, 635, 636, 637, 638, 639, 637, 640, 641, 637, 637,
-    641, 637, 642, 643, 642, 637, 637, 644, 637, 637, 637, 637, 637, 645, 637, 637, 646, 647, 648,
-    649, 650, 651, 652, 653, 653, 653, 653, 653, 653, 653, 653, 654, 646, 655, 656, 657, 658, 658,
-    659, 646, 646, 660, 646, 661, 650, 662, 663, 664, 665, 665, 665, 665, 666, 667, 667, 667, 668,
-    669, 670, 670, 671, 671, 671, 671, 671, 672, 672, 673, 674, 674, 675, 676, 677, 676, 676, 676,
-    676, 678, 671, 679, 680, 680, 680, 680, 680, 680, 680, 680, 680, 681, 680, 680, 680, 680, 682,
-    683, 684, 684, 685, 686, 687, 688, 689, 690, 691, 692, 693, 694, 695, 696, 694, 697, 698, 699,
-    700, 701, 702, 703, 704, 705, 706, 707, 708, 709, 710, 711, 704, 712, 713, 714, 715, 716, 717,
-    718, 719, 720, 721, 722, 723, 724, 725, 726, 727, 728, 729, 730, 731, 732, 733, 734, 735, 736,
-    737, 738, 739, 740, 741, 742, 743, 744, 744, 744, 745, 736, 733, 736, 734, 746, 747, 748, 748,
-    748, 749, 750, 751, 751, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752,
-    752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 752, 753,
-    753, 753, 753, 753, 753, 754, 753, 753, 753, 753, 753, 753, 753, 753, 753, 755, 756, 757, 758,
-    759, 759, 759, 759, 759, 759, 759, 760, 759, 759, 759, 761, 762, 762, 762, 762, 762, 762, 762,
-    762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762, 762,
-    762, 762, 762, 762, 762, 762, 763, 764, 764, 765, 764, 764, 766, 766, 767, 768, 769, 770, 764,
-    764, 771, 764, 764, 764, 772, 764, 764, 764, 764, 764, 764, 764, 764, 764, 764, 764, 764, 764,
-    773, 773, 773, 773, 773, 774, 775, 775, 775, 775, 775, 776, 777, 778, 779, 780, 781, 781, 781,
-    781, 781, 781, 781, 781, 781, 781, 781, 781, 782, 783, 784, 785, 786, 786, 786, 786, 787, 788,
-    789, 789, 789, 789, 789, 789, 789, 790, 791, 792, 793, 793, 794, 795, 794, 794, 794, 794, 794,
-    794, 794, 794, 796, 796, 796, 796, 797, 798, 799, 800, 801, 802, 803, 804, 805, 806, 807, 808,
-    808, 808, 808, 808, 809, 809, 809, 810, 809, 809, 809, 809, 809, 809, 809, 809, 809, 809, 811,
-    812, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813, 813,
-    813, 813, 813, 813, 813, 813, 813, 813, 814, 815, 173, 173, 816, 817, 818, 819, 820, 821, 822,
-    823, 824, 825, 826, 827, 828, 828, 829, 828, 828, 828, 830, 831, 832, 833, 834, 835, 836, 836,
-    837, 836, 836, 836, 838, 839, 840, 841, 842, 843, 843, 843, 843, 843, 844, 845, 845, 845, 845,
-    845, 845, 845, 845, 845, 845, 846, 847, 848, 849, 849, 849, 849, 850, 850, 850, 850, 851, 852,
-    853, 853, 854, 854, 854, 855, 856, 857, 858, 858, 858, 859, 860, 861, 854, 854, 854, 862, 856,
-    857, 863, 864, 858, 858, 865, 861, 858, 866, 867, 867, 867, 867, 867, 868, 869, 869, 869, 869,
-    869, 869, 869, 869, 869, 869, 869, 870, 870, 870, 871, 872, 870, 870, 870, 870, 870, 870, 870,
-    870, 870, 870, 870, 873, 870, 870, 870, 871, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874,
-    874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874,
-    874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874,
-    874, 874, 874, 874, 874, 874, 874, 874, 875, 875, 875, 875, 875, 875, 875, 875, 876, 876, 876,
-    876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876,
-    876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876,
-    876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876,
-    876, 876, 876, 877, 878, 878, 879, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 878, 878, 878, 878, 878, 878, 878, 878, 880, 881, 881, 881, 881, 881, 881, 882, 883, 884,
-    884, 884, 884, 884, 885, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886,
-    886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886,
-    887, 886, 886, 888, 889, 890, 890, 891, 891, 891, 891, 891, 892, 893, 894, 891, 891, 891, 895,
-    896, 896, 896, 896, 896, 896, 896, 896, 897, 898, 899, 900, 901, 902, 903, 904, 905, 906, 907,
-    906, 906, 906, 906, 906, 906, 906, 908, 909, 906, 910, 911, 906, 906, 912, 913, 906, 914, 915,
-    916, 916, 916, 916, 917, 918, 919, 920, 921, 921, 922, 923, 924, 925, 926, 926, 926, 926, 926,
-    926, 927, 928, 929, 930, 930, 930, 930, 930, 931, 932, 933, 934, 935, 936, 937, 937, 938, 939,
-    940, 941, 942, 942, 943, 944, 945, 945, 946, 947, 948, 949, 950, 950, 950, 951, 952, 953, 953,
-    953, 953, 953, 954, 955, 956, 957, 958, 959, 960, 961, 962, 963, 964, 964, 964, 964, 964, 965,
-    966, 967, 968, 969, 970, 971, 972, 972, 973, 974, 975, 975, 975, 975, 975, 975, 976, 977, 978,
-    979, 979, 980, 981, 982, 983, 984, 985, 985, 985, 986, 987, 987, 988, 988, 988, 988, 988, 989,
-    990, 991, 992, 992, 992, 992, 992, 992, 992, 992, 992, 992, 993, 993, 993, 993, 994, 995, 996,
-    997, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999,
-    1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999,
-    998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000,
-    999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998,
-    999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999,
-    999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999,
-    999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999,
-    999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999,
-    1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999,
-    998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000,
-    999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998,
-    999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1000, 999,
-    999, 999, 998, 999, 999, 1000, 999, 999, 999, 999, 999, 999, 998, 999, 999, 1000, 999, 999,
-    999, 998, 999, 999, 1000, 999, 999, 999, 998, 999, 999, 1001, 1002, 1003, 1003, 1004, 1005,
-    1006, 1006, 1006, 1006, 1006, 1007, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008,
-    1008, 1008, 1008, 1008, 1008, 1008, 1009, 1009, 1009, 1009, 1009, 1009, 1009, 1009, 1009, 1009,
-    1009, 1009, 1009, 1009, 1009, 1009, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010,
-    1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010,
-    1010, 1010, 1010, 1010, 1010, 1010, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011,
-    1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011, 1011,
-    1011, 1011, 1011, 1011, 1011, 1011, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012,
-    1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012,
-    1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012,
-    1012, 1012, 1012, 1013, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012,
-    1012, 1014, 1015, 1015, 1015, 1015, 1016, 1017, 1018, 1019, 1020, 1021, 1022, 1023, 1024, 1020,
-    1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1026, 1027, 1028, 1029,
-    1030, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025,
-    1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025,
-    1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1031, 1029, 1029,
-    1025, 1025, 1025, 1025, 1025, 1025, 1025, 1025, 1032, 1025, 1025, 1025, 1025, 1025, 1025, 1029,
-    1029, 1029, 1029, 1029, 1025, 1033, 1034, 1034, 1035, 1036, 1037, 1038, 1039, 1040, 1041, 1042,
-    1043, 1044, 1045, 1046, 1047, 1048, 1048, 1048, 1048, 1048, 1048, 1048, 1048, 1048, 1048, 1048,
-    1048, 1048, 1048, 1048, 1048, 1049, 1050, 1051, 1052, 1053, 1054, 1055, 1055, 1056, 1057, 1058,
-    1058, 1059, 1060, 1061, 1062, 1063, 1063, 1063, 1063, 1064, 1065, 1065, 1065, 1066, 1067, 1067,
-    1067, 1068, 1069, 1070, 1071, 1072, 1073, 1074, 1073, 1073, 1075, 1073, 1073, 1076, 1073, 1077,
-    1073, 1077, 1078, 1078, 1078, 1078, 1079, 1079, 1079, 1079, 1079, 1079, 1079, 1079, 1079, 1079,
-    1079, 1079, 1079, 1079, 1079, 1080, 1081, 1082, 1082, 1082, 1082, 1082, 1083, 1084, 1085, 1085,
-    1085, 1085, 1085, 1085, 1086, 1087, 1088, 1089, 1090, 1091, 1092, 1093, 1093, 1093, 1093, 1093,
-    1094, 1094, 1094, 1094, 1094, 1095, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 1096, 1096, 1096, 1097, 1098, 1098, 1098, 1098, 1098, 1098, 1099, 1100,
-    1101, 1102, 1102, 1103, 1104, 1104, 1104, 1104, 1105, 1106, 1107, 1107, 1108, 1109, 1110, 1110,
-    1110, 1110, 1111, 1112, 1113, 1113, 1113, 1114, 1115, 1115, 1115, 1115, 1116, 1115, 1117, 1118,
-    173, 173, 173, 173, 1119, 1119, 1119, 1119, 1119, 1120, 1120, 1120, 1120, 1120, 1121, 1121,
-    1121, 1121, 1121, 1121, 1122, 1122, 1122, 1123, 1124, 1125, 1126, 1126, 1126, 1126, 1127, 1128,
-    1128, 1128, 1128, 1129, 1130, 1130, 1130, 1130, 1130, 1131, 1132, 1132, 1132, 1132, 1132, 1132,
-    1133, 1134, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135,
-    1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135,
-    1135, 1135, 1135, 1135, 1135, 1135, 1135, 1135, 1136, 1137, 1135, 1135, 1138, 1137, 1135, 1137,
-    1137, 1137, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    1139, 1140, 1141, 1141, 1141, 1141, 1142, 1143, 1144, 1144, 1145, 1146, 1147, 1147, 1148, 1149,
-    1150, 1150, 1150, 1151, 1152, 1153, 173, 173, 173, 173, 173, 173, 1154, 1154, 1155, 1156, 1157,
-    1157, 1158, 1159, 1160, 1160, 1160, 1161, 173, 173, 173, 173, 173, 173, 173, 173, 1162, 1162,
-    1162, 1162, 1163, 1163, 1163, 1164, 1165, 1165, 1166, 1165, 1165, 1165, 1165, 1165, 1167, 1168,
-    1169, 1170, 1171, 1171, 1172, 1173, 1174, 1175, 1176, 1177, 1178, 1178, 1178, 1179, 1180, 1180,
-    1180, 1181, 173, 173, 173, 173, 1182, 1183, 1184, 1185, 1186, 1187, 1188, 1189, 1190, 1190,
-    1190, 1190, 1190, 1190, 1191, 1192, 1193, 1193, 1194, 1195, 1196, 1196, 1197, 1198, 1199, 1200,
-    1201, 1202, 1203, 1204, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1205, 1205, 1205,
-    1205, 1205, 1205, 1205, 1205, 1205, 1206, 173, 173, 173, 173, 173, 173, 1207, 1207, 1207, 1207,
-    1207, 1207, 1208, 1209, 1210, 1210, 1210, 1210, 1210, 1210, 1211, 1212, 1213, 1214, 1214, 1214,
-    1215, 1216, 1217, 1218, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 1219, 1219, 1219, 1220, 1221, 1221, 1221, 1221, 1221, 1222, 1223, 1224, 173,
-    173, 173, 173, 173, 173, 173, 173, 1225, 1225, 1225, 1226, 1227, 1228, 1229, 1230, 1231, 1232,
-    1233, 1234, 1235, 1235, 173, 173, 173, 173, 173, 173, 173, 173, 1236, 1237, 1238, 1239, 1240,
-    1240, 1241, 1241, 1242, 1243, 1244, 1245, 1245, 1245, 1245, 1245, 1245, 1246, 1247, 1248, 1249,
-    1250, 1251, 1252, 1253, 1254, 1255, 1256, 1256, 1256, 1256, 1256, 1257, 1258, 1259, 1260, 1261,
-    1261, 1261, 1262, 1263, 1264, 1265, 1266, 1266, 1266, 1267, 1268, 1269, 1270, 1271, 1272, 1273,
-    1273, 1273, 1273, 1274, 1275, 1276, 1277, 1277, 1277, 1277, 1277, 1278, 1279, 1280, 1281, 1282,
-    1283, 1284, 1285, 1286, 1287, 1288, 1288, 1289, 1288, 1288, 1290, 1291, 1292, 1293, 1293, 173,
-    173, 173, 173, 173, 173, 1294, 1295, 1296, 1297, 1296, 1298, 1299, 1299, 1299, 1299, 1299,
-    1300, 1301, 1302, 1303, 1304, 1305, 1306, 1307, 1308, 1308, 1309, 1310, 1311, 1312, 1313, 1314,
-    1315, 1316, 1317, 1317, 1318, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 1319, 1319, 1319, 1319, 1319, 1319, 1320, 1321, 1322, 1323, 1324, 1325, 1326,
-    1327, 1327, 1327, 1328, 1329, 1329, 1329, 1329, 1329, 1330, 1331, 1332, 1333, 1334, 1335, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    1336, 1336, 1336, 1336, 1336, 1337, 1338, 1339, 1340, 1341, 1342, 1343, 1344, 1344, 1344, 1344,
-    1345, 1345, 1345, 1345, 1345, 1345, 1346, 1347, 1348, 1349, 1350, 1351, 1352, 1353, 1354, 1354,
-    1355, 1355, 1355, 1355, 1355, 1356, 1357, 1358, 1359, 1360, 173, 173, 173, 173, 173, 173, 1361,
-    1361, 1361, 1362, 1363, 1364, 1365, 1366, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1367, 1367, 1367, 1367,
-    1367, 1368, 1369, 1370, 1371, 1371, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1372,
-    1372, 1372, 1372, 1373, 1373, 1373, 1373, 1374, 1375, 1376, 1377, 1378, 1379, 1380, 1381, 1381,
-    1381, 1382, 1383, 1384, 1385, 1386, 1387, 173, 173, 173, 173, 173, 173, 173, 173, 1388, 1389,
-    1388, 1388, 1388, 1388, 1390, 1391, 1392, 1393, 1393, 1393, 1394, 1395, 1396, 1396, 1396, 1396,
-    1397, 1398, 1399, 1400, 1401, 1402, 1403, 1403, 1403, 1403, 1404, 1405, 1406, 1407, 1408, 1409,
-    173, 173, 1410, 1410, 1410, 1410, 1410, 1410, 1410, 1411, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 1412, 1413, 1412, 1412, 1412, 1414, 1415, 1416, 1417, 1418, 1419,
-    1420, 1421, 1422, 1423, 1424, 1424, 1424, 1425, 1426, 1426, 1427, 1428, 1429, 173, 173, 173,
-    173, 173, 173, 173, 173, 1430, 1431, 1432, 1432, 1432, 1432, 1433, 1434, 1435, 1436, 1437,
-    1438, 1439, 1440, 1441, 1441, 1441, 1442, 1443, 1444, 1445, 1446, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1447, 1447, 1448, 1449, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 1450, 1451, 1452, 1452, 1453, 1454, 1455, 1456, 1457, 1458, 1459, 1459, 1459, 1459,
-    1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459,
-    1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459,
-    1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1459, 1460,
-    1461, 1461, 1461, 1461, 1461, 1461, 1461, 1461, 1461, 1461, 1461, 1461, 1462, 1462, 1462, 1462,
-    1462, 1462, 1462, 1462, 1462, 1462, 1462, 1462, 1462, 1463, 1464, 1465, 1466, 1466, 1466, 1466,
-    1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466, 1466,
-    1466, 1466, 1466, 1466, 1467, 1468, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1469, 1469, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1470, 1469, 1469, 1469, 1469, 1471, 1472, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1473, 1469, 1469, 1469, 1469, 1469, 1469, 1469,
-    1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1469, 1474, 1475,
-    1476, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477,
-    1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477,
-    1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477,
-    1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1478, 1477,
-    1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1479, 1480, 1480,
-    1480, 1480, 1480, 1480, 1480, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481,
-    1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481,
-    1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1482, 1483, 1483, 1483, 1484, 1485,
-    1486, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1487, 1487, 1487, 1488, 1489,
-    1490, 1491, 1491, 1491, 1491, 1491, 1491, 1492, 1493, 1494, 1495, 1496, 1497, 1498, 1499, 1499,
-    1500, 1499, 1499, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 1501, 1501, 1501, 1501, 1502, 1502, 1502, 1502, 1503, 1503,
-    1504, 1505, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1506, 1506, 1506, 1506,
-    1506, 1506, 1506, 1506, 1507, 1508, 1509, 1510, 1510, 1510, 1510, 1510, 1510, 1511, 1512, 1513,
-    173, 173, 173, 173, 173, 173, 173, 173, 1514, 1515, 1516, 1515, 1517, 1517, 1517, 1517, 1517,
-    1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517,
-    1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517,
-    1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517,
-    1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1517, 1518, 1519, 1519, 1519, 1519, 1519,
-    1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519,
-    1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1519, 1520, 1520, 1520, 1520, 1520,
-    1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520,
-    1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520,
-    1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520, 1520,
-    1520, 1520, 1520, 1520, 1520, 1521, 1522, 1522, 1522, 1522, 1522, 1523, 1524, 1525, 1525, 1525,
-    1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1526, 1527, 1527, 1527, 1527, 1527,
-    1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527,
-    1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1527, 1528, 1528, 1528, 1529, 1530, 1530,
-    1531, 1531, 1531, 1531, 1532, 1531, 1533, 1531, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534,
-    1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534,
-    1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534,
-    1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1534, 1535, 1536, 1536, 1536, 1536, 1536, 1536,
-    1536, 1536, 1536, 1536, 1536, 1536, 1536, 1537, 1536, 1538, 1536, 1539, 1536, 1540, 1541, 1542,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1543, 1543, 1543, 1543, 1543, 1543, 1543,
-    1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543,
-    1543, 1543, 1543, 1543, 1543, 1543, 1543, 1544, 1545, 1546, 1546, 1546, 1546, 1547, 1548, 1546,
-    1546, 1546, 1546, 1546, 1546, 1549, 1550, 1551, 1552, 1553, 1554, 1546, 1546, 1546, 1555, 1546,
-    1546, 1546, 1546, 1546, 1546, 1546, 1556, 1557, 1557, 1558, 1558, 1558, 1558, 1558, 1558, 1558,
-    1558, 1559, 1560, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 1561, 1561, 1562, 1563, 1564, 1564, 1564, 1564, 1564, 1564, 1564, 1564, 1564,
-    1564, 1565, 1566, 1567, 1567, 1567, 1568, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 1569, 1569, 1569, 1570, 1571, 1571, 1572, 1569, 1569, 1573, 1574,
-    1571, 1571, 1569, 1569, 1569, 1570, 1571, 1571, 1575, 1576, 1577, 1573, 1578, 1579, 1571, 1569,
-    1569, 1569, 1570, 1571, 1571, 1580, 1581, 1582, 1583, 1571, 1571, 1571, 1584, 1585, 1586, 1587,
-    1571, 1571, 1572, 1569, 1569, 1573, 1571, 1571, 1571, 1569, 1569, 1569, 1570, 1571, 1571, 1572,
-    1569, 1569, 1573, 1571, 1571, 1571, 1569, 1569, 1569, 1570, 1571, 1571, 1572, 1569, 1569, 1573,
-    1571, 1571, 1571, 1569, 1569, 1569, 1570, 1571, 1571, 1588, 1569, 1569, 1569, 1589, 1571, 1571,
-    1590, 1591, 1569, 1569, 1592, 1571, 1571, 1593, 1572, 1569, 1569, 1594, 1571, 1571, 1595, 1596,
-    1569, 1569, 1597, 1571, 1571, 1571, 1598, 1569, 1569, 1569, 1589, 1571, 1571, 1590, 1599, 1600,
-    1600, 1600, 1600, 1600, 1600, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601,
-    1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601, 1601,
-    1601, 1601, 1601, 1601, 1601, 1602, 1602, 1602, 1602, 1602, 1602, 1603, 1604, 1602, 1602, 1602,
-    1602, 1602, 1605, 1606, 1601, 1607, 1608, 1609, 1610, 1611, 1602, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 1612, 1613, 1613, 1614, 1615, 1616, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1617,
-    1617, 1617, 1617, 1617, 1618, 1619, 1620, 1621, 1622, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    1623, 1623, 1623, 1623, 1623, 1624, 1625, 1626, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627,
-    1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627,
-    1628, 1629, 1630, 1631, 173, 173, 173, 173, 1632, 1632, 1632, 1632, 1633, 1634, 1634, 1634,
-    1635, 1636, 1637, 1638, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173,
-    173, 1639, 1640, 1640, 1640, 1640, 1640, 1640, 1641, 1642, 1643, 173, 173, 173, 173, 173, 173,
-    173, 173, 1644, 1645, 1645, 1645, 1645, 1646, 1645, 1647, 1648, 1648, 173, 173, 173, 173, 173,
-    173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 173, 1649,
-    1650, 1650, 1650, 1651, 1652, 1653, 1654, 1655, 1656, 1651, 1657, 1651, 1653, 1653, 1658, 1650,
-    1659, 1650, 1660, 1661, 1659, 1650, 1660, 1662, 1662, 1662, 1662, 1662, 1662, 1663, 1662, 1664,
-    1665, 1665, 1665, 1665, 1666, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667,
-    1667, 1668, 1669, 1670, 1671, 1672, 1670, 1672, 1673, 1672, 1670, 1670, 1670, 1674, 1675, 1676,
-    1677, 1678, 1678, 1678, 1679, 1680, 1680, 1680, 1681, 1680, 1680, 1680, 1682, 1683, 1684, 1680,
-    1685, 1686, 1687, 1678, 1688, 1689, 1689, 1689, 1689, 1689, 1689, 1690, 1691, 1691, 1691, 1692,
-    1693, 1694, 1695, 1694, 1696, 1697, 1698, 1694, 1699, 1700, 1693, 1701, 1693, 1693, 1693, 1693,
-    1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1693, 1702,
-    1702, 1702, 1702, 1703, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1704,
-    1702, 1705, 1706, 1702, 1702, 1707, 1708, 1709, 1710, 1702, 1702, 1702, 1702, 1711, 1712, 1702,
-    1702, 1702, 1702, 1702, 1702, 1702, 1702, 1713, 1714, 1715, 1702, 1716, 1714, 1714, 1717, 1718,
-    1719, 1720, 1702, 1721, 1722, 1723, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1702, 1724, 1725,
-    1702, 1726, 1727, 1728, 1702, 1729, 1730, 1731, 1732, 1702, 1702, 1702, 1733, 1734, 1735, 1733,
-    1736, 1737, 1738, 1739, 1740, 1741, 1742, 1743, 1738, 1744, 1745, 1746, 1747, 1748, 1749, 1750,
-    1750, 1750, 1750, 1750, 1750, 1750, 1750, 1751, 1752, 1753, 1753, 1753, 1753, 1754, 1755, 1756,
-    1756, 1756, 1756, 1757, 1756, 1758, 1756, 1759, 1760, 1761, 1762, 1763, 1764, 1765, 1766, 1767,
-    1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1767, 1768, 1769, 1770,
-    1770, 1770, 1770, 1770, 1770, 1770, 1770, 1770, 1770, 1771, 1772, 1773, 1774, 1775, 1775, 1776,
-    1777, 1776, 1776, 1776, 1776, 1776, 1776, 1776, 1778, 1776, 1779, 1776, 1776, 1776, 1776, 1776,
-    1778, 1776, 1776, 1776, 1780, 1781, 1778, 1778, 1778, 1778, 1778, 1778, 1778, 1778, 1778, 1782,
-    1783, 1784, 1785, 1786, 1784, 1785, 1787, 1788, 1784, 1784, 1784, 1784, 1784, 1789, 1790, 1784,
-    1784, 1784, 1784, 1784, 1784, 1791, 1792, 1784, 1793, 1794, 1795, 1784, 1784, 1784, 1784, 1796,
-    1796, 1796, 1796, 1796, 1796, 1796, 1796, 1796, 1796, 1797, 1798, 1799, 1800, 1801, 1802, 1803,
-    1804, 1805, 1805, 1805, 1806, 1803, 1804, 1802, 1804, 1803, 1804, 1804, 1804, 1804, 1804, 1807,
-    1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807, 1807,
-    1807, 1808, 1807, 1807, 1807, 1807, 1807, 1807, 1809, 1810, 1810, 1810, 1810, 1811, 1812, 1813,
-    1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813,
-    1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813,
-    1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813,
-    1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1813, 1814, 1815,
-    1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815,
-    1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815,
-    1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815,
-    1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1815, 1816, 1817, 1817, 1817, 1817, 1818,
-    1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818,
-    1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818,
-    1818, 1818, 1818, 1818, 1818, 1819, 1820, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821,
-    1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821, 1821,
-    1821, 1821, 1822, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1824, 1825, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826,
-    1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826,
-    1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826,
-    1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826,
-    1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1827, 1828, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1829,
-    1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829,
-    1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829, 1829,
-    1829, 1829, 1830, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1831, 1832,
-    1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832,
-    1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832,
-    1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1833, 1817, 1817, 1817, 1817, 1817, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1834,
-    1835, 1835, 1835, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1837,
-    1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1838,
-    1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838,
-    1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1837, 1837, 1837,
-    1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837,
-    1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1837, 1839,
-    1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839,
-    1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839,
-    1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839,
-    1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1839, 1840, 1841,
-    1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841,
-    1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841,
-    1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841,
-    1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1841, 1842,
+const RECORD_INDEX1: [u16; 6336] = [
+    0, 7, 0, 13, 21, 29, 37, 43, 51, 52, 52, 57, 65, 66, 66, 71, 79, 85, 85, 85, 93, 101, 109, 117,
+    125, 132, 140, 148, 156, 163, 171, 179, 187, 187, 195, 187, 189, 187, 203, 211, 219, 227, 193,
+    187, 189, 187, 187, 235, 243, 248, 254, 261, 269, 277, 285, 292, 300, 305, 311, 315, 321, 310,
+    328, 310, 310, 310, 310, 321, 335, 310, 341, 252, 348, 354, 362, 362, 362, 362, 362, 362, 362,
+    362, 366, 362, 362, 362, 374, 381, 389, 397, 405, 413, 421, 427, 434, 434, 442, 442, 445, 451,
+    458, 465, 469, 476, 484, 491, 499, 507, 512, 442, 520, 528, 536, 544, 552, 553, 559, 565, 572,
+    573, 573, 579, 587, 595, 601, 603, 611, 619, 627, 635, 642, 649, 642, 642, 657, 664, 657, 657,
+    672, 680, 688, 688, 690, 688, 698, 706, 688, 688, 688, 688, 688, 688, 714, 718, 726, 730, 730,
+    730, 732, 715, 740, 740, 740, 740, 740, 740, 748, 749, 749, 749, 750, 757, 765, 765, 765, 765,
+    766, 774, 782, 789, 795, 803, 811, 819, 827, 835, 843, 843, 843, 848, 855, 835, 863, 871, 879,
+    887, 895, 903, 910, 913, 921, 929, 937, 945, 953, 959, 967, 975, 913, 983, 983, 911, 913, 913,
+    913, 913, 991, 995, 1003, 1009, 1016, 1023, 1031, 1037, 1045, 1047, 1055, 1062, 1069, 1077,
+    1085, 1092, 1100, 1106, 1114, 1121, 1114, 1126, 1133, 1136, 1144, 1144, 1144, 1144, 1146, 1152,
+    1159, 1161, 1169, 1175, 1177, 1177, 1177, 1182, 1188, 1196, 1204, 1204, 1206, 1214, 1218, 1222,
+    1230, 1231, 1239, 1247, 1251, 1258, 1266, 1274, 1282, 1282, 1282, 1282, 1282, 1282, 1290, 1296,
+    1303, 1310, 1290, 1318, 1323, 1327, 1335, 1342, 1350, 1357, 1365, 1369, 1369, 1369, 1369, 1376,
+    1383, 1389, 1397, 1404, 1412, 1420, 1428, 1434, 1442, 1369, 1450, 1455, 1459, 1462, 1462, 1469,
+    1476, 1482, 1490, 1497, 1504, 1512, 1520, 1526, 1534, 1541, 1549, 1554, 1561, 1564, 1564, 1571,
+    1578, 1586, 1594, 1601, 1608, 1615, 1622, 1628, 1636, 1643, 1651, 1656, 1660, 1663, 1663, 1661,
+    1670, 1676, 1684, 1691, 1699, 1700, 1708, 1714, 1722, 1729, 1737, 1742, 1746, 1749, 1749, 1756,
+    1763, 1769, 1777, 1785, 1791, 1799, 1806, 1812, 1820, 1828, 1836, 1841, 1848, 1855, 1863, 1841,
+    1871, 1877, 1885, 1892, 1900, 1908, 1910, 1916, 1924, 1931, 1939, 1944, 1948, 1950, 1950, 1948,
+    1950, 1956, 1963, 1971, 1977, 1985, 1993, 1999, 2007, 2015, 2023, 2028, 2032, 2034, 2034, 2032,
+    2029, 2040, 2048, 2055, 2061, 2068, 2076, 2082, 2090, 2093, 2101, 2105, 2109, 2111, 2111, 2111,
+    2111, 2116, 2123, 2130, 2138, 2146, 2153, 2159, 2167, 2174, 2182, 2187, 2188, 2195, 2187, 2187,
+    2201, 2205, 2188, 2211, 2219, 2227, 2235, 2241, 2249, 2254, 2262, 2263, 2263, 2263, 2263, 2264,
+    2272, 2280, 2288, 2296, 2304, 2310, 2314, 2314, 2314, 2314, 2322, 2328, 2332, 2332, 2336, 2343,
+    2351, 2359, 2367, 2375, 2383, 2389, 2397, 2397, 2397, 2397, 2405, 2413, 2419, 2427, 2435, 2441,
+    2445, 2453, 2461, 2469, 2472, 2470, 2480, 2487, 2494, 2500, 2508, 2516, 2521, 2529, 2532, 2530,
+    2540, 2547, 2555, 2562, 2570, 2577, 2580, 2580, 2580, 2580, 2588, 2588, 2588, 2588, 2595, 2602,
+    2609, 2617, 2625, 2631, 2639, 2647, 2655, 2662, 2669, 2588, 2675, 2682, 2690, 2696, 2704, 2704,
+    2704, 2704, 2706, 2714, 2722, 2722, 2722, 2722, 2722, 2727, 2735, 2735, 2735, 2735, 2735, 2735,
+    2735, 2735, 2735, 2735, 2735, 2736, 2744, 2745, 2745, 2745, 2745, 2745, 2745, 2745, 2745, 2753,
+    2753, 2753, 2753, 2753, 2753, 2753, 2753, 2753, 2753, 2753, 2761, 2761, 2761, 2761, 2761, 2761,
+    2761, 2761, 2761, 2768, 2762, 2768, 2761, 2761, 2761, 2761, 2761, 2768, 2761, 2761, 2761, 2761,
+    2768, 2762, 2768, 2761, 2762, 2761, 2761, 2761, 2761, 2761, 2761, 2761, 2768, 2761, 2761, 2761,
+    2761, 2761, 2761, 2761, 2761, 2771, 2779, 2786, 2787, 2790, 2798, 2798, 2806, 2812, 2820, 2820,
+    2820, 2820, 2820, 2820, 2820, 2820, 2820, 2820, 2822, 2830, 2838, 2839, 2839, 2839, 2839, 2839,
+    2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839,
+    2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839,
+    2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839,
+    2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839, 2839,
+    2839, 2839, 2839, 2839, 2839, 2839, 2839, 2842, 2839, 2839, 2850, 2851, 2851, 2856, 2864, 2864,
+    2864, 2864, 2864, 2864, 2864, 2864, 2864, 2869, 2876, 2883, 2891, 2894, 2900, 2905, 2913, 2913,
+    2919, 2926, 2934, 2934, 2940, 2944, 2952, 2955, 2962, 2966, 2974, 2974, 2974, 2974, 2979, 2974,
+    2984, 2991, 2998, 3006, 3014, 3022, 3030, 3036, 3044, 3050, 3058, 3066, 3074, 3080, 3088, 3088,
+    3088, 3088, 3093, 3097, 3097, 3097, 3097, 3097, 3097, 3104, 3112, 3097, 3097, 3097, 3097, 3119,
+    3127, 3127, 3127, 3127, 3127, 3127, 3127, 3127, 3129, 3135, 3143, 3144, 3144, 3145, 3153, 3160,
+    3168, 3175, 3183, 3189, 3197, 3197, 3197, 3199, 3200, 3205, 3213, 3213, 3213, 3213, 3213, 3217,
+    3213, 3213, 3213, 3225, 3233, 3239, 3247, 3247, 3247, 3247, 3255, 3255, 3256, 3264, 3272, 3272,
+    3272, 3272, 3272, 3272, 3275, 3283, 3291, 3298, 3304, 3310, 3318, 3324, 3318, 3324, 3332, 3340,
+    3348, 3353, 3360, 3361, 3361, 3361, 3361, 3361, 3361, 3361, 3369, 3375, 3382, 3385, 3385, 3385,
+    3389, 3397, 3405, 3410, 3418, 3424, 3431, 3436, 3441, 3445, 3453, 3456, 3456, 3456, 3463, 3471,
+    3479, 3485, 3493, 3493, 3493, 3493, 3495, 3503, 3511, 3515, 3523, 3523, 3523, 3523, 3527, 3534,
+    3539, 3547, 3555, 3561, 3569, 3575, 3577, 3577, 3577, 3585, 3593, 3600, 3608, 3608, 3608, 3608,
+    3608, 3613, 3621, 3629, 3637, 3643, 3651, 3658, 3664, 3672, 3680, 3680, 3680, 3680, 3682, 3688,
+    3696, 3692, 3696, 3698, 3696, 3705, 3711, 3717, 3680, 3725, 3733, 3733, 3733, 3738, 3741, 3741,
+    3741, 3742, 3750, 3756, 3764, 3765, 3766, 3773, 3776, 3783, 3791, 3791, 3791, 3791, 3791, 3791,
+    3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3793, 3799, 3791, 3791,
+    3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3791, 3807, 3815, 3823, 3831, 3839, 3815, 3823,
+    3815, 3823, 3831, 3839, 3815, 3846, 3815, 3823, 3815, 3831, 3815, 3854, 3815, 3854, 3815, 3854,
+    3862, 3870, 3877, 3885, 3893, 3901, 3815, 3909, 3917, 3925, 3933, 3941, 3949, 3957, 3965, 3973,
+    3981, 3989, 3997, 4004, 4010, 4018, 4026, 4034, 4042, 4048, 4056, 4062, 4070, 4073, 4081, 4089,
+    4091, 4094, 4102, 4102, 4110, 4118, 4125, 4133, 4141, 4142, 4150, 4158, 4166, 4174, 4182, 4190,
+    4198, 4205, 4213, 4219, 4227, 4233, 4241, 4245, 4241, 4247, 4255, 4262, 4270, 4276, 4284, 4292,
+    4300, 4302, 4306, 4309, 4317, 4322, 4328, 4334, 4338, 4342, 4350, 4358, 4364, 4370, 4378, 4385,
+    4392, 4400, 4407, 4415, 4421, 4427, 4435, 4443, 4451, 4455, 4463, 4471, 4479, 4487, 4493, 4501,
+    4509, 4517, 4427, 4525, 4533, 4509, 4541, 4547, 4555, 4563, 4571, 4579, 4585, 4591, 4599, 4607,
+    4610, 4616, 4616, 4616, 4616, 4616, 4616, 4616, 4616, 4621, 4571, 4629, 4632, 4638, 4641, 4641,
+    4645, 4571, 4571, 4653, 4652, 4661, 4667, 4674, 4682, 4690, 4698, 4698, 4698, 4698, 4699, 4706,
+    4706, 4706, 4714, 4719, 4722, 4722, 4730, 4730, 4734, 4738, 4738, 4746, 4746, 4750, 4754, 4754,
+    4756, 4762, 4768, 4762, 4762, 4762, 4762, 4774, 4777, 4778, 4786, 4786, 4786, 4786, 4786, 4786,
+    4786, 4786, 4786, 4790, 4786, 4786, 4786, 4786, 4789, 4794, 4802, 4802, 4810, 4816, 4824, 4830,
+    4836, 4844, 4842, 4852, 4859, 4863, 4869, 4876, 4863, 4884, 4892, 4899, 4907, 4915, 4923, 4927,
+    4935, 4943, 4951, 4959, 4963, 4936, 4971, 4979, 4935, 4987, 4995, 5001, 4957, 5009, 5017, 5022,
+    4933, 5027, 5034, 5042, 5050, 5058, 5065, 5073, 5081, 5088, 5096, 5104, 5112, 5119, 5123, 5124,
+    5129, 5134, 5138, 5146, 5153, 5161, 5168, 5176, 5178, 5184, 5184, 5184, 5188, 5134, 5123, 5134,
+    5124, 5125, 5196, 5204, 5210, 5217, 5224, 5230, 5238, 5238, 5246, 5246, 5246, 5246, 5246, 5246,
+    5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246,
+    5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5246, 5254, 5254, 5254, 5254, 5254, 5254,
+    5258, 5254, 5254, 5254, 5254, 5254, 5254, 5254, 5254, 5254, 5266, 5270, 5278, 5286, 5294, 5302,
+    5310, 5318, 5326, 5319, 5334, 5342, 5310, 5308, 5346, 5354, 5362, 5366, 5362, 5362, 5362, 5371,
+    5378, 5378, 5362, 5362, 5362, 5362, 5378, 5362, 5384, 5391, 5393, 5393, 5393, 5393, 5399, 5405,
+    5393, 5393, 5393, 5393, 5395, 5413, 5420, 5426, 5433, 5441, 5449, 5457, 5457, 5462, 5457, 5457,
+    5470, 5470, 5473, 5480, 5488, 5494, 5457, 5457, 5498, 5457, 5457, 5457, 5504, 5457, 5457, 5457,
+    5457, 5457, 5457, 5457, 5457, 5457, 5457, 5457, 5457, 5511, 5519, 5519, 5519, 5519, 5519, 5520,
+    5528, 5528, 5528, 5528, 5528, 5529, 5537, 5543, 5550, 5556, 5564, 5564, 5564, 5564, 5564, 5564,
+    5564, 5564, 5564, 5564, 5564, 5564, 5568, 5573, 5580, 5587, 5595, 5595, 5595, 5595, 5597, 5605,
+    5613, 5613, 5613, 5613, 5613, 5613, 5613, 5621, 5629, 5630, 5638, 5638, 5639, 5646, 5639, 5639,
+    5639, 5639, 5639, 5639, 5639, 5639, 5654, 5654, 5654, 5654, 5662, 5669, 5675, 5683, 5691, 5697,
+    5705, 5711, 5719, 5724, 5732, 5735, 5735, 5735, 5735, 5735, 5743, 5743, 5743, 5749, 5743, 5743,
+    5743, 5743, 5743, 5743, 5743, 5743, 5743, 5743, 5753, 5757, 5765, 5765, 5765, 5765, 5765, 5765,
+    5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765, 5765,
+    5765, 5765, 5765, 5765, 5767, 5773, 1282, 1282, 5781, 5785, 5793, 5801, 5807, 5811, 5819, 5825,
+    5833, 5841, 5849, 5855, 5859, 5859, 5865, 5871, 5879, 5881, 5887, 5895, 5902, 5909, 5917, 5923,
+    5927, 5927, 5933, 5939, 5947, 5949, 5955, 5963, 5970, 5977, 5985, 5990, 5990, 5990, 5990, 5990,
+    5998, 5999, 5999, 5999, 5999, 5999, 6003, 5999, 5999, 5999, 5999, 6008, 6016, 6022, 6030, 6030,
+    6030, 6030, 6038, 6038, 6038, 6038, 6042, 6046, 6054, 6054, 6062, 6062, 6062, 6065, 6073, 6079,
+    6081, 6081, 6085, 6093, 6101, 6102, 6110, 6110, 6110, 6114, 6122, 6128, 6130, 6136, 6138, 6138,
+    6145, 6102, 6081, 6153, 6161, 6161, 6161, 6161, 6161, 6162, 6170, 6170, 6170, 6170, 6170, 6170,
+    6170, 6170, 6170, 6170, 6170, 6178, 6178, 6178, 6185, 6192, 6195, 6195, 6195, 6195, 6195, 6195,
+    6195, 6195, 6195, 6195, 6195, 6186, 6178, 6178, 6178, 6203, 6211, 6211, 6211, 6211, 6211, 6211,
+    6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211,
+    6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211,
+    6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211, 6211,
+    6211, 6211, 6219, 6219, 6219, 6219, 6219, 6219, 6219, 6219, 6227, 6227, 6227, 6227, 6227, 6227,
+    6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227,
+    6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227,
+    6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227,
+    6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6227, 6230, 6238, 6238, 6241, 6238, 6238, 6238,
+    6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238,
+    6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238,
+    6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238,
+    6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238,
+    6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6238, 6247, 6255, 6255, 6255, 6255,
+    6255, 6255, 6256, 6263, 6271, 6271, 6271, 6271, 6271, 6279, 6287, 6287, 6287, 6287, 6287, 6287,
+    6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287,
+    6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6287, 6291, 6287, 6287, 6299, 6305,
+    6309, 6309, 6317, 6317, 6317, 6317, 6317, 6319, 6327, 6331, 6317, 6317, 6317, 6339, 6347, 6347,
+    6347, 6347, 6347, 6347, 6347, 6347, 6349, 6355, 6363, 6371, 6379, 6387, 6388, 6395, 6403, 6405,
+    6412, 6405, 6405, 6405, 6405, 6405, 6405, 6405, 6420, 6427, 6405, 6435, 6443, 6405, 6405, 6449,
+    6451, 6405, 6459, 6467, 6470, 6470, 6470, 6470, 6473, 6481, 6489, 6496, 6500, 6500, 6505, 6513,
+    6521, 6529, 6537, 6537, 6537, 6537, 6537, 6537, 6543, 6551, 6559, 6561, 6561, 6561, 6561, 6561,
+    6565, 6570, 6574, 6580, 6588, 6594, 6602, 6602, 6608, 6616, 6624, 6630, 6632, 6632, 6634, 6640,
+    6648, 6648, 6649, 6656, 6663, 6667, 6675, 6675, 6675, 6678, 6686, 6690, 6690, 6690, 6690, 6690,
+    6695, 6703, 6711, 6718, 6726, 6732, 6740, 6747, 6755, 6761, 6769, 6769, 6769, 6769, 6769, 6776,
+    6783, 6790, 6798, 6802, 6810, 6816, 6824, 6824, 6832, 6839, 6847, 6847, 6847, 6847, 6847, 6847,
+    6855, 6862, 6870, 6873, 6873, 6878, 6886, 6891, 6899, 6906, 6914, 6914, 6914, 6921, 6929, 6929,
+    6937, 6937, 6937, 6937, 6937, 6942, 6950, 6957, 6965, 6965, 6965, 6965, 6965, 6965, 6965, 6965,
+    6965, 6965, 6973, 6973, 6973, 6973, 6978, 6986, 6994, 7000, 7008, 7009, 7009, 7013, 7009, 7009,
+    7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008,
+    7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009,
+    7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009,
+    7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009,
+    7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009,
+    7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013,
+    7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009,
+    7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008,
+    7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009,
+    7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009,
+    7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009,
+    7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009,
+    7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013,
+    7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7009, 7009, 7009, 7008, 7009, 7009,
+    7013, 7009, 7009, 7009, 7008, 7009, 7009, 7013, 7009, 7009, 7009, 7008, 7009, 7009, 7018, 7022,
+    7030, 7030, 7031, 7038, 7041, 7041, 7041, 7041, 7041, 7045, 7053, 7053, 7053, 7053, 7053, 7053,
+    7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053,
+    7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053,
+    7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7053, 7061, 7061, 7061, 7061, 7061, 7061,
+    7061, 7061, 7061, 7061, 7061, 7061, 7061, 7061, 7061, 7061, 7069, 7069, 7069, 7069, 7069, 7069,
+    7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069,
+    7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7069, 7077, 7077, 7077, 7077, 7077, 7077,
+    7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077,
+    7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7077, 7085, 7085, 7085, 7085, 7085, 7085,
+    7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085,
+    7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7087, 7095, 7086, 7102, 7109,
+    7085, 7085, 7085, 7085, 7085, 7085, 7085, 7111, 7085, 7085, 7085, 7085, 7085, 7085, 7085, 7085,
+    7085, 7085, 7085, 7085, 7085, 7115, 7117, 7117, 7117, 7117, 7125, 7132, 7137, 7145, 7153, 7160,
+    7162, 7164, 7172, 7178, 7186, 7190, 7190, 7190, 7190, 7190, 7194, 7196, 7190, 7190, 7192, 7190,
+    7202, 7204, 7210, 7212, 7217, 7225, 7231, 7194, 7196, 7239, 7247, 7247, 7247, 7247, 7247, 7247,
+    7247, 7247, 7247, 7247, 7247, 7247, 7251, 7255, 7255, 7255, 7255, 7255, 7256, 7263, 7263, 7263,
+    7263, 7263, 7263, 7263, 7263, 7264, 7271, 7271, 7274, 7247, 7247, 7247, 7254, 7255, 7255, 7258,
+    7267, 7282, 7212, 7212, 7290, 7298, 7306, 7311, 7319, 7327, 7329, 7337, 7345, 7350, 7255, 7255,
+    7356, 7364, 7369, 7212, 7377, 7377, 7377, 7377, 7247, 7385, 7393, 7393, 7401, 7409, 7417, 7424,
+    7432, 7438, 7442, 7450, 7458, 7466, 7474, 7482, 7490, 7496, 7504, 7510, 7512, 7520, 7520, 7528,
+    7516, 7520, 7520, 7520, 7520, 7520, 7520, 7524, 7510, 7532, 7540, 7548, 7556, 7562, 7570, 7571,
+    7571, 7576, 7584, 7585, 7585, 7590, 7598, 7605, 7613, 7614, 7614, 7614, 7614, 7616, 7624, 7625,
+    7625, 7626, 7633, 7633, 7633, 7641, 7649, 7657, 7665, 7672, 7680, 7684, 7680, 7680, 7681, 7680,
+    7680, 7689, 7680, 7696, 7680, 7696, 7702, 7702, 7702, 7702, 7710, 7710, 7710, 7710, 7710, 7710,
+    7710, 7710, 7710, 7710, 7710, 7710, 7710, 7710, 7710, 7715, 7723, 7730, 7730, 7730, 7730, 7730,
+    7734, 7741, 7749, 7749, 7749, 7749, 7749, 7749, 7752, 7759, 7760, 7766, 7774, 7777, 7785, 7786,
+    7786, 7786, 7786, 7786, 7794, 7794, 7794, 7794, 7794, 7797, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 7805, 7805, 7805, 7808, 7816, 7816,
+    7816, 7816, 7816, 7816, 7823, 7824, 7832, 7833, 7833, 7837, 7845, 7845, 7845, 7845, 7853, 7857,
+    7865, 7865, 7872, 7878, 7886, 7886, 7886, 7886, 7888, 7894, 7902, 7902, 7902, 7904, 7912, 7912,
+    7912, 7912, 7916, 7912, 7924, 7930, 1282, 1282, 1282, 1282, 7938, 7938, 7938, 7938, 7938, 7946,
+    7946, 7946, 7946, 7946, 7954, 7954, 7954, 7954, 7954, 7954, 7962, 7962, 7962, 7964, 7972, 7978,
+    7986, 7986, 7986, 7986, 7990, 7998, 7998, 7998, 7998, 8002, 8010, 8010, 8010, 8010, 8010, 8018,
+    8026, 8026, 8026, 8026, 8026, 8026, 8030, 8034, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 8042, 8042, 8042, 8042, 8042, 8042,
+    8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042,
+    8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042, 8042,
+    8043, 8050, 8042, 8042, 8044, 8050, 8042, 8050, 8050, 8050, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 8058, 8066, 8068, 8068, 8068, 8068,
+    8070, 8077, 8085, 8085, 8087, 8095, 8103, 8103, 8104, 8111, 8119, 8119, 8119, 8120, 8127, 8134,
+    1282, 1282, 1282, 1282, 1282, 1282, 8142, 8142, 8147, 8153, 8161, 8161, 8163, 8169, 8177, 8177,
+    8177, 8183, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 8191, 8191, 8191, 8191, 8199, 8199,
+    8199, 8207, 8215, 8215, 8223, 8215, 8215, 8215, 8215, 8215, 8231, 8238, 8246, 8250, 8251, 8251,
+    8253, 8261, 8269, 8276, 8284, 8292, 8300, 8300, 8300, 8303, 8311, 8311, 8311, 8314, 1282, 1282,
+    1282, 1282, 8322, 8330, 8336, 8344, 8351, 8358, 8366, 8373, 8381, 8381, 8381, 8381, 8381, 8381,
+    8383, 8390, 8398, 8398, 8400, 8408, 8416, 8416, 8421, 8429, 8437, 8444, 8452, 8459, 8464, 8471,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 8479, 8479, 8479, 8479, 8479, 8479,
+    8479, 8479, 8479, 8486, 1282, 1282, 1282, 1282, 1282, 1282, 8494, 8494, 8494, 8494, 8494, 8494,
+    8499, 8502, 8510, 8510, 8510, 8510, 8510, 8510, 8515, 8521, 8529, 8530, 8530, 8530, 8536, 8544,
+    8552, 8558, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 8566, 8566, 8566, 8567, 8575, 8575, 8575, 8575, 8575, 8581,
+    8589, 8591, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 8599, 8599, 8599, 8602, 8607, 8615,
+    8623, 8627, 8630, 8638, 8645, 8651, 8653, 8653, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    8661, 8669, 8677, 8684, 8688, 8688, 8696, 8696, 8697, 8704, 8712, 8715, 8715, 8715, 8715, 8715,
+    8715, 8723, 8729, 8736, 8742, 8744, 8746, 8752, 8760, 8761, 8769, 8772, 8772, 8778, 8772, 8783,
+    8791, 8798, 8804, 8807, 8815, 8815, 8815, 8822, 8830, 8836, 8844, 8847, 8847, 8847, 8848, 8855,
+    8863, 8869, 8877, 8885, 8893, 8893, 8893, 8893, 8898, 8905, 8913, 8916, 8916, 8916, 8916, 8916,
+    8921, 8927, 8935, 8943, 8951, 8957, 8965, 8966, 8969, 8974, 8982, 8982, 8988, 8982, 8982, 8992,
+    9000, 9008, 9015, 9015, 1282, 1282, 1282, 1282, 1282, 1282, 9023, 9029, 9036, 9024, 9036, 9043,
+    9051, 9051, 9051, 9051, 9051, 9052, 9060, 9067, 9075, 9081, 9089, 9094, 9098, 9101, 9101, 9108,
+    9115, 9121, 9129, 9136, 9144, 9152, 9158, 9164, 9164, 9169, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9177, 9177, 9177, 9177, 9177, 9177,
+    9180, 9188, 9196, 9204, 9212, 9218, 9226, 9228, 9228, 9228, 9236, 9237, 9237, 9237, 9237, 9237,
+    9245, 9252, 9259, 9267, 9275, 9281, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9289, 9289, 9289, 9289, 9289, 9290,
+    9298, 9306, 9314, 9321, 9322, 9330, 9336, 9336, 9336, 9336, 9344, 9344, 9344, 9344, 9344, 9344,
+    9352, 9359, 9367, 9372, 9380, 9386, 9394, 9397, 9402, 9402, 9410, 9410, 9410, 9410, 9410, 9415,
+    9423, 9431, 9439, 9445, 1282, 1282, 1282, 1282, 1282, 1282, 9453, 9453, 9453, 9458, 9466, 9474,
+    9482, 9488, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9496, 9496, 9496, 9496, 9496, 9500,
+    9507, 9515, 9519, 9519, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9527, 9527,
+    9527, 9527, 9535, 9535, 9535, 9535, 9543, 9549, 9554, 9557, 9565, 9572, 9576, 9584, 9584, 9584,
+    9592, 9600, 9608, 9615, 9623, 9629, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9637, 9645,
+    9637, 9637, 9637, 9637, 9652, 9660, 9668, 9673, 9673, 9673, 9681, 9688, 9691, 9691, 9691, 9691,
+    9696, 9703, 9711, 9719, 9727, 9734, 9738, 9738, 9738, 9738, 9742, 9748, 9750, 9758, 9765, 9768,
+    1282, 1282, 9776, 9776, 9776, 9776, 9776, 9776, 9776, 9783, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9791, 9798, 9791, 9791, 9791, 9800,
+    9808, 9816, 9824, 9830, 9838, 9844, 9846, 9849, 9857, 9859, 9859, 9859, 9867, 9869, 9869, 9877,
+    9885, 9892, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 9900, 9905, 9908, 9908, 9908, 9908,
+    9915, 9922, 9930, 9938, 9946, 9952, 9960, 9965, 9967, 9967, 9967, 9973, 9981, 9989, 9997,
+    10003, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10011, 10011, 10017, 10024, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 10032, 10033, 10041, 10041, 10044, 10049, 10056, 10057, 10063, 10065, 10073,
+    10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073,
+    10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073,
+    10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073,
+    10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10073, 10079, 10081,
+    10081, 10081, 10081, 10081, 10081, 10081, 10081, 10081, 10081, 10081, 10081, 10089, 10089,
+    10089, 10089, 10089, 10089, 10089, 10089, 10089, 10089, 10089, 10089, 10089, 10090, 10098,
+    10103, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111,
+    10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10111, 10115,
+    10119, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10135, 10127,
+    10127, 10127, 10127, 10141, 10147, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10154, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127, 10127,
+    10127, 10127, 10127, 10127, 10158, 10166, 10174, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182,
+    10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182,
+    10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182,
+    10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182,
+    10182, 10182, 10182, 10182, 10182, 10182, 10184, 10182, 10182, 10182, 10182, 10182, 10182,
+    10182, 10182, 10182, 10182, 10182, 10182, 10182, 10182, 10192, 10199, 10199, 10199, 10199,
+    10199, 10199, 10199, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207,
+    10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207,
+    10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207, 10207,
+    10207, 10207, 10207, 10214, 10222, 10222, 10222, 10223, 10231, 10237, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10245, 10245, 10245, 10247, 10255, 10261,
+    10269, 10269, 10269, 10269, 10269, 10269, 10277, 10284, 10292, 10298, 10306, 10312, 10318,
+    10321, 10321, 10329, 10321, 10321, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10337, 10337, 10337,
+    10337, 10345, 10345, 10345, 10345, 10353, 10353, 10354, 10361, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 10369, 10369, 10369, 10369, 10369, 10369, 10369,
+    10369, 10372, 10377, 10385, 10386, 10386, 10386, 10386, 10386, 10386, 10394, 10401, 10404,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10412, 10417, 10425, 10417, 10433, 10433,
+    10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433,
+    10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433,
+    10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433,
+    10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433,
+    10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10433, 10441, 10449, 10449, 10449,
+    10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449,
+    10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449, 10449,
+    10449, 10449, 10449, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457,
+    10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457,
+    10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457,
+    10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457,
+    10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10457, 10459, 10465, 10465, 10465,
+    10465, 10465, 10473, 10480, 10481, 10481, 10481, 10481, 10481, 10481, 10481, 10481, 10481,
+    10481, 10481, 10481, 10481, 10481, 10481, 10481, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 10489, 10490, 10490, 10490, 10490, 10490, 10490,
+    10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490,
+    10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10490, 10498,
+    10498, 10498, 10499, 10506, 10506, 10514, 10514, 10514, 10514, 10522, 10514, 10526, 10514,
+    10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534,
+    10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534,
+    10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534,
+    10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10534, 10538, 10546, 10546,
+    10546, 10546, 10546, 10546, 10546, 10546, 10546, 10546, 10546, 10546, 10546, 10551, 10546,
+    10549, 10546, 10553, 10546, 10561, 10569, 10573, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581,
+    10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581, 10581,
+    10581, 10581, 10581, 10581, 10581, 10581, 10583, 10589, 10597, 10597, 10597, 10597, 10598,
+    10605, 10597, 10597, 10597, 10597, 10597, 10607, 10613, 10620, 10626, 10631, 10636, 10642,
+    10597, 10597, 10597, 10648, 10597, 10610, 10656, 10597, 10597, 10597, 10597, 10663, 10664,
+    10664, 10672, 10672, 10672, 10672, 10672, 10672, 10672, 10672, 10678, 10684, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    10692, 10692, 10696, 10700, 10708, 10708, 10708, 10708, 10708, 10708, 10708, 10708, 10708,
+    10708, 10709, 10716, 10724, 10724, 10724, 10731, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 10739, 10739, 10739, 10745, 10747, 10747,
+    10751, 10739, 10739, 10741, 10759, 10747, 10747, 10739, 10739, 10739, 10745, 10747, 10747,
+    10765, 10773, 10780, 10741, 10788, 10760, 10747, 10739, 10739, 10739, 10745, 10747, 10747,
+    10793, 10800, 10805, 10811, 10747, 10747, 10747, 10778, 10819, 10826, 10833, 10747, 10747,
+    10751, 10739, 10739, 10741, 10747, 10747, 10747, 10739, 10739, 10739, 10745, 10747, 10747,
+    10751, 10739, 10739, 10741, 10747, 10747, 10747, 10739, 10739, 10739, 10745, 10747, 10747,
+    10751, 10739, 10739, 10741, 10747, 10747, 10747, 10739, 10739, 10739, 10745, 10747, 10747,
+    10835, 10739, 10739, 10739, 10843, 10747, 10747, 10848, 10854, 10739, 10739, 10859, 10747,
+    10747, 10846, 10751, 10739, 10739, 10857, 10747, 10747, 10863, 10749, 10739, 10739, 10871,
+    10747, 10747, 10747, 10879, 10739, 10739, 10739, 10843, 10747, 10747, 10848, 10885, 10891,
+    10891, 10891, 10891, 10891, 10891, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899,
+    10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899,
+    10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10899, 10907, 10907,
+    10907, 10907, 10907, 10907, 10908, 10904, 10907, 10907, 10907, 10907, 10907, 10910, 10915,
+    10899, 10916, 10923, 10927, 10932, 10934, 10907, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 10942, 10950, 10950, 10957, 10963, 10969, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 10977, 10977, 10977, 10977, 10977, 10980, 10988, 10995, 11003, 11009,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 11017,
+    11017, 11017, 11017, 11017, 11021, 11029, 11035, 11043, 11043, 11043, 11043, 11043, 11043,
+    11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043, 11043,
+    11043, 11043, 11043, 11043, 11043, 11046, 11053, 11061, 11068, 1282, 1282, 1282, 1282, 11076,
+    11076, 11076, 11076, 11082, 11084, 11084, 11084, 11088, 11096, 11104, 11110, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    11118, 11119, 11119, 11119, 11119, 11119, 11119, 11123, 11131, 11136, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 11144, 11145, 11145, 11145, 11145, 11147, 11145, 11154, 11160, 11160,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 11168, 11173, 11173, 11173, 11181, 11172, 11169, 11187,
+    11193, 11199, 11181, 11207, 11181, 11169, 11169, 11213, 11173, 11170, 11173, 11221, 11228,
+    11170, 11173, 11221, 11236, 11236, 11236, 11236, 11236, 11236, 11244, 11236, 11252, 11257,
+    11257, 11257, 11257, 11261, 11269, 11269, 11269, 11269, 11269, 11269, 11269, 11269, 11269,
+    11269, 11269, 11269, 11273, 11277, 11285, 11286, 11293, 11285, 11293, 11294, 11293, 11285,
+    11285, 11285, 11302, 11308, 11316, 11321, 11329, 11329, 11329, 11334, 11342, 11342, 11342,
+    11348, 11356, 11356, 11356, 11362, 11370, 11372, 11356, 11380, 11388, 11393, 11396, 11399,
+    11405, 11405, 11405, 11405, 11405, 11405, 11407, 11413, 11413, 11413, 11421, 11424, 11432,
+    11438, 11432, 11433, 11444, 11449, 11457, 11464, 11472, 11424, 11480, 11424, 11424, 11424,
+    11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424, 11424,
+    11424, 11424, 11424, 11488, 11488, 11488, 11488, 11494, 11488, 11488, 11488, 11488, 11488,
+    11488, 11488, 11488, 11488, 11488, 11488, 11498, 11488, 11492, 11506, 11488, 11488, 11512,
+    11519, 11525, 11530, 11488, 11488, 11488, 11488, 11537, 11544, 11488, 11488, 11488, 11488,
+    11488, 11488, 11488, 11488, 11552, 11558, 11565, 11488, 11567, 11558, 11558, 11574, 11581,
+    11566, 11589, 11488, 11597, 11603, 11609, 11488, 11488, 11488, 11488, 11488, 11488, 11488,
+    11488, 11612, 11620, 11488, 11627, 11634, 11622, 11488, 11632, 11636, 11642, 11649, 11488,
+    11488, 11488, 11656, 11663, 11669, 11656, 11675, 11683, 11690, 11694, 11655, 11697, 11701,
+    11707, 11690, 11714, 11718, 11725, 11728, 11732, 11738, 11746, 11746, 11746, 11746, 11746,
+    11746, 11746, 11746, 11749, 11751, 11759, 11759, 11759, 11759, 11761, 11768, 11776, 11776,
+    11776, 11776, 11781, 11776, 11785, 11776, 11791, 11797, 11802, 11810, 11818, 11825, 11833,
+    11836, 11844, 11844, 11844, 11844, 11844, 11844, 11844, 11844, 11844, 11844, 11844, 11844,
+    11844, 11844, 11848, 11852, 11860, 11860, 11860, 11860, 11860, 11860, 11860, 11860, 11860,
+    11860, 11863, 11870, 11878, 11882, 11886, 11886, 11894, 11898, 11894, 11894, 11894, 11894,
+    11894, 11894, 11894, 11902, 11894, 11900, 11894, 11894, 11894, 11894, 11894, 11902, 11894,
+    11894, 11894, 11896, 11910, 11902, 11902, 11902, 11902, 11902, 11902, 11902, 11902, 11902,
+    11918, 11922, 11930, 11938, 11946, 11930, 11938, 11954, 11961, 11930, 11930, 11930, 11930,
+    11930, 11931, 11968, 11930, 11930, 11930, 11930, 11930, 11930, 11971, 11976, 11930, 11980,
+    11937, 11940, 11930, 11930, 11930, 11930, 11988, 11988, 11988, 11988, 11988, 11988, 11988,
+    11988, 11988, 11988, 11992, 11996, 12004, 12006, 12014, 12016, 12024, 12031, 12039, 12039,
+    12039, 12030, 12024, 12031, 12016, 12031, 12024, 12031, 12031, 12031, 12031, 12031, 12047,
+    12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047, 12047,
+    12047, 12047, 12047, 12047, 12052, 12047, 12047, 12047, 12047, 12047, 12047, 12057, 12060,
+    12060, 12060, 12060, 12068, 12074, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082,
+    12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082,
+    12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082,
+    12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082,
+    12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082, 12082,
+    12082, 12082, 12082, 12084, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092,
+    12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092,
+    12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092,
+    12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092,
+    12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12092, 12094, 12102,
+    12102, 12102, 12102, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110,
+    12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110,
+    12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110, 12110,
+    12110, 12110, 12113, 12118, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126,
+    12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126, 12126,
+    12126, 12126, 12126, 12126, 12126, 12128, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136,
+    12136, 12136, 12136, 12136, 12136, 12136, 12136, 12136, 12142, 12144, 12152, 12152, 12152,
+    12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152,
+    12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152,
+    12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152,
+    12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152,
+    12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152, 12152,
+    12152, 12152, 12159, 12160, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12168,
+    12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168,
+    12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168,
+    12168, 12168, 12168, 12168, 12168, 12168, 12168, 12168, 12170, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12178, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186,
+    12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186,
+    12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186, 12186,
+    12186, 12186, 12186, 12186, 12186, 12186, 12191, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102, 12102,
+    12102, 12102, 12102, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282, 1282,
+    1282, 1282, 1282, 12199, 12207, 12209, 12209, 12209, 12217, 12217, 12217, 12217, 12217, 12217,
+    12217, 12217, 12217, 12217, 12217, 12217, 12225, 12225, 12225, 12225, 12225, 12225, 12225,
+    12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12233, 12233, 12233, 12233,
+    12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233,
+    12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233, 12233,
+    12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225,
+    12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225,
+    12225, 12225, 12225, 12225, 12225, 12225, 12225, 12225, 12241, 12241, 12241, 12241, 12241,
+    12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241,
+    12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241,
+    12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241,
+    12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241, 12241,
+    12241, 12241, 12241, 12241, 12241, 12241, 12243, 12251, 12251, 12251, 12251, 12251, 12251,
+    12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251,
+    12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251,
+    12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251,
+    12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251, 12251,
+    12251, 12251, 12251, 12251, 12251, 12253,
 ];
 
 #[rustfmt::skip]
-const RECORD_INDEX2: [u16; 14744] = [
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 0, 0, 0, 0, 0, 0, 6, 6, 6, 7, 8, 9, 10, 11, 12, 13,
-    14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 24, 24, 24, 24, 24, 24, 24, 24, 25, 26, 27, 27,
-    27, 9, 14, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 16, 29, 17,
-    30, 31, 30, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 16, 33, 34,
-    27, 0, 35, 35, 35, 35, 35, 36, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 37, 38, 39, 40, 40, 40,
-    41, 42, 43, 44, 45, 46, 47, 48, 44, 49, 50, 51, 52, 52, 53, 54, 42, 55, 43, 56, 45, 57, 58, 58,
-    58, 38, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 59, 60, 59, 59, 59, 59, 59, 59,
-    59, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 61, 62, 63, 64, 63, 64, 63, 64,
-    63, 64, 64, 63, 64, 63, 64, 63, 64, 63, 64, 64, 63, 64, 63, 64, 63, 64, 63, 63, 64, 63, 64, 63,
-    64, 64, 65, 66, 66, 65, 66, 65, 66, 66, 65, 66, 66, 66, 65, 65, 66, 66, 66, 66, 65, 66, 66, 65,
-    66, 66, 66, 65, 65, 65, 66, 66, 65, 66, 66, 65, 66, 65, 66, 65, 66, 66, 65, 66, 65, 65, 66, 65,
-    66, 66, 65, 66, 66, 66, 65, 66, 65, 66, 66, 65, 65, 67, 66, 65, 65, 65, 67, 67, 67, 67, 66, 68,
-    65, 66, 68, 65, 66, 68, 65, 66, 65, 66, 65, 66, 65, 66, 65, 66, 65, 66, 65, 66, 65, 66, 65, 65,
-    66, 65, 66, 65, 66, 65, 66, 65, 66, 65, 65, 66, 68, 65, 66, 65, 66, 66, 66, 65, 66, 65, 65, 65,
-    65, 65, 65, 65, 66, 66, 65, 66, 66, 65, 65, 66, 65, 66, 66, 66, 66, 65, 69, 69, 69, 69, 69, 69,
-    69, 69, 69, 69, 69, 69, 70, 69, 69, 69, 71, 71, 71, 71, 71, 71, 71, 71, 71, 72, 72, 73, 73, 73,
-    73, 73, 73, 73, 74, 74, 74, 74, 72, 75, 76, 75, 75, 75, 76, 75, 72, 72, 77, 73, 74, 74, 74, 74,
-    74, 74, 78, 78, 78, 78, 79, 78, 74, 80, 71, 71, 71, 71, 71, 74, 74, 74, 74, 74, 81, 81, 72, 74,
-    73, 74, 74, 74, 74, 74, 74, 74, 74, 74, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 82, 83,
-    84, 84, 84, 84, 83, 85, 84, 84, 84, 84, 84, 86, 86, 84, 84, 84, 84, 86, 86, 84, 84, 84, 84, 84,
-    84, 84, 84, 84, 84, 84, 87, 87, 87, 87, 87, 84, 84, 84, 84, 82, 82, 82, 82, 82, 82, 82, 82, 88,
-    82, 84, 84, 84, 82, 82, 82, 84, 84, 89, 82, 82, 82, 84, 84, 84, 84, 82, 83, 84, 84, 82, 90, 91,
-    91, 90, 91, 91, 90, 82, 82, 82, 82, 82, 92, 93, 92, 93, 94, 95, 92, 93, 96, 96, 97, 93, 93, 93,
-    98, 92, 96, 96, 96, 96, 95, 99, 92, 100, 92, 92, 92, 96, 92, 96, 92, 92, 93, 92, 92, 92, 92,
-    92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 92, 96, 92, 92, 92, 92, 92, 92, 92, 92, 92, 93,
-    93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 93, 92, 93, 93, 92, 92, 92,
-    93, 93, 93, 92, 93, 92, 93, 92, 93, 92, 93, 92, 93, 101, 102, 101, 102, 101, 102, 101, 102,
-    101, 102, 101, 102, 101, 102, 93, 93, 93, 93, 92, 93, 103, 92, 93, 92, 92, 93, 93, 92, 92, 92,
-    104, 104, 104, 104, 104, 104, 104, 104, 105, 105, 105, 105, 105, 105, 105, 105, 104, 105, 104,
-    105, 104, 105, 104, 105, 104, 105, 106, 107, 107, 108, 108, 107, 109, 109, 104, 105, 104, 105,
-    104, 105, 104, 104, 105, 104, 105, 104, 105, 104, 105, 104, 105, 104, 105, 104, 105, 105, 110,
-    111, 110, 111, 110, 111, 110, 111, 112, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113,
-    113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 113, 112, 112, 114, 115, 115, 115, 116, 115,
-    117, 118, 118, 118, 118, 118, 118, 118, 118, 118, 119, 120, 112, 112, 121, 121, 122, 123, 124,
-    125, 125, 125, 125, 124, 125, 125, 125, 126, 124, 125, 125, 125, 125, 125, 125, 124, 124, 124,
-    124, 124, 124, 125, 125, 124, 125, 125, 126, 127, 125, 128, 129, 130, 131, 132, 133, 134, 135,
-    136, 137, 137, 138, 139, 140, 141, 142, 143, 144, 145, 143, 125, 124, 146, 136, 123, 123, 123,
-    123, 123, 123, 123, 123, 147, 147, 147, 147, 147, 147, 147, 147, 147, 147, 147, 123, 123, 123,
-    123, 147, 147, 147, 147, 148, 149, 123, 123, 123, 150, 150, 150, 150, 150, 151, 152, 152, 153,
-    154, 154, 155, 156, 157, 158, 158, 159, 159, 159, 159, 159, 159, 159, 159, 160, 161, 162, 163,
-    164, 165, 166, 163, 167, 168, 169, 169, 169, 169, 167, 169, 167, 169, 167, 167, 167, 167, 167,
-    169, 169, 169, 169, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 167, 170, 167,
-    167, 167, 167, 167, 167, 167, 169, 167, 167, 171, 172, 173, 174, 175, 176, 177, 178, 179, 179,
-    180, 181, 159, 159, 159, 159, 159, 181, 159, 159, 181, 182, 182, 182, 182, 182, 182, 182, 182,
-    182, 182, 154, 183, 184, 185, 167, 167, 186, 169, 169, 169, 168, 169, 169, 169, 169, 169, 169,
-    169, 169, 169, 169, 169, 169, 169, 167, 167, 167, 167, 167, 167, 169, 167, 167, 169, 169, 169,
-    169, 169, 169, 169, 169, 169, 167, 169, 167, 169, 167, 167, 169, 169, 166, 169, 159, 159, 159,
-    159, 159, 159, 159, 151, 158, 159, 159, 159, 159, 181, 159, 187, 187, 159, 159, 158, 181, 159,
-    159, 181, 169, 169, 188, 188, 188, 188, 188, 188, 188, 188, 188, 188, 167, 167, 167, 189, 189,
-    167, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 190, 191, 192, 193, 194,
-    195, 195, 195, 196, 196, 197, 197, 197, 195, 195, 195, 195, 197, 195, 195, 195, 195, 195, 195,
-    195, 195, 195, 197, 195, 196, 195, 197, 195, 195, 196, 198, 199, 198, 198, 199, 198, 198, 199,
-    199, 199, 198, 199, 199, 198, 199, 198, 198, 198, 199, 198, 199, 198, 199, 198, 199, 198, 198,
-    191, 191, 197, 195, 195, 200, 200, 200, 200, 200, 200, 200, 200, 200, 201, 201, 201, 200, 200,
-    200, 200, 200, 200, 200, 201, 201, 200, 200, 200, 200, 201, 200, 201, 201, 200, 200, 200, 201,
-    201, 200, 200, 200, 200, 200, 200, 202, 202, 202, 202, 202, 202, 202, 202, 202, 202, 202, 202,
-    202, 202, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 203, 202, 204, 204, 204, 204, 204,
-    204, 204, 204, 204, 204, 204, 204, 204, 204, 205, 205, 205, 205, 205, 205, 205, 205, 205, 205,
-    206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 206, 207, 207,
-    207, 207, 207, 207, 207, 208, 207, 209, 209, 210, 211, 212, 213, 214, 215, 215, 216, 217, 217,
-    218, 218, 218, 218, 218, 218, 218, 218, 218, 218, 218, 218, 218, 218, 219, 219, 219, 219, 220,
-    219, 219, 219, 219, 219, 219, 219, 219, 219, 220, 219, 219, 219, 220, 219, 219, 219, 219, 219,
-    221, 221, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 222, 221, 223,
-    224, 224, 224, 224, 224, 223, 223, 224, 223, 224, 224, 224, 224, 224, 224, 224, 224, 224, 224,
-    223, 224, 223, 223, 223, 225, 225, 225, 226, 226, 227, 226, 228, 229, 228, 228, 228, 228, 229,
-    230, 228, 230, 230, 231, 231, 231, 231, 231, 232, 232, 232, 232, 232, 232, 232, 232, 233, 233,
-    233, 233, 233, 233, 233, 233, 233, 233, 234, 234, 234, 235, 234, 233, 233, 234, 234, 233, 233,
-    236, 233, 233, 233, 234, 233, 233, 233, 233, 233, 233, 236, 236, 236, 236, 236, 236, 236, 236,
-    236, 236, 236, 237, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 238, 239,
-    237, 238, 238, 237, 238, 238, 237, 238, 238, 238, 237, 237, 237, 240, 241, 242, 238, 238, 238,
-    237, 238, 238, 237, 237, 238, 238, 238, 238, 238, 243, 243, 243, 244, 245, 245, 245, 245, 245,
-    245, 245, 245, 245, 245, 245, 245, 245, 245, 246, 247, 248, 245, 247, 249, 247, 250, 250, 250,
-    250, 246, 246, 246, 246, 247, 247, 247, 247, 251, 249, 247, 252, 253, 254, 255, 255, 246, 250,
-    250, 245, 245, 250, 250, 256, 256, 257, 257, 257, 257, 257, 257, 257, 257, 257, 257, 258, 259,
-    245, 245, 245, 245, 245, 245, 260, 261, 262, 262, 263, 264, 264, 264, 264, 264, 264, 264, 264,
-    263, 263, 264, 264, 263, 263, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264, 264,
-    264, 263, 264, 264, 264, 264, 264, 264, 264, 263, 264, 263, 263, 263, 264, 264, 264, 264, 263,
-    263, 265, 264, 266, 267, 268, 269, 269, 269, 269, 263, 263, 267, 267, 263, 263, 270, 270, 271,
-    272, 263, 263, 263, 263, 263, 263, 263, 263, 266, 263, 263, 263, 263, 264, 264, 263, 264, 264,
-    264, 269, 269, 263, 263, 273, 273, 273, 273, 273, 273, 273, 273, 273, 273, 264, 264, 274, 274,
-    275, 275, 275, 275, 275, 276, 277, 278, 264, 279, 280, 263, 281, 282, 282, 283, 281, 284, 284,
-    284, 284, 284, 284, 281, 281, 281, 281, 284, 284, 281, 281, 284, 284, 284, 284, 284, 284, 284,
-    284, 284, 284, 284, 284, 284, 284, 281, 284, 284, 284, 284, 284, 284, 284, 281, 284, 284, 281,
-    284, 284, 281, 284, 284, 281, 281, 285, 281, 286, 287, 286, 288, 288, 281, 281, 281, 281, 289,
-    289, 281, 281, 289, 289, 290, 281, 281, 281, 291, 281, 281, 281, 281, 281, 281, 281, 284, 284,
-    284, 284, 281, 284, 281, 281, 281, 281, 281, 281, 281, 292, 292, 292, 292, 292, 292, 292, 292,
-    292, 292, 282, 293, 294, 294, 295, 296, 297, 281, 281, 281, 281, 281, 281, 281, 281, 281, 298,
-    299, 299, 300, 298, 301, 301, 301, 301, 301, 301, 301, 301, 301, 298, 301, 301, 301, 298, 301,
-    301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 301, 298, 301, 301, 301, 301, 301,
-    301, 301, 298, 301, 301, 298, 301, 301, 301, 301, 301, 298, 298, 302, 301, 303, 304, 303, 305,
-    305, 305, 305, 306, 298, 306, 306, 307, 298, 303, 303, 308, 298, 298, 309, 298, 298, 298, 298,
-    298, 298, 298, 298, 298, 298, 298, 298, 298, 298, 298, 301, 301, 305, 305, 298, 298, 310, 310,
-    310, 310, 310, 310, 310, 310, 310, 310, 311, 312, 298, 298, 298, 298, 298, 298, 298, 301, 299,
-    299, 299, 313, 313, 313, 314, 315, 316, 316, 314, 317, 317, 317, 317, 317, 317, 317, 317, 314,
-    314, 317, 317, 314, 314, 317, 317, 317, 317, 317, 317, 317, 317, 317, 317, 317, 317, 317, 317,
-    314, 317, 317, 317, 317, 317, 317, 317, 314, 317, 317, 314, 317, 317, 317, 317, 317, 314, 314,
-    318, 317, 319, 320, 321, 322, 322, 322, 322, 314, 314, 323, 324, 314, 314, 324, 324, 325, 314,
-    314, 314, 314, 314, 314, 314, 320, 320, 326, 314, 314, 314, 314, 317, 317, 314, 317, 317, 317,
-    322, 322, 314, 314, 327, 327, 327, 327, 327, 327, 327, 327, 327, 327, 328, 317, 329, 329, 329,
-    329, 329, 329, 314, 314, 314, 314, 314, 314, 314, 314, 330, 330, 331, 332, 330, 333, 333, 333,
-    333, 333, 333, 330, 330, 330, 333, 333, 333, 330, 333, 333, 333, 333, 330, 330, 330, 333, 333,
-    330, 333, 330, 333, 333, 330, 330, 330, 333, 333, 330, 330, 330, 333, 333, 333, 333, 333, 333,
-    333, 333, 333, 333, 330, 330, 330, 330, 334, 335, 336, 335, 335, 330, 330, 330, 337, 337, 337,
-    330, 338, 338, 338, 339, 330, 330, 340, 330, 330, 330, 330, 330, 330, 334, 330, 330, 330, 330,
-    330, 330, 330, 330, 330, 330, 330, 330, 330, 330, 341, 341, 341, 341, 341, 341, 341, 341, 341,
-    341, 342, 342, 342, 343, 343, 343, 343, 343, 343, 344, 343, 330, 330, 330, 330, 330, 345, 346,
-    346, 346, 345, 347, 347, 347, 347, 347, 347, 347, 347, 348, 347, 347, 347, 348, 347, 347, 347,
-    347, 347, 347, 347, 347, 347, 347, 347, 347, 347, 347, 347, 347, 348, 348, 348, 347, 349, 349,
-    349, 350, 350, 350, 350, 348, 349, 349, 351, 348, 349, 349, 349, 352, 348, 348, 348, 348, 348,
-    348, 348, 353, 354, 348, 347, 347, 347, 348, 348, 348, 348, 348, 347, 347, 355, 355, 348, 348,
-    356, 356, 356, 356, 356, 356, 356, 356, 356, 356, 348, 348, 348, 348, 348, 348, 348, 357, 358,
-    358, 358, 358, 358, 358, 358, 359, 360, 361, 362, 362, 363, 360, 360, 360, 360, 360, 360, 360,
-    360, 364, 360, 360, 360, 364, 360, 360, 360, 360, 360, 360, 360, 360, 360, 360, 360, 360, 360,
-    360, 360, 360, 360, 360, 364, 360, 360, 360, 360, 360, 364, 364, 365, 360, 366, 367, 368, 366,
-    369, 366, 366, 364, 367, 368, 368, 364, 368, 368, 370, 371, 364, 364, 364, 364, 364, 364, 364,
-    369, 369, 364, 364, 364, 364, 364, 364, 364, 360, 364, 360, 360, 372, 372, 364, 364, 373, 373,
-    373, 373, 373, 373, 373, 373, 373, 373, 364, 374, 374, 364, 364, 364, 364, 364, 364, 364, 364,
-    364, 364, 364, 364, 364, 375, 375, 376, 376, 377, 377, 377, 377, 377, 377, 377, 377, 377, 378,
-    377, 377, 377, 378, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377, 377,
-    377, 377, 379, 379, 377, 380, 381, 381, 382, 382, 383, 383, 378, 384, 384, 384, 378, 385, 385,
-    385, 386, 387, 388, 378, 378, 378, 378, 389, 389, 389, 380, 390, 390, 390, 390, 390, 390, 390,
-    377, 377, 377, 383, 383, 378, 378, 391, 391, 391, 391, 391, 391, 391, 391, 391, 391, 390, 390,
-    390, 390, 390, 390, 390, 390, 390, 392, 389, 389, 389, 389, 389, 389, 393, 394, 395, 395, 393,
-    396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 396, 393,
-    393, 393, 396, 396, 396, 396, 396, 396, 396, 396, 393, 396, 396, 396, 396, 396, 396, 396, 396,
-    396, 393, 396, 393, 393, 393, 393, 397, 393, 393, 393, 393, 398, 399, 399, 400, 400, 401, 393,
-    401, 393, 399, 402, 403, 402, 403, 403, 403, 398, 393, 393, 393, 393, 393, 393, 404, 404, 404,
-    404, 404, 404, 404, 404, 404, 404, 393, 393, 399, 399, 405, 393, 393, 393, 393, 393, 393, 393,
-    393, 393, 393, 393, 406, 407, 407, 407, 407, 407, 407, 407, 407, 407, 407, 407, 407, 407, 407,
-    407, 407, 407, 407, 407, 407, 407, 407, 408, 407, 409, 407, 410, 409, 409, 409, 409, 411, 411,
-    412, 406, 406, 406, 406, 413, 407, 407, 407, 407, 407, 407, 414, 409, 415, 415, 415, 415, 416,
-    417, 409, 418, 419, 419, 419, 419, 419, 419, 419, 419, 419, 419, 420, 420, 406, 406, 406, 406,
-    406, 406, 406, 406, 406, 406, 406, 406, 421, 422, 422, 421, 422, 421, 422, 422, 422, 422, 422,
-    421, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 422, 421, 422,
-    421, 422, 422, 422, 422, 422, 422, 422, 422, 423, 422, 424, 422, 425, 424, 424, 424, 424, 426,
-    426, 427, 424, 428, 422, 421, 421, 422, 422, 422, 422, 422, 421, 429, 421, 430, 430, 430, 430,
-    431, 432, 421, 421, 433, 433, 433, 433, 433, 433, 433, 433, 433, 433, 421, 421, 422, 422, 422,
-    422, 421, 421, 421, 421, 421, 421, 421, 421, 434, 435, 436, 436, 437, 438, 437, 439, 440, 439,
-    439, 441, 440, 442, 442, 442, 442, 442, 440, 443, 442, 443, 443, 443, 444, 444, 443, 443, 443,
-    443, 443, 443, 445, 445, 445, 445, 445, 445, 445, 445, 445, 445, 446, 446, 446, 446, 446, 446,
-    446, 446, 446, 446, 447, 448, 443, 448, 443, 449, 450, 451, 450, 451, 452, 452, 434, 434, 434,
-    434, 434, 434, 434, 434, 453, 434, 434, 434, 434, 434, 434, 434, 434, 434, 434, 434, 434, 453,
-    453, 453, 453, 454, 455, 456, 457, 458, 456, 459, 456, 459, 455, 455, 455, 455, 460, 461, 455,
-    456, 462, 462, 463, 441, 462, 462, 434, 434, 434, 434, 434, 464, 464, 464, 464, 464, 464, 464,
-    464, 464, 464, 464, 453, 464, 464, 464, 464, 464, 464, 464, 464, 464, 464, 464, 464, 453, 447,
-    447, 443, 443, 443, 443, 443, 443, 448, 443, 443, 443, 443, 443, 443, 453, 443, 443, 439, 439,
-    441, 439, 465, 466, 466, 466, 466, 440, 440, 453, 453, 453, 453, 453, 453, 453, 453, 453, 453,
-    453, 453, 453, 467, 467, 467, 467, 467, 467, 467, 467, 467, 468, 468, 468, 468, 468, 468, 468,
-    468, 468, 468, 469, 469, 470, 470, 471, 471, 472, 473, 470, 470, 470, 474, 475, 476, 477, 478,
-    479, 480, 481, 482, 467, 483, 484, 484, 484, 484, 484, 484, 484, 484, 484, 485, 486, 487, 487,
-    488, 489, 467, 467, 468, 468, 468, 468, 490, 490, 471, 471, 467, 467, 467, 467, 491, 491, 482,
-    467, 469, 492, 492, 467, 467, 469, 469, 492, 492, 492, 492, 492, 467, 467, 467, 470, 470, 470,
-    470, 467, 467, 467, 467, 467, 481, 469, 472, 470, 470, 476, 476, 476, 476, 476, 476, 493, 467,
-    476, 484, 484, 484, 484, 484, 484, 484, 484, 484, 484, 476, 476, 494, 470, 495, 495, 496, 496,
-    496, 496, 496, 496, 496, 496, 496, 496, 496, 496, 496, 496, 497, 496, 497, 497, 497, 497, 497,
-    496, 497, 497, 498, 498, 498, 498, 498, 498, 498, 498, 498, 498, 498, 499, 500, 498, 498, 498,
-    501, 501, 501, 501, 501, 501, 501, 501, 502, 502, 502, 502, 502, 502, 502, 502, 503, 503, 503,
-    503, 503, 503, 503, 503, 504, 504, 504, 504, 504, 504, 504, 504, 504, 505, 504, 504, 504, 504,
-    505, 505, 504, 504, 504, 504, 504, 504, 504, 505, 504, 504, 504, 505, 505, 506, 506, 506, 507,
-    508, 507, 507, 507, 507, 507, 507, 507, 509, 509, 509, 509, 509, 509, 509, 509, 509, 509, 509,
-    509, 509, 509, 509, 509, 509, 509, 509, 509, 505, 505, 505, 510, 510, 510, 510, 510, 510, 510,
-    510, 511, 511, 511, 511, 511, 511, 511, 511, 511, 511, 512, 512, 512, 512, 512, 512, 513, 513,
-    513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 513, 514, 514, 515, 515, 515, 515, 515,
-    515, 514, 514, 516, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517, 517,
-    517, 517, 517, 517, 517, 518, 519, 517, 520, 521, 521, 521, 521, 521, 521, 521, 521, 521, 521,
-    521, 521, 521, 521, 521, 521, 521, 521, 522, 523, 524, 524, 524, 525, 525, 525, 525, 525, 525,
-    525, 525, 525, 525, 525, 526, 526, 526, 527, 527, 527, 525, 525, 525, 525, 525, 525, 525, 525,
-    528, 528, 528, 528, 528, 528, 528, 529, 529, 529, 529, 529, 529, 529, 529, 529, 529, 529, 529,
-    529, 530, 529, 529, 529, 529, 531, 532, 533, 530, 530, 530, 530, 530, 530, 530, 530, 530, 530,
-    530, 534, 534, 534, 534, 534, 534, 534, 534, 534, 534, 535, 536, 537, 538, 538, 539, 539, 539,
-    539, 539, 539, 539, 539, 539, 540, 540, 540, 540, 540, 540, 540, 540, 540, 540, 541, 542, 543,
-    543, 543, 543, 543, 543, 543, 543, 543, 543, 543, 543, 544, 544, 544, 544, 544, 544, 544, 544,
-    544, 544, 544, 544, 544, 545, 544, 544, 544, 545, 546, 547, 545, 545, 545, 545, 545, 545, 545,
-    545, 545, 545, 545, 545, 548, 548, 548, 548, 548, 548, 548, 548, 548, 548, 548, 548, 549, 549,
-    550, 551, 551, 551, 551, 552, 552, 552, 550, 550, 550, 553, 553, 553, 550, 550, 554, 555, 550,
-    554, 554, 556, 557, 558, 556, 554, 556, 551, 559, 556, 560, 560, 561, 562, 560, 563, 560, 564,
-    548, 565, 566, 566, 567, 567, 567, 567, 567, 567, 567, 567, 567, 567, 566, 566, 566, 566, 566,
-    566, 568, 568, 568, 568, 568, 568, 568, 568, 568, 568, 566, 566, 566, 566, 566, 566, 569, 570,
-    571, 571, 572, 573, 574, 575, 576, 576, 575, 577, 577, 577, 578, 579, 580, 580, 580, 580, 580,
-    580, 580, 580, 580, 580, 579, 579, 579, 579, 579, 579, 581, 581, 581, 581, 581, 581, 581, 581,
-    581, 581, 581, 582, 583, 583, 583, 583, 583, 583, 583, 583, 583, 583, 583, 583, 583, 579, 579,
-    579, 579, 579, 579, 579, 584, 584, 584, 584, 584, 585, 585, 583, 583, 586, 583, 579, 579, 579,
-    579, 579, 587, 587, 587, 587, 587, 587, 587, 587, 587, 587, 587, 587, 587, 587, 588, 588, 588,
-    588, 588, 588, 588, 588, 588, 588, 589, 590, 590, 590, 590, 590, 590, 590, 590, 590, 590, 590,
-    590, 590, 590, 590, 590, 590, 590, 590, 590, 590, 590, 591, 592, 592, 593, 594, 594, 595, 595,
-    592, 592, 596, 596, 596, 591, 591, 591, 591, 597, 597, 598, 597, 597, 597, 597, 597, 597, 599,
-    600, 601, 591, 591, 591, 591, 602, 591, 591, 591, 603, 603, 604, 604, 604, 604, 604, 604, 604,
-    604, 604, 604, 605, 605, 605, 605, 605, 605, 605, 605, 605, 605, 605, 605, 605, 605, 606, 606,
-    605, 605, 605, 605, 605, 606, 606, 606, 606, 606, 606, 606, 606, 606, 606, 606, 607, 607, 607,
-    607, 607, 607, 607, 607, 607, 607, 607, 607, 608, 608, 608, 608, 609, 609, 608, 608, 608, 608,
-    608, 608, 610, 610, 610, 610, 610, 610, 610, 610, 610, 610, 611, 608, 608, 608, 612, 612, 613,
-    613, 613, 613, 613, 613, 613, 613, 614, 614, 614, 614, 614, 614, 614, 614, 614, 614, 614, 614,
-    614, 614, 614, 615, 616, 617, 618, 619, 620, 620, 621, 621, 622, 622, 622, 622, 622, 622, 622,
-    622, 622, 622, 622, 622, 622, 623, 624, 625, 626, 626, 627, 628, 628, 628, 628, 629, 630, 631,
-    632, 631, 631, 632, 632, 632, 632, 633, 633, 632, 633, 634, 635, 635, 635, 635, 635, 632, 636,
-    637, 637, 637, 637, 637, 638, 637, 637, 629, 629, 639, 640, 640, 640, 640, 640, 640, 640, 640,
-    640, 640, 629, 629, 629, 629, 629, 629, 641, 641, 641, 641, 641, 641, 641, 642, 641, 641, 641,
-    641, 641, 641, 629, 629, 643, 643, 643, 643, 643, 644, 644, 644, 644, 644, 644, 643, 643, 644,
-    645, 644, 644, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 646, 647,
-    647, 647, 648, 649, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650, 650,
-    651, 652, 653, 653, 654, 654, 654, 655, 653, 656, 657, 657, 658, 658, 653, 656, 659, 650, 650,
-    650, 650, 650, 650, 650, 660, 660, 660, 660, 661, 661, 661, 661, 661, 661, 661, 661, 661, 661,
-    662, 662, 663, 662, 662, 662, 662, 664, 664, 664, 664, 664, 664, 664, 664, 664, 664, 665, 666,
-    665, 665, 665, 665, 665, 665, 665, 664, 664, 664, 664, 664, 664, 664, 664, 664, 660, 660, 660,
-    667, 668, 669, 670, 670, 670, 670, 670, 670, 670, 670, 670, 670, 670, 670, 670, 670, 671, 672,
-    672, 673, 674, 675, 676, 673, 673, 677, 678, 672, 672, 670, 670, 679, 679, 679, 679, 679, 679,
-    679, 679, 679, 679, 670, 670, 670, 670, 670, 670, 680, 680, 680, 680, 680, 680, 680, 680, 680,
-    680, 680, 680, 680, 680, 681, 682, 683, 683, 682, 682, 682, 683, 682, 683, 684, 684, 685, 685,
-    686, 686, 686, 686, 686, 686, 686, 686, 687, 687, 687, 687, 688, 688, 688, 688, 688, 688, 688,
-    688, 688, 688, 688, 688, 689, 689, 690, 691, 691, 691, 690, 690, 692, 693, 693, 693, 693, 693,
-    693, 693, 694, 694, 695, 696, 697, 697, 697, 698, 698, 698, 698, 698, 699, 699, 699, 699, 699,
-    699, 699, 699, 699, 699, 697, 697, 697, 688, 688, 688, 700, 700, 700, 700, 700, 700, 700, 700,
-    700, 700, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 701, 702, 702, 702,
-    702, 702, 702, 703, 703, 704, 704, 704, 704, 704, 704, 704, 704, 704, 705, 705, 705, 705, 705,
-    705, 705, 706, 706, 706, 706, 706, 706, 706, 706, 706, 706, 706, 707, 707, 706, 706, 706, 708,
-    708, 708, 708, 708, 708, 708, 708, 709, 709, 709, 709, 709, 709, 709, 709, 710, 710, 710, 711,
-    712, 713, 713, 713, 713, 713, 710, 710, 713, 713, 713, 713, 710, 714, 715, 715, 715, 715, 715,
-    715, 715, 716, 716, 716, 716, 717, 716, 716, 716, 716, 718, 718, 710, 719, 719, 714, 720, 720,
-    721, 722, 722, 722, 722, 722, 723, 723, 723, 723, 723, 723, 723, 723, 723, 723, 723, 723, 723,
-    723, 724, 724, 724, 724, 724, 725, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726, 726,
-    726, 726, 726, 726, 726, 727, 727, 727, 727, 727, 726, 726, 726, 726, 727, 727, 727, 727, 727,
-    723, 723, 723, 723, 723, 728, 723, 723, 723, 723, 723, 723, 723, 729, 729, 729, 729, 729, 729,
-    729, 729, 729, 729, 729, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730, 730,
-    730, 730, 730, 730, 730, 730, 731, 732, 732, 733, 732, 732, 732, 732, 732, 732, 732, 733, 732,
-    732, 734, 735, 733, 736, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732, 732,
-    732, 732, 732, 732, 732, 732, 732, 737, 738, 738, 733, 739, 740, 741, 733, 732, 733, 742, 743,
-    742, 743, 742, 743, 742, 743, 742, 743, 742, 743, 742, 743, 743, 743, 743, 743, 743, 743, 743,
-    743, 742, 743, 744, 744, 744, 744, 744, 744, 744, 744, 745, 745, 745, 745, 745, 745, 745, 745,
-    744, 744, 744, 744, 744, 744, 746, 746, 745, 745, 745, 745, 745, 745, 746, 746, 746, 745, 746,
-    745, 746, 745, 746, 745, 747, 747, 747, 747, 747, 747, 747, 747, 744, 744, 744, 744, 744, 746,
-    744, 744, 745, 745, 745, 745, 747, 748, 744, 748, 748, 748, 744, 744, 744, 746, 744, 744, 745,
-    745, 745, 745, 747, 748, 748, 748, 744, 744, 744, 744, 746, 746, 744, 744, 745, 745, 745, 745,
-    746, 748, 748, 748, 745, 745, 745, 745, 745, 748, 748, 748, 746, 746, 744, 744, 744, 746, 744,
-    744, 745, 745, 745, 745, 747, 749, 748, 746, 750, 750, 750, 750, 750, 750, 750, 751, 750, 750,
-    750, 752, 753, 754, 755, 756, 757, 758, 759, 759, 760, 761, 762, 763, 764, 765, 766, 767, 767,
-    768, 766, 767, 762, 762, 769, 763, 770, 771, 771, 772, 773, 774, 775, 776, 777, 778, 779, 780,
-    781, 781, 781, 781, 781, 782, 782, 782, 763, 767, 768, 762, 783, 784, 763, 785, 785, 763, 763,
-    763, 786, 787, 788, 784, 784, 783, 763, 763, 763, 763, 763, 763, 763, 763, 789, 763, 785, 763,
-    790, 763, 790, 790, 790, 790, 763, 790, 790, 750, 791, 792, 792, 792, 792, 793, 794, 795, 796,
-    797, 798, 798, 798, 798, 798, 798, 799, 800, 801, 801, 802, 799, 799, 799, 799, 799, 803, 803,
-    804, 805, 806, 807, 799, 808, 802, 802, 802, 799, 799, 799, 799, 799, 803, 803, 804, 805, 806,
-    801, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 800, 801, 801, 801, 809, 809,
-    809, 809, 809, 809, 809, 810, 809, 809, 809, 809, 809, 809, 809, 809, 809, 809, 809, 809, 809,
-    809, 810, 809, 809, 809, 809, 810, 809, 809, 810, 809, 811, 811, 811, 811, 811, 811, 811, 811,
-    812, 812, 813, 813, 812, 812, 812, 812, 813, 813, 813, 812, 812, 814, 814, 814, 814, 812, 814,
-    814, 814, 813, 813, 812, 815, 812, 813, 813, 815, 815, 815, 815, 816, 817, 817, 817, 817, 817,
-    817, 817, 817, 817, 817, 817, 817, 817, 817, 817, 818, 818, 819, 820, 818, 821, 818, 819, 818,
-    820, 822, 819, 819, 819, 822, 822, 819, 819, 819, 823, 818, 819, 824, 818, 825, 819, 819, 819,
-    819, 819, 818, 818, 818, 821, 826, 818, 819, 818, 827, 818, 819, 818, 828, 829, 819, 819, 830,
-    822, 819, 819, 828, 819, 822, 831, 831, 831, 831, 832, 818, 818, 822, 822, 819, 819, 825, 825,
-    825, 825, 825, 819, 822, 822, 822, 822, 818, 825, 818, 818, 833, 834, 835, 835, 835, 835, 836,
-    836, 835, 835, 835, 835, 835, 836, 835, 835, 836, 835, 837, 837, 837, 837, 837, 837, 837, 837,
-    837, 837, 837, 837, 838, 838, 838, 838, 837, 837, 838, 838, 838, 838, 838, 838, 838, 838, 838,
-    839, 840, 838, 838, 838, 838, 836, 841, 841, 842, 842, 842, 842, 843, 843, 843, 843, 844, 845,
-    845, 845, 845, 845, 846, 846, 847, 847, 847, 847, 846, 847, 847, 846, 847, 847, 846, 847, 847,
-    848, 848, 847, 847, 847, 846, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847, 847,
-    847, 847, 846, 846, 847, 847, 843, 847, 843, 847, 847, 847, 847, 847, 847, 847, 846, 846, 846,
-    846, 846, 846, 846, 846, 846, 846, 846, 846, 849, 850, 849, 849, 850, 850, 850, 849, 849, 850,
-    850, 849, 850, 850, 850, 849, 850, 849, 851, 852, 850, 849, 850, 850, 850, 850, 849, 850, 850,
-    849, 849, 849, 849, 850, 850, 849, 850, 849, 850, 849, 849, 849, 849, 849, 849, 850, 849, 850,
-    850, 850, 850, 850, 849, 849, 849, 849, 850, 850, 850, 850, 849, 849, 850, 850, 850, 850, 850,
-    850, 850, 850, 850, 850, 849, 850, 850, 850, 849, 850, 850, 850, 850, 850, 849, 850, 850, 850,
-    850, 850, 849, 849, 850, 850, 849, 849, 849, 849, 850, 850, 849, 849, 850, 850, 849, 849, 850,
-    850, 850, 850, 850, 849, 850, 850, 850, 849, 850, 850, 850, 850, 850, 850, 850, 850, 850, 850,
-    850, 850, 850, 849, 850, 850, 850, 850, 850, 850, 850, 853, 854, 854, 854, 854, 854, 854, 854,
-    854, 855, 856, 855, 856, 854, 854, 854, 854, 854, 854, 857, 854, 854, 854, 854, 854, 854, 854,
-    858, 858, 854, 854, 854, 854, 859, 859, 854, 854, 854, 854, 854, 854, 860, 855, 856, 854, 854,
-    854, 854, 854, 854, 854, 854, 854, 854, 854, 861, 861, 861, 861, 861, 861, 861, 861, 861, 861,
-    861, 861, 861, 854, 859, 854, 854, 854, 862, 854, 854, 854, 854, 854, 854, 854, 854, 854, 854,
-    854, 854, 861, 854, 854, 854, 854, 854, 859, 859, 859, 859, 859, 859, 859, 859, 859, 859, 859,
-    859, 859, 859, 859, 859, 859, 854, 854, 854, 854, 854, 854, 854, 854, 854, 854, 854, 860, 854,
-    854, 854, 854, 859, 859, 859, 859, 854, 860, 860, 860, 860, 860, 860, 860, 858, 858, 858, 858,
-    854, 854, 854, 854, 860, 860, 860, 854, 854, 854, 854, 854, 863, 863, 863, 863, 863, 863, 863,
-    863, 863, 863, 863, 863, 863, 863, 863, 864, 864, 864, 864, 864, 864, 864, 864, 864, 865, 865,
-    865, 865, 865, 865, 865, 865, 865, 865, 865, 866, 866, 866, 866, 866, 866, 866, 866, 866, 866,
-    866, 866, 866, 867, 867, 867, 867, 867, 867, 867, 867, 868, 868, 868, 868, 868, 868, 868, 868,
-    868, 868, 868, 868, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869, 869,
-    869, 869, 869, 870, 870, 870, 870, 870, 870, 870, 870, 870, 870, 870, 870, 871, 870, 870, 870,
-    870, 870, 870, 870, 867, 867, 867, 867, 867, 867, 867, 867, 867, 867, 867, 867, 867, 872, 873,
-    873, 873, 873, 873, 873, 873, 873, 873, 873, 873, 873, 874, 874, 874, 874, 873, 873, 873, 873,
-    873, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 874, 875, 875, 875, 875, 875, 875, 875,
-    875, 876, 876, 875, 875, 875, 875, 876, 876, 876, 876, 876, 876, 876, 876, 876, 876, 877, 877,
-    878, 877, 877, 877, 877, 877, 877, 877, 879, 879, 878, 878, 878, 878, 878, 878, 877, 877, 878,
-    878, 880, 881, 878, 878, 878, 878, 877, 877, 878, 878, 880, 881, 878, 878, 878, 878, 877, 877,
-    877, 878, 878, 877, 882, 878, 877, 877, 877, 877, 878, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 878, 878, 878, 878, 878, 878, 877, 877, 877, 877, 878, 878, 878, 878, 878, 878, 878, 878,
-    878, 877, 883, 883, 883, 884, 884, 884, 884, 883, 885, 885, 885, 885, 886, 887, 888, 889, 889,
-    887, 889, 889, 889, 889, 890, 887, 889, 886, 889, 891, 885, 885, 887, 887, 885, 889, 892, 892,
-    892, 893, 892, 892, 886, 889, 886, 886, 889, 889, 886, 889, 889, 889, 886, 889, 889, 889, 886,
-    886, 889, 889, 889, 889, 889, 889, 889, 889, 886, 885, 885, 892, 889, 889, 889, 889, 890, 889,
-    890, 889, 889, 889, 889, 889, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 886, 889,
-    889, 889, 889, 889, 889, 889, 889, 889, 889, 889, 886, 890, 887, 889, 890, 887, 890, 886, 887,
-    885, 887, 887, 889, 887, 887, 889, 894, 889, 889, 889, 886, 889, 889, 886, 885, 889, 889, 889,
-    889, 889, 889, 891, 891, 891, 891, 891, 891, 891, 891, 891, 891, 889, 889, 886, 886, 886, 886,
-    886, 886, 889, 886, 889, 886, 886, 889, 887, 887, 886, 886, 889, 889, 889, 889, 889, 886, 889,
-    889, 886, 886, 895, 889, 889, 889, 886, 886, 889, 889, 889, 889, 889, 889, 889, 889, 889, 889,
-    889, 885, 885, 892, 892, 892, 892, 892, 885, 885, 892, 892, 885, 887, 887, 887, 887, 892, 886,
-    885, 892, 885, 887, 885, 885, 887, 887, 887, 892, 892, 887, 887, 892, 887, 887, 892, 892, 892,
-    889, 887, 889, 889, 889, 889, 887, 890, 885, 887, 887, 887, 887, 887, 890, 885, 885, 885, 885,
-    885, 887, 885, 885, 893, 885, 887, 887, 885, 892, 892, 896, 896, 897, 896, 896, 898, 899, 899,
-    897, 897, 900, 900, 900, 900, 901, 898, 901, 901, 898, 899, 898, 899, 898, 899, 899, 899, 899,
-    899, 899, 898, 899, 899, 899, 898, 899, 899, 899, 899, 899, 899, 898, 899, 899, 899, 899, 899,
-    899, 899, 899, 899, 899, 898, 898, 899, 899, 899, 899, 899, 899, 899, 899, 899, 899, 899, 899,
-    899, 899, 899, 898, 899, 899, 898, 899, 899, 899, 899, 898, 899, 898, 899, 899, 899, 899, 898,
-    898, 898, 899, 902, 899, 899, 899, 903, 903, 903, 903, 903, 903, 899, 904, 905, 897, 901, 901,
-    901, 906, 907, 906, 907, 906, 907, 906, 907, 906, 907, 906, 907, 906, 907, 908, 908, 908, 908,
-    908, 908, 908, 908, 908, 908, 908, 908, 908, 908, 899, 898, 898, 898, 899, 899, 899, 899, 899,
-    899, 899, 898, 909, 909, 909, 909, 909, 910, 911, 909, 909, 909, 909, 909, 909, 909, 909, 909,
-    909, 909, 909, 909, 909, 909, 910, 911, 910, 911, 910, 911, 910, 911, 910, 911, 912, 912, 912,
-    912, 912, 912, 912, 912, 913, 913, 913, 913, 913, 913, 913, 913, 914, 914, 914, 914, 914, 914,
-    914, 914, 914, 914, 914, 914, 915, 915, 914, 914, 916, 916, 916, 917, 918, 917, 918, 917, 918,
-    917, 918, 917, 918, 917, 919, 917, 918, 917, 918, 917, 918, 917, 918, 917, 918, 916, 916, 916,
-    916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 916, 917, 918, 917, 918, 916, 916, 916,
-    916, 916, 916, 916, 916, 917, 918, 916, 916, 920, 920, 920, 920, 920, 920, 920, 920, 921, 921,
-    921, 921, 921, 922, 922, 922, 921, 921, 921, 921, 921, 921, 921, 921, 921, 921, 921, 922, 922,
-    921, 921, 921, 923, 923, 923, 923, 923, 923, 923, 923, 923, 923, 923, 923, 923, 921, 921, 923,
-    923, 923, 923, 923, 923, 921, 921, 921, 922, 921, 921, 921, 921, 924, 925, 925, 925, 925, 921,
-    921, 921, 921, 921, 921, 921, 921, 921, 921, 926, 926, 921, 921, 921, 921, 921, 921, 921, 921,
-    926, 921, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 927, 928, 929,
-    929, 929, 929, 929, 929, 929, 929, 929, 929, 929, 929, 929, 929, 929, 928, 930, 931, 930, 930,
-    930, 931, 931, 930, 931, 930, 931, 930, 931, 930, 930, 930, 930, 931, 930, 931, 931, 930, 931,
-    931, 931, 931, 931, 931, 932, 932, 930, 930, 933, 934, 933, 934, 933, 934, 933, 934, 933, 934,
-    933, 934, 934, 935, 935, 935, 935, 935, 935, 933, 934, 933, 934, 936, 936, 936, 933, 934, 937,
-    937, 937, 937, 937, 938, 939, 939, 939, 940, 938, 939, 941, 941, 941, 941, 941, 941, 941, 941,
-    941, 941, 941, 941, 941, 941, 942, 941, 942, 942, 942, 942, 942, 941, 942, 942, 943, 943, 943,
-    943, 943, 943, 943, 943, 944, 944, 944, 944, 944, 944, 944, 945, 946, 944, 944, 944, 944, 944,
-    944, 944, 944, 944, 944, 944, 944, 944, 944, 947, 948, 948, 948, 948, 948, 948, 948, 948, 948,
-    948, 948, 948, 948, 948, 948, 949, 949, 949, 949, 949, 949, 949, 949, 949, 950, 950, 950, 950,
-    950, 950, 950, 950, 951, 951, 952, 953, 952, 953, 951, 951, 951, 952, 953, 951, 952, 953, 954,
-    954, 954, 954, 954, 954, 954, 954, 955, 956, 957, 954, 958, 955, 952, 953, 955, 955, 952, 953,
-    959, 960, 959, 960, 959, 960, 959, 960, 954, 954, 954, 954, 961, 962, 954, 954, 955, 954, 954,
-    955, 955, 955, 955, 955, 963, 963, 954, 954, 954, 955, 956, 954, 964, 954, 954, 954, 954, 954,
-    954, 954, 954, 955, 954, 955, 954, 954, 965, 965, 955, 966, 966, 966, 966, 966, 966, 966, 966,
-    966, 966, 966, 966, 966, 967, 967, 967, 967, 967, 967, 967, 967, 967, 967, 968, 967, 967, 967,
-    967, 967, 967, 967, 967, 967, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 968, 969,
-    969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 969, 970, 970, 970, 970, 970, 970,
-    970, 970, 970, 970, 971, 971, 971, 971, 971, 971, 971, 971, 971, 971, 971, 971, 972, 972, 972,
-    972, 973, 974, 974, 975, 976, 977, 978, 979, 980, 981, 980, 981, 980, 981, 980, 981, 980, 981,
-    976, 976, 980, 981, 980, 981, 980, 981, 980, 981, 982, 983, 984, 984, 976, 979, 979, 979, 979,
-    979, 979, 979, 979, 979, 985, 986, 987, 988, 989, 989, 990, 991, 991, 991, 991, 992, 976, 976,
-    979, 979, 979, 977, 993, 994, 976, 976, 995, 996, 997, 996, 997, 996, 997, 996, 997, 996, 997,
-    997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 997, 996, 997, 997,
-    997, 997, 997, 997, 997, 996, 997, 996, 997, 996, 997, 997, 997, 997, 997, 997, 996, 997, 997,
-    997, 997, 997, 997, 996, 996, 995, 995, 998, 998, 999, 999, 1000, 1000, 997, 1001, 1002, 1003,
-    1002, 1003, 1002, 1003, 1002, 1003, 1002, 1003, 1003, 1003, 1003, 1003, 1003, 1003, 1003, 1003,
-    1003, 1003, 1003, 1003, 1003, 1003, 1003, 1003, 1002, 1003, 1003, 1003, 1003, 1003, 1003, 1003,
-    1002, 1003, 1002, 1003, 1002, 1003, 1003, 1003, 1003, 1003, 1003, 1002, 1003, 1003, 1003, 1003,
-    1003, 1003, 1002, 1002, 1003, 1003, 1003, 1003, 1004, 1005, 1006, 1006, 1003, 1007, 1007, 1007,
-    1007, 1007, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1009, 1010, 1010,
-    1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010,
-    1010, 1010, 1010, 1010, 1009, 1011, 1011, 1012, 1012, 1012, 1012, 1011, 1011, 1011, 1011, 1011,
-    1011, 1011, 1011, 1011, 1011, 1013, 1013, 1013, 1013, 1013, 1013, 1013, 1013, 1014, 1014, 1014,
-    1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1015, 1015, 1015, 1015, 1015, 1015, 1015,
-    1015, 1015, 1015, 1015, 1015, 1016, 1016, 1016, 1016, 1016, 1016, 1016, 1016, 1017, 1017, 1017,
-    1017, 1017, 1017, 1017, 1017, 1017, 1017, 1017, 1017, 1017, 1018, 1018, 1019, 1020, 1020, 1020,
-    1020, 1020, 1020, 1020, 1020, 1020, 1020, 1021, 1021, 1021, 1021, 1021, 1021, 1021, 1021, 1021,
-    1021, 1021, 1021, 1021, 1021, 1022, 1022, 1022, 1022, 1022, 1022, 1022, 1022, 1023, 1024, 1024,
-    1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1017, 1017, 1017,
-    1017, 1018, 1018, 1018, 1021, 1021, 1021, 1021, 1021, 1021, 1021, 1021, 1025, 1021, 1025, 1021,
-    1021, 1021, 1021, 1021, 1021, 1021, 1024, 1024, 1024, 1024, 1024, 1024, 1024, 1021, 1021, 1021,
-    1021, 1023, 1023, 1023, 1023, 1026, 1026, 1026, 1026, 1026, 1026, 1026, 1026, 1026, 1026, 1026,
-    1026, 1026, 1026, 1026, 1021, 1027, 1027, 1027, 1027, 1027, 1027, 1027, 1027, 1028, 1028, 1028,
-    1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1029, 1029, 1029, 1029,
-    1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1028, 1029, 1029, 1030, 1030, 1030,
-    1030, 1030, 1030, 1030, 1030, 1031, 1031, 1031, 1031, 1031, 1031, 1031, 1031, 1032, 1032, 1032,
-    1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1032, 1033, 1033, 1033, 1034, 1034, 1034,
-    1034, 1034, 1034, 1034, 1034, 1034, 1034, 1034, 1034, 1034, 1035, 1034, 1034, 1034, 1034, 1034,
-    1034, 1034, 1036, 1036, 1036, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1037,
-    1037, 1037, 1037, 1037, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1038, 1039, 1039, 1039,
-    1039, 1039, 1039, 1039, 1039, 1040, 1040, 1040, 1040, 1040, 1040, 1041, 1041, 1042, 1042, 1042,
-    1042, 1042, 1042, 1042, 1042, 1042, 1042, 1042, 1042, 1043, 1044, 1045, 1044, 1046, 1046, 1046,
-    1046, 1046, 1046, 1046, 1046, 1046, 1046, 1042, 1042, 1047, 1047, 1047, 1047, 1047, 1047, 1047,
-    1047, 1047, 1047, 1047, 1047, 1048, 1049, 1048, 1049, 1048, 1049, 1048, 1049, 1048, 1049, 1048,
-    1049, 1048, 1049, 1050, 1051, 1052, 1052, 1052, 1053, 1051, 1051, 1051, 1051, 1051, 1051, 1051,
-    1051, 1051, 1051, 1053, 1054, 1048, 1049, 1048, 1049, 1055, 1055, 1051, 1051, 1056, 1056, 1056,
-    1056, 1056, 1056, 1056, 1056, 1056, 1056, 1056, 1056, 1056, 1056, 1057, 1057, 1057, 1057, 1057,
-    1057, 1057, 1057, 1057, 1057, 1058, 1058, 1059, 1060, 1060, 1060, 1060, 1060, 1061, 1061, 1061,
-    1061, 1061, 1061, 1061, 1061, 1062, 1062, 1062, 1062, 1062, 1062, 1062, 1062, 1063, 1063, 1063,
-    1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1063, 1064, 1064, 1064, 1064,
-    1064, 1064, 1064, 1064, 1064, 1065, 1065, 1066, 1067, 1066, 1067, 1066, 1067, 1066, 1067, 1066,
-    1067, 1066, 1067, 1066, 1067, 1067, 1067, 1066, 1067, 1066, 1067, 1066, 1067, 1068, 1067, 1067,
-    1067, 1067, 1067, 1067, 1067, 1067, 1066, 1067, 1066, 1067, 1066, 1066, 1067, 1069, 1070, 1070,
-    1066, 1067, 1066, 1067, 1071, 1066, 1067, 1066, 1067, 1067, 1067, 1066, 1067, 1066, 1067, 1066,
-    1066, 1066, 1066, 1066, 1067, 1066, 1066, 1066, 1066, 1066, 1067, 1066, 1067, 1072, 1072, 1066,
-    1067, 1066, 1066, 1066, 1066, 1067, 1066, 1067, 1072, 1072, 1072, 1072, 1072, 1072, 1072, 1072,
-    1072, 1072, 1072, 1072, 1072, 1072, 1072, 1072, 1072, 1072, 1066, 1067, 1071, 1068, 1068, 1067,
-    1071, 1071, 1071, 1071, 1071, 1073, 1073, 1074, 1073, 1073, 1073, 1075, 1073, 1073, 1073, 1073,
-    1076, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073, 1073,
-    1077, 1077, 1078, 1074, 1077, 1079, 1079, 1079, 1079, 1080, 1081, 1081, 1081, 1082, 1082, 1082,
-    1082, 1082, 1082, 1083, 1083, 1084, 1085, 1086, 1086, 1086, 1086, 1086, 1086, 1087, 1087, 1087,
-    1087, 1087, 1087, 1087, 1087, 1087, 1087, 1088, 1089, 1090, 1090, 1091, 1091, 1092, 1092, 1092,
-    1092, 1092, 1092, 1092, 1092, 1093, 1093, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094,
-    1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1095, 1096, 1096, 1096, 1096, 1096, 1096,
-    1096, 1096, 1096, 1096, 1096, 1096, 1096, 1096, 1096, 1097, 1098, 1099, 1099, 1099, 1099, 1099,
-    1099, 1099, 1099, 1100, 1100, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1099,
-    1099, 1099, 1099, 1099, 1099, 1102, 1102, 1102, 1102, 1102, 1102, 1102, 1102, 1102, 1102, 1103,
-    1103, 1104, 1104, 1104, 1104, 1105, 1105, 1105, 1104, 1106, 1104, 1103, 1107, 1108, 1108, 1108,
-    1108, 1108, 1108, 1108, 1108, 1108, 1108, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109,
-    1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1109, 1110, 1110, 1110, 1110, 1110,
-    1111, 1111, 1111, 1112, 1113, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114,
-    1114, 1114, 1114, 1114, 1115, 1115, 1115, 1116, 1115, 1115, 1115, 1115, 1117, 1117, 1117, 1118,
-    1119, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1120, 1121, 1122, 1122, 1122,
-    1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1123, 1123, 1123, 1124, 1124, 1124,
-    1125, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126, 1126,
-    1127, 1128, 1128, 1129, 1129, 1130, 1130, 1131, 1131, 1129, 1132, 1133, 1134, 1135, 1136, 1136,
-    1136, 1136, 1136, 1136, 1137, 1137, 1137, 1136, 1136, 1136, 1136, 1138, 1139, 1140, 1140, 1140,
-    1140, 1140, 1140, 1140, 1140, 1140, 1140, 1138, 1138, 1138, 1138, 1136, 1136, 1141, 1141, 1141,
-    1141, 1141, 1142, 1143, 1141, 1141, 1141, 1141, 1141, 1141, 1141, 1141, 1141, 1144, 1144, 1144,
-    1144, 1144, 1144, 1144, 1144, 1144, 1144, 1141, 1141, 1141, 1141, 1141, 1145, 1146, 1146, 1146,
-    1146, 1146, 1146, 1146, 1146, 1146, 1147, 1148, 1148, 1148, 1149, 1148, 1150, 1150, 1148, 1149,
-    1151, 1152, 1153, 1154, 1155, 1155, 1155, 1155, 1155, 1155, 1155, 1155, 1155, 1146, 1146, 1146,
-    1156, 1146, 1146, 1146, 1146, 1146, 1146, 1146, 1146, 1156, 1157, 1155, 1155, 1158, 1158, 1158,
-    1158, 1158, 1158, 1158, 1158, 1158, 1158, 1155, 1155, 1159, 1160, 1160, 1160, 1161, 1161, 1161,
-    1161, 1161, 1161, 1161, 1161, 1162, 1161, 1161, 1161, 1163, 1163, 1163, 1164, 1164, 1164, 1161,
-    1165, 1166, 1167, 1168, 1168, 1169, 1169, 1169, 1169, 1169, 1169, 1169, 1169, 1170, 1169, 1170,
-    1170, 1171, 1169, 1169, 1170, 1170, 1169, 1169, 1169, 1169, 1169, 1170, 1172, 1169, 1172, 1169,
-    1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173, 1173,
-    1174, 1174, 1175, 1176, 1176, 1177, 1177, 1177, 1177, 1177, 1177, 1177, 1177, 1177, 1177, 1177,
-    1178, 1179, 1180, 1178, 1181, 1182, 1182, 1183, 1184, 1184, 1185, 1186, 1187, 1187, 1187, 1187,
-    1187, 1187, 1187, 1187, 1187, 1188, 1189, 1189, 1189, 1189, 1189, 1189, 1188, 1188, 1188, 1188,
-    1188, 1188, 1188, 1188, 1188, 1189, 1189, 1189, 1189, 1189, 1189, 1189, 1188, 1190, 1190, 1190,
-    1190, 1190, 1190, 1190, 1190, 1190, 1190, 1190, 1191, 1192, 1192, 1192, 1192, 1190, 1190, 1190,
-    1190, 1190, 1193, 1190, 1190, 1190, 1192, 1194, 1194, 1195, 1195, 1195, 1195, 1196, 1196, 1196,
-    1196, 1196, 1196, 1196, 1196, 1197, 1197, 1197, 1197, 1197, 1197, 1197, 1197, 1197, 1197, 1197,
-    1198, 1198, 1199, 1198, 1198, 1200, 1198, 1198, 1201, 1202, 1203, 1204, 1204, 1205, 1205, 1205,
-    1205, 1205, 1205, 1205, 1205, 1205, 1205, 1204, 1204, 1204, 1204, 1204, 1204, 1206, 1207, 1207,
-    1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207,
-    1207, 1206, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1208, 1208, 1208, 1208, 1208, 1208, 1208,
-    1208, 1208, 1208, 1208, 1208, 1209, 1209, 1209, 1209, 1209, 1209, 1209, 1209, 1209, 1209, 1209,
-    1209, 1209, 1209, 1209, 1210, 1210, 1210, 1210, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211,
-    1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1211, 1210, 1210, 1210, 1210, 1212, 1212, 1212,
-    1212, 1212, 1212, 1212, 1212, 1213, 1213, 1213, 1213, 1213, 1213, 1213, 1213, 1214, 1214, 1214,
-    1214, 1214, 1214, 1214, 1214, 1215, 1215, 1215, 1215, 1215, 1215, 1215, 1215, 1216, 1216, 1216,
-    1216, 1216, 1216, 1216, 1216, 1216, 1216, 1216, 1216, 1216, 1216, 1217, 1217, 1216, 1216, 1217,
-    1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1218, 1218, 1218,
-    1218, 1218, 1218, 1218, 1219, 1219, 1219, 1219, 1219, 1219, 1219, 1219, 1219, 1219, 1219, 1219,
-    1220, 1220, 1220, 1220, 1220, 1219, 1219, 1219, 1219, 1219, 1221, 1222, 1221, 1221, 1221, 1221,
-    1221, 1221, 1221, 1221, 1221, 1221, 1223, 1221, 1221, 1221, 1221, 1221, 1221, 1221, 1221, 1221,
-    1221, 1221, 1221, 1221, 1219, 1221, 1221, 1221, 1221, 1221, 1219, 1221, 1219, 1221, 1221, 1219,
-    1221, 1221, 1219, 1221, 1221, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1225,
-    1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1226,
-    1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226, 1226,
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1227, 1228, 1226, 1226, 1224,
-    1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1224, 1229, 1230, 1226, 1226, 1231, 1231, 1231,
-    1231, 1231, 1231, 1231, 1231, 1232, 1233, 1233, 1234, 1232, 1235, 1235, 1236, 1237, 1238, 1239,
-    1239, 1239, 1239, 1239, 1239, 1240, 1240, 1240, 1240, 1240, 1240, 1240, 1241, 1241, 1241, 1241,
-    1241, 1241, 1241, 1242, 1242, 1243, 1244, 1244, 1245, 1245, 1246, 1247, 1246, 1247, 1246, 1247,
-    1246, 1247, 1246, 1247, 1246, 1247, 1246, 1247, 1246, 1247, 1243, 1243, 1246, 1247, 1243, 1243,
-    1243, 1243, 1245, 1245, 1245, 1248, 1249, 1250, 1251, 1252, 1253, 1254, 1254, 1255, 1256, 1257,
-    1256, 1257, 1256, 1257, 1258, 1249, 1249, 1259, 1260, 1261, 1261, 1261, 1251, 1249, 1262, 1263,
-    1249, 1251, 1251, 1251, 1251, 1264, 1264, 1264, 1264, 1264, 1265, 1264, 1264, 1264, 1264, 1264,
-    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1265, 1265, 1266, 1267, 1268, 1269,
-    1270, 1271, 1272, 1269, 1273, 1274, 1275, 1269, 1276, 1277, 1278, 1279, 1280, 1281, 1281, 1281,
-    1281, 1281, 1281, 1281, 1281, 1281, 1281, 1282, 1283, 1284, 1284, 1284, 1268, 1269, 1285, 1285,
-    1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285, 1285,
-    1274, 1269, 1275, 1286, 1287, 1286, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1288,
-    1288, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1274, 1284, 1275, 1284, 1274, 1275, 1289, 1274,
-    1275, 1289, 1290, 1291, 1292, 1292, 1292, 1292, 1292, 1292, 1292, 1292, 1292, 1293, 1291, 1291,
-    1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291, 1291,
-    1291, 1291, 1291, 1294, 1294, 1295, 1295, 1295, 1295, 1295, 1295, 1295, 1295, 1295, 1295, 1295,
-    1295, 1295, 1295, 1295, 1267, 1267, 1267, 1295, 1295, 1295, 1295, 1295, 1295, 1267, 1267, 1295,
-    1295, 1295, 1267, 1267, 1267, 1296, 1271, 1284, 1286, 1297, 1271, 1271, 1267, 1298, 1299, 1299,
-    1299, 1299, 1298, 1298, 1267, 1300, 1300, 1300, 1300, 1300, 1300, 1300, 1300, 1300, 1301, 1301,
-    1301, 1302, 1303, 1304, 1304, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305,
-    1305, 1306, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1306, 1305, 1305, 1305,
-    1306, 1305, 1305, 1306, 1305, 1305, 1305, 1305, 1305, 1305, 1305, 1306, 1306, 1306, 1306, 1306,
-    1306, 1306, 1306, 1306, 1306, 1307, 1307, 1307, 1307, 1307, 1307, 1307, 1307, 1307, 1307, 1307,
-    1308, 1308, 1308, 1308, 1308, 1309, 1310, 1309, 1311, 1311, 1311, 1311, 1312, 1312, 1312, 1312,
-    1312, 1312, 1312, 1312, 1312, 1312, 1312, 1312, 1312, 1311, 1311, 1311, 1313, 1313, 1313, 1313,
-    1313, 1313, 1313, 1313, 1313, 1314, 1314, 1314, 1314, 1314, 1314, 1314, 1314, 1314, 1314, 1314,
-    1314, 1314, 1315, 1315, 1315, 1315, 1316, 1316, 1316, 1316, 1316, 1316, 1316, 1316, 1316, 1316,
-    1316, 1316, 1316, 1316, 1316, 1316, 1316, 1315, 1315, 1316, 1317, 1317, 1318, 1319, 1319, 1319,
-    1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1319, 1320, 1320, 1320, 1321, 1320, 1320,
-    1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1320, 1322, 1322, 1322,
-    1322, 1322, 1322, 1322, 1322, 1322, 1322, 1322, 1322, 1322, 1323, 1324, 1324, 1325, 1325, 1325,
-    1325, 1325, 1325, 1325, 1325, 1325, 1325, 1325, 1325, 1325, 1326, 1326, 1326, 1327, 1327, 1327,
-    1327, 1327, 1327, 1327, 1327, 1327, 1328, 1328, 1328, 1328, 1328, 1328, 1328, 1328, 1328, 1328,
-    1328, 1328, 1328, 1328, 1328, 1329, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330,
-    1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1330, 1331, 1331, 1331, 1331, 1332, 1332, 1332,
-    1332, 1332, 1332, 1332, 1332, 1333, 1333, 1333, 1333, 1334, 1334, 1334, 1334, 1334, 1334, 1334,
-    1334, 1334, 1332, 1332, 1332, 1335, 1335, 1335, 1335, 1335, 1335, 1335, 1335, 1335, 1336, 1335,
-    1335, 1335, 1335, 1335, 1335, 1335, 1335, 1336, 1337, 1337, 1337, 1337, 1337, 1338, 1338, 1338,
-    1338, 1338, 1338, 1338, 1338, 1338, 1338, 1338, 1338, 1338, 1338, 1339, 1339, 1339, 1339, 1339,
-    1340, 1340, 1340, 1340, 1340, 1341, 1341, 1341, 1341, 1341, 1341, 1341, 1341, 1341, 1341, 1341,
-    1341, 1341, 1341, 1342, 1343, 1344, 1344, 1344, 1344, 1344, 1344, 1344, 1344, 1344, 1344, 1344,
-    1344, 1345, 1345, 1345, 1345, 1346, 1347, 1347, 1347, 1347, 1347, 1345, 1345, 1345, 1345, 1345,
-    1345, 1345, 1345, 1345, 1345, 1348, 1348, 1348, 1348, 1348, 1348, 1348, 1348, 1349, 1349, 1349,
-    1349, 1349, 1349, 1349, 1349, 1350, 1350, 1350, 1350, 1350, 1350, 1350, 1350, 1351, 1351, 1351,
-    1351, 1351, 1351, 1351, 1351, 1351, 1351, 1351, 1351, 1351, 1351, 1352, 1352, 1353, 1353, 1353,
-    1353, 1353, 1353, 1353, 1353, 1353, 1353, 1352, 1352, 1352, 1352, 1352, 1352, 1354, 1354, 1354,
-    1354, 1354, 1354, 1354, 1354, 1354, 1354, 1354, 1354, 1355, 1355, 1355, 1355, 1356, 1356, 1356,
-    1356, 1356, 1356, 1356, 1356, 1356, 1356, 1356, 1356, 1355, 1355, 1355, 1355, 1357, 1357, 1357,
-    1357, 1357, 1357, 1357, 1357, 1358, 1358, 1358, 1358, 1358, 1358, 1358, 1358, 1359, 1359, 1359,
-    1359, 1359, 1359, 1359, 1359, 1359, 1359, 1359, 1359, 1360, 1360, 1360, 1360, 1360, 1360, 1360,
-    1360, 1360, 1360, 1360, 1361, 1362, 1362, 1362, 1362, 1362, 1362, 1362, 1362, 1362, 1362, 1362,
-    1362, 1362, 1362, 1362, 1363, 1363, 1363, 1363, 1363, 1363, 1363, 1363, 1363, 1362, 1362, 1362,
-    1362, 1362, 1362, 1363, 1363, 1364, 1364, 1364, 1364, 1364, 1364, 1365, 1365, 1364, 1365, 1364,
-    1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364, 1364,
-    1364, 1364, 1364, 1365, 1364, 1364, 1365, 1365, 1365, 1364, 1365, 1365, 1364, 1366, 1366, 1366,
-    1366, 1366, 1366, 1366, 1366, 1366, 1366, 1366, 1366, 1366, 1366, 1367, 1368, 1369, 1369, 1369,
-    1369, 1369, 1369, 1369, 1369, 1370, 1370, 1370, 1370, 1370, 1370, 1370, 1370, 1370, 1370, 1370,
-    1370, 1370, 1370, 1370, 1371, 1371, 1372, 1372, 1372, 1372, 1372, 1372, 1372, 1373, 1373, 1373,
-    1373, 1373, 1373, 1373, 1373, 1373, 1373, 1373, 1373, 1373, 1373, 1373, 1374, 1374, 1374, 1374,
-    1374, 1374, 1374, 1374, 1375, 1375, 1375, 1375, 1375, 1375, 1375, 1375, 1375, 1376, 1376, 1376,
-    1376, 1376, 1376, 1376, 1376, 1376, 1376, 1376, 1377, 1376, 1376, 1377, 1377, 1377, 1377, 1377,
-    1378, 1378, 1378, 1378, 1378, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1379,
-    1379, 1379, 1379, 1380, 1380, 1380, 1380, 1380, 1380, 1381, 1381, 1381, 1382, 1383, 1383, 1383,
-    1383, 1383, 1383, 1383, 1383, 1383, 1383, 1384, 1384, 1384, 1384, 1384, 1385, 1386, 1386, 1386,
-    1386, 1386, 1386, 1386, 1386, 1387, 1387, 1387, 1387, 1387, 1387, 1387, 1387, 1388, 1388, 1388,
-    1388, 1389, 1389, 1387, 1387, 1389, 1389, 1389, 1389, 1389, 1389, 1389, 1389, 1388, 1388, 1389,
-    1389, 1389, 1389, 1389, 1389, 1390, 1391, 1391, 1391, 1392, 1393, 1391, 1392, 1392, 1392, 1392,
-    1392, 1394, 1395, 1396, 1397, 1390, 1390, 1390, 1390, 1392, 1390, 1390, 1390, 1392, 1390, 1390,
-    1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390,
-    1390, 1390, 1390, 1392, 1392, 1398, 1399, 1400, 1392, 1392, 1392, 1392, 1401, 1402, 1402, 1402,
-    1402, 1402, 1402, 1402, 1402, 1402, 1392, 1392, 1392, 1392, 1392, 1392, 1392, 1403, 1403, 1403,
-    1403, 1403, 1403, 1403, 1403, 1404, 1392, 1392, 1392, 1392, 1392, 1392, 1392, 1405, 1405, 1405,
-    1405, 1405, 1405, 1405, 1405, 1405, 1405, 1405, 1405, 1405, 1406, 1406, 1407, 1408, 1408, 1408,
-    1408, 1408, 1408, 1408, 1408, 1408, 1408, 1408, 1408, 1408, 1409, 1409, 1409, 1410, 1410, 1410,
-    1410, 1410, 1411, 1412, 1411, 1413, 1411, 1411, 1412, 1412, 1414, 1411, 1411, 1411, 1411, 1411,
-    1410, 1410, 1410, 1410, 1414, 1410, 1410, 1410, 1410, 1410, 1411, 1410, 1410, 1410, 1411, 1412,
-    1412, 1411, 1415, 1416, 1417, 1417, 1417, 1417, 1418, 1418, 1418, 1418, 1419, 1420, 1420, 1420,
-    1420, 1420, 1420, 1421, 1417, 1417, 1417, 1417, 1417, 1417, 1417, 1417, 1417, 1422, 1422, 1422,
-    1422, 1422, 1422, 1422, 1422, 1422, 1422, 1422, 1422, 1422, 1422, 1423, 1423, 1423, 1424, 1424,
-    1424, 1424, 1424, 1424, 1424, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425,
-    1425, 1425, 1425, 1426, 1426, 1427, 1427, 1427, 1427, 1427, 1427, 1427, 1427, 1428, 1428, 1428,
-    1428, 1428, 1428, 1428, 1428, 1428, 1428, 1428, 1429, 1429, 1429, 1429, 1429, 1430, 1430, 1430,
-    1430, 1430, 1430, 1430, 1430, 1431, 1432, 1431, 1432, 1432, 1432, 1431, 1431, 1431, 1432, 1431,
-    1431, 1432, 1431, 1432, 1432, 1431, 1432, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1434, 1434,
-    1434, 1434, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1433, 1435, 1435,
-    1435, 1435, 1436, 1436, 1437, 1438, 1438, 1438, 1438, 1438, 1438, 1438, 1438, 1438, 1439, 1439,
-    1439, 1439, 1439, 1439, 1439, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1440,
-    1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1442, 1442, 1442,
-    1442, 1442, 1442, 1442, 1442, 1442, 1442, 1442, 1441, 1441, 1441, 1441, 1441, 1441, 1441, 1443,
-    1443, 1443, 1443, 1443, 1443, 1444, 1445, 1445, 1445, 1445, 1445, 1445, 1445, 1445, 1445, 1445,
-    1445, 1445, 1445, 1445, 1445, 1445, 1445, 1446, 1445, 1447, 1447, 1447, 1448, 1449, 1449, 1449,
-    1449, 1449, 1449, 1449, 1449, 1450, 1450, 1450, 1450, 1450, 1450, 1450, 1450, 1450, 1450, 1449,
-    1449, 1449, 1449, 1449, 1449, 1451, 1451, 1451, 1451, 1451, 1451, 1451, 1451, 1451, 1451, 1451,
-    1451, 1451, 1451, 1451, 1452, 1453, 1453, 1453, 1453, 1453, 1453, 1453, 1453, 1453, 1453, 1454,
-    1455, 1455, 1456, 1454, 1454, 1453, 1453, 1454, 1454, 1454, 1454, 1454, 1454, 1454, 1454, 1454,
-    1454, 1454, 1454, 1454, 1454, 1457, 1457, 1457, 1457, 1457, 1457, 1457, 1457, 1457, 1457, 1457,
-    1457, 1457, 1458, 1458, 1458, 1458, 1458, 1458, 1458, 1458, 1458, 1458, 1457, 1459, 1459, 1459,
-    1459, 1459, 1459, 1459, 1459, 1460, 1460, 1460, 1461, 1460, 1460, 1460, 1460, 1460, 1460, 1460,
-    1460, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 1460, 1462, 1463, 1463, 1464, 1464, 1464,
-    1463, 1464, 1463, 1463, 1463, 1463, 1465, 1465, 1465, 1466, 1467, 1467, 1467, 1467, 1467, 1468,
-    1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1468, 1469, 1470, 1469,
-    1469, 1471, 1471, 1471, 1470, 1469, 1471, 1471, 1469, 1469, 1471, 1469, 1469, 1470, 1469, 1471,
-    1471, 1469, 1472, 1472, 1472, 1472, 1473, 1474, 1475, 1476, 1476, 1476, 1476, 1476, 1476, 1476,
-    1476, 1476, 1476, 1476, 1476, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477, 1477,
-    1477, 1477, 1477, 1477, 1478, 1478, 1478, 1478, 1478, 1478, 1478, 1478, 1478, 1479, 1480, 1479,
-    1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1481, 1482, 1482, 1482,
-    1482, 1483, 1483, 1483, 1483, 1483, 1483, 1482, 1482, 1482, 1482, 1484, 1485, 1485, 1486, 1486,
-    1486, 1486, 1486, 1487, 1487, 1487, 1487, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488,
-    1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1488, 1489, 1489, 1489, 1489, 1489,
-    1489, 1489, 1489, 1489, 1489, 1487, 1487, 1487, 1487, 1487, 1487, 1487, 1487, 1487, 1487, 1487,
-    1487, 1487, 1487, 1487, 1490, 1491, 1491, 1492, 1493, 1493, 1493, 1493, 1493, 1493, 1493, 1493,
-    1493, 1493, 1493, 1493, 1493, 1494, 1495, 1494, 1496, 1496, 1497, 1497, 1494, 1494, 1498, 1499,
-    1500, 1500, 1501, 1502, 1502, 1502, 1502, 1503, 1503, 1503, 1503, 1503, 1503, 1503, 1503, 1503,
-    1503, 1503, 1501, 1503, 1503, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1504, 1505, 1505,
-    1505, 1505, 1505, 1505, 1505, 1506, 1506, 1506, 1506, 1506, 1506, 1506, 1506, 1506, 1506, 1505,
-    1505, 1505, 1505, 1505, 1505, 1507, 1507, 1507, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508,
-    1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1508, 1509, 1509, 1509, 1509,
-    1509, 1510, 1509, 1511, 1511, 1509, 1509, 1509, 1512, 1513, 1514, 1515, 1515, 1515, 1515, 1515,
-    1515, 1515, 1515, 1515, 1515, 1516, 1516, 1516, 1516, 1508, 1517, 1517, 1508, 1514, 1514, 1514,
-    1514, 1514, 1514, 1514, 1514, 1518, 1518, 1518, 1518, 1518, 1518, 1518, 1518, 1518, 1518, 1518,
-    1519, 1520, 1521, 1522, 1523, 1523, 1523, 1523, 1523, 1523, 1523, 1523, 1523, 1524, 1524, 1525,
-    1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526,
-    1527, 1528, 1527, 1529, 1529, 1529, 1529, 1529, 1529, 1530, 1530, 1530, 1531, 1532, 1526, 1533,
-    1533, 1534, 1535, 1535, 1536, 1535, 1537, 1538, 1530, 1529, 1536, 1528, 1524, 1539, 1539, 1539,
-    1539, 1539, 1539, 1539, 1539, 1539, 1539, 1526, 1540, 1534, 1535, 1535, 1535, 1541, 1542, 1542,
-    1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542, 1542,
-    1542, 1542, 1541, 1541, 1541, 1541, 1541, 1541, 1541, 1541, 1541, 1541, 1541, 1543, 1543, 1543,
-    1543, 1543, 1543, 1543, 1543, 1543, 1543, 1544, 1543, 1543, 1543, 1543, 1543, 1543, 1543, 1543,
-    1543, 1545, 1545, 1545, 1546, 1547, 1547, 1548, 1548, 1549, 1550, 1551, 1552, 1553, 1553, 1554,
-    1553, 1553, 1554, 1549, 1544, 1544, 1544, 1544, 1544, 1544, 1544, 1544, 1544, 1555, 1555, 1555,
-    1555, 1555, 1555, 1555, 1556, 1555, 1556, 1555, 1555, 1555, 1555, 1556, 1555, 1555, 1555, 1555,
-    1555, 1555, 1555, 1555, 1555, 1555, 1555, 1555, 1555, 1555, 1555, 1556, 1555, 1555, 1557, 1556,
-    1556, 1556, 1556, 1556, 1556, 1558, 1558, 1558, 1558, 1558, 1558, 1558, 1558, 1558, 1558, 1558,
-    1558, 1558, 1558, 1558, 1559, 1560, 1561, 1560, 1562, 1562, 1563, 1563, 1563, 1563, 1564, 1565,
-    1566, 1566, 1566, 1566, 1566, 1567, 1567, 1567, 1567, 1567, 1567, 1567, 1567, 1567, 1567, 1566,
-    1566, 1566, 1566, 1566, 1566, 1568, 1568, 1569, 1569, 1570, 1571, 1571, 1571, 1571, 1571, 1571,
-    1571, 1571, 1570, 1570, 1571, 1571, 1570, 1570, 1571, 1571, 1571, 1571, 1571, 1571, 1571, 1571,
-    1571, 1571, 1571, 1571, 1571, 1571, 1570, 1571, 1571, 1571, 1571, 1571, 1571, 1571, 1570, 1571,
-    1571, 1570, 1571, 1571, 1571, 1571, 1571, 1570, 1572, 1573, 1571, 1574, 1575, 1576, 1575, 1575,
-    1575, 1575, 1570, 1570, 1577, 1577, 1570, 1570, 1578, 1578, 1579, 1570, 1570, 1580, 1570, 1570,
-    1570, 1570, 1570, 1570, 1574, 1570, 1570, 1570, 1570, 1570, 1580, 1571, 1571, 1571, 1571, 1575,
-    1575, 1570, 1570, 1581, 1581, 1581, 1581, 1581, 1581, 1581, 1570, 1570, 1570, 1570, 1570, 1570,
-    1570, 1570, 1570, 1570, 1570, 1582, 1582, 1582, 1582, 1582, 1582, 1582, 1582, 1582, 1582, 1582,
-    1582, 1582, 1583, 1584, 1583, 1585, 1585, 1585, 1585, 1585, 1585, 1586, 1586, 1583, 1583, 1587,
-    1588, 1588, 1589, 1590, 1582, 1591, 1591, 1591, 1592, 1592, 1592, 1592, 1593, 1594, 1594, 1594,
-    1594, 1594, 1594, 1594, 1594, 1594, 1594, 1592, 1592, 1595, 1593, 1596, 1582, 1597, 1597, 1595,
-    1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1598, 1599, 1599,
-    1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1599, 1600, 1601, 1602,
-    1603, 1603, 1603, 1603, 1603, 1603, 1601, 1604, 1605, 1605, 1600, 1605, 1606, 1606, 1607, 1608,
-    1609, 1599, 1598, 1610, 1598, 1611, 1611, 1611, 1611, 1611, 1611, 1611, 1611, 1612, 1612, 1612,
-    1612, 1612, 1612, 1612, 1612, 1612, 1612, 1611, 1611, 1611, 1611, 1611, 1611, 1613, 1613, 1613,
-    1613, 1613, 1613, 1613, 1613, 1613, 1613, 1613, 1613, 1613, 1613, 1613, 1614, 1615, 1616, 1617,
-    1617, 1617, 1617, 1618, 1618, 1615, 1616, 1619, 1619, 1620, 1620, 1621, 1622, 1623, 1624, 1625,
-    1625, 1626, 1626, 1627, 1627, 1627, 1625, 1625, 1625, 1625, 1625, 1625, 1625, 1625, 1625, 1625,
-    1625, 1625, 1625, 1625, 1625, 1613, 1613, 1613, 1613, 1617, 1617, 1618, 1618, 1618, 1618, 1618,
-    1618, 1618, 1618, 1618, 1618, 1628, 1628, 1628, 1628, 1628, 1628, 1628, 1628, 1629, 1629, 1629,
-    1630, 1630, 1630, 1630, 1630, 1630, 1631, 1631, 1629, 1629, 1632, 1633, 1634, 1631, 1635, 1635,
-    1636, 1637, 1638, 1638, 1638, 1638, 1638, 1638, 1638, 1638, 1638, 1638, 1638, 1639, 1639, 1639,
-    1639, 1639, 1639, 1639, 1639, 1639, 1639, 1638, 1638, 1638, 1638, 1638, 1638, 1640, 1640, 1640,
-    1640, 1640, 1640, 1640, 1640, 1640, 1640, 1640, 1640, 1640, 1641, 1641, 1641, 1641, 1641, 1641,
-    1641, 1641, 1641, 1641, 1641, 1642, 1642, 1642, 1642, 1642, 1642, 1642, 1642, 1642, 1642, 1642,
-    1643, 1644, 1645, 1646, 1647, 1648, 1648, 1645, 1645, 1645, 1645, 1649, 1650, 1642, 1651, 1651,
-    1651, 1651, 1651, 1651, 1651, 1652, 1652, 1652, 1652, 1652, 1652, 1652, 1652, 1652, 1652, 1651,
-    1651, 1651, 1651, 1651, 1651, 1653, 1653, 1653, 1653, 1653, 1653, 1653, 1653, 1653, 1653, 1653,
-    1654, 1654, 1655, 1656, 1657, 1658, 1658, 1659, 1659, 1660, 1660, 1661, 1659, 1660, 1659, 1659,
-    1662, 1654, 1654, 1654, 1654, 1663, 1663, 1663, 1663, 1663, 1663, 1663, 1663, 1663, 1663, 1664,
-    1664, 1665, 1665, 1665, 1666, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667, 1667,
-    1667, 1668, 1669, 1668, 1670, 1670, 1670, 1670, 1671, 1671, 1671, 1671, 1672, 1673, 1674, 1675,
-    1676, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1677, 1678, 1678, 1678,
-    1678, 1678, 1678, 1678, 1678, 1679, 1679, 1679, 1679, 1679, 1679, 1679, 1679, 1680, 1680, 1680,
-    1680, 1680, 1680, 1680, 1680, 1680, 1680, 1681, 1681, 1681, 1681, 1681, 1681, 1681, 1681, 1681,
-    1682, 1682, 1682, 1682, 1682, 1682, 1682, 1682, 1682, 1682, 1682, 1682, 1683, 1684, 1684, 1684,
-    1684, 1684, 1684, 1684, 1685, 1685, 1684, 1685, 1685, 1684, 1684, 1684, 1684, 1684, 1684, 1684,
-    1684, 1685, 1684, 1684, 1685, 1684, 1684, 1684, 1684, 1684, 1684, 1684, 1684, 1686, 1687, 1687,
-    1687, 1687, 1688, 1685, 1688, 1689, 1685, 1685, 1690, 1690, 1691, 1692, 1693, 1694, 1695, 1696,
-    1697, 1698, 1698, 1698, 1685, 1685, 1685, 1685, 1685, 1685, 1685, 1685, 1685, 1699, 1699, 1699,
-    1699, 1699, 1699, 1699, 1699, 1699, 1699, 1685, 1685, 1685, 1685, 1685, 1685, 1700, 1700, 1700,
-    1700, 1700, 1700, 1700, 1700, 1701, 1701, 1700, 1700, 1700, 1700, 1700, 1700, 1700, 1702, 1703,
-    1702, 1704, 1704, 1704, 1704, 1701, 1701, 1705, 1705, 1702, 1702, 1706, 1706, 1707, 1700, 1708,
-    1709, 1703, 1701, 1701, 1701, 1701, 1701, 1701, 1701, 1701, 1701, 1701, 1701, 1710, 1711, 1712,
-    1712, 1711, 1711, 1711, 1713, 1713, 1711, 1712, 1710, 1710, 1710, 1710, 1710, 1710, 1710, 1710,
-    1710, 1710, 1710, 1710, 1710, 1710, 1710, 1710, 1714, 1715, 1716, 1716, 1716, 1716, 1717, 1718,
-    1719, 1719, 1719, 1719, 1720, 1721, 1722, 1722, 1722, 1722, 1720, 1721, 1723, 1724, 1724, 1724,
-    1724, 1724, 1724, 1724, 1724, 1725, 1726, 1727, 1727, 1726, 1726, 1726, 1728, 1728, 1727, 1727,
-    1727, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1725,
-    1725, 1729, 1729, 1729, 1729, 1729, 1729, 1730, 1730, 1730, 1730, 1730, 1730, 1730, 1730, 1730,
-    1730, 1730, 1730, 1731, 1732, 1733, 1734, 1735, 1735, 1735, 1725, 1736, 1736, 1736, 1735, 1735,
-    1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1737, 1738, 1738, 1738,
-    1738, 1738, 1738, 1738, 1738, 1738, 1739, 1739, 1739, 1739, 1739, 1739, 1739, 1740, 1740, 1740,
-    1740, 1740, 1740, 1740, 1740, 1740, 1741, 1740, 1740, 1740, 1740, 1740, 1740, 1740, 1740, 1740,
-    1740, 1740, 1740, 1740, 1742, 1743, 1743, 1744, 1744, 1744, 1744, 1744, 1741, 1743, 1743, 1743,
-    1743, 1745, 1745, 1746, 1747, 1740, 1748, 1748, 1748, 1748, 1748, 1741, 1741, 1741, 1741, 1741,
-    1741, 1741, 1741, 1741, 1741, 1749, 1749, 1749, 1749, 1749, 1749, 1749, 1749, 1749, 1749, 1750,
-    1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750, 1750,
-    1750, 1750, 1741, 1741, 1741, 1751, 1752, 1753, 1753, 1753, 1753, 1753, 1753, 1753, 1753, 1753,
-    1753, 1753, 1753, 1753, 1753, 1754, 1754, 1755, 1755, 1755, 1755, 1755, 1755, 1755, 1755, 1755,
-    1755, 1755, 1755, 1755, 1755, 1754, 1756, 1755, 1755, 1755, 1755, 1755, 1755, 1757, 1758, 1757,
-    1759, 1760, 1761, 1761, 1754, 1754, 1754, 1754, 1754, 1754, 1754, 1754, 1754, 1762, 1762, 1762,
-    1762, 1762, 1762, 1762, 1763, 1762, 1762, 1763, 1762, 1762, 1762, 1762, 1762, 1762, 1762, 1762,
-    1762, 1762, 1762, 1762, 1762, 1762, 1764, 1764, 1764, 1764, 1764, 1765, 1763, 1763, 1763, 1764,
-    1763, 1764, 1764, 1763, 1764, 1766, 1766, 1767, 1764, 1768, 1769, 1770, 1771, 1763, 1763, 1763,
-    1763, 1763, 1763, 1763, 1763, 1772, 1772, 1772, 1772, 1772, 1772, 1772, 1772, 1772, 1772, 1763,
-    1763, 1763, 1763, 1763, 1763, 1773, 1773, 1773, 1773, 1773, 1773, 1774, 1773, 1773, 1774, 1773,
-    1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1773, 1775,
-    1775, 1775, 1775, 1775, 1774, 1776, 1776, 1774, 1775, 1775, 1777, 1778, 1779, 1780, 1774, 1774,
-    1774, 1774, 1774, 1774, 1774, 1781, 1781, 1781, 1781, 1781, 1781, 1781, 1781, 1781, 1781, 1774,
-    1774, 1774, 1774, 1774, 1774, 1782, 1782, 1782, 1782, 1782, 1782, 1782, 1782, 1782, 1782, 1783,
-    1784, 1785, 1786, 1787, 1788, 1788, 1789, 1789, 1789, 1789, 1789, 1789, 1789, 1790, 1791, 1791,
-    1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1791, 1792, 1792, 1792,
-    1792, 1792, 1792, 1792, 1792, 1792, 1792, 1792, 1792, 1792, 1793, 1793, 1793, 1793, 1793, 1793,
-    1793, 1793, 1794, 1794, 1794, 1794, 1793, 1793, 1793, 1793, 1793, 1793, 1793, 1793, 1793, 1793,
-    1793, 1793, 1793, 1793, 1793, 1793, 1793, 1795, 1795, 1795, 1795, 1795, 1795, 1795, 1795, 1795,
-    1795, 1795, 1795, 1795, 1796, 1797, 1797, 1797, 1797, 1797, 1797, 1797, 1797, 1797, 1797, 1798,
-    1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1798, 1799, 1799, 1799,
-    1799, 1799, 1799, 1799, 1799, 1799, 1799, 1799, 1799, 1799, 1799, 1799, 1800, 1801, 1801, 1801,
-    1801, 1801, 1800, 1800, 1800, 1800, 1800, 1800, 1800, 1800, 1800, 1800, 1800, 1802, 1802, 1802,
-    1802, 1802, 1802, 1802, 1802, 1802, 1802, 1802, 1802, 1803, 1803, 1803, 1803, 1803, 1803, 1803,
-    1803, 1803, 1803, 1803, 1803, 1804, 1804, 1804, 1804, 1804, 1804, 1804, 1804, 1805, 1805, 1805,
-    1806, 1806, 1806, 1804, 1804, 1804, 1804, 1806, 1804, 1804, 1804, 1805, 1806, 1805, 1806, 1804,
-    1804, 1804, 1804, 1804, 1804, 1804, 1805, 1806, 1806, 1804, 1804, 1804, 1804, 1804, 1804, 1804,
-    1804, 1804, 1804, 1804, 1807, 1808, 1808, 1808, 1808, 1808, 1808, 1808, 1809, 1810, 1811, 1811,
-    1811, 1811, 1811, 1811, 1811, 1812, 1812, 1812, 1812, 1812, 1812, 1812, 1812, 1812, 1812, 1812,
-    1812, 1812, 1812, 1813, 1814, 1812, 1812, 1812, 1812, 1812, 1812, 1812, 1815, 1815, 1815, 1815,
-    1815, 1815, 1815, 1815, 1815, 1816, 1816, 1816, 1816, 1816, 1816, 1816, 1816, 1816, 1817, 1817,
-    1817, 1817, 1817, 1817, 1817, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818, 1818,
-    1818, 1818, 1818, 1818, 1819, 1820, 1820, 1820, 1820, 1820, 1820, 1820, 1820, 1820, 1820, 1819,
-    1819, 1819, 1819, 1821, 1821, 1822, 1822, 1822, 1822, 1822, 1822, 1822, 1822, 1822, 1822, 1822,
-    1822, 1822, 1822, 1823, 1823, 1824, 1824, 1824, 1824, 1824, 1825, 1823, 1823, 1823, 1823, 1823,
-    1823, 1823, 1823, 1823, 1823, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1826, 1827, 1827, 1827,
-    1827, 1827, 1827, 1827, 1828, 1828, 1828, 1829, 1829, 1830, 1830, 1830, 1830, 1831, 1831, 1831,
-    1831, 1828, 1830, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1832, 1833, 1833, 1833,
-    1833, 1833, 1833, 1833, 1833, 1833, 1833, 1832, 1834, 1834, 1834, 1834, 1834, 1834, 1834, 1832,
-    1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1832, 1832, 1832,
-    1832, 1832, 1835, 1835, 1835, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1836, 1837, 1837, 1837,
-    1837, 1837, 1837, 1837, 1837, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838, 1838,
-    1838, 1838, 1838, 1838, 1839, 1839, 1840, 1840, 1841, 1841, 1841, 1841, 1841, 1842, 1842, 1842,
-    1842, 1842, 1842, 1842, 1842, 1842, 1842, 1842, 1842, 1842, 1843, 1843, 1843, 1843, 1843, 1843,
-    1844, 1844, 1844, 1844, 1845, 1842, 1846, 1846, 1846, 1846, 1846, 1846, 1846, 1846, 1846, 1846,
-    1846, 1846, 1846, 1846, 1846, 1844, 1844, 1844, 1844, 1844, 1844, 1844, 1847, 1847, 1847, 1847,
-    1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1848, 1849, 1850, 1851,
-    1852, 1853, 1854, 1854, 1854, 1854, 1854, 1854, 1854, 1854, 1854, 1854, 1854, 1855, 1855, 1854,
-    1854, 1854, 1854, 1854, 1854, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1856, 1857, 1857, 1857,
-    1857, 1857, 1857, 1857, 1857, 1858, 1858, 1858, 1858, 1858, 1858, 1858, 1858, 1859, 1859, 1859,
-    1859, 1859, 1859, 1859, 1859, 1859, 1859, 1859, 1859, 1859, 1859, 1860, 1860, 1860, 1860, 1860,
-    1860, 1860, 1860, 1860, 1860, 1861, 1861, 1861, 1861, 1861, 1861, 1861, 1861, 1861, 1862, 1862,
-    1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1862, 1863, 1864, 1864,
-    1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1864, 1865, 1865, 1865,
-    1865, 1865, 1865, 1865, 1865, 1865, 1865, 1865, 1865, 1865, 1865, 1865, 1866, 1866, 1866, 1866,
-    1866, 1866, 1866, 1866, 1866, 1867, 1867, 1867, 1867, 1867, 1867, 1867, 1867, 1868, 1868, 1868,
-    1867, 1867, 1867, 1867, 1867, 1867, 1867, 1867, 1867, 1869, 1869, 1869, 1869, 1870, 1870, 1870,
-    1870, 1870, 1870, 1870, 1870, 1870, 1870, 1870, 1870, 1871, 1871, 1871, 1871, 1872, 1872, 1872,
-    1872, 1872, 1872, 1872, 1872, 1872, 1872, 1872, 1873, 1873, 1873, 1873, 1873, 1872, 1872, 1872,
-    1872, 1872, 1873, 1873, 1873, 1872, 1873, 1873, 1873, 1873, 1873, 1873, 1873, 1872, 1872, 1874,
-    1874, 1875, 1876, 1877, 1878, 1879, 1879, 1879, 1879, 1880, 1880, 1880, 1880, 1880, 1880, 1880,
-    1880, 1880, 1880, 1880, 1880, 1881, 1881, 1881, 1881, 1881, 1881, 1881, 1881, 1881, 1881, 1881,
-    1881, 1881, 1881, 1882, 1882, 1882, 1882, 1882, 1882, 1882, 1882, 1882, 1882, 1883, 1883, 1883,
-    1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1884, 1884, 1883, 1883,
-    1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1883, 1885, 1886, 1887, 1887, 1887, 1883,
-    1883, 1883, 1888, 1885, 1885, 1885, 1885, 1885, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 1889,
-    1890, 1890, 1890, 1890, 1890, 1890, 1890, 1890, 1883, 1883, 1891, 1891, 1891, 1891, 1891, 1890,
-    1890, 1883, 1883, 1883, 1883, 1883, 1883, 1891, 1891, 1891, 1891, 1883, 1883, 1883, 1884, 1884,
-    1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1884, 1892, 1892, 1892,
-    1892, 1892, 1892, 1892, 1892, 1892, 1892, 1893, 1893, 1893, 1892, 1894, 1894, 1894, 1894, 1894,
-    1894, 1894, 1894, 1894, 1894, 1895, 1895, 1895, 1895, 1895, 1895, 1895, 1895, 1895, 1895, 1895,
-    1895, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1896, 1897, 1897, 1897,
-    1897, 1897, 1897, 1897, 1897, 1897, 1897, 1897, 1897, 1897, 1897, 1897, 1898, 1898, 1898, 1898,
-    1898, 1898, 1898, 1898, 1898, 1899, 1899, 1899, 1899, 1899, 1899, 1899, 1899, 1899, 1900, 1900,
-    1900, 1900, 1900, 1900, 1900, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1902,
-    1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902,
-    1902, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1902, 1902, 1902, 1902, 1902,
-    1902, 1902, 1903, 1902, 1902, 1902, 1902, 1902, 1902, 1901, 1903, 1901, 1901, 1903, 1903, 1901,
-    1903, 1903, 1901, 1901, 1903, 1903, 1901, 1901, 1901, 1901, 1903, 1901, 1901, 1902, 1902, 1903,
-    1902, 1903, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1903, 1902, 1902, 1902, 1902, 1902, 1902,
-    1902, 1901, 1901, 1903, 1901, 1901, 1901, 1901, 1903, 1903, 1901, 1901, 1901, 1901, 1901, 1901,
-    1901, 1901, 1903, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1903, 1902, 1902, 1901, 1901, 1903,
-    1901, 1901, 1901, 1901, 1903, 1901, 1901, 1901, 1901, 1901, 1903, 1901, 1903, 1903, 1903, 1901,
-    1901, 1901, 1901, 1901, 1901, 1901, 1903, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902,
-    1902, 1902, 1902, 1903, 1903, 1901, 1904, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902,
-    1905, 1902, 1902, 1902, 1902, 1902, 1902, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901,
-    1904, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1905, 1902, 1902, 1901, 1901, 1901,
-    1901, 1901, 1904, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1905, 1902, 1902, 1902,
-    1902, 1902, 1902, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1901, 1904, 1902, 1905, 1902,
-    1902, 1902, 1902, 1902, 1902, 1902, 1902, 1901, 1902, 1903, 1903, 1906, 1906, 1906, 1906, 1906,
-    1906, 1906, 1906, 1906, 1906, 1907, 1907, 1907, 1907, 1907, 1907, 1907, 1907, 1908, 1908, 1908,
-    1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1907, 1907, 1907, 1907,
-    1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1907, 1907, 1907, 1907, 1907, 1907,
-    1907, 1907, 1908, 1907, 1907, 1907, 1907, 1907, 1907, 1908, 1907, 1907, 1909, 1909, 1909, 1909,
-    1910, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911, 1911,
-    1908, 1908, 1908, 1908, 1908, 1911, 1908, 1908, 1908, 1908, 1908, 1908, 1908, 1912, 1912, 1912,
-    1912, 1912, 1912, 1912, 1913, 1912, 1912, 1912, 1912, 1912, 1912, 1912, 1912, 1912, 1913, 1913,
-    1912, 1912, 1912, 1912, 1912, 1912, 1912, 1913, 1912, 1912, 1913, 1912, 1912, 1912, 1912, 1912,
-    1913, 1913, 1913, 1913, 1913, 1914, 1914, 1914, 1914, 1914, 1914, 1914, 1914, 1914, 1914, 1914,
-    1914, 1914, 1915, 1915, 1915, 1916, 1916, 1916, 1916, 1916, 1916, 1916, 1917, 1917, 1917, 1917,
-    1917, 1917, 1917, 1915, 1915, 1918, 1918, 1918, 1918, 1918, 1918, 1918, 1918, 1918, 1918, 1915,
-    1915, 1915, 1915, 1914, 1919, 1920, 1920, 1920, 1920, 1920, 1920, 1920, 1920, 1920, 1920, 1920,
-    1920, 1921, 1921, 1921, 1921, 1922, 1922, 1922, 1922, 1922, 1922, 1922, 1922, 1922, 1922, 1923,
-    1923, 1923, 1923, 1923, 1924, 1925, 1925, 1925, 1925, 1925, 1925, 1925, 1925, 1925, 1925, 1925,
-    1925, 1925, 1926, 1926, 1927, 1927, 1927, 1927, 1927, 1927, 1927, 1927, 1927, 1928, 1928, 1928,
-    1928, 1928, 1928, 1928, 1926, 1926, 1926, 1926, 1926, 1926, 1926, 1926, 1926, 1929, 1929, 1929,
-    1929, 1929, 1929, 1929, 1929, 1929, 1929, 1930, 1930, 1930, 1930, 1930, 1930, 1930, 1930, 1930,
-    1930, 1930, 1930, 1930, 1930, 1930, 1930, 1930, 1930, 1931, 1931, 1931, 1931, 1931, 1931, 1932,
-    1933, 1934, 1934, 1934, 1934, 1935, 1935, 1935, 1935, 1935, 1935, 1935, 1935, 1935, 1935, 1934,
-    1934, 1934, 1934, 1936, 1936, 1937, 1938, 1938, 1938, 1938, 1938, 1938, 1938, 1938, 1938, 1938,
-    1938, 1938, 1938, 1938, 1938, 1938, 1938, 1938, 1938, 1939, 1938, 1938, 1938, 1940, 1938, 1938,
-    1938, 1938, 1937, 1937, 1937, 1937, 1937, 1937, 1937, 1937, 1937, 1937, 1937, 1941, 1942, 1942,
-    1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1942,
-    1942, 1942, 1942, 1943, 1942, 1942, 1942, 1942, 1942, 1942, 1942, 1941, 1941, 1941, 1941, 1941,
-    1941, 1941, 1941, 1941, 1941, 1944, 1944, 1944, 1944, 1945, 1944, 1944, 1944, 1944, 1944, 1944,
-    1944, 1944, 1944, 1944, 1944, 1945, 1944, 1944, 1945, 1944, 1945, 1945, 1944, 1945, 1944, 1944,
-    1944, 1944, 1944, 1944, 1944, 1944, 1944, 1944, 1945, 1944, 1944, 1944, 1944, 1945, 1944, 1945,
-    1944, 1945, 1945, 1945, 1945, 1945, 1945, 1944, 1945, 1945, 1945, 1945, 1944, 1945, 1944, 1945,
-    1944, 1945, 1944, 1944, 1944, 1945, 1944, 1945, 1944, 1945, 1944, 1945, 1944, 1945, 1944, 1944,
-    1944, 1944, 1945, 1944, 1945, 1944, 1944, 1945, 1944, 1944, 1944, 1944, 1944, 1944, 1944, 1944,
-    1944, 1945, 1945, 1945, 1945, 1945, 1944, 1944, 1944, 1945, 1944, 1944, 1944, 1945, 1945, 1945,
-    1945, 1945, 1945, 1945, 1945, 1946, 1946, 1945, 1945, 1945, 1945, 1945, 1945, 1947, 1947, 1947,
-    1947, 1948, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947,
-    1947, 1949, 1949, 1949, 1949, 1950, 1950, 1950, 1950, 1950, 1950, 1950, 1950, 1950, 1950, 1950,
-    1950, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1951, 1952, 1952, 1952,
-    1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1953, 1953, 1952, 1952,
-    1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1952, 1954, 1952, 1952, 1952,
-    1952, 1952, 1952, 1953, 1953, 1953, 1953, 1953, 1953, 1953, 1953, 1953, 1953, 1955, 1955, 1955,
-    1955, 1955, 1955, 1955, 1955, 1955, 1955, 1955, 1956, 1956, 1957, 1957, 1957, 1958, 1958, 1958,
-    1958, 1958, 1958, 1958, 1958, 1958, 1958, 1958, 1958, 1958, 1958, 1959, 1960, 1961, 1961, 1961,
-    1961, 1961, 1961, 1961, 1961, 1961, 1961, 1958, 1958, 1958, 1958, 1958, 1958, 1961, 1961, 1962,
-    1962, 1960, 1957, 1957, 1957, 1963, 1963, 1961, 1961, 1961, 1961, 1961, 1961, 1961, 1961, 1961,
-    1961, 1961, 1961, 1963, 1963, 1961, 1961, 1958, 1958, 1958, 1958, 1964, 1958, 1958, 1964, 1964,
-    1964, 1964, 1964, 1964, 1964, 1964, 1964, 1964, 1958, 1958, 1958, 1958, 1958, 1958, 1958, 1958,
-    1958, 1958, 1957, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965, 1965,
-    1965, 1965, 1965, 1966, 1966, 1966, 1966, 1966, 1966, 1966, 1966, 1966, 1966, 1967, 1968, 1968,
-    1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1970, 1970, 1970,
-    1970, 1970, 1970, 1970, 1970, 1970, 1970, 1968, 1970, 1970, 1970, 1970, 1970, 1970, 1970, 1970,
-    1970, 1970, 1970, 1970, 1968, 1970, 1970, 1968, 1968, 1968, 1968, 1968, 1968, 1968, 1968, 1968,
-    1970, 1969, 1969, 1969, 1969, 1970, 1969, 1969, 1969, 1969, 1969, 1969, 1969, 1968, 1968, 1969,
-    1969, 1969, 1969, 1969, 1969, 1971, 1971, 1971, 1971, 1971, 1971, 1969, 1969, 1972, 1972, 1972,
-    1972, 1972, 1972, 1972, 1972, 1972, 1972, 1973, 1973, 1972, 1972, 1972, 1972, 1972, 1972, 1972,
-    1972, 1972, 1974, 1972, 1972, 1972, 1972, 1972, 1972, 1973, 1973, 1972, 1972, 1973, 1972, 1972,
-    1972, 1975, 1975, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1976, 1976, 1972, 1972, 1972, 1972,
-    1972, 1976, 1972, 1972, 1972, 1972, 1972, 1974, 1974, 1974, 1972, 1972, 1974, 1972, 1972, 1974,
-    1974, 1974, 1972, 1972, 1972, 1972, 1973, 1973, 1972, 1972, 1972, 1973, 1972, 1972, 1972, 1972,
-    1977, 1977, 1977, 1977, 1977, 1972, 1972, 1974, 1974, 1972, 1972, 1974, 1974, 1974, 1974, 1974,
-    1974, 1974, 1974, 1974, 1974, 1974, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1972,
-    1972, 1972, 1972, 1974, 1974, 1974, 1972, 1972, 1972, 1974, 1972, 1972, 1972, 1972, 1974, 1974,
-    1974, 1972, 1974, 1974, 1974, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1974, 1972, 1974, 1972,
-    1972, 1972, 1972, 1972, 1972, 1976, 1972, 1976, 1972, 1976, 1972, 1972, 1972, 1972, 1972, 1974,
-    1972, 1972, 1972, 1972, 1976, 1972, 1976, 1976, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1972,
-    1972, 1972, 1972, 1973, 1972, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1972, 1972, 1972, 1972,
-    1972, 1972, 1972, 1972, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976,
-    1976, 1976, 1972, 1972, 1972, 1972, 1972, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976, 1976,
-    1976, 1976, 1976, 1978, 1978, 1978, 1978, 1978, 1978, 1978, 1978, 1975, 1975, 1975, 1976, 1972,
-    1972, 1972, 1972, 1972, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1972, 1972, 1973, 1973,
-    1972, 1974, 1974, 1972, 1972, 1972, 1972, 1974, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1972,
-    1972, 1972, 1972, 1973, 1973, 1974, 1973, 1973, 1973, 1973, 1974, 1974, 1973, 1973, 1973, 1973,
-    1973, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1972, 1972, 1973, 1973, 1972, 1973, 1973,
-    1973, 1973, 1973, 1973, 1973, 1973, 1972, 1972, 1973, 1973, 1973, 1973, 1973, 1973, 1973, 1973,
-    1973, 1972, 1973, 1973, 1973, 1973, 1973, 1972, 1972, 1972, 1973, 1973, 1973, 1973, 1972, 1972,
-    1972, 1975, 1975, 1975, 1975, 1975, 1975, 1975, 1975, 1972, 1972, 1972, 1973, 1973, 1972, 1973,
-    1972, 1973, 1973, 1973, 1973, 1972, 1973, 1973, 1973, 1973, 1973, 1973, 1972, 1973, 1973, 1973,
-    1972, 1975, 1975, 1975, 1975, 1975, 1975, 1972, 1972, 1972, 1972, 1972, 1972, 1979, 1979, 1979,
-    1979, 1979, 1979, 1979, 1979, 1979, 1979, 1979, 1979, 1979, 1980, 1980, 1980, 1979, 1979, 1979,
-    1980, 1980, 1980, 1980, 1980, 1981, 1981, 1981, 1981, 1981, 1981, 1981, 1981, 1981, 1981, 1981,
-    1981, 1981, 1981, 1982, 1982, 1982, 1983, 1983, 1983, 1981, 1981, 1981, 1981, 1984, 1984, 1984,
-    1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1985, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1985, 1985, 1985, 1984, 1985, 1984, 1984, 1984, 1984, 1984, 1986, 1986, 1986, 1986, 1986,
-    1984, 1985, 1984, 1984, 1984, 1984, 1984, 1984, 1986, 1986, 1984, 1984, 1984, 1987, 1987, 1987,
-    1987, 1987, 1987, 1987, 1987, 1984, 1984, 1984, 1984, 1984, 1984, 1986, 1986, 1986, 1984, 1986,
-    1984, 1984, 1987, 1987, 1987, 1984, 1986, 1986, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1984,
-    1984, 1984, 1987, 1987, 1987, 1988, 1988, 1988, 1988, 1988, 1988, 1988, 1988, 1988, 1988, 1988,
-    1988, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1989, 1990, 1990, 1990,
-    1990, 1990, 1990, 1990, 1990, 1990, 1990, 1990, 1990, 1990, 1991, 1991, 1991, 1991, 1992, 1992,
-    1992, 1992, 1992, 1992, 1992, 1993, 1993, 1993, 1993, 1993, 1993, 1993, 1993, 1993, 1993, 1993,
-    1993, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1992, 1994, 1994, 1994,
-    1994, 1994, 1994, 1994, 1994, 1994, 1994, 1994, 1994, 1995, 1995, 1995, 1995, 1995, 1995, 1995,
-    1995, 1995, 1995, 1995, 1995, 1994, 1994, 1995, 1995, 1995, 1995, 1995, 1995, 1994, 1994, 1994,
-    1994, 1994, 1994, 1995, 1995, 1996, 1996, 1995, 1995, 1995, 1995, 1995, 1995, 1997, 1997, 1997,
-    1997, 1997, 1997, 1997, 1997, 1997, 1997, 1997, 1997, 1998, 1999, 1999, 1998, 1999, 1999, 1999,
-    1999, 1999, 1999, 1999, 1999, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1999, 1999, 1999,
-    1999, 1999, 1999, 1998, 1999, 1998, 1998, 1999, 2000, 1998, 1998, 1998, 1999, 1999, 1999, 1999,
-    1999, 1999, 1999, 2000, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1998, 1999, 2001, 1999,
-    1999, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1998, 1998, 1999, 1998, 1998, 1999,
-    1998, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 1999, 2001, 1998, 1998, 1998, 1999, 1998, 1998,
-    1998, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1998, 1999, 1999, 2002, 2002, 2002,
-    2002, 2002, 2002, 2002, 2002, 2002, 2002, 2002, 2002, 2003, 2003, 2003, 2003, 2003, 2003, 2003,
-    2003, 2003, 2003, 2003, 2003, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2004,
-    2004, 2004, 2004, 2003, 2003, 2005, 2005, 2005, 2005, 2005, 2006, 2006, 2006, 2005, 2005, 2005,
-    2006, 2006, 2006, 2006, 2006, 2005, 2005, 2005, 2005, 2005, 2005, 2005, 2006, 2006, 2006, 2006,
-    2006, 2006, 2006, 2006, 2006, 2005, 2005, 2005, 2005, 2005, 2005, 2005, 2005, 2005, 2006, 2006,
-    2006, 2006, 2006, 2006, 2006, 2007, 2007, 2007, 2007, 2007, 2007, 2007, 2007, 2007, 2007, 2007,
-    2008, 2007, 2007, 2007, 2007, 2007, 2007, 2007, 2008, 2008, 2008, 2008, 2008, 2008, 2008, 2008,
-    2008, 2008, 2008, 2008, 2008, 2009, 2009, 2009, 2009, 2009, 2009, 2009, 2009, 2009, 2009, 2008,
-    2008, 2008, 2008, 2008, 2008, 2010, 2010, 2010, 2010, 2010, 2010, 2010, 2010, 2010, 2010, 2010,
-    2010, 2010, 2010, 232, 232, 2011, 2011, 2011, 2011, 2011, 2011, 2011, 2011, 2011, 2011, 2011,
-    2011, 2011, 2011, 2012, 2012, 2013, 2013, 2013, 2013, 2013, 2013, 2013, 2013, 2014, 2014, 2014,
-    2014, 2014, 2014, 2014, 2014, 2014, 2014, 2014, 2014, 2014, 2015, 2015, 2015, 2015, 2015, 2015,
-    2015, 2015, 2015, 2015, 2015, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016, 2016,
-    2016, 2016, 2016, 2017, 2017, 2018, 2018, 2018, 2018, 2018, 2018, 2018, 2018, 2018, 2018, 2019,
-    2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2020, 2020, 2020,
-    2020, 2020, 2020, 2020, 2020, 2020, 2021, 2021, 2021, 2021, 2021, 2021, 2021, 2021, 2021, 2021,
-    2021, 2021, 2021, 2021, 2021, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2022,
-    2022, 2022, 2022, 2023, 2023, 2013, 2013, 2013, 2013, 2013, 2013, 232, 232, 2024, 2024, 2024,
-    2024, 2024, 2024, 2024, 2024, 2024, 2024, 2024, 2025, 2025, 2025, 2025, 2025, 2026, 2027, 2026,
-    2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2028, 2028, 2028,
-    2028, 2028, 2028, 2028, 2028, 2029, 2029, 2029, 2029, 2029, 2029, 2029, 2029, 2030, 2030, 2030,
-    2030, 2030, 2030, 2030, 2030, 2031, 2031, 2031, 2031, 2031, 2031, 2031, 2031, 2031, 2031, 2031,
-    2031, 2031, 2031, 2032, 2032, 2033, 2033, 2033, 2033, 2033, 2033, 2033, 2033, 2033, 2033, 2033,
-    2033, 2033, 2033, 2034, 2034,
+const RECORD_INDEX2: [u16; 12261] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4, 5, 0, 0, 0, 0, 6, 6, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    16, 17, 18, 19, 20, 21, 22, 23, 24, 24, 24, 24, 24, 24, 24, 24, 25, 26, 27, 28, 29, 9, 14, 30,
+    30, 30, 30, 30, 30, 30, 30, 31, 32, 33, 34, 35, 36, 37, 37, 37, 37, 37, 37, 37, 37, 31, 38, 39,
+    28, 0, 40, 40, 40, 40, 40, 41, 40, 40, 40, 40, 40, 40, 40, 40, 42, 43, 44, 45, 45, 45, 46, 47,
+    48, 49, 50, 51, 52, 53, 49, 54, 55, 56, 57, 57, 58, 59, 47, 60, 48, 61, 50, 62, 63, 63, 63, 43,
+    64, 64, 64, 64, 64, 64, 65, 64, 64, 64, 64, 64, 64, 64, 64, 65, 64, 64, 64, 64, 64, 64, 66, 65,
+    64, 64, 64, 64, 64, 65, 67, 68, 68, 68, 68, 68, 68, 67, 68, 68, 68, 68, 68, 68, 68, 68, 67, 68,
+    68, 68, 68, 68, 68, 69, 67, 68, 68, 68, 68, 68, 67, 68, 70, 71, 70, 71, 70, 71, 70, 71, 72, 73,
+    70, 71, 70, 71, 70, 71, 70, 73, 74, 75, 70, 71, 70, 71, 73, 70, 71, 70, 71, 70, 71, 74, 75, 72,
+    73, 70, 71, 70, 71, 70, 71, 76, 72, 73, 70, 71, 70, 71, 70, 70, 71, 70, 71, 70, 71, 75, 77, 78,
+    78, 77, 78, 77, 78, 78, 78, 77, 77, 78, 78, 77, 78, 78, 77, 78, 78, 77, 77, 77, 78, 78, 77, 78,
+    79, 80, 78, 77, 78, 77, 78, 78, 77, 78, 77, 77, 78, 77, 78, 79, 80, 78, 78, 78, 77, 78, 77, 78,
+    77, 77, 81, 78, 77, 77, 77, 81, 81, 81, 81, 82, 83, 84, 82, 83, 84, 79, 80, 79, 80, 79, 80, 79,
+    80, 79, 80, 77, 79, 80, 79, 80, 78, 77, 79, 80, 82, 83, 84, 79, 80, 78, 78, 77, 78, 77, 78, 77,
+    79, 80, 79, 80, 77, 77, 77, 77, 78, 77, 78, 78, 78, 78, 77, 78, 77, 78, 77, 78, 77, 85, 85, 85,
+    85, 85, 85, 85, 85, 86, 85, 85, 85, 87, 87, 87, 87, 87, 87, 87, 87, 88, 88, 89, 89, 89, 89, 89,
+    90, 90, 91, 91, 91, 91, 88, 92, 93, 92, 92, 92, 93, 92, 88, 88, 94, 89, 91, 91, 91, 91, 91, 91,
+    95, 95, 95, 95, 96, 95, 91, 97, 87, 87, 87, 87, 87, 91, 91, 91, 98, 98, 88, 91, 89, 91, 91, 91,
+    91, 91, 91, 91, 91, 99, 99, 99, 99, 99, 99, 99, 99, 100, 101, 101, 100, 102, 101, 101, 101,
+    101, 103, 103, 101, 101, 101, 101, 103, 101, 101, 101, 101, 101, 101, 101, 104, 104, 104, 104,
+    101, 101, 101, 101, 99, 99, 99, 105, 105, 99, 105, 105, 106, 99, 101, 101, 99, 99, 99, 101,
+    101, 107, 99, 99, 99, 101, 101, 101, 101, 99, 100, 101, 101, 99, 108, 109, 109, 108, 99, 99,
+    99, 99, 99, 110, 111, 110, 111, 112, 113, 110, 111, 114, 114, 115, 111, 111, 111, 116, 110,
+    114, 114, 114, 114, 117, 118, 119, 120, 119, 119, 119, 114, 119, 114, 119, 119, 121, 110, 110,
+    110, 110, 110, 110, 110, 110, 114, 110, 110, 110, 110, 110, 119, 119, 121, 121, 121, 121, 111,
+    111, 111, 111, 111, 111, 111, 111, 121, 121, 121, 121, 121, 110, 122, 122, 123, 119, 119, 122,
+    124, 111, 110, 111, 110, 111, 110, 111, 110, 111, 125, 126, 125, 126, 125, 126, 125, 126, 122,
+    122, 124, 111, 123, 122, 127, 110, 111, 128, 110, 111, 111, 110, 110, 110, 129, 129, 130, 129,
+    130, 130, 130, 129, 130, 130, 130, 130, 129, 129, 129, 130, 130, 130, 130, 130, 130, 130, 130,
+    129, 130, 130, 130, 130, 130, 130, 131, 131, 131, 131, 131, 131, 131, 131, 132, 131, 131, 131,
+    131, 131, 131, 132, 132, 131, 132, 131, 131, 131, 132, 131, 131, 131, 131, 132, 132, 132, 131,
+    130, 131, 130, 131, 130, 131, 130, 131, 129, 132, 130, 131, 133, 134, 134, 135, 135, 134, 136,
+    136, 130, 131, 130, 131, 130, 131, 130, 129, 132, 130, 131, 130, 131, 130, 131, 130, 131, 131,
+    129, 132, 129, 132, 130, 131, 129, 132, 129, 132, 129, 132, 130, 131, 137, 138, 137, 138, 137,
+    138, 137, 138, 139, 140, 140, 140, 140, 140, 140, 140, 140, 139, 141, 142, 142, 142, 143, 142,
+    144, 145, 145, 145, 145, 145, 145, 145, 145, 146, 145, 147, 148, 139, 139, 149, 149, 150, 151,
+    152, 153, 153, 153, 153, 152, 153, 153, 154, 152, 153, 153, 153, 153, 152, 152, 152, 152, 152,
+    152, 153, 153, 152, 153, 153, 154, 155, 153, 156, 157, 158, 159, 160, 161, 162, 163, 164, 165,
+    165, 166, 167, 168, 169, 170, 171, 172, 173, 171, 174, 175, 176, 164, 151, 151, 151, 151, 151,
+    151, 151, 151, 177, 177, 177, 177, 177, 177, 177, 177, 151, 151, 151, 151, 177, 177, 177, 178,
+    179, 151, 151, 151, 180, 180, 180, 180, 180, 181, 182, 182, 183, 184, 184, 185, 186, 187, 188,
+    188, 189, 189, 189, 189, 189, 189, 189, 189, 190, 191, 192, 193, 194, 195, 196, 193, 197, 198,
+    199, 199, 199, 199, 200, 201, 197, 201, 197, 197, 197, 197, 197, 201, 201, 201, 197, 197, 197,
+    197, 197, 197, 197, 197, 202, 197, 197, 197, 197, 197, 197, 197, 201, 197, 197, 203, 204, 205,
+    206, 207, 208, 209, 210, 211, 211, 212, 213, 189, 214, 189, 189, 189, 213, 189, 189, 213, 215,
+    215, 215, 215, 215, 215, 215, 215, 184, 216, 217, 218, 197, 197, 219, 201, 201, 220, 198, 221,
+    221, 221, 222, 197, 197, 197, 197, 197, 197, 197, 201, 201, 201, 201, 201, 201, 201, 201, 199,
+    197, 200, 201, 201, 201, 201, 201, 197, 201, 197, 201, 197, 197, 201, 199, 196, 201, 189, 189,
+    189, 189, 189, 181, 188, 214, 189, 189, 213, 189, 223, 223, 189, 188, 224, 214, 214, 213, 201,
+    201, 225, 225, 225, 225, 225, 225, 225, 225, 197, 197, 197, 226, 226, 197, 227, 227, 227, 227,
+    227, 227, 227, 227, 228, 229, 230, 231, 232, 232, 232, 233, 233, 234, 234, 232, 232, 232, 232,
+    234, 232, 232, 232, 232, 232, 232, 232, 232, 234, 232, 233, 232, 234, 232, 232, 233, 235, 236,
+    235, 235, 236, 235, 235, 236, 236, 235, 236, 236, 235, 236, 235, 237, 237, 238, 237, 238, 237,
+    238, 237, 237, 228, 228, 234, 232, 232, 239, 239, 239, 239, 239, 239, 239, 239, 240, 240, 240,
+    239, 239, 239, 239, 240, 240, 239, 239, 239, 240, 239, 240, 240, 239, 239, 239, 239, 239, 239,
+    241, 241, 241, 241, 241, 241, 241, 241, 242, 242, 242, 242, 242, 242, 242, 242, 241, 243, 243,
+    243, 243, 243, 243, 243, 243, 244, 244, 244, 244, 244, 244, 244, 244, 245, 245, 245, 245, 245,
+    245, 245, 245, 246, 246, 246, 246, 246, 247, 246, 248, 248, 249, 250, 251, 252, 253, 254, 254,
+    255, 256, 256, 257, 257, 257, 257, 257, 257, 257, 257, 258, 258, 259, 259, 260, 258, 258, 258,
+    258, 258, 260, 258, 258, 258, 258, 259, 261, 261, 262, 262, 262, 262, 262, 262, 262, 262, 261,
+    263, 264, 264, 264, 264, 264, 263, 263, 264, 263, 264, 264, 264, 264, 264, 264, 263, 264, 263,
+    263, 265, 265, 265, 266, 266, 267, 266, 268, 269, 268, 268, 268, 268, 269, 270, 268, 270, 270,
+    271, 271, 271, 271, 271, 272, 272, 272, 272, 272, 272, 272, 272, 273, 273, 273, 273, 273, 273,
+    273, 273, 274, 274, 274, 275, 274, 273, 274, 274, 273, 273, 276, 273, 273, 274, 273, 273, 273,
+    273, 273, 273, 276, 276, 276, 276, 276, 276, 276, 276, 277, 278, 278, 278, 278, 278, 278, 278,
+    278, 279, 279, 280, 281, 278, 278, 281, 278, 281, 279, 279, 279, 277, 277, 277, 282, 283, 284,
+    278, 278, 278, 281, 278, 281, 281, 278, 278, 278, 278, 278, 285, 285, 285, 286, 287, 287, 287,
+    287, 287, 287, 287, 287, 288, 287, 287, 287, 287, 287, 287, 288, 287, 287, 288, 287, 287, 287,
+    289, 290, 291, 287, 290, 292, 290, 293, 293, 293, 293, 289, 289, 289, 290, 290, 290, 290, 294,
+    292, 290, 295, 296, 297, 298, 298, 289, 293, 293, 288, 288, 288, 288, 288, 288, 288, 288, 287,
+    287, 293, 293, 299, 299, 300, 300, 300, 300, 300, 300, 300, 300, 301, 302, 287, 287, 287, 287,
+    287, 287, 303, 304, 305, 305, 306, 307, 307, 307, 307, 307, 306, 306, 307, 307, 307, 307, 307,
+    307, 307, 307, 306, 307, 307, 307, 307, 307, 307, 306, 307, 306, 306, 306, 307, 307, 306, 306,
+    308, 307, 309, 310, 311, 312, 312, 312, 312, 306, 306, 310, 306, 306, 313, 313, 314, 315, 306,
+    306, 306, 306, 306, 306, 306, 309, 306, 306, 306, 306, 316, 316, 306, 316, 307, 307, 312, 312,
+    306, 306, 317, 317, 317, 317, 317, 317, 317, 317, 307, 307, 318, 318, 319, 319, 319, 319, 320,
+    321, 322, 307, 323, 324, 306, 325, 326, 326, 327, 325, 328, 328, 328, 325, 325, 325, 325, 328,
+    325, 325, 328, 328, 328, 328, 328, 328, 328, 328, 325, 328, 328, 328, 328, 328, 328, 325, 328,
+    329, 325, 328, 329, 325, 328, 328, 325, 325, 330, 325, 331, 332, 331, 333, 333, 325, 325, 325,
+    325, 334, 325, 325, 334, 334, 335, 325, 325, 336, 325, 325, 325, 325, 325, 325, 329, 329, 329,
+    328, 325, 329, 325, 325, 325, 325, 325, 325, 337, 337, 337, 337, 337, 337, 337, 337, 326, 338,
+    339, 339, 340, 341, 342, 325, 325, 325, 325, 325, 325, 325, 325, 343, 344, 344, 345, 343, 346,
+    346, 346, 346, 346, 346, 343, 346, 346, 346, 346, 346, 346, 346, 346, 343, 346, 346, 343, 346,
+    346, 346, 343, 343, 347, 346, 348, 349, 348, 350, 350, 350, 350, 351, 343, 351, 352, 343, 348,
+    348, 353, 343, 343, 354, 343, 343, 343, 343, 343, 343, 343, 343, 346, 346, 350, 350, 343, 343,
+    355, 355, 355, 355, 355, 355, 355, 355, 356, 357, 343, 343, 343, 343, 343, 343, 346, 344, 344,
+    344, 358, 358, 358, 359, 360, 361, 361, 359, 362, 362, 362, 362, 362, 359, 359, 362, 362, 362,
+    362, 362, 362, 362, 362, 359, 362, 362, 362, 362, 362, 362, 359, 362, 362, 359, 362, 362, 362,
+    359, 359, 363, 362, 364, 365, 366, 367, 367, 367, 367, 359, 359, 368, 369, 359, 359, 369, 369,
+    370, 359, 359, 359, 359, 359, 371, 365, 372, 359, 359, 359, 359, 373, 373, 359, 362, 362, 367,
+    367, 359, 359, 374, 374, 374, 374, 374, 374, 374, 374, 375, 362, 376, 376, 376, 376, 376, 376,
+    359, 359, 359, 359, 359, 359, 359, 359, 377, 377, 378, 379, 377, 380, 380, 380, 377, 377, 377,
+    380, 380, 377, 380, 380, 381, 380, 377, 377, 380, 380, 377, 380, 377, 380, 380, 377, 377, 377,
+    380, 380, 377, 377, 377, 380, 380, 380, 380, 380, 380, 380, 380, 377, 377, 377, 377, 382, 383,
+    384, 383, 383, 377, 377, 377, 385, 385, 377, 386, 386, 386, 387, 377, 377, 388, 377, 377, 377,
+    377, 377, 377, 382, 377, 377, 377, 377, 377, 377, 377, 377, 389, 389, 389, 389, 389, 389, 389,
+    389, 390, 390, 390, 391, 391, 391, 391, 391, 392, 391, 377, 377, 377, 377, 377, 393, 394, 394,
+    394, 395, 396, 396, 396, 396, 396, 397, 396, 396, 396, 396, 396, 396, 396, 396, 397, 397, 397,
+    396, 398, 398, 399, 399, 399, 399, 397, 398, 398, 400, 397, 398, 398, 398, 401, 397, 397, 397,
+    397, 397, 402, 403, 397, 396, 396, 396, 397, 397, 397, 397, 397, 396, 396, 404, 404, 397, 397,
+    405, 405, 405, 405, 405, 405, 405, 405, 397, 397, 397, 397, 397, 397, 397, 406, 407, 407, 407,
+    407, 407, 407, 407, 408, 409, 410, 411, 411, 412, 409, 409, 409, 409, 409, 413, 409, 409, 409,
+    409, 409, 409, 409, 409, 413, 413, 414, 409, 415, 416, 417, 415, 418, 415, 415, 413, 416, 417,
+    413, 417, 417, 419, 420, 413, 413, 413, 413, 413, 418, 418, 413, 413, 413, 413, 413, 413, 409,
+    413, 409, 409, 421, 421, 413, 413, 422, 422, 422, 422, 422, 422, 422, 422, 413, 423, 423, 413,
+    413, 413, 413, 413, 413, 413, 413, 424, 424, 425, 425, 426, 426, 426, 426, 426, 427, 426, 426,
+    426, 426, 426, 426, 426, 426, 428, 428, 426, 429, 430, 431, 431, 432, 432, 427, 433, 433, 427,
+    434, 434, 434, 435, 436, 437, 427, 427, 427, 427, 438, 438, 438, 429, 439, 439, 439, 439, 439,
+    439, 439, 426, 426, 432, 432, 427, 427, 440, 440, 440, 440, 440, 440, 440, 440, 439, 439, 439,
+    439, 439, 439, 439, 439, 441, 438, 438, 438, 438, 438, 438, 442, 443, 444, 444, 442, 445, 445,
+    445, 445, 445, 445, 445, 445, 442, 442, 445, 445, 445, 445, 445, 445, 442, 445, 445, 445, 445,
+    445, 442, 445, 442, 442, 446, 442, 442, 442, 442, 447, 448, 448, 449, 449, 450, 442, 450, 442,
+    448, 451, 452, 451, 452, 452, 452, 447, 442, 442, 442, 442, 442, 442, 453, 453, 453, 453, 453,
+    453, 453, 453, 442, 442, 448, 448, 454, 442, 442, 442, 442, 442, 442, 442, 442, 455, 456, 456,
+    456, 456, 456, 456, 456, 456, 457, 456, 458, 456, 459, 458, 458, 458, 458, 460, 460, 461, 455,
+    455, 455, 455, 462, 456, 456, 456, 456, 456, 456, 463, 464, 465, 465, 465, 465, 466, 467, 464,
+    468, 469, 469, 469, 469, 469, 469, 469, 469, 470, 470, 455, 455, 455, 455, 455, 455, 455, 455,
+    471, 472, 472, 471, 472, 471, 472, 472, 472, 471, 472, 472, 472, 472, 472, 472, 472, 472, 471,
+    472, 471, 472, 472, 472, 472, 472, 472, 472, 473, 472, 474, 472, 475, 474, 474, 474, 474, 476,
+    476, 477, 474, 478, 472, 471, 471, 472, 472, 472, 472, 472, 471, 479, 471, 480, 480, 480, 480,
+    481, 482, 471, 471, 483, 483, 483, 483, 483, 483, 483, 483, 471, 471, 484, 484, 472, 472, 471,
+    471, 471, 471, 471, 471, 471, 471, 485, 486, 487, 487, 488, 489, 488, 490, 491, 490, 490, 492,
+    493, 494, 494, 494, 491, 495, 494, 495, 495, 495, 496, 496, 495, 495, 495, 495, 495, 495, 497,
+    497, 497, 497, 497, 497, 497, 497, 498, 498, 498, 498, 498, 498, 499, 500, 495, 500, 495, 501,
+    502, 503, 502, 503, 504, 504, 485, 485, 485, 505, 485, 485, 485, 485, 506, 485, 485, 485, 485,
+    505, 485, 485, 485, 485, 505, 485, 485, 485, 485, 485, 485, 485, 485, 505, 485, 485, 485, 506,
+    506, 506, 507, 508, 509, 510, 511, 509, 512, 508, 508, 508, 508, 513, 514, 508, 509, 515, 515,
+    516, 492, 515, 515, 485, 485, 485, 485, 485, 517, 517, 517, 518, 517, 517, 517, 517, 506, 517,
+    517, 517, 517, 518, 517, 517, 517, 517, 518, 517, 517, 517, 517, 517, 517, 517, 517, 518, 517,
+    517, 517, 506, 499, 499, 495, 495, 495, 495, 495, 495, 500, 495, 495, 495, 495, 495, 506, 495,
+    495, 490, 490, 492, 490, 519, 520, 520, 520, 491, 491, 506, 506, 506, 506, 506, 506, 506, 506,
+    521, 521, 521, 521, 521, 521, 521, 521, 522, 522, 522, 522, 522, 523, 522, 522, 522, 524, 524,
+    525, 525, 526, 527, 528, 525, 525, 525, 529, 530, 531, 532, 533, 534, 535, 536, 537, 521, 538,
+    539, 539, 539, 539, 539, 539, 539, 540, 541, 542, 542, 543, 544, 521, 521, 522, 522, 522, 522,
+    545, 545, 526, 526, 521, 521, 521, 521, 546, 546, 537, 521, 524, 547, 547, 521, 521, 524, 547,
+    547, 547, 547, 547, 521, 521, 525, 525, 525, 525, 521, 521, 521, 536, 524, 527, 525, 525, 531,
+    531, 531, 531, 531, 548, 521, 531, 539, 539, 539, 539, 539, 539, 539, 539, 531, 531, 549, 525,
+    550, 550, 551, 551, 551, 551, 551, 551, 551, 551, 552, 551, 552, 552, 552, 552, 552, 551, 552,
+    552, 553, 553, 553, 553, 553, 553, 553, 553, 554, 555, 553, 553, 553, 556, 556, 556, 556, 556,
+    556, 556, 556, 557, 558, 559, 559, 559, 559, 559, 559, 559, 559, 560, 560, 560, 560, 560, 560,
+    560, 560, 561, 561, 561, 561, 561, 561, 561, 561, 562, 561, 561, 561, 561, 562, 562, 563, 563,
+    563, 564, 565, 564, 564, 564, 564, 564, 564, 566, 566, 566, 566, 566, 566, 566, 566, 562, 562,
+    562, 567, 567, 567, 567, 567, 567, 567, 567, 568, 568, 568, 568, 568, 568, 568, 568, 569, 569,
+    569, 569, 569, 569, 570, 570, 570, 570, 570, 570, 570, 570, 571, 571, 572, 572, 572, 572, 572,
+    572, 571, 571, 573, 574, 574, 574, 574, 574, 574, 574, 574, 575, 576, 574, 577, 578, 578, 578,
+    578, 578, 578, 578, 578, 579, 580, 581, 581, 581, 582, 582, 582, 582, 582, 582, 582, 582, 583,
+    583, 583, 584, 584, 582, 582, 582, 582, 582, 582, 582, 585, 585, 585, 585, 585, 585, 585, 586,
+    586, 586, 586, 586, 586, 586, 586, 587, 586, 586, 588, 589, 590, 587, 587, 587, 587, 587, 587,
+    587, 587, 591, 591, 591, 591, 591, 591, 591, 591, 592, 593, 594, 595, 595, 596, 596, 596, 596,
+    596, 596, 596, 596, 597, 597, 597, 597, 597, 597, 597, 597, 598, 599, 600, 600, 600, 600, 600,
+    600, 600, 600, 601, 601, 601, 601, 601, 601, 601, 601, 602, 601, 601, 602, 603, 604, 602, 602,
+    602, 602, 602, 602, 602, 602, 605, 605, 605, 605, 605, 605, 605, 605, 606, 606, 605, 605, 605,
+    605, 607, 607, 608, 609, 609, 609, 610, 610, 610, 608, 608, 611, 611, 611, 608, 608, 612, 613,
+    608, 614, 614, 615, 616, 617, 615, 614, 615, 618, 619, 615, 620, 620, 621, 622, 620, 623, 620,
+    624, 605, 625, 626, 626, 627, 627, 627, 627, 627, 627, 627, 627, 626, 626, 626, 626, 626, 626,
+    628, 628, 628, 628, 628, 628, 628, 628, 626, 626, 626, 626, 626, 626, 629, 630, 631, 631, 632,
+    633, 634, 635, 636, 636, 635, 637, 637, 637, 638, 639, 640, 640, 640, 640, 640, 640, 640, 640,
+    641, 641, 641, 641, 641, 641, 642, 642, 642, 642, 642, 642, 642, 642, 643, 644, 644, 644, 644,
+    644, 644, 644, 644, 641, 641, 641, 641, 641, 641, 641, 645, 645, 645, 645, 645, 646, 646, 644,
+    647, 644, 641, 641, 641, 641, 641, 648, 648, 648, 648, 648, 648, 648, 648, 649, 649, 649, 649,
+    649, 649, 649, 649, 650, 651, 651, 651, 651, 651, 651, 651, 651, 652, 653, 653, 654, 655, 655,
+    656, 656, 653, 657, 657, 657, 652, 652, 652, 652, 658, 658, 659, 658, 658, 658, 658, 658, 660,
+    661, 662, 652, 652, 652, 652, 663, 652, 652, 652, 664, 664, 665, 665, 665, 665, 665, 665, 665,
+    665, 666, 666, 666, 666, 666, 666, 666, 666, 667, 667, 667, 667, 667, 667, 667, 667, 668, 668,
+    668, 668, 668, 668, 668, 668, 669, 669, 669, 669, 670, 670, 669, 669, 669, 669, 669, 669, 671,
+    671, 671, 671, 671, 671, 671, 671, 672, 669, 669, 669, 673, 673, 674, 674, 674, 674, 674, 674,
+    674, 674, 675, 675, 675, 675, 675, 675, 675, 675, 676, 677, 678, 679, 680, 681, 681, 682, 682,
+    683, 683, 683, 683, 683, 683, 683, 683, 684, 685, 686, 687, 687, 688, 689, 689, 689, 689, 690,
+    691, 692, 693, 692, 692, 693, 693, 693, 694, 694, 693, 694, 695, 696, 696, 696, 693, 697, 698,
+    698, 698, 699, 698, 698, 690, 690, 700, 701, 701, 701, 701, 701, 701, 701, 701, 690, 690, 690,
+    690, 690, 690, 702, 702, 702, 702, 702, 702, 702, 703, 702, 702, 702, 702, 702, 702, 690, 690,
+    704, 704, 704, 704, 704, 705, 705, 705, 704, 704, 705, 706, 707, 708, 708, 708, 708, 708, 708,
+    708, 708, 709, 709, 709, 710, 711, 712, 713, 712, 713, 712, 713, 712, 713, 712, 712, 713, 712,
+    712, 712, 712, 712, 712, 712, 712, 714, 715, 716, 716, 717, 717, 717, 718, 716, 719, 720, 720,
+    721, 721, 716, 719, 722, 712, 712, 712, 712, 723, 723, 723, 723, 724, 724, 724, 724, 724, 724,
+    724, 724, 725, 725, 726, 725, 725, 725, 727, 727, 727, 727, 727, 727, 727, 728, 729, 728, 728,
+    728, 728, 727, 727, 727, 727, 727, 723, 723, 723, 730, 731, 732, 733, 733, 733, 733, 733, 733,
+    733, 733, 734, 735, 735, 736, 737, 738, 739, 736, 736, 740, 741, 735, 735, 733, 733, 742, 742,
+    742, 742, 742, 742, 742, 742, 733, 733, 733, 733, 733, 733, 743, 743, 743, 743, 743, 743, 743,
+    743, 744, 745, 746, 746, 745, 745, 745, 746, 745, 746, 747, 747, 748, 748, 749, 749, 749, 749,
+    750, 750, 750, 750, 751, 751, 751, 751, 751, 751, 751, 751, 752, 752, 753, 754, 754, 753, 753,
+    755, 756, 756, 756, 756, 757, 757, 758, 759, 760, 760, 760, 761, 761, 761, 761, 761, 762, 762,
+    762, 762, 762, 762, 762, 762, 760, 760, 760, 751, 751, 751, 763, 763, 763, 763, 763, 763, 763,
+    763, 764, 764, 764, 764, 764, 764, 764, 764, 765, 765, 765, 765, 765, 765, 766, 766, 767, 767,
+    767, 767, 767, 767, 767, 767, 768, 768, 768, 768, 768, 768, 768, 769, 769, 769, 769, 769, 769,
+    769, 769, 770, 770, 769, 769, 769, 771, 771, 771, 771, 771, 771, 771, 771, 772, 772, 772, 772,
+    772, 772, 772, 772, 773, 773, 773, 774, 775, 776, 776, 776, 773, 773, 776, 776, 776, 776, 773,
+    777, 778, 778, 778, 778, 778, 778, 779, 779, 779, 779, 780, 779, 779, 781, 781, 773, 782, 782,
+    777, 783, 783, 784, 785, 785, 785, 785, 785, 786, 786, 786, 786, 786, 786, 786, 786, 787, 787,
+    787, 788, 789, 789, 789, 790, 789, 789, 789, 789, 789, 789, 789, 789, 790, 789, 789, 789, 789,
+    789, 791, 791, 791, 792, 792, 792, 792, 793, 793, 793, 786, 786, 786, 786, 786, 794, 786, 786,
+    786, 786, 786, 786, 786, 795, 795, 795, 795, 795, 795, 795, 795, 796, 796, 796, 796, 796, 796,
+    796, 796, 797, 798, 798, 799, 798, 798, 798, 798, 798, 799, 798, 798, 800, 801, 799, 802, 798,
+    798, 798, 798, 798, 798, 798, 798, 803, 803, 803, 803, 803, 803, 803, 803, 798, 804, 805, 799,
+    806, 807, 808, 799, 798, 799, 809, 810, 809, 810, 809, 810, 809, 810, 810, 810, 811, 810, 812,
+    812, 813, 812, 809, 810, 813, 812, 813, 812, 813, 812, 814, 814, 814, 814, 814, 814, 814, 814,
+    815, 815, 815, 815, 815, 815, 815, 815, 814, 814, 814, 814, 814, 814, 816, 816, 815, 815, 815,
+    815, 815, 815, 816, 816, 815, 816, 815, 816, 815, 816, 815, 817, 817, 817, 817, 817, 817, 817,
+    817, 814, 814, 814, 814, 814, 816, 814, 814, 815, 815, 815, 815, 817, 818, 814, 818, 819, 814,
+    814, 814, 816, 814, 814, 815, 815, 815, 815, 817, 819, 819, 819, 814, 814, 814, 814, 816, 816,
+    814, 814, 815, 815, 815, 815, 816, 819, 819, 819, 815, 815, 815, 815, 815, 819, 819, 819, 816,
+    816, 814, 814, 814, 816, 814, 814, 815, 815, 815, 815, 817, 820, 818, 816, 821, 821, 822, 822,
+    822, 822, 822, 823, 822, 822, 822, 824, 825, 826, 827, 828, 829, 830, 831, 831, 832, 833, 834,
+    835, 836, 837, 838, 839, 839, 840, 838, 839, 841, 841, 842, 843, 844, 845, 845, 846, 847, 848,
+    849, 850, 851, 852, 853, 854, 855, 855, 856, 857, 857, 858, 859, 859, 843, 860, 861, 841, 862,
+    863, 835, 864, 865, 843, 843, 843, 866, 867, 868, 869, 862, 843, 843, 843, 843, 843, 843, 870,
+    843, 864, 843, 871, 835, 871, 871, 871, 871, 843, 871, 871, 822, 872, 873, 873, 873, 873, 874,
+    875, 876, 877, 878, 879, 879, 879, 879, 879, 879, 880, 881, 882, 882, 883, 880, 880, 880, 884,
+    884, 885, 886, 887, 888, 889, 890, 891, 891, 891, 889, 889, 889, 892, 892, 893, 894, 895, 882,
+    896, 896, 896, 896, 896, 896, 896, 896, 882, 882, 882, 897, 897, 897, 897, 897, 897, 897, 898,
+    899, 897, 897, 897, 897, 897, 897, 897, 898, 897, 897, 898, 897, 900, 900, 900, 900, 900, 900,
+    900, 900, 901, 901, 902, 902, 901, 901, 901, 901, 902, 902, 902, 901, 901, 903, 903, 903, 901,
+    903, 903, 903, 902, 902, 904, 905, 904, 906, 902, 907, 907, 907, 907, 908, 909, 909, 909, 909,
+    909, 909, 909, 909, 910, 910, 911, 912, 913, 914, 910, 915, 913, 912, 916, 911, 911, 911, 916,
+    916, 911, 911, 911, 917, 913, 911, 918, 913, 919, 911, 911, 911, 911, 911, 913, 913, 920, 914,
+    921, 913, 911, 913, 922, 913, 911, 923, 924, 925, 911, 911, 926, 916, 911, 911, 927, 911, 916,
+    928, 928, 928, 929, 913, 910, 916, 916, 911, 911, 930, 919, 919, 919, 919, 911, 916, 916, 913,
+    919, 913, 913, 931, 932, 933, 933, 933, 933, 934, 934, 933, 933, 933, 934, 933, 933, 934, 933,
+    935, 935, 935, 935, 935, 935, 935, 935, 936, 936, 936, 936, 936, 936, 937, 937, 937, 938, 939,
+    937, 937, 937, 934, 940, 940, 941, 941, 941, 941, 942, 942, 942, 942, 943, 944, 944, 944, 945,
+    945, 946, 946, 946, 946, 947, 946, 946, 947, 946, 946, 947, 946, 948, 949, 949, 946, 946, 946,
+    945, 948, 946, 946, 948, 948, 948, 948, 946, 946, 946, 946, 946, 946, 946, 946, 950, 945, 945,
+    946, 946, 942, 946, 942, 946, 946, 946, 946, 948, 946, 948, 948, 948, 948, 946, 946, 948, 948,
+    948, 948, 948, 948, 948, 948, 947, 947, 947, 947, 947, 947, 947, 947, 951, 952, 951, 951, 953,
+    952, 952, 951, 954, 955, 956, 957, 958, 959, 952, 951, 960, 961, 952, 962, 952, 952, 951, 952,
+    952, 951, 951, 963, 964, 965, 966, 951, 967, 951, 953, 951, 951, 951, 951, 968, 969, 951, 969,
+    952, 952, 952, 951, 951, 951, 951, 952, 952, 952, 952, 970, 971, 952, 952, 953, 952, 972, 953,
+    973, 952, 953, 951, 953, 952, 952, 974, 952, 952, 952, 970, 975, 976, 975, 952, 952, 952, 952,
+    952, 952, 952, 952, 977, 951, 953, 952, 970, 971, 970, 971, 976, 975, 970, 971, 952, 953, 978,
+    979, 980, 981, 976, 975, 980, 981, 976, 975, 976, 975, 976, 975, 980, 981, 970, 971, 980, 981,
+    970, 971, 980, 981, 976, 975, 952, 952, 952, 976, 975, 976, 975, 952, 952, 951, 952, 952, 982,
+    951, 952, 952, 952, 952, 952, 952, 976, 975, 952, 951, 983, 952, 984, 985, 952, 985, 953, 953,
+    953, 953, 976, 975, 976, 975, 976, 975, 976, 975, 986, 952, 952, 952, 952, 952, 952, 951, 952,
+    976, 975, 976, 975, 987, 952, 952, 976, 975, 952, 952, 952, 952, 976, 975, 980, 981, 980, 981,
+    976, 975, 976, 975, 980, 981, 980, 981, 952, 988, 976, 975, 989, 989, 989, 952, 973, 973, 952,
+    952, 990, 990, 990, 991, 991, 952, 992, 992, 992, 992, 992, 992, 992, 992, 993, 994, 993, 994,
+    992, 992, 992, 992, 995, 992, 992, 992, 992, 992, 996, 996, 992, 992, 992, 992, 997, 997, 992,
+    992, 992, 992, 992, 992, 998, 999, 1000, 992, 992, 992, 992, 992, 992, 1001, 1001, 1001, 1001,
+    1001, 1001, 1001, 1001, 992, 997, 992, 992, 992, 1002, 992, 992, 992, 992, 992, 992, 992, 1001,
+    992, 992, 992, 997, 997, 997, 997, 997, 997, 997, 997, 1003, 1003, 992, 1003, 992, 992, 992,
+    992, 992, 992, 992, 998, 992, 992, 992, 992, 997, 997, 997, 997, 1003, 992, 992, 992, 992, 992,
+    998, 998, 998, 998, 998, 998, 998, 996, 996, 996, 996, 992, 992, 992, 992, 998, 998, 998, 992,
+    992, 992, 992, 992, 1004, 1004, 1004, 1004, 1004, 1004, 1004, 1004, 1005, 1005, 1005, 1005,
+    1005, 1005, 1005, 1005, 1006, 1006, 1006, 1006, 1006, 1006, 1006, 1006, 1007, 1007, 1007, 1007,
+    1007, 1007, 1007, 1007, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1008, 1009, 1009, 1009, 1009,
+    1009, 1009, 1009, 1009, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1010, 1011, 1011, 1011, 1011,
+    1011, 1011, 1011, 1011, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1012, 1013, 1012, 1012, 1012,
+    1012, 1012, 1008, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1014, 1015, 1016, 1016, 1016, 1016,
+    1016, 1016, 1016, 1016, 1017, 1017, 1017, 1017, 1017, 1017, 1017, 1017, 1018, 1018, 1018, 1018,
+    1018, 1018, 1018, 1018, 1019, 1019, 1018, 1018, 1018, 1018, 1019, 1019, 1019, 1019, 1019, 1019,
+    1019, 1019, 1020, 1020, 1021, 1022, 1022, 1022, 1022, 1022, 1023, 1023, 1021, 1021, 1024, 1024,
+    1020, 1020, 1024, 1024, 1025, 1026, 1021, 1021, 1021, 1021, 1020, 1020, 1024, 1024, 1022, 1021,
+    1024, 1020, 1027, 1021, 1022, 1020, 1020, 1024, 1024, 1021, 1021, 1021, 1021, 1021, 1021, 1021,
+    1021, 1020, 1022, 1020, 1022, 1021, 1024, 1024, 1024, 1024, 1024, 1021, 1021, 1022, 1028, 1028,
+    1028, 1029, 1029, 1029, 1029, 1028, 1030, 1030, 1030, 1030, 1031, 1032, 1033, 1034, 1035, 1034,
+    1034, 1034, 1034, 1036, 1035, 1034, 1031, 1034, 1037, 1030, 1030, 1035, 1035, 1030, 1034, 1038,
+    1038, 1038, 1039, 1038, 1038, 1031, 1034, 1031, 1031, 1034, 1034, 1031, 1034, 1034, 1034, 1031,
+    1031, 1034, 1034, 1034, 1034, 1034, 1034, 1034, 1034, 1031, 1030, 1030, 1038, 1034, 1034, 1034,
+    1034, 1040, 1034, 1040, 1034, 1034, 1034, 1034, 1034, 1031, 1031, 1031, 1031, 1031, 1031, 1031,
+    1031, 1034, 1034, 1034, 1034, 1040, 1032, 1041, 1040, 1035, 1036, 1031, 1035, 1030, 1035, 1035,
+    1034, 1035, 1032, 1041, 1042, 1034, 1034, 1034, 1031, 1034, 1034, 1031, 1030, 1034, 1034, 1034,
+    1034, 1034, 1034, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1037, 1034, 1031, 1034, 1031, 1031,
+    1034, 1035, 1035, 1031, 1031, 1034, 1034, 1034, 1034, 1034, 1031, 1031, 1043, 1034, 1034, 1034,
+    1034, 1034, 1030, 1030, 1038, 1038, 1038, 1038, 1030, 1030, 1038, 1038, 1030, 1035, 1035, 1035,
+    1035, 1038, 1031, 1030, 1038, 1030, 1035, 1030, 1030, 1035, 1035, 1035, 1038, 1038, 1035, 1035,
+    1038, 1035, 1035, 1038, 1038, 1034, 1035, 1034, 1034, 1034, 1034, 1035, 1036, 1030, 1035, 1035,
+    1035, 1035, 1035, 1036, 1030, 1030, 1030, 1030, 1030, 1035, 1030, 1039, 1030, 1035, 1035, 1030,
+    1038, 1038, 1044, 1044, 1045, 1044, 1044, 1046, 1047, 1047, 1045, 1045, 1048, 1048, 1048, 1048,
+    1049, 1046, 1049, 1049, 1046, 1047, 1046, 1047, 1046, 1047, 1047, 1047, 1047, 1047, 1046, 1047,
+    1047, 1047, 1047, 1047, 1047, 1047, 1046, 1046, 1047, 1047, 1047, 1047, 1047, 1047, 1047, 1047,
+    1046, 1047, 1047, 1046, 1047, 1047, 1047, 1047, 1046, 1047, 1046, 1047, 1047, 1047, 1046, 1046,
+    1046, 1047, 1050, 1047, 1047, 1047, 1051, 1051, 1051, 1051, 1051, 1047, 1052, 1053, 1045, 1049,
+    1049, 1049, 1054, 1055, 1054, 1055, 1054, 1055, 1054, 1055, 1056, 1056, 1056, 1056, 1056, 1056,
+    1056, 1056, 1047, 1046, 1046, 1046, 1057, 1057, 1057, 1058, 1059, 1060, 1061, 1057, 1058, 1059,
+    1057, 1062, 1057, 1063, 1057, 1057, 1057, 1057, 1057, 1058, 1059, 1057, 1057, 1057, 1057, 1064,
+    1058, 1059, 1057, 1057, 1058, 1059, 1058, 1059, 1060, 1061, 1060, 1061, 1060, 1061, 1060, 1061,
+    1065, 1065, 1065, 1065, 1065, 1065, 1065, 1065, 1066, 1066, 1066, 1066, 1066, 1066, 1066, 1066,
+    1067, 1067, 1067, 1067, 1067, 1067, 1067, 1067, 1068, 1068, 1067, 1067, 1069, 1069, 1069, 1070,
+    1071, 1070, 1071, 1070, 1071, 1072, 1073, 1074, 1075, 1070, 1071, 1070, 1071, 1070, 1071, 1070,
+    1071, 1069, 1069, 1076, 1069, 1069, 1069, 1069, 1077, 1069, 1069, 1078, 1079, 1080, 1069, 1069,
+    1079, 1080, 1079, 1080, 1079, 1080, 1079, 1080, 1069, 1069, 1069, 1069, 1069, 1069, 1069, 1069,
+    1081, 1069, 1069, 1069, 1069, 1069, 1069, 1069, 1079, 1080, 1069, 1069, 1079, 1080, 1069, 1069,
+    1080, 1079, 1080, 1069, 1079, 1080, 1069, 1069, 1070, 1071, 1070, 1071, 1069, 1069, 1069, 1069,
+    1069, 1082, 1069, 1069, 1079, 1080, 1069, 1069, 1070, 1071, 1069, 1069, 1083, 1083, 1083, 1083,
+    1083, 1083, 1083, 1083, 1084, 1083, 1083, 1083, 1085, 1086, 1085, 1086, 1083, 1083, 1083, 1083,
+    1085, 1086, 1083, 1083, 1083, 1083, 1084, 1084, 1084, 1083, 1085, 1086, 1085, 1086, 1085, 1086,
+    1085, 1086, 1085, 1086, 1083, 1083, 1083, 1085, 1086, 1085, 1086, 1085, 1086, 1083, 1085, 1083,
+    1083, 1083, 1083, 1087, 1083, 1088, 1083, 1083, 1083, 1089, 1090, 1089, 1083, 1083, 1083, 1083,
+    1085, 1086, 1091, 1083, 1083, 1083, 1083, 1083, 1083, 1083, 1085, 1086, 1085, 1086, 1083, 1083,
+    1083, 1083, 1083, 1092, 1092, 1092, 1092, 1092, 1093, 1093, 1093, 1092, 1092, 1092, 1092, 1092,
+    1092, 1092, 1092, 1093, 1093, 1092, 1092, 1092, 1094, 1094, 1094, 1094, 1094, 1094, 1094, 1094,
+    1092, 1092, 1094, 1094, 1094, 1094, 1094, 1092, 1092, 1092, 1093, 1092, 1092, 1092, 1092, 1095,
+    1096, 1096, 1092, 1092, 1092, 1092, 1092, 1092, 1097, 1097, 1092, 1092, 1092, 1092, 1092, 1092,
+    1097, 1092, 1092, 1092, 1092, 1092, 1092, 1098, 1092, 1099, 1099, 1099, 1099, 1099, 1099, 1099,
+    1099, 1100, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1101, 1100, 1102, 1103, 1102, 1102, 1102,
+    1103, 1103, 1102, 1103, 1102, 1103, 1102, 1102, 1102, 1103, 1102, 1103, 1103, 1102, 1103, 1103,
+    1103, 1103, 1104, 1105, 1102, 1102, 1106, 1107, 1106, 1107, 1106, 1107, 1106, 1107, 1107, 1108,
+    1108, 1108, 1106, 1107, 1106, 1107, 1109, 1109, 1106, 1107, 1110, 1110, 1110, 1110, 1111, 1112,
+    1112, 1112, 1113, 1111, 1112, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1114, 1115, 1114, 1115,
+    1115, 1115, 1115, 1115, 1114, 1115, 1115, 1116, 1116, 1116, 1116, 1116, 1116, 1116, 1116, 1117,
+    1117, 1117, 1117, 1117, 1117, 1117, 1118, 1119, 1117, 1117, 1117, 1117, 1117, 1117, 1117, 1120,
+    1121, 1121, 1121, 1121, 1121, 1121, 1121, 1121, 1122, 1122, 1122, 1122, 1122, 1122, 1122, 1122,
+    1123, 1123, 1123, 1123, 1123, 1123, 1123, 1123, 1124, 1124, 1125, 1126, 1125, 1126, 1124, 1124,
+    1125, 1126, 1124, 1125, 1126, 1127, 1127, 1127, 1127, 1127, 1127, 1128, 1129, 1130, 1127, 1131,
+    1128, 1125, 1126, 1128, 1128, 1125, 1126, 1132, 1133, 1132, 1133, 1132, 1133, 1127, 1127, 1127,
+    1127, 1134, 1135, 1127, 1127, 1128, 1127, 1127, 1128, 1128, 1128, 1136, 1136, 1127, 1127, 1127,
+    1128, 1129, 1127, 1137, 1127, 1127, 1127, 1127, 1127, 1128, 1127, 1128, 1127, 1127, 1138, 1138,
+    1128, 1139, 1139, 1139, 1139, 1139, 1139, 1139, 1139, 1140, 1140, 1140, 1140, 1140, 1140, 1140,
+    1140, 1141, 1140, 1140, 1140, 1140, 1142, 1141, 1141, 1141, 1141, 1141, 1141, 1141, 1141, 1143,
+    1143, 1143, 1143, 1143, 1143, 1143, 1143, 1144, 1144, 1144, 1144, 1144, 1144, 1144, 1144, 1145,
+    1145, 1145, 1145, 1145, 1145, 1145, 1145, 1146, 1146, 1146, 1146, 1147, 1148, 1148, 1149, 1150,
+    1151, 1152, 1153, 1154, 1155, 1154, 1155, 1154, 1155, 1154, 1155, 1150, 1150, 1154, 1155, 1154,
+    1155, 1156, 1157, 1158, 1158, 1150, 1153, 1153, 1153, 1153, 1153, 1153, 1153, 1159, 1160, 1161,
+    1162, 1163, 1163, 1164, 1165, 1165, 1165, 1165, 1166, 1167, 1150, 1168, 1168, 1168, 1151, 1169,
+    1170, 1150, 1150, 1171, 1172, 1173, 1172, 1173, 1172, 1173, 1172, 1173, 1173, 1174, 1173, 1174,
+    1173, 1174, 1173, 1174, 1173, 1174, 1172, 1173, 1174, 1173, 1174, 1173, 1173, 1173, 1173, 1173,
+    1173, 1174, 1174, 1173, 1174, 1174, 1173, 1174, 1174, 1173, 1173, 1173, 1172, 1173, 1172, 1173,
+    1172, 1173, 1173, 1173, 1173, 1173, 1173, 1172, 1173, 1173, 1173, 1173, 1174, 1172, 1172, 1171,
+    1175, 1175, 1176, 1176, 1177, 1178, 1179, 1180, 1181, 1182, 1181, 1182, 1181, 1182, 1181, 1182,
+    1182, 1183, 1182, 1183, 1182, 1183, 1182, 1183, 1182, 1183, 1181, 1182, 1183, 1182, 1183, 1182,
+    1182, 1182, 1182, 1182, 1182, 1183, 1183, 1182, 1183, 1183, 1182, 1183, 1183, 1182, 1182, 1182,
+    1181, 1182, 1181, 1182, 1181, 1182, 1182, 1182, 1182, 1182, 1182, 1181, 1182, 1182, 1182, 1182,
+    1183, 1181, 1181, 1183, 1183, 1183, 1184, 1185, 1186, 1187, 1188, 1189, 1189, 1189, 1189, 1189,
+    1190, 1190, 1190, 1190, 1190, 1190, 1190, 1190, 1191, 1192, 1192, 1192, 1192, 1192, 1192, 1192,
+    1192, 1193, 1192, 1192, 1192, 1192, 1192, 1192, 1192, 1191, 1194, 1194, 1195, 1195, 1195, 1195,
+    1196, 1196, 1196, 1196, 1196, 1196, 1196, 1196, 1197, 1197, 1197, 1197, 1197, 1197, 1197, 1197,
+    1198, 1198, 1198, 1198, 1198, 1198, 1198, 1198, 1199, 1199, 1199, 1199, 1199, 1199, 1199, 1199,
+    1200, 1200, 1200, 1200, 1200, 1200, 1200, 1200, 1201, 1201, 1201, 1201, 1201, 1201, 1201, 1201,
+    1202, 1202, 1203, 1204, 1204, 1204, 1204, 1204, 1204, 1204, 1204, 1205, 1205, 1205, 1205, 1205,
+    1205, 1205, 1205, 1206, 1206, 1206, 1206, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1207, 1208,
+    1209, 1209, 1209, 1209, 1209, 1209, 1209, 1209, 1210, 1210, 1210, 1210, 1210, 1210, 1210, 1210,
+    1211, 1211, 1211, 1212, 1213, 1213, 1213, 1213, 1213, 1213, 1213, 1213, 1206, 1206, 1206, 1206,
+    1206, 1206, 1206, 1214, 1206, 1206, 1206, 1206, 1206, 1206, 1206, 1206, 1209, 1209, 1209, 1209,
+    1209, 1209, 1209, 1205, 1205, 1205, 1205, 1208, 1208, 1208, 1208, 1215, 1215, 1215, 1215, 1215,
+    1215, 1215, 1215, 1216, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1217, 1218, 1218, 1218, 1218,
+    1218, 1218, 1218, 1218, 1219, 1219, 1219, 1219, 1219, 1219, 1220, 1220, 1220, 1219, 1219, 1219,
+    1219, 1219, 1219, 1219, 1219, 1218, 1218, 1218, 1218, 1218, 1218, 1218, 1220, 1221, 1221, 1221,
+    1221, 1221, 1221, 1221, 1221, 1222, 1222, 1222, 1222, 1222, 1222, 1222, 1222, 1223, 1223, 1223,
+    1223, 1223, 1223, 1223, 1223, 1224, 1224, 1224, 1225, 1225, 1225, 1225, 1225, 1225, 1225, 1225,
+    1226, 1225, 1225, 1225, 1225, 1225, 1227, 1227, 1227, 1228, 1228, 1228, 1228, 1228, 1228, 1228,
+    1228, 1229, 1229, 1229, 1229, 1229, 1229, 1229, 1229, 1230, 1230, 1230, 1230, 1230, 1230, 1230,
+    1230, 1231, 1231, 1231, 1231, 1231, 1231, 1232, 1232, 1233, 1233, 1233, 1233, 1233, 1233, 1233,
+    1233, 1234, 1235, 1236, 1235, 1237, 1237, 1237, 1237, 1237, 1237, 1237, 1237, 1233, 1233, 1238,
+    1238, 1238, 1238, 1238, 1238, 1238, 1238, 1239, 1240, 1239, 1240, 1239, 1240, 1239, 1240, 1241,
+    1242, 1243, 1243, 1243, 1244, 1245, 1245, 1245, 1245, 1242, 1242, 1244, 1246, 1239, 1240, 1239,
+    1240, 1247, 1247, 1245, 1245, 1248, 1248, 1248, 1248, 1248, 1248, 1248, 1248, 1249, 1249, 1249,
+    1249, 1249, 1249, 1249, 1249, 1250, 1250, 1251, 1252, 1252, 1252, 1252, 1252, 1253, 1253, 1253,
+    1253, 1253, 1253, 1253, 1253, 1254, 1254, 1254, 1254, 1254, 1254, 1254, 1254, 1255, 1255, 1255,
+    1255, 1255, 1255, 1255, 1255, 1256, 1256, 1256, 1256, 1256, 1256, 1256, 1256, 1257, 1257, 1258,
+    1259, 1258, 1259, 1258, 1259, 1258, 1259, 1259, 1258, 1259, 1258, 1259, 1258, 1259, 1260, 1259,
+    1259, 1259, 1259, 1259, 1259, 1259, 1258, 1259, 1258, 1259, 1258, 1258, 1259, 1261, 1262, 1262,
+    1258, 1259, 1258, 1259, 1263, 1258, 1259, 1258, 1259, 1259, 1259, 1258, 1259, 1258, 1258, 1258,
+    1258, 1258, 1259, 1258, 1259, 1264, 1264, 1258, 1259, 1258, 1258, 1258, 1258, 1259, 1258, 1259,
+    1264, 1264, 1264, 1264, 1264, 1264, 1264, 1264, 1258, 1259, 1263, 1260, 1260, 1259, 1263, 1263,
+    1263, 1263, 1263, 1265, 1265, 1266, 1265, 1265, 1265, 1267, 1265, 1265, 1265, 1268, 1265, 1265,
+    1265, 1265, 1265, 1265, 1265, 1265, 1269, 1269, 1270, 1266, 1269, 1271, 1271, 1271, 1271, 1272,
+    1273, 1273, 1273, 1274, 1274, 1274, 1274, 1274, 1274, 1275, 1275, 1276, 1277, 1278, 1278, 1278,
+    1278, 1278, 1278, 1279, 1279, 1279, 1279, 1279, 1279, 1279, 1279, 1280, 1281, 1282, 1282, 1283,
+    1283, 1284, 1284, 1284, 1284, 1284, 1284, 1284, 1284, 1285, 1285, 1286, 1286, 1286, 1286, 1286,
+    1286, 1286, 1286, 1287, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1288, 1289, 1290, 1291, 1291,
+    1291, 1291, 1291, 1291, 1292, 1292, 1293, 1293, 1293, 1293, 1293, 1293, 1293, 1293, 1291, 1291,
+    1291, 1291, 1291, 1291, 1294, 1294, 1294, 1294, 1294, 1294, 1294, 1294, 1295, 1295, 1296, 1296,
+    1296, 1296, 1297, 1297, 1297, 1296, 1298, 1296, 1295, 1299, 1300, 1300, 1300, 1300, 1300, 1300,
+    1300, 1300, 1301, 1301, 1301, 1301, 1301, 1301, 1301, 1301, 1302, 1302, 1302, 1303, 1303, 1303,
+    1304, 1305, 1306, 1306, 1306, 1306, 1306, 1306, 1306, 1306, 1307, 1307, 1308, 1307, 1307, 1307,
+    1307, 1309, 1309, 1310, 1311, 1312, 1312, 1312, 1312, 1312, 1312, 1312, 1313, 1314, 1314, 1314,
+    1314, 1314, 1314, 1314, 1314, 1315, 1315, 1315, 1316, 1316, 1316, 1317, 1318, 1318, 1318, 1318,
+    1318, 1318, 1318, 1318, 1319, 1320, 1320, 1321, 1321, 1322, 1322, 1323, 1323, 1321, 1324, 1325,
+    1326, 1327, 1328, 1328, 1328, 1328, 1328, 1328, 1329, 1329, 1328, 1328, 1328, 1328, 1330, 1331,
+    1332, 1332, 1332, 1332, 1332, 1332, 1332, 1332, 1330, 1330, 1330, 1330, 1328, 1328, 1333, 1333,
+    1333, 1333, 1333, 1334, 1335, 1333, 1333, 1333, 1333, 1333, 1333, 1333, 1333, 1336, 1336, 1336,
+    1336, 1336, 1336, 1336, 1336, 1333, 1333, 1333, 1333, 1333, 1337, 1338, 1338, 1338, 1338, 1338,
+    1338, 1338, 1338, 1339, 1340, 1340, 1340, 1341, 1340, 1342, 1340, 1341, 1343, 1344, 1345, 1346,
+    1347, 1347, 1347, 1347, 1347, 1347, 1347, 1347, 1338, 1338, 1338, 1348, 1338, 1338, 1338, 1338,
+    1348, 1349, 1347, 1347, 1350, 1350, 1350, 1350, 1350, 1350, 1350, 1350, 1347, 1347, 1351, 1352,
+    1352, 1352, 1353, 1353, 1353, 1353, 1353, 1353, 1353, 1353, 1354, 1353, 1353, 1353, 1355, 1355,
+    1355, 1356, 1356, 1353, 1357, 1358, 1359, 1360, 1360, 1361, 1361, 1361, 1361, 1361, 1361, 1361,
+    1361, 1362, 1361, 1362, 1362, 1363, 1361, 1361, 1362, 1361, 1361, 1361, 1361, 1361, 1362, 1364,
+    1361, 1364, 1361, 1365, 1365, 1365, 1365, 1365, 1365, 1365, 1365, 1366, 1366, 1367, 1368, 1368,
+    1369, 1369, 1369, 1369, 1369, 1369, 1369, 1369, 1370, 1371, 1372, 1370, 1373, 1374, 1374, 1375,
+    1376, 1376, 1377, 1378, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1379, 1380, 1381, 1381, 1381,
+    1381, 1381, 1381, 1380, 1380, 1380, 1380, 1380, 1380, 1380, 1380, 1381, 1381, 1381, 1381, 1381,
+    1381, 1381, 1380, 1382, 1382, 1382, 1382, 1382, 1382, 1382, 1382, 1383, 1384, 1384, 1384, 1384,
+    1382, 1382, 1382, 1382, 1382, 1385, 1382, 1382, 1386, 1387, 1387, 1388, 1388, 1388, 1388, 1389,
+    1389, 1389, 1389, 1389, 1389, 1389, 1389, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1390, 1391,
+    1391, 1392, 1391, 1391, 1393, 1391, 1391, 1394, 1395, 1396, 1397, 1397, 1398, 1398, 1398, 1398,
+    1398, 1398, 1398, 1398, 1397, 1397, 1397, 1397, 1397, 1397, 1399, 1400, 1400, 1400, 1400, 1400,
+    1400, 1400, 1400, 1399, 1400, 1400, 1400, 1400, 1401, 1401, 1401, 1401, 1401, 1401, 1401, 1401,
+    1402, 1402, 1402, 1402, 1402, 1402, 1402, 1402, 1403, 1403, 1403, 1404, 1404, 1404, 1404, 1404,
+    1404, 1404, 1404, 1403, 1403, 1403, 1403, 1405, 1405, 1405, 1405, 1405, 1405, 1405, 1405, 1406,
+    1406, 1406, 1406, 1406, 1406, 1406, 1406, 1407, 1407, 1407, 1407, 1407, 1407, 1407, 1407, 1408,
+    1408, 1408, 1408, 1408, 1408, 1408, 1408, 1409, 1409, 1409, 1409, 1409, 1409, 1409, 1409, 1410,
+    1410, 1409, 1410, 1409, 1410, 1410, 1409, 1409, 1409, 1410, 1409, 1410, 1410, 1409, 1409, 1410,
+    1410, 1409, 1409, 1409, 1409, 1409, 1409, 1411, 1411, 1411, 1411, 1411, 1411, 1411, 1411, 1412,
+    1412, 1412, 1412, 1412, 1412, 1412, 1413, 1413, 1413, 1413, 1413, 1413, 1413, 1413, 1414, 1414,
+    1414, 1414, 1414, 1413, 1413, 1413, 1413, 1413, 1415, 1416, 1415, 1417, 1417, 1417, 1417, 1417,
+    1417, 1417, 1417, 1418, 1415, 1415, 1415, 1415, 1415, 1415, 1415, 1413, 1415, 1413, 1415, 1415,
+    1413, 1415, 1415, 1413, 1415, 1415, 1415, 1415, 1415, 1415, 1415, 1419, 1420, 1421, 1420, 1421,
+    1422, 1423, 1420, 1421, 1422, 1423, 1420, 1421, 1420, 1421, 1420, 1421, 1420, 1421, 1424, 1424,
+    1424, 1424, 1424, 1424, 1424, 1424, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1420, 1421,
+    1422, 1423, 1420, 1421, 1420, 1421, 1420, 1421, 1420, 1420, 1421, 1420, 1421, 1420, 1421, 1422,
+    1423, 1422, 1420, 1421, 1422, 1420, 1421, 1422, 1423, 1420, 1420, 1420, 1420, 1420, 1420, 1420,
+    1420, 1421, 1421, 1421, 1421, 1421, 1421, 1421, 1421, 1422, 1422, 1422, 1422, 1422, 1422, 1422,
+    1422, 1423, 1423, 1423, 1423, 1423, 1423, 1423, 1423, 1420, 1420, 1420, 1423, 1423, 1423, 1423,
+    1421, 1420, 1426, 1427, 1422, 1421, 1422, 1422, 1422, 1422, 1422, 1422, 1421, 1422, 1421, 1421,
+    1422, 1422, 1421, 1421, 1422, 1422, 1421, 1422, 1421, 1422, 1421, 1421, 1422, 1421, 1422, 1421,
+    1421, 1422, 1421, 1422, 1422, 1421, 1421, 1421, 1422, 1421, 1421, 1421, 1421, 1421, 1422, 1421,
+    1421, 1422, 1421, 1422, 1422, 1422, 1421, 1422, 1422, 1422, 1422, 1425, 1425, 1422, 1422, 1422,
+    1422, 1421, 1421, 1421, 1421, 1422, 1421, 1421, 1421, 1421, 1422, 1422, 1421, 1421, 1422, 1421,
+    1422, 1421, 1421, 1421, 1421, 1421, 1422, 1422, 1422, 1421, 1421, 1428, 1428, 1428, 1428, 1428,
+    1428, 1428, 1428, 1420, 1420, 1420, 1420, 1429, 1430, 1425, 1425, 1431, 1431, 1431, 1431, 1431,
+    1431, 1431, 1431, 1432, 1433, 1433, 1434, 1432, 1435, 1435, 1436, 1437, 1438, 1439, 1439, 1439,
+    1439, 1439, 1439, 1440, 1440, 1440, 1440, 1440, 1440, 1440, 1441, 1441, 1441, 1441, 1441, 1441,
+    1442, 1442, 1443, 1444, 1444, 1445, 1445, 1446, 1447, 1446, 1447, 1446, 1447, 1446, 1447, 1446,
+    1447, 1448, 1448, 1446, 1447, 1449, 1449, 1449, 1449, 1450, 1450, 1450, 1451, 1452, 1453, 1454,
+    1455, 1456, 1457, 1457, 1458, 1459, 1460, 1459, 1460, 1459, 1460, 1461, 1452, 1462, 1463, 1464,
+    1465, 1466, 1467, 1454, 1462, 1468, 1469, 1452, 1454, 1454, 1454, 1454, 1470, 1471, 1470, 1472,
+    1470, 1473, 1470, 1471, 1470, 1471, 1470, 1471, 1470, 1471, 1470, 1470, 1474, 1470, 1474, 1470,
+    1474, 1470, 1474, 1475, 1471, 1470, 1474, 1470, 1474, 1475, 1471, 1470, 1474, 1475, 1471, 1470,
+    1474, 1475, 1471, 1470, 1474, 1470, 1474, 1470, 1474, 1470, 1474, 1473, 1473, 1476, 1477, 1478,
+    1479, 1480, 1481, 1482, 1479, 1483, 1484, 1485, 1479, 1486, 1487, 1488, 1489, 1490, 1491, 1491,
+    1491, 1491, 1491, 1491, 1491, 1491, 1492, 1493, 1494, 1495, 1496, 1478, 1479, 1497, 1497, 1497,
+    1497, 1497, 1497, 1497, 1497, 1498, 1499, 1500, 1501, 1502, 1503, 1504, 1504, 1504, 1504, 1504,
+    1504, 1504, 1504, 1498, 1495, 1500, 1495, 1484, 1485, 1505, 1506, 1507, 1505, 1508, 1509, 1510,
+    1510, 1510, 1510, 1510, 1510, 1510, 1510, 1511, 1509, 1509, 1509, 1509, 1509, 1509, 1509, 1509,
+    1512, 1512, 1513, 1514, 1514, 1514, 1514, 1514, 1514, 1514, 1514, 1477, 1477, 1514, 1514, 1514,
+    1514, 1514, 1514, 1477, 1477, 1514, 1514, 1514, 1477, 1477, 1477, 1515, 1481, 1495, 1503, 1516,
+    1481, 1481, 1477, 1517, 1518, 1518, 1518, 1518, 1517, 1517, 1477, 1519, 1519, 1519, 1519, 1519,
+    1519, 1519, 1519, 1520, 1520, 1520, 1521, 1522, 1523, 1523, 1524, 1524, 1524, 1524, 1524, 1524,
+    1524, 1524, 1525, 1524, 1524, 1524, 1525, 1524, 1524, 1525, 1524, 1524, 1524, 1524, 1524, 1524,
+    1525, 1525, 1525, 1525, 1525, 1525, 1525, 1525, 1526, 1526, 1526, 1526, 1526, 1526, 1526, 1526,
+    1527, 1527, 1527, 1527, 1527, 1528, 1529, 1528, 1530, 1530, 1530, 1530, 1531, 1531, 1531, 1531,
+    1531, 1531, 1531, 1531, 1530, 1530, 1530, 1532, 1532, 1532, 1532, 1532, 1532, 1532, 1532, 1533,
+    1533, 1533, 1533, 1533, 1533, 1533, 1533, 1534, 1534, 1534, 1535, 1535, 1535, 1535, 1535, 1535,
+    1535, 1535, 1534, 1534, 1535, 1536, 1536, 1537, 1538, 1538, 1538, 1538, 1538, 1538, 1538, 1538,
+    1539, 1539, 1539, 1540, 1539, 1539, 1539, 1539, 1539, 1539, 1539, 1539, 1541, 1541, 1541, 1541,
+    1541, 1541, 1541, 1541, 1542, 1543, 1543, 1544, 1544, 1544, 1544, 1544, 1544, 1544, 1544, 1545,
+    1545, 1545, 1546, 1546, 1546, 1546, 1546, 1546, 1546, 1546, 1547, 1547, 1547, 1547, 1547, 1547,
+    1547, 1547, 1548, 1549, 1549, 1549, 1549, 1549, 1549, 1549, 1549, 1550, 1550, 1550, 1550, 1551,
+    1551, 1551, 1551, 1551, 1551, 1551, 1551, 1552, 1552, 1552, 1552, 1553, 1553, 1553, 1553, 1553,
+    1551, 1551, 1551, 1554, 1554, 1554, 1554, 1554, 1554, 1554, 1554, 1555, 1554, 1554, 1554, 1554,
+    1554, 1554, 1555, 1556, 1556, 1556, 1556, 1556, 1557, 1557, 1557, 1557, 1557, 1557, 1557, 1557,
+    1558, 1558, 1558, 1559, 1559, 1559, 1559, 1559, 1560, 1560, 1560, 1560, 1560, 1560, 1560, 1560,
+    1561, 1562, 1563, 1563, 1563, 1563, 1563, 1563, 1563, 1563, 1564, 1564, 1564, 1564, 1565, 1566,
+    1566, 1566, 1566, 1566, 1564, 1564, 1564, 1564, 1564, 1564, 1564, 1564, 1567, 1567, 1567, 1567,
+    1567, 1567, 1567, 1567, 1568, 1568, 1568, 1568, 1568, 1568, 1568, 1568, 1569, 1569, 1569, 1569,
+    1569, 1569, 1569, 1569, 1570, 1570, 1570, 1570, 1570, 1570, 1570, 1570, 1571, 1571, 1572, 1572,
+    1572, 1572, 1572, 1572, 1572, 1572, 1571, 1571, 1571, 1571, 1571, 1571, 1573, 1573, 1573, 1573,
+    1573, 1573, 1573, 1573, 1574, 1574, 1574, 1574, 1575, 1575, 1575, 1575, 1575, 1575, 1575, 1575,
+    1574, 1574, 1574, 1574, 1576, 1576, 1576, 1576, 1576, 1576, 1576, 1576, 1577, 1577, 1577, 1577,
+    1577, 1577, 1577, 1577, 1578, 1578, 1578, 1578, 1578, 1578, 1578, 1578, 1579, 1579, 1579, 1579,
+    1579, 1579, 1579, 1580, 1581, 1581, 1581, 1581, 1581, 1581, 1581, 1581, 1582, 1582, 1582, 1582,
+    1582, 1582, 1582, 1582, 1583, 1583, 1583, 1583, 1583, 1583, 1584, 1584, 1583, 1584, 1583, 1583,
+    1583, 1583, 1583, 1583, 1583, 1583, 1584, 1583, 1584, 1584, 1584, 1583, 1584, 1584, 1583, 1585,
+    1585, 1585, 1585, 1585, 1585, 1585, 1585, 1586, 1587, 1588, 1588, 1588, 1588, 1588, 1588, 1588,
+    1588, 1589, 1589, 1589, 1589, 1589, 1589, 1589, 1589, 1590, 1591, 1591, 1591, 1591, 1591, 1591,
+    1591, 1592, 1592, 1592, 1592, 1592, 1592, 1592, 1592, 1593, 1593, 1593, 1593, 1593, 1593, 1593,
+    1594, 1594, 1594, 1594, 1594, 1594, 1594, 1594, 1595, 1595, 1595, 1595, 1595, 1595, 1595, 1595,
+    1596, 1595, 1595, 1596, 1596, 1596, 1597, 1597, 1597, 1597, 1597, 1598, 1598, 1598, 1598, 1598,
+    1598, 1598, 1598, 1599, 1599, 1599, 1599, 1600, 1600, 1600, 1601, 1602, 1602, 1602, 1602, 1602,
+    1602, 1602, 1602, 1603, 1603, 1603, 1603, 1603, 1604, 1605, 1605, 1605, 1605, 1605, 1605, 1605,
+    1605, 1606, 1606, 1606, 1606, 1606, 1606, 1606, 1606, 1607, 1607, 1607, 1607, 1608, 1608, 1606,
+    1606, 1608, 1608, 1608, 1608, 1608, 1608, 1608, 1608, 1607, 1607, 1608, 1608, 1608, 1608, 1608,
+    1608, 1609, 1610, 1610, 1610, 1611, 1612, 1610, 1611, 1611, 1611, 1611, 1613, 1614, 1615, 1616,
+    1609, 1609, 1609, 1609, 1611, 1609, 1609, 1609, 1609, 1609, 1609, 1609, 1609, 1611, 1611, 1617,
+    1618, 1619, 1611, 1611, 1611, 1611, 1620, 1621, 1621, 1621, 1621, 1621, 1621, 1621, 1621, 1611,
+    1611, 1611, 1611, 1611, 1611, 1611, 1622, 1622, 1622, 1622, 1622, 1622, 1622, 1622, 1623, 1611,
+    1611, 1611, 1611, 1611, 1611, 1611, 1624, 1624, 1624, 1624, 1624, 1624, 1624, 1624, 1625, 1625,
+    1626, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1627, 1628, 1628, 1628, 1629, 1629, 1629, 1629,
+    1629, 1630, 1631, 1630, 1632, 1630, 1630, 1631, 1631, 1633, 1630, 1630, 1630, 1629, 1629, 1629,
+    1629, 1633, 1629, 1629, 1629, 1629, 1629, 1630, 1629, 1629, 1630, 1631, 1631, 1630, 1634, 1635,
+    1636, 1636, 1636, 1637, 1637, 1637, 1637, 1638, 1639, 1639, 1639, 1639, 1639, 1639, 1640, 1636,
+    1636, 1636, 1636, 1636, 1636, 1636, 1636, 1641, 1641, 1641, 1641, 1641, 1641, 1641, 1641, 1642,
+    1642, 1643, 1643, 1643, 1643, 1643, 1643, 1643, 1644, 1644, 1644, 1644, 1644, 1644, 1644, 1644,
+    1645, 1645, 1646, 1646, 1646, 1646, 1646, 1646, 1646, 1646, 1647, 1647, 1647, 1647, 1647, 1647,
+    1647, 1647, 1648, 1648, 1648, 1648, 1648, 1649, 1649, 1649, 1649, 1649, 1649, 1649, 1649, 1650,
+    1651, 1650, 1651, 1651, 1651, 1650, 1650, 1651, 1650, 1650, 1651, 1650, 1651, 1651, 1650, 1651,
+    1652, 1652, 1652, 1652, 1652, 1652, 1653, 1653, 1653, 1653, 1652, 1652, 1652, 1652, 1652, 1652,
+    1652, 1652, 1654, 1654, 1654, 1654, 1655, 1655, 1656, 1657, 1657, 1657, 1657, 1657, 1657, 1657,
+    1657, 1658, 1658, 1658, 1658, 1658, 1658, 1658, 1659, 1659, 1659, 1659, 1659, 1659, 1659, 1659,
+    1660, 1660, 1660, 1660, 1660, 1660, 1660, 1660, 1661, 1661, 1661, 1661, 1661, 1661, 1661, 1661,
+    1660, 1660, 1660, 1660, 1660, 1662, 1662, 1662, 1662, 1662, 1662, 1663, 1664, 1664, 1664, 1664,
+    1664, 1664, 1664, 1664, 1665, 1664, 1666, 1666, 1666, 1667, 1668, 1668, 1668, 1668, 1668, 1668,
+    1668, 1668, 1669, 1669, 1669, 1669, 1669, 1669, 1669, 1669, 1668, 1668, 1668, 1668, 1668, 1668,
+    1670, 1670, 1670, 1670, 1670, 1670, 1670, 1670, 1671, 1672, 1672, 1672, 1672, 1672, 1672, 1672,
+    1672, 1673, 1674, 1674, 1675, 1673, 1673, 1672, 1672, 1673, 1673, 1673, 1673, 1673, 1673, 1673,
+    1673, 1676, 1676, 1676, 1676, 1676, 1676, 1676, 1676, 1677, 1677, 1677, 1677, 1677, 1677, 1677,
+    1676, 1678, 1678, 1678, 1678, 1678, 1678, 1678, 1678, 1679, 1679, 1679, 1680, 1679, 1679, 1679,
+    1679, 1679, 1679, 1679, 1679, 1681, 1682, 1682, 1683, 1683, 1683, 1682, 1683, 1682, 1682, 1682,
+    1684, 1684, 1684, 1685, 1686, 1686, 1686, 1687, 1687, 1687, 1687, 1687, 1687, 1687, 1687, 1688,
+    1689, 1688, 1688, 1690, 1690, 1690, 1689, 1688, 1690, 1690, 1688, 1688, 1690, 1688, 1688, 1689,
+    1688, 1690, 1690, 1688, 1691, 1691, 1691, 1692, 1693, 1694, 1695, 1695, 1695, 1695, 1695, 1695,
+    1695, 1695, 1696, 1696, 1696, 1696, 1696, 1696, 1696, 1696, 1697, 1697, 1697, 1697, 1697, 1697,
+    1697, 1697, 1698, 1699, 1698, 1700, 1700, 1700, 1700, 1700, 1700, 1700, 1700, 1701, 1701, 1701,
+    1701, 1702, 1702, 1702, 1702, 1701, 1701, 1701, 1701, 1703, 1704, 1705, 1705, 1705, 1705, 1705,
+    1706, 1706, 1707, 1707, 1707, 1707, 1707, 1707, 1707, 1707, 1708, 1708, 1708, 1708, 1708, 1708,
+    1708, 1708, 1706, 1706, 1706, 1706, 1706, 1706, 1706, 1706, 1709, 1710, 1710, 1711, 1712, 1712,
+    1712, 1712, 1712, 1712, 1712, 1712, 1713, 1712, 1713, 1712, 1712, 1712, 1713, 1712, 1712, 1712,
+    1712, 1714, 1715, 1714, 1716, 1716, 1717, 1717, 1714, 1718, 1719, 1720, 1720, 1721, 1722, 1722,
+    1723, 1723, 1723, 1723, 1723, 1723, 1721, 1723, 1723, 1724, 1724, 1724, 1724, 1724, 1724, 1724,
+    1724, 1725, 1725, 1725, 1725, 1725, 1725, 1725, 1726, 1726, 1726, 1726, 1726, 1726, 1726, 1726,
+    1725, 1725, 1725, 1725, 1725, 1725, 1727, 1727, 1727, 1728, 1728, 1728, 1728, 1728, 1728, 1728,
+    1728, 1729, 1729, 1729, 1729, 1730, 1729, 1731, 1731, 1729, 1729, 1729, 1732, 1733, 1734, 1735,
+    1735, 1735, 1735, 1735, 1735, 1735, 1735, 1736, 1736, 1736, 1736, 1728, 1737, 1737, 1728, 1734,
+    1734, 1734, 1734, 1734, 1734, 1734, 1734, 1738, 1738, 1738, 1738, 1738, 1738, 1738, 1738, 1739,
+    1740, 1741, 1742, 1743, 1743, 1743, 1743, 1743, 1743, 1743, 1743, 1744, 1744, 1745, 1746, 1746,
+    1746, 1746, 1746, 1746, 1746, 1746, 1747, 1748, 1747, 1749, 1749, 1749, 1749, 1750, 1750, 1750,
+    1751, 1752, 1746, 1753, 1753, 1754, 1755, 1755, 1756, 1755, 1757, 1758, 1759, 1760, 1756, 1748,
+    1744, 1761, 1761, 1761, 1761, 1761, 1761, 1761, 1761, 1746, 1762, 1754, 1755, 1755, 1755, 1763,
+    1764, 1764, 1764, 1764, 1764, 1764, 1764, 1764, 1763, 1763, 1763, 1763, 1763, 1763, 1763, 1763,
+    1765, 1765, 1765, 1765, 1765, 1765, 1765, 1765, 1766, 1765, 1765, 1765, 1765, 1765, 1767, 1767,
+    1767, 1768, 1769, 1769, 1770, 1770, 1771, 1772, 1773, 1774, 1775, 1775, 1776, 1775, 1775, 1776,
+    1771, 1766, 1766, 1766, 1766, 1766, 1766, 1766, 1766, 1777, 1777, 1777, 1777, 1777, 1777, 1777,
+    1778, 1777, 1777, 1777, 1777, 1778, 1777, 1777, 1777, 1777, 1777, 1777, 1777, 1777, 1779, 1778,
+    1778, 1778, 1778, 1778, 1778, 1780, 1780, 1780, 1780, 1780, 1780, 1780, 1780, 1781, 1782, 1783,
+    1782, 1784, 1784, 1785, 1785, 1785, 1786, 1787, 1788, 1788, 1788, 1788, 1788, 1789, 1789, 1789,
+    1789, 1789, 1789, 1789, 1789, 1788, 1788, 1788, 1788, 1788, 1788, 1790, 1790, 1791, 1791, 1792,
+    1793, 1793, 1793, 1793, 1793, 1792, 1792, 1793, 1793, 1793, 1793, 1793, 1793, 1793, 1793, 1792,
+    1793, 1793, 1793, 1793, 1793, 1793, 1792, 1793, 1793, 1792, 1793, 1793, 1793, 1792, 1794, 1795,
+    1793, 1796, 1797, 1798, 1797, 1797, 1797, 1797, 1792, 1792, 1799, 1792, 1792, 1800, 1800, 1801,
+    1792, 1792, 1802, 1792, 1792, 1792, 1792, 1792, 1792, 1796, 1792, 1792, 1792, 1792, 1792, 1802,
+    1793, 1793, 1797, 1797, 1792, 1792, 1803, 1803, 1803, 1803, 1803, 1792, 1792, 1792, 1792, 1792,
+    1792, 1792, 1792, 1804, 1804, 1804, 1804, 1804, 1804, 1804, 1804, 1805, 1806, 1805, 1807, 1807,
+    1807, 1807, 1807, 1807, 1808, 1808, 1805, 1805, 1809, 1810, 1810, 1811, 1812, 1804, 1813, 1813,
+    1813, 1814, 1814, 1814, 1814, 1815, 1816, 1816, 1816, 1816, 1816, 1816, 1816, 1816, 1814, 1814,
+    1817, 1815, 1818, 1804, 1819, 1819, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1817, 1820, 1821,
+    1821, 1821, 1821, 1821, 1821, 1821, 1821, 1822, 1823, 1824, 1825, 1825, 1825, 1825, 1825, 1823,
+    1826, 1827, 1827, 1822, 1827, 1828, 1829, 1830, 1831, 1821, 1820, 1832, 1820, 1833, 1833, 1833,
+    1833, 1833, 1833, 1833, 1833, 1834, 1834, 1834, 1834, 1834, 1834, 1834, 1834, 1833, 1833, 1833,
+    1833, 1833, 1833, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1835, 1836, 1837, 1838, 1839, 1839,
+    1839, 1839, 1840, 1840, 1837, 1838, 1841, 1841, 1842, 1842, 1843, 1844, 1845, 1846, 1847, 1847,
+    1848, 1848, 1849, 1849, 1847, 1847, 1847, 1847, 1847, 1847, 1847, 1847, 1835, 1835, 1835, 1835,
+    1839, 1839, 1840, 1840, 1840, 1840, 1840, 1840, 1840, 1840, 1850, 1850, 1850, 1850, 1850, 1850,
+    1850, 1850, 1851, 1851, 1851, 1852, 1852, 1852, 1852, 1852, 1853, 1853, 1851, 1851, 1854, 1855,
+    1856, 1853, 1857, 1857, 1858, 1859, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1860, 1861, 1861,
+    1861, 1861, 1861, 1861, 1861, 1861, 1860, 1860, 1860, 1860, 1860, 1860, 1862, 1862, 1862, 1862,
+    1862, 1862, 1862, 1862, 1863, 1863, 1863, 1863, 1863, 1863, 1863, 1863, 1864, 1864, 1864, 1864,
+    1864, 1864, 1864, 1864, 1865, 1866, 1867, 1868, 1869, 1870, 1870, 1867, 1867, 1867, 1867, 1871,
+    1872, 1864, 1873, 1873, 1873, 1873, 1873, 1873, 1873, 1874, 1874, 1874, 1874, 1874, 1874, 1874,
+    1874, 1873, 1873, 1873, 1873, 1873, 1873, 1875, 1875, 1875, 1875, 1875, 1875, 1875, 1875, 1876,
+    1876, 1877, 1878, 1879, 1880, 1880, 1881, 1881, 1882, 1882, 1883, 1881, 1882, 1881, 1881, 1884,
+    1876, 1876, 1876, 1876, 1885, 1885, 1885, 1885, 1885, 1885, 1885, 1885, 1886, 1886, 1887, 1887,
+    1887, 1888, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 1889, 1890, 1891, 1890, 1892, 1892, 1892,
+    1893, 1893, 1893, 1893, 1894, 1895, 1896, 1897, 1898, 1899, 1899, 1899, 1899, 1899, 1899, 1899,
+    1899, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1900, 1901, 1901, 1901, 1901, 1901, 1901, 1901,
+    1901, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1902, 1903, 1903, 1903, 1903, 1903, 1903, 1904,
+    1904, 1904, 1904, 1904, 1904, 1904, 1905, 1906, 1906, 1906, 1906, 1906, 1906, 1906, 1907, 1906,
+    1907, 1907, 1906, 1906, 1906, 1906, 1907, 1906, 1906, 1907, 1906, 1906, 1906, 1906, 1906, 1906,
+    1906, 1906, 1908, 1909, 1909, 1909, 1909, 1910, 1907, 1910, 1911, 1907, 1907, 1912, 1912, 1913,
+    1914, 1915, 1916, 1917, 1918, 1919, 1920, 1920, 1920, 1907, 1907, 1907, 1907, 1907, 1907, 1907,
+    1907, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1921, 1907, 1907, 1907, 1907, 1907, 1907, 1922,
+    1922, 1922, 1922, 1922, 1922, 1922, 1922, 1923, 1923, 1922, 1922, 1922, 1922, 1922, 1922, 1924,
+    1925, 1924, 1926, 1926, 1926, 1926, 1923, 1923, 1927, 1927, 1924, 1924, 1928, 1928, 1929, 1922,
+    1930, 1931, 1925, 1923, 1923, 1923, 1923, 1923, 1923, 1923, 1923, 1932, 1933, 1934, 1934, 1933,
+    1933, 1933, 1935, 1933, 1934, 1932, 1932, 1932, 1932, 1932, 1932, 1932, 1932, 1936, 1937, 1938,
+    1938, 1938, 1939, 1940, 1941, 1941, 1941, 1941, 1942, 1943, 1944, 1944, 1944, 1944, 1942, 1943,
+    1945, 1946, 1946, 1946, 1946, 1946, 1946, 1946, 1946, 1947, 1948, 1949, 1949, 1948, 1948, 1948,
+    1950, 1949, 1949, 1949, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1947, 1951, 1951, 1951, 1951,
+    1952, 1952, 1952, 1952, 1952, 1952, 1953, 1954, 1955, 1956, 1957, 1957, 1957, 1947, 1958, 1958,
+    1957, 1957, 1959, 1959, 1959, 1959, 1959, 1959, 1959, 1959, 1960, 1960, 1960, 1960, 1960, 1960,
+    1960, 1960, 1961, 1961, 1961, 1961, 1961, 1961, 1961, 1962, 1962, 1962, 1962, 1962, 1962, 1962,
+    1962, 1963, 1962, 1962, 1962, 1962, 1962, 1962, 1962, 1964, 1965, 1965, 1966, 1966, 1966, 1966,
+    1966, 1963, 1965, 1965, 1965, 1965, 1967, 1967, 1968, 1969, 1962, 1970, 1970, 1970, 1970, 1970,
+    1963, 1963, 1963, 1963, 1963, 1963, 1963, 1963, 1971, 1971, 1971, 1971, 1971, 1971, 1971, 1971,
+    1972, 1972, 1972, 1972, 1972, 1972, 1972, 1972, 1963, 1963, 1963, 1973, 1974, 1975, 1975, 1975,
+    1975, 1975, 1975, 1975, 1975, 1976, 1976, 1977, 1977, 1977, 1977, 1977, 1977, 1977, 1977, 1976,
+    1978, 1977, 1977, 1977, 1977, 1977, 1977, 1979, 1980, 1979, 1981, 1982, 1983, 1983, 1976, 1976,
+    1976, 1976, 1976, 1976, 1976, 1976, 1984, 1984, 1984, 1984, 1984, 1984, 1984, 1985, 1984, 1984,
+    1984, 1984, 1984, 1984, 1984, 1984, 1986, 1986, 1986, 1986, 1986, 1987, 1985, 1985, 1986, 1985,
+    1986, 1986, 1985, 1986, 1988, 1988, 1989, 1986, 1990, 1991, 1992, 1993, 1985, 1985, 1985, 1985,
+    1985, 1985, 1985, 1985, 1994, 1994, 1994, 1994, 1994, 1994, 1994, 1994, 1985, 1985, 1985, 1985,
+    1985, 1985, 1995, 1995, 1995, 1995, 1995, 1995, 1996, 1995, 1995, 1995, 1995, 1995, 1995, 1995,
+    1995, 1997, 1997, 1997, 1997, 1997, 1996, 1998, 1998, 1996, 1997, 1997, 1999, 2000, 2001, 2002,
+    1996, 1996, 1996, 1996, 1996, 1996, 1996, 2003, 2003, 2003, 2003, 2003, 2003, 2003, 2003, 1996,
+    1996, 1996, 1996, 1996, 1996, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2004, 2005, 2006, 2007,
+    2008, 2009, 2010, 2011, 2011, 2011, 2011, 2011, 2011, 2011, 2012, 2013, 2013, 2013, 2013, 2013,
+    2013, 2013, 2013, 2014, 2014, 2014, 2014, 2014, 2014, 2014, 2014, 2015, 2015, 2015, 2015, 2015,
+    2016, 2016, 2016, 2015, 2015, 2015, 2015, 2015, 2015, 2015, 2015, 2017, 2017, 2017, 2017, 2017,
+    2017, 2017, 2018, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2019, 2020, 2020, 2020, 2020, 2020,
+    2020, 2020, 2020, 2021, 2021, 2021, 2021, 2021, 2021, 2021, 2021, 2022, 2023, 2023, 2023, 2023,
+    2023, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2022, 2024, 2024, 2024, 2024, 2024, 2024, 2024,
+    2024, 2025, 2025, 2025, 2025, 2025, 2025, 2025, 2025, 2026, 2026, 2026, 2026, 2026, 2026, 2026,
+    2026, 2027, 2027, 2027, 2028, 2028, 2028, 2026, 2026, 2028, 2026, 2026, 2026, 2027, 2028, 2026,
+    2026, 2026, 2026, 2026, 2026, 2027, 2028, 2028, 2026, 2026, 2026, 2026, 2026, 2026, 2026, 2029,
+    2030, 2030, 2030, 2030, 2030, 2030, 2030, 2031, 2032, 2033, 2033, 2033, 2033, 2033, 2033, 2033,
+    2034, 2034, 2034, 2034, 2034, 2034, 2034, 2034, 2035, 2036, 2034, 2034, 2034, 2034, 2034, 2034,
+    2034, 2037, 2037, 2037, 2037, 2037, 2037, 2037, 2037, 2038, 2038, 2038, 2038, 2038, 2038, 2038,
+    2038, 2039, 2039, 2039, 2039, 2039, 2039, 2039, 2040, 2040, 2040, 2040, 2040, 2040, 2040, 2040,
+    2041, 2042, 2042, 2042, 2042, 2042, 2042, 2042, 2042, 2041, 2041, 2041, 2041, 2043, 2043, 2044,
+    2044, 2044, 2044, 2044, 2044, 2044, 2044, 2045, 2045, 2046, 2046, 2046, 2046, 2046, 2047, 2045,
+    2045, 2045, 2045, 2045, 2045, 2045, 2045, 2048, 2048, 2048, 2048, 2048, 2048, 2048, 2048, 2049,
+    2049, 2049, 2049, 2049, 2049, 2049, 2050, 2050, 2051, 2051, 2052, 2052, 2052, 2052, 2053, 2053,
+    2053, 2053, 2050, 2052, 2054, 2054, 2054, 2054, 2054, 2054, 2054, 2054, 2055, 2055, 2055, 2055,
+    2055, 2055, 2055, 2055, 2054, 2056, 2056, 2056, 2056, 2056, 2054, 2057, 2057, 2057, 2057, 2057,
+    2057, 2057, 2057, 2054, 2054, 2054, 2054, 2054, 2057, 2057, 2057, 2058, 2058, 2058, 2058, 2058,
+    2058, 2058, 2058, 2059, 2059, 2059, 2059, 2059, 2059, 2059, 2059, 2060, 2060, 2060, 2060, 2060,
+    2060, 2060, 2060, 2061, 2062, 2062, 2063, 2063, 2063, 2063, 2063, 2064, 2064, 2064, 2064, 2064,
+    2064, 2064, 2064, 2065, 2065, 2065, 2066, 2066, 2066, 2066, 2067, 2064, 2068, 2068, 2068, 2068,
+    2068, 2068, 2068, 2068, 2066, 2066, 2066, 2066, 2066, 2066, 2066, 2069, 2069, 2069, 2070, 2070,
+    2070, 2070, 2070, 2070, 2070, 2070, 2071, 2072, 2073, 2074, 2075, 2076, 2076, 2076, 2076, 2076,
+    2076, 2076, 2076, 2077, 2077, 2076, 2076, 2076, 2076, 2076, 2076, 2078, 2078, 2078, 2078, 2078,
+    2078, 2078, 2078, 2079, 2079, 2079, 2079, 2079, 2079, 2079, 2079, 2080, 2080, 2080, 2080, 2080,
+    2080, 2080, 2080, 2081, 2081, 2081, 2081, 2081, 2081, 2081, 2081, 2082, 2082, 2082, 2082, 2082,
+    2082, 2082, 2082, 2083, 2083, 2083, 2083, 2083, 2083, 2083, 2083, 2084, 2084, 2084, 2084, 2084,
+    2084, 2084, 2084, 2085, 2086, 2086, 2086, 2086, 2086, 2086, 2086, 2086, 2087, 2087, 2087, 2087,
+    2087, 2087, 2087, 2087, 2088, 2088, 2088, 2088, 2088, 2088, 2088, 2088, 2089, 2089, 2089, 2089,
+    2089, 2089, 2089, 2089, 2090, 2090, 2090, 2089, 2089, 2089, 2089, 2089, 2091, 2091, 2091, 2091,
+    2092, 2092, 2092, 2092, 2092, 2092, 2092, 2092, 2093, 2093, 2093, 2093, 2094, 2094, 2094, 2094,
+    2094, 2094, 2094, 2094, 2095, 2095, 2095, 2095, 2095, 2095, 2095, 2094, 2094, 2096, 2096, 2097,
+    2098, 2099, 2100, 2101, 2101, 2101, 2101, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2102, 2103,
+    2103, 2103, 2103, 2103, 2103, 2103, 2103, 2104, 2104, 2104, 2104, 2104, 2104, 2104, 2104, 2105,
+    2105, 2105, 2105, 2105, 2105, 2105, 2105, 2106, 2105, 2105, 2105, 2105, 2105, 2105, 2105, 2107,
+    2107, 2107, 2107, 2107, 2108, 2109, 2110, 2110, 2105, 2105, 2105, 2111, 2108, 2108, 2108, 2112,
+    2112, 2112, 2112, 2112, 2113, 2113, 2113, 2113, 2113, 2105, 2105, 2114, 2114, 2114, 2113, 2113,
+    2105, 2105, 2105, 2105, 2114, 2114, 2114, 2114, 2105, 2105, 2107, 2105, 2105, 2105, 2105, 2105,
+    2105, 2105, 2106, 2106, 2106, 2106, 2106, 2106, 2106, 2106, 2115, 2115, 2115, 2115, 2115, 2115,
+    2115, 2115, 2116, 2116, 2116, 2115, 2117, 2117, 2117, 2117, 2117, 2117, 2117, 2117, 2118, 2118,
+    2118, 2118, 2118, 2118, 2118, 2118, 2119, 2119, 2119, 2119, 2119, 2119, 2119, 2119, 2120, 2120,
+    2120, 2120, 2120, 2120, 2120, 2120, 2121, 2121, 2121, 2121, 2121, 2121, 2121, 2121, 2122, 2122,
+    2122, 2122, 2122, 2122, 2122, 2122, 2123, 2123, 2123, 2123, 2123, 2123, 2123, 2124, 2124, 2124,
+    2124, 2124, 2124, 2124, 2124, 2125, 2125, 2125, 2125, 2125, 2125, 2125, 2125, 2124, 2124, 2124,
+    2124, 2125, 2125, 2125, 2125, 2125, 2126, 2125, 2125, 2125, 2125, 2124, 2126, 2124, 2124, 2126,
+    2126, 2124, 2126, 2126, 2124, 2124, 2126, 2124, 2124, 2124, 2124, 2126, 2124, 2124, 2125, 2125,
+    2126, 2125, 2126, 2125, 2125, 2125, 2125, 2124, 2124, 2126, 2124, 2124, 2124, 2126, 2126, 2124,
+    2124, 2124, 2124, 2124, 2126, 2124, 2124, 2124, 2124, 2124, 2126, 2125, 2125, 2124, 2124, 2124,
+    2124, 2124, 2126, 2124, 2126, 2126, 2124, 2124, 2124, 2124, 2124, 2124, 2126, 2125, 2125, 2125,
+    2125, 2125, 2125, 2126, 2126, 2124, 2127, 2125, 2125, 2125, 2125, 2125, 2125, 2128, 2125, 2125,
+    2125, 2125, 2124, 2124, 2124, 2124, 2124, 2124, 2127, 2125, 2125, 2125, 2125, 2125, 2125, 2125,
+    2128, 2124, 2124, 2124, 2124, 2124, 2124, 2124, 2127, 2125, 2128, 2125, 2125, 2125, 2125, 2125,
+    2125, 2124, 2125, 2126, 2126, 2129, 2129, 2129, 2129, 2129, 2129, 2129, 2129, 2130, 2130, 2130,
+    2130, 2130, 2130, 2130, 2130, 2131, 2131, 2131, 2131, 2131, 2131, 2131, 2131, 2130, 2130, 2130,
+    2130, 2130, 2131, 2130, 2130, 2132, 2132, 2132, 2133, 2134, 2134, 2134, 2134, 2134, 2134, 2134,
+    2134, 2131, 2131, 2131, 2131, 2131, 2131, 2131, 2135, 2135, 2135, 2135, 2135, 2135, 2135, 2136,
+    2135, 2135, 2135, 2135, 2135, 2135, 2135, 2135, 2136, 2136, 2135, 2135, 2135, 2135, 2135, 2136,
+    2135, 2135, 2136, 2135, 2135, 2135, 2136, 2136, 2136, 2136, 2136, 2137, 2137, 2137, 2137, 2137,
+    2137, 2137, 2137, 2138, 2138, 2138, 2139, 2139, 2139, 2139, 2139, 2139, 2139, 2140, 2140, 2140,
+    2140, 2140, 2140, 2138, 2138, 2141, 2141, 2141, 2141, 2141, 2141, 2141, 2141, 2138, 2138, 2138,
+    2138, 2137, 2142, 2143, 2143, 2143, 2143, 2143, 2143, 2143, 2143, 2144, 2144, 2144, 2144, 2145,
+    2145, 2145, 2145, 2145, 2145, 2145, 2145, 2146, 2146, 2146, 2146, 2146, 2147, 2148, 2148, 2148,
+    2148, 2148, 2148, 2148, 2148, 2149, 2149, 2150, 2150, 2150, 2150, 2150, 2150, 2150, 2150, 2151,
+    2151, 2151, 2151, 2151, 2151, 2151, 2149, 2149, 2149, 2149, 2149, 2149, 2149, 2149, 2152, 2152,
+    2152, 2152, 2152, 2152, 2152, 2152, 2153, 2153, 2153, 2153, 2153, 2153, 2153, 2153, 2154, 2154,
+    2154, 2155, 2154, 2154, 2156, 2157, 2158, 2158, 2158, 2158, 2159, 2159, 2159, 2159, 2159, 2159,
+    2159, 2159, 2158, 2158, 2158, 2158, 2160, 2160, 2161, 2162, 2162, 2162, 2162, 2162, 2162, 2162,
+    2162, 2163, 2162, 2162, 2162, 2164, 2162, 2162, 2162, 2162, 2161, 2161, 2161, 2161, 2161, 2161,
+    2161, 2161, 2165, 2166, 2166, 2166, 2166, 2166, 2166, 2166, 2166, 2167, 2166, 2166, 2166, 2166,
+    2166, 2166, 2165, 2165, 2165, 2165, 2165, 2165, 2165, 2165, 2168, 2168, 2168, 2168, 2169, 2168,
+    2168, 2168, 2168, 2168, 2168, 2168, 2168, 2169, 2168, 2168, 2169, 2168, 2169, 2169, 2168, 2169,
+    2168, 2169, 2169, 2169, 2169, 2168, 2169, 2169, 2169, 2169, 2168, 2169, 2168, 2169, 2168, 2168,
+    2168, 2169, 2168, 2169, 2168, 2169, 2168, 2169, 2168, 2168, 2168, 2168, 2169, 2168, 2169, 2168,
+    2168, 2168, 2168, 2169, 2169, 2169, 2169, 2168, 2168, 2168, 2169, 2168, 2168, 2168, 2169, 2169,
+    2169, 2169, 2169, 2169, 2169, 2169, 2170, 2170, 2169, 2169, 2169, 2169, 2169, 2169, 2171, 2171,
+    2171, 2171, 2172, 2171, 2171, 2171, 2171, 2171, 2171, 2171, 2171, 2173, 2173, 2173, 2173, 2174,
+    2174, 2174, 2174, 2174, 2174, 2174, 2174, 2175, 2175, 2175, 2175, 2175, 2175, 2175, 2175, 2176,
+    2176, 2176, 2176, 2176, 2176, 2176, 2176, 2177, 2176, 2176, 2176, 2176, 2176, 2176, 2176, 2178,
+    2176, 2176, 2176, 2176, 2176, 2176, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2177, 2179, 2179,
+    2179, 2179, 2179, 2179, 2179, 2179, 2180, 2180, 2181, 2181, 2181, 2182, 2182, 2182, 2182, 2182,
+    2182, 2182, 2182, 2183, 2183, 2183, 2184, 2185, 2186, 2186, 2186, 2186, 2186, 2186, 2186, 2186,
+    2187, 2187, 2187, 2187, 2187, 2187, 2188, 2188, 2188, 2188, 2188, 2188, 2188, 2188, 2189, 2189,
+    2190, 2181, 2181, 2181, 2191, 2191, 2188, 2188, 2188, 2188, 2188, 2188, 2191, 2191, 2188, 2188,
+    2192, 2192, 2192, 2192, 2193, 2192, 2187, 2193, 2193, 2193, 2193, 2193, 2193, 2193, 2192, 2192,
+    2192, 2192, 2192, 2192, 2192, 2192, 2181, 2194, 2194, 2194, 2194, 2194, 2194, 2194, 2194, 2195,
+    2195, 2195, 2195, 2195, 2195, 2195, 2195, 2196, 2197, 2197, 2198, 2198, 2198, 2198, 2198, 2198,
+    2198, 2198, 2199, 2199, 2199, 2199, 2199, 2199, 2199, 2199, 2197, 2199, 2199, 2199, 2199, 2199,
+    2197, 2197, 2197, 2197, 2197, 2197, 2199, 2198, 2198, 2198, 2198, 2200, 2200, 2200, 2200, 2200,
+    2200, 2200, 2200, 2198, 2198, 2198, 2198, 2198, 2198, 2198, 2201, 2201, 2198, 2198, 2198, 2198,
+    2198, 2198, 2202, 2202, 2202, 2202, 2202, 2202, 2198, 2198, 2203, 2203, 2203, 2203, 2203, 2203,
+    2203, 2203, 2204, 2204, 2203, 2203, 2203, 2203, 2203, 2205, 2203, 2203, 2204, 2203, 2203, 2203,
+    2206, 2206, 2203, 2203, 2203, 2203, 2203, 2207, 2207, 2203, 2203, 2203, 2203, 2207, 2203, 2203,
+    2203, 2205, 2205, 2205, 2203, 2203, 2205, 2205, 2205, 2203, 2203, 2203, 2204, 2204, 2203, 2203,
+    2203, 2204, 2203, 2203, 2203, 2208, 2208, 2208, 2208, 2208, 2203, 2203, 2205, 2205, 2203, 2203,
+    2205, 2205, 2205, 2205, 2205, 2205, 2205, 2205, 2203, 2203, 2203, 2203, 2203, 2203, 2203, 2205,
+    2205, 2203, 2203, 2203, 2205, 2203, 2203, 2203, 2205, 2205, 2205, 2203, 2205, 2205, 2205, 2203,
+    2205, 2203, 2203, 2203, 2203, 2203, 2203, 2207, 2203, 2207, 2203, 2207, 2203, 2203, 2203, 2205,
+    2203, 2203, 2203, 2203, 2207, 2207, 2203, 2203, 2203, 2203, 2203, 2203, 2204, 2203, 2207, 2207,
+    2207, 2207, 2207, 2207, 2207, 2203, 2203, 2203, 2203, 2203, 2203, 2203, 2207, 2207, 2207, 2207,
+    2207, 2207, 2207, 2207, 2209, 2209, 2209, 2209, 2209, 2209, 2206, 2206, 2207, 2203, 2203, 2203,
+    2203, 2203, 2204, 2204, 2204, 2204, 2204, 2204, 2204, 2203, 2204, 2204, 2203, 2205, 2205, 2203,
+    2203, 2205, 2204, 2204, 2204, 2204, 2204, 2203, 2203, 2203, 2203, 2204, 2204, 2205, 2204, 2204,
+    2204, 2204, 2205, 2205, 2204, 2204, 2204, 2204, 2204, 2204, 2204, 2204, 2203, 2203, 2204, 2204,
+    2204, 2204, 2204, 2203, 2204, 2204, 2204, 2203, 2203, 2203, 2204, 2204, 2204, 2203, 2203, 2203,
+    2206, 2206, 2206, 2206, 2203, 2203, 2203, 2204, 2203, 2204, 2203, 2204, 2204, 2204, 2204, 2204,
+    2204, 2203, 2206, 2206, 2206, 2206, 2203, 2203, 2203, 2203, 2203, 2203, 2210, 2210, 2210, 2210,
+    2210, 2210, 2210, 2210, 2211, 2211, 2211, 2211, 2211, 2212, 2212, 2212, 2212, 2212, 2212, 2212,
+    2212, 2213, 2213, 2214, 2214, 2214, 2212, 2212, 2212, 2212, 2215, 2215, 2215, 2215, 2215, 2215,
+    2215, 2215, 2216, 2215, 2215, 2215, 2215, 2216, 2216, 2216, 2215, 2215, 2215, 2215, 2215, 2217,
+    2217, 2217, 2215, 2216, 2215, 2215, 2215, 2217, 2217, 2215, 2215, 2215, 2218, 2218, 2218, 2218,
+    2218, 2218, 2218, 2218, 2215, 2215, 2215, 2215, 2215, 2215, 2217, 2217, 2215, 2217, 2215, 2215,
+    2218, 2218, 2218, 2215, 2217, 2217, 2215, 2215, 2215, 2215, 2215, 2218, 2218, 2218, 2219, 2219,
+    2219, 2219, 2219, 2219, 2219, 2219, 2220, 2220, 2220, 2220, 2220, 2220, 2220, 2220, 2221, 2221,
+    2221, 2221, 2221, 2221, 2221, 2221, 2222, 2222, 2222, 2223, 2223, 2223, 2223, 2223, 2223, 2223,
+    2224, 2224, 2224, 2224, 2224, 2224, 2224, 2224, 2223, 2223, 2223, 2223, 2223, 2223, 2223, 2223,
+    2225, 2225, 2225, 2225, 2225, 2225, 2225, 2225, 2226, 2226, 2226, 2226, 2226, 2226, 2226, 2226,
+    2227, 2227, 2226, 2226, 2226, 2226, 2226, 2226, 2228, 2228, 2228, 2228, 2228, 2228, 2228, 2228,
+    2229, 2230, 2230, 2229, 2230, 2230, 2230, 2230, 2230, 2230, 2230, 2230, 2229, 2229, 2229, 2229,
+    2229, 2229, 2229, 2229, 2230, 2230, 2230, 2230, 2230, 2230, 2229, 2230, 2229, 2229, 2230, 2231,
+    2229, 2229, 2229, 2230, 2230, 2230, 2230, 2230, 2230, 2231, 2230, 2232, 2230, 2230, 2230, 2230,
+    2230, 2230, 2229, 2229, 2230, 2229, 2230, 2230, 2230, 2230, 2232, 2229, 2229, 2229, 2233, 2233,
+    2233, 2233, 2233, 2233, 2233, 2233, 2234, 2234, 2234, 2234, 2234, 2234, 2234, 2234, 2235, 2235,
+    2235, 2235, 2235, 2235, 2235, 2235, 2234, 2234, 2236, 2236, 2236, 2236, 2236, 2237, 2237, 2237,
+    2237, 2237, 2236, 2236, 2236, 2236, 2236, 2236, 2236, 2237, 2237, 2237, 2237, 2237, 2237, 2237,
+    2237, 2236, 2236, 2236, 2236, 2236, 2236, 2236, 2236, 2238, 2238, 2238, 2238, 2238, 2238, 2238,
+    2238, 2239, 2238, 2238, 2238, 2238, 2239, 2239, 2239, 2239, 2239, 2239, 2239, 2239, 2240, 2240,
+    2240, 2240, 2240, 2240, 2240, 2240, 2239, 2239, 2239, 2239, 2239, 2239, 2241, 2241, 2241, 2241,
+    2241, 2241, 2241, 2241, 2242, 2242, 2243, 2243, 2243, 2243, 2243, 2243, 2243, 2243, 2244, 2244,
+    2245, 2245, 2245, 2245, 2245, 2245, 2245, 2245, 2246, 2246, 2246, 2246, 2246, 2246, 2246, 2246,
+    2247, 2247, 2247, 2247, 2247, 2247, 2247, 2247, 2248, 2248, 2248, 2248, 2248, 2248, 2248, 2248,
+    2249, 2249, 2250, 2250, 2250, 2250, 2250, 2250, 2250, 2250, 2251, 2251, 2251, 2251, 2251, 2251,
+    2251, 2251, 2252, 2252, 2252, 2252, 2252, 2252, 2252, 2252, 2253, 2253, 2253, 2253, 2253, 2253,
+    2253, 2253, 2254, 2254, 2254, 2254, 2254, 2254, 2254, 2254, 2255, 2255, 2245, 2245, 2245, 2245,
+    2245, 2245, 2242, 2242, 2256, 2256, 2256, 2256, 2256, 2256, 2256, 2256, 2257, 2257, 2257, 2257,
+    2257, 272, 272, 272, 272, 272, 272, 2242, 2242, 2258, 2259, 2258, 2258, 2258, 2258, 2258, 2258,
+    2258, 2258, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2260, 2261, 2261, 2261, 2261, 2261, 2261,
+    2261, 2261, 2262, 2262, 2262, 2262, 2262, 2262, 2262, 2262, 2263, 2263, 2263, 2263, 2263, 2263,
+    2263, 2263, 2264, 2264, 2265, 2265, 2265, 2265, 2265, 2265, 2265, 2265, 2266, 2266,
 ];
 
-pub fn get_record_index(x: usize) -> usize {
+pub const fn get_record_index(x: usize) -> usize {
     const SHIFT1: usize = 5;
     const SHIFT2: usize = 3;
     if x >= 1114112 {
@@ -2584,30 +2612,190 @@ pub fn get_record_index(x: usize) -> usize {
     }
     let mut index = (RECORD_INDEX0[x >> (SHIFT1 + SHIFT2)] as usize) << SHIFT1;
     let mut offset = (x >> SHIFT2) & ((1 << SHIFT1) - 1);
-    index = (RECORD_INDEX1[index + offset] as usize) << SHIFT2;
+    // Blocks of the last level overlap, so the middle level stores offsets
+    // rather than block numbers.
+    index = RECORD_INDEX1[index + offset] as usize;
     offset = x & ((1 << SHIFT2) - 1);
     RECORD_INDEX2[index + offset] as usize
 }
 
+/// Bit set of the blocks of the middle level where every code point maps to
+/// the same record.
+const UNIFORM_BLOCKS: [u32; 8] = {
+    const SHIFT1: usize = 5;
+    const SHIFT2: usize = 3;
+    let mut bits = [0u32; 8];
+    let mut block = 0;
+    while block < RECORD_INDEX1.len() >> SHIFT1 {
+        let base = block << SHIFT1;
+        let leaf = RECORD_INDEX1[base] as usize;
+        let mut uniform = true;
+        let mut i = 0;
+        while i < 1 << SHIFT1 {
+            uniform &= RECORD_INDEX1[base + i] as usize == leaf;
+            i += 1;
+        }
+        i = 0;
+        while i < 1 << SHIFT2 {
+            uniform &= RECORD_INDEX2[leaf + i] == RECORD_INDEX2[leaf];
+            i += 1;
+        }
+        if uniform {
+            bits[block >> 5] |= 1 << (block & 31);
+        }
+        block += 1;
+    }
+    bits
+};
+
+/// Memoized record lookup for sequences of code points.
+///
+/// This retains the record for the last block of the middle level where
+/// every code point maps to the same record. Such blocks are shared across
+/// large ranges such as CJK ideographs, so runs of these characters are
+/// resolved with a single comparison.
+#[derive(Copy, Clone)]
+pub struct RecordCache {
+    block: u16,
+    index: u16,
+}
+
+impl RecordCache {
+    pub const fn new() -> Self {
+        Self {
+            // Outside the range of the first level.
+            block: u16::MAX,
+            index: 0,
+        }
+    }
+
+    #[inline]
+    pub fn get(&mut self, x: usize) -> usize {
+        const SHIFT1: usize = 5;
+        const SHIFT2: usize = 3;
+        if x >= 1114112 {
+            return 0;
+        }
+        let block = RECORD_INDEX0[x >> (SHIFT1 + SHIFT2)] as usize;
+        if block == self.block as usize {
+            return self.index as usize;
+        }
+        let offset = (x >> SHIFT2) & ((1 << SHIFT1) - 1);
+        let index = RECORD_INDEX1[(block << SHIFT1) + offset] as usize;
+        let index = RECORD_INDEX2[index + (x & ((1 << SHIFT2) - 1))];
+        if UNIFORM_BLOCKS[block >> 5] & (1 << (block & 31)) != 0 {
+            self.block = block as u16;
+            self.index = index;
+        }
+        index as usize
+    }
+}
+
+/// Packed properties for a set of characters.
+///
+/// Enumerated properties are stored as bit fields in groups that correspond
+/// to the optional features so that disabled groups occupy no space.
 #[derive(Copy, Clone)]
 pub struct Record {
     pub flags: Flags,
-    pub category: Category,
+    /// Script, decomposition type and joining type.
+    script: u16,
+    /// Category, cluster break and word break.
+    category: u16,
+    /// Canonical combining class and line break.
+    combining: u16,
+    #[cfg(feature = "blocks")]
     pub block: Block,
-    pub script: Script,
-    pub combining_class: u8,
-    pub bidi_class: BidiClass,
-    pub joining_type: JoiningType,
-    pub cluster_break: ClusterBreak,
-    pub word_break: WordBreak,
-    pub line_break: LineBreak,
-    pub use_class: UseClass,
-    pub myanmar_class: MyanmarClass,
+    /// BiDi class and index of the mirroring offset.
+    #[cfg(feature = "bidi")]
+    bidi: u16,
+    /// Universal Shaping Engine and Myanmar shaping classes.
+    #[cfg(feature = "complex-scripts")]
+    shaping: u16,
+}
+
+// SAFETY: The bit fields are populated from valid discriminants of the
+// corresponding enums on construction.
+impl Record {
+    pub fn category(&self) -> Category {
+        unsafe { core::mem::transmute((self.category & 0x3F) as u8) }
+    }
+
+    pub fn script(&self) -> Script {
+        unsafe { core::mem::transmute(self.script as u8) }
+    }
+
+    pub fn combining_class(&self) -> u8 {
+        self.combining as u8
+    }
+
+    #[cfg(feature = "bidi")]
+    pub fn bidi_class(&self) -> BidiClass {
+        unsafe { core::mem::transmute((self.bidi & 0x1F) as u8) }
+    }
+
+    pub fn joining_type(&self) -> JoiningType {
+        unsafe { core::mem::transmute((self.script >> 13) as u8) }
+    }
+
+    pub fn cluster_break(&self) -> ClusterBreak {
+        unsafe { core::mem::transmute((self.category >> 6 & 0xF) as u8) }
+    }
+
+    pub fn word_break(&self) -> WordBreak {
+        unsafe { core::mem::transmute((self.category >> 10 & 0x1F) as u8) }
+    }
+
+    pub fn line_break(&self) -> LineBreak {
+        unsafe { core::mem::transmute((self.combining >> 8 & 0x3F) as u8) }
+    }
+
+    #[cfg(feature = "complex-scripts")]
+    pub fn use_class(&self) -> UseClass {
+        unsafe { core::mem::transmute((self.shaping & 0x3F) as u8) }
+    }
+
+    #[cfg(feature = "complex-scripts")]
+    pub fn myanmar_class(&self) -> MyanmarClass {
+        unsafe { core::mem::transmute((self.shaping >> 6 & 0x1F) as u8) }
+    }
+
+    pub fn decomposition_type(&self) -> DecompositionType {
+        unsafe { core::mem::transmute((self.script >> 8 & 0x1F) as u8) }
+    }
+
+    /// Returns the offset from the character to its mirror or zero if the
+    /// character has no mirror.
+    #[cfg(feature = "bidi")]
+    pub fn mirror(&self) -> i16 {
+        MIRROR_OFFSETS[(self.bidi >> 5) as usize]
+    }
+}
+
+/// Distinct mirroring offsets, referenced by index from the records.
+#[cfg(feature = "bidi")]
+#[rustfmt::skip]
+const MIRROR_OFFSETS: [i16; 37] = [
+    0, 1, -1, 2, -2, 3, -3, 7, -7, 8, -8, 16, -16, 138, -138, 1316, -1316, 1824, -1824, 1914,
+    -1914, 1918, -1918, 1923, -1923, 2016, -2016, 2104, -2104, 2106, -2106, 2108, -2108, 2250,
+    -2250, 2527, -2527,
+];
+
+#[cfg(feature = "bidi")]
+const fn mirror_index(offset: i16) -> u16 {
+    let mut i = 0;
+    while i < MIRROR_OFFSETS.len() {
+        if MIRROR_OFFSETS[i] == offset {
+            return i as u16;
+        }
+        i += 1;
+    }
+    panic!("missing mirroring offset")
 }
 
 #[derive(Copy, Clone)]
 #[repr(transparent)]
-pub struct Flags(pub u8);
+pub struct Flags(pub u16);
 
 impl Flags {
     pub fn is_emoji(self) -> bool {
@@ -2641,10 +2829,43 @@ impl Flags {
     pub fn needs_decomp(self) -> bool {
         self.0 & 128 != 0
     }
+
+    pub fn is_default_ignorable(self) -> bool {
+        self.0 & 256 != 0
+    }
+
+    pub fn is_noncharacter(self) -> bool {
+        self.0 & 512 != 0
+    }
+
+    pub fn is_deprecated(self) -> bool {
+        self.0 & 1024 != 0
+    }
+
+    pub fn is_math(self) -> bool {
+        self.0 & 2048 != 0
+    }
+
+    pub fn is_alphabetic(self) -> bool {
+        self.0 & 4096 != 0
+    }
+
+    pub fn is_case_ignorable(self) -> bool {
+        self.0 & 8192 != 0
+    }
+
+    pub fn is_cased(self) -> bool {
+        self.0 & 16384 != 0
+    }
+
+    pub fn is_whitespace(self) -> bool {
+        self.0 & 32768 != 0
+    }
 }
 
+#[allow(unused_variables)]
 const fn r(
-    flags: u8,
+    flags: u16,
     category: Category,
     block: Block,
     script: Script,
@@ -2656,1073 +2877,1618 @@ const fn r(
     line_break: LineBreak,
     use_class: UseClass,
     myanmar_class: MyanmarClass,
+    decomposition_type: DecompositionType,
+    mirror: i16,
 ) -> Record {
     Record {
         flags: Flags(flags),
-        category,
+        script: script as u16 | (decomposition_type as u16) << 8 | (joining_type as u16) << 13,
+        category: category as u16 | (cluster_break as u16) << 6 | (word_break as u16) << 10,
+        combining: combining_class as u16 | (line_break as u16) << 8,
+        #[cfg(feature = "blocks")]
         block,
-        script,
-        combining_class,
-        bidi_class,
-        joining_type,
-        cluster_break,
-        word_break,
-        line_break,
-        use_class,
-        myanmar_class,
+        #[cfg(feature = "bidi")]
+        bidi: bidi_class as u16 | mirror_index(mirror) << 5,
+        #[cfg(feature = "complex-scripts")]
+        shaping: use_class as u16 | (myanmar_class as u16) << 6,
     }
 }
 
 use self::{
-    BidiClass as Bc, Block as B, Category as C, ClusterBreak as Cb, JoiningType as Jt,
-    LineBreak as Lb, MyanmarClass as Mc, Script as S, UseClass as Uc, WordBreak as Wb,
+    BidiClass as Bc, Block as B, Category as C, ClusterBreak as Cb, DecompositionType as Dt,
+    JoiningType as Jt, LineBreak as Lb, MyanmarClass as Mc, Script as S, UseClass as Uc,
+    WordBreak as Wb,
 };
 
 #[rustfmt::skip]
-pub const RECORDS: [Record; 2035] = [
-    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::BN,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O), r(32,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::XX,Lb::BA,Uc::O,Mc::O),
-    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::LF,Wb::LF,Lb::LF,Uc::O,Mc::O), r(32,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O),
-    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::WS,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O), r(32,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::CR,Wb::CR,Lb::CR,Uc::O,Mc::O),
-    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O), r(32,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O),
-    r(64,C::SpaceSeparator,B::BasicLatin,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::SP,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::DQ,Lb::QU,Uc::IND,Mc::O), r(65,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::CurrencySymbol,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::SQ,Lb::QU,Uc::IND,Mc::O),
-    r(68,C::OpenPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O), r(72,C::ClosePunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CP,Uc::O,Mc::O),
-    r(65,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O), r(64,C::DashPunctuation,B::BasicLatin,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::HY,Uc::GB,Mc::O),
-    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::IS,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::SY,Uc::IND,Mc::O),
-    r(65,C::DecimalNumber,B::BasicLatin,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O), r(64,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::BasicLatin,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::IND,Mc::O),
-    r(64,C::ModifierSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::ConnectorPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::BasicLatin,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(32,C::Control,B::Latin1Supplement,S::Common,0,Bc::BN,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Control,B::Latin1Supplement,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::NL,Lb::NL,Uc::O,Mc::O), r(64,C::SpaceSeparator,B::Latin1Supplement,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::GB,Mc::GB),
-    r(64,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O), r(64,C::CurrencySymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::CurrencySymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::IND,Mc::O), r(64,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(67,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O),
-    r(64,C::InitialPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O), r(64,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(32,C::Format,B::Latin1Supplement,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::BA,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::FM,Mc::O), r(64,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::Latin1Supplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::AI,Uc::IND,Mc::O),
-    r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::FinalPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::GB,Mc::GB), r(64,C::LowercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::TitlecaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::IPAExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::IPAExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BB,Uc::O,Mc::O), r(64,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BB,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::SpacingModifierLetters,S::Bopomofo,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,216,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,202,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,240,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::CGJ,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,233,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,234,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::GreekandCoptic,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::GreekandCoptic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::ModifierSymbol,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::UppercaseLetter,B::GreekandCoptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::GreekandCoptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::GreekandCoptic,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Cyrillic,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Cyrillic,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::EnclosingMark,B::Cyrillic,S::Cyrillic,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::CyrillicSupplement,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::CyrillicSupplement,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Armenian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O), r(64,C::LowercaseLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O), r(64,C::DashPunctuation,B::Armenian,S::Armenian,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BA,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Armenian,S::Armenian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::CurrencySymbol,B::Armenian,S::Armenian,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::Other,B::Hebrew,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,10,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,11,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,12,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,13,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,14,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,15,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,16,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,17,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,18,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,19,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,20,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,21,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,22,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::DashPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,23,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,24,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hebrew,S::Hebrew,25,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O), r(32,C::Format,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O),
-    r(32,C::Format,B::Arabic,S::Common,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O), r(64,C::MathSymbol,B::Arabic,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O),
-    r(64,C::CurrencySymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::OtherPunctuation,B::Arabic,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O), r(64,C::OtherSymbol,B::Arabic,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Arabic,30,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Arabic,31,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Arabic,32,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Arabic,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(32,C::Format,B::Arabic,S::Arabic,0,Bc::AL,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::Arabic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Arabic,S::Common,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Inherited,27,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,28,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Inherited,29,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,30,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Inherited,31,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,32,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Inherited,33,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,34,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Arabic,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::MN,Lb::NU,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Arabic,S::Inherited,35,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Arabic,S::Arabic,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherPunctuation,B::Syriac,S::Syriac,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::Other,B::Syriac,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(32,C::Format,B::Syriac,S::Syriac,0,Bc::AL,Jt::T,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::Alaph,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Syriac,S::Syriac,36,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::DalathRish,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Syriac,S::Syriac,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Syriac,S::Syriac,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherLetter,B::ArabicSupplement,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::ArabicSupplement,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Thaana,S::Thaana,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Thaana,S::Thaana,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::Thaana,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::OtherLetter,B::NKo,S::Nko,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::NKo,S::Nko,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::NKo,S::Nko,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::ModifierLetter,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::ModifierLetter,B::NKo,S::Nko,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::NKo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::NKo,S::Nko,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O),
-    r(64,C::CurrencySymbol,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::OtherLetter,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Samaritan,S::Samaritan,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Samaritan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Mandaic,S::Mandaic,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Mandaic,S::Mandaic,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Mandaic,S::Mandaic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::Other,B::Mandaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Mandaic,S::Mandaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::SyriacSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::ArabicExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::ArabicExtendedA,S::Common,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,27,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,28,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,29,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Devanagari,S::Devanagari,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Devanagari,S::Devanagari,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Devanagari,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::Devanagari,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Devanagari,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Devanagari,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::ModifierLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::NonspacingMark,B::Bengali,S::Bengali,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::Other,B::Bengali,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Bengali,S::Bengali,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Bengali,S::Bengali,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(192,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Bengali,S::Bengali,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::CurrencySymbol,B::Bengali,S::Bengali,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::CurrencySymbol,B::Bengali,S::Bengali,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Bengali,S::Bengali,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O),
-    r(64,C::Other,B::Gurmukhi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O), r(64,C::DecimalNumber,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Gujarati,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::CurrencySymbol,B::Gujarati,S::Gujarati,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::Other,B::Oriya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Oriya,S::Oriya,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(192,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Oriya,S::Oriya,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::DecimalNumber,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherSymbol,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Tamil,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Tamil,S::Tamil,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Tamil,S::Tamil,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(192,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Tamil,S::Tamil,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherNumber,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Tamil,S::Tamil,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::CurrencySymbol,B::Tamil,S::Tamil,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Telugu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(192,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Telugu,S::Telugu,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::Telugu,S::Telugu,84,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Telugu,S::Telugu,91,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::DecimalNumber,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::OtherNumber,B::Telugu,S::Telugu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherLetter,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherPunctuation,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::Other,B::Kannada,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Kannada,S::Kannada,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(192,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Kannada,S::Kannada,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::DecimalNumber,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O),
-    r(64,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Malayalam,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Malayalam,S::Malayalam,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(192,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Malayalam,S::Malayalam,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherNumber,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherSymbol,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::Other,B::Sinhala,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::OtherLetter,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Sinhala,S::Sinhala,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(192,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::DecimalNumber,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Thai,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O), r(64,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::SM,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Thai,S::Thai,103,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Thai,S::Thai,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O),
-    r(64,C::CurrencySymbol,B::Thai,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::ModifierLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Thai,S::Thai,107,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O), r(64,C::OtherPunctuation,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::Other,B::Lao,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O),
-    r(64,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::SM,Wb::XX,Lb::SA,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Lao,S::Lao,118,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Lao,S::Lao,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O),
-    r(64,C::ModifierLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Lao,S::Lao,122,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O),
-    r(64,C::DecimalNumber,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::B,Mc::O), r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherNumber,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::S,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,216,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(68,C::OpenPunctuation,B::Tibetan,S::Tibetan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::Tibetan,S::Tibetan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::SpacingMark,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::Tibetan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,129,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,130,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(192,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,132,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(192,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::BA,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O),
-    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherSymbol,B::Tibetan,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::C), r(64,C::OtherLetter,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::IV),
-    r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::VPst), r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::VAbv),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::VBlw), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::VPre),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::A), r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::A),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMBlw,Mc::DB), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::V),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::H), r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::As),
-    r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPst,Mc::MY), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPre,Mc::MR),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MW), r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MH),
-    r(64,C::DecimalNumber,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::D0), r(64,C::DecimalNumber,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::D),
-    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::P), r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::P),
-    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::S), r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::C),
-    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::VPst),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MY), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::PT),
-    r(64,C::NonspacingMark,B::Myanmar,S::Myanmar,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMBlw,Mc::V), r(64,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::V),
-    r(64,C::OtherSymbol,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::S), r(64,C::UppercaseLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Georgian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Georgian,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::ModifierLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::L,Wb::LE,Lb::JL,Uc::O,Mc::O), r(64,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::V,Wb::LE,Lb::JV,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::T,Wb::LE,Lb::JT,Uc::O,Mc::O), r(64,C::OtherLetter,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Ethiopic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Ethiopic,S::Ethiopic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherNumber,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::EthiopicSupplement,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::EthiopicSupplement,S::Ethiopic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::EthiopicSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::Cherokee,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Cherokee,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::Cherokee,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::DashPunctuation,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::SpaceSeparator,B::Ogham,S::Ogham,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Ogham,S::Ogham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(68,C::OpenPunctuation,B::Ogham,S::Ogham,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::Ogham,S::Ogham,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::Other,B::Ogham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Runic,S::Runic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Runic,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::LetterNumber,B::Runic,S::Runic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Runic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Tagalog,S::Tagalog,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Tagalog,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Tagalog,S::Tagalog,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Tagalog,S::Tagalog,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Tagalog,S::Tagalog,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::OtherLetter,B::Hanunoo,S::Hanunoo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Hanunoo,S::Hanunoo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Hanunoo,S::Hanunoo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Hanunoo,S::Hanunoo,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::OtherPunctuation,B::Hanunoo,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::Other,B::Hanunoo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Buhid,S::Buhid,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Buhid,S::Buhid,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Buhid,S::Buhid,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::Other,B::Buhid,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Tagbanwa,S::Tagbanwa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Tagbanwa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Tagbanwa,S::Tagbanwa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Tagbanwa,S::Tagbanwa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::OtherLetter,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O), r(64,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FM,Mc::O),
-    r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FAbv,Mc::O), r(64,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Khmer,S::Khmer,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::O), r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O), r(64,C::ModifierLetter,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::CurrencySymbol,B::Khmer,S::Khmer,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Khmer,S::Khmer,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FM,Mc::O), r(64,C::Other,B::Khmer,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherNumber,B::Khmer,S::Khmer,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Mongolian,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Mongolian,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::DashPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::NonspacingMark,B::Mongolian,S::Mongolian,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::Mongolian,S::Mongolian,0,Bc::BN,Jt::U,Cb::CN,Wb::FO,Lb::GL,Uc::O,Mc::O),
-    r(64,C::Other,B::Mongolian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Mongolian,S::Mongolian,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::ModifierLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::NonspacingMark,B::Mongolian,S::Mongolian,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::NonspacingMark,B::Mongolian,S::Mongolian,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherLetter,B::UnifiedCanadianAboriginalSyllabicsExtended,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::UnifiedCanadianAboriginalSyllabicsExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::OtherLetter,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Limbu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O), r(64,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O),
-    r(64,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O), r(64,C::NonspacingMark,B::Limbu,S::Limbu,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Limbu,S::Limbu,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Limbu,S::Limbu,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O),
-    r(64,C::OtherSymbol,B::Limbu,S::Limbu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherPunctuation,B::Limbu,S::Limbu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::TaiLe,S::TaiLe,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::Other,B::TaiLe,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::Other,B::NewTaiLue,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::VMPst,Mc::O),
-    r(64,C::DecimalNumber,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherNumber,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::OtherSymbol,B::NewTaiLue,S::NewTaiLue,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::O), r(64,C::OtherSymbol,B::KhmerSymbols,S::Khmer,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Buginese,S::Buginese,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Buginese,S::Buginese,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::SpacingMark,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Buginese,S::Buginese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::Other,B::Buginese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O), r(64,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPre,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O), r(64,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::SUB,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FAbv,Mc::O), r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::SUB,Mc::O), r(64,C::Other,B::TaiTham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::O), r(64,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::TaiTham,S::TaiTham,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::DecimalNumber,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::IND,Mc::O),
-    r(64,C::ModifierLetter,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::EnclosingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::CombiningDiacriticalMarksExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O), r(64,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Balinese,S::Balinese,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(192,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(192,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(192,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Balinese,S::Balinese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::Other,B::Balinese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherSymbol,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::NonspacingMark,B::Balinese,S::Balinese,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Balinese,S::Balinese,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O), r(64,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O),
-    r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O), r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Sundanese,S::Sundanese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Sundanese,S::Sundanese,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::DecimalNumber,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Batak,S::Batak,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Batak,S::Batak,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Batak,S::Batak,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O), r(64,C::SpacingMark,B::Batak,S::Batak,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::Other,B::Batak,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O),
-    r(64,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O),
-    r(64,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPre,Mc::O), r(64,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Lepcha,S::Lepcha,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::Other,B::Lepcha,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::DecimalNumber,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::DecimalNumber,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::OtherLetter,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::LowercaseLetter,B::CyrillicExtendedC,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::CyrillicExtendedC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::GeorgianExtended,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::GeorgianExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::SundaneseSupplement,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::SundaneseSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::OtherPunctuation,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O), r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::SpacingMark,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O),
-    r(64,C::NonspacingMark,B::VedicExtensions,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::Other,B::VedicExtensions,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::PhoneticExtensions,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::PhoneticExtensions,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::PhoneticExtensions,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::PhoneticExtensions,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::PhoneticExtensionsSupplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::PhoneticExtensionsSupplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::PhoneticExtensionsSupplement,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,234,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,214,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,202,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::CombiningDiacriticalMarksSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,233,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::GreekExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::TitlecaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::GreekExtended,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::GreekExtended,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O),
-    r(64,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O), r(64,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::XX,Lb::ZW,Uc::O,Mc::O), r(96,C::Format,B::GeneralPunctuation,S::Inherited,0,Bc::BN,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::ZWNJ,Mc::J),
-    r(96,C::Format,B::GeneralPunctuation,S::Inherited,0,Bc::BN,Jt::D,Cb::ZWJ,Wb::ZWJ,Lb::ZWJ,Uc::ZWJ,Mc::J), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::R,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::O),
-    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::GB,Mc::O), r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::GB),
-    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::B2,Uc::GB,Mc::GB), r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::GB,Mc::GB),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::InitialPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::QU,Uc::O,Mc::O), r(64,C::FinalPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::QU,Uc::O,Mc::O),
-    r(64,C::OpenPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O), r(64,C::InitialPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O),
-    r(64,C::FinalPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::GB),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::IN,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::BA,Uc::IND,Mc::O), r(64,C::LineSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O),
-    r(64,C::ParagraphSeparator,B::GeneralPunctuation,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRE,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLE,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::PDF,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRO,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLO,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(64,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::EX,Lb::GL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O), r(67,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O), r(64,C::ConnectorPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::GeneralPunctuation,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::O,Mc::O), r(68,C::OpenPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::MathSymbol,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::WJ,Uc::WJ,Mc::WJ),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::GeneralPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::FSI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::PDI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::SuperscriptsandSubscripts,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::SuperscriptsandSubscripts,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::FM,Mc::O), r(64,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(68,C::OpenPunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::SuperscriptsandSubscripts,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::CurrencySymbol,B::CurrencySymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::CurrencySymbol,B::CurrencySymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::Other,B::CurrencySymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::EnclosingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::Other,B::CombiningDiacriticalMarksforSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::UppercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::MathSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(67,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(64,C::UppercaseLetter,B::LetterlikeSymbols,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(67,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(64,C::LetterNumber,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O), r(64,C::LetterNumber,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::NumberForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(67,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(67,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O), r(64,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::O,Mc::O), r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(68,C::OpenPunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O), r(72,C::ClosePunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(67,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::MathSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(67,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::ControlPictures,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::ControlPictures,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::OpticalCharacterRecognition,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::OpticalCharacterRecognition,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O), r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::BoxDrawing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::BoxDrawing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::BlockElements,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::BlockElements,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(64,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::GB),
-    r(64,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(67,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::GB),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O), r(66,C::MathSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O),
-    r(66,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::S,Mc::O), r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::S,Mc::O), r(68,C::OpenPunctuation,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OtherNumber,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(68,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::MathSymbol,B::SupplementalArrowsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::BraillePatterns,S::Braille,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::MathSymbol,B::SupplementalArrowsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(67,C::MathSymbol,B::SupplementalArrowsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(68,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O),
-    r(64,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::MathSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O),
-    r(64,C::Other,B::MiscellaneousSymbolsandArrows,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Glagolitic,S::Glagolitic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Glagolitic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::Glagolitic,S::Glagolitic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Coptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::Coptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Coptic,S::Coptic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::Coptic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherNumber,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::GeorgianSupplement,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::GeorgianSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Tifinagh,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Tifinagh,S::Tifinagh,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::EthiopicExtended,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::EthiopicExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CyrillicExtendedA,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::IND,Mc::O),
-    r(64,C::InitialPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O), r(64,C::FinalPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O),
-    r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(68,C::OpenPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::ModifierLetter,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::B2,Uc::O,Mc::O),
-    r(64,C::OpenPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O), r(64,C::OtherSymbol,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::SupplementalPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::CJKRadicalsSupplement,S::Han,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::Other,B::CJKRadicalsSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::KangxiRadicals,S::Han,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::Other,B::KangxiRadicals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::IdeographicDescriptionCharacters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::Other,B::IdeographicDescriptionCharacters,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpaceSeparator,B::CJKSymbolsandPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O),
-    r(64,C::OtherSymbol,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::LetterNumber,B::CJKSymbolsandPunctuation,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(68,C::OpenPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O), r(72,C::ClosePunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O),
-    r(64,C::DashPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::O,Mc::O), r(64,C::OpenPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(64,C::ClosePunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,218,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::SpacingMark,B::CJKSymbolsandPunctuation,S::Hangul,224,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(67,C::DashPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CM,Uc::O,Mc::O), r(64,C::OtherLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(67,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O), r(64,C::Other,B::Hiragana,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::CJ,Uc::O,Mc::O), r(64,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Hiragana,S::Inherited,8,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::Hiragana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::O,Mc::O), r(64,C::DashPunctuation,B::Katakana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O), r(64,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Katakana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O), r(64,C::ModifierLetter,B::Katakana,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O), r(64,C::Other,B::Bopomofo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Bopomofo,S::Bopomofo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::HangulCompatibilityJamo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulCompatibilityJamo,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Kanbun,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::Kanbun,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherLetter,B::BopomofoExtended,S::Bopomofo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::CJKStrokes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::Other,B::CJKStrokes,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::KatakanaPhoneticExtensions,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O), r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::Other,B::EnclosedCJKLettersandMonths,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(67,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::S,Mc::O), r(64,C::OtherSymbol,B::CJKCompatibility,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::CJKCompatibility,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::OtherSymbol,B::CJKCompatibility,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionA,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherSymbol,B::YijingHexagramSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographs,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::YiSyllables,S::Yi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O), r(64,C::ModifierLetter,B::YiSyllables,S::Yi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(64,C::Other,B::YiSyllables,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::YiRadicals,S::Yi,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::Other,B::YiRadicals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Vai,S::Vai,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Vai,S::Vai,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::Other,B::Vai,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::CyrillicExtendedB,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::EnclosingMark,B::CyrillicExtendedB,S::Cyrillic,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::CyrillicExtendedB,S::Cyrillic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::ModifierLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LetterNumber,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Bamum,S::Bamum,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::Other,B::Bamum,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::LatinExtendedD,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::LatinExtendedD,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::LatinExtendedD,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::LatinExtendedD,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::SylotiNagri,S::SylotiNagri,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::OtherSymbol,B::SylotiNagri,S::SylotiNagri,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::Other,B::SylotiNagri,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::CommonIndicNumberForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::CommonIndicNumberForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::CurrencySymbol,B::CommonIndicNumberForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::OtherSymbol,B::CommonIndicNumberForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::CommonIndicNumberForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Phagspa,S::PhagsPa,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Phagspa,S::PhagsPa,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::Other,B::Phagspa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O),
-    r(64,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Saurashtra,S::Saurashtra,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::Saurashtra,S::Saurashtra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::Other,B::Saurashtra,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::DecimalNumber,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::DevanagariExtended,S::Devanagari,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::OtherLetter,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::DevanagariExtended,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::DecimalNumber,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::KayahLi,S::KayahLi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::KayahLi,S::KayahLi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::KayahLi,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O),
-    r(64,C::SpacingMark,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O), r(64,C::SpacingMark,B::Rejang,S::Rejang,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::Other,B::Rejang,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::HangulJamoExtendedA,S::Hangul,0,Bc::L,Jt::U,Cb::L,Wb::LE,Lb::JL,Uc::O,Mc::O), r(64,C::Other,B::HangulJamoExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Javanese,S::Javanese,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O), r(64,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O),
-    r(64,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::SpacingMark,B::Javanese,S::Javanese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherPunctuation,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::Other,B::Javanese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::ModifierLetter,B::Javanese,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::MyanmarExtendedB,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::ModifierLetter,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::Other,B::MyanmarExtendedB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O),
-    r(64,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPre,Mc::O), r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O), r(64,C::Other,B::Cham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O), r(64,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O),
-    r(64,C::DecimalNumber,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::C),
-    r(64,C::ModifierLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::S), r(64,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::GB,Mc::C),
-    r(64,C::OtherSymbol,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::S), r(64,C::SpacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::PT),
-    r(64,C::NonspacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::R), r(64,C::SpacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::R),
-    r(64,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::R), r(64,C::OtherLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::TaiViet,S::TaiViet,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::TaiViet,S::TaiViet,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::TaiViet,S::TaiViet,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O), r(64,C::Other,B::TaiViet,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O), r(64,C::ModifierLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::IND,Mc::O), r(64,C::OtherLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::OtherPunctuation,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::Other,B::MeeteiMayekExtensions,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::Other,B::EthiopicExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::EthiopicExtendedA,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::LatinExtendedE,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::LatinExtendedE,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::LatinExtendedE,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::LatinExtendedE,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::LatinExtendedE,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::LatinExtendedE,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::CherokeeSupplement,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::SpacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::Other,B::MeeteiMayek,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::HangulSyllables,S::Hangul,0,Bc::L,Jt::U,Cb::LV,Wb::LE,Lb::H2,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulSyllables,S::Hangul,0,Bc::L,Jt::U,Cb::LVT,Wb::LE,Lb::H3,Uc::O,Mc::O), r(64,C::Other,B::HangulSyllables,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulJamoExtendedB,S::Hangul,0,Bc::L,Jt::U,Cb::V,Wb::LE,Lb::JV,Uc::O,Mc::O), r(64,C::Other,B::HangulJamoExtendedB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HangulJamoExtendedB,S::Hangul,0,Bc::L,Jt::U,Cb::T,Wb::LE,Lb::JT,Uc::O,Mc::O), r(64,C::Surrogate,B::HighSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O),
-    r(64,C::Surrogate,B::HighPrivateUseSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O), r(64,C::Surrogate,B::LowSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O),
-    r(64,C::PrivateUse,B::PrivateUseArea,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::CJKCompatibilityIdeographs,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::Other,B::CJKCompatibilityIdeographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::AlphabeticPresentationForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::AlphabeticPresentationForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::AlphabeticPresentationForms,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::AlphabeticPresentationForms,S::Hebrew,26,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::ModifierSymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::ArabicPresentationFormsA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::ClosePunctuation,B::ArabicPresentationFormsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OpenPunctuation,B::ArabicPresentationFormsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(64,C::CurrencySymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::OtherSymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(80,C::NonspacingMark,B::VariationSelectors,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::IND,Mc::VS), r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::IS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::OpenPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(64,C::ClosePunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O),
-    r(64,C::Other,B::VerticalForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::CombiningHalfMarks,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CombiningHalfMarks,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::CombiningHalfMarks,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O), r(64,C::DashPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::ConnectorPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::ID,Uc::O,Mc::O), r(64,C::OpenPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(64,C::ClosePunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::CL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::CL,Uc::IND,Mc::O),
-    r(64,C::Other,B::SmallFormVariants,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::NS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::NS,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::DashPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(68,C::OpenPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O),
-    r(64,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::DashPunctuation,B::SmallFormVariants,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::CurrencySymbol,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O),
-    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O), r(64,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::ArabicPresentationFormsB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(32,C::Format,B::ArabicPresentationFormsB,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::WJ,Uc::O,Mc::O),
-    r(64,C::Other,B::HalfwidthandFullwidthForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O),
-    r(64,C::CurrencySymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::ID,Uc::IND,Mc::O), r(68,C::OpenPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O),
-    r(72,C::ClosePunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O), r(64,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::CL,Uc::IND,Mc::O), r(64,C::DashPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::CL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::HalfwidthandFullwidthForms,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::ID,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::NS,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::NS,Uc::IND,Mc::O), r(64,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::HalfwidthandFullwidthForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O), r(64,C::ModifierSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::ConnectorPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::ID,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::HalfwidthandFullwidthForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O), r(64,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::HalfwidthandFullwidthForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O), r(64,C::ModifierLetter,B::HalfwidthandFullwidthForms,S::Common,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::NS,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O), r(64,C::CurrencySymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::OtherSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Specials,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(32,C::Format,B::Specials,S::Common,0,Bc::ON,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Specials,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CB,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::Specials,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::Other,B::Specials,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::LinearBSyllabary,S::LinearB,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::LinearBSyllabary,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::LinearBIdeograms,S::LinearB,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::LinearBIdeograms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::AegeanNumbers,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::Other,B::AegeanNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::LetterNumber,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::AncientGreekNumbers,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::AncientGreekNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::AncientSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::Other,B::AncientSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::AncientSymbols,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherSymbol,B::PhaistosDisc,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::PhaistosDisc,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::PhaistosDisc,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Lycian,S::Lycian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Lycian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Carian,S::Carian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Carian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::CopticEpactNumbers,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherNumber,B::CopticEpactNumbers,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::CopticEpactNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::OldItalic,S::OldItalic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::OldItalic,S::OldItalic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::OldItalic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Gothic,S::Gothic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LetterNumber,B::Gothic,S::Gothic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Gothic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::OldPermic,S::OldPermic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::OldPermic,S::OldPermic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::OldPermic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Ugaritic,S::Ugaritic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Ugaritic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Ugaritic,S::Ugaritic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherLetter,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::OldPersian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::LetterNumber,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Deseret,S::Deseret,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::Deseret,S::Deseret,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Shavian,S::Shavian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Osmanya,S::Osmanya,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Osmanya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Osmanya,S::Osmanya,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Osage,S::Osage,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Osage,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::Osage,S::Osage,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Elbasan,S::Elbasan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Elbasan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CaucasianAlbanian,S::CaucasianAlbanian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::CaucasianAlbanian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::CaucasianAlbanian,S::CaucasianAlbanian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::LinearA,S::LinearA,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::LinearA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::CypriotSyllabary,S::Cypriot,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::CypriotSyllabary,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::ImperialAramaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherNumber,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherNumber,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Nabataean,S::Nabataean,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Nabataean,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Nabataean,S::Nabataean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Hatran,S::Hatran,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Hatran,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::Hatran,S::Hatran,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Phoenician,S::Phoenician,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::Phoenician,S::Phoenician,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Phoenician,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Phoenician,S::Phoenician,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Lydian,S::Lydian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Lydian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Lydian,S::Lydian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::MeroiticHieroglyphs,S::MeroiticHieroglyphs,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::MeroiticCursive,S::MeroiticCursive,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::MeroiticCursive,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::MeroiticCursive,S::MeroiticCursive,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::Other,B::Kharoshthi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O), r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherNumber,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::OldNorthArabian,S::OldNorthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::OldNorthArabian,S::OldNorthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherSymbol,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Manichaean,S::Manichaean,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::NonspacingMark,B::Manichaean,S::Manichaean,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::Other,B::Manichaean,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::Manichaean,S::Manichaean,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Manichaean,S::Manichaean,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Avestan,S::Avestan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Avestan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Avestan,S::Avestan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::InscriptionalParthian,S::InscriptionalParthian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::InscriptionalParthian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::InscriptionalParthian,S::InscriptionalParthian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::InscriptionalPahlavi,S::InscriptionalPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::InscriptionalPahlavi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::InscriptionalPahlavi,S::InscriptionalPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::PsalterPahlavi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::OldTurkic,S::OldTurkic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::OldTurkic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::OldHungarian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::HanifiRohingya,S::HanifiRohingya,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::HanifiRohingya,S::HanifiRohingya,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::Other,B::HanifiRohingya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherNumber,B::RumiNumeralSymbols,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::RumiNumeralSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Yezidi,S::Yezidi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Yezidi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Yezidi,S::Yezidi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::DashPunctuation,B::Yezidi,S::Yezidi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O), r(64,C::OtherLetter,B::OldSogdian,S::OldSogdian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::OldSogdian,S::OldSogdian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::OldSogdian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Sogdian,S::Sogdian,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Sogdian,S::Sogdian,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O), r(64,C::OtherNumber,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherNumber,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::Other,B::Sogdian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::L,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Chorasmian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Elymaic,S::Elymaic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Elymaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::OtherLetter,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Brahmi,S::Brahmi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherPunctuation,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Brahmi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Brahmi,S::Brahmi,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::N,Mc::O), r(64,C::DecimalNumber,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Brahmi,S::Brahmi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::HN,Mc::O), r(64,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::OtherLetter,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Kaithi,S::Kaithi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Kaithi,S::Kaithi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(32,C::Format,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::Other,B::Kaithi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::SoraSompeng,S::SoraSompeng,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::SoraSompeng,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::SoraSompeng,S::SoraSompeng,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Chakma,S::Chakma,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::OtherLetter,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Chakma,S::Chakma,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(192,C::NonspacingMark,B::Chakma,S::Chakma,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Chakma,S::Chakma,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Chakma,S::Chakma,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::Other,B::Chakma,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::SpacingMark,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::OtherLetter,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Mahajani,S::Mahajani,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Mahajani,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Sharada,S::Sharada,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O),
-    r(64,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O),
-    r(64,C::NonspacingMark,B::Sharada,S::Sharada,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::DecimalNumber,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::Other,B::SinhalaArchaicNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::SinhalaArchaicNumbers,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Khojki,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Khojki,S::Khojki,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Khojki,S::Khojki,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::OtherPunctuation,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Multani,S::Multani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Multani,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Multani,S::Multani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Khudawadi,S::Khudawadi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::NonspacingMark,B::Khudawadi,S::Khudawadi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::Other,B::Khudawadi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Grantha,S::Grantha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::Other,B::Grantha,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Grantha,S::Inherited,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::NonspacingMark,B::Grantha,S::Grantha,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Grantha,S::Grantha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(192,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Grantha,S::Grantha,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Grantha,S::Grantha,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Newa,S::Newa,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Newa,S::Newa,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::DecimalNumber,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::Other,B::Newa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::Newa,S::Newa,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O), r(64,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O),
-    r(64,C::OtherLetter,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(192,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Tirhuta,S::Tirhuta,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Tirhuta,S::Tirhuta,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Tirhuta,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Siddham,S::Siddham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::Other,B::Siddham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(192,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Siddham,S::Siddham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Siddham,S::Siddham,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Siddham,S::Siddham,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::SpacingMark,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Modi,S::Modi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherPunctuation,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Modi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::MongolianSupplement,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::Other,B::MongolianSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Takri,S::Takri,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::NonspacingMark,B::Takri,S::Takri,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O), r(64,C::Other,B::Takri,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O),
-    r(64,C::Other,B::Ahom,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O),
-    r(64,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MAbv,Mc::O),
-    r(64,C::SpacingMark,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O), r(64,C::SpacingMark,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Ahom,S::Ahom,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O), r(64,C::DecimalNumber,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherNumber,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherSymbol,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::O), r(64,C::OtherLetter,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::NonspacingMark,B::Dogra,S::Dogra,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::NonspacingMark,B::Dogra,S::Dogra,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Dogra,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::OtherNumber,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::WarangCiti,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::DivesAkuru,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(192,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O),
-    r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O), r(64,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::DecimalNumber,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Nandinagari,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O),
-    r(64,C::NonspacingMark,B::Nandinagari,S::Nandinagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Nandinagari,S::Nandinagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::Nandinagari,S::Nandinagari,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherPunctuation,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O), r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::SpacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O),
-    r(64,C::OtherLetter,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O), r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O),
-    r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::GB,Mc::O), r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::Other,B::ZanabazarSquare,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::OtherLetter,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O),
-    r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O), r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Soyombo,S::Soyombo,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O), r(64,C::OtherPunctuation,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O), r(64,C::Other,B::Soyombo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::PauCinHau,S::PauCinHau,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::PauCinHau,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Bhaiksuki,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,9,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherPunctuation,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::DecimalNumber,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherNumber,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O), r(64,C::OtherPunctuation,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Marchen,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O),
-    r(64,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O), r(64,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O),
-    r(64,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O), r(64,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::OtherLetter,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::MasaramGondi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O), r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O), r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O),
-    r(64,C::DecimalNumber,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherLetter,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::GunjalaGondi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::SpacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O), r(64,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O),
-    r(64,C::OtherLetter,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::DecimalNumber,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::OtherLetter,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O),
-    r(64,C::NonspacingMark,B::Makasar,S::Makasar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O), r(64,C::NonspacingMark,B::Makasar,S::Makasar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O),
-    r(64,C::SpacingMark,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O), r(64,C::SpacingMark,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O),
-    r(64,C::OtherPunctuation,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Makasar,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::LisuSupplement,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::LisuSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::TamilSupplement,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::TamilSupplement,S::Tamil,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::CurrencySymbol,B::TamilSupplement,S::Tamil,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::Other,B::TamilSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::TamilSupplement,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherLetter,B::Cuneiform,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::Cuneiform,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::LetterNumber,B::CuneiformNumbersandPunctuation,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::CuneiformNumbersandPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::CuneiformNumbersandPunctuation,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::EarlyDynasticCuneiform,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::EarlyDynasticCuneiform,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::OP,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::CL,Uc::O,Mc::O), r(64,C::Other,B::EgyptianHieroglyphs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(32,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::GL,Uc::O,Mc::O),
-    r(32,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::OP,Uc::O,Mc::O),
-    r(32,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::CL,Uc::O,Mc::O), r(64,C::Other,B::EgyptianHieroglyphFormatControls,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::OP,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::CL,Uc::O,Mc::O), r(64,C::Other,B::AnatolianHieroglyphs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::BamumSupplement,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::BamumSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::Mro,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::BassaVah,S::BassaVah,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::BassaVah,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::BassaVah,S::BassaVah,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::BassaVah,S::BassaVah,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::PahawhHmong,S::PahawhHmong,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::OtherPunctuation,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(64,C::OtherPunctuation,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O),
-    r(64,C::OtherSymbol,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::ModifierLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::PahawhHmong,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::UppercaseLetter,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::LowercaseLetter,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::Medefaidrin,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::OtherLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Miao,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Miao,S::Miao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O),
-    r(64,C::SpacingMark,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Miao,S::Miao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(64,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Nushu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::IdeographicSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O),
-    r(64,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::IdeographicSymbolsandPunctuation,S::KhitanSmallScript,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O),
-    r(64,C::Other,B::IdeographicSymbolsandPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::IdeographicSymbolsandPunctuation,S::Han,6,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Tangut,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::Tangut,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::TangutComponents,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherLetter,B::KhitanSmallScript,S::KhitanSmallScript,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::KhitanSmallScript,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::TangutSupplement,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::Other,B::TangutSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherLetter,B::KanaSupplement,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::KanaSupplement,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherLetter,B::KanaExtendedA,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::Other,B::KanaExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::Other,B::SmallKanaExtension,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::SmallKanaExtension,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::CJ,Uc::O,Mc::O), r(64,C::OtherLetter,B::SmallKanaExtension,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Nushu,S::Nushu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::Nushu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::Duployan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::B,Mc::O),
-    r(64,C::Other,B::Duployan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::Duployan,S::Duployan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::Duployan,S::Duployan,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherPunctuation,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O), r(32,C::Format,B::ShorthandFormatControls,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::ShorthandFormatControls,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::ByzantineMusicalSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::ByzantineMusicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::MusicalSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::MusicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::SpacingMark,B::MusicalSymbols,S::Common,216,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::MusicalSymbols,S::Common,216,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::MusicalSymbols,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::SpacingMark,B::MusicalSymbols,S::Common,226,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(32,C::Format,B::MusicalSymbols,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::MusicalSymbols,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::NonspacingMark,B::MusicalSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::AncientGreekMusicalNotation,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::NonspacingMark,B::AncientGreekMusicalNotation,S::Greek,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O),
-    r(64,C::Other,B::AncientGreekMusicalNotation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::MayanNumerals,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::MayanNumerals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherSymbol,B::TaiXuanJingSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::TaiXuanJingSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::CountingRodNumerals,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::CountingRodNumerals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::LowercaseLetter,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::MathematicalAlphanumericSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::MathSymbol,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::DecimalNumber,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O), r(64,C::OtherSymbol,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::NonspacingMark,B::SuttonSignWriting,S::SignWriting,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::OtherPunctuation,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O),
-    r(64,C::OtherPunctuation,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O), r(64,C::Other,B::SuttonSignWriting,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::GlagoliticSupplement,S::Glagolitic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::GlagoliticSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::Other,B::NyiakengPuachueHmong,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::ModifierLetter,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::DecimalNumber,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::OtherSymbol,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O),
-    r(64,C::OtherLetter,B::Wancho,S::Wancho,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Wancho,S::Wancho,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O),
-    r(64,C::DecimalNumber,B::Wancho,S::Wancho,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O), r(64,C::Other,B::Wancho,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::CurrencySymbol,B::Wancho,S::Wancho,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O), r(64,C::OtherLetter,B::MendeKikakui,S::MendeKikakui,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O),
-    r(64,C::Other,B::MendeKikakui,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::OtherNumber,B::MendeKikakui,S::MendeKikakui,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O),
-    r(64,C::NonspacingMark,B::MendeKikakui,S::MendeKikakui,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::UppercaseLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::LowercaseLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O), r(64,C::NonspacingMark,B::Adlam,S::Adlam,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O),
-    r(64,C::NonspacingMark,B::Adlam,S::Adlam,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O), r(64,C::ModifierLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::T,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O),
-    r(64,C::Other,B::Adlam,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::Adlam,S::Adlam,0,Bc::R,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O),
-    r(64,C::OtherPunctuation,B::Adlam,S::Adlam,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O), r(64,C::Other,B::IndicSiyaqNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O),
-    r(64,C::CurrencySymbol,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O), r(64,C::Other,B::OttomanSiyaqNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::OttomanSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(64,C::OtherSymbol,B::OttomanSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherLetter,B::ArabicMathematicalAlphabeticSymbols,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O), r(64,C::Other,B::ArabicMathematicalAlphabeticSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::MathSymbol,B::ArabicMathematicalAlphabeticSymbols,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O), r(66,C::OtherSymbol,B::MahjongTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MahjongTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::MahjongTiles,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::DominoTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::DominoTiles,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::PlayingCards,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::PlayingCards,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(67,C::OtherSymbol,B::PlayingCards,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::OtherNumber,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O),
-    r(64,C::OtherNumber,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O), r(66,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(66,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O), r(66,C::Other,B::EnclosedAlphanumericSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(65,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::RI,Wb::RI,Lb::RI,Uc::S,Mc::O), r(64,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::EnclosedIdeographicSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O), r(66,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(65,C::ModifierSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::EX,Wb::Extend,Lb::EM,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(67,C::OtherSymbol,B::Emoticons,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::Emoticons,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O), r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::S,Mc::O), r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(67,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O),
-    r(66,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::TransportandMapSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::AlchemicalSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::Other,B::AlchemicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(66,C::Other,B::GeometricShapesExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(67,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::SupplementalArrowsC,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::Other,B::SupplementalArrowsC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::SupplementalArrowsC,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(64,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(67,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O), r(67,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(64,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(66,C::Other,B::SupplementalSymbolsandPictographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::ChessSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O), r(66,C::Other,B::ChessSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(66,C::OtherSymbol,B::ChessSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O), r(67,C::OtherSymbol,B::SymbolsandPictographsExtendedA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O),
-    r(66,C::Other,B::SymbolsandPictographsExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherSymbol,B::SymbolsforLegacyComputing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O),
-    r(64,C::Other,B::SymbolsforLegacyComputing,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::DecimalNumber,B::SymbolsforLegacyComputing,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O),
-    r(66,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionB,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::Other,B::CJKUnifiedIdeographsExtensionB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionC,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographsExtensionC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionD,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographsExtensionD,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionE,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographsExtensionE,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionF,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographsExtensionF,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKCompatibilityIdeographsSupplement,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKCompatibilityIdeographsSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::OtherLetter,B::CJKUnifiedIdeographsExtensionG,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O), r(64,C::Other,B::CJKUnifiedIdeographsExtensionG,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O),
-    r(64,C::Other,B::Tags,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O), r(32,C::Format,B::Tags,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O),
-    r(32,C::Format,B::Tags,S::Common,0,Bc::BN,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(80,C::NonspacingMark,B::VariationSelectorsSupplement,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O), r(64,C::PrivateUse,B::SupplementaryPrivateUseAreaA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::Other,B::SupplementaryPrivateUseAreaA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O), r(64,C::PrivateUse,B::SupplementaryPrivateUseAreaB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
-    r(64,C::Other,B::SupplementaryPrivateUseAreaB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O),
+pub const RECORDS: [Record; 2267] = [
+    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::BN,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(32800,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0),
+    r(32800,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::LF,Wb::LF,Lb::LF,Uc::O,Mc::O,Dt::None,0), r(32800,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O,Dt::None,0),
+    r(32800,C::Control,B::BasicLatin,S::Common,0,Bc::WS,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O,Dt::None,0), r(32800,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::CR,Wb::CR,Lb::CR,Uc::O,Mc::O,Dt::None,0),
+    r(32,C::Control,B::BasicLatin,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(32,C::Control,B::BasicLatin,S::Common,0,Bc::S,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(32832,C::SpaceSeparator,B::BasicLatin,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::SP,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::DQ,Lb::QU,Uc::IND,Mc::O,Dt::None,0), r(65,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(8256,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::SQ,Lb::QU,Uc::IND,Mc::O,Dt::None,0),
+    r(68,C::OpenPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1), r(72,C::ClosePunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CP,Uc::O,Mc::O,Dt::None,-1),
+    r(65,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0), r(64,C::DashPunctuation,B::BasicLatin,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::HY,Uc::GB,Mc::O,Dt::None,0),
+    r(8256,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::IS,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::SY,Uc::IND,Mc::O,Dt::None,0),
+    r(65,C::DecimalNumber,B::BasicLatin,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(8256,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::IS,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,2),
+    r(2112,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2),
+    r(20544,C::UppercaseLetter,B::BasicLatin,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(68,C::OpenPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,2),
+    r(64,C::OtherPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::IND,Mc::O,Dt::None,0), r(72,C::ClosePunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CP,Uc::O,Mc::O,Dt::None,-2),
+    r(10304,C::ModifierSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::ConnectorPunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::BasicLatin,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0), r(72,C::ClosePunctuation,B::BasicLatin,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-2),
+    r(32,C::Control,B::Latin1Supplement,S::Common,0,Bc::BN,Jt::U,Cb::CN,Wb::XX,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(32800,C::Control,B::Latin1Supplement,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::NL,Lb::NL,Uc::O,Mc::O,Dt::None,0),
+    r(32832,C::SpaceSeparator,B::Latin1Supplement,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::GB,Mc::GB,Dt::NoBreak,0),
+    r(64,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::IND,Mc::O,Dt::None,0), r(8256,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0),
+    r(67,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(20544,C::OtherLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::Super,0),
+    r(64,C::InitialPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,16), r(2112,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::Latin1Supplement,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::BA,Uc::O,Mc::O,Dt::None,0), r(8256,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::FM,Mc::O,Dt::Super,0), r(8256,C::ModifierSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::LowercaseLetter,B::Latin1Supplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(8256,C::OtherPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::AI,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Super,0),
+    r(64,C::FinalPunctuation,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,-16), r(64,C::OtherNumber,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Fraction,0),
+    r(20544,C::UppercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::UppercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::GB,Mc::GB,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Latin1Supplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(2112,C::MathSymbol,B::Latin1Supplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(20544,C::UppercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(21568,C::LowercaseLetter,B::LatinExtendedA,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(4160,C::OtherLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(20544,C::TitlecaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedB,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(20544,C::LowercaseLetter,B::IPAExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::IPAExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(28736,C::ModifierLetter,B::SpacingModifierLetters,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(12352,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BB,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::SpacingModifierLetters,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0),
+    r(8256,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(8256,C::ModifierSymbol,B::SpacingModifierLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BB,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::SpacingModifierLetters,S::Bopomofo,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,216,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,202,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::Canonical,0),
+    r(28736,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,240,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8512,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::CGJ,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,233,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarks,S::Inherited,234,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(8256,C::ModifierSymbol,B::GreekandCoptic,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::GreekandCoptic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(28736,C::ModifierLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherPunctuation,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::Canonical,0), r(8256,C::ModifierSymbol,B::GreekandCoptic,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(8256,C::ModifierSymbol,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::UppercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(8256,C::OtherPunctuation,B::GreekandCoptic,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O,Dt::Canonical,0),
+    r(20544,C::LowercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(22592,C::LowercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(22592,C::UppercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(20544,C::LowercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::UppercaseLetter,B::GreekandCoptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::GreekandCoptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::GreekandCoptic,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::GreekandCoptic,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::UppercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(20544,C::UppercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(64,C::OtherSymbol,B::Cyrillic,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Cyrillic,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Cyrillic,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8256,C::EnclosingMark,B::Cyrillic,S::Cyrillic,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::CyrillicSupplement,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::CyrillicSupplement,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Armenian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(64,C::OtherPunctuation,B::Armenian,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::Armenian,S::Armenian,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::BA,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Armenian,S::Armenian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Armenian,S::Armenian,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::Hebrew,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Hebrew,S::Hebrew,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Hebrew,S::Hebrew,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Hebrew,S::Hebrew,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Hebrew,S::Hebrew,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,10,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,11,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,12,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,13,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,14,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,15,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,16,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,17,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,18,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,19,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,20,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,21,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,22,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::DashPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,23,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,24,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,25,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hebrew,S::Hebrew,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(8256,C::OtherPunctuation,B::Hebrew,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::ML,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(8224,C::Format,B::Arabic,S::Common,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::Arabic,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Arabic,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Arabic,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Arabic,30,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Arabic,31,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Arabic,32,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Arabic,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::Arabic,S::Arabic,0,Bc::AL,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Arabic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Arabic,S::Common,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,27,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Inherited,28,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,29,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Inherited,30,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,31,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Inherited,32,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,33,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Inherited,34,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Arabic,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Arabic,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::MN,Lb::NU,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Arabic,S::Inherited,35,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(5184,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(4160,C::OtherLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(12352,C::ModifierLetter,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Arabic,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Arabic,S::Arabic,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Arabic,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Syriac,S::Syriac,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Syriac,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8224,C::Format,B::Syriac,S::Syriac,0,Bc::AL,Jt::T,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::Alaph,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Syriac,S::Syriac,36,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::DalathRish,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Syriac,S::Syriac,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Syriac,S::Syriac,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Syriac,S::Syriac,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Syriac,S::Syriac,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Syriac,S::Syriac,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::ArabicSupplement,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ArabicSupplement,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Thaana,S::Thaana,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Thaana,S::Thaana,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Thaana,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::NKo,S::Nko,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::NKo,S::Nko,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::NKo,S::Nko,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::NKo,S::Nko,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::NKo,S::Nko,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::NKo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::NKo,S::Nko,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::NKo,S::Nko,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Samaritan,S::Samaritan,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Samaritan,S::Samaritan,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Samaritan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Samaritan,S::Samaritan,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Mandaic,S::Mandaic,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Mandaic,S::Mandaic,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Mandaic,S::Mandaic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Mandaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mandaic,S::Mandaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::SyriacSupplement,S::Syriac,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::SyriacSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ArabicExtendedA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::ArabicExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8224,C::Format,B::ArabicExtendedA,S::Common,0,Bc::AN,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,27,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,28,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ArabicExtendedA,S::Arabic,29,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0),
+    r(12352,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Devanagari,S::Devanagari,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Devanagari,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Devanagari,S::Devanagari,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Devanagari,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Devanagari,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Devanagari,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Devanagari,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Devanagari,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Bengali,S::Bengali,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Bengali,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Bengali,S::Bengali,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Bengali,S::Bengali,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4288,C::SpacingMark,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Bengali,S::Bengali,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0), r(64,C::DecimalNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Bengali,S::Bengali,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Bengali,S::Bengali,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Bengali,S::Bengali,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Bengali,S::Bengali,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0), r(64,C::Other,B::Gurmukhi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Gurmukhi,S::Gurmukhi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Gurmukhi,S::Gurmukhi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Gujarati,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Gujarati,S::Gujarati,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Gujarati,S::Gujarati,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Gujarati,S::Gujarati,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Gujarati,S::Gujarati,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Gujarati,S::Gujarati,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Oriya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Oriya,S::Oriya,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0), r(8256,C::NonspacingMark,B::Oriya,S::Oriya,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Oriya,S::Oriya,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0), r(64,C::DecimalNumber,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Oriya,S::Oriya,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Tamil,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Tamil,S::Tamil,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0), r(4160,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Tamil,S::Tamil,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4288,C::SpacingMark,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Tamil,S::Tamil,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Tamil,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Tamil,S::Tamil,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::Tamil,S::Tamil,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Telugu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12480,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Telugu,S::Telugu,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Telugu,S::Telugu,84,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Telugu,S::Telugu,91,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Telugu,S::Telugu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Telugu,S::Telugu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Telugu,S::Telugu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Kannada,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Kannada,S::Kannada,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Canonical,0), r(4160,C::SpacingMark,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Kannada,S::Kannada,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kannada,S::Kannada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Kannada,S::Kannada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Malayalam,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Malayalam,S::Malayalam,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Malayalam,S::Malayalam,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Malayalam,S::Malayalam,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Malayalam,S::Malayalam,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::Sinhala,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Sinhala,S::Sinhala,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Sinhala,S::Sinhala,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4288,C::SpacingMark,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(64,C::DecimalNumber,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Sinhala,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Thai,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::SM,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::Compat,0), r(12352,C::NonspacingMark,B::Thai,S::Thai,103,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Thai,S::Thai,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::Thai,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Thai,S::Thai,107,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Thai,S::Thai,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Thai,S::Thai,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Lao,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::SM,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::Compat,0), r(12352,C::NonspacingMark,B::Lao,S::Lao,118,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Lao,S::Lao,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Lao,S::Lao,122,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Lao,S::Lao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Lao,S::Lao,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::Compat,0),
+    r(4160,C::OtherLetter,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::IND,Mc::O,Dt::NoBreak,0), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tibetan,S::Tibetan,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tibetan,S::Tibetan,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Tibetan,S::Tibetan,216,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(68,C::OpenPunctuation,B::Tibetan,S::Tibetan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(72,C::ClosePunctuation,B::Tibetan,S::Tibetan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1), r(64,C::SpacingMark,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0), r(64,C::Other,B::Tibetan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,129,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,130,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12480,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Canonical,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,132,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12480,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::Canonical,0),
+    r(13376,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Compat,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Tibetan,S::Tibetan,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tibetan,S::Tibetan,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tibetan,S::Tibetan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::Canonical,0), r(64,C::OtherPunctuation,B::Tibetan,S::Tibetan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Tibetan,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::C,Dt::None,0),
+    r(4160,C::OtherLetter,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::IV,Dt::None,0), r(4160,C::OtherLetter,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::IV,Dt::Canonical,0),
+    r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::VPst,Dt::None,0), r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::VAbv,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::VBlw,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::VPre,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::A,Dt::None,0), r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::A,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Myanmar,S::Myanmar,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMBlw,Mc::DB,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::V,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Myanmar,S::Myanmar,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::H,Dt::None,0), r(8256,C::NonspacingMark,B::Myanmar,S::Myanmar,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::As,Dt::None,0),
+    r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPst,Mc::MY,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPre,Mc::MR,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MW,Dt::None,0), r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MH,Dt::None,0),
+    r(64,C::DecimalNumber,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::D0,Dt::None,0), r(64,C::DecimalNumber,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::D,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::P,Dt::None,0), r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::P,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::S,Dt::None,0), r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::C,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::VPst,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::MY,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::PT,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Myanmar,S::Myanmar,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMBlw,Mc::V,Dt::None,0), r(4160,C::SpacingMark,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::V,Dt::None,0),
+    r(64,C::OtherSymbol,B::Myanmar,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::S,Dt::None,0), r(20544,C::UppercaseLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Georgian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Georgian,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Georgian,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(4160,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::L,Wb::LE,Lb::JL,Uc::O,Mc::O,Dt::None,0), r(4416,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::L,Wb::LE,Lb::JL,Uc::O,Mc::O,Dt::None,0),
+    r(4416,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::V,Wb::LE,Lb::JV,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::V,Wb::LE,Lb::JV,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HangulJamo,S::Hangul,0,Bc::L,Jt::U,Cb::T,Wb::LE,Lb::JT,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Ethiopic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Ethiopic,S::Ethiopic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Ethiopic,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::EthiopicSupplement,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EthiopicSupplement,S::Ethiopic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::EthiopicSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::Cherokee,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Cherokee,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Cherokee,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::UnifiedCanadianAboriginalSyllabics,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(32832,C::SpaceSeparator,B::Ogham,S::Ogham,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Ogham,S::Ogham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(68,C::OpenPunctuation,B::Ogham,S::Ogham,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1), r(72,C::ClosePunctuation,B::Ogham,S::Ogham,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::Other,B::Ogham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Runic,S::Runic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Runic,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::LetterNumber,B::Runic,S::Runic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Runic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tagalog,S::Tagalog,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Tagalog,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Tagalog,S::Tagalog,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tagalog,S::Tagalog,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tagalog,S::Tagalog,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Hanunoo,S::Hanunoo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Hanunoo,S::Hanunoo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Hanunoo,S::Hanunoo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::NonspacingMark,B::Hanunoo,S::Hanunoo,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Hanunoo,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Hanunoo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Buhid,S::Buhid,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Buhid,S::Buhid,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Buhid,S::Buhid,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Buhid,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Tagbanwa,S::Tagbanwa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Tagbanwa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tagbanwa,S::Tagbanwa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tagbanwa,S::Tagbanwa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(5184,C::OtherLetter,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0), r(8512,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FM,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khmer,S::Khmer,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Khmer,S::Khmer,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::Khmer,S::Khmer,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Khmer,S::Khmer,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FM,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Khmer,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Khmer,S::Khmer,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Khmer,S::Khmer,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Mongolian,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Mongolian,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mongolian,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(8512,C::NonspacingMark,B::Mongolian,S::Mongolian,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::Mongolian,S::Mongolian,0,Bc::BN,Jt::U,Cb::CN,Wb::FO,Lb::GL,Uc::O,Mc::O,Dt::None,0), r(320,C::Other,B::Mongolian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Mongolian,S::Mongolian,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Mongolian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Mongolian,S::Mongolian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Mongolian,S::Mongolian,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Mongolian,S::Mongolian,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::UnifiedCanadianAboriginalSyllabicsExtended,S::CanadianAboriginal,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::UnifiedCanadianAboriginalSyllabicsExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Limbu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Limbu,S::Limbu,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Limbu,S::Limbu,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Limbu,S::Limbu,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Limbu,S::Limbu,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Limbu,S::Limbu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Limbu,S::Limbu,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Limbu,S::Limbu,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::TaiLe,S::TaiLe,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::TaiLe,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::NewTaiLue,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::VMPst,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::NewTaiLue,S::NewTaiLue,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::NewTaiLue,S::NewTaiLue,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::KhmerSymbols,S::Khmer,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Buginese,S::Buginese,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Buginese,S::Buginese,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Buginese,S::Buginese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Buginese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Buginese,S::Buginese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::MPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::SUB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::FAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::SUB,Mc::O,Dt::None,0), r(64,C::Other,B::TaiTham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::TaiTham,S::TaiTham,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::H,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiTham,S::TaiTham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::TaiTham,S::TaiTham,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::TaiTham,S::TaiTham,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::TaiTham,S::TaiTham,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::IND,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::TaiTham,S::TaiTham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::EnclosingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::CombiningDiacriticalMarksExtended,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CombiningDiacriticalMarksExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0),
+    r(8256,C::NonspacingMark,B::Balinese,S::Balinese,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Balinese,S::Balinese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::Canonical,0), r(4288,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Canonical,0),
+    r(4160,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4288,C::SpacingMark,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(64,C::SpacingMark,B::Balinese,S::Balinese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(64,C::Other,B::Balinese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Balinese,S::Balinese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Balinese,S::Balinese,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Balinese,S::Balinese,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sundanese,S::Sundanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::Sundanese,S::Sundanese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Sundanese,S::Sundanese,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Sundanese,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Batak,S::Batak,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Batak,S::Batak,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Batak,S::Batak,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::Batak,S::Batak,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Batak,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Batak,S::Batak,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPre,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Lepcha,S::Lepcha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Lepcha,S::Lepcha,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Lepcha,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Lepcha,S::Lepcha,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::OlChiki,S::OlChiki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::CyrillicExtendedC,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::CyrillicExtendedC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::GeorgianExtended,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::GeorgianExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SundaneseSupplement,S::Sundanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::SundaneseSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::VedicExtensions,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::VedicExtensions,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0), r(64,C::Other,B::VedicExtensions,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::PhoneticExtensions,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::PhoneticExtensions,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0), r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0), r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensions,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(20544,C::LowercaseLetter,B::PhoneticExtensionsSupplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensionsSupplement,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(28736,C::ModifierLetter,B::PhoneticExtensionsSupplement,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,234,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,214,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,202,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CombiningDiacriticalMarksSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksSupplement,S::Inherited,233,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedAdditional,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(20544,C::UppercaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(64,C::Other,B::GreekExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::TitlecaseLetter,B::GreekExtended,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(8256,C::ModifierSymbol,B::GreekExtended,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(8256,C::ModifierSymbol,B::GreekExtended,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(8256,C::ModifierSymbol,B::GreekExtended,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::O,Mc::O,Dt::Canonical,0),
+    r(32832,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O,Dt::Canonical,0),
+    r(32832,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O,Dt::Compat,0),
+    r(32832,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::O,Mc::O,Dt::NoBreak,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::XX,Lb::ZW,Uc::O,Mc::O,Dt::None,0),
+    r(8544,C::Format,B::GeneralPunctuation,S::Inherited,0,Bc::BN,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::ZWNJ,Mc::J,Dt::None,0),
+    r(8544,C::Format,B::GeneralPunctuation,S::Inherited,0,Bc::BN,Jt::D,Cb::ZWJ,Wb::ZWJ,Lb::ZWJ,Uc::ZWJ,Mc::J,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::R,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::GL,Uc::GB,Mc::O,Dt::NoBreak,0),
+    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::GB,Mc::GB,Dt::None,0),
+    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::B2,Uc::GB,Mc::GB,Dt::None,0),
+    r(64,C::DashPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::GB,Mc::GB,Dt::None,0),
+    r(2112,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::Compat,0),
+    r(8256,C::InitialPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::QU,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::FinalPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::QU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OpenPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::InitialPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::FinalPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::GB,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::IN,Uc::IND,Mc::O,Dt::Compat,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O,Dt::Compat,0),
+    r(8256,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(32832,C::LineSeparator,B::GeneralPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O,Dt::None,0),
+    r(32832,C::ParagraphSeparator,B::GeneralPunctuation,S::Common,0,Bc::B,Jt::U,Cb::CN,Wb::NL,Lb::BK,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRE,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLE,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::PDF,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRO,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLO,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(32832,C::SpaceSeparator,B::GeneralPunctuation,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::EX,Lb::GL,Uc::O,Mc::O,Dt::NoBreak,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::None,0),
+    r(2112,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::None,0),
+    r(2112,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::Compat,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::Compat,0),
+    r(64,C::InitialPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,1),
+    r(64,C::FinalPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,-1),
+    r(67,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::Compat,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::ConnectorPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::ConnectorPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::GeneralPunctuation,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::O,Mc::O,Dt::None,0), r(68,C::OpenPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(72,C::ClosePunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::Compat,0), r(2112,C::MathSymbol,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::GeneralPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::WJ,Uc::WJ,Mc::WJ,Dt::None,0),
+    r(10528,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(320,C::Other,B::GeneralPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::LRI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::RLI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::FSI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::GeneralPunctuation,S::Common,0,Bc::PDI,Jt::U,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(9504,C::Format,B::GeneralPunctuation,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(28736,C::ModifierLetter,B::SuperscriptsandSubscripts,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(64,C::Other,B::SuperscriptsandSubscripts,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::FM,Mc::O,Dt::Super,0),
+    r(2112,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(2112,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(2116,C::OpenPunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Super,1),
+    r(2120,C::ClosePunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Super,-1),
+    r(28736,C::ModifierLetter,B::SuperscriptsandSubscripts,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::Super,0),
+    r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Sub,0),
+    r(64,C::OtherNumber,B::SuperscriptsandSubscripts,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::FM,Mc::O,Dt::Sub,0),
+    r(2112,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(2112,C::MathSymbol,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(2116,C::OpenPunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Sub,1),
+    r(2120,C::ClosePunctuation,B::SuperscriptsandSubscripts,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Sub,-1),
+    r(28736,C::ModifierLetter,B::SuperscriptsandSubscripts,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Sub,0), r(64,C::CurrencySymbol,B::CurrencySymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::CurrencySymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::CurrencySymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::Other,B::CurrencySymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O,Dt::None,0),
+    r(10304,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(10304,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::EnclosingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(10304,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningDiacriticalMarksforSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CombiningDiacriticalMarksforSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Compat,0), r(22592,C::UppercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::Compat,0), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Compat,0), r(22592,C::UppercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(22592,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0), r(22592,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::Font,0),
+    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::Compat,0), r(2112,C::MathSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Super,0), r(67,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Super,0),
+    r(20544,C::UppercaseLetter,B::LetterlikeSymbols,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(2112,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(20544,C::UppercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::Canonical,0), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(6208,C::OtherLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(20547,C::LowercaseLetter,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0), r(2112,C::MathSymbol,B::LetterlikeSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(20544,C::LowercaseLetter,B::LetterlikeSymbols,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::LetterlikeSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Fraction,0), r(64,C::OtherNumber,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Fraction,0),
+    r(20544,C::LetterNumber,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::O,Mc::O,Dt::Compat,0), r(20544,C::LetterNumber,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(4160,C::LetterNumber,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::NumberForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::NumberForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::NumberForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(2115,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0), r(2115,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0), r(2112,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2115,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(2112,C::OtherSymbol,B::Arrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Canonical,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,3),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,3),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,3), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,-3),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,-3),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-3), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,2016),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,2527),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,1923),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1914),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1918),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,2250),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,-1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,138), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,7),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,-7), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Canonical,0),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Canonical,1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Canonical,-1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,-1),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1824),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,2104),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,2108),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,2106),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1316),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-138),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::O,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,8),
+    r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-8), r(2112,C::MathSymbol,B::MathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-7),
+    r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2116,C::OpenPunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(2120,C::ClosePunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(1092,C::OpenPunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Canonical,1),
+    r(1096,C::ClosePunctuation,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Canonical,-1),
+    r(64,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::OtherSymbol,B::MiscellaneousTechnical,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::ControlPictures,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::ControlPictures,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::OpticalCharacterRecognition,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OpticalCharacterRecognition,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Circle,0),
+    r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0), r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Compat,0),
+    r(20544,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O,Dt::Circle,0),
+    r(20547,C::OtherSymbol,B::EnclosedAlphanumerics,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O,Dt::Circle,0), r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::EnclosedAlphanumerics,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::BoxDrawing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::BoxDrawing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::BlockElements,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::BlockElements,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(2112,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(2112,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2115,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0), r(2112,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::GB,Dt::None,0), r(2112,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2115,C::MathSymbol,B::GeometricShapes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::GB,Mc::GB,Dt::None,0), r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(2114,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0), r(2115,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(2114,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(2114,C::MathSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::MiscellaneousSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::S,Mc::O,Dt::None,0), r(68,C::OpenPunctuation,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(72,C::ClosePunctuation,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1), r(64,C::OtherNumber,B::Dingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1),
+    r(2116,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(2120,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,2),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1316),
+    r(2112,C::MathSymbol,B::SupplementalArrowsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::BraillePatterns,S::Braille,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::SupplementalArrowsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(2115,C::MathSymbol,B::SupplementalArrowsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2116,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(2120,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(2116,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,3),
+    r(2120,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,1),
+    r(2116,C::OpenPunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,-1),
+    r(2120,C::ClosePunctuation,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-3),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1914),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1918),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1923),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1824),
+    r(2112,C::MathSymbol,B::MiscellaneousMathematicalSymbolsB,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2016),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,1),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-1),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2104),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2106),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2108),
+    r(2112,C::MathSymbol,B::SupplementalMathematicalOperators,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,-2250),
+    r(64,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::MiscellaneousSymbolsandArrows,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MiscellaneousSymbolsandArrows,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,-2527),
+    r(20544,C::UppercaseLetter,B::Glagolitic,S::Glagolitic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Glagolitic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Glagolitic,S::Glagolitic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(28736,C::ModifierLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Sub,0),
+    r(28736,C::ModifierLetter,B::LatinExtendedC,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0), r(20544,C::UppercaseLetter,B::Coptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Coptic,S::Coptic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Coptic,S::Coptic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Coptic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Coptic,S::Coptic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::GeorgianSupplement,S::Georgian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::GeorgianSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Tifinagh,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Super,0),
+    r(64,C::OtherPunctuation,B::Tifinagh,S::Tifinagh,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tifinagh,S::Tifinagh,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EthiopicExtended,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::EthiopicExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::CyrillicExtendedA,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::InitialPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,1),
+    r(64,C::FinalPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(68,C::OpenPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(72,C::ClosePunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::OtherPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DashPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::B2,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OpenPunctuation,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::SupplementalPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::SupplementalPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::CJKRadicalsSupplement,S::Han,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::CJKRadicalsSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::CJKRadicalsSupplement,S::Han,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0), r(64,C::OtherSymbol,B::KangxiRadicals,S::Han,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::Other,B::KangxiRadicals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::IdeographicDescriptionCharacters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::IdeographicDescriptionCharacters,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(32832,C::SpaceSeparator,B::CJKSymbolsandPunctuation,S::Common,0,Bc::WS,Jt::U,Cb::XX,Wb::WSegSpace,Lb::BA,Uc::O,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::LetterNumber,B::CJKSymbolsandPunctuation,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(68,C::OpenPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,1),
+    r(72,C::ClosePunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,-1),
+    r(64,C::DashPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OpenPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::ClosePunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,218,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,228,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,232,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CJKSymbolsandPunctuation,S::Inherited,222,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::CJKSymbolsandPunctuation,S::Hangul,224,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(67,C::DashPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(4160,C::LetterNumber,B::CJKSymbolsandPunctuation,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Compat,0),
+    r(4160,C::OtherLetter,B::CJKSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(67,C::OtherPunctuation,B::CJKSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Hiragana,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::CJ,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Canonical,0), r(8256,C::NonspacingMark,B::Hiragana,S::Inherited,8,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::Hiragana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O,Dt::Compat,0), r(12352,C::ModifierLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::O,Mc::O,Dt::Canonical,0), r(4160,C::OtherLetter,B::Hiragana,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::DashPunctuation,B::Katakana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::Canonical,0),
+    r(64,C::OtherPunctuation,B::Katakana,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Katakana,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::NS,Uc::O,Mc::O,Dt::Canonical,0),
+    r(4160,C::OtherLetter,B::Katakana,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::Vertical,0), r(64,C::Other,B::Bopomofo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Bopomofo,S::Bopomofo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::HangulCompatibilityJamo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HangulCompatibilityJamo,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Compat,0),
+    r(4416,C::OtherLetter,B::HangulCompatibilityJamo,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Compat,0), r(64,C::OtherSymbol,B::Kanbun,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Kanbun,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Super,0), r(64,C::OtherSymbol,B::Kanbun,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Super,0),
+    r(4160,C::OtherLetter,B::BopomofoExtended,S::Bopomofo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::CJKStrokes,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKStrokes,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::KatakanaPhoneticExtensions,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::Other,B::EnclosedCJKLettersandMonths,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Hangul,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Circle,0),
+    r(67,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedCJKLettersandMonths,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(64,C::OtherSymbol,B::CJKCompatibility,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::S,Mc::O,Dt::Square,0), r(64,C::OtherSymbol,B::CJKCompatibility,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::CJKCompatibility,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0), r(64,C::OtherSymbol,B::CJKCompatibility,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionA,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::YijingHexagramSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::CJKUnifiedIdeographs,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::YiSyllables,S::Yi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::YiSyllables,S::Yi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::YiSyllables,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::YiRadicals,S::Yi,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::YiRadicals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Lisu,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Vai,S::Vai,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Vai,S::Vai,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Vai,S::Vai,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Vai,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CyrillicExtendedB,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::EnclosingMark,B::CyrillicExtendedB,S::Cyrillic,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::CyrillicExtendedB,S::Cyrillic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::CyrillicExtendedB,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::CyrillicExtendedB,S::Cyrillic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0), r(4160,C::OtherLetter,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::LetterNumber,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Bamum,S::Bamum,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Bamum,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Bamum,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::ModifierSymbol,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::ModifierToneLetters,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(8256,C::ModifierSymbol,B::LatinExtendedD,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(28736,C::ModifierLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0), r(12352,C::ModifierLetter,B::LatinExtendedD,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::LatinExtendedD,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::LatinExtendedD,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::LatinExtendedD,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::SylotiNagri,S::SylotiNagri,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::SylotiNagri,S::SylotiNagri,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::SylotiNagri,S::SylotiNagri,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::SylotiNagri,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::CommonIndicNumberForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::CommonIndicNumberForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::CommonIndicNumberForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::CommonIndicNumberForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::CommonIndicNumberForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Phagspa,S::PhagsPa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Phagspa,S::PhagsPa,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Phagspa,S::PhagsPa,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Phagspa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Saurashtra,S::Saurashtra,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Saurashtra,S::Saurashtra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(64,C::Other,B::Saurashtra,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Saurashtra,S::Saurashtra,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::DevanagariExtended,S::Devanagari,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::DevanagariExtended,S::Devanagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::DevanagariExtended,S::Devanagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::KayahLi,S::KayahLi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::KayahLi,S::KayahLi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::KayahLi,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::KayahLi,S::KayahLi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Rejang,S::Rejang,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::Rejang,S::Rejang,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(64,C::Other,B::Rejang,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Rejang,S::Rejang,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::HangulJamoExtendedA,S::Hangul,0,Bc::L,Jt::U,Cb::L,Wb::LE,Lb::JL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::HangulJamoExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Javanese,S::Javanese,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Javanese,S::Javanese,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::Javanese,S::Javanese,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Javanese,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::Javanese,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Javanese,S::Javanese,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MyanmarExtendedB,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::MyanmarExtendedB,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::MyanmarExtendedB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Cham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Cham,S::Cham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::FPst,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Cham,S::Cham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::C,Dt::None,0), r(12352,C::ModifierLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::S,Dt::None,0),
+    r(4160,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::GB,Mc::C,Dt::None,0), r(64,C::OtherSymbol,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::S,Dt::None,0),
+    r(4160,C::SpacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::PT,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::R,Dt::None,0),
+    r(4160,C::SpacingMark,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::Extend,Lb::SA,Uc::VMPst,Mc::R,Dt::None,0), r(4160,C::OtherLetter,B::MyanmarExtendedA,S::Myanmar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::R,Dt::None,0),
+    r(4160,C::OtherLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::TaiViet,S::TaiViet,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::TaiViet,S::TaiViet,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::TaiViet,S::TaiViet,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VMAbv,Mc::O,Dt::None,0), r(64,C::Other,B::TaiViet,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::TaiViet,S::TaiViet,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MeeteiMayekExtensions,S::MeeteiMayek,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::Other,B::MeeteiMayekExtensions,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::EthiopicExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EthiopicExtendedA,S::Ethiopic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::LatinExtendedE,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::ModifierSymbol,B::LatinExtendedE,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(28736,C::ModifierLetter,B::LatinExtendedE,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(20544,C::LowercaseLetter,B::LatinExtendedE,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::LatinExtendedE,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Super,0),
+    r(8256,C::ModifierSymbol,B::LatinExtendedE,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::LatinExtendedE,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::CherokeeSupplement,S::Cherokee,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MeeteiMayek,S::MeeteiMayek,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::MeeteiMayek,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::MeeteiMayek,S::MeeteiMayek,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::HangulSyllables,S::Hangul,0,Bc::L,Jt::U,Cb::LV,Wb::LE,Lb::H2,Uc::O,Mc::O,Dt::Canonical,0),
+    r(4160,C::OtherLetter,B::HangulSyllables,S::Hangul,0,Bc::L,Jt::U,Cb::LVT,Wb::LE,Lb::H3,Uc::O,Mc::O,Dt::Canonical,0), r(64,C::Other,B::HangulSyllables,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HangulJamoExtendedB,S::Hangul,0,Bc::L,Jt::U,Cb::V,Wb::LE,Lb::JV,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::HangulJamoExtendedB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HangulJamoExtendedB,S::Hangul,0,Bc::L,Jt::U,Cb::T,Wb::LE,Lb::JT,Uc::O,Mc::O,Dt::None,0), r(64,C::Surrogate,B::HighSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Surrogate,B::HighPrivateUseSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O,Dt::None,0), r(64,C::Surrogate,B::LowSurrogates,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SG,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::PrivateUse,B::PrivateUseArea,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::CJKCompatibilityIdeographs,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Canonical,0),
+    r(4160,C::OtherLetter,B::CJKCompatibilityIdeographs,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::CJKCompatibilityIdeographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::AlphabeticPresentationForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::Other,B::AlphabeticPresentationForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::AlphabeticPresentationForms,S::Armenian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Compat,0),
+    r(4160,C::OtherLetter,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O,Dt::Canonical,0),
+    r(12352,C::NonspacingMark,B::AlphabeticPresentationForms,S::Hebrew,26,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O,Dt::Font,0),
+    r(2112,C::MathSymbol,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(4160,C::OtherLetter,B::AlphabeticPresentationForms,S::Hebrew,0,Bc::R,Jt::U,Cb::XX,Wb::HL,Lb::HL,Uc::O,Mc::O,Dt::Compat,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Isolated,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Final,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Initial,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Medial,0),
+    r(8256,C::ModifierSymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::ArabicPresentationFormsA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::ClosePunctuation,B::ArabicPresentationFormsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OpenPunctuation,B::ArabicPresentationFormsA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(576,C::Other,B::ArabicPresentationFormsA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::Isolated,0),
+    r(64,C::OtherSymbol,B::ArabicPresentationFormsA,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8528,C::NonspacingMark,B::VariationSelectors,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::IND,Mc::VS,Dt::None,0),
+    r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::IS,Uc::IND,Mc::O,Dt::Vertical,0),
+    r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O,Dt::Vertical,0),
+    r(8256,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::ML,Lb::IS,Uc::IND,Mc::O,Dt::Vertical,0),
+    r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::Vertical,0), r(64,C::OpenPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::ClosePunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Vertical,0), r(64,C::OtherPunctuation,B::VerticalForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O,Dt::Vertical,0),
+    r(64,C::Other,B::VerticalForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::CombiningHalfMarks,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningHalfMarks,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CombiningHalfMarks,S::Cyrillic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Vertical,0),
+    r(64,C::DashPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::ConnectorPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::ID,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::OpenPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::ClosePunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Vertical,0),
+    r(64,C::OtherPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Compat,0),
+    r(64,C::ConnectorPunctuation,B::CJKCompatibilityForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::ID,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::CL,Uc::IND,Mc::O,Dt::Small,0),
+    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Small,0),
+    r(8256,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::CL,Uc::IND,Mc::O,Dt::Small,0), r(64,C::Other,B::SmallFormVariants,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::NS,Uc::IND,Mc::O,Dt::Small,0),
+    r(8256,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::NS,Uc::IND,Mc::O,Dt::Small,0),
+    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::Small,0),
+    r(64,C::DashPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,0), r(68,C::OpenPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Small,1),
+    r(72,C::ClosePunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Small,-1),
+    r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Small,0),
+    r(2112,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Small,0), r(2112,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,0),
+    r(2112,C::DashPunctuation,B::SmallFormVariants,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,0), r(2112,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,1),
+    r(2112,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,-1), r(2112,C::MathSymbol,B::SmallFormVariants,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Small,0),
+    r(64,C::CurrencySymbol,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::Small,0), r(64,C::OtherPunctuation,B::SmallFormVariants,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::Small,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Isolated,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Medial,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::ArabicPresentationFormsB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Final,0),
+    r(4160,C::OtherLetter,B::ArabicPresentationFormsB,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Initial,0),
+    r(8480,C::Format,B::ArabicPresentationFormsB,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::WJ,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::HalfwidthandFullwidthForms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::CurrencySymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::IND,Mc::O,Dt::Wide,0),
+    r(8256,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MB,Lb::ID,Uc::IND,Mc::O,Dt::Wide,0),
+    r(68,C::OpenPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Wide,1),
+    r(72,C::ClosePunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Wide,-1),
+    r(2112,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MN,Lb::CL,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::DashPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ES,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(8256,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::MB,Lb::CL,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::DecimalNumber,B::HalfwidthandFullwidthForms,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(8256,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::CS,Jt::U,Cb::XX,Wb::ML,Lb::NS,Uc::IND,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::MN,Lb::NS,Uc::IND,Mc::O,Dt::Wide,0),
+    r(2112,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,2),
+    r(2112,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(2112,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,-2),
+    r(20544,C::UppercaseLetter,B::HalfwidthandFullwidthForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(68,C::OpenPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Wide,2),
+    r(2112,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::IND,Mc::O,Dt::Wide,0),
+    r(72,C::ClosePunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Wide,-2),
+    r(10304,C::ModifierSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(64,C::ConnectorPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::EX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(8256,C::ModifierSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(20544,C::LowercaseLetter,B::HalfwidthandFullwidthForms,S::Latin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Wide,0),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::IND,Mc::O,Dt::Narrow,0),
+    r(68,C::OpenPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::O,Mc::O,Dt::Narrow,1),
+    r(72,C::ClosePunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CL,Uc::O,Mc::O,Dt::Narrow,-1),
+    r(64,C::OtherPunctuation,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::Narrow,0),
+    r(4160,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::Narrow,0),
+    r(4160,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::Narrow,0),
+    r(12352,C::ModifierLetter,B::HalfwidthandFullwidthForms,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::Narrow,0),
+    r(12352,C::ModifierLetter,B::HalfwidthandFullwidthForms,S::Common,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::NS,Uc::O,Mc::O,Dt::Narrow,0),
+    r(4416,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Narrow,0),
+    r(4160,C::OtherLetter,B::HalfwidthandFullwidthForms,S::Hangul,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::ID,Uc::O,Mc::O,Dt::Narrow,0),
+    r(64,C::CurrencySymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::Wide,0),
+    r(64,C::OtherSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Wide,0),
+    r(64,C::OtherSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Narrow,0),
+    r(2112,C::MathSymbol,B::HalfwidthandFullwidthForms,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Narrow,0), r(320,C::Other,B::Specials,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::Specials,S::Common,0,Bc::ON,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Specials,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::CB,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Specials,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0), r(576,C::Other,B::Specials,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::LinearBSyllabary,S::LinearB,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::LinearBSyllabary,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::LinearBIdeograms,S::LinearB,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::LinearBIdeograms,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::AegeanNumbers,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::AegeanNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AegeanNumbers,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(4160,C::LetterNumber,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::AncientGreekNumbers,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AncientGreekNumbers,S::Greek,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::AncientGreekNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AncientSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::AncientSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AncientSymbols,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::PhaistosDisc,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::PhaistosDisc,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::PhaistosDisc,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lycian,S::Lycian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Lycian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Carian,S::Carian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Carian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::CopticEpactNumbers,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::CopticEpactNumbers,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::CopticEpactNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldItalic,S::OldItalic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::OldItalic,S::OldItalic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OldItalic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Gothic,S::Gothic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::LetterNumber,B::Gothic,S::Gothic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Gothic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldPermic,S::OldPermic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::OldPermic,S::OldPermic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OldPermic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Ugaritic,S::Ugaritic,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Ugaritic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Ugaritic,S::Ugaritic,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::OldPersian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::LetterNumber,B::OldPersian,S::OldPersian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::Deseret,S::Deseret,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::Deseret,S::Deseret,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Shavian,S::Shavian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Osmanya,S::Osmanya,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Osmanya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Osmanya,S::Osmanya,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::Osage,S::Osage,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Osage,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Osage,S::Osage,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Elbasan,S::Elbasan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Elbasan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::CaucasianAlbanian,S::CaucasianAlbanian,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CaucasianAlbanian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::CaucasianAlbanian,S::CaucasianAlbanian,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::LinearA,S::LinearA,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::LinearA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CypriotSyllabary,S::Cypriot,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::CypriotSyllabary,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::ImperialAramaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::ImperialAramaic,S::ImperialAramaic,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Palmyrene,S::Palmyrene,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Nabataean,S::Nabataean,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Nabataean,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Nabataean,S::Nabataean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Hatran,S::Hatran,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Hatran,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Hatran,S::Hatran,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Phoenician,S::Phoenician,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Phoenician,S::Phoenician,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Phoenician,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Phoenician,S::Phoenician,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Lydian,S::Lydian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Lydian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Lydian,S::Lydian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::MeroiticHieroglyphs,S::MeroiticHieroglyphs,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MeroiticCursive,S::MeroiticCursive,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::MeroiticCursive,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::MeroiticCursive,S::MeroiticCursive,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Kharoshthi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Kharoshthi,S::Kharoshthi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Kharoshthi,S::Kharoshthi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::OldSouthArabian,S::OldSouthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldNorthArabian,S::OldNorthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::OldNorthArabian,S::OldNorthArabian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Manichaean,S::Manichaean,0,Bc::R,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Manichaean,S::Manichaean,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Manichaean,S::Manichaean,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Manichaean,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Manichaean,S::Manichaean,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Manichaean,S::Manichaean,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Manichaean,S::Manichaean,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::IN,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Avestan,S::Avestan,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Avestan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Avestan,S::Avestan,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::InscriptionalParthian,S::InscriptionalParthian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::InscriptionalParthian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::InscriptionalParthian,S::InscriptionalParthian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::InscriptionalPahlavi,S::InscriptionalPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::InscriptionalPahlavi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::InscriptionalPahlavi,S::InscriptionalPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::PsalterPahlavi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::PsalterPahlavi,S::PsalterPahlavi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::OldTurkic,S::OldTurkic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OldTurkic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OldHungarian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::OldHungarian,S::OldHungarian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::L,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::HanifiRohingya,S::HanifiRohingya,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::HanifiRohingya,S::HanifiRohingya,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(64,C::Other,B::HanifiRohingya,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::HanifiRohingya,S::HanifiRohingya,0,Bc::AN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::RumiNumeralSymbols,S::Arabic,0,Bc::AN,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::RumiNumeralSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Yezidi,S::Yezidi,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Yezidi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Yezidi,S::Yezidi,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::DashPunctuation,B::Yezidi,S::Yezidi,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::OldSogdian,S::OldSogdian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::OldSogdian,S::OldSogdian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OldSogdian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Sogdian,S::Sogdian,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Sogdian,S::Sogdian,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMBlw,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Sogdian,S::Sogdian,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Sogdian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::R,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::R,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::D,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Chorasmian,S::Chorasmian,0,Bc::R,Jt::L,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Chorasmian,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Elymaic,S::Elymaic,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Elymaic,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Brahmi,S::Brahmi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Brahmi,S::Brahmi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Brahmi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Brahmi,S::Brahmi,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::N,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Brahmi,S::Brahmi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Brahmi,S::Brahmi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::HN,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::Canonical,0), r(4160,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Kaithi,S::Kaithi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Kaithi,S::Kaithi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Kaithi,S::Kaithi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::PP,Wb::FO,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Kaithi,S::Kaithi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Kaithi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::SoraSompeng,S::SoraSompeng,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::SoraSompeng,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::SoraSompeng,S::SoraSompeng,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Chakma,S::Chakma,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Chakma,S::Chakma,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12480,C::NonspacingMark,B::Chakma,S::Chakma,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::Canonical,0), r(8256,C::NonspacingMark,B::Chakma,S::Chakma,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Chakma,S::Chakma,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(64,C::Other,B::Chakma,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Chakma,S::Chakma,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Mahajani,S::Mahajani,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Mahajani,S::Mahajani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Mahajani,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::Sharada,S::Sharada,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Sharada,S::Sharada,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Sharada,S::Sharada,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Sharada,S::Sharada,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::SinhalaArchaicNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::SinhalaArchaicNumbers,S::Sinhala,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Khojki,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(64,C::SpacingMark,B::Khojki,S::Khojki,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khojki,S::Khojki,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Khojki,S::Khojki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Khojki,S::Khojki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Multani,S::Multani,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Multani,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Multani,S::Multani,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Khudawadi,S::Khudawadi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khudawadi,S::Khudawadi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Khudawadi,S::Khudawadi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Khudawadi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Khudawadi,S::Khudawadi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Grantha,S::Grantha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(64,C::Other,B::Grantha,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Grantha,S::Inherited,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Grantha,S::Grantha,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Grantha,S::Grantha,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4288,C::SpacingMark,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(64,C::SpacingMark,B::Grantha,S::Grantha,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Grantha,S::Grantha,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Grantha,S::Grantha,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Newa,S::Newa,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Newa,S::Newa,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Newa,S::Newa,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Newa,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Newa,S::Newa,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Newa,S::Newa,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::CS,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0), r(12352,C::NonspacingMark,B::Tirhuta,S::Tirhuta,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Tirhuta,S::Tirhuta,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Tirhuta,S::Tirhuta,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Tirhuta,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Tirhuta,S::Tirhuta,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Siddham,S::Siddham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(64,C::Other,B::Siddham,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0), r(12352,C::NonspacingMark,B::Siddham,S::Siddham,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Siddham,S::Siddham,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Siddham,S::Siddham,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Siddham,S::Siddham,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Modi,S::Modi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Modi,S::Modi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::Modi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Modi,S::Modi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::MongolianSupplement,S::Mongolian,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::MongolianSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Takri,S::Takri,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::Takri,S::Takri,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Takri,S::Takri,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Takri,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Takri,S::Takri,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Ahom,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MBlw,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::MAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Ahom,S::Ahom,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::SA,Uc::VPre,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Ahom,S::Ahom,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::SA,Uc::VAbv,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::Ahom,S::Ahom,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::SA,Uc::S,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Dogra,S::Dogra,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Dogra,S::Dogra,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Dogra,S::Dogra,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Dogra,S::Dogra,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Dogra,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::WarangCiti,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::WarangCiti,S::WarangCiti,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::DivesAkuru,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(4288,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::Canonical,0),
+    r(12352,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,9,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::MPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::DivesAkuru,S::DivesAkuru,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::DivesAkuru,S::DivesAkuru,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Nandinagari,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Nandinagari,S::Nandinagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Nandinagari,S::Nandinagari,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Nandinagari,S::Nandinagari,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Nandinagari,S::Nandinagari,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FM,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::GB,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::ZanabazarSquare,S::ZanabazarSquare,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::ZanabazarSquare,S::ZanabazarSquare,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0), r(64,C::Other,B::ZanabazarSquare,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::R,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::FBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Soyombo,S::Soyombo,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Soyombo,S::Soyombo,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Soyombo,S::Soyombo,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Soyombo,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::PauCinHau,S::PauCinHau,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::PauCinHau,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Bhaiksuki,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Bhaiksuki,S::Bhaiksuki,9,Bc::L,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Bhaiksuki,S::Bhaiksuki,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BB,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::EX,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Marchen,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::SUB,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Marchen,S::Marchen,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Marchen,S::Marchen,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::MasaramGondi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::PP,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::MasaramGondi,S::MasaramGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::MBlw,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::MasaramGondi,S::MasaramGondi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::GunjalaGondi,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VMPst,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::GunjalaGondi,S::GunjalaGondi,9,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::H,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::GunjalaGondi,S::GunjalaGondi,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::GB,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Makasar,S::Makasar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VAbv,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Makasar,S::Makasar,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPre,Mc::O,Dt::None,0), r(4160,C::SpacingMark,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::VPst,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Makasar,S::Makasar,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Makasar,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::LisuSupplement,S::Lisu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::LisuSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::TamilSupplement,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::TamilSupplement,S::Tamil,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::CurrencySymbol,B::TamilSupplement,S::Tamil,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::TamilSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::TamilSupplement,S::Tamil,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Cuneiform,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Cuneiform,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::LetterNumber,B::CuneiformNumbersandPunctuation,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CuneiformNumbersandPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::CuneiformNumbersandPunctuation,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EarlyDynasticCuneiform,S::Cuneiform,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::EarlyDynasticCuneiform,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::EgyptianHieroglyphs,S::EgyptianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::CL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::EgyptianHieroglyphs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::GL,Uc::O,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(8224,C::Format,B::EgyptianHieroglyphFormatControls,S::EgyptianHieroglyphs,0,Bc::L,Jt::T,Cb::CN,Wb::FO,Lb::CL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::EgyptianHieroglyphFormatControls,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::OP,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::AnatolianHieroglyphs,S::AnatolianHieroglyphs,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::CL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::AnatolianHieroglyphs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::BamumSupplement,S::Bamum,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::BamumSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Mro,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Mro,S::Mro,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::BassaVah,S::BassaVah,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::BassaVah,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::BassaVah,S::BassaVah,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::BassaVah,S::BassaVah,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::PahawhHmong,S::PahawhHmong,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::PahawhHmong,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::PahawhHmong,S::PahawhHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(20544,C::UppercaseLetter,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(20544,C::LowercaseLetter,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::Medefaidrin,S::Medefaidrin,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::Medefaidrin,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Miao,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Miao,S::Miao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMBlw,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(12352,C::NonspacingMark,B::Miao,S::Miao,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Miao,S::Miao,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(12352,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Nushu,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::IdeographicSymbolsandPunctuation,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::IND,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::IdeographicSymbolsandPunctuation,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::NS,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::IdeographicSymbolsandPunctuation,S::KhitanSmallScript,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::GL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::IdeographicSymbolsandPunctuation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::SpacingMark,B::IdeographicSymbolsandPunctuation,S::Han,6,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Tangut,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Tangut,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::TangutComponents,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::KhitanSmallScript,S::KhitanSmallScript,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::KhitanSmallScript,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::TangutSupplement,S::Tangut,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::TangutSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::KanaSupplement,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::KanaSupplement,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::KanaExtendedA,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::KanaExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::SmallKanaExtension,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::SmallKanaExtension,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::CJ,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::SmallKanaExtension,S::Katakana,0,Bc::L,Jt::U,Cb::XX,Wb::KA,Lb::CJ,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Nushu,S::Nushu,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Nushu,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Duployan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Duployan,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Duployan,S::Duployan,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Duployan,S::Duployan,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Duployan,S::Duployan,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(8480,C::Format,B::ShorthandFormatControls,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::ShorthandFormatControls,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::ByzantineMusicalSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::ByzantineMusicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MusicalSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::MusicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MusicalSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Canonical,0), r(64,C::SpacingMark,B::MusicalSymbols,S::Common,216,Bc::L,Jt::U,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::MusicalSymbols,S::Common,216,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::MusicalSymbols,S::Inherited,1,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::SpacingMark,B::MusicalSymbols,S::Common,226,Bc::L,Jt::U,Cb::SM,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::MusicalSymbols,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MusicalSymbols,S::Inherited,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MusicalSymbols,S::Inherited,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AncientGreekMusicalNotation,S::Greek,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::AncientGreekMusicalNotation,S::Greek,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::AncientGreekMusicalNotation,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::MayanNumerals,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::MayanNumerals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::TaiXuanJingSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::TaiXuanJingSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::CountingRodNumerals,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CountingRodNumerals,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(22592,C::UppercaseLetter,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(22592,C::LowercaseLetter,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(64,C::Other,B::MathematicalAlphanumericSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(2112,C::MathSymbol,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(2112,C::DecimalNumber,B::MathematicalAlphanumericSymbols,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::Font,0),
+    r(64,C::OtherSymbol,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::SuttonSignWriting,S::SignWriting,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::BA,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::OtherPunctuation,B::SuttonSignWriting,S::SignWriting,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::IND,Mc::O,Dt::None,0), r(64,C::Other,B::SuttonSignWriting,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::GlagoliticSupplement,S::Glagolitic,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::GlagoliticSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::NyiakengPuachueHmong,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::NyiakengPuachueHmong,S::NyiakengPuachueHmong,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::Wancho,S::Wancho,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::Wancho,S::Wancho,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::VMAbv,Mc::O,Dt::None,0), r(64,C::DecimalNumber,B::Wancho,S::Wancho,0,Bc::L,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0),
+    r(64,C::Other,B::Wancho,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::Wancho,S::Wancho,0,Bc::ET,Jt::U,Cb::XX,Wb::XX,Lb::PR,Uc::S,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::MendeKikakui,S::MendeKikakui,0,Bc::R,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::MendeKikakui,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::MendeKikakui,S::MendeKikakui,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(8256,C::NonspacingMark,B::MendeKikakui,S::MendeKikakui,220,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(20544,C::UppercaseLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0),
+    r(20544,C::LowercaseLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::D,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Adlam,S::Adlam,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(12352,C::NonspacingMark,B::Adlam,S::Adlam,230,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0), r(8256,C::NonspacingMark,B::Adlam,S::Adlam,7,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::CMAbv,Mc::O,Dt::None,0),
+    r(12352,C::ModifierLetter,B::Adlam,S::Adlam,0,Bc::R,Jt::T,Cb::XX,Wb::LE,Lb::AL,Uc::B,Mc::O,Dt::None,0), r(64,C::Other,B::Adlam,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::Adlam,S::Adlam,0,Bc::R,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::B,Mc::O,Dt::None,0), r(64,C::OtherPunctuation,B::Adlam,S::Adlam,0,Bc::R,Jt::U,Cb::XX,Wb::XX,Lb::OP,Uc::IND,Mc::O,Dt::None,0),
+    r(64,C::Other,B::IndicSiyaqNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0), r(64,C::CurrencySymbol,B::IndicSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::PO,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::Other,B::OttomanSiyaqNumbers,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(64,C::OtherNumber,B::OttomanSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::OttomanSiyaqNumbers,S::Common,0,Bc::AL,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(6208,C::OtherLetter,B::ArabicMathematicalAlphabeticSymbols,S::Arabic,0,Bc::AL,Jt::U,Cb::XX,Wb::LE,Lb::AL,Uc::O,Mc::O,Dt::Font,0),
+    r(64,C::Other,B::ArabicMathematicalAlphabeticSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(2112,C::MathSymbol,B::ArabicMathematicalAlphabeticSymbols,S::Arabic,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::O,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::MahjongTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::MahjongTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::MahjongTiles,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::DominoTiles,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::DominoTiles,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::PlayingCards,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::PlayingCards,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::PlayingCards,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherNumber,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::Compat,0),
+    r(64,C::OtherNumber,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::O,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Compat,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Circle,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Circle,0),
+    r(66,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(20544,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O,Dt::Square,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::Square,0),
+    r(20544,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Super,0),
+    r(66,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::Super,0),
+    r(20547,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::LE,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::AI,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::EnclosedAlphanumericSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(65,C::OtherSymbol,B::EnclosedAlphanumericSupplement,S::Common,0,Bc::L,Jt::U,Cb::RI,Wb::RI,Lb::RI,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Hiragana,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(67,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(66,C::Other,B::EnclosedIdeographicSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Square,0),
+    r(64,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Compat,0),
+    r(67,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::Circle,0),
+    r(66,C::OtherSymbol,B::EnclosedIdeographicSupplement,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(8257,C::ModifierSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::EX,Wb::Extend,Lb::EM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::MiscellaneousSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::Emoticons,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::Emoticons,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::QU,Uc::S,Mc::O,Dt::None,0), r(64,C::OtherSymbol,B::OrnamentalDingbats,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::NS,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::TransportandMapSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0), r(66,C::Other,B::TransportandMapSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::AlchemicalSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::Other,B::AlchemicalSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::GeometricShapesExtended,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(67,C::OtherSymbol,B::GeometricShapesExtended,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::SupplementalArrowsC,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(66,C::Other,B::SupplementalArrowsC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(66,C::OtherSymbol,B::SupplementalArrowsC,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::EB,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::SupplementalSymbolsandPictographs,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::SupplementalSymbolsandPictographs,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::ChessSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::ChessSymbols,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(66,C::OtherSymbol,B::ChessSymbols,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(67,C::OtherSymbol,B::SymbolsandPictographsExtendedA,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::S,Mc::O,Dt::None,0),
+    r(66,C::Other,B::SymbolsandPictographsExtendedA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::OtherSymbol,B::SymbolsforLegacyComputing,S::Common,0,Bc::ON,Jt::U,Cb::XX,Wb::XX,Lb::AL,Uc::S,Mc::O,Dt::None,0), r(64,C::Other,B::SymbolsforLegacyComputing,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::DecimalNumber,B::SymbolsforLegacyComputing,S::Common,0,Bc::EN,Jt::U,Cb::XX,Wb::NU,Lb::NU,Uc::O,Mc::O,Dt::Font,0), r(66,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(576,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionB,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(64,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionC,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionC,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionD,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionD,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionE,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionE,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionF,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionF,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKCompatibilityIdeographsSupplement,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::Canonical,0),
+    r(64,C::Other,B::CJKCompatibilityIdeographsSupplement,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(4160,C::OtherLetter,B::CJKUnifiedIdeographsExtensionG,S::Han,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::Other,B::CJKUnifiedIdeographsExtensionG,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::ID,Uc::O,Mc::O,Dt::None,0), r(320,C::Other,B::Tags,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(9504,C::Format,B::Tags,S::Common,0,Bc::BN,Jt::T,Cb::CN,Wb::FO,Lb::CM,Uc::O,Mc::O,Dt::None,0), r(8480,C::Format,B::Tags,S::Common,0,Bc::BN,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(320,C::Other,B::NoBlock,S::Unknown,0,Bc::L,Jt::U,Cb::CN,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0), r(8528,C::NonspacingMark,B::VariationSelectorsSupplement,S::Inherited,0,Bc::NSM,Jt::T,Cb::EX,Wb::Extend,Lb::CM,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::PrivateUse,B::SupplementaryPrivateUseAreaA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(576,C::Other,B::SupplementaryPrivateUseAreaA,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(64,C::PrivateUse,B::SupplementaryPrivateUseAreaB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+    r(576,C::Other,B::SupplementaryPrivateUseAreaB,S::Unknown,0,Bc::L,Jt::U,Cb::XX,Wb::XX,Lb::XX,Uc::O,Mc::O,Dt::None,0),
+];
+
+/// First code point with each record.
+#[cfg(feature = "serde")]
+#[rustfmt::skip]
+pub const RECORD_CODEPOINTS: [u32; 2267] = [
+    0, 9, 10, 11, 12, 13, 28, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47,
+    48, 58, 59, 60, 61, 62, 65, 91, 92, 93, 94, 95, 96, 97, 124, 125, 128, 133, 160, 161, 162, 163,
+    166, 167, 168, 169, 170, 171, 172, 173, 175, 176, 177, 178, 180, 181, 183, 185, 187, 188, 192,
+    198, 215, 223, 224, 247, 256, 257, 272, 273, 306, 307, 329, 384, 385, 416, 417, 443, 452, 453,
+    454, 592, 660, 688, 697, 699, 704, 706, 711, 712, 720, 728, 732, 735, 746, 768, 789, 790, 795,
+    801, 820, 832, 837, 847, 860, 861, 880, 881, 884, 885, 888, 890, 894, 900, 901, 902, 903, 912,
+    976, 978, 982, 994, 995, 1014, 1017, 1024, 1026, 1072, 1081, 1154, 1155, 1157, 1160, 1280,
+    1281, 1328, 1329, 1369, 1370, 1373, 1375, 1376, 1415, 1417, 1418, 1421, 1423, 1424, 1425, 1426,
+    1434, 1454, 1456, 1457, 1458, 1459, 1460, 1461, 1462, 1463, 1464, 1465, 1467, 1468, 1469, 1470,
+    1471, 1472, 1473, 1474, 1476, 1477, 1478, 1488, 1523, 1524, 1536, 1541, 1542, 1544, 1545, 1547,
+    1548, 1549, 1550, 1552, 1560, 1561, 1562, 1563, 1564, 1565, 1566, 1568, 1569, 1570, 1574, 1575,
+    1600, 1611, 1612, 1613, 1614, 1615, 1616, 1617, 1618, 1619, 1621, 1622, 1624, 1632, 1643, 1644,
+    1645, 1648, 1651, 1653, 1656, 1765, 1770, 1776, 1789, 1792, 1806, 1807, 1808, 1809, 1810, 1813,
+    1815, 1840, 1841, 1856, 1858, 1872, 1881, 1920, 1958, 1970, 1984, 1994, 2027, 2034, 2036, 2038,
+    2039, 2040, 2041, 2042, 2043, 2045, 2046, 2048, 2070, 2072, 2074, 2094, 2096, 2112, 2113, 2137,
+    2140, 2142, 2144, 2145, 2151, 2155, 2160, 2208, 2218, 2221, 2229, 2259, 2260, 2272, 2274, 2275,
+    2288, 2289, 2290, 2304, 2307, 2308, 2345, 2362, 2363, 2364, 2367, 2369, 2381, 2384, 2385, 2386,
+    2387, 2404, 2406, 2416, 2417, 2432, 2433, 2434, 2436, 2437, 2492, 2494, 2495, 2496, 2497, 2507,
+    2509, 2510, 2524, 2534, 2546, 2548, 2553, 2554, 2555, 2557, 2558, 2560, 2561, 2563, 2565, 2611,
+    2620, 2622, 2623, 2625, 2631, 2637, 2641, 2662, 2673, 2674, 2676, 2677, 2678, 2688, 2689, 2691,
+    2693, 2748, 2750, 2751, 2753, 2757, 2761, 2765, 2768, 2790, 2800, 2801, 2813, 2816, 2817, 2818,
+    2821, 2876, 2878, 2879, 2880, 2881, 2887, 2888, 2893, 2901, 2903, 2908, 2918, 2928, 2930, 2944,
+    2946, 2947, 2949, 2964, 3006, 3007, 3008, 3014, 3018, 3021, 3024, 3046, 3056, 3059, 3065, 3072,
+    3073, 3076, 3077, 3085, 3134, 3137, 3144, 3149, 3157, 3158, 3170, 3174, 3191, 3192, 3199, 3200,
+    3201, 3202, 3204, 3213, 3260, 3262, 3263, 3264, 3266, 3276, 3277, 3298, 3302, 3313, 3328, 3330,
+    3332, 3341, 3387, 3390, 3391, 3393, 3395, 3398, 3402, 3405, 3406, 3407, 3412, 3416, 3430, 3449,
+    3456, 3457, 3458, 3461, 3530, 3535, 3536, 3538, 3540, 3545, 3546, 3558, 3572, 3584, 3585, 3631,
+    3633, 3635, 3640, 3642, 3647, 3654, 3655, 3656, 3660, 3661, 3663, 3664, 3674, 3712, 3713, 3759,
+    3761, 3763, 3768, 3770, 3772, 3782, 3784, 3788, 3789, 3792, 3804, 3840, 3841, 3842, 3844, 3845,
+    3847, 3848, 3851, 3852, 3853, 3859, 3864, 3872, 3882, 3892, 3893, 3897, 3898, 3899, 3902, 3907,
+    3912, 3953, 3954, 3955, 3956, 3957, 3959, 3966, 3967, 3970, 3972, 3981, 3987, 4052, 4053, 4096,
+    4129, 4134, 4139, 4141, 4143, 4145, 4146, 4150, 4151, 4152, 4153, 4154, 4155, 4156, 4157, 4158,
+    4160, 4161, 4170, 4171, 4172, 4174, 4175, 4182, 4190, 4195, 4237, 4252, 4254, 4256, 4294, 4304,
+    4347, 4348, 4352, 4447, 4448, 4449, 4520, 4608, 4681, 4957, 4960, 4961, 4969, 4992, 5008, 5018,
+    5024, 5110, 5112, 5120, 5121, 5741, 5742, 5760, 5761, 5787, 5788, 5789, 5792, 5867, 5870, 5881,
+    5888, 5901, 5906, 5907, 5908, 5920, 5938, 5939, 5940, 5941, 5943, 5952, 5970, 5971, 5972, 5984,
+    5997, 6002, 6003, 6016, 6051, 6068, 6070, 6071, 6075, 6081, 6086, 6087, 6089, 6091, 6092, 6093,
+    6097, 6098, 6100, 6102, 6103, 6105, 6107, 6109, 6110, 6112, 6128, 6144, 6145, 6146, 6148, 6149,
+    6150, 6151, 6152, 6155, 6158, 6159, 6160, 6170, 6176, 6211, 6212, 6272, 6277, 6313, 6320, 6390,
+    6400, 6401, 6431, 6432, 6434, 6435, 6437, 6441, 6448, 6450, 6457, 6458, 6459, 6464, 6468, 6470,
+    6480, 6510, 6528, 6572, 6600, 6608, 6618, 6622, 6624, 6656, 6679, 6680, 6681, 6682, 6683, 6684,
+    6686, 6688, 6741, 6742, 6743, 6744, 6746, 6747, 6751, 6752, 6753, 6754, 6761, 6765, 6766, 6772,
+    6773, 6778, 6783, 6784, 6816, 6823, 6832, 6837, 6846, 6847, 6849, 6912, 6915, 6916, 6917, 6918,
+    6964, 6965, 6966, 6968, 6971, 6973, 6974, 6976, 6980, 6988, 6992, 7002, 7004, 7009, 7019, 7020,
+    7040, 7041, 7042, 7043, 7073, 7074, 7076, 7077, 7078, 7079, 7082, 7083, 7088, 7104, 7142, 7143,
+    7144, 7152, 7154, 7156, 7164, 7168, 7204, 7206, 7207, 7212, 7213, 7220, 7222, 7223, 7224, 7227,
+    7232, 7248, 7258, 7288, 7294, 7296, 7305, 7312, 7355, 7360, 7368, 7376, 7379, 7380, 7381, 7393,
+    7394, 7401, 7405, 7410, 7413, 7416, 7418, 7419, 7424, 7462, 7467, 7468, 7471, 7517, 7522, 7526,
+    7544, 7552, 7579, 7615, 7616, 7618, 7629, 7630, 7632, 7655, 7670, 7671, 7674, 7675, 7676, 7680,
+    7681, 7834, 7836, 7838, 7936, 7944, 7958, 8072, 8125, 8129, 8189, 8192, 8194, 8199, 8203, 8204,
+    8205, 8206, 8207, 8208, 8209, 8210, 8212, 8213, 8214, 8215, 8216, 8217, 8218, 8219, 8221, 8224,
+    8226, 8227, 8228, 8229, 8231, 8232, 8233, 8234, 8235, 8236, 8237, 8238, 8239, 8240, 8242, 8243,
+    8245, 8246, 8249, 8250, 8252, 8253, 8255, 8256, 8260, 8261, 8262, 8263, 8274, 8278, 8288, 8289,
+    8293, 8294, 8295, 8296, 8297, 8298, 8304, 8305, 8306, 8308, 8314, 8316, 8317, 8318, 8319, 8320,
+    8321, 8322, 8330, 8332, 8333, 8334, 8336, 8352, 8359, 8360, 8384, 8400, 8402, 8413, 8423, 8424,
+    8426, 8428, 8432, 8433, 8448, 8450, 8451, 8452, 8453, 8455, 8458, 8467, 8470, 8472, 8480, 8482,
+    8486, 8489, 8490, 8491, 8494, 8498, 8501, 8505, 8512, 8526, 8527, 8528, 8532, 8544, 8556, 8576,
+    8579, 8580, 8586, 8588, 8592, 8596, 8597, 8602, 8604, 8608, 8616, 8617, 8653, 8704, 8705, 8708,
+    8712, 8713, 8714, 8715, 8716, 8717, 8722, 8723, 8725, 8735, 8736, 8737, 8738, 8740, 8748, 8749,
+    8764, 8765, 8771, 8773, 8780, 8787, 8788, 8800, 8814, 8815, 8816, 8817, 8856, 8870, 8872, 8873,
+    8888, 8909, 8943, 8946, 8954, 8957, 8960, 8968, 8969, 8978, 8986, 8992, 9000, 9001, 9002, 9014,
+    9096, 9140, 9216, 9255, 9280, 9291, 9312, 9332, 9352, 9372, 9398, 9410, 9451, 9471, 9472, 9548,
+    9600, 9616, 9632, 9634, 9635, 9642, 9646, 9654, 9655, 9676, 9720, 9723, 9728, 9732, 9733, 9734,
+    9735, 9737, 9742, 9747, 9754, 9757, 9792, 9826, 9839, 9900, 9984, 9986, 9989, 9990, 9994, 9998,
+    10071, 10075, 10082, 10083, 10088, 10089, 10102, 10176, 10179, 10180, 10181, 10182, 10187,
+    10189, 10204, 10224, 10240, 10496, 10548, 10624, 10627, 10628, 10637, 10638, 10639, 10640,
+    10651, 10656, 10659, 10660, 10661, 10680, 10741, 10752, 10764, 10795, 10796, 10972, 10974,
+    10979, 10980, 10990, 11008, 11013, 11056, 11093, 11094, 11124, 11262, 11264, 11311, 11312,
+    11360, 11361, 11388, 11389, 11392, 11393, 11493, 11503, 11508, 11513, 11514, 11517, 11520,
+    11558, 11568, 11624, 11631, 11632, 11647, 11648, 11671, 11744, 11776, 11778, 11779, 11790,
+    11798, 11799, 11800, 11802, 11810, 11811, 11822, 11823, 11834, 11842, 11856, 11859, 11904,
+    11930, 11935, 12032, 12246, 12272, 12284, 12288, 12289, 12291, 12292, 12293, 12294, 12295,
+    12296, 12297, 12316, 12317, 12318, 12330, 12331, 12332, 12333, 12334, 12336, 12337, 12341,
+    12342, 12344, 12348, 12349, 12352, 12353, 12354, 12364, 12441, 12443, 12445, 12446, 12447,
+    12448, 12449, 12450, 12460, 12539, 12540, 12541, 12542, 12543, 12544, 12549, 12592, 12593,
+    12644, 12688, 12690, 12694, 12704, 12736, 12772, 12784, 12800, 12829, 12831, 12832, 12842,
+    12868, 12872, 12880, 12881, 12896, 12924, 12927, 12928, 12951, 13008, 13055, 13056, 13144,
+    13169, 13175, 13312, 19904, 19968, 40957, 40960, 40981, 42125, 42128, 42183, 42192, 42232,
+    42238, 42240, 42508, 42509, 42510, 42528, 42540, 42560, 42561, 42606, 42607, 42608, 42611,
+    42612, 42623, 42652, 42656, 42726, 42736, 42738, 42739, 42744, 42752, 42760, 42775, 42784,
+    42786, 42787, 42864, 42888, 42889, 42895, 42944, 43008, 43010, 43014, 43019, 43043, 43045,
+    43048, 43052, 43053, 43056, 43062, 43064, 43065, 43066, 43072, 43122, 43123, 43124, 43126,
+    43128, 43136, 43138, 43188, 43189, 43204, 43205, 43206, 43214, 43216, 43232, 43250, 43252,
+    43256, 43260, 43263, 43264, 43274, 43302, 43307, 43310, 43311, 43312, 43335, 43338, 43343,
+    43346, 43347, 43348, 43359, 43360, 43389, 43392, 43395, 43396, 43443, 43444, 43446, 43448,
+    43450, 43453, 43454, 43455, 43456, 43457, 43463, 43470, 43471, 43472, 43488, 43493, 43494,
+    43504, 43519, 43520, 43561, 43562, 43565, 43567, 43571, 43572, 43573, 43574, 43575, 43587,
+    43597, 43600, 43612, 43613, 43616, 43632, 43636, 43639, 43643, 43644, 43645, 43646, 43648,
+    43696, 43700, 43711, 43715, 43739, 43741, 43742, 43744, 43755, 43756, 43757, 43759, 43760,
+    43762, 43763, 43765, 43766, 43767, 43776, 43777, 43824, 43867, 43868, 43877, 43881, 43882,
+    43884, 43888, 43968, 44003, 44005, 44008, 44011, 44012, 44013, 44014, 44016, 44032, 44033,
+    55204, 55216, 55239, 55243, 55296, 56192, 56320, 57344, 63744, 64014, 64110, 64256, 64263,
+    64275, 64285, 64286, 64288, 64297, 64335, 64336, 64337, 64340, 64341, 64434, 64450, 64830,
+    64831, 64976, 65020, 65021, 65024, 65040, 65041, 65043, 65045, 65047, 65048, 65049, 65050,
+    65056, 65063, 65070, 65072, 65073, 65075, 65077, 65078, 65093, 65097, 65101, 65104, 65105,
+    65106, 65107, 65108, 65109, 65110, 65112, 65113, 65114, 65119, 65121, 65122, 65123, 65124,
+    65125, 65126, 65129, 65130, 65136, 65137, 65139, 65141, 65154, 65163, 65279, 65280, 65281,
+    65282, 65283, 65284, 65285, 65287, 65288, 65289, 65291, 65292, 65293, 65294, 65295, 65296,
+    65306, 65307, 65308, 65309, 65310, 65313, 65339, 65340, 65341, 65342, 65343, 65344, 65345,
+    65377, 65378, 65379, 65381, 65382, 65383, 65392, 65438, 65440, 65441, 65504, 65508, 65512,
+    65513, 65520, 65529, 65532, 65533, 65534, 65536, 65548, 65664, 65787, 65792, 65793, 65795,
+    65799, 65847, 65856, 65909, 65913, 65933, 65935, 65936, 65949, 65952, 66000, 66045, 66046,
+    66176, 66205, 66208, 66257, 66272, 66273, 66300, 66304, 66336, 66340, 66352, 66369, 66379,
+    66384, 66422, 66427, 66432, 66462, 66463, 66464, 66500, 66512, 66513, 66560, 66600, 66640,
+    66688, 66718, 66720, 66736, 66772, 66776, 66816, 66856, 66864, 66916, 66927, 67072, 67383,
+    67584, 67590, 67648, 67670, 67671, 67672, 67680, 67703, 67705, 67712, 67743, 67751, 67808,
+    67827, 67835, 67840, 67862, 67868, 67871, 67872, 67898, 67903, 67968, 68000, 68024, 68028,
+    68096, 68097, 68100, 68101, 68108, 68109, 68110, 68111, 68152, 68153, 68154, 68159, 68160,
+    68176, 68184, 68192, 68221, 68223, 68224, 68253, 68288, 68293, 68294, 68296, 68301, 68325,
+    68326, 68327, 68331, 68335, 68336, 68342, 68352, 68406, 68409, 68416, 68438, 68440, 68448,
+    68467, 68472, 68480, 68481, 68498, 68505, 68521, 68525, 68527, 68608, 68681, 68736, 68787,
+    68800, 68858, 68864, 68865, 68898, 68900, 68903, 68904, 68912, 69216, 69247, 69248, 69290,
+    69291, 69293, 69376, 69405, 69416, 69424, 69427, 69445, 69446, 69448, 69457, 69460, 69461,
+    69466, 69552, 69553, 69556, 69573, 69577, 69578, 69579, 69580, 69600, 69623, 69632, 69633,
+    69635, 69688, 69692, 69702, 69703, 69705, 69710, 69714, 69734, 69759, 69760, 69762, 69763,
+    69786, 69808, 69809, 69811, 69813, 69817, 69818, 69819, 69821, 69822, 69826, 69840, 69865,
+    69872, 69888, 69891, 69927, 69932, 69934, 69939, 69940, 69941, 69942, 69952, 69957, 69968,
+    70003, 70004, 70005, 70006, 70007, 70016, 70018, 70019, 70067, 70068, 70070, 70076, 70079,
+    70080, 70082, 70084, 70085, 70087, 70089, 70090, 70091, 70092, 70096, 70107, 70112, 70113,
+    70144, 70162, 70188, 70191, 70192, 70194, 70196, 70197, 70198, 70199, 70200, 70202, 70272,
+    70279, 70313, 70320, 70367, 70368, 70369, 70371, 70373, 70377, 70378, 70379, 70384, 70400,
+    70402, 70404, 70405, 70459, 70460, 70462, 70463, 70464, 70471, 70475, 70477, 70480, 70502,
+    70656, 70709, 70710, 70712, 70718, 70722, 70723, 70725, 70726, 70728, 70731, 70735, 70736,
+    70748, 70750, 70752, 70784, 70785, 70832, 70833, 70834, 70835, 70842, 70843, 70847, 70849,
+    70850, 70851, 70854, 70856, 70864, 71040, 71087, 71088, 71089, 71090, 71094, 71098, 71100,
+    71102, 71103, 71104, 71105, 71106, 71108, 71110, 71168, 71216, 71219, 71225, 71229, 71230,
+    71231, 71233, 71235, 71236, 71237, 71248, 71264, 71277, 71296, 71339, 71340, 71341, 71342,
+    71343, 71344, 71350, 71351, 71353, 71360, 71424, 71451, 71453, 71454, 71455, 71456, 71458,
+    71460, 71462, 71467, 71472, 71482, 71484, 71487, 71680, 71724, 71725, 71727, 71731, 71735,
+    71736, 71737, 71738, 71739, 71740, 71840, 71872, 71904, 71914, 71923, 71935, 71936, 71943,
+    71984, 71985, 71989, 71992, 71995, 71997, 71998, 71999, 72000, 72001, 72002, 72003, 72004,
+    72016, 72096, 72104, 72145, 72146, 72148, 72154, 72158, 72160, 72162, 72163, 72192, 72193,
+    72194, 72199, 72243, 72244, 72245, 72249, 72250, 72251, 72255, 72256, 72257, 72263, 72264,
+    72272, 72273, 72274, 72279, 72324, 72330, 72342, 72343, 72344, 72345, 72346, 72350, 72355,
+    72384, 72441, 72704, 72713, 72751, 72752, 72754, 72764, 72766, 72767, 72769, 72784, 72794,
+    72816, 72817, 72818, 72848, 72850, 72873, 72880, 72881, 72883, 72884, 72885, 72960, 72967,
+    73009, 73014, 73024, 73026, 73028, 73029, 73030, 73031, 73040, 73056, 73062, 73098, 73104,
+    73109, 73110, 73111, 73112, 73120, 73440, 73458, 73459, 73460, 73461, 73462, 73463, 73465,
+    73648, 73649, 73664, 73685, 73693, 73714, 73727, 73728, 74650, 74752, 74863, 74864, 74880,
+    75076, 77824, 78424, 78427, 78895, 78896, 78903, 78904, 78905, 82944, 83406, 83407, 83527,
+    92160, 92729, 92736, 92767, 92768, 92782, 92880, 92910, 92912, 92917, 92928, 92976, 92983,
+    92986, 92988, 92992, 92998, 93008, 93019, 93027, 93760, 93792, 93824, 93847, 93849, 93851,
+    93952, 94021, 94027, 94031, 94033, 94095, 94099, 94176, 94177, 94178, 94179, 94180, 94181,
+    94192, 94208, 100344, 100352, 101120, 101590, 101632, 101641, 110592, 110593, 110848, 110879,
+    110896, 110928, 110948, 110960, 111356, 113664, 113771, 113818, 113820, 113821, 113822, 113823,
+    113824, 113828, 118784, 119030, 119040, 119079, 119134, 119141, 119142, 119143, 119149, 119155,
+    119163, 119173, 119296, 119362, 119366, 119520, 119540, 119552, 119639, 119648, 119673, 119808,
+    119834, 119893, 120513, 120539, 120782, 120832, 121344, 121479, 121483, 121484, 122880, 122887,
+    123136, 123181, 123184, 123191, 123200, 123215, 123584, 123628, 123632, 123642, 123647, 124928,
+    125125, 125127, 125136, 125184, 125218, 125252, 125255, 125258, 125259, 125260, 125264, 125278,
+    126064, 126065, 126124, 126128, 126208, 126209, 126254, 126464, 126468, 126704, 126976, 126980,
+    127020, 127024, 127124, 127136, 127151, 127183, 127232, 127243, 127245, 127248, 127275, 127278,
+    127279, 127280, 127306, 127312, 127338, 127340, 127344, 127370, 127374, 127406, 127462, 127488,
+    127489, 127491, 127504, 127552, 127568, 127584, 127744, 127778, 127877, 127900, 127925, 127995,
+    128318, 128512, 128581, 128592, 128630, 128633, 128640, 128675, 128710, 128728, 128768, 128884,
+    128896, 128981, 128985, 128992, 129024, 129036, 129200, 129280, 129292, 129293, 129339, 129401,
+    129536, 129620, 129632, 129648, 129653, 129792, 129939, 130032, 130048, 131070, 131072, 173790,
+    173792, 173824, 177973, 177984, 178206, 178208, 183970, 183984, 191457, 194560, 195102, 196608,
+    201547, 917504, 917505, 917536, 917632, 917760, 983040, 1048574, 1048576, 1114110,
 ];
 
 pub const COMPOSE0_COUNT: usize = 377;
@@ -5481,3 +6247,1421 @@ pub const DECOMPOSE_COMPAT: [u32; 9307] = [
     12309, 3, 12308, 21213, 12309, 3, 12308, 25943, 12309, 1, 24471, 1, 21487, 1, 48, 1, 49, 1, 50,
     1, 51, 1, 52, 1, 53, 1, 54, 1, 55, 1, 56, 1, 57,
 ];
+
+#[cfg(feature = "emoji-sequences")]
+#[rustfmt::skip]
+pub const EMOJI_SEQUENCE_CHARS: [u32; 11224] = [
+    35, 65039, 8419, 35, 8419, 42, 65039, 8419, 42, 8419, 48, 65039, 8419, 48, 8419, 49, 65039,
+    8419, 49, 8419, 50, 65039, 8419, 50, 8419, 51, 65039, 8419, 51, 8419, 52, 65039, 8419, 52,
+    8419, 53, 65039, 8419, 53, 8419, 54, 65039, 8419, 54, 8419, 55, 65039, 8419, 55, 8419, 56,
+    65039, 8419, 56, 8419, 57, 65039, 8419, 57, 8419, 169, 65039, 169, 174, 65039, 174, 8252,
+    65039, 8252, 8265, 65039, 8265, 8482, 65039, 8482, 8505, 65039, 8505, 8596, 65039, 8596, 8597,
+    65039, 8597, 8598, 65039, 8598, 8599, 65039, 8599, 8600, 65039, 8600, 8601, 65039, 8601, 8617,
+    65039, 8617, 8618, 65039, 8618, 8986, 8987, 9000, 65039, 9000, 9167, 65039, 9167, 9193, 9194,
+    9195, 9196, 9197, 65039, 9197, 9198, 65039, 9198, 9199, 65039, 9199, 9200, 9201, 65039, 9201,
+    9202, 65039, 9202, 9203, 9208, 65039, 9208, 9209, 65039, 9209, 9210, 65039, 9210, 9410, 65039,
+    9410, 9642, 65039, 9642, 9643, 65039, 9643, 9654, 65039, 9654, 9664, 65039, 9664, 9723, 65039,
+    9723, 9724, 65039, 9724, 9725, 9726, 9728, 65039, 9728, 9729, 65039, 9729, 9730, 65039, 9730,
+    9731, 65039, 9731, 9732, 65039, 9732, 9742, 65039, 9742, 9745, 65039, 9745, 9748, 9749, 9752,
+    65039, 9752, 9757, 65039, 9757, 9757, 127995, 9757, 127996, 9757, 127997, 9757, 127998, 9757,
+    127999, 9760, 65039, 9760, 9762, 65039, 9762, 9763, 65039, 9763, 9766, 65039, 9766, 9770,
+    65039, 9770, 9774, 65039, 9774, 9775, 65039, 9775, 9784, 65039, 9784, 9785, 65039, 9785, 9786,
+    65039, 9786, 9792, 65039, 9792, 9794, 65039, 9794, 9800, 9801, 9802, 9803, 9804, 9805, 9806,
+    9807, 9808, 9809, 9810, 9811, 9823, 65039, 9823, 9824, 65039, 9824, 9827, 65039, 9827, 9829,
+    65039, 9829, 9830, 65039, 9830, 9832, 65039, 9832, 9851, 65039, 9851, 9854, 65039, 9854, 9855,
+    9874, 65039, 9874, 9875, 9876, 65039, 9876, 9877, 65039, 9877, 9878, 65039, 9878, 9879, 65039,
+    9879, 9881, 65039, 9881, 9883, 65039, 9883, 9884, 65039, 9884, 9888, 65039, 9888, 9889, 9895,
+    65039, 9895, 9898, 9899, 9904, 65039, 9904, 9905, 65039, 9905, 9917, 9918, 9924, 9925, 9928,
+    65039, 9928, 9934, 9935, 65039, 9935, 9937, 65039, 9937, 9939, 65039, 9939, 9940, 9961, 65039,
+    9961, 9962, 9968, 65039, 9968, 9969, 65039, 9969, 9970, 9971, 9972, 65039, 9972, 9973, 9975,
+    65039, 9975, 9976, 65039, 9976, 9977, 65039, 9977, 9977, 65039, 8205, 9792, 65039, 9977, 65039,
+    8205, 9792, 9977, 8205, 9792, 65039, 9977, 8205, 9792, 9977, 65039, 8205, 9794, 65039, 9977,
+    65039, 8205, 9794, 9977, 8205, 9794, 65039, 9977, 8205, 9794, 9977, 127995, 9977, 127995, 8205,
+    9792, 65039, 9977, 127995, 8205, 9792, 9977, 127995, 8205, 9794, 65039, 9977, 127995, 8205,
+    9794, 9977, 127996, 9977, 127996, 8205, 9792, 65039, 9977, 127996, 8205, 9792, 9977, 127996,
+    8205, 9794, 65039, 9977, 127996, 8205, 9794, 9977, 127997, 9977, 127997, 8205, 9792, 65039,
+    9977, 127997, 8205, 9792, 9977, 127997, 8205, 9794, 65039, 9977, 127997, 8205, 9794, 9977,
+    127998, 9977, 127998, 8205, 9792, 65039, 9977, 127998, 8205, 9792, 9977, 127998, 8205, 9794,
+    65039, 9977, 127998, 8205, 9794, 9977, 127999, 9977, 127999, 8205, 9792, 65039, 9977, 127999,
+    8205, 9792, 9977, 127999, 8205, 9794, 65039, 9977, 127999, 8205, 9794, 9978, 9981, 9986, 65039,
+    9986, 9989, 9992, 65039, 9992, 9993, 65039, 9993, 9994, 9994, 127995, 9994, 127996, 9994,
+    127997, 9994, 127998, 9994, 127999, 9995, 9995, 127995, 9995, 127996, 9995, 127997, 9995,
+    127998, 9995, 127999, 9996, 65039, 9996, 9996, 127995, 9996, 127996, 9996, 127997, 9996,
+    127998, 9996, 127999, 9997, 65039, 9997, 9997, 127995, 9997, 127996, 9997, 127997, 9997,
+    127998, 9997, 127999, 9999, 65039, 9999, 10002, 65039, 10002, 10004, 65039, 10004, 10006,
+    65039, 10006, 10013, 65039, 10013, 10017, 65039, 10017, 10024, 10035, 65039, 10035, 10036,
+    65039, 10036, 10052, 65039, 10052, 10055, 65039, 10055, 10060, 10062, 10067, 10068, 10069,
+    10071, 10083, 65039, 10083, 10084, 65039, 10084, 10133, 10134, 10135, 10145, 65039, 10145,
+    10160, 10175, 10548, 65039, 10548, 10549, 65039, 10549, 11013, 65039, 11013, 11014, 65039,
+    11014, 11015, 65039, 11015, 11035, 11036, 11088, 11093, 12336, 65039, 12336, 12349, 65039,
+    12349, 12951, 65039, 12951, 12953, 65039, 12953, 126980, 127183, 127344, 65039, 127344, 127345,
+    65039, 127345, 127358, 65039, 127358, 127359, 65039, 127359, 127374, 127377, 127378, 127379,
+    127380, 127381, 127382, 127383, 127384, 127385, 127386, 127462, 127464, 127462, 127465, 127462,
+    127466, 127462, 127467, 127462, 127468, 127462, 127470, 127462, 127473, 127462, 127474, 127462,
+    127476, 127462, 127478, 127462, 127479, 127462, 127480, 127462, 127481, 127462, 127482, 127462,
+    127484, 127462, 127485, 127462, 127487, 127463, 127462, 127463, 127463, 127463, 127465, 127463,
+    127466, 127463, 127467, 127463, 127468, 127463, 127469, 127463, 127470, 127463, 127471, 127463,
+    127473, 127463, 127474, 127463, 127475, 127463, 127476, 127463, 127478, 127463, 127479, 127463,
+    127480, 127463, 127481, 127463, 127483, 127463, 127484, 127463, 127486, 127463, 127487, 127464,
+    127462, 127464, 127464, 127464, 127465, 127464, 127467, 127464, 127468, 127464, 127469, 127464,
+    127470, 127464, 127472, 127464, 127473, 127464, 127474, 127464, 127475, 127464, 127476, 127464,
+    127477, 127464, 127479, 127464, 127482, 127464, 127483, 127464, 127484, 127464, 127485, 127464,
+    127486, 127464, 127487, 127465, 127466, 127465, 127468, 127465, 127471, 127465, 127472, 127465,
+    127474, 127465, 127476, 127465, 127487, 127466, 127462, 127466, 127464, 127466, 127466, 127466,
+    127468, 127466, 127469, 127466, 127479, 127466, 127480, 127466, 127481, 127466, 127482, 127467,
+    127470, 127467, 127471, 127467, 127472, 127467, 127474, 127467, 127476, 127467, 127479, 127468,
+    127462, 127468, 127463, 127468, 127465, 127468, 127466, 127468, 127467, 127468, 127468, 127468,
+    127469, 127468, 127470, 127468, 127473, 127468, 127474, 127468, 127475, 127468, 127477, 127468,
+    127478, 127468, 127479, 127468, 127480, 127468, 127481, 127468, 127482, 127468, 127484, 127468,
+    127486, 127469, 127472, 127469, 127474, 127469, 127475, 127469, 127479, 127469, 127481, 127469,
+    127482, 127470, 127464, 127470, 127465, 127470, 127466, 127470, 127473, 127470, 127474, 127470,
+    127475, 127470, 127476, 127470, 127478, 127470, 127479, 127470, 127480, 127470, 127481, 127471,
+    127466, 127471, 127474, 127471, 127476, 127471, 127477, 127472, 127466, 127472, 127468, 127472,
+    127469, 127472, 127470, 127472, 127474, 127472, 127475, 127472, 127477, 127472, 127479, 127472,
+    127484, 127472, 127486, 127472, 127487, 127473, 127462, 127473, 127463, 127473, 127464, 127473,
+    127470, 127473, 127472, 127473, 127479, 127473, 127480, 127473, 127481, 127473, 127482, 127473,
+    127483, 127473, 127486, 127474, 127462, 127474, 127464, 127474, 127465, 127474, 127466, 127474,
+    127467, 127474, 127468, 127474, 127469, 127474, 127472, 127474, 127473, 127474, 127474, 127474,
+    127475, 127474, 127476, 127474, 127477, 127474, 127478, 127474, 127479, 127474, 127480, 127474,
+    127481, 127474, 127482, 127474, 127483, 127474, 127484, 127474, 127485, 127474, 127486, 127474,
+    127487, 127475, 127462, 127475, 127464, 127475, 127466, 127475, 127467, 127475, 127468, 127475,
+    127470, 127475, 127473, 127475, 127476, 127475, 127477, 127475, 127479, 127475, 127482, 127475,
+    127487, 127476, 127474, 127477, 127462, 127477, 127466, 127477, 127467, 127477, 127468, 127477,
+    127469, 127477, 127472, 127477, 127473, 127477, 127474, 127477, 127475, 127477, 127479, 127477,
+    127480, 127477, 127481, 127477, 127484, 127477, 127486, 127478, 127462, 127479, 127466, 127479,
+    127476, 127479, 127480, 127479, 127482, 127479, 127484, 127480, 127462, 127480, 127463, 127480,
+    127464, 127480, 127465, 127480, 127466, 127480, 127468, 127480, 127469, 127480, 127470, 127480,
+    127471, 127480, 127472, 127480, 127473, 127480, 127474, 127480, 127475, 127480, 127476, 127480,
+    127479, 127480, 127480, 127480, 127481, 127480, 127483, 127480, 127485, 127480, 127486, 127480,
+    127487, 127481, 127462, 127481, 127464, 127481, 127465, 127481, 127467, 127481, 127468, 127481,
+    127469, 127481, 127471, 127481, 127472, 127481, 127473, 127481, 127474, 127481, 127475, 127481,
+    127476, 127481, 127479, 127481, 127481, 127481, 127483, 127481, 127484, 127481, 127487, 127482,
+    127462, 127482, 127468, 127482, 127474, 127482, 127475, 127482, 127480, 127482, 127486, 127482,
+    127487, 127483, 127462, 127483, 127464, 127483, 127466, 127483, 127468, 127483, 127470, 127483,
+    127475, 127483, 127482, 127484, 127467, 127484, 127480, 127485, 127472, 127486, 127466, 127486,
+    127481, 127487, 127462, 127487, 127474, 127487, 127484, 127489, 127490, 65039, 127490, 127514,
+    127535, 127538, 127539, 127540, 127541, 127542, 127543, 65039, 127543, 127544, 127545, 127546,
+    127568, 127569, 127744, 127745, 127746, 127747, 127748, 127749, 127750, 127751, 127752, 127753,
+    127754, 127755, 127756, 127757, 127758, 127759, 127760, 127761, 127762, 127763, 127764, 127765,
+    127766, 127767, 127768, 127769, 127770, 127771, 127772, 127773, 127774, 127775, 127776, 127777,
+    65039, 127777, 127780, 65039, 127780, 127781, 65039, 127781, 127782, 65039, 127782, 127783,
+    65039, 127783, 127784, 65039, 127784, 127785, 65039, 127785, 127786, 65039, 127786, 127787,
+    65039, 127787, 127788, 65039, 127788, 127789, 127790, 127791, 127792, 127793, 127794, 127795,
+    127796, 127797, 127798, 65039, 127798, 127799, 127800, 127801, 127802, 127803, 127804, 127805,
+    127806, 127807, 127808, 127809, 127810, 127811, 127812, 127813, 127814, 127815, 127816, 127817,
+    127818, 127819, 127820, 127821, 127822, 127823, 127824, 127825, 127826, 127827, 127828, 127829,
+    127830, 127831, 127832, 127833, 127834, 127835, 127836, 127837, 127838, 127839, 127840, 127841,
+    127842, 127843, 127844, 127845, 127846, 127847, 127848, 127849, 127850, 127851, 127852, 127853,
+    127854, 127855, 127856, 127857, 127858, 127859, 127860, 127861, 127862, 127863, 127864, 127865,
+    127866, 127867, 127868, 127869, 65039, 127869, 127870, 127871, 127872, 127873, 127874, 127875,
+    127876, 127877, 127877, 127995, 127877, 127996, 127877, 127997, 127877, 127998, 127877, 127999,
+    127878, 127879, 127880, 127881, 127882, 127883, 127884, 127885, 127886, 127887, 127888, 127889,
+    127890, 127891, 127894, 65039, 127894, 127895, 65039, 127895, 127897, 65039, 127897, 127898,
+    65039, 127898, 127899, 65039, 127899, 127902, 65039, 127902, 127903, 65039, 127903, 127904,
+    127905, 127906, 127907, 127908, 127909, 127910, 127911, 127912, 127913, 127914, 127915, 127916,
+    127917, 127918, 127919, 127920, 127921, 127922, 127923, 127924, 127925, 127926, 127927, 127928,
+    127929, 127930, 127931, 127932, 127933, 127934, 127935, 127936, 127937, 127938, 127938, 127995,
+    127938, 127996, 127938, 127997, 127938, 127998, 127938, 127999, 127939, 127939, 8205, 9792,
+    65039, 127939, 8205, 9792, 127939, 8205, 9794, 65039, 127939, 8205, 9794, 127939, 127995,
+    127939, 127995, 8205, 9792, 65039, 127939, 127995, 8205, 9792, 127939, 127995, 8205, 9794,
+    65039, 127939, 127995, 8205, 9794, 127939, 127996, 127939, 127996, 8205, 9792, 65039, 127939,
+    127996, 8205, 9792, 127939, 127996, 8205, 9794, 65039, 127939, 127996, 8205, 9794, 127939,
+    127997, 127939, 127997, 8205, 9792, 65039, 127939, 127997, 8205, 9792, 127939, 127997, 8205,
+    9794, 65039, 127939, 127997, 8205, 9794, 127939, 127998, 127939, 127998, 8205, 9792, 65039,
+    127939, 127998, 8205, 9792, 127939, 127998, 8205, 9794, 65039, 127939, 127998, 8205, 9794,
+    127939, 127999, 127939, 127999, 8205, 9792, 65039, 127939, 127999, 8205, 9792, 127939, 127999,
+    8205, 9794, 65039, 127939, 127999, 8205, 9794, 127940, 127940, 8205, 9792, 65039, 127940, 8205,
+    9792, 127940, 8205, 9794, 65039, 127940, 8205, 9794, 127940, 127995, 127940, 127995, 8205,
+    9792, 65039, 127940, 127995, 8205, 9792, 127940, 127995, 8205, 9794, 65039, 127940, 127995,
+    8205, 9794, 127940, 127996, 127940, 127996, 8205, 9792, 65039, 127940, 127996, 8205, 9792,
+    127940, 127996, 8205, 9794, 65039, 127940, 127996, 8205, 9794, 127940, 127997, 127940, 127997,
+    8205, 9792, 65039, 127940, 127997, 8205, 9792, 127940, 127997, 8205, 9794, 65039, 127940,
+    127997, 8205, 9794, 127940, 127998, 127940, 127998, 8205, 9792, 65039, 127940, 127998, 8205,
+    9792, 127940, 127998, 8205, 9794, 65039, 127940, 127998, 8205, 9794, 127940, 127999, 127940,
+    127999, 8205, 9792, 65039, 127940, 127999, 8205, 9792, 127940, 127999, 8205, 9794, 65039,
+    127940, 127999, 8205, 9794, 127941, 127942, 127943, 127943, 127995, 127943, 127996, 127943,
+    127997, 127943, 127998, 127943, 127999, 127944, 127945, 127946, 127946, 8205, 9792, 65039,
+    127946, 8205, 9792, 127946, 8205, 9794, 65039, 127946, 8205, 9794, 127946, 127995, 127946,
+    127995, 8205, 9792, 65039, 127946, 127995, 8205, 9792, 127946, 127995, 8205, 9794, 65039,
+    127946, 127995, 8205, 9794, 127946, 127996, 127946, 127996, 8205, 9792, 65039, 127946, 127996,
+    8205, 9792, 127946, 127996, 8205, 9794, 65039, 127946, 127996, 8205, 9794, 127946, 127997,
+    127946, 127997, 8205, 9792, 65039, 127946, 127997, 8205, 9792, 127946, 127997, 8205, 9794,
+    65039, 127946, 127997, 8205, 9794, 127946, 127998, 127946, 127998, 8205, 9792, 65039, 127946,
+    127998, 8205, 9792, 127946, 127998, 8205, 9794, 65039, 127946, 127998, 8205, 9794, 127946,
+    127999, 127946, 127999, 8205, 9792, 65039, 127946, 127999, 8205, 9792, 127946, 127999, 8205,
+    9794, 65039, 127946, 127999, 8205, 9794, 127947, 65039, 127947, 127947, 65039, 8205, 9792,
+    65039, 127947, 65039, 8205, 9792, 127947, 8205, 9792, 65039, 127947, 8205, 9792, 127947, 65039,
+    8205, 9794, 65039, 127947, 65039, 8205, 9794, 127947, 8205, 9794, 65039, 127947, 8205, 9794,
+    127947, 127995, 127947, 127995, 8205, 9792, 65039, 127947, 127995, 8205, 9792, 127947, 127995,
+    8205, 9794, 65039, 127947, 127995, 8205, 9794, 127947, 127996, 127947, 127996, 8205, 9792,
+    65039, 127947, 127996, 8205, 9792, 127947, 127996, 8205, 9794, 65039, 127947, 127996, 8205,
+    9794, 127947, 127997, 127947, 127997, 8205, 9792, 65039, 127947, 127997, 8205, 9792, 127947,
+    127997, 8205, 9794, 65039, 127947, 127997, 8205, 9794, 127947, 127998, 127947, 127998, 8205,
+    9792, 65039, 127947, 127998, 8205, 9792, 127947, 127998, 8205, 9794, 65039, 127947, 127998,
+    8205, 9794, 127947, 127999, 127947, 127999, 8205, 9792, 65039, 127947, 127999, 8205, 9792,
+    127947, 127999, 8205, 9794, 65039, 127947, 127999, 8205, 9794, 127948, 65039, 127948, 127948,
+    65039, 8205, 9792, 65039, 127948, 65039, 8205, 9792, 127948, 8205, 9792, 65039, 127948, 8205,
+    9792, 127948, 65039, 8205, 9794, 65039, 127948, 65039, 8205, 9794, 127948, 8205, 9794, 65039,
+    127948, 8205, 9794, 127948, 127995, 127948, 127995, 8205, 9792, 65039, 127948, 127995, 8205,
+    9792, 127948, 127995, 8205, 9794, 65039, 127948, 127995, 8205, 9794, 127948, 127996, 127948,
+    127996, 8205, 9792, 65039, 127948, 127996, 8205, 9792, 127948, 127996, 8205, 9794, 65039,
+    127948, 127996, 8205, 9794, 127948, 127997, 127948, 127997, 8205, 9792, 65039, 127948, 127997,
+    8205, 9792, 127948, 127997, 8205, 9794, 65039, 127948, 127997, 8205, 9794, 127948, 127998,
+    127948, 127998, 8205, 9792, 65039, 127948, 127998, 8205, 9792, 127948, 127998, 8205, 9794,
+    65039, 127948, 127998, 8205, 9794, 127948, 127999, 127948, 127999, 8205, 9792, 65039, 127948,
+    127999, 8205, 9792, 127948, 127999, 8205, 9794, 65039, 127948, 127999, 8205, 9794, 127949,
+    65039, 127949, 127950, 65039, 127950, 127951, 127952, 127953, 127954, 127955, 127956, 65039,
+    127956, 127957, 65039, 127957, 127958, 65039, 127958, 127959, 65039, 127959, 127960, 65039,
+    127960, 127961, 65039, 127961, 127962, 65039, 127962, 127963, 65039, 127963, 127964, 65039,
+    127964, 127965, 65039, 127965, 127966, 65039, 127966, 127967, 65039, 127967, 127968, 127969,
+    127970, 127971, 127972, 127973, 127974, 127975, 127976, 127977, 127978, 127979, 127980, 127981,
+    127982, 127983, 127984, 127987, 65039, 127987, 127987, 65039, 8205, 9895, 65039, 127987, 65039,
+    8205, 9895, 127987, 8205, 9895, 65039, 127987, 8205, 9895, 127987, 65039, 8205, 127752, 127987,
+    8205, 127752, 127988, 127988, 8205, 9760, 65039, 127988, 8205, 9760, 127988, 917607, 917602,
+    917605, 917614, 917607, 917631, 127988, 917607, 917602, 917619, 917603, 917620, 917631, 127988,
+    917607, 917602, 917623, 917612, 917619, 917631, 127989, 65039, 127989, 127991, 65039, 127991,
+    127992, 127993, 127994, 127995, 127996, 127997, 127998, 127999, 128000, 128001, 128002, 128003,
+    128004, 128005, 128006, 128007, 128008, 128008, 8205, 11035, 128009, 128010, 128011, 128012,
+    128013, 128014, 128015, 128016, 128017, 128018, 128019, 128020, 128021, 128021, 8205, 129466,
+    128022, 128023, 128024, 128025, 128026, 128027, 128028, 128029, 128030, 128031, 128032, 128033,
+    128034, 128035, 128036, 128037, 128038, 128039, 128040, 128041, 128042, 128043, 128044, 128045,
+    128046, 128047, 128048, 128049, 128050, 128051, 128052, 128053, 128054, 128055, 128056, 128057,
+    128058, 128059, 128059, 8205, 10052, 65039, 128059, 8205, 10052, 128060, 128061, 128062,
+    128063, 65039, 128063, 128064, 128065, 65039, 128065, 128065, 65039, 8205, 128488, 65039,
+    128065, 65039, 8205, 128488, 128065, 8205, 128488, 65039, 128065, 8205, 128488, 128066, 128066,
+    127995, 128066, 127996, 128066, 127997, 128066, 127998, 128066, 127999, 128067, 128067, 127995,
+    128067, 127996, 128067, 127997, 128067, 127998, 128067, 127999, 128068, 128069, 128070, 128070,
+    127995, 128070, 127996, 128070, 127997, 128070, 127998, 128070, 127999, 128071, 128071, 127995,
+    128071, 127996, 128071, 127997, 128071, 127998, 128071, 127999, 128072, 128072, 127995, 128072,
+    127996, 128072, 127997, 128072, 127998, 128072, 127999, 128073, 128073, 127995, 128073, 127996,
+    128073, 127997, 128073, 127998, 128073, 127999, 128074, 128074, 127995, 128074, 127996, 128074,
+    127997, 128074, 127998, 128074, 127999, 128075, 128075, 127995, 128075, 127996, 128075, 127997,
+    128075, 127998, 128075, 127999, 128076, 128076, 127995, 128076, 127996, 128076, 127997, 128076,
+    127998, 128076, 127999, 128077, 128077, 127995, 128077, 127996, 128077, 127997, 128077, 127998,
+    128077, 127999, 128078, 128078, 127995, 128078, 127996, 128078, 127997, 128078, 127998, 128078,
+    127999, 128079, 128079, 127995, 128079, 127996, 128079, 127997, 128079, 127998, 128079, 127999,
+    128080, 128080, 127995, 128080, 127996, 128080, 127997, 128080, 127998, 128080, 127999, 128081,
+    128082, 128083, 128084, 128085, 128086, 128087, 128088, 128089, 128090, 128091, 128092, 128093,
+    128094, 128095, 128096, 128097, 128098, 128099, 128100, 128101, 128102, 128102, 127995, 128102,
+    127996, 128102, 127997, 128102, 127998, 128102, 127999, 128103, 128103, 127995, 128103, 127996,
+    128103, 127997, 128103, 127998, 128103, 127999, 128104, 128104, 8205, 9877, 65039, 128104,
+    8205, 9877, 128104, 8205, 9878, 65039, 128104, 8205, 9878, 128104, 8205, 9992, 65039, 128104,
+    8205, 9992, 128104, 8205, 10084, 65039, 8205, 128104, 128104, 8205, 10084, 8205, 128104,
+    128104, 8205, 10084, 65039, 8205, 128139, 8205, 128104, 128104, 8205, 10084, 8205, 128139,
+    8205, 128104, 128104, 8205, 127806, 128104, 8205, 127859, 128104, 8205, 127868, 128104, 8205,
+    127891, 128104, 8205, 127908, 128104, 8205, 127912, 128104, 8205, 127979, 128104, 8205, 127981,
+    128104, 8205, 128102, 128104, 8205, 128102, 8205, 128102, 128104, 8205, 128103, 128104, 8205,
+    128103, 8205, 128102, 128104, 8205, 128103, 8205, 128103, 128104, 8205, 128104, 8205, 128102,
+    128104, 8205, 128104, 8205, 128102, 8205, 128102, 128104, 8205, 128104, 8205, 128103, 128104,
+    8205, 128104, 8205, 128103, 8205, 128102, 128104, 8205, 128104, 8205, 128103, 8205, 128103,
+    128104, 8205, 128105, 8205, 128102, 128104, 8205, 128105, 8205, 128102, 8205, 128102, 128104,
+    8205, 128105, 8205, 128103, 128104, 8205, 128105, 8205, 128103, 8205, 128102, 128104, 8205,
+    128105, 8205, 128103, 8205, 128103, 128104, 8205, 128187, 128104, 8205, 128188, 128104, 8205,
+    128295, 128104, 8205, 128300, 128104, 8205, 128640, 128104, 8205, 128658, 128104, 8205, 129455,
+    128104, 8205, 129456, 128104, 8205, 129457, 128104, 8205, 129458, 128104, 8205, 129459, 128104,
+    8205, 129468, 128104, 8205, 129469, 128104, 127995, 128104, 127995, 8205, 9877, 65039, 128104,
+    127995, 8205, 9877, 128104, 127995, 8205, 9878, 65039, 128104, 127995, 8205, 9878, 128104,
+    127995, 8205, 9992, 65039, 128104, 127995, 8205, 9992, 128104, 127995, 8205, 127806, 128104,
+    127995, 8205, 127859, 128104, 127995, 8205, 127868, 128104, 127995, 8205, 127891, 128104,
+    127995, 8205, 127908, 128104, 127995, 8205, 127912, 128104, 127995, 8205, 127979, 128104,
+    127995, 8205, 127981, 128104, 127995, 8205, 128187, 128104, 127995, 8205, 128188, 128104,
+    127995, 8205, 128295, 128104, 127995, 8205, 128300, 128104, 127995, 8205, 128640, 128104,
+    127995, 8205, 128658, 128104, 127995, 8205, 129309, 8205, 128104, 127996, 128104, 127995, 8205,
+    129309, 8205, 128104, 127997, 128104, 127995, 8205, 129309, 8205, 128104, 127998, 128104,
+    127995, 8205, 129309, 8205, 128104, 127999, 128104, 127995, 8205, 129455, 128104, 127995, 8205,
+    129456, 128104, 127995, 8205, 129457, 128104, 127995, 8205, 129458, 128104, 127995, 8205,
+    129459, 128104, 127995, 8205, 129468, 128104, 127995, 8205, 129469, 128104, 127996, 128104,
+    127996, 8205, 9877, 65039, 128104, 127996, 8205, 9877, 128104, 127996, 8205, 9878, 65039,
+    128104, 127996, 8205, 9878, 128104, 127996, 8205, 9992, 65039, 128104, 127996, 8205, 9992,
+    128104, 127996, 8205, 127806, 128104, 127996, 8205, 127859, 128104, 127996, 8205, 127868,
+    128104, 127996, 8205, 127891, 128104, 127996, 8205, 127908, 128104, 127996, 8205, 127912,
+    128104, 127996, 8205, 127979, 128104, 127996, 8205, 127981, 128104, 127996, 8205, 128187,
+    128104, 127996, 8205, 128188, 128104, 127996, 8205, 128295, 128104, 127996, 8205, 128300,
+    128104, 127996, 8205, 128640, 128104, 127996, 8205, 128658, 128104, 127996, 8205, 129309, 8205,
+    128104, 127995, 128104, 127996, 8205, 129309, 8205, 128104, 127997, 128104, 127996, 8205,
+    129309, 8205, 128104, 127998, 128104, 127996, 8205, 129309, 8205, 128104, 127999, 128104,
+    127996, 8205, 129455, 128104, 127996, 8205, 129456, 128104, 127996, 8205, 129457, 128104,
+    127996, 8205, 129458, 128104, 127996, 8205, 129459, 128104, 127996, 8205, 129468, 128104,
+    127996, 8205, 129469, 128104, 127997, 128104, 127997, 8205, 9877, 65039, 128104, 127997, 8205,
+    9877, 128104, 127997, 8205, 9878, 65039, 128104, 127997, 8205, 9878, 128104, 127997, 8205,
+    9992, 65039, 128104, 127997, 8205, 9992, 128104, 127997, 8205, 127806, 128104, 127997, 8205,
+    127859, 128104, 127997, 8205, 127868, 128104, 127997, 8205, 127891, 128104, 127997, 8205,
+    127908, 128104, 127997, 8205, 127912, 128104, 127997, 8205, 127979, 128104, 127997, 8205,
+    127981, 128104, 127997, 8205, 128187, 128104, 127997, 8205, 128188, 128104, 127997, 8205,
+    128295, 128104, 127997, 8205, 128300, 128104, 127997, 8205, 128640, 128104, 127997, 8205,
+    128658, 128104, 127997, 8205, 129309, 8205, 128104, 127995, 128104, 127997, 8205, 129309, 8205,
+    128104, 127996, 128104, 127997, 8205, 129309, 8205, 128104, 127998, 128104, 127997, 8205,
+    129309, 8205, 128104, 127999, 128104, 127997, 8205, 129455, 128104, 127997, 8205, 129456,
+    128104, 127997, 8205, 129457, 128104, 127997, 8205, 129458, 128104, 127997, 8205, 129459,
+    128104, 127997, 8205, 129468, 128104, 127997, 8205, 129469, 128104, 127998, 128104, 127998,
+    8205, 9877, 65039, 128104, 127998, 8205, 9877, 128104, 127998, 8205, 9878, 65039, 128104,
+    127998, 8205, 9878, 128104, 127998, 8205, 9992, 65039, 128104, 127998, 8205, 9992, 128104,
+    127998, 8205, 127806, 128104, 127998, 8205, 127859, 128104, 127998, 8205, 127868, 128104,
+    127998, 8205, 127891, 128104, 127998, 8205, 127908, 128104, 127998, 8205, 127912, 128104,
+    127998, 8205, 127979, 128104, 127998, 8205, 127981, 128104, 127998, 8205, 128187, 128104,
+    127998, 8205, 128188, 128104, 127998, 8205, 128295, 128104, 127998, 8205, 128300, 128104,
+    127998, 8205, 128640, 128104, 127998, 8205, 128658, 128104, 127998, 8205, 129309, 8205, 128104,
+    127995, 128104, 127998, 8205, 129309, 8205, 128104, 127996, 128104, 127998, 8205, 129309, 8205,
+    128104, 127997, 128104, 127998, 8205, 129309, 8205, 128104, 127999, 128104, 127998, 8205,
+    129455, 128104, 127998, 8205, 129456, 128104, 127998, 8205, 129457, 128104, 127998, 8205,
+    129458, 128104, 127998, 8205, 129459, 128104, 127998, 8205, 129468, 128104, 127998, 8205,
+    129469, 128104, 127999, 128104, 127999, 8205, 9877, 65039, 128104, 127999, 8205, 9877, 128104,
+    127999, 8205, 9878, 65039, 128104, 127999, 8205, 9878, 128104, 127999, 8205, 9992, 65039,
+    128104, 127999, 8205, 9992, 128104, 127999, 8205, 127806, 128104, 127999, 8205, 127859, 128104,
+    127999, 8205, 127868, 128104, 127999, 8205, 127891, 128104, 127999, 8205, 127908, 128104,
+    127999, 8205, 127912, 128104, 127999, 8205, 127979, 128104, 127999, 8205, 127981, 128104,
+    127999, 8205, 128187, 128104, 127999, 8205, 128188, 128104, 127999, 8205, 128295, 128104,
+    127999, 8205, 128300, 128104, 127999, 8205, 128640, 128104, 127999, 8205, 128658, 128104,
+    127999, 8205, 129309, 8205, 128104, 127995, 128104, 127999, 8205, 129309, 8205, 128104, 127996,
+    128104, 127999, 8205, 129309, 8205, 128104, 127997, 128104, 127999, 8205, 129309, 8205, 128104,
+    127998, 128104, 127999, 8205, 129455, 128104, 127999, 8205, 129456, 128104, 127999, 8205,
+    129457, 128104, 127999, 8205, 129458, 128104, 127999, 8205, 129459, 128104, 127999, 8205,
+    129468, 128104, 127999, 8205, 129469, 128105, 128105, 8205, 9877, 65039, 128105, 8205, 9877,
+    128105, 8205, 9878, 65039, 128105, 8205, 9878, 128105, 8205, 9992, 65039, 128105, 8205, 9992,
+    128105, 8205, 10084, 65039, 8205, 128104, 128105, 8205, 10084, 8205, 128104, 128105, 8205,
+    10084, 65039, 8205, 128105, 128105, 8205, 10084, 8205, 128105, 128105, 8205, 10084, 65039,
+    8205, 128139, 8205, 128104, 128105, 8205, 10084, 8205, 128139, 8205, 128104, 128105, 8205,
+    10084, 65039, 8205, 128139, 8205, 128105, 128105, 8205, 10084, 8205, 128139, 8205, 128105,
+    128105, 8205, 127806, 128105, 8205, 127859, 128105, 8205, 127868, 128105, 8205, 127891, 128105,
+    8205, 127908, 128105, 8205, 127912, 128105, 8205, 127979, 128105, 8205, 127981, 128105, 8205,
+    128102, 128105, 8205, 128102, 8205, 128102, 128105, 8205, 128103, 128105, 8205, 128103, 8205,
+    128102, 128105, 8205, 128103, 8205, 128103, 128105, 8205, 128105, 8205, 128102, 128105, 8205,
+    128105, 8205, 128102, 8205, 128102, 128105, 8205, 128105, 8205, 128103, 128105, 8205, 128105,
+    8205, 128103, 8205, 128102, 128105, 8205, 128105, 8205, 128103, 8205, 128103, 128105, 8205,
+    128187, 128105, 8205, 128188, 128105, 8205, 128295, 128105, 8205, 128300, 128105, 8205, 128640,
+    128105, 8205, 128658, 128105, 8205, 129455, 128105, 8205, 129456, 128105, 8205, 129457, 128105,
+    8205, 129458, 128105, 8205, 129459, 128105, 8205, 129468, 128105, 8205, 129469, 128105, 127995,
+    128105, 127995, 8205, 9877, 65039, 128105, 127995, 8205, 9877, 128105, 127995, 8205, 9878,
+    65039, 128105, 127995, 8205, 9878, 128105, 127995, 8205, 9992, 65039, 128105, 127995, 8205,
+    9992, 128105, 127995, 8205, 127806, 128105, 127995, 8205, 127859, 128105, 127995, 8205, 127868,
+    128105, 127995, 8205, 127891, 128105, 127995, 8205, 127908, 128105, 127995, 8205, 127912,
+    128105, 127995, 8205, 127979, 128105, 127995, 8205, 127981, 128105, 127995, 8205, 128187,
+    128105, 127995, 8205, 128188, 128105, 127995, 8205, 128295, 128105, 127995, 8205, 128300,
+    128105, 127995, 8205, 128640, 128105, 127995, 8205, 128658, 128105, 127995, 8205, 129309, 8205,
+    128104, 127996, 128105, 127995, 8205, 129309, 8205, 128104, 127997, 128105, 127995, 8205,
+    129309, 8205, 128104, 127998, 128105, 127995, 8205, 129309, 8205, 128104, 127999, 128105,
+    127995, 8205, 129309, 8205, 128105, 127996, 128105, 127995, 8205, 129309, 8205, 128105, 127997,
+    128105, 127995, 8205, 129309, 8205, 128105, 127998, 128105, 127995, 8205, 129309, 8205, 128105,
+    127999, 128105, 127995, 8205, 129455, 128105, 127995, 8205, 129456, 128105, 127995, 8205,
+    129457, 128105, 127995, 8205, 129458, 128105, 127995, 8205, 129459, 128105, 127995, 8205,
+    129468, 128105, 127995, 8205, 129469, 128105, 127996, 128105, 127996, 8205, 9877, 65039,
+    128105, 127996, 8205, 9877, 128105, 127996, 8205, 9878, 65039, 128105, 127996, 8205, 9878,
+    128105, 127996, 8205, 9992, 65039, 128105, 127996, 8205, 9992, 128105, 127996, 8205, 127806,
+    128105, 127996, 8205, 127859, 128105, 127996, 8205, 127868, 128105, 127996, 8205, 127891,
+    128105, 127996, 8205, 127908, 128105, 127996, 8205, 127912, 128105, 127996, 8205, 127979,
+    128105, 127996, 8205, 127981, 128105, 127996, 8205, 128187, 128105, 127996, 8205, 128188,
+    128105, 127996, 8205, 128295, 128105, 127996, 8205, 128300, 128105, 127996, 8205, 128640,
+    128105, 127996, 8205, 128658, 128105, 127996, 8205, 129309, 8205, 128104, 127995, 128105,
+    127996, 8205, 129309, 8205, 128104, 127997, 128105, 127996, 8205, 129309, 8205, 128104, 127998,
+    128105, 127996, 8205, 129309, 8205, 128104, 127999, 128105, 127996, 8205, 129309, 8205, 128105,
+    127995, 128105, 127996, 8205, 129309, 8205, 128105, 127997, 128105, 127996, 8205, 129309, 8205,
+    128105, 127998, 128105, 127996, 8205, 129309, 8205, 128105, 127999, 128105, 127996, 8205,
+    129455, 128105, 127996, 8205, 129456, 128105, 127996, 8205, 129457, 128105, 127996, 8205,
+    129458, 128105, 127996, 8205, 129459, 128105, 127996, 8205, 129468, 128105, 127996, 8205,
+    129469, 128105, 127997, 128105, 127997, 8205, 9877, 65039, 128105, 127997, 8205, 9877, 128105,
+    127997, 8205, 9878, 65039, 128105, 127997, 8205, 9878, 128105, 127997, 8205, 9992, 65039,
+    128105, 127997, 8205, 9992, 128105, 127997, 8205, 127806, 128105, 127997, 8205, 127859, 128105,
+    127997, 8205, 127868, 128105, 127997, 8205, 127891, 128105, 127997, 8205, 127908, 128105,
+    127997, 8205, 127912, 128105, 127997, 8205, 127979, 128105, 127997, 8205, 127981, 128105,
+    127997, 8205, 128187, 128105, 127997, 8205, 128188, 128105, 127997, 8205, 128295, 128105,
+    127997, 8205, 128300, 128105, 127997, 8205, 128640, 128105, 127997, 8205, 128658, 128105,
+    127997, 8205, 129309, 8205, 128104, 127995, 128105, 127997, 8205, 129309, 8205, 128104, 127996,
+    128105, 127997, 8205, 129309, 8205, 128104, 127998, 128105, 127997, 8205, 129309, 8205, 128104,
+    127999, 128105, 127997, 8205, 129309, 8205, 128105, 127995, 128105, 127997, 8205, 129309, 8205,
+    128105, 127996, 128105, 127997, 8205, 129309, 8205, 128105, 127998, 128105, 127997, 8205,
+    129309, 8205, 128105, 127999, 128105, 127997, 8205, 129455, 128105, 127997, 8205, 129456,
+    128105, 127997, 8205, 129457, 128105, 127997, 8205, 129458, 128105, 127997, 8205, 129459,
+    128105, 127997, 8205, 129468, 128105, 127997, 8205, 129469, 128105, 127998, 128105, 127998,
+    8205, 9877, 65039, 128105, 127998, 8205, 9877, 128105, 127998, 8205, 9878, 65039, 128105,
+    127998, 8205, 9878, 128105, 127998, 8205, 9992, 65039, 128105, 127998, 8205, 9992, 128105,
+    127998, 8205, 127806, 128105, 127998, 8205, 127859, 128105, 127998, 8205, 127868, 128105,
+    127998, 8205, 127891, 128105, 127998, 8205, 127908, 128105, 127998, 8205, 127912, 128105,
+    127998, 8205, 127979, 128105, 127998, 8205, 127981, 128105, 127998, 8205, 128187, 128105,
+    127998, 8205, 128188, 128105, 127998, 8205, 128295, 128105, 127998, 8205, 128300, 128105,
+    127998, 8205, 128640, 128105, 127998, 8205, 128658, 128105, 127998, 8205, 129309, 8205, 128104,
+    127995, 128105, 127998, 8205, 129309, 8205, 128104, 127996, 128105, 127998, 8205, 129309, 8205,
+    128104, 127997, 128105, 127998, 8205, 129309, 8205, 128104, 127999, 128105, 127998, 8205,
+    129309, 8205, 128105, 127995, 128105, 127998, 8205, 129309, 8205, 128105, 127996, 128105,
+    127998, 8205, 129309, 8205, 128105, 127997, 128105, 127998, 8205, 129309, 8205, 128105, 127999,
+    128105, 127998, 8205, 129455, 128105, 127998, 8205, 129456, 128105, 127998, 8205, 129457,
+    128105, 127998, 8205, 129458, 128105, 127998, 8205, 129459, 128105, 127998, 8205, 129468,
+    128105, 127998, 8205, 129469, 128105, 127999, 128105, 127999, 8205, 9877, 65039, 128105,
+    127999, 8205, 9877, 128105, 127999, 8205, 9878, 65039, 128105, 127999, 8205, 9878, 128105,
+    127999, 8205, 9992, 65039, 128105, 127999, 8205, 9992, 128105, 127999, 8205, 127806, 128105,
+    127999, 8205, 127859, 128105, 127999, 8205, 127868, 128105, 127999, 8205, 127891, 128105,
+    127999, 8205, 127908, 128105, 127999, 8205, 127912, 128105, 127999, 8205, 127979, 128105,
+    127999, 8205, 127981, 128105, 127999, 8205, 128187, 128105, 127999, 8205, 128188, 128105,
+    127999, 8205, 128295, 128105, 127999, 8205, 128300, 128105, 127999, 8205, 128640, 128105,
+    127999, 8205, 128658, 128105, 127999, 8205, 129309, 8205, 128104, 127995, 128105, 127999, 8205,
+    129309, 8205, 128104, 127996, 128105, 127999, 8205, 129309, 8205, 128104, 127997, 128105,
+    127999, 8205, 129309, 8205, 128104, 127998, 128105, 127999, 8205, 129309, 8205, 128105, 127995,
+    128105, 127999, 8205, 129309, 8205, 128105, 127996, 128105, 127999, 8205, 129309, 8205, 128105,
+    127997, 128105, 127999, 8205, 129309, 8205, 128105, 127998, 128105, 127999, 8205, 129455,
+    128105, 127999, 8205, 129456, 128105, 127999, 8205, 129457, 128105, 127999, 8205, 129458,
+    128105, 127999, 8205, 129459, 128105, 127999, 8205, 129468, 128105, 127999, 8205, 129469,
+    128106, 128107, 128107, 127995, 128107, 127996, 128107, 127997, 128107, 127998, 128107, 127999,
+    128108, 128108, 127995, 128108, 127996, 128108, 127997, 128108, 127998, 128108, 127999, 128109,
+    128109, 127995, 128109, 127996, 128109, 127997, 128109, 127998, 128109, 127999, 128110, 128110,
+    8205, 9792, 65039, 128110, 8205, 9792, 128110, 8205, 9794, 65039, 128110, 8205, 9794, 128110,
+    127995, 128110, 127995, 8205, 9792, 65039, 128110, 127995, 8205, 9792, 128110, 127995, 8205,
+    9794, 65039, 128110, 127995, 8205, 9794, 128110, 127996, 128110, 127996, 8205, 9792, 65039,
+    128110, 127996, 8205, 9792, 128110, 127996, 8205, 9794, 65039, 128110, 127996, 8205, 9794,
+    128110, 127997, 128110, 127997, 8205, 9792, 65039, 128110, 127997, 8205, 9792, 128110, 127997,
+    8205, 9794, 65039, 128110, 127997, 8205, 9794, 128110, 127998, 128110, 127998, 8205, 9792,
+    65039, 128110, 127998, 8205, 9792, 128110, 127998, 8205, 9794, 65039, 128110, 127998, 8205,
+    9794, 128110, 127999, 128110, 127999, 8205, 9792, 65039, 128110, 127999, 8205, 9792, 128110,
+    127999, 8205, 9794, 65039, 128110, 127999, 8205, 9794, 128111, 128111, 8205, 9792, 65039,
+    128111, 8205, 9792, 128111, 8205, 9794, 65039, 128111, 8205, 9794, 128112, 128112, 8205, 9792,
+    65039, 128112, 8205, 9792, 128112, 8205, 9794, 65039, 128112, 8205, 9794, 128112, 127995,
+    128112, 127995, 8205, 9792, 65039, 128112, 127995, 8205, 9792, 128112, 127995, 8205, 9794,
+    65039, 128112, 127995, 8205, 9794, 128112, 127996, 128112, 127996, 8205, 9792, 65039, 128112,
+    127996, 8205, 9792, 128112, 127996, 8205, 9794, 65039, 128112, 127996, 8205, 9794, 128112,
+    127997, 128112, 127997, 8205, 9792, 65039, 128112, 127997, 8205, 9792, 128112, 127997, 8205,
+    9794, 65039, 128112, 127997, 8205, 9794, 128112, 127998, 128112, 127998, 8205, 9792, 65039,
+    128112, 127998, 8205, 9792, 128112, 127998, 8205, 9794, 65039, 128112, 127998, 8205, 9794,
+    128112, 127999, 128112, 127999, 8205, 9792, 65039, 128112, 127999, 8205, 9792, 128112, 127999,
+    8205, 9794, 65039, 128112, 127999, 8205, 9794, 128113, 128113, 8205, 9792, 65039, 128113, 8205,
+    9792, 128113, 8205, 9794, 65039, 128113, 8205, 9794, 128113, 127995, 128113, 127995, 8205,
+    9792, 65039, 128113, 127995, 8205, 9792, 128113, 127995, 8205, 9794, 65039, 128113, 127995,
+    8205, 9794, 128113, 127996, 128113, 127996, 8205, 9792, 65039, 128113, 127996, 8205, 9792,
+    128113, 127996, 8205, 9794, 65039, 128113, 127996, 8205, 9794, 128113, 127997, 128113, 127997,
+    8205, 9792, 65039, 128113, 127997, 8205, 9792, 128113, 127997, 8205, 9794, 65039, 128113,
+    127997, 8205, 9794, 128113, 127998, 128113, 127998, 8205, 9792, 65039, 128113, 127998, 8205,
+    9792, 128113, 127998, 8205, 9794, 65039, 128113, 127998, 8205, 9794, 128113, 127999, 128113,
+    127999, 8205, 9792, 65039, 128113, 127999, 8205, 9792, 128113, 127999, 8205, 9794, 65039,
+    128113, 127999, 8205, 9794, 128114, 128114, 127995, 128114, 127996, 128114, 127997, 128114,
+    127998, 128114, 127999, 128115, 128115, 8205, 9792, 65039, 128115, 8205, 9792, 128115, 8205,
+    9794, 65039, 128115, 8205, 9794, 128115, 127995, 128115, 127995, 8205, 9792, 65039, 128115,
+    127995, 8205, 9792, 128115, 127995, 8205, 9794, 65039, 128115, 127995, 8205, 9794, 128115,
+    127996, 128115, 127996, 8205, 9792, 65039, 128115, 127996, 8205, 9792, 128115, 127996, 8205,
+    9794, 65039, 128115, 127996, 8205, 9794, 128115, 127997, 128115, 127997, 8205, 9792, 65039,
+    128115, 127997, 8205, 9792, 128115, 127997, 8205, 9794, 65039, 128115, 127997, 8205, 9794,
+    128115, 127998, 128115, 127998, 8205, 9792, 65039, 128115, 127998, 8205, 9792, 128115, 127998,
+    8205, 9794, 65039, 128115, 127998, 8205, 9794, 128115, 127999, 128115, 127999, 8205, 9792,
+    65039, 128115, 127999, 8205, 9792, 128115, 127999, 8205, 9794, 65039, 128115, 127999, 8205,
+    9794, 128116, 128116, 127995, 128116, 127996, 128116, 127997, 128116, 127998, 128116, 127999,
+    128117, 128117, 127995, 128117, 127996, 128117, 127997, 128117, 127998, 128117, 127999, 128118,
+    128118, 127995, 128118, 127996, 128118, 127997, 128118, 127998, 128118, 127999, 128119, 128119,
+    8205, 9792, 65039, 128119, 8205, 9792, 128119, 8205, 9794, 65039, 128119, 8205, 9794, 128119,
+    127995, 128119, 127995, 8205, 9792, 65039, 128119, 127995, 8205, 9792, 128119, 127995, 8205,
+    9794, 65039, 128119, 127995, 8205, 9794, 128119, 127996, 128119, 127996, 8205, 9792, 65039,
+    128119, 127996, 8205, 9792, 128119, 127996, 8205, 9794, 65039, 128119, 127996, 8205, 9794,
+    128119, 127997, 128119, 127997, 8205, 9792, 65039, 128119, 127997, 8205, 9792, 128119, 127997,
+    8205, 9794, 65039, 128119, 127997, 8205, 9794, 128119, 127998, 128119, 127998, 8205, 9792,
+    65039, 128119, 127998, 8205, 9792, 128119, 127998, 8205, 9794, 65039, 128119, 127998, 8205,
+    9794, 128119, 127999, 128119, 127999, 8205, 9792, 65039, 128119, 127999, 8205, 9792, 128119,
+    127999, 8205, 9794, 65039, 128119, 127999, 8205, 9794, 128120, 128120, 127995, 128120, 127996,
+    128120, 127997, 128120, 127998, 128120, 127999, 128121, 128122, 128123, 128124, 128124, 127995,
+    128124, 127996, 128124, 127997, 128124, 127998, 128124, 127999, 128125, 128126, 128127, 128128,
+    128129, 128129, 8205, 9792, 65039, 128129, 8205, 9792, 128129, 8205, 9794, 65039, 128129, 8205,
+    9794, 128129, 127995, 128129, 127995, 8205, 9792, 65039, 128129, 127995, 8205, 9792, 128129,
+    127995, 8205, 9794, 65039, 128129, 127995, 8205, 9794, 128129, 127996, 128129, 127996, 8205,
+    9792, 65039, 128129, 127996, 8205, 9792, 128129, 127996, 8205, 9794, 65039, 128129, 127996,
+    8205, 9794, 128129, 127997, 128129, 127997, 8205, 9792, 65039, 128129, 127997, 8205, 9792,
+    128129, 127997, 8205, 9794, 65039, 128129, 127997, 8205, 9794, 128129, 127998, 128129, 127998,
+    8205, 9792, 65039, 128129, 127998, 8205, 9792, 128129, 127998, 8205, 9794, 65039, 128129,
+    127998, 8205, 9794, 128129, 127999, 128129, 127999, 8205, 9792, 65039, 128129, 127999, 8205,
+    9792, 128129, 127999, 8205, 9794, 65039, 128129, 127999, 8205, 9794, 128130, 128130, 8205,
+    9792, 65039, 128130, 8205, 9792, 128130, 8205, 9794, 65039, 128130, 8205, 9794, 128130, 127995,
+    128130, 127995, 8205, 9792, 65039, 128130, 127995, 8205, 9792, 128130, 127995, 8205, 9794,
+    65039, 128130, 127995, 8205, 9794, 128130, 127996, 128130, 127996, 8205, 9792, 65039, 128130,
+    127996, 8205, 9792, 128130, 127996, 8205, 9794, 65039, 128130, 127996, 8205, 9794, 128130,
+    127997, 128130, 127997, 8205, 9792, 65039, 128130, 127997, 8205, 9792, 128130, 127997, 8205,
+    9794, 65039, 128130, 127997, 8205, 9794, 128130, 127998, 128130, 127998, 8205, 9792, 65039,
+    128130, 127998, 8205, 9792, 128130, 127998, 8205, 9794, 65039, 128130, 127998, 8205, 9794,
+    128130, 127999, 128130, 127999, 8205, 9792, 65039, 128130, 127999, 8205, 9792, 128130, 127999,
+    8205, 9794, 65039, 128130, 127999, 8205, 9794, 128131, 128131, 127995, 128131, 127996, 128131,
+    127997, 128131, 127998, 128131, 127999, 128132, 128133, 128133, 127995, 128133, 127996, 128133,
+    127997, 128133, 127998, 128133, 127999, 128134, 128134, 8205, 9792, 65039, 128134, 8205, 9792,
+    128134, 8205, 9794, 65039, 128134, 8205, 9794, 128134, 127995, 128134, 127995, 8205, 9792,
+    65039, 128134, 127995, 8205, 9792, 128134, 127995, 8205, 9794, 65039, 128134, 127995, 8205,
+    9794, 128134, 127996, 128134, 127996, 8205, 9792, 65039, 128134, 127996, 8205, 9792, 128134,
+    127996, 8205, 9794, 65039, 128134, 127996, 8205, 9794, 128134, 127997, 128134, 127997, 8205,
+    9792, 65039, 128134, 127997, 8205, 9792, 128134, 127997, 8205, 9794, 65039, 128134, 127997,
+    8205, 9794, 128134, 127998, 128134, 127998, 8205, 9792, 65039, 128134, 127998, 8205, 9792,
+    128134, 127998, 8205, 9794, 65039, 128134, 127998, 8205, 9794, 128134, 127999, 128134, 127999,
+    8205, 9792, 65039, 128134, 127999, 8205, 9792, 128134, 127999, 8205, 9794, 65039, 128134,
+    127999, 8205, 9794, 128135, 128135, 8205, 9792, 65039, 128135, 8205, 9792, 128135, 8205, 9794,
+    65039, 128135, 8205, 9794, 128135, 127995, 128135, 127995, 8205, 9792, 65039, 128135, 127995,
+    8205, 9792, 128135, 127995, 8205, 9794, 65039, 128135, 127995, 8205, 9794, 128135, 127996,
+    128135, 127996, 8205, 9792, 65039, 128135, 127996, 8205, 9792, 128135, 127996, 8205, 9794,
+    65039, 128135, 127996, 8205, 9794, 128135, 127997, 128135, 127997, 8205, 9792, 65039, 128135,
+    127997, 8205, 9792, 128135, 127997, 8205, 9794, 65039, 128135, 127997, 8205, 9794, 128135,
+    127998, 128135, 127998, 8205, 9792, 65039, 128135, 127998, 8205, 9792, 128135, 127998, 8205,
+    9794, 65039, 128135, 127998, 8205, 9794, 128135, 127999, 128135, 127999, 8205, 9792, 65039,
+    128135, 127999, 8205, 9792, 128135, 127999, 8205, 9794, 65039, 128135, 127999, 8205, 9794,
+    128136, 128137, 128138, 128139, 128140, 128141, 128142, 128143, 128144, 128145, 128146, 128147,
+    128148, 128149, 128150, 128151, 128152, 128153, 128154, 128155, 128156, 128157, 128158, 128159,
+    128160, 128161, 128162, 128163, 128164, 128165, 128166, 128167, 128168, 128169, 128170, 128170,
+    127995, 128170, 127996, 128170, 127997, 128170, 127998, 128170, 127999, 128171, 128172, 128173,
+    128174, 128175, 128176, 128177, 128178, 128179, 128180, 128181, 128182, 128183, 128184, 128185,
+    128186, 128187, 128188, 128189, 128190, 128191, 128192, 128193, 128194, 128195, 128196, 128197,
+    128198, 128199, 128200, 128201, 128202, 128203, 128204, 128205, 128206, 128207, 128208, 128209,
+    128210, 128211, 128212, 128213, 128214, 128215, 128216, 128217, 128218, 128219, 128220, 128221,
+    128222, 128223, 128224, 128225, 128226, 128227, 128228, 128229, 128230, 128231, 128232, 128233,
+    128234, 128235, 128236, 128237, 128238, 128239, 128240, 128241, 128242, 128243, 128244, 128245,
+    128246, 128247, 128248, 128249, 128250, 128251, 128252, 128253, 65039, 128253, 128255, 128256,
+    128257, 128258, 128259, 128260, 128261, 128262, 128263, 128264, 128265, 128266, 128267, 128268,
+    128269, 128270, 128271, 128272, 128273, 128274, 128275, 128276, 128277, 128278, 128279, 128280,
+    128281, 128282, 128283, 128284, 128285, 128286, 128287, 128288, 128289, 128290, 128291, 128292,
+    128293, 128294, 128295, 128296, 128297, 128298, 128299, 128300, 128301, 128302, 128303, 128304,
+    128305, 128306, 128307, 128308, 128309, 128310, 128311, 128312, 128313, 128314, 128315, 128316,
+    128317, 128329, 65039, 128329, 128330, 65039, 128330, 128331, 128332, 128333, 128334, 128336,
+    128337, 128338, 128339, 128340, 128341, 128342, 128343, 128344, 128345, 128346, 128347, 128348,
+    128349, 128350, 128351, 128352, 128353, 128354, 128355, 128356, 128357, 128358, 128359, 128367,
+    65039, 128367, 128368, 65039, 128368, 128371, 65039, 128371, 128372, 65039, 128372, 128372,
+    127995, 128372, 127996, 128372, 127997, 128372, 127998, 128372, 127999, 128373, 65039, 128373,
+    128373, 65039, 8205, 9792, 65039, 128373, 65039, 8205, 9792, 128373, 8205, 9792, 65039, 128373,
+    8205, 9792, 128373, 65039, 8205, 9794, 65039, 128373, 65039, 8205, 9794, 128373, 8205, 9794,
+    65039, 128373, 8205, 9794, 128373, 127995, 128373, 127995, 8205, 9792, 65039, 128373, 127995,
+    8205, 9792, 128373, 127995, 8205, 9794, 65039, 128373, 127995, 8205, 9794, 128373, 127996,
+    128373, 127996, 8205, 9792, 65039, 128373, 127996, 8205, 9792, 128373, 127996, 8205, 9794,
+    65039, 128373, 127996, 8205, 9794, 128373, 127997, 128373, 127997, 8205, 9792, 65039, 128373,
+    127997, 8205, 9792, 128373, 127997, 8205, 9794, 65039, 128373, 127997, 8205, 9794, 128373,
+    127998, 128373, 127998, 8205, 9792, 65039, 128373, 127998, 8205, 9792, 128373, 127998, 8205,
+    9794, 65039, 128373, 127998, 8205, 9794, 128373, 127999, 128373, 127999, 8205, 9792, 65039,
+    128373, 127999, 8205, 9792, 128373, 127999, 8205, 9794, 65039, 128373, 127999, 8205, 9794,
+    128374, 65039, 128374, 128375, 65039, 128375, 128376, 65039, 128376, 128377, 65039, 128377,
+    128378, 128378, 127995, 128378, 127996, 128378, 127997, 128378, 127998, 128378, 127999, 128391,
+    65039, 128391, 128394, 65039, 128394, 128395, 65039, 128395, 128396, 65039, 128396, 128397,
+    65039, 128397, 128400, 65039, 128400, 128400, 127995, 128400, 127996, 128400, 127997, 128400,
+    127998, 128400, 127999, 128405, 128405, 127995, 128405, 127996, 128405, 127997, 128405, 127998,
+    128405, 127999, 128406, 128406, 127995, 128406, 127996, 128406, 127997, 128406, 127998, 128406,
+    127999, 128420, 128421, 65039, 128421, 128424, 65039, 128424, 128433, 65039, 128433, 128434,
+    65039, 128434, 128444, 65039, 128444, 128450, 65039, 128450, 128451, 65039, 128451, 128452,
+    65039, 128452, 128465, 65039, 128465, 128466, 65039, 128466, 128467, 65039, 128467, 128476,
+    65039, 128476, 128477, 65039, 128477, 128478, 65039, 128478, 128481, 65039, 128481, 128483,
+    65039, 128483, 128488, 65039, 128488, 128495, 65039, 128495, 128499, 65039, 128499, 128506,
+    65039, 128506, 128507, 128508, 128509, 128510, 128511, 128512, 128513, 128514, 128515, 128516,
+    128517, 128518, 128519, 128520, 128521, 128522, 128523, 128524, 128525, 128526, 128527, 128528,
+    128529, 128530, 128531, 128532, 128533, 128534, 128535, 128536, 128537, 128538, 128539, 128540,
+    128541, 128542, 128543, 128544, 128545, 128546, 128547, 128548, 128549, 128550, 128551, 128552,
+    128553, 128554, 128555, 128556, 128557, 128558, 128559, 128560, 128561, 128562, 128563, 128564,
+    128565, 128566, 128567, 128568, 128569, 128570, 128571, 128572, 128573, 128574, 128575, 128576,
+    128577, 128578, 128579, 128580, 128581, 128581, 8205, 9792, 65039, 128581, 8205, 9792, 128581,
+    8205, 9794, 65039, 128581, 8205, 9794, 128581, 127995, 128581, 127995, 8205, 9792, 65039,
+    128581, 127995, 8205, 9792, 128581, 127995, 8205, 9794, 65039, 128581, 127995, 8205, 9794,
+    128581, 127996, 128581, 127996, 8205, 9792, 65039, 128581, 127996, 8205, 9792, 128581, 127996,
+    8205, 9794, 65039, 128581, 127996, 8205, 9794, 128581, 127997, 128581, 127997, 8205, 9792,
+    65039, 128581, 127997, 8205, 9792, 128581, 127997, 8205, 9794, 65039, 128581, 127997, 8205,
+    9794, 128581, 127998, 128581, 127998, 8205, 9792, 65039, 128581, 127998, 8205, 9792, 128581,
+    127998, 8205, 9794, 65039, 128581, 127998, 8205, 9794, 128581, 127999, 128581, 127999, 8205,
+    9792, 65039, 128581, 127999, 8205, 9792, 128581, 127999, 8205, 9794, 65039, 128581, 127999,
+    8205, 9794, 128582, 128582, 8205, 9792, 65039, 128582, 8205, 9792, 128582, 8205, 9794, 65039,
+    128582, 8205, 9794, 128582, 127995, 128582, 127995, 8205, 9792, 65039, 128582, 127995, 8205,
+    9792, 128582, 127995, 8205, 9794, 65039, 128582, 127995, 8205, 9794, 128582, 127996, 128582,
+    127996, 8205, 9792, 65039, 128582, 127996, 8205, 9792, 128582, 127996, 8205, 9794, 65039,
+    128582, 127996, 8205, 9794, 128582, 127997, 128582, 127997, 8205, 9792, 65039, 128582, 127997,
+    8205, 9792, 128582, 127997, 8205, 9794, 65039, 128582, 127997, 8205, 9794, 128582, 127998,
+    128582, 127998, 8205, 9792, 65039, 128582, 127998, 8205, 9792, 128582, 127998, 8205, 9794,
+    65039, 128582, 127998, 8205, 9794, 128582, 127999, 128582, 127999, 8205, 9792, 65039, 128582,
+    127999, 8205, 9792, 128582, 127999, 8205, 9794, 65039, 128582, 127999, 8205, 9794, 128583,
+    128583, 8205, 9792, 65039, 128583, 8205, 9792, 128583, 8205, 9794, 65039, 128583, 8205, 9794,
+    128583, 127995, 128583, 127995, 8205, 9792, 65039, 128583, 127995, 8205, 9792, 128583, 127995,
+    8205, 9794, 65039, 128583, 127995, 8205, 9794, 128583, 127996, 128583, 127996, 8205, 9792,
+    65039, 128583, 127996, 8205, 9792, 128583, 127996, 8205, 9794, 65039, 128583, 127996, 8205,
+    9794, 128583, 127997, 128583, 127997, 8205, 9792, 65039, 128583, 127997, 8205, 9792, 128583,
+    127997, 8205, 9794, 65039, 128583, 127997, 8205, 9794, 128583, 127998, 128583, 127998, 8205,
+    9792, 65039, 128583, 127998, 8205, 9792, 128583, 127998, 8205, 9794, 65039, 128583, 127998,
+    8205, 9794, 128583, 127999, 128583, 127999, 8205, 9792, 65039, 128583, 127999, 8205, 9792,
+    128583, 127999, 8205, 9794, 65039, 128583, 127999, 8205, 9794, 128584, 128585, 128586, 128587,
+    128587, 8205, 9792, 65039, 128587, 8205, 9792, 128587, 8205, 9794, 65039, 128587, 8205, 9794,
+    128587, 127995, 128587, 127995, 8205, 9792, 65039, 128587, 127995, 8205, 9792, 128587, 127995,
+    8205, 9794, 65039, 128587, 127995, 8205, 9794, 128587, 127996, 128587, 127996, 8205, 9792,
+    65039, 128587, 127996, 8205, 9792, 128587, 127996, 8205, 9794, 65039, 128587, 127996, 8205,
+    9794, 128587, 127997, 128587, 127997, 8205, 9792, 65039, 128587, 127997, 8205, 9792, 128587,
+    127997, 8205, 9794, 65039, 128587, 127997, 8205, 9794, 128587, 127998, 128587, 127998, 8205,
+    9792, 65039, 128587, 127998, 8205, 9792, 128587, 127998, 8205, 9794, 65039, 128587, 127998,
+    8205, 9794, 128587, 127999, 128587, 127999, 8205, 9792, 65039, 128587, 127999, 8205, 9792,
+    128587, 127999, 8205, 9794, 65039, 128587, 127999, 8205, 9794, 128588, 128588, 127995, 128588,
+    127996, 128588, 127997, 128588, 127998, 128588, 127999, 128589, 128589, 8205, 9792, 65039,
+    128589, 8205, 9792, 128589, 8205, 9794, 65039, 128589, 8205, 9794, 128589, 127995, 128589,
+    127995, 8205, 9792, 65039, 128589, 127995, 8205, 9792, 128589, 127995, 8205, 9794, 65039,
+    128589, 127995, 8205, 9794, 128589, 127996, 128589, 127996, 8205, 9792, 65039, 128589, 127996,
+    8205, 9792, 128589, 127996, 8205, 9794, 65039, 128589, 127996, 8205, 9794, 128589, 127997,
+    128589, 127997, 8205, 9792, 65039, 128589, 127997, 8205, 9792, 128589, 127997, 8205, 9794,
+    65039, 128589, 127997, 8205, 9794, 128589, 127998, 128589, 127998, 8205, 9792, 65039, 128589,
+    127998, 8205, 9792, 128589, 127998, 8205, 9794, 65039, 128589, 127998, 8205, 9794, 128589,
+    127999, 128589, 127999, 8205, 9792, 65039, 128589, 127999, 8205, 9792, 128589, 127999, 8205,
+    9794, 65039, 128589, 127999, 8205, 9794, 128590, 128590, 8205, 9792, 65039, 128590, 8205, 9792,
+    128590, 8205, 9794, 65039, 128590, 8205, 9794, 128590, 127995, 128590, 127995, 8205, 9792,
+    65039, 128590, 127995, 8205, 9792, 128590, 127995, 8205, 9794, 65039, 128590, 127995, 8205,
+    9794, 128590, 127996, 128590, 127996, 8205, 9792, 65039, 128590, 127996, 8205, 9792, 128590,
+    127996, 8205, 9794, 65039, 128590, 127996, 8205, 9794, 128590, 127997, 128590, 127997, 8205,
+    9792, 65039, 128590, 127997, 8205, 9792, 128590, 127997, 8205, 9794, 65039, 128590, 127997,
+    8205, 9794, 128590, 127998, 128590, 127998, 8205, 9792, 65039, 128590, 127998, 8205, 9792,
+    128590, 127998, 8205, 9794, 65039, 128590, 127998, 8205, 9794, 128590, 127999, 128590, 127999,
+    8205, 9792, 65039, 128590, 127999, 8205, 9792, 128590, 127999, 8205, 9794, 65039, 128590,
+    127999, 8205, 9794, 128591, 128591, 127995, 128591, 127996, 128591, 127997, 128591, 127998,
+    128591, 127999, 128640, 128641, 128642, 128643, 128644, 128645, 128646, 128647, 128648, 128649,
+    128650, 128651, 128652, 128653, 128654, 128655, 128656, 128657, 128658, 128659, 128660, 128661,
+    128662, 128663, 128664, 128665, 128666, 128667, 128668, 128669, 128670, 128671, 128672, 128673,
+    128674, 128675, 128675, 8205, 9792, 65039, 128675, 8205, 9792, 128675, 8205, 9794, 65039,
+    128675, 8205, 9794, 128675, 127995, 128675, 127995, 8205, 9792, 65039, 128675, 127995, 8205,
+    9792, 128675, 127995, 8205, 9794, 65039, 128675, 127995, 8205, 9794, 128675, 127996, 128675,
+    127996, 8205, 9792, 65039, 128675, 127996, 8205, 9792, 128675, 127996, 8205, 9794, 65039,
+    128675, 127996, 8205, 9794, 128675, 127997, 128675, 127997, 8205, 9792, 65039, 128675, 127997,
+    8205, 9792, 128675, 127997, 8205, 9794, 65039, 128675, 127997, 8205, 9794, 128675, 127998,
+    128675, 127998, 8205, 9792, 65039, 128675, 127998, 8205, 9792, 128675, 127998, 8205, 9794,
+    65039, 128675, 127998, 8205, 9794, 128675, 127999, 128675, 127999, 8205, 9792, 65039, 128675,
+    127999, 8205, 9792, 128675, 127999, 8205, 9794, 65039, 128675, 127999, 8205, 9794, 128676,
+    128677, 128678, 128679, 128680, 128681, 128682, 128683, 128684, 128685, 128686, 128687, 128688,
+    128689, 128690, 128691, 128692, 128692, 8205, 9792, 65039, 128692, 8205, 9792, 128692, 8205,
+    9794, 65039, 128692, 8205, 9794, 128692, 127995, 128692, 127995, 8205, 9792, 65039, 128692,
+    127995, 8205, 9792, 128692, 127995, 8205, 9794, 65039, 128692, 127995, 8205, 9794, 128692,
+    127996, 128692, 127996, 8205, 9792, 65039, 128692, 127996, 8205, 9792, 128692, 127996, 8205,
+    9794, 65039, 128692, 127996, 8205, 9794, 128692, 127997, 128692, 127997, 8205, 9792, 65039,
+    128692, 127997, 8205, 9792, 128692, 127997, 8205, 9794, 65039, 128692, 127997, 8205, 9794,
+    128692, 127998, 128692, 127998, 8205, 9792, 65039, 128692, 127998, 8205, 9792, 128692, 127998,
+    8205, 9794, 65039, 128692, 127998, 8205, 9794, 128692, 127999, 128692, 127999, 8205, 9792,
+    65039, 128692, 127999, 8205, 9792, 128692, 127999, 8205, 9794, 65039, 128692, 127999, 8205,
+    9794, 128693, 128693, 8205, 9792, 65039, 128693, 8205, 9792, 128693, 8205, 9794, 65039, 128693,
+    8205, 9794, 128693, 127995, 128693, 127995, 8205, 9792, 65039, 128693, 127995, 8205, 9792,
+    128693, 127995, 8205, 9794, 65039, 128693, 127995, 8205, 9794, 128693, 127996, 128693, 127996,
+    8205, 9792, 65039, 128693, 127996, 8205, 9792, 128693, 127996, 8205, 9794, 65039, 128693,
+    127996, 8205, 9794, 128693, 127997, 128693, 127997, 8205, 9792, 65039, 128693, 127997, 8205,
+    9792, 128693, 127997, 8205, 9794, 65039, 128693, 127997, 8205, 9794, 128693, 127998, 128693,
+    127998, 8205, 9792, 65039, 128693, 127998, 8205, 9792, 128693, 127998, 8205, 9794, 65039,
+    128693, 127998, 8205, 9794, 128693, 127999, 128693, 127999, 8205, 9792, 65039, 128693, 127999,
+    8205, 9792, 128693, 127999, 8205, 9794, 65039, 128693, 127999, 8205, 9794, 128694, 128694,
+    8205, 9792, 65039, 128694, 8205, 9792, 128694, 8205, 9794, 65039, 128694, 8205, 9794, 128694,
+    127995, 128694, 127995, 8205, 9792, 65039, 128694, 127995, 8205, 9792, 128694, 127995, 8205,
+    9794, 65039, 128694, 127995, 8205, 9794, 128694, 127996, 128694, 127996, 8205, 9792, 65039,
+    128694, 127996, 8205, 9792, 128694, 127996, 8205, 9794, 65039, 128694, 127996, 8205, 9794,
+    128694, 127997, 128694, 127997, 8205, 9792, 65039, 128694, 127997, 8205, 9792, 128694, 127997,
+    8205, 9794, 65039, 128694, 127997, 8205, 9794, 128694, 127998, 128694, 127998, 8205, 9792,
+    65039, 128694, 127998, 8205, 9792, 128694, 127998, 8205, 9794, 65039, 128694, 127998, 8205,
+    9794, 128694, 127999, 128694, 127999, 8205, 9792, 65039, 128694, 127999, 8205, 9792, 128694,
+    127999, 8205, 9794, 65039, 128694, 127999, 8205, 9794, 128695, 128696, 128697, 128698, 128699,
+    128700, 128701, 128702, 128703, 128704, 128704, 127995, 128704, 127996, 128704, 127997, 128704,
+    127998, 128704, 127999, 128705, 128706, 128707, 128708, 128709, 128715, 65039, 128715, 128716,
+    128716, 127995, 128716, 127996, 128716, 127997, 128716, 127998, 128716, 127999, 128717, 65039,
+    128717, 128718, 65039, 128718, 128719, 65039, 128719, 128720, 128721, 128722, 128725, 128726,
+    128727, 128736, 65039, 128736, 128737, 65039, 128737, 128738, 65039, 128738, 128739, 65039,
+    128739, 128740, 65039, 128740, 128741, 65039, 128741, 128745, 65039, 128745, 128747, 128748,
+    128752, 65039, 128752, 128755, 65039, 128755, 128756, 128757, 128758, 128759, 128760, 128761,
+    128762, 128763, 128764, 128992, 128993, 128994, 128995, 128996, 128997, 128998, 128999, 129000,
+    129001, 129002, 129003, 129292, 129292, 127995, 129292, 127996, 129292, 127997, 129292, 127998,
+    129292, 127999, 129293, 129294, 129295, 129295, 127995, 129295, 127996, 129295, 127997, 129295,
+    127998, 129295, 127999, 129296, 129297, 129298, 129299, 129300, 129301, 129302, 129303, 129304,
+    129304, 127995, 129304, 127996, 129304, 127997, 129304, 127998, 129304, 127999, 129305, 129305,
+    127995, 129305, 127996, 129305, 127997, 129305, 127998, 129305, 127999, 129306, 129306, 127995,
+    129306, 127996, 129306, 127997, 129306, 127998, 129306, 127999, 129307, 129307, 127995, 129307,
+    127996, 129307, 127997, 129307, 127998, 129307, 127999, 129308, 129308, 127995, 129308, 127996,
+    129308, 127997, 129308, 127998, 129308, 127999, 129309, 129310, 129310, 127995, 129310, 127996,
+    129310, 127997, 129310, 127998, 129310, 127999, 129311, 129311, 127995, 129311, 127996, 129311,
+    127997, 129311, 127998, 129311, 127999, 129312, 129313, 129314, 129315, 129316, 129317, 129318,
+    129318, 8205, 9792, 65039, 129318, 8205, 9792, 129318, 8205, 9794, 65039, 129318, 8205, 9794,
+    129318, 127995, 129318, 127995, 8205, 9792, 65039, 129318, 127995, 8205, 9792, 129318, 127995,
+    8205, 9794, 65039, 129318, 127995, 8205, 9794, 129318, 127996, 129318, 127996, 8205, 9792,
+    65039, 129318, 127996, 8205, 9792, 129318, 127996, 8205, 9794, 65039, 129318, 127996, 8205,
+    9794, 129318, 127997, 129318, 127997, 8205, 9792, 65039, 129318, 127997, 8205, 9792, 129318,
+    127997, 8205, 9794, 65039, 129318, 127997, 8205, 9794, 129318, 127998, 129318, 127998, 8205,
+    9792, 65039, 129318, 127998, 8205, 9792, 129318, 127998, 8205, 9794, 65039, 129318, 127998,
+    8205, 9794, 129318, 127999, 129318, 127999, 8205, 9792, 65039, 129318, 127999, 8205, 9792,
+    129318, 127999, 8205, 9794, 65039, 129318, 127999, 8205, 9794, 129319, 129320, 129321, 129322,
+    129323, 129324, 129325, 129326, 129327, 129328, 129328, 127995, 129328, 127996, 129328, 127997,
+    129328, 127998, 129328, 127999, 129329, 129329, 127995, 129329, 127996, 129329, 127997, 129329,
+    127998, 129329, 127999, 129330, 129330, 127995, 129330, 127996, 129330, 127997, 129330, 127998,
+    129330, 127999, 129331, 129331, 127995, 129331, 127996, 129331, 127997, 129331, 127998, 129331,
+    127999, 129332, 129332, 127995, 129332, 127996, 129332, 127997, 129332, 127998, 129332, 127999,
+    129333, 129333, 8205, 9792, 65039, 129333, 8205, 9792, 129333, 8205, 9794, 65039, 129333, 8205,
+    9794, 129333, 127995, 129333, 127995, 8205, 9792, 65039, 129333, 127995, 8205, 9792, 129333,
+    127995, 8205, 9794, 65039, 129333, 127995, 8205, 9794, 129333, 127996, 129333, 127996, 8205,
+    9792, 65039, 129333, 127996, 8205, 9792, 129333, 127996, 8205, 9794, 65039, 129333, 127996,
+    8205, 9794, 129333, 127997, 129333, 127997, 8205, 9792, 65039, 129333, 127997, 8205, 9792,
+    129333, 127997, 8205, 9794, 65039, 129333, 127997, 8205, 9794, 129333, 127998, 129333, 127998,
+    8205, 9792, 65039, 129333, 127998, 8205, 9792, 129333, 127998, 8205, 9794, 65039, 129333,
+    127998, 8205, 9794, 129333, 127999, 129333, 127999, 8205, 9792, 65039, 129333, 127999, 8205,
+    9792, 129333, 127999, 8205, 9794, 65039, 129333, 127999, 8205, 9794, 129334, 129334, 127995,
+    129334, 127996, 129334, 127997, 129334, 127998, 129334, 127999, 129335, 129335, 8205, 9792,
+    65039, 129335, 8205, 9792, 129335, 8205, 9794, 65039, 129335, 8205, 9794, 129335, 127995,
+    129335, 127995, 8205, 9792, 65039, 129335, 127995, 8205, 9792, 129335, 127995, 8205, 9794,
+    65039, 129335, 127995, 8205, 9794, 129335, 127996, 129335, 127996, 8205, 9792, 65039, 129335,
+    127996, 8205, 9792, 129335, 127996, 8205, 9794, 65039, 129335, 127996, 8205, 9794, 129335,
+    127997, 129335, 127997, 8205, 9792, 65039, 129335, 127997, 8205, 9792, 129335, 127997, 8205,
+    9794, 65039, 129335, 127997, 8205, 9794, 129335, 127998, 129335, 127998, 8205, 9792, 65039,
+    129335, 127998, 8205, 9792, 129335, 127998, 8205, 9794, 65039, 129335, 127998, 8205, 9794,
+    129335, 127999, 129335, 127999, 8205, 9792, 65039, 129335, 127999, 8205, 9792, 129335, 127999,
+    8205, 9794, 65039, 129335, 127999, 8205, 9794, 129336, 129336, 8205, 9792, 65039, 129336, 8205,
+    9792, 129336, 8205, 9794, 65039, 129336, 8205, 9794, 129336, 127995, 129336, 127995, 8205,
+    9792, 65039, 129336, 127995, 8205, 9792, 129336, 127995, 8205, 9794, 65039, 129336, 127995,
+    8205, 9794, 129336, 127996, 129336, 127996, 8205, 9792, 65039, 129336, 127996, 8205, 9792,
+    129336, 127996, 8205, 9794, 65039, 129336, 127996, 8205, 9794, 129336, 127997, 129336, 127997,
+    8205, 9792, 65039, 129336, 127997, 8205, 9792, 129336, 127997, 8205, 9794, 65039, 129336,
+    127997, 8205, 9794, 129336, 127998, 129336, 127998, 8205, 9792, 65039, 129336, 127998, 8205,
+    9792, 129336, 127998, 8205, 9794, 65039, 129336, 127998, 8205, 9794, 129336, 127999, 129336,
+    127999, 8205, 9792, 65039, 129336, 127999, 8205, 9792, 129336, 127999, 8205, 9794, 65039,
+    129336, 127999, 8205, 9794, 129337, 129337, 8205, 9792, 65039, 129337, 8205, 9792, 129337,
+    8205, 9794, 65039, 129337, 8205, 9794, 129337, 127995, 129337, 127995, 8205, 9792, 65039,
+    129337, 127995, 8205, 9792, 129337, 127995, 8205, 9794, 65039, 129337, 127995, 8205, 9794,
+    129337, 127996, 129337, 127996, 8205, 9792, 65039, 129337, 127996, 8205, 9792, 129337, 127996,
+    8205, 9794, 65039, 129337, 127996, 8205, 9794, 129337, 127997, 129337, 127997, 8205, 9792,
+    65039, 129337, 127997, 8205, 9792, 129337, 127997, 8205, 9794, 65039, 129337, 127997, 8205,
+    9794, 129337, 127998, 129337, 127998, 8205, 9792, 65039, 129337, 127998, 8205, 9792, 129337,
+    127998, 8205, 9794, 65039, 129337, 127998, 8205, 9794, 129337, 127999, 129337, 127999, 8205,
+    9792, 65039, 129337, 127999, 8205, 9792, 129337, 127999, 8205, 9794, 65039, 129337, 127999,
+    8205, 9794, 129338, 129340, 129340, 8205, 9792, 65039, 129340, 8205, 9792, 129340, 8205, 9794,
+    65039, 129340, 8205, 9794, 129341, 129341, 8205, 9792, 65039, 129341, 8205, 9792, 129341, 8205,
+    9794, 65039, 129341, 8205, 9794, 129341, 127995, 129341, 127995, 8205, 9792, 65039, 129341,
+    127995, 8205, 9792, 129341, 127995, 8205, 9794, 65039, 129341, 127995, 8205, 9794, 129341,
+    127996, 129341, 127996, 8205, 9792, 65039, 129341, 127996, 8205, 9792, 129341, 127996, 8205,
+    9794, 65039, 129341, 127996, 8205, 9794, 129341, 127997, 129341, 127997, 8205, 9792, 65039,
+    129341, 127997, 8205, 9792, 129341, 127997, 8205, 9794, 65039, 129341, 127997, 8205, 9794,
+    129341, 127998, 129341, 127998, 8205, 9792, 65039, 129341, 127998, 8205, 9792, 129341, 127998,
+    8205, 9794, 65039, 129341, 127998, 8205, 9794, 129341, 127999, 129341, 127999, 8205, 9792,
+    65039, 129341, 127999, 8205, 9792, 129341, 127999, 8205, 9794, 65039, 129341, 127999, 8205,
+    9794, 129342, 129342, 8205, 9792, 65039, 129342, 8205, 9792, 129342, 8205, 9794, 65039, 129342,
+    8205, 9794, 129342, 127995, 129342, 127995, 8205, 9792, 65039, 129342, 127995, 8205, 9792,
+    129342, 127995, 8205, 9794, 65039, 129342, 127995, 8205, 9794, 129342, 127996, 129342, 127996,
+    8205, 9792, 65039, 129342, 127996, 8205, 9792, 129342, 127996, 8205, 9794, 65039, 129342,
+    127996, 8205, 9794, 129342, 127997, 129342, 127997, 8205, 9792, 65039, 129342, 127997, 8205,
+    9792, 129342, 127997, 8205, 9794, 65039, 129342, 127997, 8205, 9794, 129342, 127998, 129342,
+    127998, 8205, 9792, 65039, 129342, 127998, 8205, 9792, 129342, 127998, 8205, 9794, 65039,
+    129342, 127998, 8205, 9794, 129342, 127999, 129342, 127999, 8205, 9792, 65039, 129342, 127999,
+    8205, 9792, 129342, 127999, 8205, 9794, 65039, 129342, 127999, 8205, 9794, 129343, 129344,
+    129345, 129346, 129347, 129348, 129349, 129351, 129352, 129353, 129354, 129355, 129356, 129357,
+    129358, 129359, 129360, 129361, 129362, 129363, 129364, 129365, 129366, 129367, 129368, 129369,
+    129370, 129371, 129372, 129373, 129374, 129375, 129376, 129377, 129378, 129379, 129380, 129381,
+    129382, 129383, 129384, 129385, 129386, 129387, 129388, 129389, 129390, 129391, 129392, 129393,
+    129394, 129395, 129396, 129397, 129398, 129399, 129399, 127995, 129399, 127996, 129399, 127997,
+    129399, 127998, 129399, 127999, 129400, 129402, 129403, 129404, 129405, 129406, 129407, 129408,
+    129409, 129410, 129411, 129412, 129413, 129414, 129415, 129416, 129417, 129418, 129419, 129420,
+    129421, 129422, 129423, 129424, 129425, 129426, 129427, 129428, 129429, 129430, 129431, 129432,
+    129433, 129434, 129435, 129436, 129437, 129438, 129439, 129440, 129441, 129442, 129443, 129444,
+    129445, 129446, 129447, 129448, 129449, 129450, 129451, 129452, 129453, 129454, 129455, 129456,
+    129457, 129458, 129459, 129460, 129461, 129461, 127995, 129461, 127996, 129461, 127997, 129461,
+    127998, 129461, 127999, 129462, 129462, 127995, 129462, 127996, 129462, 127997, 129462, 127998,
+    129462, 127999, 129463, 129464, 129464, 8205, 9792, 65039, 129464, 8205, 9792, 129464, 8205,
+    9794, 65039, 129464, 8205, 9794, 129464, 127995, 129464, 127995, 8205, 9792, 65039, 129464,
+    127995, 8205, 9792, 129464, 127995, 8205, 9794, 65039, 129464, 127995, 8205, 9794, 129464,
+    127996, 129464, 127996, 8205, 9792, 65039, 129464, 127996, 8205, 9792, 129464, 127996, 8205,
+    9794, 65039, 129464, 127996, 8205, 9794, 129464, 127997, 129464, 127997, 8205, 9792, 65039,
+    129464, 127997, 8205, 9792, 129464, 127997, 8205, 9794, 65039, 129464, 127997, 8205, 9794,
+    129464, 127998, 129464, 127998, 8205, 9792, 65039, 129464, 127998, 8205, 9792, 129464, 127998,
+    8205, 9794, 65039, 129464, 127998, 8205, 9794, 129464, 127999, 129464, 127999, 8205, 9792,
+    65039, 129464, 127999, 8205, 9792, 129464, 127999, 8205, 9794, 65039, 129464, 127999, 8205,
+    9794, 129465, 129465, 8205, 9792, 65039, 129465, 8205, 9792, 129465, 8205, 9794, 65039, 129465,
+    8205, 9794, 129465, 127995, 129465, 127995, 8205, 9792, 65039, 129465, 127995, 8205, 9792,
+    129465, 127995, 8205, 9794, 65039, 129465, 127995, 8205, 9794, 129465, 127996, 129465, 127996,
+    8205, 9792, 65039, 129465, 127996, 8205, 9792, 129465, 127996, 8205, 9794, 65039, 129465,
+    127996, 8205, 9794, 129465, 127997, 129465, 127997, 8205, 9792, 65039, 129465, 127997, 8205,
+    9792, 129465, 127997, 8205, 9794, 65039, 129465, 127997, 8205, 9794, 129465, 127998, 129465,
+    127998, 8205, 9792, 65039, 129465, 127998, 8205, 9792, 129465, 127998, 8205, 9794, 65039,
+    129465, 127998, 8205, 9794, 129465, 127999, 129465, 127999, 8205, 9792, 65039, 129465, 127999,
+    8205, 9792, 129465, 127999, 8205, 9794, 65039, 129465, 127999, 8205, 9794, 129466, 129467,
+    129467, 127995, 129467, 127996, 129467, 127997, 129467, 127998, 129467, 127999, 129468, 129469,
+    129470, 129471, 129472, 129473, 129474, 129475, 129476, 129477, 129478, 129479, 129480, 129481,
+    129482, 129483, 129485, 129485, 8205, 9792, 65039, 129485, 8205, 9792, 129485, 8205, 9794,
+    65039, 129485, 8205, 9794, 129485, 127995, 129485, 127995, 8205, 9792, 65039, 129485, 127995,
+    8205, 9792, 129485, 127995, 8205, 9794, 65039, 129485, 127995, 8205, 9794, 129485, 127996,
+    129485, 127996, 8205, 9792, 65039, 129485, 127996, 8205, 9792, 129485, 127996, 8205, 9794,
+    65039, 129485, 127996, 8205, 9794, 129485, 127997, 129485, 127997, 8205, 9792, 65039, 129485,
+    127997, 8205, 9792, 129485, 127997, 8205, 9794, 65039, 129485, 127997, 8205, 9794, 129485,
+    127998, 129485, 127998, 8205, 9792, 65039, 129485, 127998, 8205, 9792, 129485, 127998, 8205,
+    9794, 65039, 129485, 127998, 8205, 9794, 129485, 127999, 129485, 127999, 8205, 9792, 65039,
+    129485, 127999, 8205, 9792, 129485, 127999, 8205, 9794, 65039, 129485, 127999, 8205, 9794,
+    129486, 129486, 8205, 9792, 65039, 129486, 8205, 9792, 129486, 8205, 9794, 65039, 129486, 8205,
+    9794, 129486, 127995, 129486, 127995, 8205, 9792, 65039, 129486, 127995, 8205, 9792, 129486,
+    127995, 8205, 9794, 65039, 129486, 127995, 8205, 9794, 129486, 127996, 129486, 127996, 8205,
+    9792, 65039, 129486, 127996, 8205, 9792, 129486, 127996, 8205, 9794, 65039, 129486, 127996,
+    8205, 9794, 129486, 127997, 129486, 127997, 8205, 9792, 65039, 129486, 127997, 8205, 9792,
+    129486, 127997, 8205, 9794, 65039, 129486, 127997, 8205, 9794, 129486, 127998, 129486, 127998,
+    8205, 9792, 65039, 129486, 127998, 8205, 9792, 129486, 127998, 8205, 9794, 65039, 129486,
+    127998, 8205, 9794, 129486, 127999, 129486, 127999, 8205, 9792, 65039, 129486, 127999, 8205,
+    9792, 129486, 127999, 8205, 9794, 65039, 129486, 127999, 8205, 9794, 129487, 129487, 8205,
+    9792, 65039, 129487, 8205, 9792, 129487, 8205, 9794, 65039, 129487, 8205, 9794, 129487, 127995,
+    129487, 127995, 8205, 9792, 65039, 129487, 127995, 8205, 9792, 129487, 127995, 8205, 9794,
+    65039, 129487, 127995, 8205, 9794, 129487, 127996, 129487, 127996, 8205, 9792, 65039, 129487,
+    127996, 8205, 9792, 129487, 127996, 8205, 9794, 65039, 129487, 127996, 8205, 9794, 129487,
+    127997, 129487, 127997, 8205, 9792, 65039, 129487, 127997, 8205, 9792, 129487, 127997, 8205,
+    9794, 65039, 129487, 127997, 8205, 9794, 129487, 127998, 129487, 127998, 8205, 9792, 65039,
+    129487, 127998, 8205, 9792, 129487, 127998, 8205, 9794, 65039, 129487, 127998, 8205, 9794,
+    129487, 127999, 129487, 127999, 8205, 9792, 65039, 129487, 127999, 8205, 9792, 129487, 127999,
+    8205, 9794, 65039, 129487, 127999, 8205, 9794, 129488, 129489, 129489, 8205, 9877, 65039,
+    129489, 8205, 9877, 129489, 8205, 9878, 65039, 129489, 8205, 9878, 129489, 8205, 9992, 65039,
+    129489, 8205, 9992, 129489, 8205, 127806, 129489, 8205, 127859, 129489, 8205, 127868, 129489,
+    8205, 127876, 129489, 8205, 127891, 129489, 8205, 127908, 129489, 8205, 127912, 129489, 8205,
+    127979, 129489, 8205, 127981, 129489, 8205, 128187, 129489, 8205, 128188, 129489, 8205, 128295,
+    129489, 8205, 128300, 129489, 8205, 128640, 129489, 8205, 128658, 129489, 8205, 129309, 8205,
+    129489, 129489, 8205, 129455, 129489, 8205, 129456, 129489, 8205, 129457, 129489, 8205, 129458,
+    129489, 8205, 129459, 129489, 8205, 129468, 129489, 8205, 129469, 129489, 127995, 129489,
+    127995, 8205, 9877, 65039, 129489, 127995, 8205, 9877, 129489, 127995, 8205, 9878, 65039,
+    129489, 127995, 8205, 9878, 129489, 127995, 8205, 9992, 65039, 129489, 127995, 8205, 9992,
+    129489, 127995, 8205, 127806, 129489, 127995, 8205, 127859, 129489, 127995, 8205, 127868,
+    129489, 127995, 8205, 127876, 129489, 127995, 8205, 127891, 129489, 127995, 8205, 127908,
+    129489, 127995, 8205, 127912, 129489, 127995, 8205, 127979, 129489, 127995, 8205, 127981,
+    129489, 127995, 8205, 128187, 129489, 127995, 8205, 128188, 129489, 127995, 8205, 128295,
+    129489, 127995, 8205, 128300, 129489, 127995, 8205, 128640, 129489, 127995, 8205, 128658,
+    129489, 127995, 8205, 129309, 8205, 129489, 127995, 129489, 127995, 8205, 129309, 8205, 129489,
+    127996, 129489, 127995, 8205, 129309, 8205, 129489, 127997, 129489, 127995, 8205, 129309, 8205,
+    129489, 127998, 129489, 127995, 8205, 129309, 8205, 129489, 127999, 129489, 127995, 8205,
+    129455, 129489, 127995, 8205, 129456, 129489, 127995, 8205, 129457, 129489, 127995, 8205,
+    129458, 129489, 127995, 8205, 129459, 129489, 127995, 8205, 129468, 129489, 127995, 8205,
+    129469, 129489, 127996, 129489, 127996, 8205, 9877, 65039, 129489, 127996, 8205, 9877, 129489,
+    127996, 8205, 9878, 65039, 129489, 127996, 8205, 9878, 129489, 127996, 8205, 9992, 65039,
+    129489, 127996, 8205, 9992, 129489, 127996, 8205, 127806, 129489, 127996, 8205, 127859, 129489,
+    127996, 8205, 127868, 129489, 127996, 8205, 127876, 129489, 127996, 8205, 127891, 129489,
+    127996, 8205, 127908, 129489, 127996, 8205, 127912, 129489, 127996, 8205, 127979, 129489,
+    127996, 8205, 127981, 129489, 127996, 8205, 128187, 129489, 127996, 8205, 128188, 129489,
+    127996, 8205, 128295, 129489, 127996, 8205, 128300, 129489, 127996, 8205, 128640, 129489,
+    127996, 8205, 128658, 129489, 127996, 8205, 129309, 8205, 129489, 127995, 129489, 127996, 8205,
+    129309, 8205, 129489, 127996, 129489, 127996, 8205, 129309, 8205, 129489, 127997, 129489,
+    127996, 8205, 129309, 8205, 129489, 127998, 129489, 127996, 8205, 129309, 8205, 129489, 127999,
+    129489, 127996, 8205, 129455, 129489, 127996, 8205, 129456, 129489, 127996, 8205, 129457,
+    129489, 127996, 8205, 129458, 129489, 127996, 8205, 129459, 129489, 127996, 8205, 129468,
+    129489, 127996, 8205, 129469, 129489, 127997, 129489, 127997, 8205, 9877, 65039, 129489,
+    127997, 8205, 9877, 129489, 127997, 8205, 9878, 65039, 129489, 127997, 8205, 9878, 129489,
+    127997, 8205, 9992, 65039, 129489, 127997, 8205, 9992, 129489, 127997, 8205, 127806, 129489,
+    127997, 8205, 127859, 129489, 127997, 8205, 127868, 129489, 127997, 8205, 127876, 129489,
+    127997, 8205, 127891, 129489, 127997, 8205, 127908, 129489, 127997, 8205, 127912, 129489,
+    127997, 8205, 127979, 129489, 127997, 8205, 127981, 129489, 127997, 8205, 128187, 129489,
+    127997, 8205, 128188, 129489, 127997, 8205, 128295, 129489, 127997, 8205, 128300, 129489,
+    127997, 8205, 128640, 129489, 127997, 8205, 128658, 129489, 127997, 8205, 129309, 8205, 129489,
+    127995, 129489, 127997, 8205, 129309, 8205, 129489, 127996, 129489, 127997, 8205, 129309, 8205,
+    129489, 127997, 129489, 127997, 8205, 129309, 8205, 129489, 127998, 129489, 127997, 8205,
+    129309, 8205, 129489, 127999, 129489, 127997, 8205, 129455, 129489, 127997, 8205, 129456,
+    129489, 127997, 8205, 129457, 129489, 127997, 8205, 129458, 129489, 127997, 8205, 129459,
+    129489, 127997, 8205, 129468, 129489, 127997, 8205, 129469, 129489, 127998, 129489, 127998,
+    8205, 9877, 65039, 129489, 127998, 8205, 9877, 129489, 127998, 8205, 9878, 65039, 129489,
+    127998, 8205, 9878, 129489, 127998, 8205, 9992, 65039, 129489, 127998, 8205, 9992, 129489,
+    127998, 8205, 127806, 129489, 127998, 8205, 127859, 129489, 127998, 8205, 127868, 129489,
+    127998, 8205, 127876, 129489, 127998, 8205, 127891, 129489, 127998, 8205, 127908, 129489,
+    127998, 8205, 127912, 129489, 127998, 8205, 127979, 129489, 127998, 8205, 127981, 129489,
+    127998, 8205, 128187, 129489, 127998, 8205, 128188, 129489, 127998, 8205, 128295, 129489,
+    127998, 8205, 128300, 129489, 127998, 8205, 128640, 129489, 127998, 8205, 128658, 129489,
+    127998, 8205, 129309, 8205, 129489, 127995, 129489, 127998, 8205, 129309, 8205, 129489, 127996,
+    129489, 127998, 8205, 129309, 8205, 129489, 127997, 129489, 127998, 8205, 129309, 8205, 129489,
+    127998, 129489, 127998, 8205, 129309, 8205, 129489, 127999, 129489, 127998, 8205, 129455,
+    129489, 127998, 8205, 129456, 129489, 127998, 8205, 129457, 129489, 127998, 8205, 129458,
+    129489, 127998, 8205, 129459, 129489, 127998, 8205, 129468, 129489, 127998, 8205, 129469,
+    129489, 127999, 129489, 127999, 8205, 9877, 65039, 129489, 127999, 8205, 9877, 129489, 127999,
+    8205, 9878, 65039, 129489, 127999, 8205, 9878, 129489, 127999, 8205, 9992, 65039, 129489,
+    127999, 8205, 9992, 129489, 127999, 8205, 127806, 129489, 127999, 8205, 127859, 129489, 127999,
+    8205, 127868, 129489, 127999, 8205, 127876, 129489, 127999, 8205, 127891, 129489, 127999, 8205,
+    127908, 129489, 127999, 8205, 127912, 129489, 127999, 8205, 127979, 129489, 127999, 8205,
+    127981, 129489, 127999, 8205, 128187, 129489, 127999, 8205, 128188, 129489, 127999, 8205,
+    128295, 129489, 127999, 8205, 128300, 129489, 127999, 8205, 128640, 129489, 127999, 8205,
+    128658, 129489, 127999, 8205, 129309, 8205, 129489, 127995, 129489, 127999, 8205, 129309, 8205,
+    129489, 127996, 129489, 127999, 8205, 129309, 8205, 129489, 127997, 129489, 127999, 8205,
+    129309, 8205, 129489, 127998, 129489, 127999, 8205, 129309, 8205, 129489, 127999, 129489,
+    127999, 8205, 129455, 129489, 127999, 8205, 129456, 129489, 127999, 8205, 129457, 129489,
+    127999, 8205, 129458, 129489, 127999, 8205, 129459, 129489, 127999, 8205, 129468, 129489,
+    127999, 8205, 129469, 129490, 129490, 127995, 129490, 127996, 129490, 127997, 129490, 127998,
+    129490, 127999, 129491, 129491, 127995, 129491, 127996, 129491, 127997, 129491, 127998, 129491,
+    127999, 129492, 129492, 127995, 129492, 127996, 129492, 127997, 129492, 127998, 129492, 127999,
+    129493, 129493, 127995, 129493, 127996, 129493, 127997, 129493, 127998, 129493, 127999, 129494,
+    129494, 8205, 9792, 65039, 129494, 8205, 9792, 129494, 8205, 9794, 65039, 129494, 8205, 9794,
+    129494, 127995, 129494, 127995, 8205, 9792, 65039, 129494, 127995, 8205, 9792, 129494, 127995,
+    8205, 9794, 65039, 129494, 127995, 8205, 9794, 129494, 127996, 129494, 127996, 8205, 9792,
+    65039, 129494, 127996, 8205, 9792, 129494, 127996, 8205, 9794, 65039, 129494, 127996, 8205,
+    9794, 129494, 127997, 129494, 127997, 8205, 9792, 65039, 129494, 127997, 8205, 9792, 129494,
+    127997, 8205, 9794, 65039, 129494, 127997, 8205, 9794, 129494, 127998, 129494, 127998, 8205,
+    9792, 65039, 129494, 127998, 8205, 9792, 129494, 127998, 8205, 9794, 65039, 129494, 127998,
+    8205, 9794, 129494, 127999, 129494, 127999, 8205, 9792, 65039, 129494, 127999, 8205, 9792,
+    129494, 127999, 8205, 9794, 65039, 129494, 127999, 8205, 9794, 129495, 129495, 8205, 9792,
+    65039, 129495, 8205, 9792, 129495, 8205, 9794, 65039, 129495, 8205, 9794, 129495, 127995,
+    129495, 127995, 8205, 9792, 65039, 129495, 127995, 8205, 9792, 129495, 127995, 8205, 9794,
+    65039, 129495, 127995, 8205, 9794, 129495, 127996, 129495, 127996, 8205, 9792, 65039, 129495,
+    127996, 8205, 9792, 129495, 127996, 8205, 9794, 65039, 129495, 127996, 8205, 9794, 129495,
+    127997, 129495, 127997, 8205, 9792, 65039, 129495, 127997, 8205, 9792, 129495, 127997, 8205,
+    9794, 65039, 129495, 127997, 8205, 9794, 129495, 127998, 129495, 127998, 8205, 9792, 65039,
+    129495, 127998, 8205, 9792, 129495, 127998, 8205, 9794, 65039, 129495, 127998, 8205, 9794,
+    129495, 127999, 129495, 127999, 8205, 9792, 65039, 129495, 127999, 8205, 9792, 129495, 127999,
+    8205, 9794, 65039, 129495, 127999, 8205, 9794, 129496, 129496, 8205, 9792, 65039, 129496, 8205,
+    9792, 129496, 8205, 9794, 65039, 129496, 8205, 9794, 129496, 127995, 129496, 127995, 8205,
+    9792, 65039, 129496, 127995, 8205, 9792, 129496, 127995, 8205, 9794, 65039, 129496, 127995,
+    8205, 9794, 129496, 127996, 129496, 127996, 8205, 9792, 65039, 129496, 127996, 8205, 9792,
+    129496, 127996, 8205, 9794, 65039, 129496, 127996, 8205, 9794, 129496, 127997, 129496, 127997,
+    8205, 9792, 65039, 129496, 127997, 8205, 9792, 129496, 127997, 8205, 9794, 65039, 129496,
+    127997, 8205, 9794, 129496, 127998, 129496, 127998, 8205, 9792, 65039, 129496, 127998, 8205,
+    9792, 129496, 127998, 8205, 9794, 65039, 129496, 127998, 8205, 9794, 129496, 127999, 129496,
+    127999, 8205, 9792, 65039, 129496, 127999, 8205, 9792, 129496, 127999, 8205, 9794, 65039,
+    129496, 127999, 8205, 9794, 129497, 129497, 8205, 9792, 65039, 129497, 8205, 9792, 129497,
+    8205, 9794, 65039, 129497, 8205, 9794, 129497, 127995, 129497, 127995, 8205, 9792, 65039,
+    129497, 127995, 8205, 9792, 129497, 127995, 8205, 9794, 65039, 129497, 127995, 8205, 9794,
+    129497, 127996, 129497, 127996, 8205, 9792, 65039, 129497, 127996, 8205, 9792, 129497, 127996,
+    8205, 9794, 65039, 129497, 127996, 8205, 9794, 129497, 127997, 129497, 127997, 8205, 9792,
+    65039, 129497, 127997, 8205, 9792, 129497, 127997, 8205, 9794, 65039, 129497, 127997, 8205,
+    9794, 129497, 127998, 129497, 127998, 8205, 9792, 65039, 129497, 127998, 8205, 9792, 129497,
+    127998, 8205, 9794, 65039, 129497, 127998, 8205, 9794, 129497, 127999, 129497, 127999, 8205,
+    9792, 65039, 129497, 127999, 8205, 9792, 129497, 127999, 8205, 9794, 65039, 129497, 127999,
+    8205, 9794, 129498, 129498, 8205, 9792, 65039, 129498, 8205, 9792, 129498, 8205, 9794, 65039,
+    129498, 8205, 9794, 129498, 127995, 129498, 127995, 8205, 9792, 65039, 129498, 127995, 8205,
+    9792, 129498, 127995, 8205, 9794, 65039, 129498, 127995, 8205, 9794, 129498, 127996, 129498,
+    127996, 8205, 9792, 65039, 129498, 127996, 8205, 9792, 129498, 127996, 8205, 9794, 65039,
+    129498, 127996, 8205, 9794, 129498, 127997, 129498, 127997, 8205, 9792, 65039, 129498, 127997,
+    8205, 9792, 129498, 127997, 8205, 9794, 65039, 129498, 127997, 8205, 9794, 129498, 127998,
+    129498, 127998, 8205, 9792, 65039, 129498, 127998, 8205, 9792, 129498, 127998, 8205, 9794,
+    65039, 129498, 127998, 8205, 9794, 129498, 127999, 129498, 127999, 8205, 9792, 65039, 129498,
+    127999, 8205, 9792, 129498, 127999, 8205, 9794, 65039, 129498, 127999, 8205, 9794, 129499,
+    129499, 8205, 9792, 65039, 129499, 8205, 9792, 129499, 8205, 9794, 65039, 129499, 8205, 9794,
+    129499, 127995, 129499, 127995, 8205, 9792, 65039, 129499, 127995, 8205, 9792, 129499, 127995,
+    8205, 9794, 65039, 129499, 127995, 8205, 9794, 129499, 127996, 129499, 127996, 8205, 9792,
+    65039, 129499, 127996, 8205, 9792, 129499, 127996, 8205, 9794, 65039, 129499, 127996, 8205,
+    9794, 129499, 127997, 129499, 127997, 8205, 9792, 65039, 129499, 127997, 8205, 9792, 129499,
+    127997, 8205, 9794, 65039, 129499, 127997, 8205, 9794, 129499, 127998, 129499, 127998, 8205,
+    9792, 65039, 129499, 127998, 8205, 9792, 129499, 127998, 8205, 9794, 65039, 129499, 127998,
+    8205, 9794, 129499, 127999, 129499, 127999, 8205, 9792, 65039, 129499, 127999, 8205, 9792,
+    129499, 127999, 8205, 9794, 65039, 129499, 127999, 8205, 9794, 129500, 129500, 8205, 9792,
+    65039, 129500, 8205, 9792, 129500, 8205, 9794, 65039, 129500, 8205, 9794, 129500, 127995,
+    129500, 127995, 8205, 9792, 65039, 129500, 127995, 8205, 9792, 129500, 127995, 8205, 9794,
+    65039, 129500, 127995, 8205, 9794, 129500, 127996, 129500, 127996, 8205, 9792, 65039, 129500,
+    127996, 8205, 9792, 129500, 127996, 8205, 9794, 65039, 129500, 127996, 8205, 9794, 129500,
+    127997, 129500, 127997, 8205, 9792, 65039, 129500, 127997, 8205, 9792, 129500, 127997, 8205,
+    9794, 65039, 129500, 127997, 8205, 9794, 129500, 127998, 129500, 127998, 8205, 9792, 65039,
+    129500, 127998, 8205, 9792, 129500, 127998, 8205, 9794, 65039, 129500, 127998, 8205, 9794,
+    129500, 127999, 129500, 127999, 8205, 9792, 65039, 129500, 127999, 8205, 9792, 129500, 127999,
+    8205, 9794, 65039, 129500, 127999, 8205, 9794, 129501, 129501, 8205, 9792, 65039, 129501, 8205,
+    9792, 129501, 8205, 9794, 65039, 129501, 8205, 9794, 129501, 127995, 129501, 127995, 8205,
+    9792, 65039, 129501, 127995, 8205, 9792, 129501, 127995, 8205, 9794, 65039, 129501, 127995,
+    8205, 9794, 129501, 127996, 129501, 127996, 8205, 9792, 65039, 129501, 127996, 8205, 9792,
+    129501, 127996, 8205, 9794, 65039, 129501, 127996, 8205, 9794, 129501, 127997, 129501, 127997,
+    8205, 9792, 65039, 129501, 127997, 8205, 9792, 129501, 127997, 8205, 9794, 65039, 129501,
+    127997, 8205, 9794, 129501, 127998, 129501, 127998, 8205, 9792, 65039, 129501, 127998, 8205,
+    9792, 129501, 127998, 8205, 9794, 65039, 129501, 127998, 8205, 9794, 129501, 127999, 129501,
+    127999, 8205, 9792, 65039, 129501, 127999, 8205, 9792, 129501, 127999, 8205, 9794, 65039,
+    129501, 127999, 8205, 9794, 129502, 129502, 8205, 9792, 65039, 129502, 8205, 9792, 129502,
+    8205, 9794, 65039, 129502, 8205, 9794, 129503, 129503, 8205, 9792, 65039, 129503, 8205, 9792,
+    129503, 8205, 9794, 65039, 129503, 8205, 9794, 129504, 129505, 129506, 129507, 129508, 129509,
+    129510, 129511, 129512, 129513, 129514, 129515, 129516, 129517, 129518, 129519, 129520, 129521,
+    129522, 129523, 129524, 129525, 129526, 129527, 129528, 129529, 129530, 129531, 129532, 129533,
+    129534, 129535, 129648, 129649, 129650, 129651, 129652, 129656, 129657, 129658, 129664, 129665,
+    129666, 129667, 129668, 129669, 129670, 129680, 129681, 129682, 129683, 129684, 129685, 129686,
+    129687, 129688, 129689, 129690, 129691, 129692, 129693, 129694, 129695, 129696, 129697, 129698,
+    129699, 129700, 129701, 129702, 129703, 129704, 129712, 129713, 129714, 129715, 129716, 129717,
+    129718, 129728, 129729, 129730, 129744, 129745, 129746, 129747, 129748, 129749, 129750,
+];
+
+#[cfg(feature = "emoji-sequences")]
+#[rustfmt::skip]
+pub const EMOJI_SEQUENCES: [(u16, u8, u8); 4168] = [
+    (0,3,1), (3,2,3), (5,3,1), (8,2,3), (10,3,1), (13,2,3), (15,3,1), (18,2,3), (20,3,1), (23,2,3),
+    (25,3,1), (28,2,3), (30,3,1), (33,2,3), (35,3,1), (38,2,3), (40,3,1), (43,2,3), (45,3,1),
+    (48,2,3), (50,3,1), (53,2,3), (55,3,1), (58,2,3), (60,2,1), (62,1,3), (63,2,1), (65,1,3),
+    (66,2,1), (68,1,3), (69,2,1), (71,1,3), (72,2,1), (74,1,3), (75,2,1), (77,1,3), (78,2,1),
+    (80,1,3), (81,2,1), (83,1,3), (84,2,1), (86,1,3), (87,2,1), (89,1,3), (90,2,1), (92,1,3),
+    (93,2,1), (95,1,3), (96,2,1), (98,1,3), (99,2,1), (101,1,3), (102,1,1), (103,1,1), (104,2,1),
+    (106,1,3), (107,2,1), (109,1,3), (110,1,1), (111,1,1), (112,1,1), (113,1,1), (114,2,1),
+    (116,1,3), (117,2,1), (119,1,3), (120,2,1), (122,1,3), (123,1,1), (124,2,1), (126,1,3),
+    (127,2,1), (129,1,3), (130,1,1), (131,2,1), (133,1,3), (134,2,1), (136,1,3), (137,2,1),
+    (139,1,3), (140,2,1), (142,1,3), (143,2,1), (145,1,3), (146,2,1), (148,1,3), (149,2,1),
+    (151,1,3), (152,2,1), (154,1,3), (155,2,1), (157,1,3), (158,2,1), (160,1,3), (161,1,1),
+    (162,1,1), (163,2,1), (165,1,3), (166,2,1), (168,1,3), (169,2,1), (171,1,3), (172,2,1),
+    (174,1,3), (175,2,1), (177,1,3), (178,2,1), (180,1,3), (181,2,1), (183,1,3), (184,1,1),
+    (185,1,1), (186,2,1), (188,1,3), (189,2,1), (191,1,3), (192,2,1), (194,2,1), (196,2,1),
+    (198,2,1), (200,2,1), (202,2,1), (204,1,3), (205,2,1), (207,1,3), (208,2,1), (210,1,3),
+    (211,2,1), (213,1,3), (214,2,1), (216,1,3), (217,2,1), (219,1,3), (220,2,1), (222,1,3),
+    (223,2,1), (225,1,3), (226,2,1), (228,1,3), (229,2,1), (231,1,3), (232,2,1), (234,1,3),
+    (235,2,1), (237,1,3), (238,1,1), (239,1,1), (240,1,1), (241,1,1), (242,1,1), (243,1,1),
+    (244,1,1), (245,1,1), (246,1,1), (247,1,1), (248,1,1), (249,1,1), (250,2,1), (252,1,3),
+    (253,2,1), (255,1,3), (256,2,1), (258,1,3), (259,2,1), (261,1,3), (262,2,1), (264,1,3),
+    (265,2,1), (267,1,3), (268,2,1), (270,1,3), (271,2,1), (273,1,3), (274,1,1), (275,2,1),
+    (277,1,3), (278,1,1), (279,2,1), (281,1,3), (282,2,1), (284,1,3), (285,2,1), (287,1,3),
+    (288,2,1), (290,1,3), (291,2,1), (293,1,3), (294,2,1), (296,1,3), (297,2,1), (299,1,3),
+    (300,2,1), (302,1,3), (303,1,1), (304,2,1), (306,1,3), (307,1,1), (308,1,1), (309,2,1),
+    (311,1,3), (312,2,1), (314,1,3), (315,1,1), (316,1,1), (317,1,1), (318,1,1), (319,2,1),
+    (321,1,3), (322,1,1), (323,2,1), (325,1,3), (326,2,1), (328,1,3), (329,2,1), (331,1,3),
+    (332,1,1), (333,2,1), (335,1,3), (336,1,1), (337,2,1), (339,1,3), (340,2,1), (342,1,3),
+    (343,1,1), (344,1,1), (345,2,1), (347,1,3), (348,1,1), (349,2,1), (351,1,3), (352,2,1),
+    (354,1,3), (355,2,1), (357,1,3), (358,5,1), (363,4,2), (367,4,3), (371,3,3), (374,5,1),
+    (379,4,2), (383,4,3), (387,3,3), (390,2,1), (392,5,1), (397,4,2), (401,5,1), (406,4,2),
+    (410,2,1), (412,5,1), (417,4,2), (421,5,1), (426,4,2), (430,2,1), (432,5,1), (437,4,2),
+    (441,5,1), (446,4,2), (450,2,1), (452,5,1), (457,4,2), (461,5,1), (466,4,2), (470,2,1),
+    (472,5,1), (477,4,2), (481,5,1), (486,4,2), (490,1,1), (491,1,1), (492,2,1), (494,1,3),
+    (495,1,1), (496,2,1), (498,1,3), (499,2,1), (501,1,3), (502,1,1), (503,2,1), (505,2,1),
+    (507,2,1), (509,2,1), (511,2,1), (513,1,1), (514,2,1), (516,2,1), (518,2,1), (520,2,1),
+    (522,2,1), (524,2,1), (526,1,3), (527,2,1), (529,2,1), (531,2,1), (533,2,1), (535,2,1),
+    (537,2,1), (539,1,3), (540,2,1), (542,2,1), (544,2,1), (546,2,1), (548,2,1), (550,2,1),
+    (552,1,3), (553,2,1), (555,1,3), (556,2,1), (558,1,3), (559,2,1), (561,1,3), (562,2,1),
+    (564,1,3), (565,2,1), (567,1,3), (568,1,1), (569,2,1), (571,1,3), (572,2,1), (574,1,3),
+    (575,2,1), (577,1,3), (578,2,1), (580,1,3), (581,1,1), (582,1,1), (583,1,1), (584,1,1),
+    (585,1,1), (586,1,1), (587,2,1), (589,1,3), (590,2,1), (592,1,3), (593,1,1), (594,1,1),
+    (595,1,1), (596,2,1), (598,1,3), (599,1,1), (600,1,1), (601,2,1), (603,1,3), (604,2,1),
+    (606,1,3), (607,2,1), (609,1,3), (610,2,1), (612,1,3), (613,2,1), (615,1,3), (616,1,1),
+    (617,1,1), (618,1,1), (619,1,1), (620,2,1), (622,1,3), (623,2,1), (625,1,3), (626,2,1),
+    (628,1,3), (629,2,1), (631,1,3), (632,1,1), (633,1,1), (634,2,1), (636,1,3), (637,2,1),
+    (639,1,3), (640,2,1), (642,1,3), (643,2,1), (645,1,3), (646,1,1), (647,1,1), (648,1,1),
+    (649,1,1), (650,1,1), (651,1,1), (652,1,1), (653,1,1), (654,1,1), (655,1,1), (656,1,1),
+    (657,2,1), (659,2,1), (661,2,1), (663,2,1), (665,2,1), (667,2,1), (669,2,1), (671,2,1),
+    (673,2,1), (675,2,1), (677,2,1), (679,2,1), (681,2,1), (683,2,1), (685,2,1), (687,2,1),
+    (689,2,1), (691,2,1), (693,2,1), (695,2,1), (697,2,1), (699,2,1), (701,2,1), (703,2,1),
+    (705,2,1), (707,2,1), (709,2,1), (711,2,1), (713,2,1), (715,2,1), (717,2,1), (719,2,1),
+    (721,2,1), (723,2,1), (725,2,1), (727,2,1), (729,2,1), (731,2,1), (733,2,1), (735,2,1),
+    (737,2,1), (739,2,1), (741,2,1), (743,2,1), (745,2,1), (747,2,1), (749,2,1), (751,2,1),
+    (753,2,1), (755,2,1), (757,2,1), (759,2,1), (761,2,1), (763,2,1), (765,2,1), (767,2,1),
+    (769,2,1), (771,2,1), (773,2,1), (775,2,1), (777,2,1), (779,2,1), (781,2,1), (783,2,1),
+    (785,2,1), (787,2,1), (789,2,1), (791,2,1), (793,2,1), (795,2,1), (797,2,1), (799,2,1),
+    (801,2,1), (803,2,1), (805,2,1), (807,2,1), (809,2,1), (811,2,1), (813,2,1), (815,2,1),
+    (817,2,1), (819,2,1), (821,2,1), (823,2,1), (825,2,1), (827,2,1), (829,2,1), (831,2,1),
+    (833,2,1), (835,2,1), (837,2,1), (839,2,1), (841,2,1), (843,2,1), (845,2,1), (847,2,1),
+    (849,2,1), (851,2,1), (853,2,1), (855,2,1), (857,2,1), (859,2,1), (861,2,1), (863,2,1),
+    (865,2,1), (867,2,1), (869,2,1), (871,2,1), (873,2,1), (875,2,1), (877,2,1), (879,2,1),
+    (881,2,1), (883,2,1), (885,2,1), (887,2,1), (889,2,1), (891,2,1), (893,2,1), (895,2,1),
+    (897,2,1), (899,2,1), (901,2,1), (903,2,1), (905,2,1), (907,2,1), (909,2,1), (911,2,1),
+    (913,2,1), (915,2,1), (917,2,1), (919,2,1), (921,2,1), (923,2,1), (925,2,1), (927,2,1),
+    (929,2,1), (931,2,1), (933,2,1), (935,2,1), (937,2,1), (939,2,1), (941,2,1), (943,2,1),
+    (945,2,1), (947,2,1), (949,2,1), (951,2,1), (953,2,1), (955,2,1), (957,2,1), (959,2,1),
+    (961,2,1), (963,2,1), (965,2,1), (967,2,1), (969,2,1), (971,2,1), (973,2,1), (975,2,1),
+    (977,2,1), (979,2,1), (981,2,1), (983,2,1), (985,2,1), (987,2,1), (989,2,1), (991,2,1),
+    (993,2,1), (995,2,1), (997,2,1), (999,2,1), (1001,2,1), (1003,2,1), (1005,2,1), (1007,2,1),
+    (1009,2,1), (1011,2,1), (1013,2,1), (1015,2,1), (1017,2,1), (1019,2,1), (1021,2,1), (1023,2,1),
+    (1025,2,1), (1027,2,1), (1029,2,1), (1031,2,1), (1033,2,1), (1035,2,1), (1037,2,1), (1039,2,1),
+    (1041,2,1), (1043,2,1), (1045,2,1), (1047,2,1), (1049,2,1), (1051,2,1), (1053,2,1), (1055,2,1),
+    (1057,2,1), (1059,2,1), (1061,2,1), (1063,2,1), (1065,2,1), (1067,2,1), (1069,2,1), (1071,2,1),
+    (1073,2,1), (1075,2,1), (1077,2,1), (1079,2,1), (1081,2,1), (1083,2,1), (1085,2,1), (1087,2,1),
+    (1089,2,1), (1091,2,1), (1093,2,1), (1095,2,1), (1097,2,1), (1099,2,1), (1101,2,1), (1103,2,1),
+    (1105,2,1), (1107,2,1), (1109,2,1), (1111,2,1), (1113,2,1), (1115,2,1), (1117,2,1), (1119,2,1),
+    (1121,2,1), (1123,2,1), (1125,2,1), (1127,2,1), (1129,2,1), (1131,2,1), (1133,2,1), (1135,2,1),
+    (1137,2,1), (1139,2,1), (1141,2,1), (1143,2,1), (1145,2,1), (1147,2,1), (1149,2,1), (1151,2,1),
+    (1153,2,1), (1155,2,1), (1157,2,1), (1159,2,1), (1161,2,1), (1163,2,1), (1165,2,1), (1167,2,1),
+    (1169,2,1), (1171,2,1), (1173,1,1), (1174,2,1), (1176,1,3), (1177,1,1), (1178,1,1), (1179,1,1),
+    (1180,1,1), (1181,1,1), (1182,1,1), (1183,1,1), (1184,2,1), (1186,1,3), (1187,1,1), (1188,1,1),
+    (1189,1,1), (1190,1,1), (1191,1,1), (1192,1,1), (1193,1,1), (1194,1,1), (1195,1,1), (1196,1,1),
+    (1197,1,1), (1198,1,1), (1199,1,1), (1200,1,1), (1201,1,1), (1202,1,1), (1203,1,1), (1204,1,1),
+    (1205,1,1), (1206,1,1), (1207,1,1), (1208,1,1), (1209,1,1), (1210,1,1), (1211,1,1), (1212,1,1),
+    (1213,1,1), (1214,1,1), (1215,1,1), (1216,1,1), (1217,1,1), (1218,1,1), (1219,1,1), (1220,1,1),
+    (1221,1,1), (1222,1,1), (1223,1,1), (1224,1,1), (1225,2,1), (1227,1,3), (1228,2,1), (1230,1,3),
+    (1231,2,1), (1233,1,3), (1234,2,1), (1236,1,3), (1237,2,1), (1239,1,3), (1240,2,1), (1242,1,3),
+    (1243,2,1), (1245,1,3), (1246,2,1), (1248,1,3), (1249,2,1), (1251,1,3), (1252,2,1), (1254,1,3),
+    (1255,1,1), (1256,1,1), (1257,1,1), (1258,1,1), (1259,1,1), (1260,1,1), (1261,1,1), (1262,1,1),
+    (1263,1,1), (1264,2,1), (1266,1,3), (1267,1,1), (1268,1,1), (1269,1,1), (1270,1,1), (1271,1,1),
+    (1272,1,1), (1273,1,1), (1274,1,1), (1275,1,1), (1276,1,1), (1277,1,1), (1278,1,1), (1279,1,1),
+    (1280,1,1), (1281,1,1), (1282,1,1), (1283,1,1), (1284,1,1), (1285,1,1), (1286,1,1), (1287,1,1),
+    (1288,1,1), (1289,1,1), (1290,1,1), (1291,1,1), (1292,1,1), (1293,1,1), (1294,1,1), (1295,1,1),
+    (1296,1,1), (1297,1,1), (1298,1,1), (1299,1,1), (1300,1,1), (1301,1,1), (1302,1,1), (1303,1,1),
+    (1304,1,1), (1305,1,1), (1306,1,1), (1307,1,1), (1308,1,1), (1309,1,1), (1310,1,1), (1311,1,1),
+    (1312,1,1), (1313,1,1), (1314,1,1), (1315,1,1), (1316,1,1), (1317,1,1), (1318,1,1), (1319,1,1),
+    (1320,1,1), (1321,1,1), (1322,1,1), (1323,1,1), (1324,1,1), (1325,1,1), (1326,1,1), (1327,1,1),
+    (1328,1,1), (1329,1,1), (1330,1,1), (1331,1,1), (1332,1,1), (1333,1,1), (1334,1,1), (1335,1,1),
+    (1336,1,1), (1337,2,1), (1339,1,3), (1340,1,1), (1341,1,1), (1342,1,1), (1343,1,1), (1344,1,1),
+    (1345,1,1), (1346,1,1), (1347,1,1), (1348,2,1), (1350,2,1), (1352,2,1), (1354,2,1), (1356,2,1),
+    (1358,1,1), (1359,1,1), (1360,1,1), (1361,1,1), (1362,1,1), (1363,1,1), (1364,1,1), (1365,1,1),
+    (1366,1,1), (1367,1,1), (1368,1,1), (1369,1,1), (1370,1,1), (1371,1,1), (1372,2,1), (1374,1,3),
+    (1375,2,1), (1377,1,3), (1378,2,1), (1380,1,3), (1381,2,1), (1383,1,3), (1384,2,1), (1386,1,3),
+    (1387,2,1), (1389,1,3), (1390,2,1), (1392,1,3), (1393,1,1), (1394,1,1), (1395,1,1), (1396,1,1),
+    (1397,1,1), (1398,1,1), (1399,1,1), (1400,1,1), (1401,1,1), (1402,1,1), (1403,1,1), (1404,1,1),
+    (1405,1,1), (1406,1,1), (1407,1,1), (1408,1,1), (1409,1,1), (1410,1,1), (1411,1,1), (1412,1,1),
+    (1413,1,1), (1414,1,1), (1415,1,1), (1416,1,1), (1417,1,1), (1418,1,1), (1419,1,1), (1420,1,1),
+    (1421,1,1), (1422,1,1), (1423,1,1), (1424,1,1), (1425,1,1), (1426,1,1), (1427,1,1), (1428,2,1),
+    (1430,2,1), (1432,2,1), (1434,2,1), (1436,2,1), (1438,1,1), (1439,4,1), (1443,3,2), (1446,4,1),
+    (1450,3,2), (1453,2,1), (1455,5,1), (1460,4,2), (1464,5,1), (1469,4,2), (1473,2,1), (1475,5,1),
+    (1480,4,2), (1484,5,1), (1489,4,2), (1493,2,1), (1495,5,1), (1500,4,2), (1504,5,1), (1509,4,2),
+    (1513,2,1), (1515,5,1), (1520,4,2), (1524,5,1), (1529,4,2), (1533,2,1), (1535,5,1), (1540,4,2),
+    (1544,5,1), (1549,4,2), (1553,1,1), (1554,4,1), (1558,3,2), (1561,4,1), (1565,3,2), (1568,2,1),
+    (1570,5,1), (1575,4,2), (1579,5,1), (1584,4,2), (1588,2,1), (1590,5,1), (1595,4,2), (1599,5,1),
+    (1604,4,2), (1608,2,1), (1610,5,1), (1615,4,2), (1619,5,1), (1624,4,2), (1628,2,1), (1630,5,1),
+    (1635,4,2), (1639,5,1), (1644,4,2), (1648,2,1), (1650,5,1), (1655,4,2), (1659,5,1), (1664,4,2),
+    (1668,1,1), (1669,1,1), (1670,1,1), (1671,2,1), (1673,2,1), (1675,2,1), (1677,2,1), (1679,2,1),
+    (1681,1,1), (1682,1,1), (1683,1,1), (1684,4,1), (1688,3,2), (1691,4,1), (1695,3,2), (1698,2,1),
+    (1700,5,1), (1705,4,2), (1709,5,1), (1714,4,2), (1718,2,1), (1720,5,1), (1725,4,2), (1729,5,1),
+    (1734,4,2), (1738,2,1), (1740,5,1), (1745,4,2), (1749,5,1), (1754,4,2), (1758,2,1), (1760,5,1),
+    (1765,4,2), (1769,5,1), (1774,4,2), (1778,2,1), (1780,5,1), (1785,4,2), (1789,5,1), (1794,4,2),
+    (1798,2,1), (1800,1,3), (1801,5,1), (1806,4,2), (1810,4,3), (1814,3,3), (1817,5,1), (1822,4,2),
+    (1826,4,3), (1830,3,3), (1833,2,1), (1835,5,1), (1840,4,2), (1844,5,1), (1849,4,2), (1853,2,1),
+    (1855,5,1), (1860,4,2), (1864,5,1), (1869,4,2), (1873,2,1), (1875,5,1), (1880,4,2), (1884,5,1),
+    (1889,4,2), (1893,2,1), (1895,5,1), (1900,4,2), (1904,5,1), (1909,4,2), (1913,2,1), (1915,5,1),
+    (1920,4,2), (1924,5,1), (1929,4,2), (1933,2,1), (1935,1,3), (1936,5,1), (1941,4,2), (1945,4,3),
+    (1949,3,3), (1952,5,1), (1957,4,2), (1961,4,3), (1965,3,3), (1968,2,1), (1970,5,1), (1975,4,2),
+    (1979,5,1), (1984,4,2), (1988,2,1), (1990,5,1), (1995,4,2), (1999,5,1), (2004,4,2), (2008,2,1),
+    (2010,5,1), (2015,4,2), (2019,5,1), (2024,4,2), (2028,2,1), (2030,5,1), (2035,4,2), (2039,5,1),
+    (2044,4,2), (2048,2,1), (2050,5,1), (2055,4,2), (2059,5,1), (2064,4,2), (2068,2,1), (2070,1,3),
+    (2071,2,1), (2073,1,3), (2074,1,1), (2075,1,1), (2076,1,1), (2077,1,1), (2078,1,1), (2079,2,1),
+    (2081,1,3), (2082,2,1), (2084,1,3), (2085,2,1), (2087,1,3), (2088,2,1), (2090,1,3), (2091,2,1),
+    (2093,1,3), (2094,2,1), (2096,1,3), (2097,2,1), (2099,1,3), (2100,2,1), (2102,1,3), (2103,2,1),
+    (2105,1,3), (2106,2,1), (2108,1,3), (2109,2,1), (2111,1,3), (2112,2,1), (2114,1,3), (2115,1,1),
+    (2116,1,1), (2117,1,1), (2118,1,1), (2119,1,1), (2120,1,1), (2121,1,1), (2122,1,1), (2123,1,1),
+    (2124,1,1), (2125,1,1), (2126,1,1), (2127,1,1), (2128,1,1), (2129,1,1), (2130,1,1), (2131,1,1),
+    (2132,2,1), (2134,1,3), (2135,5,1), (2140,4,2), (2144,4,3), (2148,3,3), (2151,4,1), (2155,3,3),
+    (2158,1,1), (2159,4,1), (2163,3,2), (2166,7,1), (2173,7,1), (2180,7,1), (2187,2,1), (2189,1,3),
+    (2190,2,1), (2192,1,3), (2193,1,1), (2194,1,1), (2195,1,1), (2196,1,0), (2197,1,0), (2198,1,0),
+    (2199,1,0), (2200,1,0), (2201,1,1), (2202,1,1), (2203,1,1), (2204,1,1), (2205,1,1), (2206,1,1),
+    (2207,1,1), (2208,1,1), (2209,1,1), (2210,3,1), (2213,1,1), (2214,1,1), (2215,1,1), (2216,1,1),
+    (2217,1,1), (2218,1,1), (2219,1,1), (2220,1,1), (2221,1,1), (2222,1,1), (2223,1,1), (2224,1,1),
+    (2225,1,1), (2226,3,1), (2229,1,1), (2230,1,1), (2231,1,1), (2232,1,1), (2233,1,1), (2234,1,1),
+    (2235,1,1), (2236,1,1), (2237,1,1), (2238,1,1), (2239,1,1), (2240,1,1), (2241,1,1), (2242,1,1),
+    (2243,1,1), (2244,1,1), (2245,1,1), (2246,1,1), (2247,1,1), (2248,1,1), (2249,1,1), (2250,1,1),
+    (2251,1,1), (2252,1,1), (2253,1,1), (2254,1,1), (2255,1,1), (2256,1,1), (2257,1,1), (2258,1,1),
+    (2259,1,1), (2260,1,1), (2261,1,1), (2262,1,1), (2263,1,1), (2264,1,1), (2265,1,1), (2266,1,1),
+    (2267,4,1), (2271,3,2), (2274,1,1), (2275,1,1), (2276,1,1), (2277,2,1), (2279,1,3), (2280,1,1),
+    (2281,2,1), (2283,1,3), (2284,5,1), (2289,4,2), (2293,4,3), (2297,3,3), (2300,1,1), (2301,2,1),
+    (2303,2,1), (2305,2,1), (2307,2,1), (2309,2,1), (2311,1,1), (2312,2,1), (2314,2,1), (2316,2,1),
+    (2318,2,1), (2320,2,1), (2322,1,1), (2323,1,1), (2324,1,1), (2325,2,1), (2327,2,1), (2329,2,1),
+    (2331,2,1), (2333,2,1), (2335,1,1), (2336,2,1), (2338,2,1), (2340,2,1), (2342,2,1), (2344,2,1),
+    (2346,1,1), (2347,2,1), (2349,2,1), (2351,2,1), (2353,2,1), (2355,2,1), (2357,1,1), (2358,2,1),
+    (2360,2,1), (2362,2,1), (2364,2,1), (2366,2,1), (2368,1,1), (2369,2,1), (2371,2,1), (2373,2,1),
+    (2375,2,1), (2377,2,1), (2379,1,1), (2380,2,1), (2382,2,1), (2384,2,1), (2386,2,1), (2388,2,1),
+    (2390,1,1), (2391,2,1), (2393,2,1), (2395,2,1), (2397,2,1), (2399,2,1), (2401,1,1), (2402,2,1),
+    (2404,2,1), (2406,2,1), (2408,2,1), (2410,2,1), (2412,1,1), (2413,2,1), (2415,2,1), (2417,2,1),
+    (2419,2,1), (2421,2,1), (2423,1,1), (2424,2,1), (2426,2,1), (2428,2,1), (2430,2,1), (2432,2,1),
+    (2434,1,1), (2435,2,1), (2437,2,1), (2439,2,1), (2441,2,1), (2443,2,1), (2445,1,1), (2446,1,1),
+    (2447,1,1), (2448,1,1), (2449,1,1), (2450,1,1), (2451,1,1), (2452,1,1), (2453,1,1), (2454,1,1),
+    (2455,1,1), (2456,1,1), (2457,1,1), (2458,1,1), (2459,1,1), (2460,1,1), (2461,1,1), (2462,1,1),
+    (2463,1,1), (2464,1,1), (2465,1,1), (2466,1,1), (2467,2,1), (2469,2,1), (2471,2,1), (2473,2,1),
+    (2475,2,1), (2477,1,1), (2478,2,1), (2480,2,1), (2482,2,1), (2484,2,1), (2486,2,1), (2488,1,1),
+    (2489,4,1), (2493,3,2), (2496,4,1), (2500,3,2), (2503,4,1), (2507,3,2), (2510,6,1), (2516,5,2),
+    (2521,8,1), (2529,7,2), (2536,3,1), (2539,3,1), (2542,3,1), (2545,3,1), (2548,3,1), (2551,3,1),
+    (2554,3,1), (2557,3,1), (2560,3,1), (2563,5,1), (2568,3,1), (2571,5,1), (2576,5,1), (2581,5,1),
+    (2586,7,1), (2593,5,1), (2598,7,1), (2605,7,1), (2612,5,1), (2617,7,1), (2624,5,1), (2629,7,1),
+    (2636,7,1), (2643,3,1), (2646,3,1), (2649,3,1), (2652,3,1), (2655,3,1), (2658,3,1), (2661,3,1),
+    (2664,3,1), (2667,3,1), (2670,3,1), (2673,3,1), (2676,3,1), (2679,3,1), (2682,2,1), (2684,5,1),
+    (2689,4,2), (2693,5,1), (2698,4,2), (2702,5,1), (2707,4,2), (2711,4,1), (2715,4,1), (2719,4,1),
+    (2723,4,1), (2727,4,1), (2731,4,1), (2735,4,1), (2739,4,1), (2743,4,1), (2747,4,1), (2751,4,1),
+    (2755,4,1), (2759,4,1), (2763,4,1), (2767,7,1), (2774,7,1), (2781,7,1), (2788,7,1), (2795,4,1),
+    (2799,4,1), (2803,4,1), (2807,4,1), (2811,4,1), (2815,4,1), (2819,4,1), (2823,2,1), (2825,5,1),
+    (2830,4,2), (2834,5,1), (2839,4,2), (2843,5,1), (2848,4,2), (2852,4,1), (2856,4,1), (2860,4,1),
+    (2864,4,1), (2868,4,1), (2872,4,1), (2876,4,1), (2880,4,1), (2884,4,1), (2888,4,1), (2892,4,1),
+    (2896,4,1), (2900,4,1), (2904,4,1), (2908,7,1), (2915,7,1), (2922,7,1), (2929,7,1), (2936,4,1),
+    (2940,4,1), (2944,4,1), (2948,4,1), (2952,4,1), (2956,4,1), (2960,4,1), (2964,2,1), (2966,5,1),
+    (2971,4,2), (2975,5,1), (2980,4,2), (2984,5,1), (2989,4,2), (2993,4,1), (2997,4,1), (3001,4,1),
+    (3005,4,1), (3009,4,1), (3013,4,1), (3017,4,1), (3021,4,1), (3025,4,1), (3029,4,1), (3033,4,1),
+    (3037,4,1), (3041,4,1), (3045,4,1), (3049,7,1), (3056,7,1), (3063,7,1), (3070,7,1), (3077,4,1),
+    (3081,4,1), (3085,4,1), (3089,4,1), (3093,4,1), (3097,4,1), (3101,4,1), (3105,2,1), (3107,5,1),
+    (3112,4,2), (3116,5,1), (3121,4,2), (3125,5,1), (3130,4,2), (3134,4,1), (3138,4,1), (3142,4,1),
+    (3146,4,1), (3150,4,1), (3154,4,1), (3158,4,1), (3162,4,1), (3166,4,1), (3170,4,1), (3174,4,1),
+    (3178,4,1), (3182,4,1), (3186,4,1), (3190,7,1), (3197,7,1), (3204,7,1), (3211,7,1), (3218,4,1),
+    (3222,4,1), (3226,4,1), (3230,4,1), (3234,4,1), (3238,4,1), (3242,4,1), (3246,2,1), (3248,5,1),
+    (3253,4,2), (3257,5,1), (3262,4,2), (3266,5,1), (3271,4,2), (3275,4,1), (3279,4,1), (3283,4,1),
+    (3287,4,1), (3291,4,1), (3295,4,1), (3299,4,1), (3303,4,1), (3307,4,1), (3311,4,1), (3315,4,1),
+    (3319,4,1), (3323,4,1), (3327,4,1), (3331,7,1), (3338,7,1), (3345,7,1), (3352,7,1), (3359,4,1),
+    (3363,4,1), (3367,4,1), (3371,4,1), (3375,4,1), (3379,4,1), (3383,4,1), (3387,1,1), (3388,4,1),
+    (3392,3,2), (3395,4,1), (3399,3,2), (3402,4,1), (3406,3,2), (3409,6,1), (3415,5,2), (3420,6,1),
+    (3426,5,2), (3431,8,1), (3439,7,2), (3446,8,1), (3454,7,2), (3461,3,1), (3464,3,1), (3467,3,1),
+    (3470,3,1), (3473,3,1), (3476,3,1), (3479,3,1), (3482,3,1), (3485,3,1), (3488,5,1), (3493,3,1),
+    (3496,5,1), (3501,5,1), (3506,5,1), (3511,7,1), (3518,5,1), (3523,7,1), (3530,7,1), (3537,3,1),
+    (3540,3,1), (3543,3,1), (3546,3,1), (3549,3,1), (3552,3,1), (3555,3,1), (3558,3,1), (3561,3,1),
+    (3564,3,1), (3567,3,1), (3570,3,1), (3573,3,1), (3576,2,1), (3578,5,1), (3583,4,2), (3587,5,1),
+    (3592,4,2), (3596,5,1), (3601,4,2), (3605,4,1), (3609,4,1), (3613,4,1), (3617,4,1), (3621,4,1),
+    (3625,4,1), (3629,4,1), (3633,4,1), (3637,4,1), (3641,4,1), (3645,4,1), (3649,4,1), (3653,4,1),
+    (3657,4,1), (3661,7,1), (3668,7,1), (3675,7,1), (3682,7,1), (3689,7,1), (3696,7,1), (3703,7,1),
+    (3710,7,1), (3717,4,1), (3721,4,1), (3725,4,1), (3729,4,1), (3733,4,1), (3737,4,1), (3741,4,1),
+    (3745,2,1), (3747,5,1), (3752,4,2), (3756,5,1), (3761,4,2), (3765,5,1), (3770,4,2), (3774,4,1),
+    (3778,4,1), (3782,4,1), (3786,4,1), (3790,4,1), (3794,4,1), (3798,4,1), (3802,4,1), (3806,4,1),
+    (3810,4,1), (3814,4,1), (3818,4,1), (3822,4,1), (3826,4,1), (3830,7,1), (3837,7,1), (3844,7,1),
+    (3851,7,1), (3858,7,1), (3865,7,1), (3872,7,1), (3879,7,1), (3886,4,1), (3890,4,1), (3894,4,1),
+    (3898,4,1), (3902,4,1), (3906,4,1), (3910,4,1), (3914,2,1), (3916,5,1), (3921,4,2), (3925,5,1),
+    (3930,4,2), (3934,5,1), (3939,4,2), (3943,4,1), (3947,4,1), (3951,4,1), (3955,4,1), (3959,4,1),
+    (3963,4,1), (3967,4,1), (3971,4,1), (3975,4,1), (3979,4,1), (3983,4,1), (3987,4,1), (3991,4,1),
+    (3995,4,1), (3999,7,1), (4006,7,1), (4013,7,1), (4020,7,1), (4027,7,1), (4034,7,1), (4041,7,1),
+    (4048,7,1), (4055,4,1), (4059,4,1), (4063,4,1), (4067,4,1), (4071,4,1), (4075,4,1), (4079,4,1),
+    (4083,2,1), (4085,5,1), (4090,4,2), (4094,5,1), (4099,4,2), (4103,5,1), (4108,4,2), (4112,4,1),
+    (4116,4,1), (4120,4,1), (4124,4,1), (4128,4,1), (4132,4,1), (4136,4,1), (4140,4,1), (4144,4,1),
+    (4148,4,1), (4152,4,1), (4156,4,1), (4160,4,1), (4164,4,1), (4168,7,1), (4175,7,1), (4182,7,1),
+    (4189,7,1), (4196,7,1), (4203,7,1), (4210,7,1), (4217,7,1), (4224,4,1), (4228,4,1), (4232,4,1),
+    (4236,4,1), (4240,4,1), (4244,4,1), (4248,4,1), (4252,2,1), (4254,5,1), (4259,4,2), (4263,5,1),
+    (4268,4,2), (4272,5,1), (4277,4,2), (4281,4,1), (4285,4,1), (4289,4,1), (4293,4,1), (4297,4,1),
+    (4301,4,1), (4305,4,1), (4309,4,1), (4313,4,1), (4317,4,1), (4321,4,1), (4325,4,1), (4329,4,1),
+    (4333,4,1), (4337,7,1), (4344,7,1), (4351,7,1), (4358,7,1), (4365,7,1), (4372,7,1), (4379,7,1),
+    (4386,7,1), (4393,4,1), (4397,4,1), (4401,4,1), (4405,4,1), (4409,4,1), (4413,4,1), (4417,4,1),
+    (4421,1,1), (4422,1,1), (4423,2,1), (4425,2,1), (4427,2,1), (4429,2,1), (4431,2,1), (4433,1,1),
+    (4434,2,1), (4436,2,1), (4438,2,1), (4440,2,1), (4442,2,1), (4444,1,1), (4445,2,1), (4447,2,1),
+    (4449,2,1), (4451,2,1), (4453,2,1), (4455,1,1), (4456,4,1), (4460,3,2), (4463,4,1), (4467,3,2),
+    (4470,2,1), (4472,5,1), (4477,4,2), (4481,5,1), (4486,4,2), (4490,2,1), (4492,5,1), (4497,4,2),
+    (4501,5,1), (4506,4,2), (4510,2,1), (4512,5,1), (4517,4,2), (4521,5,1), (4526,4,2), (4530,2,1),
+    (4532,5,1), (4537,4,2), (4541,5,1), (4546,4,2), (4550,2,1), (4552,5,1), (4557,4,2), (4561,5,1),
+    (4566,4,2), (4570,1,1), (4571,4,1), (4575,3,2), (4578,4,1), (4582,3,2), (4585,1,1), (4586,4,1),
+    (4590,3,2), (4593,4,1), (4597,3,2), (4600,2,1), (4602,5,1), (4607,4,2), (4611,5,1), (4616,4,2),
+    (4620,2,1), (4622,5,1), (4627,4,2), (4631,5,1), (4636,4,2), (4640,2,1), (4642,5,1), (4647,4,2),
+    (4651,5,1), (4656,4,2), (4660,2,1), (4662,5,1), (4667,4,2), (4671,5,1), (4676,4,2), (4680,2,1),
+    (4682,5,1), (4687,4,2), (4691,5,1), (4696,4,2), (4700,1,1), (4701,4,1), (4705,3,2), (4708,4,1),
+    (4712,3,2), (4715,2,1), (4717,5,1), (4722,4,2), (4726,5,1), (4731,4,2), (4735,2,1), (4737,5,1),
+    (4742,4,2), (4746,5,1), (4751,4,2), (4755,2,1), (4757,5,1), (4762,4,2), (4766,5,1), (4771,4,2),
+    (4775,2,1), (4777,5,1), (4782,4,2), (4786,5,1), (4791,4,2), (4795,2,1), (4797,5,1), (4802,4,2),
+    (4806,5,1), (4811,4,2), (4815,1,1), (4816,2,1), (4818,2,1), (4820,2,1), (4822,2,1), (4824,2,1),
+    (4826,1,1), (4827,4,1), (4831,3,2), (4834,4,1), (4838,3,2), (4841,2,1), (4843,5,1), (4848,4,2),
+    (4852,5,1), (4857,4,2), (4861,2,1), (4863,5,1), (4868,4,2), (4872,5,1), (4877,4,2), (4881,2,1),
+    (4883,5,1), (4888,4,2), (4892,5,1), (4897,4,2), (4901,2,1), (4903,5,1), (4908,4,2), (4912,5,1),
+    (4917,4,2), (4921,2,1), (4923,5,1), (4928,4,2), (4932,5,1), (4937,4,2), (4941,1,1), (4942,2,1),
+    (4944,2,1), (4946,2,1), (4948,2,1), (4950,2,1), (4952,1,1), (4953,2,1), (4955,2,1), (4957,2,1),
+    (4959,2,1), (4961,2,1), (4963,1,1), (4964,2,1), (4966,2,1), (4968,2,1), (4970,2,1), (4972,2,1),
+    (4974,1,1), (4975,4,1), (4979,3,2), (4982,4,1), (4986,3,2), (4989,2,1), (4991,5,1), (4996,4,2),
+    (5000,5,1), (5005,4,2), (5009,2,1), (5011,5,1), (5016,4,2), (5020,5,1), (5025,4,2), (5029,2,1),
+    (5031,5,1), (5036,4,2), (5040,5,1), (5045,4,2), (5049,2,1), (5051,5,1), (5056,4,2), (5060,5,1),
+    (5065,4,2), (5069,2,1), (5071,5,1), (5076,4,2), (5080,5,1), (5085,4,2), (5089,1,1), (5090,2,1),
+    (5092,2,1), (5094,2,1), (5096,2,1), (5098,2,1), (5100,1,1), (5101,1,1), (5102,1,1), (5103,1,1),
+    (5104,2,1), (5106,2,1), (5108,2,1), (5110,2,1), (5112,2,1), (5114,1,1), (5115,1,1), (5116,1,1),
+    (5117,1,1), (5118,1,1), (5119,4,1), (5123,3,2), (5126,4,1), (5130,3,2), (5133,2,1), (5135,5,1),
+    (5140,4,2), (5144,5,1), (5149,4,2), (5153,2,1), (5155,5,1), (5160,4,2), (5164,5,1), (5169,4,2),
+    (5173,2,1), (5175,5,1), (5180,4,2), (5184,5,1), (5189,4,2), (5193,2,1), (5195,5,1), (5200,4,2),
+    (5204,5,1), (5209,4,2), (5213,2,1), (5215,5,1), (5220,4,2), (5224,5,1), (5229,4,2), (5233,1,1),
+    (5234,4,1), (5238,3,2), (5241,4,1), (5245,3,2), (5248,2,1), (5250,5,1), (5255,4,2), (5259,5,1),
+    (5264,4,2), (5268,2,1), (5270,5,1), (5275,4,2), (5279,5,1), (5284,4,2), (5288,2,1), (5290,5,1),
+    (5295,4,2), (5299,5,1), (5304,4,2), (5308,2,1), (5310,5,1), (5315,4,2), (5319,5,1), (5324,4,2),
+    (5328,2,1), (5330,5,1), (5335,4,2), (5339,5,1), (5344,4,2), (5348,1,1), (5349,2,1), (5351,2,1),
+    (5353,2,1), (5355,2,1), (5357,2,1), (5359,1,1), (5360,1,1), (5361,2,1), (5363,2,1), (5365,2,1),
+    (5367,2,1), (5369,2,1), (5371,1,1), (5372,4,1), (5376,3,2), (5379,4,1), (5383,3,2), (5386,2,1),
+    (5388,5,1), (5393,4,2), (5397,5,1), (5402,4,2), (5406,2,1), (5408,5,1), (5413,4,2), (5417,5,1),
+    (5422,4,2), (5426,2,1), (5428,5,1), (5433,4,2), (5437,5,1), (5442,4,2), (5446,2,1), (5448,5,1),
+    (5453,4,2), (5457,5,1), (5462,4,2), (5466,2,1), (5468,5,1), (5473,4,2), (5477,5,1), (5482,4,2),
+    (5486,1,1), (5487,4,1), (5491,3,2), (5494,4,1), (5498,3,2), (5501,2,1), (5503,5,1), (5508,4,2),
+    (5512,5,1), (5517,4,2), (5521,2,1), (5523,5,1), (5528,4,2), (5532,5,1), (5537,4,2), (5541,2,1),
+    (5543,5,1), (5548,4,2), (5552,5,1), (5557,4,2), (5561,2,1), (5563,5,1), (5568,4,2), (5572,5,1),
+    (5577,4,2), (5581,2,1), (5583,5,1), (5588,4,2), (5592,5,1), (5597,4,2), (5601,1,1), (5602,1,1),
+    (5603,1,1), (5604,1,1), (5605,1,1), (5606,1,1), (5607,1,1), (5608,1,1), (5609,1,1), (5610,1,1),
+    (5611,1,1), (5612,1,1), (5613,1,1), (5614,1,1), (5615,1,1), (5616,1,1), (5617,1,1), (5618,1,1),
+    (5619,1,1), (5620,1,1), (5621,1,1), (5622,1,1), (5623,1,1), (5624,1,1), (5625,1,1), (5626,1,1),
+    (5627,1,1), (5628,1,1), (5629,1,1), (5630,1,1), (5631,1,1), (5632,1,1), (5633,1,1), (5634,1,1),
+    (5635,1,1), (5636,2,1), (5638,2,1), (5640,2,1), (5642,2,1), (5644,2,1), (5646,1,1), (5647,1,1),
+    (5648,1,1), (5649,1,1), (5650,1,1), (5651,1,1), (5652,1,1), (5653,1,1), (5654,1,1), (5655,1,1),
+    (5656,1,1), (5657,1,1), (5658,1,1), (5659,1,1), (5660,1,1), (5661,1,1), (5662,1,1), (5663,1,1),
+    (5664,1,1), (5665,1,1), (5666,1,1), (5667,1,1), (5668,1,1), (5669,1,1), (5670,1,1), (5671,1,1),
+    (5672,1,1), (5673,1,1), (5674,1,1), (5675,1,1), (5676,1,1), (5677,1,1), (5678,1,1), (5679,1,1),
+    (5680,1,1), (5681,1,1), (5682,1,1), (5683,1,1), (5684,1,1), (5685,1,1), (5686,1,1), (5687,1,1),
+    (5688,1,1), (5689,1,1), (5690,1,1), (5691,1,1), (5692,1,1), (5693,1,1), (5694,1,1), (5695,1,1),
+    (5696,1,1), (5697,1,1), (5698,1,1), (5699,1,1), (5700,1,1), (5701,1,1), (5702,1,1), (5703,1,1),
+    (5704,1,1), (5705,1,1), (5706,1,1), (5707,1,1), (5708,1,1), (5709,1,1), (5710,1,1), (5711,1,1),
+    (5712,1,1), (5713,1,1), (5714,1,1), (5715,1,1), (5716,1,1), (5717,1,1), (5718,1,1), (5719,1,1),
+    (5720,1,1), (5721,1,1), (5722,1,1), (5723,1,1), (5724,1,1), (5725,1,1), (5726,1,1), (5727,1,1),
+    (5728,2,1), (5730,1,3), (5731,1,1), (5732,1,1), (5733,1,1), (5734,1,1), (5735,1,1), (5736,1,1),
+    (5737,1,1), (5738,1,1), (5739,1,1), (5740,1,1), (5741,1,1), (5742,1,1), (5743,1,1), (5744,1,1),
+    (5745,1,1), (5746,1,1), (5747,1,1), (5748,1,1), (5749,1,1), (5750,1,1), (5751,1,1), (5752,1,1),
+    (5753,1,1), (5754,1,1), (5755,1,1), (5756,1,1), (5757,1,1), (5758,1,1), (5759,1,1), (5760,1,1),
+    (5761,1,1), (5762,1,1), (5763,1,1), (5764,1,1), (5765,1,1), (5766,1,1), (5767,1,1), (5768,1,1),
+    (5769,1,1), (5770,1,1), (5771,1,1), (5772,1,1), (5773,1,1), (5774,1,1), (5775,1,1), (5776,1,1),
+    (5777,1,1), (5778,1,1), (5779,1,1), (5780,1,1), (5781,1,1), (5782,1,1), (5783,1,1), (5784,1,1),
+    (5785,1,1), (5786,1,1), (5787,1,1), (5788,1,1), (5789,1,1), (5790,1,1), (5791,1,1), (5792,1,1),
+    (5793,1,1), (5794,2,1), (5796,1,3), (5797,2,1), (5799,1,3), (5800,1,1), (5801,1,1), (5802,1,1),
+    (5803,1,1), (5804,1,1), (5805,1,1), (5806,1,1), (5807,1,1), (5808,1,1), (5809,1,1), (5810,1,1),
+    (5811,1,1), (5812,1,1), (5813,1,1), (5814,1,1), (5815,1,1), (5816,1,1), (5817,1,1), (5818,1,1),
+    (5819,1,1), (5820,1,1), (5821,1,1), (5822,1,1), (5823,1,1), (5824,1,1), (5825,1,1), (5826,1,1),
+    (5827,1,1), (5828,2,1), (5830,1,3), (5831,2,1), (5833,1,3), (5834,2,1), (5836,1,3), (5837,2,1),
+    (5839,1,3), (5840,2,1), (5842,2,1), (5844,2,1), (5846,2,1), (5848,2,1), (5850,2,1), (5852,1,3),
+    (5853,5,1), (5858,4,2), (5862,4,3), (5866,3,3), (5869,5,1), (5874,4,2), (5878,4,3), (5882,3,3),
+    (5885,2,1), (5887,5,1), (5892,4,2), (5896,5,1), (5901,4,2), (5905,2,1), (5907,5,1), (5912,4,2),
+    (5916,5,1), (5921,4,2), (5925,2,1), (5927,5,1), (5932,4,2), (5936,5,1), (5941,4,2), (5945,2,1),
+    (5947,5,1), (5952,4,2), (5956,5,1), (5961,4,2), (5965,2,1), (5967,5,1), (5972,4,2), (5976,5,1),
+    (5981,4,2), (5985,2,1), (5987,1,3), (5988,2,1), (5990,1,3), (5991,2,1), (5993,1,3), (5994,2,1),
+    (5996,1,3), (5997,1,1), (5998,2,1), (6000,2,1), (6002,2,1), (6004,2,1), (6006,2,1), (6008,2,1),
+    (6010,1,3), (6011,2,1), (6013,1,3), (6014,2,1), (6016,1,3), (6017,2,1), (6019,1,3), (6020,2,1),
+    (6022,1,3), (6023,2,1), (6025,1,3), (6026,2,1), (6028,2,1), (6030,2,1), (6032,2,1), (6034,2,1),
+    (6036,1,1), (6037,2,1), (6039,2,1), (6041,2,1), (6043,2,1), (6045,2,1), (6047,1,1), (6048,2,1),
+    (6050,2,1), (6052,2,1), (6054,2,1), (6056,2,1), (6058,1,1), (6059,2,1), (6061,1,3), (6062,2,1),
+    (6064,1,3), (6065,2,1), (6067,1,3), (6068,2,1), (6070,1,3), (6071,2,1), (6073,1,3), (6074,2,1),
+    (6076,1,3), (6077,2,1), (6079,1,3), (6080,2,1), (6082,1,3), (6083,2,1), (6085,1,3), (6086,2,1),
+    (6088,1,3), (6089,2,1), (6091,1,3), (6092,2,1), (6094,1,3), (6095,2,1), (6097,1,3), (6098,2,1),
+    (6100,1,3), (6101,2,1), (6103,1,3), (6104,2,1), (6106,1,3), (6107,2,1), (6109,1,3), (6110,2,1),
+    (6112,1,3), (6113,2,1), (6115,1,3), (6116,2,1), (6118,1,3), (6119,1,1), (6120,1,1), (6121,1,1),
+    (6122,1,1), (6123,1,1), (6124,1,1), (6125,1,1), (6126,1,1), (6127,1,1), (6128,1,1), (6129,1,1),
+    (6130,1,1), (6131,1,1), (6132,1,1), (6133,1,1), (6134,1,1), (6135,1,1), (6136,1,1), (6137,1,1),
+    (6138,1,1), (6139,1,1), (6140,1,1), (6141,1,1), (6142,1,1), (6143,1,1), (6144,1,1), (6145,1,1),
+    (6146,1,1), (6147,1,1), (6148,1,1), (6149,1,1), (6150,1,1), (6151,1,1), (6152,1,1), (6153,1,1),
+    (6154,1,1), (6155,1,1), (6156,1,1), (6157,1,1), (6158,1,1), (6159,1,1), (6160,1,1), (6161,1,1),
+    (6162,1,1), (6163,1,1), (6164,1,1), (6165,1,1), (6166,1,1), (6167,1,1), (6168,1,1), (6169,1,1),
+    (6170,1,1), (6171,1,1), (6172,1,1), (6173,1,1), (6174,1,1), (6175,1,1), (6176,1,1), (6177,1,1),
+    (6178,1,1), (6179,1,1), (6180,1,1), (6181,1,1), (6182,1,1), (6183,1,1), (6184,1,1), (6185,1,1),
+    (6186,1,1), (6187,1,1), (6188,1,1), (6189,1,1), (6190,1,1), (6191,1,1), (6192,1,1), (6193,1,1),
+    (6194,4,1), (6198,3,2), (6201,4,1), (6205,3,2), (6208,2,1), (6210,5,1), (6215,4,2), (6219,5,1),
+    (6224,4,2), (6228,2,1), (6230,5,1), (6235,4,2), (6239,5,1), (6244,4,2), (6248,2,1), (6250,5,1),
+    (6255,4,2), (6259,5,1), (6264,4,2), (6268,2,1), (6270,5,1), (6275,4,2), (6279,5,1), (6284,4,2),
+    (6288,2,1), (6290,5,1), (6295,4,2), (6299,5,1), (6304,4,2), (6308,1,1), (6309,4,1), (6313,3,2),
+    (6316,4,1), (6320,3,2), (6323,2,1), (6325,5,1), (6330,4,2), (6334,5,1), (6339,4,2), (6343,2,1),
+    (6345,5,1), (6350,4,2), (6354,5,1), (6359,4,2), (6363,2,1), (6365,5,1), (6370,4,2), (6374,5,1),
+    (6379,4,2), (6383,2,1), (6385,5,1), (6390,4,2), (6394,5,1), (6399,4,2), (6403,2,1), (6405,5,1),
+    (6410,4,2), (6414,5,1), (6419,4,2), (6423,1,1), (6424,4,1), (6428,3,2), (6431,4,1), (6435,3,2),
+    (6438,2,1), (6440,5,1), (6445,4,2), (6449,5,1), (6454,4,2), (6458,2,1), (6460,5,1), (6465,4,2),
+    (6469,5,1), (6474,4,2), (6478,2,1), (6480,5,1), (6485,4,2), (6489,5,1), (6494,4,2), (6498,2,1),
+    (6500,5,1), (6505,4,2), (6509,5,1), (6514,4,2), (6518,2,1), (6520,5,1), (6525,4,2), (6529,5,1),
+    (6534,4,2), (6538,1,1), (6539,1,1), (6540,1,1), (6541,1,1), (6542,4,1), (6546,3,2), (6549,4,1),
+    (6553,3,2), (6556,2,1), (6558,5,1), (6563,4,2), (6567,5,1), (6572,4,2), (6576,2,1), (6578,5,1),
+    (6583,4,2), (6587,5,1), (6592,4,2), (6596,2,1), (6598,5,1), (6603,4,2), (6607,5,1), (6612,4,2),
+    (6616,2,1), (6618,5,1), (6623,4,2), (6627,5,1), (6632,4,2), (6636,2,1), (6638,5,1), (6643,4,2),
+    (6647,5,1), (6652,4,2), (6656,1,1), (6657,2,1), (6659,2,1), (6661,2,1), (6663,2,1), (6665,2,1),
+    (6667,1,1), (6668,4,1), (6672,3,2), (6675,4,1), (6679,3,2), (6682,2,1), (6684,5,1), (6689,4,2),
+    (6693,5,1), (6698,4,2), (6702,2,1), (6704,5,1), (6709,4,2), (6713,5,1), (6718,4,2), (6722,2,1),
+    (6724,5,1), (6729,4,2), (6733,5,1), (6738,4,2), (6742,2,1), (6744,5,1), (6749,4,2), (6753,5,1),
+    (6758,4,2), (6762,2,1), (6764,5,1), (6769,4,2), (6773,5,1), (6778,4,2), (6782,1,1), (6783,4,1),
+    (6787,3,2), (6790,4,1), (6794,3,2), (6797,2,1), (6799,5,1), (6804,4,2), (6808,5,1), (6813,4,2),
+    (6817,2,1), (6819,5,1), (6824,4,2), (6828,5,1), (6833,4,2), (6837,2,1), (6839,5,1), (6844,4,2),
+    (6848,5,1), (6853,4,2), (6857,2,1), (6859,5,1), (6864,4,2), (6868,5,1), (6873,4,2), (6877,2,1),
+    (6879,5,1), (6884,4,2), (6888,5,1), (6893,4,2), (6897,1,1), (6898,2,1), (6900,2,1), (6902,2,1),
+    (6904,2,1), (6906,2,1), (6908,1,1), (6909,1,1), (6910,1,1), (6911,1,1), (6912,1,1), (6913,1,1),
+    (6914,1,1), (6915,1,1), (6916,1,1), (6917,1,1), (6918,1,1), (6919,1,1), (6920,1,1), (6921,1,1),
+    (6922,1,1), (6923,1,1), (6924,1,1), (6925,1,1), (6926,1,1), (6927,1,1), (6928,1,1), (6929,1,1),
+    (6930,1,1), (6931,1,1), (6932,1,1), (6933,1,1), (6934,1,1), (6935,1,1), (6936,1,1), (6937,1,1),
+    (6938,1,1), (6939,1,1), (6940,1,1), (6941,1,1), (6942,1,1), (6943,1,1), (6944,4,1), (6948,3,2),
+    (6951,4,1), (6955,3,2), (6958,2,1), (6960,5,1), (6965,4,2), (6969,5,1), (6974,4,2), (6978,2,1),
+    (6980,5,1), (6985,4,2), (6989,5,1), (6994,4,2), (6998,2,1), (7000,5,1), (7005,4,2), (7009,5,1),
+    (7014,4,2), (7018,2,1), (7020,5,1), (7025,4,2), (7029,5,1), (7034,4,2), (7038,2,1), (7040,5,1),
+    (7045,4,2), (7049,5,1), (7054,4,2), (7058,1,1), (7059,1,1), (7060,1,1), (7061,1,1), (7062,1,1),
+    (7063,1,1), (7064,1,1), (7065,1,1), (7066,1,1), (7067,1,1), (7068,1,1), (7069,1,1), (7070,1,1),
+    (7071,1,1), (7072,1,1), (7073,1,1), (7074,1,1), (7075,4,1), (7079,3,2), (7082,4,1), (7086,3,2),
+    (7089,2,1), (7091,5,1), (7096,4,2), (7100,5,1), (7105,4,2), (7109,2,1), (7111,5,1), (7116,4,2),
+    (7120,5,1), (7125,4,2), (7129,2,1), (7131,5,1), (7136,4,2), (7140,5,1), (7145,4,2), (7149,2,1),
+    (7151,5,1), (7156,4,2), (7160,5,1), (7165,4,2), (7169,2,1), (7171,5,1), (7176,4,2), (7180,5,1),
+    (7185,4,2), (7189,1,1), (7190,4,1), (7194,3,2), (7197,4,1), (7201,3,2), (7204,2,1), (7206,5,1),
+    (7211,4,2), (7215,5,1), (7220,4,2), (7224,2,1), (7226,5,1), (7231,4,2), (7235,5,1), (7240,4,2),
+    (7244,2,1), (7246,5,1), (7251,4,2), (7255,5,1), (7260,4,2), (7264,2,1), (7266,5,1), (7271,4,2),
+    (7275,5,1), (7280,4,2), (7284,2,1), (7286,5,1), (7291,4,2), (7295,5,1), (7300,4,2), (7304,1,1),
+    (7305,4,1), (7309,3,2), (7312,4,1), (7316,3,2), (7319,2,1), (7321,5,1), (7326,4,2), (7330,5,1),
+    (7335,4,2), (7339,2,1), (7341,5,1), (7346,4,2), (7350,5,1), (7355,4,2), (7359,2,1), (7361,5,1),
+    (7366,4,2), (7370,5,1), (7375,4,2), (7379,2,1), (7381,5,1), (7386,4,2), (7390,5,1), (7395,4,2),
+    (7399,2,1), (7401,5,1), (7406,4,2), (7410,5,1), (7415,4,2), (7419,1,1), (7420,1,1), (7421,1,1),
+    (7422,1,1), (7423,1,1), (7424,1,1), (7425,1,1), (7426,1,1), (7427,1,1), (7428,1,1), (7429,2,1),
+    (7431,2,1), (7433,2,1), (7435,2,1), (7437,2,1), (7439,1,1), (7440,1,1), (7441,1,1), (7442,1,1),
+    (7443,1,1), (7444,2,1), (7446,1,3), (7447,1,1), (7448,2,1), (7450,2,1), (7452,2,1), (7454,2,1),
+    (7456,2,1), (7458,2,1), (7460,1,3), (7461,2,1), (7463,1,3), (7464,2,1), (7466,1,3), (7467,1,1),
+    (7468,1,1), (7469,1,1), (7470,1,1), (7471,1,1), (7472,1,1), (7473,2,1), (7475,1,3), (7476,2,1),
+    (7478,1,3), (7479,2,1), (7481,1,3), (7482,2,1), (7484,1,3), (7485,2,1), (7487,1,3), (7488,2,1),
+    (7490,1,3), (7491,2,1), (7493,1,3), (7494,1,1), (7495,1,1), (7496,2,1), (7498,1,3), (7499,2,1),
+    (7501,1,3), (7502,1,1), (7503,1,1), (7504,1,1), (7505,1,1), (7506,1,1), (7507,1,1), (7508,1,1),
+    (7509,1,1), (7510,1,1), (7511,1,1), (7512,1,1), (7513,1,1), (7514,1,1), (7515,1,1), (7516,1,1),
+    (7517,1,1), (7518,1,1), (7519,1,1), (7520,1,1), (7521,1,1), (7522,1,1), (7523,1,1), (7524,2,1),
+    (7526,2,1), (7528,2,1), (7530,2,1), (7532,2,1), (7534,1,1), (7535,1,1), (7536,1,1), (7537,2,1),
+    (7539,2,1), (7541,2,1), (7543,2,1), (7545,2,1), (7547,1,1), (7548,1,1), (7549,1,1), (7550,1,1),
+    (7551,1,1), (7552,1,1), (7553,1,1), (7554,1,1), (7555,1,1), (7556,2,1), (7558,2,1), (7560,2,1),
+    (7562,2,1), (7564,2,1), (7566,1,1), (7567,2,1), (7569,2,1), (7571,2,1), (7573,2,1), (7575,2,1),
+    (7577,1,1), (7578,2,1), (7580,2,1), (7582,2,1), (7584,2,1), (7586,2,1), (7588,1,1), (7589,2,1),
+    (7591,2,1), (7593,2,1), (7595,2,1), (7597,2,1), (7599,1,1), (7600,2,1), (7602,2,1), (7604,2,1),
+    (7606,2,1), (7608,2,1), (7610,1,1), (7611,1,1), (7612,2,1), (7614,2,1), (7616,2,1), (7618,2,1),
+    (7620,2,1), (7622,1,1), (7623,2,1), (7625,2,1), (7627,2,1), (7629,2,1), (7631,2,1), (7633,1,1),
+    (7634,1,1), (7635,1,1), (7636,1,1), (7637,1,1), (7638,1,1), (7639,1,1), (7640,4,1), (7644,3,2),
+    (7647,4,1), (7651,3,2), (7654,2,1), (7656,5,1), (7661,4,2), (7665,5,1), (7670,4,2), (7674,2,1),
+    (7676,5,1), (7681,4,2), (7685,5,1), (7690,4,2), (7694,2,1), (7696,5,1), (7701,4,2), (7705,5,1),
+    (7710,4,2), (7714,2,1), (7716,5,1), (7721,4,2), (7725,5,1), (7730,4,2), (7734,2,1), (7736,5,1),
+    (7741,4,2), (7745,5,1), (7750,4,2), (7754,1,1), (7755,1,1), (7756,1,1), (7757,1,1), (7758,1,1),
+    (7759,1,1), (7760,1,1), (7761,1,1), (7762,1,1), (7763,1,1), (7764,2,1), (7766,2,1), (7768,2,1),
+    (7770,2,1), (7772,2,1), (7774,1,1), (7775,2,1), (7777,2,1), (7779,2,1), (7781,2,1), (7783,2,1),
+    (7785,1,1), (7786,2,1), (7788,2,1), (7790,2,1), (7792,2,1), (7794,2,1), (7796,1,1), (7797,2,1),
+    (7799,2,1), (7801,2,1), (7803,2,1), (7805,2,1), (7807,1,1), (7808,2,1), (7810,2,1), (7812,2,1),
+    (7814,2,1), (7816,2,1), (7818,1,1), (7819,4,1), (7823,3,2), (7826,4,1), (7830,3,2), (7833,2,1),
+    (7835,5,1), (7840,4,2), (7844,5,1), (7849,4,2), (7853,2,1), (7855,5,1), (7860,4,2), (7864,5,1),
+    (7869,4,2), (7873,2,1), (7875,5,1), (7880,4,2), (7884,5,1), (7889,4,2), (7893,2,1), (7895,5,1),
+    (7900,4,2), (7904,5,1), (7909,4,2), (7913,2,1), (7915,5,1), (7920,4,2), (7924,5,1), (7929,4,2),
+    (7933,1,1), (7934,2,1), (7936,2,1), (7938,2,1), (7940,2,1), (7942,2,1), (7944,1,1), (7945,4,1),
+    (7949,3,2), (7952,4,1), (7956,3,2), (7959,2,1), (7961,5,1), (7966,4,2), (7970,5,1), (7975,4,2),
+    (7979,2,1), (7981,5,1), (7986,4,2), (7990,5,1), (7995,4,2), (7999,2,1), (8001,5,1), (8006,4,2),
+    (8010,5,1), (8015,4,2), (8019,2,1), (8021,5,1), (8026,4,2), (8030,5,1), (8035,4,2), (8039,2,1),
+    (8041,5,1), (8046,4,2), (8050,5,1), (8055,4,2), (8059,1,1), (8060,4,1), (8064,3,2), (8067,4,1),
+    (8071,3,2), (8074,2,1), (8076,5,1), (8081,4,2), (8085,5,1), (8090,4,2), (8094,2,1), (8096,5,1),
+    (8101,4,2), (8105,5,1), (8110,4,2), (8114,2,1), (8116,5,1), (8121,4,2), (8125,5,1), (8130,4,2),
+    (8134,2,1), (8136,5,1), (8141,4,2), (8145,5,1), (8150,4,2), (8154,2,1), (8156,5,1), (8161,4,2),
+    (8165,5,1), (8170,4,2), (8174,1,1), (8175,4,1), (8179,3,2), (8182,4,1), (8186,3,2), (8189,2,1),
+    (8191,5,1), (8196,4,2), (8200,5,1), (8205,4,2), (8209,2,1), (8211,5,1), (8216,4,2), (8220,5,1),
+    (8225,4,2), (8229,2,1), (8231,5,1), (8236,4,2), (8240,5,1), (8245,4,2), (8249,2,1), (8251,5,1),
+    (8256,4,2), (8260,5,1), (8265,4,2), (8269,2,1), (8271,5,1), (8276,4,2), (8280,5,1), (8285,4,2),
+    (8289,1,1), (8290,1,1), (8291,4,1), (8295,3,2), (8298,4,1), (8302,3,2), (8305,1,1), (8306,4,1),
+    (8310,3,2), (8313,4,1), (8317,3,2), (8320,2,1), (8322,5,1), (8327,4,2), (8331,5,1), (8336,4,2),
+    (8340,2,1), (8342,5,1), (8347,4,2), (8351,5,1), (8356,4,2), (8360,2,1), (8362,5,1), (8367,4,2),
+    (8371,5,1), (8376,4,2), (8380,2,1), (8382,5,1), (8387,4,2), (8391,5,1), (8396,4,2), (8400,2,1),
+    (8402,5,1), (8407,4,2), (8411,5,1), (8416,4,2), (8420,1,1), (8421,4,1), (8425,3,2), (8428,4,1),
+    (8432,3,2), (8435,2,1), (8437,5,1), (8442,4,2), (8446,5,1), (8451,4,2), (8455,2,1), (8457,5,1),
+    (8462,4,2), (8466,5,1), (8471,4,2), (8475,2,1), (8477,5,1), (8482,4,2), (8486,5,1), (8491,4,2),
+    (8495,2,1), (8497,5,1), (8502,4,2), (8506,5,1), (8511,4,2), (8515,2,1), (8517,5,1), (8522,4,2),
+    (8526,5,1), (8531,4,2), (8535,1,1), (8536,1,1), (8537,1,1), (8538,1,1), (8539,1,1), (8540,1,1),
+    (8541,1,1), (8542,1,1), (8543,1,1), (8544,1,1), (8545,1,1), (8546,1,1), (8547,1,1), (8548,1,1),
+    (8549,1,1), (8550,1,1), (8551,1,1), (8552,1,1), (8553,1,1), (8554,1,1), (8555,1,1), (8556,1,1),
+    (8557,1,1), (8558,1,1), (8559,1,1), (8560,1,1), (8561,1,1), (8562,1,1), (8563,1,1), (8564,1,1),
+    (8565,1,1), (8566,1,1), (8567,1,1), (8568,1,1), (8569,1,1), (8570,1,1), (8571,1,1), (8572,1,1),
+    (8573,1,1), (8574,1,1), (8575,1,1), (8576,1,1), (8577,1,1), (8578,1,1), (8579,1,1), (8580,1,1),
+    (8581,1,1), (8582,1,1), (8583,1,1), (8584,1,1), (8585,1,1), (8586,1,1), (8587,1,1), (8588,1,1),
+    (8589,1,1), (8590,1,1), (8591,2,1), (8593,2,1), (8595,2,1), (8597,2,1), (8599,2,1), (8601,1,1),
+    (8602,1,1), (8603,1,1), (8604,1,1), (8605,1,1), (8606,1,1), (8607,1,1), (8608,1,1), (8609,1,1),
+    (8610,1,1), (8611,1,1), (8612,1,1), (8613,1,1), (8614,1,1), (8615,1,1), (8616,1,1), (8617,1,1),
+    (8618,1,1), (8619,1,1), (8620,1,1), (8621,1,1), (8622,1,1), (8623,1,1), (8624,1,1), (8625,1,1),
+    (8626,1,1), (8627,1,1), (8628,1,1), (8629,1,1), (8630,1,1), (8631,1,1), (8632,1,1), (8633,1,1),
+    (8634,1,1), (8635,1,1), (8636,1,1), (8637,1,1), (8638,1,1), (8639,1,1), (8640,1,1), (8641,1,1),
+    (8642,1,1), (8643,1,1), (8644,1,1), (8645,1,1), (8646,1,1), (8647,1,1), (8648,1,1), (8649,1,1),
+    (8650,1,1), (8651,1,1), (8652,1,1), (8653,1,1), (8654,1,1), (8655,1,1), (8656,1,0), (8657,1,0),
+    (8658,1,0), (8659,1,0), (8660,1,1), (8661,1,1), (8662,2,1), (8664,2,1), (8666,2,1), (8668,2,1),
+    (8670,2,1), (8672,1,1), (8673,2,1), (8675,2,1), (8677,2,1), (8679,2,1), (8681,2,1), (8683,1,1),
+    (8684,1,1), (8685,4,1), (8689,3,2), (8692,4,1), (8696,3,2), (8699,2,1), (8701,5,1), (8706,4,2),
+    (8710,5,1), (8715,4,2), (8719,2,1), (8721,5,1), (8726,4,2), (8730,5,1), (8735,4,2), (8739,2,1),
+    (8741,5,1), (8746,4,2), (8750,5,1), (8755,4,2), (8759,2,1), (8761,5,1), (8766,4,2), (8770,5,1),
+    (8775,4,2), (8779,2,1), (8781,5,1), (8786,4,2), (8790,5,1), (8795,4,2), (8799,1,1), (8800,4,1),
+    (8804,3,2), (8807,4,1), (8811,3,2), (8814,2,1), (8816,5,1), (8821,4,2), (8825,5,1), (8830,4,2),
+    (8834,2,1), (8836,5,1), (8841,4,2), (8845,5,1), (8850,4,2), (8854,2,1), (8856,5,1), (8861,4,2),
+    (8865,5,1), (8870,4,2), (8874,2,1), (8876,5,1), (8881,4,2), (8885,5,1), (8890,4,2), (8894,2,1),
+    (8896,5,1), (8901,4,2), (8905,5,1), (8910,4,2), (8914,1,1), (8915,1,1), (8916,2,1), (8918,2,1),
+    (8920,2,1), (8922,2,1), (8924,2,1), (8926,1,1), (8927,1,1), (8928,1,1), (8929,1,1), (8930,1,1),
+    (8931,1,1), (8932,1,1), (8933,1,1), (8934,1,1), (8935,1,1), (8936,1,1), (8937,1,1), (8938,1,1),
+    (8939,1,1), (8940,1,1), (8941,1,1), (8942,1,1), (8943,4,1), (8947,3,2), (8950,4,1), (8954,3,2),
+    (8957,2,1), (8959,5,1), (8964,4,2), (8968,5,1), (8973,4,2), (8977,2,1), (8979,5,1), (8984,4,2),
+    (8988,5,1), (8993,4,2), (8997,2,1), (8999,5,1), (9004,4,2), (9008,5,1), (9013,4,2), (9017,2,1),
+    (9019,5,1), (9024,4,2), (9028,5,1), (9033,4,2), (9037,2,1), (9039,5,1), (9044,4,2), (9048,5,1),
+    (9053,4,2), (9057,1,1), (9058,4,1), (9062,3,2), (9065,4,1), (9069,3,2), (9072,2,1), (9074,5,1),
+    (9079,4,2), (9083,5,1), (9088,4,2), (9092,2,1), (9094,5,1), (9099,4,2), (9103,5,1), (9108,4,2),
+    (9112,2,1), (9114,5,1), (9119,4,2), (9123,5,1), (9128,4,2), (9132,2,1), (9134,5,1), (9139,4,2),
+    (9143,5,1), (9148,4,2), (9152,2,1), (9154,5,1), (9159,4,2), (9163,5,1), (9168,4,2), (9172,1,1),
+    (9173,4,1), (9177,3,2), (9180,4,1), (9184,3,2), (9187,2,1), (9189,5,1), (9194,4,2), (9198,5,1),
+    (9203,4,2), (9207,2,1), (9209,5,1), (9214,4,2), (9218,5,1), (9223,4,2), (9227,2,1), (9229,5,1),
+    (9234,4,2), (9238,5,1), (9243,4,2), (9247,2,1), (9249,5,1), (9254,4,2), (9258,5,1), (9263,4,2),
+    (9267,2,1), (9269,5,1), (9274,4,2), (9278,5,1), (9283,4,2), (9287,1,1), (9288,1,1), (9289,4,1),
+    (9293,3,2), (9296,4,1), (9300,3,2), (9303,4,1), (9307,3,2), (9310,3,1), (9313,3,1), (9316,3,1),
+    (9319,3,1), (9322,3,1), (9325,3,1), (9328,3,1), (9331,3,1), (9334,3,1), (9337,3,1), (9340,3,1),
+    (9343,3,1), (9346,3,1), (9349,3,1), (9352,3,1), (9355,5,1), (9360,3,1), (9363,3,1), (9366,3,1),
+    (9369,3,1), (9372,3,1), (9375,3,1), (9378,3,1), (9381,2,1), (9383,5,1), (9388,4,2), (9392,5,1),
+    (9397,4,2), (9401,5,1), (9406,4,2), (9410,4,1), (9414,4,1), (9418,4,1), (9422,4,1), (9426,4,1),
+    (9430,4,1), (9434,4,1), (9438,4,1), (9442,4,1), (9446,4,1), (9450,4,1), (9454,4,1), (9458,4,1),
+    (9462,4,1), (9466,4,1), (9470,7,1), (9477,7,1), (9484,7,1), (9491,7,1), (9498,7,1), (9505,4,1),
+    (9509,4,1), (9513,4,1), (9517,4,1), (9521,4,1), (9525,4,1), (9529,4,1), (9533,2,1), (9535,5,1),
+    (9540,4,2), (9544,5,1), (9549,4,2), (9553,5,1), (9558,4,2), (9562,4,1), (9566,4,1), (9570,4,1),
+    (9574,4,1), (9578,4,1), (9582,4,1), (9586,4,1), (9590,4,1), (9594,4,1), (9598,4,1), (9602,4,1),
+    (9606,4,1), (9610,4,1), (9614,4,1), (9618,4,1), (9622,7,1), (9629,7,1), (9636,7,1), (9643,7,1),
+    (9650,7,1), (9657,4,1), (9661,4,1), (9665,4,1), (9669,4,1), (9673,4,1), (9677,4,1), (9681,4,1),
+    (9685,2,1), (9687,5,1), (9692,4,2), (9696,5,1), (9701,4,2), (9705,5,1), (9710,4,2), (9714,4,1),
+    (9718,4,1), (9722,4,1), (9726,4,1), (9730,4,1), (9734,4,1), (9738,4,1), (9742,4,1), (9746,4,1),
+    (9750,4,1), (9754,4,1), (9758,4,1), (9762,4,1), (9766,4,1), (9770,4,1), (9774,7,1), (9781,7,1),
+    (9788,7,1), (9795,7,1), (9802,7,1), (9809,4,1), (9813,4,1), (9817,4,1), (9821,4,1), (9825,4,1),
+    (9829,4,1), (9833,4,1), (9837,2,1), (9839,5,1), (9844,4,2), (9848,5,1), (9853,4,2), (9857,5,1),
+    (9862,4,2), (9866,4,1), (9870,4,1), (9874,4,1), (9878,4,1), (9882,4,1), (9886,4,1), (9890,4,1),
+    (9894,4,1), (9898,4,1), (9902,4,1), (9906,4,1), (9910,4,1), (9914,4,1), (9918,4,1), (9922,4,1),
+    (9926,7,1), (9933,7,1), (9940,7,1), (9947,7,1), (9954,7,1), (9961,4,1), (9965,4,1), (9969,4,1),
+    (9973,4,1), (9977,4,1), (9981,4,1), (9985,4,1), (9989,2,1), (9991,5,1), (9996,4,2),
+    (10000,5,1), (10005,4,2), (10009,5,1), (10014,4,2), (10018,4,1), (10022,4,1), (10026,4,1),
+    (10030,4,1), (10034,4,1), (10038,4,1), (10042,4,1), (10046,4,1), (10050,4,1), (10054,4,1),
+    (10058,4,1), (10062,4,1), (10066,4,1), (10070,4,1), (10074,4,1), (10078,7,1), (10085,7,1),
+    (10092,7,1), (10099,7,1), (10106,7,1), (10113,4,1), (10117,4,1), (10121,4,1), (10125,4,1),
+    (10129,4,1), (10133,4,1), (10137,4,1), (10141,1,1), (10142,2,1), (10144,2,1), (10146,2,1),
+    (10148,2,1), (10150,2,1), (10152,1,1), (10153,2,1), (10155,2,1), (10157,2,1), (10159,2,1),
+    (10161,2,1), (10163,1,1), (10164,2,1), (10166,2,1), (10168,2,1), (10170,2,1), (10172,2,1),
+    (10174,1,1), (10175,2,1), (10177,2,1), (10179,2,1), (10181,2,1), (10183,2,1), (10185,1,1),
+    (10186,4,1), (10190,3,2), (10193,4,1), (10197,3,2), (10200,2,1), (10202,5,1), (10207,4,2),
+    (10211,5,1), (10216,4,2), (10220,2,1), (10222,5,1), (10227,4,2), (10231,5,1), (10236,4,2),
+    (10240,2,1), (10242,5,1), (10247,4,2), (10251,5,1), (10256,4,2), (10260,2,1), (10262,5,1),
+    (10267,4,2), (10271,5,1), (10276,4,2), (10280,2,1), (10282,5,1), (10287,4,2), (10291,5,1),
+    (10296,4,2), (10300,1,1), (10301,4,1), (10305,3,2), (10308,4,1), (10312,3,2), (10315,2,1),
+    (10317,5,1), (10322,4,2), (10326,5,1), (10331,4,2), (10335,2,1), (10337,5,1), (10342,4,2),
+    (10346,5,1), (10351,4,2), (10355,2,1), (10357,5,1), (10362,4,2), (10366,5,1), (10371,4,2),
+    (10375,2,1), (10377,5,1), (10382,4,2), (10386,5,1), (10391,4,2), (10395,2,1), (10397,5,1),
+    (10402,4,2), (10406,5,1), (10411,4,2), (10415,1,1), (10416,4,1), (10420,3,2), (10423,4,1),
+    (10427,3,2), (10430,2,1), (10432,5,1), (10437,4,2), (10441,5,1), (10446,4,2), (10450,2,1),
+    (10452,5,1), (10457,4,2), (10461,5,1), (10466,4,2), (10470,2,1), (10472,5,1), (10477,4,2),
+    (10481,5,1), (10486,4,2), (10490,2,1), (10492,5,1), (10497,4,2), (10501,5,1), (10506,4,2),
+    (10510,2,1), (10512,5,1), (10517,4,2), (10521,5,1), (10526,4,2), (10530,1,1), (10531,4,1),
+    (10535,3,2), (10538,4,1), (10542,3,2), (10545,2,1), (10547,5,1), (10552,4,2), (10556,5,1),
+    (10561,4,2), (10565,2,1), (10567,5,1), (10572,4,2), (10576,5,1), (10581,4,2), (10585,2,1),
+    (10587,5,1), (10592,4,2), (10596,5,1), (10601,4,2), (10605,2,1), (10607,5,1), (10612,4,2),
+    (10616,5,1), (10621,4,2), (10625,2,1), (10627,5,1), (10632,4,2), (10636,5,1), (10641,4,2),
+    (10645,1,1), (10646,4,1), (10650,3,2), (10653,4,1), (10657,3,2), (10660,2,1), (10662,5,1),
+    (10667,4,2), (10671,5,1), (10676,4,2), (10680,2,1), (10682,5,1), (10687,4,2), (10691,5,1),
+    (10696,4,2), (10700,2,1), (10702,5,1), (10707,4,2), (10711,5,1), (10716,4,2), (10720,2,1),
+    (10722,5,1), (10727,4,2), (10731,5,1), (10736,4,2), (10740,2,1), (10742,5,1), (10747,4,2),
+    (10751,5,1), (10756,4,2), (10760,1,1), (10761,4,1), (10765,3,2), (10768,4,1), (10772,3,2),
+    (10775,2,1), (10777,5,1), (10782,4,2), (10786,5,1), (10791,4,2), (10795,2,1), (10797,5,1),
+    (10802,4,2), (10806,5,1), (10811,4,2), (10815,2,1), (10817,5,1), (10822,4,2), (10826,5,1),
+    (10831,4,2), (10835,2,1), (10837,5,1), (10842,4,2), (10846,5,1), (10851,4,2), (10855,2,1),
+    (10857,5,1), (10862,4,2), (10866,5,1), (10871,4,2), (10875,1,1), (10876,4,1), (10880,3,2),
+    (10883,4,1), (10887,3,2), (10890,2,1), (10892,5,1), (10897,4,2), (10901,5,1), (10906,4,2),
+    (10910,2,1), (10912,5,1), (10917,4,2), (10921,5,1), (10926,4,2), (10930,2,1), (10932,5,1),
+    (10937,4,2), (10941,5,1), (10946,4,2), (10950,2,1), (10952,5,1), (10957,4,2), (10961,5,1),
+    (10966,4,2), (10970,2,1), (10972,5,1), (10977,4,2), (10981,5,1), (10986,4,2), (10990,1,1),
+    (10991,4,1), (10995,3,2), (10998,4,1), (11002,3,2), (11005,2,1), (11007,5,1), (11012,4,2),
+    (11016,5,1), (11021,4,2), (11025,2,1), (11027,5,1), (11032,4,2), (11036,5,1), (11041,4,2),
+    (11045,2,1), (11047,5,1), (11052,4,2), (11056,5,1), (11061,4,2), (11065,2,1), (11067,5,1),
+    (11072,4,2), (11076,5,1), (11081,4,2), (11085,2,1), (11087,5,1), (11092,4,2), (11096,5,1),
+    (11101,4,2), (11105,1,1), (11106,4,1), (11110,3,2), (11113,4,1), (11117,3,2), (11120,1,1),
+    (11121,4,1), (11125,3,2), (11128,4,1), (11132,3,2), (11135,1,1), (11136,1,1), (11137,1,1),
+    (11138,1,1), (11139,1,1), (11140,1,1), (11141,1,1), (11142,1,1), (11143,1,1), (11144,1,1),
+    (11145,1,1), (11146,1,1), (11147,1,1), (11148,1,1), (11149,1,1), (11150,1,1), (11151,1,1),
+    (11152,1,1), (11153,1,1), (11154,1,1), (11155,1,1), (11156,1,1), (11157,1,1), (11158,1,1),
+    (11159,1,1), (11160,1,1), (11161,1,1), (11162,1,1), (11163,1,1), (11164,1,1), (11165,1,1),
+    (11166,1,1), (11167,1,1), (11168,1,1), (11169,1,1), (11170,1,1), (11171,1,1), (11172,1,1),
+    (11173,1,1), (11174,1,1), (11175,1,1), (11176,1,1), (11177,1,1), (11178,1,1), (11179,1,1),
+    (11180,1,1), (11181,1,1), (11182,1,1), (11183,1,1), (11184,1,1), (11185,1,1), (11186,1,1),
+    (11187,1,1), (11188,1,1), (11189,1,1), (11190,1,1), (11191,1,1), (11192,1,1), (11193,1,1),
+    (11194,1,1), (11195,1,1), (11196,1,1), (11197,1,1), (11198,1,1), (11199,1,1), (11200,1,1),
+    (11201,1,1), (11202,1,1), (11203,1,1), (11204,1,1), (11205,1,1), (11206,1,1), (11207,1,1),
+    (11208,1,1), (11209,1,1), (11210,1,1), (11211,1,1), (11212,1,1), (11213,1,1), (11214,1,1),
+    (11215,1,1), (11216,1,1), (11217,1,1), (11218,1,1), (11219,1,1), (11220,1,1), (11221,1,1),
+    (11222,1,1), (11223,1,1),
+];
@@ -0,0 +1,175 @@
+/*!
+Bindings for use from JavaScript through `wasm-bindgen`.
+
+These expose character properties along with grapheme, word, line and
+cluster segmentation so that a web based editor can produce the same
+boundaries as a native renderer using this crate.
+
+Text is accepted as a JavaScript string and boundaries are returned as
+arrays of offsets in UTF-16 code units, which can be used directly as
+string indices. Unpaired surrogates are treated as U+FFFD.
+*/
+
+use super::cluster::{source_chars_utf16, Cluster, Parser};
+use super::locale::Locale;
+use super::paragraph::{paragraph_info, LineBoundary, LineBreakOptions};
+use super::unicode::{Codepoint, Properties, Script};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Unicode properties of a character.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct CharProperties(Properties);
+
+#[wasm_bindgen]
+impl CharProperties {
+    /// Returns the general category, such as "UppercaseLetter".
+    #[wasm_bindgen(getter)]
+    pub fn category(&self) -> String {
+        format!("{:?}", self.0.category())
+    }
+
+    /// Returns the name of the script, such as "Latin".
+    #[wasm_bindgen(getter)]
+    pub fn script(&self) -> String {
+        self.0.script().name().into()
+    }
+
+    /// Returns the canonical combining class.
+    #[wasm_bindgen(getter, js_name = combiningClass)]
+    pub fn combining_class(&self) -> u8 {
+        self.0.combining_class()
+    }
+
+    /// Returns the BiDi class, such as "L".
+    #[wasm_bindgen(getter, js_name = bidiClass)]
+    pub fn bidi_class(&self) -> String {
+        format!("{:?}", self.0.bidi_class())
+    }
+
+    /// Returns the grapheme cluster break property, such as "CR".
+    #[wasm_bindgen(getter, js_name = clusterBreak)]
+    pub fn cluster_break(&self) -> String {
+        format!("{:?}", self.0.cluster_break())
+    }
+
+    /// Returns the word break property, such as "LE".
+    #[wasm_bindgen(getter, js_name = wordBreak)]
+    pub fn word_break(&self) -> String {
+        format!("{:?}", self.0.word_break())
+    }
+
+    /// Returns the line break property, such as "AL".
+    #[wasm_bindgen(getter, js_name = lineBreak)]
+    pub fn line_break(&self) -> String {
+        format!("{:?}", self.0.line_break())
+    }
+
+    /// Returns true if the character has the `Emoji` property.
+    #[wasm_bindgen(getter, js_name = isEmoji)]
+    pub fn is_emoji(&self) -> bool {
+        self.0.is_emoji()
+    }
+
+    /// Returns true if the character has the `Extended_Pictographic`
+    /// property.
+    #[wasm_bindgen(getter, js_name = isExtendedPictographic)]
+    pub fn is_extended_pictographic(&self) -> bool {
+        self.0.is_extended_pictographic()
+    }
+
+    /// Returns true if the character has the `White_Space` property.
+    #[wasm_bindgen(getter, js_name = isWhitespace)]
+    pub fn is_whitespace(&self) -> bool {
+        self.0.is_whitespace()
+    }
+
+    /// Returns true if the character is default ignorable.
+    #[wasm_bindgen(getter, js_name = isDefaultIgnorable)]
+    pub fn is_default_ignorable(&self) -> bool {
+        self.0.is_default_ignorable()
+    }
+}
+
+/// Returns the Unicode properties of the specified character.
+#[wasm_bindgen(js_name = charProperties)]
+pub fn char_properties(ch: char) -> CharProperties {
+    CharProperties(ch.properties())
+}
+
+/// Returns the offsets at which each grapheme cluster in the text begins.
+#[wasm_bindgen(js_name = graphemeBoundaries)]
+pub fn grapheme_boundaries(text: &str) -> Vec<u32> {
+    cluster_starts(text, Script::Common)
+}
+
+/// Returns the offsets of the word boundaries in the text. Each offset
+/// begins a word or a run of non-word characters.
+#[wasm_bindgen(js_name = wordBoundaries)]
+pub fn word_boundaries(text: &str) -> Vec<u32> {
+    let text = text.encode_utf16().collect::<Vec<_>>();
+    source_chars_utf16(&text)
+        .filter(|ch| ch.info.is_word_boundary())
+        .map(|ch| ch.offset as u32)
+        .collect()
+}
+
+/// Returns the offsets at which a line may begin. Line breaking is tailored
+/// by the `lb` and `lw` keywords of the optional locale. Returns `undefined`
+/// if the locale cannot be parsed.
+#[wasm_bindgen(js_name = lineBreakOpportunities)]
+pub fn line_break_opportunities(text: &str, locale: Option<String>) -> Option<Vec<u32>> {
+    let options = match locale {
+        Some(locale) => LineBreakOptions::from_locale(&Locale::parse(&locale)?),
+        None => LineBreakOptions::default(),
+    };
+    Some(line_boundaries(text, options, LineBoundary::Soft))
+}
+
+/// Returns the offsets at which a line must begin, following hard line
+/// breaks such as line feeds and paragraph separators.
+#[wasm_bindgen(js_name = mandatoryLineBreaks)]
+pub fn mandatory_line_breaks(text: &str) -> Vec<u32> {
+    line_boundaries(text, LineBreakOptions::default(), LineBoundary::Hard)
+}
+
+/// Returns the offsets at which each shaping cluster in the text begins,
+/// using the rules of the specified script. The script may be given by
+/// name or by ISO 15924 code. Returns `undefined` if the script is not
+/// recognized.
+///
+/// For most scripts, clusters are equivalent to grapheme clusters. Complex
+/// scripts may produce clusters that contain multiple graphemes.
+#[wasm_bindgen(js_name = clusterBoundaries)]
+pub fn cluster_boundaries(text: &str, script: &str) -> Option<Vec<u32>> {
+    Some(cluster_starts(text, Script::from_name(script)?))
+}
+
+fn cluster_starts(text: &str, script: Script) -> Vec<u32> {
+    let text = text.encode_utf16().collect::<Vec<_>>();
+    let mut parser = Parser::from_utf16(script, &text);
+    let mut cluster = Cluster::new();
+    let mut offsets = Vec::new();
+    while parser.next(&mut cluster) {
+        offsets.push(cluster.range().start as u32);
+    }
+    offsets
+}
+
+fn line_boundaries(text: &str, options: LineBreakOptions, min: LineBoundary) -> Vec<u32> {
+    let mut offsets = Vec::new();
+    let mut offset = 0;
+    for (ch, info) in text
+        .chars()
+        .zip(paragraph_info(text.chars()).with_line_break_options(options))
+    {
+        if info.line_boundary() >= min {
+            offsets.push(offset);
+        }
+        offset += ch.len_utf16() as u32;
+    }
+    offsets
+}